@@ -2,115 +2,146 @@
 mod ui;
 
 use anyhow::Context;
-use pokemon_battle_core::battle::{Battle, BattleOptions, BattlePolicy, PlayerAction, Side};
-use pokemon_battle_core::{load_teams, model::{Pokemon, TeamsFile}, MctsMode, MctsParams};
-use rand::rngs::SmallRng;
-use rand::seq::SliceRandom;
-use rand::{Rng, SeedableRng};
+use pokemon_battle_core::dex::Dex;
+use pokemon_battle_core::formats::{require_format, Format};
+use pokemon_battle_core::parser::ParseOptions;
+use pokemon_battle_core::random_teams::RandomSetPool;
+use pokemon_battle_core::sim::ai::{policy_with, POLICIES};
+use pokemon_battle_core::sim::{run_battle_with_state, Action, BattleAI, BattleState, Pokemon, TeamChoice, TeamPreview};
+use pokemon_battle_core::teams::{load_teams, Team, TeamLibrary};
+use pokemon_battle_core::{MctsMode, MctsParams};
 use std::env;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Duration;
 
+const DEFAULT_FORMAT: &str = "gen9bss";
+/// Format of `--random-teams` games without `--format`.
+const DEFAULT_RANDOM_FORMAT: &str = "gen9randombattle";
+/// Seed for random teams without `--seed`.
+const RANDOM_TEAM_SEED: u64 = 0xBADC0DE;
+
 struct CliOptions {
     teams_path: PathBuf,
-    seed: u64,
-    human_side: Side,
-    policy: BattlePolicy,
+    team_names: Vec<String>,
+    teams_dir: Option<PathBuf>,
+    format: Option<String>,
+    /// `--random-teams`: generate both teams instead of reading them.
+    random_teams: bool,
+    /// `--random-sets <file>`: the set pool for random teams (default: the bundled pool).
+    random_sets: Option<PathBuf>,
+    seed: Option<u64>,
+    /// 0 = team A, 1 = team B.
+    human_side: usize,
+    policy: String,
+    mcts_params: MctsParams,
 }
 
 fn main() -> anyhow::Result<()> {
     let opts = parse_args()?;
-    let teams = load_teams(&opts.teams_path).context("チームデータの読み込みに失敗しました")?;
-    run_game(opts, teams)
+    let dex = Dex::builtin();
+    let (format, teams) = prepare_teams(&dex, &opts)?;
+    run_game(&dex, &opts, format, teams)
 }
 
 fn parse_args() -> anyhow::Result<CliOptions> {
-    let mut teams_path = PathBuf::from("../teams.json");
-    let mut seed = 0u64;
-    let mut human_side = Side::A;
-    let mut policy = BattlePolicy::Random;
-    let mut mcts_params = MctsParams::default();
+    let mut opts = CliOptions {
+        teams_path: PathBuf::from("teams.json"),
+        team_names: Vec::new(),
+        teams_dir: None,
+        format: None,
+        random_teams: false,
+        random_sets: None,
+        seed: None,
+        human_side: 0,
+        policy: "random".to_string(),
+        mcts_params: MctsParams::default(),
+    };
 
     let mut args = env::args().skip(1);
     while let Some(arg) = args.next() {
         match arg.as_str() {
             "--teams" => {
-                teams_path = args
+                opts.teams_path = args
                     .next()
                     .map(PathBuf::from)
                     .ok_or_else(|| anyhow::anyhow!("--teams の後にパスを指定してください"))?;
             }
+            "--team" => {
+                opts.team_names.push(
+                    args.next()
+                        .ok_or_else(|| anyhow::anyhow!("--team の後にチーム名またはファイルを指定してください"))?,
+                );
+            }
+            "--teams-dir" => {
+                opts.teams_dir = Some(
+                    args.next()
+                        .map(PathBuf::from)
+                        .ok_or_else(|| anyhow::anyhow!("--teams-dir の後にディレクトリを指定してください"))?,
+                );
+            }
+            "--format" => {
+                opts.format =
+                    Some(args.next().ok_or_else(|| anyhow::anyhow!("--format の後にフォーマットIDを指定してください"))?);
+            }
             "--seed" => {
                 let val = args
                     .next()
                     .ok_or_else(|| anyhow::anyhow!("--seed の後に数値を指定してください"))?;
-                seed = val.parse()?;
+                opts.seed = Some(val.parse()?);
+            }
+            "--random-teams" => opts.random_teams = true,
+            "--random-sets" => {
+                opts.random_sets = Some(PathBuf::from(
+                    args.next()
+                        .ok_or_else(|| anyhow::anyhow!("--random-sets の後にセットファイルを指定してください"))?,
+                ));
             }
             "--human-side" => {
                 let val = args
                     .next()
                     .ok_or_else(|| anyhow::anyhow!("--human-side の後に A または B を指定してください"))?;
-                human_side = parse_side(&val)
+                opts.human_side = parse_side(&val)
                     .ok_or_else(|| anyhow::anyhow!("--human-side は A または B を指定してください"))?;
             }
             "--policy" => {
-                let val = args
-                    .next()
-                    .ok_or_else(|| anyhow::anyhow!("--policy の後に random または mcts を指定してください"))?;
-                policy = match val.to_ascii_lowercase().as_str() {
-                    "random" => BattlePolicy::Random,
-                    "mcts" => BattlePolicy::Mcts(mcts_params.clone()),
-                    other => anyhow::bail!("--policy は random または mcts を指定してください (指定: {other})"),
-                };
+                opts.policy = args.next().ok_or_else(|| {
+                    anyhow::anyhow!("--policy の後に {} のいずれかを指定してください", POLICIES.join(" / "))
+                })?;
             }
             "--mcts-iters" => {
                 let val = args
                     .next()
                     .ok_or_else(|| anyhow::anyhow!("--mcts-iters の後に数値を指定してください"))?;
-                mcts_params.iterations = Some(val.parse()?);
-                if matches!(policy, BattlePolicy::Mcts(_)) {
-                    policy = BattlePolicy::Mcts(mcts_params.clone());
-                }
+                opts.mcts_params.iterations = Some(val.parse()?);
             }
             "--mcts-ms" => {
                 let val = args
                     .next()
                     .ok_or_else(|| anyhow::anyhow!("--mcts-ms の後にミリ秒を指定してください"))?;
-                mcts_params.time_budget = Some(Duration::from_millis(val.parse()?));
-                if matches!(policy, BattlePolicy::Mcts(_)) {
-                    policy = BattlePolicy::Mcts(mcts_params.clone());
-                }
+                opts.mcts_params.time_budget = Some(Duration::from_millis(val.parse()?));
             }
             "--rollout-horizon" => {
                 let val = args
                     .next()
                     .ok_or_else(|| anyhow::anyhow!("--rollout-horizon の後に数値を指定してください"))?;
-                mcts_params.rollout_horizon = val.parse()?;
-                if matches!(policy, BattlePolicy::Mcts(_)) {
-                    policy = BattlePolicy::Mcts(mcts_params.clone());
-                }
+                opts.mcts_params.rollout_horizon = val.parse()?;
             }
             "--uct-c" => {
                 let val = args
                     .next()
                     .ok_or_else(|| anyhow::anyhow!("--uct-c の後に実数を指定してください"))?;
-                mcts_params.exploration_constant = val.parse()?;
-                if matches!(policy, BattlePolicy::Mcts(_)) {
-                    policy = BattlePolicy::Mcts(mcts_params.clone());
-                }
+                opts.mcts_params.exploration_constant = val.parse()?;
             }
             "--mcts-mode" => {
                 let val = args
                     .next()
                     .ok_or_else(|| anyhow::anyhow!("--mcts-mode の後に joint または myaction を指定してください"))?;
-                mcts_params.mode = match val.to_ascii_lowercase().as_str() {
+                opts.mcts_params.mode = match val.to_ascii_lowercase().as_str() {
                     "joint" => MctsMode::Joint,
                     "myaction" | "my_action" => MctsMode::MyActionOnly,
                     other => anyhow::bail!("--mcts-mode は joint または myaction を指定してください (指定: {other})"),
                 };
-                if matches!(policy, BattlePolicy::Mcts(_)) {
-                    policy = BattlePolicy::Mcts(mcts_params.clone());
-                }
             }
             "--help" | "-h" => {
                 print_usage();
@@ -122,179 +153,141 @@ fn parse_args() -> anyhow::Result<CliOptions> {
             }
         }
     }
-
-    Ok(CliOptions {
-        teams_path,
-        seed,
-        human_side,
-        policy,
-    })
+    Ok(opts)
 }
 
-fn run_game(opts: CliOptions, teams: TeamsFile) -> anyhow::Result<()> {
-    let party_size = 3;
-    let mut selection_rng = SmallRng::seed_from_u64(opts.seed ^ 0xD1B5_C0DE);
-    let (selected_a, selected_b) =
-        prepare_parties(
-            &teams.team_a,
-            &teams.team_b,
-            opts.human_side,
-            party_size,
-            &mut selection_rng,
-        )?;
-
-    let mut battle = Battle::new_with_options(
-        &selected_a,
-        &selected_b,
-        opts.seed,
-        BattleOptions {
-            auto_switch_on_faint: false,
-        },
-    );
-    let mut ai_seed_rng = SmallRng::seed_from_u64(opts.seed ^ 0x9E37_79B9);
-    let human = opts.human_side;
-    let ai = match human {
-        Side::A => Side::B,
-        Side::B => Side::A,
-    };
-
-    let (human_candidates, opponent_candidates) = match human {
-        Side::A => (&teams.team_a, &teams.team_b),
-        Side::B => (&teams.team_b, &teams.team_a),
-    };
+/// The format and both prepared teams: generated for `--random-teams`/`--random-sets` or a random
+/// format without `--team`, else read through [`load_pair`].
+fn prepare_teams(dex: &Dex, opts: &CliOptions) -> anyhow::Result<(&'static Format, [Vec<Pokemon>; 2])> {
+    let random_format = opts.format.as_deref().map(require_format).transpose()?.filter(|f| f.random_teams);
+    if opts.random_teams || opts.random_sets.is_some() || (random_format.is_some() && opts.team_names.is_empty()) {
+        let format = require_format(opts.format.as_deref().unwrap_or(DEFAULT_RANDOM_FORMAT))?;
+        let loaded;
+        let pool = match &opts.random_sets {
+            Some(path) => {
+                loaded = RandomSetPool::load(path)?;
+                &loaded
+            }
+            None => RandomSetPool::builtin(),
+        };
+        let seed = opts.seed.unwrap_or(RANDOM_TEAM_SEED);
+        let team_a = pool.generate(dex, format, seed).context("チームA")?;
+        let team_b = pool.generate(dex, format, seed.wrapping_add(1)).context("チームB")?;
+        return Ok((format, [team_a, team_b]));
+    }
+    let (team_a, team_b) = load_pair(dex, opts).context("チームデータの読み込みに失敗しました")?;
+    let format = require_format(
+        opts.format.as_deref().or(team_a.meta.format.as_deref()).unwrap_or(DEFAULT_FORMAT),
+    )?;
+    let teams = [
+        format.prepare_team(dex, &team_a.sets).context("チームA")?,
+        format.prepare_team(dex, &team_b.sets).context("チームB")?,
+    ];
+    Ok((format, teams))
+}
 
-    loop {
-        if let Some(result) = battle.outcome() {
-            ui::render(
-                &battle.view(),
-                human,
-                human_candidates,
-                opponent_candidates,
-            );
-            ui::print_result(result, human);
-            break;
+/// Both teams: two `--team` names from the team library, else the `--teams` file.
+fn load_pair(dex: &Dex, opts: &CliOptions) -> anyhow::Result<(Team, Team)> {
+    match opts.team_names.as_slice() {
+        [] => {
+            let teams = load_teams(dex, &opts.teams_path)?;
+            Ok((teams.team_a, teams.team_b))
         }
-
-        if battle.needs_switch(human) {
-            let view = battle.view();
-            ui::render(
-                &view,
-                human,
-                human_candidates,
-                opponent_candidates,
-            );
-            let action = ui::prompt_action(&view, human, true)?;
-            if let PlayerAction::Switch(idx) = action {
-                battle.manual_switch(human, idx);
-            }
-            continue;
+        [a, b] => {
+            // `--teams-dir`, else `teams/` here or at the workspace root.
+            let dir = opts.teams_dir.clone().unwrap_or_else(|| {
+                let local = PathBuf::from("teams");
+                if local.is_dir() {
+                    local
+                } else {
+                    Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("teams")
+                }
+            });
+            let library = TeamLibrary::load_dir(dex, &dir, ParseOptions::default())?;
+            Ok((library.resolve(dex, a, ParseOptions::default())?, library.resolve(dex, b, ParseOptions::default())?))
         }
+        _ => anyhow::bail!("--team は 2 回指定してください"),
+    }
+}
 
-        if battle.needs_switch(ai) {
-            if let Some(idx) = choose_ai_switch(&battle, ai) {
-                battle.manual_switch(ai, idx);
-            }
-            continue;
-        }
+fn run_game(dex: &Arc<Dex>, opts: &CliOptions, format: &Format, teams: [Vec<Pokemon>; 2]) -> anyhow::Result<()> {
+    let human = opts.human_side;
+    let mut ai = policy_with(&opts.policy, opts.seed.unwrap_or(0), &opts.mcts_params)?;
+    let mut player = HumanPlayer {
+        dex: dex.clone(),
+        side: human,
+        opponent_candidates: teams[1 - human].iter().map(|p| p.species.clone()).collect(),
+    };
 
-        let view = battle.view();
-        ui::render(
-            &view,
-            human,
-            human_candidates,
-            opponent_candidates,
-        );
-        let human_action = ui::prompt_action(&view, human, false)?;
-        let ai_seed = ai_seed_rng.gen::<u64>();
-        let ai_action = choose_ai_action(&battle, ai, &opts.policy, ai_seed);
+    let human_party = select_party(format, &mut player, &teams[human], &teams[1 - human])?;
+    ui::print_selection_summary(dex, "あなた", &human_party);
+    let ai_party = select_party(format, ai.as_mut(), &teams[1 - human], &teams[human])?;
+    println!("相手も{}体のポケモンを選出しました。", ai_party.len());
 
-        let (a_action, b_action) = match human {
-            Side::A => (Some(human_action), ai_action),
-            Side::B => (ai_action, Some(human_action)),
-        };
+    let (party_a, party_b) = if human == 0 { (human_party, ai_party) } else { (ai_party, human_party) };
+    let mut state = format.new_battle(dex.clone(), party_a, party_b)?;
+    let result = if human == 0 {
+        run_battle_with_state(&mut state, &mut player, ai.as_mut())
+    } else {
+        run_battle_with_state(&mut state, ai.as_mut(), &mut player)
+    };
+    ui::print_result(result, human);
+    Ok(())
+}
 
-        battle.run_turn_with_actions(a_action, b_action);
-        let move_lines = ui::format_move_events(battle.last_turn_move_events(), human);
-        let status_lines = ui::format_status_events(battle.last_turn_status_events(), human);
-        let updated_view = battle.view();
-        let faint_lines = ui::describe_faints(&view, &updated_view, human);
-        ui::print_turn_summary(&move_lines, &status_lines, &faint_lines);
-        ui::wait_for_next_turn();
+/// Team preview through `chooser` when `format` has one, else the whole team in order.
+fn select_party(format: &Format, chooser: &mut dyn BattleAI, team: &[Pokemon], opponent: &[Pokemon]) -> anyhow::Result<Vec<Pokemon>> {
+    if !format.team_preview {
+        return Ok(team.to_vec());
     }
+    let preview = TeamPreview::new(team, opponent, format.bring_count(team.len()));
+    let choice = chooser.choose_team(&preview, &preview.legal_choices());
+    Ok(choice.select(team))
+}
 
-    Ok(())
+/// The player at the keyboard.
+struct HumanPlayer {
+    dex: Arc<Dex>,
+    side: usize,
+    opponent_candidates: Vec<String>,
 }
 
-fn choose_ai_switch(battle: &Battle, side: Side) -> Option<usize> {
-    battle.available_switches(side).into_iter().next()
+impl HumanPlayer {
+    fn or_exit<T>(result: anyhow::Result<T>) -> T {
+        result.unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            std::process::exit(1)
+        })
+    }
 }
 
-fn choose_ai_action(battle: &Battle, side: Side, policy: &BattlePolicy, seed: u64) -> Option<PlayerAction> {
-    if battle.needs_switch(side) {
-        return choose_ai_switch(battle, side).map(PlayerAction::Switch);
+impl BattleAI for HumanPlayer {
+    fn choose_action(&mut self, state: &BattleState, valid_actions: &[Action]) -> Action {
+        ui::render(state, self.side, &self.opponent_candidates);
+        Self::or_exit(ui::prompt_action(state, self.side, valid_actions))
+    }
+
+    fn choose_replacement(&mut self, state: &BattleState, options: &[usize]) -> Option<usize> {
+        Some(Self::or_exit(ui::prompt_replacement(state, self.side, options)))
+    }
+
+    fn choose_team(&mut self, preview: &TeamPreview, _legal: &[TeamChoice]) -> TeamChoice {
+        Self::or_exit(ui::prompt_team_selection(&self.dex, "あなた", preview))
     }
-    battle.select_action(side, policy, seed)
 }
 
-fn parse_side(s: &str) -> Option<Side> {
+fn parse_side(s: &str) -> Option<usize> {
     match s.to_ascii_lowercase().as_str() {
-        "a" => Some(Side::A),
-        "b" => Some(Side::B),
+        "a" => Some(0),
+        "b" => Some(1),
         _ => None,
     }
 }
 
 fn print_usage() {
     eprintln!(
-        "Usage: cargo run -p pokemon-battle-cli -- [--teams ../teams.json] [--seed N] [--human-side A|B] \
-[--policy random|mcts] [--mcts-iters N] [--mcts-ms MS] [--rollout-horizon H] [--uct-c C] [--mcts-mode joint|myaction]"
+        "Usage: cargo run -p pokemon-battle-cli --features ui --bin pokemon-battle-cli-ui -- [--teams teams.json | --team <a> --team <b> [--teams-dir <dir>]] \
+[--format <id>] [--random-teams] [--random-sets <file>] [--seed N] [--human-side A|B] [--policy <{}>] [--mcts-iters N] [--mcts-ms MS] [--rollout-horizon H] [--uct-c C] [--mcts-mode joint|myaction]",
+        POLICIES.join("|")
     );
 }
-
-fn prepare_parties(
-    team_a: &[Pokemon],
-    team_b: &[Pokemon],
-    human_side: Side,
-    count: usize,
-    rng: &mut SmallRng,
-) -> anyhow::Result<(Vec<Pokemon>, Vec<Pokemon>)> {
-    match human_side {
-        Side::A => {
-            let picks = ui::prompt_team_selection("あなた", team_a, team_b, count)?;
-            let human_party = clone_selected(team_a, &picks);
-            ui::print_selectiummary("あなた", &human_party);
-            let ai_party = select_ai_party(team_b, count, rng)?;
-            println!("相手も{}体のポケモンを選出しました。", ai_party.len());
-            Ok((human_party, ai_party))
-        }
-        Side::B => {
-            let picks = ui::prompt_team_selection("あなた", team_b, team_a, count)?;
-            let human_party = clone_selected(team_b, &picks);
-            ui::print_selection_summary("あなた", &human_party);
-            let ai_party = select_ai_party(team_a, count, rng)?;
-            println!("相手も{}体のポケモンを選出しました。", ai_party.len());
-            Ok((ai_party, human_party))
-        }
-    }
-}
-
-fn clone_selected(team: &[Pokemon], indexes: &[usize]) -> Vec<Pokemon> {
-    indexes
-        .iter()
-        .map(|&idx| {
-            team.get(idx)
-                .cloned()
-                .expect("invalid team index during selection")
-        })
-        .collect()
-}
-
-fn select_ai_party(team: &[Pokemon], count: usize, rng: &mut SmallRng) -> anyhow::Result<Vec<Pokemon>> {
-    if team.len() < count {
-        anyhow::bail!("相手チームのポケモンが{}体未満です", count);
-    }
-    let mut indices: Vec<usize> = (0..team.len()).collect();
-    indices.shuffle(rng);
-    indices.truncate(count);
-    Ok(indices.into_iter().map(|i| team[i].clone()).collect())
-}
@@ -1,18 +1,45 @@
 use anyhow::{anyhow, Context};
-use pokemon_battle_core::battle_logger::{showdown_ident, BattleLogger};
-use pokemon_battle_core::data::moves::{get_move, normalize_move_name};
-use pokemon_battle_core::data::species::POKEDEX;
-use pokemon_battle_core::i18n::translate_pokemon;
-use pokemon_battle_core::parser::parse_showdown_team;
-use pokemon_battle_core::sim::battle::{execute_turn, Action, BattleState};
-use pokemon_battle_core::sim::{run_team_battle as sim_run_team_battle, BattleResult, RandomAI};
+use pokemon_battle_core::battle_logger::showdown_ident;
+use pokemon_battle_core::datagen::render_all;
+use pokemon_battle_core::dex::Dex;
+use pokemon_battle_core::data::moves::MoveCategory;
+use pokemon_battle_core::i18n::messages;
+use pokemon_battle_core::i18n::{
+    ability_from_japanese, describe_ability, describe_item, describe_move, item_from_japanese, move_from_japanese,
+    pokemon_from_japanese, translate_ability, translate_item, translate_move, translate_nature, translate_pokemon, translate_type,
+};
+use pokemon_battle_core::random_teams::RandomSetPool;
+use pokemon_battle_core::parser::{
+    check_showdown_team, parse_json_sets, parse_packed_sets_in, parse_showdown_sets,
+    ParseOptions, TeamParseError,
+};
+use pokemon_battle_core::sets::{export_team, pack_team, team_to_json, PokemonSet};
+use pokemon_battle_core::formats::{require_format, Format, DEFAULT_FORMAT};
+use pokemon_battle_core::teams::{load_teams, Team, TeamLibrary};
+use pokemon_battle_core::search::{base_stat_total, Query, SearchHit};
+use pokemon_battle_core::sim::battle::{execute_turn, Action};
+use pokemon_battle_core::sim::ai::{policy_with, MctsParams, RolloutPolicy, ScriptedAI, TdParams, ValueAI, ValueFunction, POLICIES};
+use pokemon_battle_core::sim::{run_battle_with_state, BattleAI, BattleResult, TeamPreview};
+use pokemon_battle_core::sim::ai::expectiminimax::HpEvaluator;
+use pokemon_battle_core::sim::review::{review, BattleRecord, ReviewParams};
+use pokemon_battle_core::usage::UsageStats;
 use rand::rngs::SmallRng;
-use rand::seq::SliceRandom;
 use rand::SeedableRng;
 use serde::Deserialize;
 use serde_json::json;
 use std::env;
 use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Format of the default CLI battle when neither `--format` nor the team names one.
+const DEFAULT_BATTLE_FORMAT: &str = "gen9bss";
+/// Format of `--random-teams` battles without `--format`.
+const DEFAULT_RANDOM_FORMAT: &str = "gen9randombattle";
+/// Seed for random teams without `--seed`.
+const RANDOM_TEAM_SEED: u64 = 0xBADC0DE;
 
 #[derive(Deserialize)]
 struct TeamsJson {
@@ -36,6 +63,7 @@ struct ShowdownCompatCase {
 
 #[derive(Debug, Deserialize)]
 struct PlayerCase {
+    #[allow(dead_code)]
     name: String,
     team: String,
 }
@@ -48,24 +76,62 @@ struct CaseEvents {
 }
 
 fn main() -> anyhow::Result<()> {
-    let mut args = env::args().skip(1);
-    match args.next().as_deref() {
+    let args = apply_locale_args(env::args().skip(1))?;
+    let (mod_paths, args) = split_mod_args(args.into_iter())?;
+    let (teams, args) = split_team_args(args.into_iter())?;
+    let (policies, args) = split_policy_args(args.into_iter())?;
+    let dex = Arc::new(Dex::with_mods(&mod_paths)?);
+    let mut args = args.into_iter();
+    let command = args.next();
+    teams.check_used(command.as_deref())?;
+    match command.as_deref() {
         Some("check-species") => {
             let name = args
                 .next()
                 .ok_or_else(|| anyhow!("Usage: cargo run -- check-species <species>"))?;
-            check_species(&name)
+            check_species(&dex, &name)
         }
         Some("check-move") => {
             let name = args
                 .next()
                 .ok_or_else(|| anyhow!("Usage: cargo run -- check-move <move>"))?;
-            check_move(&name)
+            check_move(&dex, &name)
+        }
+        Some("list-species") => list_species(&dex),
+        Some("teams") => {
+            let tag = args.next();
+            list_teams(&dex, &teams, tag.as_deref())
+        }
+        Some("dex") => {
+            let name: Vec<String> = args.collect();
+            if name.is_empty() {
+                return Err(anyhow!("Usage: cargo run -- dex <species|move|item|ability>"));
+            }
+            dex_lookup(&dex, &name.join(" "))
+        }
+        Some("search") => {
+            let query: Vec<String> = args.collect();
+            if query.is_empty() {
+                return Err(anyhow!(
+                    "Usage: cargo run -- search <query>  (e.g. search type:fire spe>100 sort:spe)"
+                ));
+            }
+            search(&dex, &query.join(" "))
         }
-        Some("list-species") => list_species(),
         Some("test-parse") => {
-            let path = args.next().unwrap_or_else(|| "teams.json".to_string());
-            test_parse(&path)
+            let mut path: Option<String> = None;
+            let mut options = ParseOptions::default();
+            for arg in args {
+                match arg.as_str() {
+                    "--strict" => options.strict = true,
+                    other if other.starts_with("--") => return Err(anyhow!("Unknown arg '{}' for test-parse", other)),
+                    other => path = Some(other.to_string()),
+                }
+            }
+            if path.is_none() && !teams.names.is_empty() {
+                return test_parse_teams(&dex, &teams, options);
+            }
+            test_parse(&dex, path.as_deref().unwrap_or("teams.json"), options)
         }
         Some("run-case") => {
             let mut case_path: Option<String> = None;
@@ -79,43 +145,421 @@ fn main() -> anyhow::Result<()> {
             }
             let case_path = case_path.ok_or_else(|| anyhow!("Usage: cargo run -- run-case --case <case.json> --log-json <out.json>"))?;
             let out_path = out_path.ok_or_else(|| anyhow!("Usage: cargo run -- run-case --case <case.json> --log-json <out.json>"))?;
-            run_case(&case_path, &out_path)
+            run_case(&dex, &teams, &case_path, &out_path)
+        }
+        Some("convert") => {
+            let usage = "Usage: cargo run -- convert --from <export|packed|json> --to <export|packed|json> [file]";
+            let mut from: Option<String> = None;
+            let mut to: Option<String> = None;
+            let mut path: Option<String> = None;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--from" => from = args.next(),
+                    "--to" => to = args.next(),
+                    other if other.starts_with("--") => return Err(anyhow!("Unknown arg '{}' for convert", other)),
+                    other => path = Some(other.to_string()),
+                }
+            }
+            let to = to.ok_or_else(|| anyhow!(usage))?;
+            if path.is_none() && !teams.names.is_empty() {
+                if from.as_deref().is_some_and(|f| f != "export") {
+                    return Err(anyhow!("--team is read as an export; drop --from or use --from export"));
+                }
+                return convert_team(&dex, &teams, &to);
+            }
+            let from = from.ok_or_else(|| anyhow!(usage))?;
+            convert(&dex, &from, &to, path.as_deref())
+        }
+        Some("gen-data") => {
+            let mut dex_path: Option<String> = None;
+            let mut out_dir: Option<String> = None;
+            let mut check = false;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--dex" => dex_path = args.next(),
+                    "--out" => out_dir = args.next(),
+                    "--check" => check = true,
+                    other => return Err(anyhow!("Unknown arg '{}' for gen-data", other)),
+                }
+            }
+            let dex_path = dex_path.ok_or_else(|| {
+                anyhow!("Usage: cargo run -- gen-data --dex <dex.json> [--out <dir>] [--check]")
+            })?;
+            let out_dir = out_dir.map(PathBuf::from).unwrap_or_else(default_data_dir);
+            gen_data(&dex_path, &out_dir, check)
+        }
+        Some("train-value") => {
+            let usage = "Usage: cargo run -- [--random-sets <sets.json>] [--format <id>] [--seed <n>] train-value --out <weights.json> [--from <weights.json>] [--episodes <n>] [--alpha <x>] [--lambda <x>]";
+            let mut out: Option<String> = None;
+            let mut from: Option<String> = None;
+            let mut params = TdParams::default();
+            while let Some(arg) = args.next() {
+                let value = |value: Option<String>| value.ok_or_else(|| anyhow!(usage));
+                match arg.as_str() {
+                    "--out" => out = Some(value(args.next())?),
+                    "--from" => from = Some(value(args.next())?),
+                    "--episodes" => params.episodes = parse_number(&arg, &value(args.next())?)?,
+                    "--alpha" => params.alpha = parse_number(&arg, &value(args.next())?)?,
+                    "--lambda" => params.lambda = parse_number(&arg, &value(args.next())?)?,
+                    other => return Err(anyhow!("Unknown arg '{}' for train-value", other)),
+                }
+            }
+            let out = out.ok_or_else(|| anyhow!(usage))?;
+            train_value(&dex, &teams, params, from.as_deref(), &out)
+        }
+        Some("usage") => {
+            let usage = "Usage: cargo run -- usage <chaos.json> [<species>] [--top <n>] [--sets <n>]";
+            let mut path: Option<String> = None;
+            let mut species: Option<String> = None;
+            let mut top = 10;
+            let mut sets = 0;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--top" => top = parse_number(&arg, &args.next().ok_or_else(|| anyhow!(usage))?)?,
+                    "--sets" => sets = parse_number(&arg, &args.next().ok_or_else(|| anyhow!(usage))?)?,
+                    other if other.starts_with("--") => return Err(anyhow!("Unknown arg '{}' for usage", other)),
+                    other if path.is_none() => path = Some(other.to_string()),
+                    other => species = Some(species.map_or(other.to_string(), |name| format!("{} {}", name, other))),
+                }
+            }
+            let path = path.ok_or_else(|| anyhow!(usage))?;
+            usage_report(&dex, &path, species.as_deref(), top, sets, teams.seed.unwrap_or(RANDOM_TEAM_SEED))
+        }
+        Some("review") => {
+            let usage = "Usage: cargo run -- review <record.json> [--out <dir>] [--depth <n>] [--threshold <x>]";
+            let mut path: Option<String> = None;
+            let mut out = PathBuf::from("reports");
+            let mut params = ReviewParams::default();
+            while let Some(arg) = args.next() {
+                let value = |value: Option<String>| value.ok_or_else(|| anyhow!(usage));
+                match arg.as_str() {
+                    "--out" => out = PathBuf::from(value(args.next())?),
+                    "--depth" => params.search.depth = parse_number::<usize>(&arg, &value(args.next())?)?.max(1),
+                    "--threshold" => params.threshold = parse_number(&arg, &value(args.next())?)?,
+                    other if other.starts_with("--") => return Err(anyhow!("Unknown arg '{}' for review", other)),
+                    other => path = Some(other.to_string()),
+                }
+            }
+            let path = path.ok_or_else(|| anyhow!(usage))?;
+            review_battle(&dex, &path, params, &out)
         }
         Some(cmd) => Err(anyhow!("Unknown command '{}'", cmd)),
-        None => run_default_battle(),
+        None => run_default_battle(&dex, &teams, &policies),
+    }
+}
+
+/// Pull the global `--mod <file.json>` options (data mods, any number, in order)
+/// out of the command line.
+fn split_mod_args(mut args: impl Iterator<Item = String>) -> anyhow::Result<(Vec<String>, Vec<String>)> {
+    let mut mods = Vec::new();
+    let mut rest = Vec::new();
+    while let Some(arg) = args.next() {
+        if arg == "--mod" {
+            let path = args
+                .next()
+                .ok_or_else(|| anyhow!("Usage: cargo run -- --mod <mod.json> <command> ..."))?;
+            mods.push(path);
+        } else {
+            rest.push(arg);
+        }
     }
+    Ok((mods, rest))
+}
+
+/// AIs picked with the global `--policy <a>[,<b>]` option (one name for both teams,
+/// default `random`), the `--mcts-*` search settings and the `td` policy's weights.
+#[derive(Clone, Debug)]
+struct PolicyArgs {
+    names: [String; 2],
+    mcts: MctsParams,
+    /// `--value-weights <file>`: trained weights for `td` (default: untrained).
+    value_weights: Option<PathBuf>,
+    /// `--debug-ai`: scripted AIs print the rule behind each decision.
+    debug: bool,
+}
+
+impl PolicyArgs {
+    fn ai(&self, team: usize) -> anyhow::Result<Box<dyn BattleAI>> {
+        if let Some(path) = self.names[team].strip_prefix("script:") {
+            return Ok(Box::new(ScriptedAI::load(path, team as u64)?.with_debug(self.debug)));
+        }
+        if let Some(path) = &self.value_weights {
+            if self.names[team].eq_ignore_ascii_case("td") {
+                return Ok(Box::new(ValueAI::new(ValueFunction::load(path)?, team as u64)));
+            }
+        }
+        policy_with(&self.names[team], team as u64, &self.mcts)
+    }
+}
+
+/// Pull the global `--policy`, `--mcts-iters <n>`, `--mcts-ms <ms>`,
+/// `--mcts-rollout <policy>`, `--mcts-threads <n>`, `--value-weights <file>` and `--debug-ai`
+/// options.
+fn split_policy_args(mut args: impl Iterator<Item = String>) -> anyhow::Result<(PolicyArgs, Vec<String>)> {
+    let mut policies = PolicyArgs {
+        names: ["random".to_string(), "random".to_string()],
+        mcts: MctsParams::default(),
+        value_weights: None,
+        debug: false,
+    };
+    let mut rest = Vec::new();
+    let number = |option: &str, value: Option<String>| -> anyhow::Result<usize> {
+        let value = value.ok_or_else(|| anyhow!("Usage: cargo run -- {} <number> <command> ...", option))?;
+        value.parse().map_err(|_| anyhow!("{} expects a number, got '{}'", option, value))
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--policy" => {
+                let value = args.next().ok_or_else(|| {
+                    anyhow!("Usage: cargo run -- --policy <{}>[,<policy B>] <command> ...", POLICIES.join("|"))
+                })?;
+                let (a, b) = value.split_once(',').unwrap_or((&value, &value));
+                policies.names = [a.trim().to_string(), b.trim().to_string()];
+            }
+            "--mcts-iters" => policies.mcts.iterations = Some(number(&arg, args.next())?),
+            "--mcts-ms" => {
+                policies.mcts.time_budget = Some(Duration::from_millis(number(&arg, args.next())? as u64))
+            }
+            "--mcts-threads" => policies.mcts.threads = number(&arg, args.next())?.max(1),
+            "--mcts-rollout" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow!("Usage: cargo run -- --mcts-rollout <random|greedy|standard|expert> <command> ..."))?;
+                policies.mcts.rollout =
+                    RolloutPolicy::from_name(&value).ok_or_else(|| anyhow!("unknown rollout policy '{}'", value))?;
+            }
+            "--debug-ai" => policies.debug = true,
+            "--value-weights" => {
+                let path = args
+                    .next()
+                    .ok_or_else(|| anyhow!("Usage: cargo run -- --value-weights <weights.json> <command> ..."))?;
+                policies.value_weights = Some(PathBuf::from(path));
+            }
+            _ => rest.push(arg),
+        }
+    }
+    Ok((policies, rest))
+}
+
+/// Teams picked with the global `--team <name|file>` options, looked up in `--teams-dir`,
+/// the `--format <id>` they are played in, and the random team options.
+#[derive(Clone, Debug, Default)]
+struct TeamArgs {
+    names: Vec<String>,
+    dir: Option<PathBuf>,
+    format: Option<String>,
+    /// `--random-teams`: generate both teams instead of reading them.
+    random: bool,
+    /// `--random-sets <file>`: the set pool for random teams (default: the bundled pool).
+    random_sets: Option<PathBuf>,
+    seed: Option<u64>,
+}
+
+impl TeamArgs {
+    /// The team directory: `--teams-dir`, else `teams/` here or at the workspace root.
+    fn library(&self, dex: &Dex) -> anyhow::Result<TeamLibrary> {
+        let dir = match &self.dir {
+            Some(dir) => dir.clone(),
+            None => {
+                let local = PathBuf::from("teams");
+                if local.is_dir() {
+                    local
+                } else {
+                    Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("teams")
+                }
+            }
+        };
+        if self.dir.is_none() && !dir.is_dir() {
+            return Ok(TeamLibrary::new());
+        }
+        TeamLibrary::load_dir(dex, &dir, ParseOptions::default())
+    }
+
+    /// The options given on the command line.
+    fn given(&self) -> Vec<&'static str> {
+        [
+            ("--team", !self.names.is_empty()),
+            ("--teams-dir", self.dir.is_some()),
+            ("--format", self.format.is_some()),
+            ("--random-teams", self.random),
+            ("--random-sets", self.random_sets.is_some()),
+            ("--seed", self.seed.is_some()),
+        ]
+        .into_iter()
+        .filter_map(|(option, given)| given.then_some(option))
+        .collect()
+    }
+
+    /// Fail on options `command` would ignore.
+    fn check_used(&self, command: Option<&str>) -> anyhow::Result<()> {
+        let used: &[&str] = match command {
+            Some("teams") => &["--teams-dir"],
+            Some("test-parse") => &["--team", "--teams-dir", "--format"],
+            Some("convert") => &["--team", "--teams-dir"],
+            Some("run-case") => &["--random-teams", "--random-sets", "--seed"],
+            Some("train-value") => &["--format", "--random-teams", "--random-sets", "--seed"],
+            Some("usage") => &["--seed"],
+            Some("check-species" | "check-move" | "list-species" | "dex" | "search" | "gen-data" | "review") => &[],
+            _ => return Ok(()),
+        };
+        match self.given().into_iter().find(|option| !used.contains(option)) {
+            Some(option) => Err(anyhow!("{} is not used by '{}'", option, command.unwrap_or_default())),
+            None => Ok(()),
+        }
+    }
+
+    /// `--format`, else the format named by `team`, else `fallback`.
+    fn format(&self, team: Option<&Team>, fallback: &str) -> anyhow::Result<&'static Format> {
+        let id = self
+            .format
+            .as_deref()
+            .or_else(|| team.and_then(|t| t.meta.format.as_deref()))
+            .unwrap_or(fallback);
+        require_format(id)
+    }
+
+    /// Whether teams are generated: `--random-teams`/`--random-sets`, or a random format.
+    fn random_teams(&self, format: &Format) -> bool {
+        self.random || self.random_sets.is_some() || (format.random_teams && self.names.is_empty())
+    }
+
+    /// Two generated teams for `format`, from `--seed` (team B uses the next seed).
+    fn random_pair(&self, dex: &Dex, format: &Format) -> anyhow::Result<(Vec<pokemon_battle_core::sim::Pokemon>, Vec<pokemon_battle_core::sim::Pokemon>)> {
+        self.random_pair_from(dex, format, self.seed.unwrap_or(RANDOM_TEAM_SEED))
+    }
+
+    /// Two generated teams for `format` from `seed` and the next seed.
+    fn random_pair_from(&self, dex: &Dex, format: &Format, seed: u64) -> anyhow::Result<(Vec<pokemon_battle_core::sim::Pokemon>, Vec<pokemon_battle_core::sim::Pokemon>)> {
+        let loaded;
+        let pool = match &self.random_sets {
+            Some(path) => {
+                loaded = RandomSetPool::load(path)?;
+                &loaded
+            }
+            None => RandomSetPool::builtin(),
+        };
+        let team_a = pool.generate(dex, format, seed).context("team A")?;
+        let team_b = pool.generate(dex, format, seed.wrapping_add(1)).context("team B")?;
+        Ok((team_a, team_b))
+    }
+
+    fn resolve(&self, dex: &Dex) -> anyhow::Result<Vec<Team>> {
+        let library = self.library(dex)?;
+        self.names
+            .iter()
+            .map(|name| library.resolve(dex, name, ParseOptions::default()))
+            .collect()
+    }
+}
+
+/// Pull the global `--team <name|file>` (repeatable), `--teams-dir <dir>`, `--format <id>`,
+/// `--random-teams`, `--random-sets <file>` and `--seed <n>` options.
+fn split_team_args(mut args: impl Iterator<Item = String>) -> anyhow::Result<(TeamArgs, Vec<String>)> {
+    let mut teams = TeamArgs::default();
+    let mut rest = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--team" => teams.names.push(
+                args.next()
+                    .ok_or_else(|| anyhow!("Usage: cargo run -- --team <name|file> <command> ..."))?,
+            ),
+            "--teams-dir" => {
+                teams.dir = Some(PathBuf::from(
+                    args.next()
+                        .ok_or_else(|| anyhow!("Usage: cargo run -- --teams-dir <dir> <command> ..."))?,
+                ))
+            }
+            "--format" => {
+                teams.format = Some(
+                    args.next()
+                        .ok_or_else(|| anyhow!("Usage: cargo run -- --format <format id> <command> ..."))?,
+                )
+            }
+            "--random-teams" => teams.random = true,
+            "--random-sets" => {
+                teams.random_sets = Some(PathBuf::from(
+                    args.next()
+                        .ok_or_else(|| anyhow!("Usage: cargo run -- --random-sets <sets.json> <command> ..."))?,
+                ))
+            }
+            "--seed" => {
+                let seed = args.next().ok_or_else(|| anyhow!("Usage: cargo run -- --seed <number> <command> ..."))?;
+                teams.seed = Some(seed.parse().map_err(|_| anyhow!("--seed expects a number, got '{}'", seed))?);
+            }
+            _ => rest.push(arg),
+        }
+    }
+    Ok((teams, rest))
+}
+
+/// Handles the global `--lang <code>` and `--locale-file <path>` options (any order, repeatable).
+fn apply_locale_args(mut args: impl Iterator<Item = String>) -> anyhow::Result<Vec<String>> {
+    let mut rest = Vec::new();
+    let mut lang = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--lang" => {
+                lang = Some(args.next().ok_or_else(|| anyhow!("Usage: cargo run -- --lang <ja|en|...> <command> ..."))?);
+            }
+            "--locale-file" => {
+                let path = args
+                    .next()
+                    .ok_or_else(|| anyhow!("Usage: cargo run -- --locale-file <locale.json> <command> ..."))?;
+                messages::load_locale_file(&path)?;
+            }
+            _ => rest.push(arg),
+        }
+    }
+    if let Some(code) = lang {
+        messages::set_locale(&code)?;
+    }
+    Ok(rest)
+}
+
+/// Parse an export and make it battle-ready under `format`'s rules.
+fn format_team(dex: &Dex, format: &Format, text: &str) -> anyhow::Result<Vec<pokemon_battle_core::sim::Pokemon>> {
+    let parsed = check_showdown_team(dex, text);
+    let failures = parsed.failures(ParseOptions::default());
+    if !failures.is_empty() {
+        return Err(TeamParseError { diagnostics: failures }.into());
+    }
+    format.prepare_team(dex, &parsed.sets)
 }
 
 fn seed_to_u64(seed: [u32; 4]) -> u64 {
     ((seed[0] as u64) << 48) ^ ((seed[1] as u64) << 32) ^ ((seed[2] as u64) << 16) ^ (seed[3] as u64)
 }
 
-fn run_case(case_path: &str, out_path: &str) -> anyhow::Result<()> {
+fn run_case(dex: &Arc<Dex>, teams: &TeamArgs, case_path: &str, out_path: &str) -> anyhow::Result<()> {
     let content = fs::read_to_string(case_path).with_context(|| format!("failed to read {}", case_path))?;
     let case: ShowdownCompatCase =
         serde_json::from_str(&content).map_err(|e| anyhow!("failed to parse case json {}: {}", case_path, e))?;
 
-    let mut p1_team = parse_showdown_team(&case.p1.team)?;
-    let mut p2_team = parse_showdown_team(&case.p2.team)?;
-    if p1_team.is_empty() || p2_team.is_empty() {
-        return Err(anyhow!("each team must contain at least one Pokémon"));
-    }
-    let p1 = p1_team.remove(0);
-    let p2 = p2_team.remove(0);
-
-    let mut state = BattleState::new(p1, p2);
     let formatid = if case.formatid.trim().is_empty() {
-        "gen9customgame".to_string()
+        DEFAULT_FORMAT.to_string()
     } else {
         case.formatid.clone()
     };
-    state.logger = Some(BattleLogger::new_with_format(formatid.clone()));
+    let format = require_format(&formatid)?;
+    let (mut p1_team, mut p2_team) = if teams.random_teams(format) {
+        let seeded = TeamArgs { seed: teams.seed.or(Some(seed_to_u64(case.seed))), ..teams.clone() };
+        seeded.random_pair(dex, format)?
+    } else {
+        (
+            format_team(dex, format, &case.p1.team).context("p1")?,
+            format_team(dex, format, &case.p2.team).context("p2")?,
+        )
+    };
+    p1_team.truncate(1);
+    p2_team.truncate(1);
+
+    let mut state = format.new_battle(dex.clone(), p1_team, p2_team)?;
 
     if let Some(logger) = state.logger.as_mut() {
-        let p1_ident = showdown_ident(0, &state.pokemon_a.species);
-        let p2_ident = showdown_ident(1, &state.pokemon_b.species);
-        logger.log_switch(&p1_ident, &state.pokemon_a.species, state.pokemon_a.current_hp, state.pokemon_a.stats.hp);
-        logger.log_switch(&p2_ident, &state.pokemon_b.species, state.pokemon_b.current_hp, state.pokemon_b.stats.hp);
+        for (side_idx, pokemon) in [(0, &state.pokemon_a), (1, &state.pokemon_b)] {
+            let ident = showdown_ident(side_idx, &pokemon.name);
+            logger.log_switch(&ident, &pokemon.showdown_details(), pokemon.current_hp, pokemon.stats.hp);
+        }
     }
 
     if state.pokemon_a.moves.is_empty() || state.pokemon_b.moves.is_empty() {
@@ -149,43 +593,293 @@ fn run_case(case_path: &str, out_path: &str) -> anyhow::Result<()> {
     Ok(())
 }
 
-fn run_default_battle() -> anyhow::Result<()> {
-    let content =
-        fs::read_to_string("teams.json").context("failed to read teams.json in project root")?;
-    let teams: TeamsJson =
-        serde_json::from_str(&content).map_err(|e| anyhow!("failed to parse teams.json: {}", e))?;
-    let mut rng = SmallRng::seed_from_u64(0xBADC0DE);
-    let team_a = parse_showdown_team(&teams.team_a)?;
-    let team_b = parse_showdown_team(&teams.team_b)?;
-    if team_a.is_empty() || team_b.is_empty() {
-        return Err(anyhow!("each team must contain at least one Pokémon"));
-    }
-    let selected_a = select_three(team_a, &mut rng);
-    let selected_b = select_three(team_b, &mut rng);
+/// Print the `--team` teams in `to` (export, packed or JSON).
+fn convert_team(dex: &Dex, teams: &TeamArgs, to: &str) -> anyhow::Result<()> {
+    for team in teams.resolve(dex)? {
+        match to {
+            "export" => print!("{}", export_team(&team.sets)),
+            "packed" => println!("{}", pack_team(&team.sets)),
+            "json" => println!("{}", serde_json::to_string_pretty(&team_to_json(&team.sets))?),
+            other => return Err(anyhow!("Unknown team format '{}' (expected export, packed or json)", other)),
+        }
+    }
+    Ok(())
+}
+
+/// Convert a team between Showdown's text export, packed and JSON formats.
+/// Reads `path`, or stdin when no file is given.
+fn convert(dex: &Dex, from: &str, to: &str, path: Option<&str>) -> anyhow::Result<()> {
+    let input = match path {
+        Some(path) => fs::read_to_string(path).with_context(|| format!("failed to read {}", path))?,
+        None => {
+            let mut input = String::new();
+            std::io::stdin().read_to_string(&mut input).context("failed to read stdin")?;
+            input
+        }
+    };
+    let sets: Vec<PokemonSet> = match from {
+        "export" => parse_showdown_sets(&input)?,
+        "packed" => parse_packed_sets_in(dex, &input)?,
+        "json" => parse_json_sets(&input)?,
+        other => return Err(anyhow!("Unknown team format '{}' (expected export, packed or json)", other)),
+    };
+    match to {
+        "export" => print!("{}", export_team(&sets)),
+        "packed" => println!("{}", pack_team(&sets)),
+        "json" => println!("{}", serde_json::to_string_pretty(&team_to_json(&sets))?),
+        other => return Err(anyhow!("Unknown team format '{}' (expected export, packed or json)", other)),
+    }
+    Ok(())
+}
+
+fn default_data_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("pokemon-battle-core")
+        .join("src")
+        .join("data")
+}
+
+fn gen_data(dex_path: &str, out_dir: &Path, check: bool) -> anyhow::Result<()> {
+    let content = fs::read_to_string(dex_path).with_context(|| format!("failed to read {}", dex_path))?;
+    let dump: serde_json::Value =
+        serde_json::from_str(&content).map_err(|e| anyhow!("failed to parse dex dump {}: {}", dex_path, e))?;
+    let files = render_all(&dump).map_err(|e| anyhow!("{}: {}", dex_path, e))?;
+    let mut stale = Vec::new();
+    for file in &files {
+        let path = out_dir.join(file.file_name());
+        let current = fs::read_to_string(&path).unwrap_or_default();
+        if current == file.contents {
+            continue;
+        }
+        stale.push(file.file_name());
+        if !check {
+            fs::write(&path, &file.contents).with_context(|| format!("failed to write {}", path.display()))?;
+            println!("wrote {}", path.display());
+        }
+    }
+    if check && !stale.is_empty() {
+        return Err(anyhow!("stale tables in {}: {}", out_dir.display(), stale.join(", ")));
+    }
+    if stale.is_empty() {
+        println!("{} tables in {} are up to date", files.len(), out_dir.display());
+    }
+    Ok(())
+}
+
+fn run_default_battle(dex: &Arc<Dex>, teams: &TeamArgs, policies: &PolicyArgs) -> anyhow::Result<()> {
+    if teams.random || teams.random_sets.is_some() {
+        let format = teams.format(None, DEFAULT_RANDOM_FORMAT)?;
+        return run_random_battle(dex, teams, format, policies);
+    }
+    if teams.names.is_empty() {
+        if let Some(format) = teams.format.as_deref().map(require_format).transpose()? {
+            if format.random_teams {
+                return run_random_battle(dex, teams, format, policies);
+            }
+        }
+    }
+    let (team_a, team_b) = match teams.names.len() {
+        0 => {
+            let file = load_teams(dex, "teams.json").context("failed to load teams.json in project root")?;
+            (file.team_a, file.team_b)
+        }
+        2 => {
+            let mut picked = teams.resolve(dex)?.into_iter();
+            let team_a = picked.next().expect("two teams");
+            let team_b = picked.next().expect("two teams");
+            println!("{} vs {}", team_a.meta.name, team_b.meta.name);
+            (team_a, team_b)
+        }
+        _ => return Err(anyhow!("Usage: cargo run -- [--team <a> --team <b>]")),
+    };
+    let format = teams.format(Some(&team_a), DEFAULT_BATTLE_FORMAT)?;
+    let team_a = format.prepare_team(dex, &team_a.sets).context("team A")?;
+    let team_b = format.prepare_team(dex, &team_b.sets).context("team B")?;
+    run_prepared_battle(dex, format, team_a, team_b, policies)
+}
+
+fn parse_number<T: std::str::FromStr>(option: &str, value: &str) -> anyhow::Result<T> {
+    value.parse().map_err(|_| anyhow!("{} expects a number, got '{}'", option, value))
+}
+
+/// Train `td` weights by self-play on generated teams (a new pair of seeds per battle) and
+/// save them to `out`.
+fn train_value(dex: &Dex, teams: &TeamArgs, mut params: TdParams, from: Option<&str>, out: &str) -> anyhow::Result<()> {
+    let format = teams.format(None, DEFAULT_RANDOM_FORMAT)?;
+    let seed = teams.seed.unwrap_or(RANDOM_TEAM_SEED);
+    params.seed = seed;
+    let pairs = (0..params.episodes)
+        .map(|episode| teams.random_pair_from(dex, format, seed.wrapping_add(2 * episode as u64)))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let mut value = match from {
+        Some(path) => ValueFunction::load(path)?,
+        None => ValueFunction::default(),
+    };
+    let report = value.train(&params, |episode| pairs[episode].clone());
+    println!(
+        "自己対戦 {} 戦: チームA {} 勝 / チームB {} 勝 / 引き分け {} / 打ち切り {}（平均 {:.1} ターン）",
+        report.episodes,
+        report.wins[0],
+        report.wins[1],
+        report.draws,
+        report.unfinished,
+        report.turns as f64 / report.episodes.max(1) as f64
+    );
+    value.save(out)?;
+    println!("重みを {} に保存しました", out);
+    Ok(())
+}
+
+/// Print a chaos usage file's top species, or one species' moves, items, abilities,
+/// spreads, Tera Types, teammates and checks (and `sets` generated sets).
+fn usage_report(dex: &Dex, path: &str, species: Option<&str>, top: usize, sets: usize, seed: u64) -> anyhow::Result<()> {
+    let stats = UsageStats::load(path)?;
+    println!("{}（レート {} 以上、{} 戦）", stats.info.metagame, stats.info.cutoff, stats.info.battles);
+    let Some(name) = species else {
+        for (rank, (name, share)) in stats.top_species(top).into_iter().enumerate() {
+            println!("{:>3}. {} {:.2}%", rank + 1, with_japanese(name, translate_pokemon(name)), share * 100.0);
+        }
+        return Ok(());
+    };
+    let name = from_japanese(dex, name, pokemon_from_japanese);
+    let usage = stats.get(name).ok_or_else(|| anyhow!("No usage stats for '{}' in {}", name, path))?;
+    let percent = |share: f64| format!("{:.1}%", share * 100.0);
+    let list = |entries: Vec<(String, String)>| {
+        entries.into_iter().map(|(name, share)| format!("{} {}", name, share)).collect::<Vec<_>>().join(", ")
+    };
+    println!("[使用率] {} {}", with_japanese(&usage.name, translate_pokemon(&usage.name)), percent(usage.usage));
+    let moves = usage.top_moves(top).into_iter().map(|(id, share)| {
+        let name = dex.get_move(&id).map_or(id, |data| with_japanese(data.name, translate_move(data.name)));
+        (name, percent(share))
+    });
+    println!("  技: {}", list(moves.collect()));
+    let items = usage.top_items(top).into_iter().map(|(id, share)| {
+        let name = match dex.item(&id) {
+            Some(data) => with_japanese(data.name, translate_item(data.name)),
+            None if id == "nothing" => "なし".to_string(),
+            None => id,
+        };
+        (name, percent(share))
+    });
+    println!("  持ち物: {}", list(items.collect()));
+    let abilities = usage.top_abilities(top).into_iter().map(|(id, share)| {
+        let name = dex.ability(&id).map_or(id, |data| with_japanese(data.name, translate_ability(data.name)));
+        (name, percent(share))
+    });
+    println!("  特性: {}", list(abilities.collect()));
+    let spreads = usage.top_spreads(top).into_iter().map(|(spread, share)| {
+        let evs: Vec<String> = spread.evs.iter().map(u8::to_string).collect();
+        (format!("{} {}", translate_nature(spread.nature.name()), evs.join("/")), percent(share))
+    });
+    println!("  性格・努力値: {}", list(spreads.collect()));
+    if !usage.tera_types.is_empty() {
+        let tera = usage.top_tera_types(top).into_iter().map(|(id, share)| (translate_type(&id), percent(share)));
+        println!("  テラスタイプ: {}", list(tera.collect()));
+    }
+    let teammates = usage
+        .top_teammates(top)
+        .into_iter()
+        .map(|(name, share)| (with_japanese(&name, translate_pokemon(&name)), percent(share)));
+    println!("  味方: {}", list(teammates.collect()));
+    let checks = usage.top_checks(top).into_iter().map(|check| {
+        let name = with_japanese(&check.species, translate_pokemon(&check.species));
+        (name, format!("{:.1} (±{:.1})", check.score * 100.0, check.deviation * 100.0))
+    });
+    println!("  対策: {}", list(checks.collect()));
+    if sets > 0 {
+        for (set, weight) in stats.plausible_sets(dex, &usage.name, sets, seed)?.into_iter().take(top) {
+            println!("\n# {}", percent(weight));
+            print!("{}", export_team(std::slice::from_ref(&set)));
+        }
+    }
+    Ok(())
+}
+
+/// Search every turn of a recorded battle and write `<out>/<name>_review.json` and
+/// `<out>/<name>_review.html`.
+fn review_battle(dex: &Dex, path: &str, params: ReviewParams, out: &Path) -> anyhow::Result<()> {
+    let record = BattleRecord::load(dex, path)?;
+    let review = review(&record, params, &HpEvaluator)?;
+    let name = Path::new(path).file_stem().and_then(|stem| stem.to_str()).unwrap_or("battle");
+    fs::create_dir_all(out).with_context(|| format!("failed to create {}", out.display()))?;
+    let json_path = out.join(format!("{}_review.json", name));
+    let html_path = out.join(format!("{}_review.html", name));
+    fs::write(&json_path, serde_json::to_string_pretty(&review.to_json())?)
+        .with_context(|| format!("failed to write {}", json_path.display()))?;
+    fs::write(&html_path, review.to_html()).with_context(|| format!("failed to write {}", html_path.display()))?;
+    for (turn, player, decision) in review.blunders() {
+        println!(
+            "ターン {} {:?}: {}（最善 {}、損失 {:.3}）",
+            turn, player, decision.chosen, decision.best, decision.loss
+        );
+    }
+    let [loss_a, loss_b] = review.total_loss();
+    println!(
+        "{} ターン中 悪手 {} 件（損失合計 A {:.3} / B {:.3}）",
+        review.turns.len(),
+        review.blunders().count(),
+        loss_a,
+        loss_b
+    );
+    println!("レポート: {} / {}", json_path.display(), html_path.display());
+    Ok(())
+}
+
+/// A battle between two generated teams.
+fn run_random_battle(
+    dex: &Arc<Dex>,
+    teams: &TeamArgs,
+    format: &'static Format,
+    policies: &PolicyArgs,
+) -> anyhow::Result<()> {
+    let (team_a, team_b) = teams.random_pair(dex, format)?;
+    println!("ランダムチーム (seed {})", teams.seed.unwrap_or(RANDOM_TEAM_SEED));
+    run_prepared_battle(dex, format, team_a, team_b, policies)
+}
+
+fn run_prepared_battle(
+    dex: &Arc<Dex>,
+    format: &Format,
+    team_a: Vec<pokemon_battle_core::sim::Pokemon>,
+    team_b: Vec<pokemon_battle_core::sim::Pokemon>,
+    policies: &PolicyArgs,
+) -> anyhow::Result<()> {
+    let mut ai_a = policies.ai(0).context("team A")?;
+    let mut ai_b = policies.ai(1).context("team B")?;
+    let (selected_a, selected_b) = if format.team_preview {
+        let bring = format.bring_count(team_a.len().max(team_b.len()));
+        (
+            choose_at_preview(ai_a.as_mut(), &team_a, &team_b, bring),
+            choose_at_preview(ai_b.as_mut(), &team_b, &team_a, bring),
+        )
+    } else {
+        (team_a, team_b)
+    };
     println!("選抜されたチームAのポケモン:");
     for p in &selected_a {
-        println!("  {}", translate_pokemon(&p.species));
+        println!("  {}", p.display_name(dex));
     }
     println!("選抜されたチームBのポケモン:");
     for p in &selected_b {
-        println!("  {}", translate_pokemon(&p.species));
+        println!("  {}", p.display_name(dex));
     }
-    println!("\n=== ポケモンバトル (3vs3) ===");
-    let winner = run_team_battle(selected_a, selected_b)?;
+    println!("\n=== ポケモンバトル {} ({}vs{}) ===", format.name, selected_a.len(), selected_b.len());
+    let winner = run_team_battle(dex, format, selected_a, selected_b, ai_a.as_mut(), ai_b.as_mut())?;
     println!("\n勝者: {}", winner);
     Ok(())
 }
 
 fn run_team_battle(
+    dex: &Arc<Dex>,
+    format: &Format,
     team_a: Vec<pokemon_battle_core::sim::Pokemon>,
     team_b: Vec<pokemon_battle_core::sim::Pokemon>,
+    ai_a: &mut dyn BattleAI,
+    ai_b: &mut dyn BattleAI,
 ) -> anyhow::Result<&'static str> {
-    if team_a.is_empty() || team_b.is_empty() {
-        return Err(anyhow!("Teams must have at least one Pokémon"));
-    }
-    let mut ai_a = RandomAI::new(0);
-    let mut ai_b = RandomAI::new(1);
-    let result = sim_run_team_battle(team_a, team_b, &mut ai_a, &mut ai_b);
+    let mut state = format.new_battle(dex.clone(), team_a, team_b)?;
+    let result = run_battle_with_state(&mut state, ai_a, ai_b);
     let winner = match result {
         BattleResult::TeamAWins => "チームA",
         BattleResult::TeamBWins => "チームB",
@@ -194,19 +888,26 @@ fn run_team_battle(
     Ok(winner)
 }
 
-fn select_three(
-    mut team: Vec<pokemon_battle_core::sim::Pokemon>,
-    rng: &mut SmallRng,
+/// Team preview: `ai` sees the opponent's species and picks a lead plus `bring` Pokémon.
+fn choose_at_preview(
+    ai: &mut dyn BattleAI,
+    team: &[pokemon_battle_core::sim::Pokemon],
+    opponent: &[pokemon_battle_core::sim::Pokemon],
+    bring: usize,
 ) -> Vec<pokemon_battle_core::sim::Pokemon> {
-    let count = team.len().min(3);
-    team.shuffle(rng);
-    team.into_iter().take(count).collect()
+    let preview = TeamPreview::new(team, opponent, bring);
+    let choice = ai.choose_team(&preview, &preview.legal_choices());
+    choice.select(team)
+}
+
+/// `name` as typed, or its English name when it is a known Japanese name.
+fn from_japanese<'a>(dex: &Dex, name: &'a str, lookup: fn(&Dex, &str) -> Option<&'static str>) -> &'a str {
+    lookup(dex, name).unwrap_or(name)
 }
 
-fn check_species(name: &str) -> anyhow::Result<()> {
-    let id = normalize_species_id(name);
-    let data = POKEDEX
-        .get(id.as_str())
+fn check_species(dex: &Dex, name: &str) -> anyhow::Result<()> {
+    let data = dex
+        .species(from_japanese(dex, name, pokemon_from_japanese))
         .ok_or_else(|| anyhow!("Species '{}' not found in POKEDEX", name))?;
     println!(
         "Found species: {} (#{}) Types: {}{}{}",
@@ -228,9 +929,10 @@ fn check_species(name: &str) -> anyhow::Result<()> {
     Ok(())
 }
 
-fn check_move(name: &str) -> anyhow::Result<()> {
-    let normalized = normalize_move_name(name);
-    let data = get_move(normalized.as_str()).ok_or_else(|| anyhow!("Move '{}' not found", name))?;
+fn check_move(dex: &Dex, name: &str) -> anyhow::Result<()> {
+    let data = dex
+        .get_move(from_japanese(dex, name, move_from_japanese))
+        .ok_or_else(|| anyhow!("Move '{}' not found", name))?;
     println!(
         "Found move: {} (type: {}, category: {:?}, power: {:?}, priority: {})",
         data.name, data.move_type, data.category, data.base_power, data.priority
@@ -238,8 +940,8 @@ fn check_move(name: &str) -> anyhow::Result<()> {
     Ok(())
 }
 
-fn list_species() -> anyhow::Result<()> {
-    let mut entries: Vec<_> = POKEDEX.entries().collect();
+fn list_species(dex: &Dex) -> anyhow::Result<()> {
+    let mut entries: Vec<_> = dex.all_species().collect();
     entries.sort_by_key(|(id, _)| *id);
     for (id, data) in entries {
         println!("{} ({})", data.name, id);
@@ -247,24 +949,205 @@ fn list_species() -> anyhow::Result<()> {
     Ok(())
 }
 
-fn test_parse(path: &str) -> anyhow::Result<()> {
+/// English name with the Japanese name alongside when one is known.
+fn with_japanese(name: &str, japanese: String) -> String {
+    if japanese == name {
+        name.to_string()
+    } else {
+        format!("{} ({})", japanese, name)
+    }
+}
+
+fn category_label(category: MoveCategory) -> &'static str {
+    match category {
+        MoveCategory::Physical => "物理",
+        MoveCategory::Special => "特殊",
+        MoveCategory::Status => "変化",
+    }
+}
+
+/// Japanese description when translated, otherwise Showdown's English text.
+fn description_text(japanese: Option<String>, english: &str) -> String {
+    japanese
+        .or_else(|| (!english.is_empty()).then(|| english.to_string()))
+        .unwrap_or_else(|| "(説明なし)".to_string())
+}
+
+/// Show every species / move / item / ability called `name`.
+fn dex_lookup(dex: &Dex, name: &str) -> anyhow::Result<()> {
+    let mut found = 0;
+    if let Some(data) = dex.species(from_japanese(dex, name, pokemon_from_japanese)) {
+        found += 1;
+        let types: Vec<String> = data
+            .types
+            .iter()
+            .filter(|t| !t.is_empty())
+            .map(|t| translate_type(t))
+            .collect();
+        let s = data.base_stats;
+        let abilities: Vec<String> = [data.abilities.primary, data.abilities.secondary]
+            .into_iter()
+            .flatten()
+            .map(|a| with_japanese(a, translate_ability(a)))
+            .collect();
+        println!("[ポケモン] {} No.{}", with_japanese(data.name, translate_pokemon(data.name)), data.num);
+        println!("  タイプ: {}", types.join("/"));
+        println!(
+            "  種族値: H{} A{} B{} C{} D{} S{} (合計 {})",
+            s.hp,
+            s.atk,
+            s.def,
+            s.spa,
+            s.spd,
+            s.spe,
+            base_stat_total(data)
+        );
+        println!("  特性: {}", abilities.join(" / "));
+        if let Some(hidden) = data.abilities.hidden {
+            println!("  隠れ特性: {}", with_japanese(hidden, translate_ability(hidden)));
+        }
+        println!("  重さ: {}kg", data.weight_kg);
+    }
+    if let Some(data) = dex.get_move(from_japanese(dex, name, move_from_japanese)) {
+        found += 1;
+        let or_dash = |value: Option<String>| value.unwrap_or_else(|| "-".to_string());
+        println!("[技] {}", with_japanese(data.name, translate_move(data.name)));
+        println!(
+            "  {} {}  威力 {}  命中 {}  PP {}  優先度 {}",
+            translate_type(data.move_type),
+            category_label(data.category),
+            or_dash(data.base_power.filter(|bp| *bp > 0).map(|bp| bp.to_string())),
+            or_dash(data.accuracy.map(|acc| acc.to_string())),
+            or_dash(data.pp.map(|pp| pp.to_string())),
+            data.priority
+        );
+        println!("  {}", description_text(describe_move(data.name), data.description));
+    }
+    if let Some(data) = dex.item(from_japanese(dex, name, item_from_japanese)) {
+        found += 1;
+        println!("[道具] {}", with_japanese(data.name, translate_item(data.name)));
+        println!("  {}", description_text(describe_item(data.name), data.description));
+    }
+    if let Some(data) = dex.ability(from_japanese(dex, name, ability_from_japanese)) {
+        found += 1;
+        println!("[特性] {}", with_japanese(data.name, translate_ability(data.name)));
+        println!("  {}", description_text(describe_ability(data.name), data.description));
+    }
+    if found == 0 {
+        return Err(anyhow!("'{}' is not a species, move, item or ability", name));
+    }
+    Ok(())
+}
+
+fn search(dex: &Dex, query: &str) -> anyhow::Result<()> {
+    let query = Query::parse(query)?;
+    let hits = query.run(dex)?;
+    for hit in &hits {
+        match hit {
+            SearchHit::Species { data, .. } => {
+                let types: Vec<String> = data
+                    .types
+                    .iter()
+                    .filter(|t| !t.is_empty())
+                    .map(|t| translate_type(t))
+                    .collect();
+                let s = data.base_stats;
+                println!(
+                    "{}  {}  {}/{}/{}/{}/{}/{} (BST {})",
+                    with_japanese(data.name, translate_pokemon(data.name)),
+                    types.join("/"),
+                    s.hp,
+                    s.atk,
+                    s.def,
+                    s.spa,
+                    s.spd,
+                    s.spe,
+                    base_stat_total(data)
+                );
+            }
+            SearchHit::Move { data, .. } => {
+                let category = category_label(data.category);
+                let or_dash = |value: Option<String>| value.unwrap_or_else(|| "-".to_string());
+                println!(
+                    "{}  {} {}  威力 {}  命中 {}  PP {}  優先度 {}",
+                    with_japanese(data.name, translate_move(data.name)),
+                    translate_type(data.move_type),
+                    category,
+                    or_dash(data.base_power.filter(|bp| *bp > 0).map(|bp| bp.to_string())),
+                    or_dash(data.accuracy.map(|acc| acc.to_string())),
+                    or_dash(data.pp.map(|pp| pp.to_string())),
+                    data.priority
+                );
+            }
+            SearchHit::Ability { data, .. } => {
+                println!("{}", with_japanese(data.name, translate_ability(data.name)));
+            }
+        }
+    }
+    println!("{} {} found", hits.len(), query.kind);
+    Ok(())
+}
+
+/// Parse and check the `--team` teams against their formats, printing every diagnostic.
+fn test_parse_teams(dex: &Dex, teams: &TeamArgs, options: ParseOptions) -> anyhow::Result<()> {
+    let library = teams.library(dex)?;
+    for name in &teams.names {
+        let team = library.resolve(dex, name, options)?;
+        for warning in &team.warnings {
+            println!("{}: {}", team.meta.name, warning);
+        }
+        let format = teams.format(Some(&team), DEFAULT_FORMAT)?;
+        format.check(dex, &team.sets).with_context(|| team.meta.name.clone())?;
+        println!("Parsed {} Pokémon from {} ({})", team.sets.len(), team.meta.name, format.id);
+    }
+    Ok(())
+}
+
+fn list_teams(dex: &Dex, teams: &TeamArgs, tag: Option<&str>) -> anyhow::Result<()> {
+    let library = teams.library(dex)?;
+    let listed: Vec<&Team> = match tag {
+        Some(tag) => library.with_tag(tag).collect(),
+        None => library.iter().collect(),
+    };
+    if listed.is_empty() {
+        println!("(チームなし)");
+    }
+    for team in listed {
+        let mut details = Vec::new();
+        if let Some(format) = &team.meta.format {
+            details.push(format.clone());
+        }
+        if let Some(author) = &team.meta.author {
+            details.push(format!("by {}", author));
+        }
+        if !team.meta.tags.is_empty() {
+            details.push(format!("#{}", team.meta.tags.join(" #")));
+        }
+        println!("{} [{}]", team.meta.name, details.join(", "));
+        let members: Vec<String> = team.sets.iter().map(|set| translate_pokemon(&set.species)).collect();
+        println!("  {}", members.join(" / "));
+    }
+    Ok(())
+}
+
+/// Parse both teams, printing every diagnostic; fails on errors (or any warning with `--strict`).
+fn test_parse(dex: &Dex, path: &str, options: ParseOptions) -> anyhow::Result<()> {
     let content = fs::read_to_string(path).with_context(|| format!("failed to read {}", path))?;
     let teams: TeamsJson =
         serde_json::from_str(&content).map_err(|e| anyhow!("failed to parse {}: {}", path, e))?;
-    let team_a = parse_showdown_team(&teams.team_a)?;
-    let team_b = parse_showdown_team(&teams.team_b)?;
-    println!(
-        "Parsed {} Pokémon from team_a and {} from team_b in {}",
-        team_a.len(),
-        team_b.len(),
-        path
-    );
+    let mut counts = Vec::new();
+    let mut failures = Vec::new();
+    for (label, text) in [("team_a", &teams.team_a), ("team_b", &teams.team_b)] {
+        let parsed = check_showdown_team(dex, text);
+        for diagnostic in &parsed.diagnostics {
+            println!("{}: {}", label, diagnostic);
+        }
+        failures.extend(parsed.failures(options));
+        counts.push(parsed.sets.len());
+    }
+    if !failures.is_empty() {
+        return Err(TeamParseError { diagnostics: failures }.into());
+    }
+    println!("Parsed {} Pokémon from team_a and {} from team_b in {}", counts[0], counts[1], path);
     Ok(())
 }
-
-fn normalize_species_id(name: &str) -> String {
-    name.to_ascii_lowercase()
-        .chars()
-        .filter(|c| c.is_ascii_alphanumeric())
-        .collect()
-}
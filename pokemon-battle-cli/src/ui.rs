@@ -1,75 +1,65 @@
 use anyhow::Result;
-use pokemon_battle_core::battle::{
-    BattleResult, BattleView, BattlerView, MoveEventView, MoveOutcome, MoveView, PlayerAction, Side,
-    SideView, StatStagesView, StatusEventView, TeamMemberView,
-};
-use pokemon_battle_core::model::{Pokemon, StatusCondition};
-use pokemon_battle_core::types::type_effectiveness;
-use std::collections::HashSet;
+use pokemon_battle_core::data::moves::MoveCategory;
+use pokemon_battle_core::dex::Dex;
+use pokemon_battle_core::i18n::{translate_move, translate_pokemon, translate_type};
+use pokemon_battle_core::sim::battle::SideConditions;
+use pokemon_battle_core::sim::pokemon::{parse_type, Pokemon, Status};
+use pokemon_battle_core::sim::{Action, BattleResult, BattleState, TeamChoice, TeamPreview};
 use std::io::{self, Write};
-use std::thread;
-use std::time::Duration;
 
-pub fn render(
-    view: &BattleView,
-    human_side: Side,
-    human_candidates: &[Pokemon],
-    opponent_candidates: &[Pokemon],
-) {
-    clear_screen();
-    let (you, opp) = if matches!(human_side, Side::A) {
-        (&view.side_a, &view.side_b)
+/// `side`'s active Pokémon and bench (0 = team A).
+fn side_of(state: &BattleState, side: usize) -> (&Pokemon, &[Pokemon]) {
+    if side == 0 {
+        (&state.pokemon_a, &state.bench_a)
     } else {
-        (&view.side_b, &view.side_a)
-    };
+        (&state.pokemon_b, &state.bench_b)
+    }
+}
+
+fn conditions_of(state: &BattleState, side: usize) -> &SideConditions {
+    if side == 0 {
+        &state.side_a
+    } else {
+        &state.side_b
+    }
+}
 
-    if let Some(weather) = &view.weather {
-        println!("天候: {:?} (残りターン: {})", weather, view.weather_turns);
+pub fn render(state: &BattleState, human_side: usize, opponent_candidates: &[String]) {
+    let (you, your_bench) = side_of(state, human_side);
+    let (opp, _) = side_of(state, 1 - human_side);
+
+    println!();
+    if let Some(weather) = &state.weather {
+        println!("天候: {:?} (残りターン: {})", weather, state.weather_turns);
         println!();
     }
-    print_party_section(you, opp, human_candidates, opponent_candidates);
+    print_party_section(&state.dex, you, your_bench, opp, opponent_candidates);
     println!();
     println!("=== 場 ===");
-    print_field_section(you, opp);
+    print_field_section(&state.dex, you, opp);
+    println!(
+        "あなたの場: {} | 相手の場: {}",
+        describe_side(conditions_of(state, human_side)),
+        describe_side(conditions_of(state, 1 - human_side))
+    );
+    println!("{}", format_stages(you));
 }
 
-pub fn prompt_action(view: &BattleView, side: Side, force_switch: bool) -> Result<PlayerAction> {
-    let side_view = match side {
-        Side::A => &view.side_a,
-        Side::B => &view.side_b,
-    };
-    let target_types = match side {
-        Side::A => &view.side_b.active.types,
-        Side::B => &view.side_a.active.types,
-    };
-    let switch_targets: Vec<usize> = side_view
-        .team
+pub fn prompt_action(state: &BattleState, side: usize, valid_actions: &[Action]) -> Result<Action> {
+    let (active, bench) = side_of(state, side);
+    let (target, _) = side_of(state, 1 - side);
+    let switch_targets: Vec<usize> = valid_actions
         .iter()
-        .filter(|m| !m.is_active && !m.is_fainted)
-        .map(|m| m.index)
+        .filter_map(|action| match action {
+            Action::Switch(idx) => Some(*idx),
+            Action::Move(_) => None,
+        })
         .collect();
 
     loop {
-        if force_switch {
-            if switch_targets.is_empty() {
-                println!("交代可能なポケモンがいません。");
-                return Ok(PlayerAction::Move(0));
-            }
-            println!("交代先を番号で選んでください:");
-            list_switch_options(side_view);
-            let input = read_line()?;
-            if let Ok(idx) = input.trim().parse::<usize>() {
-                if switch_targets.contains(&idx) {
-                    return Ok(PlayerAction::Switch(idx));
-                }
-            }
-            println!("無効な入力です。もう一度入力してください。");
-            continue;
-        }
-
         println!("行動を選択してください:");
-        print_moves(&side_view.active.moves, target_types);
-        println!(" 0: 交代");
+        print_moves(state, active, target);
+        println!("  0: 交代");
         let input = read_line()?;
         let trimmed = input.trim();
         if trimmed == "0" {
@@ -77,237 +67,159 @@ pub fn prompt_action(view: &BattleView, side: Side, force_switch: bool) -> Resul
                 println!("交代できるポケモンがいません。");
                 continue;
             }
-            list_switch_options(side_view);
-            let input = read_line()?;
-            if let Ok(idx) = input.trim().parse::<usize>() {
-                if switch_targets.contains(&idx) {
-                    return Ok(PlayerAction::Switch(idx));
-                }
-            }
-            println!("無効な入力です。もう一度入力してください。");
-            continue;
+            return Ok(Action::Switch(prompt_switch(&state.dex, bench, &switch_targets)?));
         }
 
         if let Ok(choice) = trimmed.parse::<usize>() {
-            if choice == 0 || choice > side_view.active.moves.len() {
+            if choice == 0 || choice > active.moves.len() {
                 println!("範囲外の番号です。");
                 continue;
             }
-            let mv_idx = choice - 1;
-            if side_view.active.moves.get(mv_idx).map(|m| m.remaining_pp <= 0).unwrap_or(true) {
-                println!("PPが足りません。");
+            let action = Action::Move(choice - 1);
+            if !valid_actions.contains(&action) {
+                println!("その技は選べません。");
                 continue;
             }
-            return Ok(PlayerAction::Move(mv_idx));
+            return Ok(action);
+        }
+        println!("無効な入力です。もう一度入力してください。");
+    }
+}
+
+/// Pick a bench index from `options` to replace a fainted Pokémon.
+pub fn prompt_replacement(state: &BattleState, side: usize, options: &[usize]) -> Result<usize> {
+    let (active, bench) = side_of(state, side);
+    println!("{} は たおれた！", active.display_name(&state.dex));
+    prompt_switch(&state.dex, bench, options)
+}
+
+fn prompt_switch(dex: &Dex, bench: &[Pokemon], options: &[usize]) -> Result<usize> {
+    loop {
+        println!("交代先を番号で選んでください:");
+        list_switch_options(dex, bench, options);
+        let input = read_line()?;
+        if let Ok(num) = input.trim().parse::<usize>() {
+            if num > 0 && options.contains(&(num - 1)) {
+                return Ok(num - 1);
+            }
         }
         println!("無効な入力です。もう一度入力してください。");
     }
 }
 
-pub fn print_result(result: BattleResult, human_side: Side) {
+pub fn print_result(result: BattleResult, human_side: usize) {
     let outcome_text = match result {
-        BattleResult::AWins if matches!(human_side, Side::A) => "あなたの勝ち！",
-        BattleResult::BWins if matches!(human_side, Side::B) => "あなたの勝ち！",
-        BattleResult::AWins | BattleResult::BWins => "あなたの負け…",
-        BattleResult::Tie => "引き分け",
+        BattleResult::TeamAWins if human_side == 0 => "あなたの勝ち！",
+        BattleResult::TeamBWins if human_side == 1 => "あなたの勝ち！",
+        BattleResult::TeamAWins | BattleResult::TeamBWins => "あなたの負け…",
+        BattleResult::Draw => "引き分け",
     };
     println!();
     println!("=== 結果: {} ===", outcome_text);
 }
 
-fn print_moves(moves: &[MoveView], target_types: &[String]) {
-    for (i, mv) in moves.iter().enumerate() {
-        let status = if mv.remaining_pp <= 0 { "PPなし" } else { "" };
-        let effectiveness = type_effectiveness(&mv.move_type, target_types);
-        let effectiveness_text = effectiveness_label(effectiveness);
+fn print_moves(state: &BattleState, active: &Pokemon, target: &Pokemon) {
+    for (i, name) in active.moves.iter().enumerate() {
+        let Some(data) = state.dex.get_move(name) else {
+            println!(" {:>2}: {}", i + 1, translate_move(name));
+            continue;
+        };
+        let effectiveness = parse_type(data.move_type)
+            .filter(|_| data.category != MoveCategory::Status)
+            .map(|ty| state.type_chart.effectiveness_dual(ty, target.types[0], target.types[1]));
         println!(
-            " {:>2}: {:<16} {:<10} 命中 {:>5.1} 威力 {:<3} PP {}/{} {} {}",
+            " {:>2}: {:<16} {:<8} 命中 {:>5} 威力 {:<3} {}",
             i + 1,
-            mv.name,
-            mv.move_type,
-            mv.accuracy,
-            mv.power,
-            mv.remaining_pp,
-            mv.max_pp,
-            status,
-            effectiveness_text
+            translate_move(data.name),
+            translate_type(data.move_type),
+            data.accuracy.map_or("-".to_string(), |acc| format!("{:.0}", acc)),
+            data.base_power.filter(|bp| *bp > 0).map_or("-".to_string(), |bp| bp.to_string()),
+            effectiveness.map_or("", effectiveness_label)
         );
     }
 }
 
-fn print_party_section(
-    you: &SideView,
-    opp: &SideView,
-    human_candidates: &[Pokemon],
-    opponent_candidates: &[Pokemon],
-) {
+fn print_party_section(dex: &Dex, you: &Pokemon, bench: &[Pokemon], opp: &Pokemon, opponent_candidates: &[String]) {
     println!("=== 手持ち ===");
-    println!(
-        "{:<46} | {:<46}",
-        "あなたの手持ち",
-        "相手の手持ち"
-    );
+    println!("{:<46} | {:<46}", "あなたの手持ち", "相手の手持ち");
     println!("{}", "-".repeat(46) + " | " + &"-".repeat(46));
-    let rows = human_candidates.len().max(opponent_candidates.len()).max(3);
-    let selected_names: HashSet<String> =
-        opp.team.iter().map(|member| member.name.clone()).collect();
+    let team: Vec<&Pokemon> = std::iter::once(you).chain(bench).collect();
+    let rows = team.len().max(opponent_candidates.len());
     for idx in 0..rows {
-        let left = if idx < you.team.len() {
-            format_own_party_entry(&you.team[idx], idx)
-        } else {
-            "".to_string()
-        };
-        let right = if idx < opponent_candidates.len() {
-            format_opponent_party_entry(
-                &opponent_candidates[idx],
-                idx,
-                selected_names.contains(&opponent_candidates[idx].name),
-            )
-        } else {
-            "".to_string()
-        };
+        let left = team
+            .get(idx)
+            .map(|member| format_own_party_entry(dex, member, idx == 0))
+            .unwrap_or_default();
+        let right = opponent_candidates
+            .get(idx)
+            .map(|species| format_opponent_party_entry(species, idx, *species == opp.species))
+            .unwrap_or_default();
         println!("{:<46} | {:<46}", left, right);
     }
 }
 
-fn print_field_section(you: &SideView, opp: &SideView) {
+fn print_field_section(dex: &Dex, you: &Pokemon, opp: &Pokemon) {
     println!("{}", "-".repeat(46) + " | " + &"-".repeat(46));
-    let human_line = format_active_summary("あなた", &you.active, true);
-    let opponent_line = format_active_summary("相手", &opp.active, false);
+    let human_line = format_active_summary(dex, "あなた", you, true);
+    let opponent_line = format_active_summary(dex, "相手", opp, false);
     println!("{:<46} | {:<46}", human_line, opponent_line);
 }
 
-fn format_own_party_entry(member: &TeamMemberView, idx: usize) -> String {
-    let marker = if member.is_active { "▶" } else { " " };
+fn format_own_party_entry(dex: &Dex, member: &Pokemon, is_active: bool) -> String {
+    let marker = if is_active { "▶" } else { " " };
     format!(
-        "{}{:>2}: {:<12} [{} / {}] {}",
+        "{} {:<12} [{} / {}] {}",
         marker,
-        idx + 1,
-        member.name,
-        member.hp.max(0),
-        member.max_hp,
-        format_status(&member.status)
+        member.display_name(dex),
+        member.current_hp,
+        member.stats.hp,
+        format_status(member)
     )
 }
 
-fn format_opponent_party_entry(mon: &Pokemon, idx: usize, selected: bool) -> String {
-    let marker = if selected { "▶" } else { " " };
-    format!("{}{:>2}: {:<18}", marker, idx + 1, mon.name)
+fn format_opponent_party_entry(species: &str, idx: usize, active: bool) -> String {
+    let marker = if active { "▶" } else { " " };
+    format!("{}{:>2}: {:<18}", marker, idx + 1, translate_pokemon(species))
 }
 
-fn format_active_summary(label: &str, active: &BattlerView, show_numbers: bool) -> String {
-    let bar = hp_bar(active.hp, active.max_hp);
-    let status = format_status(&active.status);
+fn format_active_summary(dex: &Dex, label: &str, active: &Pokemon, show_numbers: bool) -> String {
+    let bar = hp_bar(active.current_hp, active.stats.hp);
+    let status = format_status(active);
     if show_numbers {
-        let hp_text = format!("{} / {}", active.hp.max(0), active.max_hp);
-        format!("{}: {} {} {} {}", label, active.name, bar, hp_text, status)
+        let hp_text = format!("{} / {}", active.current_hp, active.stats.hp);
+        format!("{}: {} {} {} {}", label, active.display_name(dex), bar, hp_text, status)
     } else {
-        format!("{}: {} {} {}", label, active.name, bar, status)
-    }
-}
-
-pub fn describe_faints(before: &BattleView, after: &BattleView, human_side: Side) -> Vec<String> {
-    let mut lines = Vec::new();
-    lines.extend(faint_lines_for_side(
-        &before.side_a,
-        &after.side_a,
-        matches!(human_side, Side::A),
-    ));
-    lines.extend(faint_lines_for_side(
-        &before.side_b,
-        &after.side_b,
-        matches!(human_side, Side::B),
-    ));
-    lines
-}
-
-pub fn print_turn_summary(
-    action_lines: &[String],
-    status_lines: &[String],
-    faint_lines: &[String],
-) {
-    if action_lines.is_empty() && status_lines.is_empty() && faint_lines.is_empty() {
-        return;
-    }
-    println!();
-    for line in action_lines {
-        println!("{}", line);
-    }
-    for line in status_lines {
-        println!("{}", line);
-    }
-    for line in faint_lines {
-        println!("{}", line);
+        format!("{}: {} {} {}", label, active.display_name(dex), bar, status)
     }
 }
 
-pub fn wait_for_next_turn() {
-    thread::sleep(Duration::from_secs(2));
-}
-
-pub fn prompt_team_selection(
-    role_label: &str,
-    team: &[Pokemon],
-    opponent: &[Pokemon],
-    count: usize,
-) -> Result<Vec<usize>> {
-    if team.len() < count {
-        anyhow::bail!("{}のチームに十分なポケモンがいません", role_label);
-    }
+pub fn prompt_team_selection(dex: &Dex, role_label: &str, preview: &TeamPreview) -> Result<TeamChoice> {
     println!(
-        "{}のチームから{}体を選んでください (例: 1 3 5)。番号はスペースまたはカンマで区切れます。",
-        role_label, count
+        "{}のチームから{}体を選んでください (例: 1 3 5)。最初の番号が先発です。番号はスペースまたはカンマで区切れます。",
+        role_label, preview.bring
     );
-    print_selection_table(team, opponent);
+    print_selection_table(dex, &preview.team, &preview.opponent_species);
     loop {
         print!("選出番号: ");
         let input = read_line()?;
-        let replaced = input.replace(',', " ");
-        let mut picks = Vec::new();
-        let mut invalid = false;
-        for token in replaced.split_whitespace() {
-            let num = match token.parse::<usize>() {
-                Ok(n) => n,
-                Err(_) => {
-                    invalid = true;
-                    break;
-                }
-            };
-            if num == 0 || num > team.len() {
-                println!("番号は1〜{}で指定してください。", team.len());
-                invalid = true;
-                break;
-            }
-            let idx = num - 1;
-            if picks.contains(&idx) {
-                println!("同じ番号を複数回選ぶことはできません。");
-                invalid = true;
-                break;
-            }
-            picks.push(idx);
-        }
-        if invalid {
-            continue;
+        match TeamChoice::parse(&input, preview.team.len(), preview.bring) {
+            Ok(choice) => return Ok(choice),
+            Err(err) => println!("{}", err),
         }
-        if picks.len() != count {
-            println!("{}体ちょうど選択してください。現在: {}", count, picks.len());
-            continue;
-        }
-        return Ok(picks);
     }
 }
 
-pub fn print_selection_summary(role_label: &str, party: &[Pokemon]) {
+pub fn print_selection_summary(dex: &Dex, role_label: &str, party: &[Pokemon]) {
     if party.is_empty() {
         return;
     }
-    let names: Vec<String> = party.iter().map(|p| p.name.clone()).collect();
+    let names: Vec<String> = party.iter().map(|p| p.display_name(dex)).collect();
     println!("{}の選出: {}", role_label, names.join(" / "));
 }
 
 fn effectiveness_label(multiplier: f32) -> &'static str {
     const EPS: f32 = 0.01;
-    if (multiplier - 0.0).abs() < EPS {
+    if multiplier.abs() < EPS {
         "こうかがない..."
     } else if multiplier > 1.0 + EPS {
         "こうかはばつぐん！"
@@ -318,161 +230,85 @@ fn effectiveness_label(multiplier: f32) -> &'static str {
     }
 }
 
-fn faint_lines_for_side(before: &SideView, after: &SideView, is_human: bool) -> Vec<String> {
-    before
-        .team
-        .iter()
-        .zip(after.team.iter())
-        .filter_map(|(prev, current)| {
-            if !prev.is_fainted && current.is_fainted {
-                let prefix = if is_human { "" } else { "相手の" };
-                Some(format!("{}{} は たおれた！", prefix, current.name))
-            } else {
-                None
-            }
-        })
-        .collect()
-}
-
-pub fn format_move_events(events: &[MoveEventView], human_side: Side) -> Vec<String> {
-    let mut lines = Vec::new();
-    for event in events {
-        let prefix = if event.side == human_side {
-            ""
-        } else {
-            "相手の"
-        };
-        lines.push(format!(
-            "{}{} は {} を つかった！",
-            prefix, event.pokemon, event.move_name
-        ));
-        match &event.outcome {
-            MoveOutcome::Missed => lines.push("しかし あたらなかった！".to_string()),
-            MoveOutcome::Protected => lines.push("しかし まもられた！".to_string()),
-            MoveOutcome::NoEffect { effectiveness } => {
-                lines.push(effectiveness_label(*effectiveness).to_string())
-            }
-            MoveOutcome::Hit { effectiveness, .. } => {
-                lines.push(effectiveness_label(*effectiveness).to_string())
-            }
-            MoveOutcome::StatusOnly => {}
-        }
-    }
-    lines
-}
-
-pub fn format_status_events(events: &[StatusEventView], human_side: Side) -> Vec<String> {
-    events
-        .iter()
-        .map(|event| {
-            let prefix = if event.side == human_side {
-                ""
-            } else {
-                "相手の"
-            };
-            format!("{}{} {}", prefix, event.pokemon, event.message)
-        })
-        .collect()
-}
-
-fn print_selection_table(team: &[Pokemon], opponent: &[Pokemon]) {
+fn print_selection_table(dex: &Dex, team: &[Pokemon], opponent: &[String]) {
     let width = 46;
-    println!(
-        "{:<width$} | {}",
-        "あなたの候補",
-        "相手の候補",
-        width = width
-    );
+    println!("{:<width$} | 相手の候補", "あなたの候補", width = width);
     println!("{}", "-".repeat(width) + " | " + &"-".repeat(width));
     let rows = team.len().max(opponent.len());
     for idx in 0..rows {
         let left = team
             .get(idx)
-            .map(|mon| format_candidate(mon, idx))
+            .map(|mon| format_candidate(dex, mon, idx))
             .unwrap_or_else(|| " ".repeat(width));
         let right = opponent
             .get(idx)
-            .map(|mon| format_candidate(mon, idx))
-            .unwrap_or_else(|| " ".repeat(width));
+            .map(|species| format!("{:>2}: {}", idx + 1, translate_pokemon(species)))
+            .unwrap_or_default();
         println!("{:<width$} | {}", left, right, width = width);
     }
     println!("{}", "-".repeat(width) + " | " + &"-".repeat(width));
 }
 
-fn format_candidate(mon: &Pokemon, idx: usize) -> String {
-    let types = if mon.types.is_empty() {
-        "-".to_string()
-    } else {
-        mon.types.join("/")
-    };
+fn format_candidate(dex: &Dex, mon: &Pokemon, idx: usize) -> String {
+    let mut types = vec![translate_type(&format!("{:?}", mon.types[0]))];
+    if mon.types[1] != mon.types[0] {
+        types.push(translate_type(&format!("{:?}", mon.types[1])));
+    }
     format!(
         "{:>2}: {:<12} タイプ:{:<12} HP:{}",
         idx + 1,
-        mon.name,
-        types,
+        mon.display_name(dex),
+        types.join("/"),
         mon.stats.hp
     )
 }
 
-fn list_switch_options(side: &SideView) {
-    for member in side.team.iter().filter(|m| !m.is_active && !m.is_fainted) {
-        let hp_bar = hp_bar(member.hp, member.max_hp);
-        let status = format_status(&member.status);
+fn list_switch_options(dex: &Dex, bench: &[Pokemon], options: &[usize]) {
+    for &idx in options {
+        let member = &bench[idx];
         println!(
-            " {:>2}: {:<12} [{}] {} {}",
-            member.index,
-            member.name,
-            hp_bar,
-            status,
-            if member.is_fainted { "(瀕死)" } else { "" }
+            " {:>2}: {:<12} {} {}",
+            idx + 1,
+            member.display_name(dex),
+            hp_bar(member.current_hp, member.stats.hp),
+            format_status(member)
         );
     }
 }
 
-fn print_active(hp: i32, max_hp: i32, status: &Option<StatusCondition>, stages: &StatStagesView, name: &str) {
-    let bar = hp_bar(hp, max_hp);
-    let status = format_status(status);
-    let stage_text = format_stages(stages);
-    println!("{} [{}] {} {}", name, bar, status, stage_text);
-}
-
-fn print_team(team: &[TeamMemberView]) {
-    for member in team {
-        let hp_bar = hp_bar(member.hp, member.max_hp);
-        let status = format_status(&member.status);
-        let marker = if member.is_active { ">" } else { " " };
-        let faint = if member.is_fainted { " (瀕死)" } else { "" };
-        println!(
-            "{} {:<12} [{}] {}{}",
-            marker, member.name, hp_bar, status, faint
-        );
-    }
-}
-
-fn describe_side(side: &SideView) -> String {
+fn describe_side(side: &SideConditions) -> String {
     let mut parts = Vec::new();
-    if side.hazards.stealth_rock {
+    if side.stealth_rock {
         parts.push("ステロ");
     }
-    if side.hazards.spikes > 0 {
-        parts.push(match side.hazards.spikes {
+    if side.spikes > 0 {
+        parts.push(match side.spikes {
             1 => "1層スパイク",
             2 => "2層スパイク",
             _ => "3層スパイク",
         });
     }
-    if side.hazards.toxic_spikes > 0 {
-        parts.push(match side.hazards.toxic_spikes {
+    if side.toxic_spikes > 0 {
+        parts.push(match side.toxic_spikes {
             1 => "どくびし1層",
             _ => "どくびし2層",
         });
     }
-    if side.screens.reflect > 0 {
+    if side.sticky_web {
+        parts.push("ねばねばネット");
+    }
+    if side.reflect_turns > 0 {
         parts.push("リフレクター");
     }
-    if side.screens.light_screen > 0 {
+    if side.light_screen_turns > 0 {
         parts.push("ひかりのかべ");
     }
+    if side.aurora_veil_turns > 0 {
+        parts.push("オーロラベール");
+    }
+    if side.tailwind_turns > 0 {
+        parts.push("おいかぜ");
+    }
     if parts.is_empty() {
         "なし".to_string()
     } else {
@@ -480,50 +316,41 @@ fn describe_side(side: &SideView) -> String {
     }
 }
 
-fn hp_bar(hp: i32, max_hp: i32) -> String {
+fn hp_bar(hp: u16, max_hp: u16) -> String {
     let width = 20usize;
-    let hp_clamped = hp.max(0) as f32;
-    let max = max_hp.max(1) as f32;
-    let filled = ((hp_clamped / max) * width as f32).round() as usize;
+    let filled = ((hp as f32 / max_hp.max(1) as f32) * width as f32).round() as usize;
     let filled = filled.min(width);
-    let mut bar = String::new();
-    bar.push('[');
-    for _ in 0..filled {
-        bar.push('=');
-    }
-    for _ in filled..width {
-        bar.push('.');
-    }
-    bar.push(']');
-    bar
+    format!("[{}{}]", "=".repeat(filled), ".".repeat(width - filled))
 }
 
-fn format_status(status: &Option<StatusCondition>) -> String {
-    match status {
-        None => "OK".to_string(),
-        Some(StatusCondition::Burn) => "BRN".to_string(),
-        Some(StatusCondition::Paralysis) => "PAR".to_string(),
-        Some(StatusCondition::Sleep) => "SLP".to_string(),
-        Some(StatusCondition::Poison) => "PSN".to_string(),
-        Some(StatusCondition::Toxic) => "TOX".to_string(),
-        Some(StatusCondition::Freeze) => "FRZ".to_string(),
+fn format_status(pokemon: &Pokemon) -> &'static str {
+    if pokemon.is_fainted() {
+        return "FNT";
+    }
+    match pokemon.status {
+        None | Some(Status::Flinch) => "OK",
+        Some(Status::Burn) => "BRN",
+        Some(Status::Paralysis) => "PAR",
+        Some(Status::Sleep) => "SLP",
+        Some(Status::Poison) if pokemon.toxic_counter > 0 => "TOX",
+        Some(Status::Poison) => "PSN",
+        Some(Status::Freeze) => "FRZ",
     }
 }
 
-fn format_stages(stages: &StatStagesView) -> String {
-    let mut parts = Vec::new();
-    let push = |parts: &mut Vec<String>, label: &str, val: i8| {
+fn format_stages(pokemon: &Pokemon) -> String {
+    let labels = ["Atk", "Def", "SpA", "SpD", "Spe"];
+    let mut parts: Vec<String> = labels
+        .iter()
+        .zip(pokemon.stat_stages)
+        .filter(|(_, val)| *val != 0)
+        .map(|(label, val)| format!("{} {:+}", label, val))
+        .collect();
+    for (label, val) in [("Acc", pokemon.accuracy_stage), ("Eva", pokemon.evasion_stage)] {
         if val != 0 {
             parts.push(format!("{} {:+}", label, val));
         }
-    };
-    push(&mut parts, "Atk", stages.atk);
-    push(&mut parts, "Def", stages.def);
-    push(&mut parts, "SpA", stages.spa);
-    push(&mut parts, "SpD", stages.spd);
-    push(&mut parts, "Spe", stages.spe);
-    push(&mut parts, "Acc", stages.acc);
-    push(&mut parts, "Eva", stages.eva);
+    }
     if parts.is_empty() {
         "能力変化: なし".to_string()
     } else {
@@ -531,14 +358,11 @@ fn format_stages(stages: &StatStagesView) -> String {
     }
 }
 
-fn clear_screen() {
-    print!("\x1b[2J\x1b[H");
-    let _ = io::stdout().flush();
-}
-
 fn read_line() -> Result<String> {
     let mut buf = String::new();
     io::stdout().flush()?;
-    io::stdin().read_line(&mut buf)?;
+    if io::stdin().read_line(&mut buf)? == 0 {
+        anyhow::bail!("入力が終了しました");
+    }
     Ok(buf)
 }
@@ -1,36 +1,70 @@
-use std::env;
-use std::path::Path;
-use std::process::Command;
+//! Build script.
+//!
+//! The tables in `src/data` are committed, so a plain build needs neither
+//! node nor a `pokemon-showdown` checkout. With the opt-in `verify-data`
+//! feature the script re-renders the tables from a Showdown dex dump and fails
+//! when the committed files are stale.
+
+#[cfg(feature = "verify-data")]
+#[path = "src/datagen.rs"]
+mod datagen;
 
 fn main() {
-    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
-    let workspace_root = Path::new(&manifest_dir)
-        .parent()
-        .unwrap_or_else(|| Path::new(&manifest_dir));
-    let extract_script = workspace_root.join("tools").join("extract_data.js");
-    println!("cargo:rerun-if-changed={}", extract_script.display());
-    let showdown_data = workspace_root
-        .join("pokemon-showdown")
-        .join("data");
-    for file in &[
-        "pokedex.ts",
-        "moves.ts",
-        "abilities.ts",
-        "items.ts",
-        "typechart.ts",
-    ] {
-        println!(
-            "cargo:rerun-if-changed={}",
-            showdown_data.join(file).display()
-        );
+    println!("cargo:rerun-if-changed=build.rs");
+    #[cfg(feature = "verify-data")]
+    verify::run();
+}
+
+#[cfg(feature = "verify-data")]
+mod verify {
+    use super::datagen;
+    use std::env;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    /// Environment variable overriding the dump location.
+    const DUMP_ENV: &str = "POKEMON_DEX_DUMP";
+
+    pub fn run() {
+        let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+        let workspace_root = manifest_dir.parent().unwrap_or(&manifest_dir).to_path_buf();
+        println!("cargo:rerun-if-env-changed={DUMP_ENV}");
+        println!("cargo:rerun-if-changed=src/datagen.rs");
+        let dump_path = env::var_os(DUMP_ENV)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| workspace_root.join("pokemon-showdown").join("dex.json"));
+        println!("cargo:rerun-if-changed={}", dump_path.display());
+        if !dump_path.exists() {
+            println!(
+                "cargo:warning=verify-data: {} not found; skipping freshness check (set {DUMP_ENV})",
+                dump_path.display()
+            );
+            return;
+        }
+        let dump = fs::read_to_string(&dump_path)
+            .unwrap_or_else(|e| panic!("failed to read {}: {e}", dump_path.display()));
+        let dump: serde_json::Value = serde_json::from_str(&dump)
+            .unwrap_or_else(|e| panic!("failed to parse {}: {e}", dump_path.display()));
+        let files = datagen::render_all(&dump).unwrap_or_else(|e| panic!("{}: {e}", dump_path.display()));
+
+        let data_dir = manifest_dir.join("src").join("data");
+        let stale: Vec<String> = files
+            .iter()
+            .filter(|file| !is_fresh(&data_dir.join(file.file_name()), &file.contents))
+            .map(|file| file.file_name())
+            .collect();
+        if !stale.is_empty() {
+            panic!(
+                "src/data is stale for {} ({}); regenerate with `cargo run --bin pokemon-battle-cli -- gen-data --dex {}`",
+                dump_path.display(),
+                stale.join(", "),
+                dump_path.display()
+            );
+        }
     }
-    let output_dir = Path::new(&manifest_dir).join("src").join("data");
-    let status = Command::new("node")
-        .arg(extract_script.as_os_str())
-        .arg(output_dir.as_os_str())
-        .status()
-        .expect("failed to run tools/extract_data.js");
-    if !status.success() {
-        panic!("tools/extract_data.js failed");
+
+    fn is_fresh(path: &Path, expected: &str) -> bool {
+        println!("cargo:rerun-if-changed={}", path.display());
+        fs::read_to_string(path).is_ok_and(|current| current == expected)
     }
 }
@@ -21,6 +21,21 @@ impl BattleLogger {
         }
     }
 
+    /// `|gametype|`, `|gen|` and `|tier|` lines that open a battle log.
+    pub fn log_header(&mut self, game_type: &str, generation: u8, tier: &str) {
+        self.log.push(format!("|gametype|{game_type}"));
+        self.log.push(format!("|gen|{generation}"));
+        self.log.push(format!("|tier|{tier}"));
+    }
+
+    pub fn log_rule(&mut self, rule: &str) {
+        self.log.push(format!("|rule|{rule}"));
+    }
+
+    pub fn log_message(&mut self, message: &str) {
+        self.log.push(format!("|-message|{message}"));
+    }
+
     pub fn log_turn(&mut self, turn: usize) {
         self.log.push(format!("|turn|{turn}"));
     }
@@ -47,6 +62,24 @@ impl BattleLogger {
             .push(format!("|switch|{pokemon}|{species}|{hp}/{max_hp}"));
     }
 
+    pub fn log_clear_poke(&mut self) {
+        self.log.push("|clearpoke".to_string());
+    }
+
+    /// `player` is `p1`/`p2`; the item itself stays hidden at team preview.
+    pub fn log_poke(&mut self, player: &str, details: &str, has_item: bool) {
+        let item = if has_item { "item" } else { "" };
+        self.log.push(format!("|poke|{player}|{details}|{item}"));
+    }
+
+    /// `pick` is how many Pokémon each player brings when it is fewer than the whole team.
+    pub fn log_team_preview(&mut self, pick: Option<usize>) {
+        match pick {
+            Some(pick) => self.log.push(format!("|teampreview|{pick}")),
+            None => self.log.push("|teampreview".to_string()),
+        }
+    }
+
     pub fn log_win(&mut self, winner: &str) {
         self.log.push(format!("|win|{winner}"));
     }
@@ -1,10 +1,11 @@
-// AUTO-GENERATED by tools/extract_data.js
+// AUTO-GENERATED by `pokemon-battle-cli gen-data`. Do not edit by hand.
 use phf::phf_map;
 
 #[derive(Clone, Copy, Debug)]
 pub struct AbilityData {
     pub num: i16,
     pub name: &'static str,
+    pub short_desc: &'static str,
     pub description: &'static str,
 }
 
@@ -12,1571 +13,1885 @@ pub static ABILITIES: phf::Map<&'static str, AbilityData> = phf_map! {
     "persistent" => AbilityData {
         num: -3,
         name: "Persistent",
-        description: "",
+        short_desc: "The duration of Gravity, Heal Block, Magic Room, Safeguard, Tailwind, Trick Room, and Wonder Room is increased by 2 turns if the effect is started by this Pokemon.",
+        description: "The duration of Gravity, Heal Block, Magic Room, Safeguard, Tailwind, Trick Room, and Wonder Room is increased by 2 turns if the effect is started by this Pokemon.",
     },
     "rebound" => AbilityData {
         num: -2,
         name: "Rebound",
-        description: "",
+        short_desc: "On switch-in, this Pokemon blocks certain Status moves and bounces them back to the user.",
+        description: "On switch-in, this Pokemon blocks certain Status moves and bounces them back to the user.",
     },
     "mountaineer" => AbilityData {
         num: -1,
         name: "Mountaineer",
-        description: "",
+        short_desc: "On switch-in, this Pokemon avoids all Rock-type attacks.",
+        description: "On switch-in, this Pokemon avoids all Rock-type attacks.",
     },
     "noability" => AbilityData {
         num: 0,
         name: "No Ability",
-        description: "",
+        short_desc: "Does nothing.",
+        description: "Does nothing.",
     },
     "stench" => AbilityData {
         num: 1,
         name: "Stench",
-        description: "",
+        short_desc: "This Pokemon's attacks without a chance to flinch have a 10% chance to flinch.",
+        description: "This Pokemon's attacks without a chance to flinch have a 10% chance to flinch.",
     },
     "drizzle" => AbilityData {
         num: 2,
         name: "Drizzle",
-        description: "",
+        short_desc: "On switch-in, this Pokemon summons Rain Dance.",
+        description: "On switch-in, this Pokemon summons Rain Dance.",
     },
     "speedboost" => AbilityData {
         num: 3,
         name: "Speed Boost",
-        description: "",
+        short_desc: "This Pokemon's Speed is raised by 1 stage at the end of each full turn on the field.",
+        description: "This Pokemon's Speed is raised by 1 stage at the end of each full turn on the field.",
     },
     "battlearmor" => AbilityData {
         num: 4,
         name: "Battle Armor",
-        description: "",
+        short_desc: "This Pokemon cannot be struck by a critical hit.",
+        description: "This Pokemon cannot be struck by a critical hit.",
     },
     "sturdy" => AbilityData {
         num: 5,
         name: "Sturdy",
-        description: "",
+        short_desc: "If this Pokemon is at full HP, it survives one hit with at least 1 HP. Immune to OHKO.",
+        description: "If this Pokemon is at full HP, it survives one hit with at least 1 HP. Immune to OHKO.",
     },
     "damp" => AbilityData {
         num: 6,
         name: "Damp",
-        description: "",
+        short_desc: "Prevents Explosion/Mind Blown/Misty Explosion/Self-Destruct/Aftermath while active.",
+        description: "Prevents Explosion/Mind Blown/Misty Explosion/Self-Destruct/Aftermath while active.",
     },
     "limber" => AbilityData {
         num: 7,
         name: "Limber",
-        description: "",
+        short_desc: "This Pokemon cannot be paralyzed. Gaining this Ability while paralyzed cures it.",
+        description: "This Pokemon cannot be paralyzed. Gaining this Ability while paralyzed cures it.",
     },
     "sandveil" => AbilityData {
         num: 8,
         name: "Sand Veil",
-        description: "",
+        short_desc: "If Sandstorm is active, this Pokemon's evasiveness is 1.25x; immunity to Sandstorm.",
+        description: "If Sandstorm is active, this Pokemon's evasiveness is 1.25x; immunity to Sandstorm.",
     },
     "static" => AbilityData {
         num: 9,
         name: "Static",
-        description: "",
+        short_desc: "30% chance a Pokemon making contact with this Pokemon will be paralyzed.",
+        description: "30% chance a Pokemon making contact with this Pokemon will be paralyzed.",
     },
     "voltabsorb" => AbilityData {
         num: 10,
         name: "Volt Absorb",
-        description: "",
+        short_desc: "This Pokemon heals 1/4 of its max HP when hit by Electric moves; Electric immunity.",
+        description: "This Pokemon heals 1/4 of its max HP when hit by Electric moves; Electric immunity.",
     },
     "waterabsorb" => AbilityData {
         num: 11,
         name: "Water Absorb",
-        description: "",
+        short_desc: "This Pokemon heals 1/4 of its max HP when hit by Water moves; Water immunity.",
+        description: "This Pokemon heals 1/4 of its max HP when hit by Water moves; Water immunity.",
     },
     "oblivious" => AbilityData {
         num: 12,
         name: "Oblivious",
-        description: "",
+        short_desc: "This Pokemon cannot be infatuated or taunted. Immune to Intimidate.",
+        description: "This Pokemon cannot be infatuated or taunted. Immune to Intimidate.",
     },
     "cloudnine" => AbilityData {
         num: 13,
         name: "Cloud Nine",
-        description: "",
+        short_desc: "While this Pokemon is active, the effects of weather conditions are disabled.",
+        description: "While this Pokemon is active, the effects of weather conditions are disabled.",
     },
     "compoundeyes" => AbilityData {
         num: 14,
         name: "Compound Eyes",
-        description: "",
+        short_desc: "This Pokemon's moves have their accuracy multiplied by 1.3.",
+        description: "This Pokemon's moves have their accuracy multiplied by 1.3.",
     },
     "insomnia" => AbilityData {
         num: 15,
         name: "Insomnia",
-        description: "",
+        short_desc: "This Pokemon cannot fall asleep. Gaining this Ability while asleep cures it.",
+        description: "This Pokemon cannot fall asleep. Gaining this Ability while asleep cures it.",
     },
     "colorchange" => AbilityData {
         num: 16,
         name: "Color Change",
-        description: "",
+        short_desc: "This Pokemon's type changes to the type of a move it's hit by, unless it has the type.",
+        description: "This Pokemon's type changes to the type of a move it's hit by, unless it has the type.",
     },
     "immunity" => AbilityData {
         num: 17,
         name: "Immunity",
-        description: "",
+        short_desc: "This Pokemon cannot be poisoned. Gaining this Ability while poisoned cures it.",
+        description: "This Pokemon cannot be poisoned. Gaining this Ability while poisoned cures it.",
     },
     "flashfire" => AbilityData {
         num: 18,
         name: "Flash Fire",
-        description: "",
+        short_desc: "This Pokemon's Fire attacks do 1.5x damage if hit by one Fire move; Fire immunity.",
+        description: "This Pokemon's Fire attacks do 1.5x damage if hit by one Fire move; Fire immunity.",
     },
     "shielddust" => AbilityData {
         num: 19,
         name: "Shield Dust",
-        description: "",
+        short_desc: "This Pokemon is not affected by the secondary effect of another Pokemon's attack.",
+        description: "This Pokemon is not affected by the secondary effect of another Pokemon's attack.",
     },
     "owntempo" => AbilityData {
         num: 20,
         name: "Own Tempo",
-        description: "",
+        short_desc: "This Pokemon cannot be confused. Immune to Intimidate.",
+        description: "This Pokemon cannot be confused. Immune to Intimidate.",
     },
     "suctioncups" => AbilityData {
         num: 21,
         name: "Suction Cups",
-        description: "",
+        short_desc: "This Pokemon cannot be forced to switch out by another Pokemon's attack or item.",
+        description: "This Pokemon cannot be forced to switch out by another Pokemon's attack or item.",
     },
     "intimidate" => AbilityData {
         num: 22,
         name: "Intimidate",
-        description: "",
+        short_desc: "On switch-in, this Pokemon lowers the Attack of opponents by 1 stage.",
+        description: "On switch-in, this Pokemon lowers the Attack of opponents by 1 stage.",
     },
     "shadowtag" => AbilityData {
         num: 23,
         name: "Shadow Tag",
-        description: "",
+        short_desc: "Prevents foes from choosing to switch unless they also have this Ability or are immune to trapping.",
+        description: "Prevents foes from choosing to switch unless they also have this Ability or are immune to trapping.",
     },
     "roughskin" => AbilityData {
         num: 24,
         name: "Rough Skin",
-        description: "",
+        short_desc: "Pokemon making contact with this Pokemon lose 1/8 of their max HP.",
+        description: "Pokemon making contact with this Pokemon lose 1/8 of their max HP.",
     },
     "wonderguard" => AbilityData {
         num: 25,
         name: "Wonder Guard",
-        description: "",
+        short_desc: "This Pokemon can only be damaged by supereffective moves and indirect damage.",
+        description: "This Pokemon can only be damaged by supereffective moves and indirect damage.",
     },
     "levitate" => AbilityData {
         num: 26,
         name: "Levitate",
-        description: "",
+        short_desc: "This Pokemon is immune to Ground; Gravity/Ingrain/Smack Down/Iron Ball nullify it.",
+        description: "This Pokemon is immune to Ground; Gravity/Ingrain/Smack Down/Iron Ball nullify it.",
     },
     "effectspore" => AbilityData {
         num: 27,
         name: "Effect Spore",
-        description: "",
+        short_desc: "30% chance of poison/paralysis/sleep on others making contact with this Pokemon.",
+        description: "30% chance of poison/paralysis/sleep on others making contact with this Pokemon.",
     },
     "synchronize" => AbilityData {
         num: 28,
         name: "Synchronize",
-        description: "",
+        short_desc: "If another Pokemon burns/poisons/paralyzes this Pokemon, it also gets that status.",
+        description: "If another Pokemon burns/poisons/paralyzes this Pokemon, it also gets that status.",
     },
     "clearbody" => AbilityData {
         num: 29,
         name: "Clear Body",
-        description: "",
+        short_desc: "Prevents other Pokemon from lowering this Pokemon's stat stages.",
+        description: "Prevents other Pokemon from lowering this Pokemon's stat stages.",
     },
     "naturalcure" => AbilityData {
         num: 30,
         name: "Natural Cure",
-        description: "",
+        short_desc: "This Pokemon has its non-volatile status condition cured when it switches out.",
+        description: "This Pokemon has its non-volatile status condition cured when it switches out.",
     },
     "lightningrod" => AbilityData {
         num: 31,
         name: "Lightning Rod",
-        description: "",
+        short_desc: "This Pokemon draws Electric moves to itself to raise Sp. Atk by 1; Electric immunity.",
+        description: "This Pokemon draws Electric moves to itself to raise Sp. Atk by 1; Electric immunity.",
     },
     "serenegrace" => AbilityData {
         num: 32,
         name: "Serene Grace",
-        description: "",
+        short_desc: "This Pokemon's moves have their secondary effect chance doubled.",
+        description: "This Pokemon's moves have their secondary effect chance doubled.",
     },
     "swiftswim" => AbilityData {
         num: 33,
         name: "Swift Swim",
-        description: "",
+        short_desc: "If Rain Dance is active, this Pokemon's Speed is doubled.",
+        description: "If Rain Dance is active, this Pokemon's Speed is doubled.",
     },
     "chlorophyll" => AbilityData {
         num: 34,
         name: "Chlorophyll",
-        description: "",
+        short_desc: "If Sunny Day is active, this Pokemon's Speed is doubled.",
+        description: "If Sunny Day is active, this Pokemon's Speed is doubled.",
     },
     "illuminate" => AbilityData {
         num: 35,
         name: "Illuminate",
-        description: "",
+        short_desc: "This Pokemon's accuracy can't be lowered by others; ignores their evasiveness stat.",
+        description: "This Pokemon's accuracy can't be lowered by others; ignores their evasiveness stat.",
     },
     "trace" => AbilityData {
         num: 36,
         name: "Trace",
-        description: "",
+        short_desc: "On switch-in, or when it can, this Pokemon copies a random adjacent foe's Ability.",
+        description: "On switch-in, or when it can, this Pokemon copies a random adjacent foe's Ability.",
     },
     "hugepower" => AbilityData {
         num: 37,
         name: "Huge Power",
-        description: "",
+        short_desc: "This Pokemon's Attack is doubled.",
+        description: "This Pokemon's Attack is doubled.",
     },
     "poisonpoint" => AbilityData {
         num: 38,
         name: "Poison Point",
-        description: "",
+        short_desc: "30% chance a Pokemon making contact with this Pokemon will be poisoned.",
+        description: "30% chance a Pokemon making contact with this Pokemon will be poisoned.",
     },
     "innerfocus" => AbilityData {
         num: 39,
         name: "Inner Focus",
-        description: "",
+        short_desc: "This Pokemon cannot be made to flinch. Immune to Intimidate.",
+        description: "This Pokemon cannot be made to flinch. Immune to Intimidate.",
     },
     "magmaarmor" => AbilityData {
         num: 40,
         name: "Magma Armor",
-        description: "",
+        short_desc: "This Pokemon cannot be frozen. Gaining this Ability while frozen cures it.",
+        description: "This Pokemon cannot be frozen. Gaining this Ability while frozen cures it.",
     },
     "waterveil" => AbilityData {
         num: 41,
         name: "Water Veil",
-        description: "",
+        short_desc: "This Pokemon cannot be burned. Gaining this Ability while burned cures it.",
+        description: "This Pokemon cannot be burned. Gaining this Ability while burned cures it.",
     },
     "magnetpull" => AbilityData {
         num: 42,
         name: "Magnet Pull",
-        description: "",
+        short_desc: "Prevents opposing Steel-type Pokemon from choosing to switch out, unless they are immune to trapping.",
+        description: "Prevents opposing Steel-type Pokemon from choosing to switch out, unless they are immune to trapping.",
     },
     "soundproof" => AbilityData {
         num: 43,
         name: "Soundproof",
-        description: "",
+        short_desc: "This Pokemon is immune to sound-based moves, including Heal Bell.",
+        description: "This Pokemon is immune to sound-based moves, including Heal Bell.",
     },
     "raindish" => AbilityData {
         num: 44,
         name: "Rain Dish",
-        description: "",
+        short_desc: "If Rain Dance is active, this Pokemon heals 1/16 of its max HP each turn.",
+        description: "If Rain Dance is active, this Pokemon heals 1/16 of its max HP each turn.",
     },
     "sandstream" => AbilityData {
         num: 45,
         name: "Sand Stream",
-        description: "",
+        short_desc: "On switch-in, this Pokemon summons Sandstorm.",
+        description: "On switch-in, this Pokemon summons Sandstorm.",
     },
     "pressure" => AbilityData {
         num: 46,
         name: "Pressure",
-        description: "",
+        short_desc: "If this Pokemon is the target of a foe's move, that move loses one additional PP.",
+        description: "If this Pokemon is the target of a foe's move, that move loses one additional PP.",
     },
     "thickfat" => AbilityData {
         num: 47,
         name: "Thick Fat",
-        description: "",
+        short_desc: "Fire-/Ice-type moves against this Pokemon deal damage with a halved offensive stat.",
+        description: "Fire-/Ice-type moves against this Pokemon deal damage with a halved offensive stat.",
     },
     "earlybird" => AbilityData {
         num: 48,
         name: "Early Bird",
-        description: "",
+        short_desc: "This Pokemon's sleep counter drops by 2 instead of 1.",
+        description: "This Pokemon's sleep counter drops by 2 instead of 1.",
     },
     "flamebody" => AbilityData {
         num: 49,
         name: "Flame Body",
-        description: "",
+        short_desc: "30% chance a Pokemon making contact with this Pokemon will be burned.",
+        description: "30% chance a Pokemon making contact with this Pokemon will be burned.",
     },
     "runaway" => AbilityData {
         num: 50,
         name: "Run Away",
-        description: "",
+        short_desc: "No competitive use.",
+        description: "No competitive use.",
     },
     "keeneye" => AbilityData {
         num: 51,
         name: "Keen Eye",
-        description: "",
+        short_desc: "This Pokemon's accuracy can't be lowered by others; ignores their evasiveness stat.",
+        description: "This Pokemon's accuracy can't be lowered by others; ignores their evasiveness stat.",
     },
     "hypercutter" => AbilityData {
         num: 52,
         name: "Hyper Cutter",
-        description: "",
+        short_desc: "Prevents other Pokemon from lowering this Pokemon's Attack stat stage.",
+        description: "Prevents other Pokemon from lowering this Pokemon's Attack stat stage.",
     },
     "pickup" => AbilityData {
         num: 53,
         name: "Pickup",
-        description: "",
+        short_desc: "If this Pokemon has no item, it finds one used by an adjacent Pokemon this turn.",
+        description: "If this Pokemon has no item, it finds one used by an adjacent Pokemon this turn.",
     },
     "truant" => AbilityData {
         num: 54,
         name: "Truant",
-        description: "",
+        short_desc: "This Pokemon skips every other turn instead of using a move.",
+        description: "This Pokemon skips every other turn instead of using a move.",
     },
     "hustle" => AbilityData {
         num: 55,
         name: "Hustle",
-        description: "",
+        short_desc: "This Pokemon's Attack is 1.5x and accuracy of its physical attacks is 0.8x.",
+        description: "This Pokemon's Attack is 1.5x and accuracy of its physical attacks is 0.8x.",
     },
     "cutecharm" => AbilityData {
         num: 56,
         name: "Cute Charm",
-        description: "",
+        short_desc: "30% chance of infatuating Pokemon of the opposite gender if they make contact.",
+        description: "30% chance of infatuating Pokemon of the opposite gender if they make contact.",
     },
     "plus" => AbilityData {
         num: 57,
         name: "Plus",
-        description: "",
+        short_desc: "If an active ally has this Ability or the Minus Ability, this Pokemon's Sp. Atk is 1.5x.",
+        description: "If an active ally has this Ability or the Minus Ability, this Pokemon's Sp. Atk is 1.5x.",
     },
     "minus" => AbilityData {
         num: 58,
         name: "Minus",
-        description: "",
+        short_desc: "If an active ally has this Ability or the Plus Ability, this Pokemon's Sp. Atk is 1.5x.",
+        description: "If an active ally has this Ability or the Plus Ability, this Pokemon's Sp. Atk is 1.5x.",
     },
     "forecast" => AbilityData {
         num: 59,
         name: "Forecast",
-        description: "",
+        short_desc: "Castform's type changes to the current weather condition's type, except Sandstorm.",
+        description: "Castform's type changes to the current weather condition's type, except Sandstorm.",
     },
     "stickyhold" => AbilityData {
         num: 60,
         name: "Sticky Hold",
-        description: "",
+        short_desc: "This Pokemon cannot lose its held item due to another Pokemon's Ability or attack.",
+        description: "This Pokemon cannot lose its held item due to another Pokemon's Ability or attack.",
     },
     "shedskin" => AbilityData {
         num: 61,
         name: "Shed Skin",
-        description: "",
+        short_desc: "This Pokemon has a 33% chance to have its status cured at the end of each turn.",
+        description: "This Pokemon has a 33% chance to have its status cured at the end of each turn.",
     },
     "guts" => AbilityData {
         num: 62,
         name: "Guts",
-        description: "",
+        short_desc: "If this Pokemon is statused, its Attack is 1.5x; ignores burn halving physical damage.",
+        description: "If this Pokemon is statused, its Attack is 1.5x; ignores burn halving physical damage.",
     },
     "marvelscale" => AbilityData {
         num: 63,
         name: "Marvel Scale",
-        description: "",
+        short_desc: "If this Pokemon is statused, its Defense is 1.5x.",
+        description: "If this Pokemon is statused, its Defense is 1.5x.",
     },
     "liquidooze" => AbilityData {
         num: 64,
         name: "Liquid Ooze",
-        description: "",
+        short_desc: "This Pokemon damages those draining HP from it for as much as they would heal.",
+        description: "This Pokemon damages those draining HP from it for as much as they would heal.",
     },
     "overgrow" => AbilityData {
         num: 65,
         name: "Overgrow",
-        description: "",
+        short_desc: "At 1/3 or less of its max HP, this Pokemon's offensive stat is 1.5x with Grass attacks.",
+        description: "At 1/3 or less of its max HP, this Pokemon's offensive stat is 1.5x with Grass attacks.",
     },
     "blaze" => AbilityData {
         num: 66,
         name: "Blaze",
-        description: "",
+        short_desc: "At 1/3 or less of its max HP, this Pokemon's offensive stat is 1.5x with Fire attacks.",
+        description: "At 1/3 or less of its max HP, this Pokemon's offensive stat is 1.5x with Fire attacks.",
     },
     "torrent" => AbilityData {
         num: 67,
         name: "Torrent",
-        description: "",
+        short_desc: "At 1/3 or less of its max HP, this Pokemon's offensive stat is 1.5x with Water attacks.",
+        description: "At 1/3 or less of its max HP, this Pokemon's offensive stat is 1.5x with Water attacks.",
     },
     "swarm" => AbilityData {
         num: 68,
         name: "Swarm",
-        description: "",
+        short_desc: "At 1/3 or less of its max HP, this Pokemon's offensive stat is 1.5x with Bug attacks.",
+        description: "At 1/3 or less of its max HP, this Pokemon's offensive stat is 1.5x with Bug attacks.",
     },
     "rockhead" => AbilityData {
         num: 69,
         name: "Rock Head",
-        description: "",
+        short_desc: "This Pokemon does not take recoil damage, except Struggle/Life Orb/crash damage.",
+        description: "This Pokemon does not take recoil damage, except Struggle/Life Orb/crash damage.",
     },
     "drought" => AbilityData {
         num: 70,
         name: "Drought",
-        description: "",
+        short_desc: "On switch-in, this Pokemon summons Sunny Day.",
+        description: "On switch-in, this Pokemon summons Sunny Day.",
     },
     "arenatrap" => AbilityData {
         num: 71,
         name: "Arena Trap",
-        description: "",
+        short_desc: "Prevents opposing grounded Pokemon from choosing to switch out unless they are immune to trapping.",
+        description: "Prevents opposing grounded Pokemon from choosing to switch out unless they are immune to trapping.",
     },
     "vitalspirit" => AbilityData {
         num: 72,
         name: "Vital Spirit",
-        description: "",
+        short_desc: "This Pokemon cannot fall asleep. Gaining this Ability while asleep cures it.",
+        description: "This Pokemon cannot fall asleep. Gaining this Ability while asleep cures it.",
     },
     "whitesmoke" => AbilityData {
         num: 73,
         name: "White Smoke",
-        description: "",
+        short_desc: "Prevents other Pokemon from lowering this Pokemon's stat stages.",
+        description: "Prevents other Pokemon from lowering this Pokemon's stat stages.",
     },
     "purepower" => AbilityData {
         num: 74,
         name: "Pure Power",
-        description: "",
+        short_desc: "This Pokemon's Attack is doubled.",
+        description: "This Pokemon's Attack is doubled.",
     },
     "shellarmor" => AbilityData {
         num: 75,
         name: "Shell Armor",
-        description: "",
+        short_desc: "This Pokemon cannot be struck by a critical hit.",
+        description: "This Pokemon cannot be struck by a critical hit.",
     },
     "airlock" => AbilityData {
         num: 76,
         name: "Air Lock",
-        description: "",
+        short_desc: "While this Pokemon is active, the effects of weather conditions are disabled.",
+        description: "While this Pokemon is active, the effects of weather conditions are disabled.",
     },
     "tangledfeet" => AbilityData {
         num: 77,
         name: "Tangled Feet",
-        description: "",
+        short_desc: "This Pokemon's evasiveness is doubled as long as it is confused.",
+        description: "This Pokemon's evasiveness is doubled as long as it is confused.",
     },
     "motordrive" => AbilityData {
         num: 78,
         name: "Motor Drive",
-        description: "",
+        short_desc: "This Pokemon's Speed is raised 1 stage if hit by an Electric move; Electric immunity.",
+        description: "This Pokemon's Speed is raised 1 stage if hit by an Electric move; Electric immunity.",
     },
     "rivalry" => AbilityData {
         num: 79,
         name: "Rivalry",
-        description: "",
+        short_desc: "This Pokemon's attacks do 1.25x on same gender targets; 0.75x on opposite gender.",
+        description: "This Pokemon's attacks do 1.25x on same gender targets; 0.75x on opposite gender.",
     },
     "steadfast" => AbilityData {
         num: 80,
         name: "Steadfast",
-        description: "",
+        short_desc: "If this Pokemon flinches, its Speed is raised by 1 stage.",
+        description: "If this Pokemon flinches, its Speed is raised by 1 stage.",
     },
     "snowcloak" => AbilityData {
         num: 81,
         name: "Snow Cloak",
-        description: "",
+        short_desc: "If Snow is active, this Pokemon's evasiveness is 1.25x.",
+        description: "If Snow is active, this Pokemon's evasiveness is 1.25x.",
     },
     "gluttony" => AbilityData {
         num: 82,
         name: "Gluttony",
-        description: "",
+        short_desc: "This Pokemon eats Berries at 1/2 max HP or less instead of their usual 1/4 max HP.",
+        description: "This Pokemon eats Berries at 1/2 max HP or less instead of their usual 1/4 max HP.",
     },
     "angerpoint" => AbilityData {
         num: 83,
         name: "Anger Point",
-        description: "",
+        short_desc: "If this Pokemon (not its substitute) takes a critical hit, its Attack is raised 12 stages.",
+        description: "If this Pokemon (not its substitute) takes a critical hit, its Attack is raised 12 stages.",
     },
     "unburden" => AbilityData {
         num: 84,
         name: "Unburden",
-        description: "",
+        short_desc: "Speed is doubled on held item loss; boost is lost if it switches, gets new item/Ability.",
+        description: "Speed is doubled on held item loss; boost is lost if it switches, gets new item/Ability.",
     },
     "heatproof" => AbilityData {
         num: 85,
         name: "Heatproof",
-        description: "",
+        short_desc: "The power of Fire-type attacks against this Pokemon is halved; burn damage halved.",
+        description: "The power of Fire-type attacks against this Pokemon is halved; burn damage halved.",
     },
     "simple" => AbilityData {
         num: 86,
         name: "Simple",
-        description: "",
+        short_desc: "When one of this Pokemon's stat stages is raised or lowered, the amount is doubled.",
+        description: "When one of this Pokemon's stat stages is raised or lowered, the amount is doubled.",
     },
     "dryskin" => AbilityData {
         num: 87,
         name: "Dry Skin",
-        description: "",
+        short_desc: "This Pokemon is healed 1/4 by Water, 1/8 by Rain; is hurt 1.25x by Fire, 1/8 by Sun.",
+        description: "This Pokemon is healed 1/4 by Water, 1/8 by Rain; is hurt 1.25x by Fire, 1/8 by Sun.",
     },
     "download" => AbilityData {
         num: 88,
         name: "Download",
-        description: "",
+        short_desc: "On switch-in, Attack or Sp. Atk is raised 1 stage based on the foes' weaker Defense.",
+        description: "On switch-in, Attack or Sp. Atk is raised 1 stage based on the foes' weaker Defense.",
     },
     "ironfist" => AbilityData {
         num: 89,
         name: "Iron Fist",
-        description: "",
+        short_desc: "This Pokemon's punch-based attacks have 1.2x power. Sucker Punch is not boosted.",
+        description: "This Pokemon's punch-based attacks have 1.2x power. Sucker Punch is not boosted.",
     },
     "poisonheal" => AbilityData {
         num: 90,
         name: "Poison Heal",
-        description: "",
+        short_desc: "This Pokemon is healed by 1/8 of its max HP each turn when poisoned; no HP loss.",
+        description: "This Pokemon is healed by 1/8 of its max HP each turn when poisoned; no HP loss.",
     },
     "adaptability" => AbilityData {
         num: 91,
         name: "Adaptability",
-        description: "",
+        short_desc: "This Pokemon's same-type attack bonus (STAB) is 2 instead of 1.5.",
+        description: "This Pokemon's same-type attack bonus (STAB) is 2 instead of 1.5.",
     },
     "skilllink" => AbilityData {
         num: 92,
         name: "Skill Link",
-        description: "",
+        short_desc: "This Pokemon's multi-hit attacks always hit the maximum number of times.",
+        description: "This Pokemon's multi-hit attacks always hit the maximum number of times.",
     },
     "hydration" => AbilityData {
         num: 93,
         name: "Hydration",
-        description: "",
+        short_desc: "This Pokemon has its non-volatile status cured at the end of each turn if Rain Dance is active.",
+        description: "This Pokemon has its non-volatile status cured at the end of each turn if Rain Dance is active.",
     },
     "solarpower" => AbilityData {
         num: 94,
         name: "Solar Power",
-        description: "",
+        short_desc: "If Sunny Day is active, this Pokemon's Sp. Atk is 1.5x; loses 1/8 max HP per turn.",
+        description: "If Sunny Day is active, this Pokemon's Sp. Atk is 1.5x; loses 1/8 max HP per turn.",
     },
     "quickfeet" => AbilityData {
         num: 95,
         name: "Quick Feet",
-        description: "",
+        short_desc: "If this Pokemon is statused, its Speed is 1.5x; ignores Speed drop from paralysis.",
+        description: "If this Pokemon is statused, its Speed is 1.5x; ignores Speed drop from paralysis.",
     },
     "normalize" => AbilityData {
         num: 96,
         name: "Normalize",
-        description: "",
+        short_desc: "This Pokemon's moves are changed to be Normal type and have 1.2x power.",
+        description: "This Pokemon's moves are changed to be Normal type and have 1.2x power.",
     },
     "sniper" => AbilityData {
         num: 97,
         name: "Sniper",
-        description: "",
+        short_desc: "If this Pokemon strikes with a critical hit, the damage is multiplied by 1.5.",
+        description: "If this Pokemon strikes with a critical hit, the damage is multiplied by 1.5.",
     },
     "magicguard" => AbilityData {
         num: 98,
         name: "Magic Guard",
-        description: "",
+        short_desc: "This Pokemon can only be damaged by direct attacks.",
+        description: "This Pokemon can only be damaged by direct attacks.",
     },
     "noguard" => AbilityData {
         num: 99,
         name: "No Guard",
-        description: "",
+        short_desc: "Every move used by or against this Pokemon will always hit.",
+        description: "Every move used by or against this Pokemon will always hit.",
     },
     "stall" => AbilityData {
         num: 100,
         name: "Stall",
-        description: "",
+        short_desc: "This Pokemon moves last among Pokemon using the same or greater priority moves.",
+        description: "This Pokemon moves last among Pokemon using the same or greater priority moves.",
     },
     "technician" => AbilityData {
         num: 101,
         name: "Technician",
-        description: "",
+        short_desc: "This Pokemon's moves of 60 power or less have 1.5x power, including Struggle.",
+        description: "This Pokemon's moves of 60 power or less have 1.5x power, including Struggle.",
     },
     "leafguard" => AbilityData {
         num: 102,
         name: "Leaf Guard",
-        description: "",
+        short_desc: "If Sunny Day is active, this Pokemon cannot be statused and Rest will fail for it.",
+        description: "If Sunny Day is active, this Pokemon cannot be statused and Rest will fail for it.",
     },
     "klutz" => AbilityData {
         num: 103,
         name: "Klutz",
-        description: "",
+        short_desc: "This Pokemon's held item has no effect, except Macho Brace. Fling cannot be used.",
+        description: "This Pokemon's held item has no effect, except Macho Brace. Fling cannot be used.",
     },
     "moldbreaker" => AbilityData {
         num: 104,
         name: "Mold Breaker",
-        description: "",
+        short_desc: "This Pokemon's moves and their effects ignore the Abilities of other Pokemon.",
+        description: "This Pokemon's moves and their effects ignore the Abilities of other Pokemon.",
     },
     "superluck" => AbilityData {
         num: 105,
         name: "Super Luck",
-        description: "",
+        short_desc: "This Pokemon's critical hit ratio is raised by 1 stage.",
+        description: "This Pokemon's critical hit ratio is raised by 1 stage.",
     },
     "aftermath" => AbilityData {
         num: 106,
         name: "Aftermath",
-        description: "",
+        short_desc: "If this Pokemon is KOed with a contact move, that move's user loses 1/4 its max HP.",
+        description: "If this Pokemon is KOed with a contact move, that move's user loses 1/4 its max HP.",
     },
     "anticipation" => AbilityData {
         num: 107,
         name: "Anticipation",
-        description: "",
+        short_desc: "On switch-in, this Pokemon shudders if any foe has a supereffective or OHKO move.",
+        description: "On switch-in, this Pokemon shudders if any foe has a supereffective or OHKO move.",
     },
     "forewarn" => AbilityData {
         num: 108,
         name: "Forewarn",
-        description: "",
+        short_desc: "On switch-in, this Pokemon is alerted to the foes' move with the highest power.",
+        description: "On switch-in, this Pokemon is alerted to the foes' move with the highest power.",
     },
     "unaware" => AbilityData {
         num: 109,
         name: "Unaware",
-        description: "",
+        short_desc: "This Pokemon ignores other Pokemon's stat stages when taking or doing damage.",
+        description: "This Pokemon ignores other Pokemon's stat stages when taking or doing damage.",
     },
     "tintedlens" => AbilityData {
         num: 110,
         name: "Tinted Lens",
-        description: "",
+        short_desc: "This Pokemon's attacks that are not very effective on a target deal double damage.",
+        description: "This Pokemon's attacks that are not very effective on a target deal double damage.",
     },
     "filter" => AbilityData {
         num: 111,
         name: "Filter",
-        description: "",
+        short_desc: "This Pokemon receives 3/4 damage from supereffective attacks.",
+        description: "This Pokemon receives 3/4 damage from supereffective attacks.",
     },
     "slowstart" => AbilityData {
         num: 112,
         name: "Slow Start",
-        description: "",
+        short_desc: "On switch-in, this Pokemon's Attack and Speed are halved for 5 turns.",
+        description: "On switch-in, this Pokemon's Attack and Speed are halved for 5 turns.",
     },
     "scrappy" => AbilityData {
         num: 113,
         name: "Scrappy",
-        description: "",
+        short_desc: "Fighting, Normal moves hit Ghost. Immune to Intimidate.",
+        description: "Fighting, Normal moves hit Ghost. Immune to Intimidate.",
     },
     "stormdrain" => AbilityData {
         num: 114,
         name: "Storm Drain",
-        description: "",
+        short_desc: "This Pokemon draws Water moves to itself to raise Sp. Atk by 1; Water immunity.",
+        description: "This Pokemon draws Water moves to itself to raise Sp. Atk by 1; Water immunity.",
     },
     "icebody" => AbilityData {
         num: 115,
         name: "Ice Body",
-        description: "",
+        short_desc: "If Snow is active, this Pokemon heals 1/16 of its max HP each turn.",
+        description: "If Snow is active, this Pokemon heals 1/16 of its max HP each turn.",
     },
     "solidrock" => AbilityData {
         num: 116,
         name: "Solid Rock",
-        description: "",
+        short_desc: "This Pokemon receives 3/4 damage from supereffective attacks.",
+        description: "This Pokemon receives 3/4 damage from supereffective attacks.",
     },
     "snowwarning" => AbilityData {
         num: 117,
         name: "Snow Warning",
-        description: "",
+        short_desc: "On switch-in, this Pokemon summons Snow.",
+        description: "On switch-in, this Pokemon summons Snow.",
     },
     "honeygather" => AbilityData {
         num: 118,
         name: "Honey Gather",
-        description: "",
+        short_desc: "No competitive use.",
+        description: "No competitive use.",
     },
     "frisk" => AbilityData {
         num: 119,
         name: "Frisk",
-        description: "",
+        short_desc: "On switch-in, this Pokemon identifies the held items of all opposing Pokemon.",
+        description: "On switch-in, this Pokemon identifies the held items of all opposing Pokemon.",
     },
     "reckless" => AbilityData {
         num: 120,
         name: "Reckless",
-        description: "",
+        short_desc: "This Pokemon's attacks with recoil or crash damage have 1.2x power; not Struggle.",
+        description: "This Pokemon's attacks with recoil or crash damage have 1.2x power; not Struggle.",
     },
     "multitype" => AbilityData {
         num: 121,
         name: "Multitype",
-        description: "",
+        short_desc: "If this Pokemon is an Arceus, its type changes to match its held Plate or Z-Crystal.",
+        description: "If this Pokemon is an Arceus, its type changes to match its held Plate or Z-Crystal.",
     },
     "flowergift" => AbilityData {
         num: 122,
         name: "Flower Gift",
-        description: "",
+        short_desc: "If user is Cherrim and Sunny Day is active, it and allies' Attack and Sp. Def are 1.5x.",
+        description: "If user is Cherrim and Sunny Day is active, it and allies' Attack and Sp. Def are 1.5x.",
     },
     "baddreams" => AbilityData {
         num: 123,
         name: "Bad Dreams",
-        description: "",
+        short_desc: "Causes sleeping adjacent foes to lose 1/8 of their max HP at the end of each turn.",
+        description: "Causes sleeping adjacent foes to lose 1/8 of their max HP at the end of each turn.",
     },
     "pickpocket" => AbilityData {
         num: 124,
         name: "Pickpocket",
-        description: "",
+        short_desc: "If this Pokemon has no item and is hit by a contact move, it steals the attacker's item.",
+        description: "If this Pokemon has no item and is hit by a contact move, it steals the attacker's item.",
     },
     "sheerforce" => AbilityData {
         num: 125,
         name: "Sheer Force",
-        description: "",
+        short_desc: "This Pokemon's attacks with secondary effects have 1.3x power; nullifies the effects.",
+        description: "This Pokemon's attacks with secondary effects have 1.3x power; nullifies the effects.",
     },
     "contrary" => AbilityData {
         num: 126,
         name: "Contrary",
-        description: "",
+        short_desc: "If this Pokemon has a stat stage raised it is lowered instead, and vice versa.",
+        description: "If this Pokemon has a stat stage raised it is lowered instead, and vice versa.",
     },
     "unnerve" => AbilityData {
         num: 127,
         name: "Unnerve",
-        description: "",
+        short_desc: "Prevents opposing Pokemon from eating their Berries.",
+        description: "Prevents opposing Pokemon from eating their Berries.",
     },
     "defiant" => AbilityData {
         num: 128,
         name: "Defiant",
-        description: "",
+        short_desc: "This Pokemon's Attack is raised by 2 for each of its stats that is lowered by a foe.",
+        description: "This Pokemon's Attack is raised by 2 for each of its stats that is lowered by a foe.",
     },
     "defeatist" => AbilityData {
         num: 129,
         name: "Defeatist",
-        description: "",
+        short_desc: "While this Pokemon has 1/2 or less of its max HP, its Attack and Sp. Atk are halved.",
+        description: "While this Pokemon has 1/2 or less of its max HP, its Attack and Sp. Atk are halved.",
     },
     "cursedbody" => AbilityData {
         num: 130,
         name: "Cursed Body",
-        description: "",
+        short_desc: "If this Pokemon is hit by an attack, there is a 30% chance that move gets disabled.",
+        description: "If this Pokemon is hit by an attack, there is a 30% chance that move gets disabled.",
     },
     "healer" => AbilityData {
         num: 131,
         name: "Healer",
-        description: "",
+        short_desc: "30% chance each of this Pokemon's adjacent allies has its non-volatile status cured.",
+        description: "30% chance each of this Pokemon's adjacent allies has its non-volatile status cured.",
     },
     "friendguard" => AbilityData {
         num: 132,
         name: "Friend Guard",
-        description: "",
+        short_desc: "This Pokemon's allies receive 3/4 damage from other Pokemon's attacks.",
+        description: "This Pokemon's allies receive 3/4 damage from other Pokemon's attacks.",
     },
     "weakarmor" => AbilityData {
         num: 133,
         name: "Weak Armor",
-        description: "",
+        short_desc: "If a physical attack hits this Pokemon, Defense is lowered by 1, Speed is raised by 2.",
+        description: "If a physical attack hits this Pokemon, Defense is lowered by 1, Speed is raised by 2.",
     },
     "heavymetal" => AbilityData {
         num: 134,
         name: "Heavy Metal",
-        description: "",
+        short_desc: "This Pokemon's weight is doubled.",
+        description: "This Pokemon's weight is doubled.",
     },
     "lightmetal" => AbilityData {
         num: 135,
         name: "Light Metal",
-        description: "",
+        short_desc: "This Pokemon's weight is halved.",
+        description: "This Pokemon's weight is halved.",
     },
     "multiscale" => AbilityData {
         num: 136,
         name: "Multiscale",
-        description: "",
+        short_desc: "If this Pokemon is at full HP, damage taken from attacks is halved.",
+        description: "If this Pokemon is at full HP, damage taken from attacks is halved.",
     },
     "toxicboost" => AbilityData {
         num: 137,
         name: "Toxic Boost",
-        description: "",
+        short_desc: "While this Pokemon is poisoned, its physical attacks have 1.5x power.",
+        description: "While this Pokemon is poisoned, its physical attacks have 1.5x power.",
     },
     "flareboost" => AbilityData {
         num: 138,
         name: "Flare Boost",
-        description: "",
+        short_desc: "While this Pokemon is burned, its special attacks have 1.5x power.",
+        description: "While this Pokemon is burned, its special attacks have 1.5x power.",
     },
     "harvest" => AbilityData {
         num: 139,
         name: "Harvest",
-        description: "",
+        short_desc: "If last item used is a Berry, 50% chance to restore it each end of turn. 100% in Sun.",
+        description: "If last item used is a Berry, 50% chance to restore it each end of turn. 100% in Sun.",
     },
     "telepathy" => AbilityData {
         num: 140,
         name: "Telepathy",
-        description: "",
+        short_desc: "This Pokemon does not take damage from attacks made by its allies.",
+        description: "This Pokemon does not take damage from attacks made by its allies.",
     },
     "moody" => AbilityData {
         num: 141,
         name: "Moody",
-        description: "",
+        short_desc: "Boosts a random stat (except accuracy/evasion) +2 and another stat -1 every turn.",
+        description: "Boosts a random stat (except accuracy/evasion) +2 and another stat -1 every turn.",
     },
     "overcoat" => AbilityData {
         num: 142,
         name: "Overcoat",
-        description: "",
+        short_desc: "This Pokemon is immune to powder moves, Sandstorm damage, and Effect Spore.",
+        description: "This Pokemon is immune to powder moves, Sandstorm damage, and Effect Spore.",
     },
     "poisontouch" => AbilityData {
         num: 143,
         name: "Poison Touch",
-        description: "",
+        short_desc: "This Pokemon's contact moves have a 30% chance of poisoning.",
+        description: "This Pokemon's contact moves have a 30% chance of poisoning.",
     },
     "regenerator" => AbilityData {
         num: 144,
         name: "Regenerator",
-        description: "",
+        short_desc: "This Pokemon restores 1/3 of its maximum HP, rounded down, when it switches out.",
+        description: "This Pokemon restores 1/3 of its maximum HP, rounded down, when it switches out.",
     },
     "bigpecks" => AbilityData {
         num: 145,
         name: "Big Pecks",
-        description: "",
+        short_desc: "Prevents other Pokemon from lowering this Pokemon's Defense stat stage.",
+        description: "Prevents other Pokemon from lowering this Pokemon's Defense stat stage.",
     },
     "sandrush" => AbilityData {
         num: 146,
         name: "Sand Rush",
-        description: "",
+        short_desc: "If Sandstorm is active, this Pokemon's Speed is doubled; immunity to Sandstorm.",
+        description: "If Sandstorm is active, this Pokemon's Speed is doubled; immunity to Sandstorm.",
     },
     "wonderskin" => AbilityData {
         num: 147,
         name: "Wonder Skin",
-        description: "",
+        short_desc: "Status moves with accuracy checks are 50% accurate when used on this Pokemon.",
+        description: "Status moves with accuracy checks are 50% accurate when used on this Pokemon.",
     },
     "analytic" => AbilityData {
         num: 148,
         name: "Analytic",
-        description: "",
+        short_desc: "This Pokemon's attacks have 1.3x power if it is the last to move in a turn.",
+        description: "This Pokemon's attacks have 1.3x power if it is the last to move in a turn.",
     },
     "illusion" => AbilityData {
         num: 149,
         name: "Illusion",
-        description: "",
+        short_desc: "This Pokemon appears as the last Pokemon in the party until it takes direct damage.",
+        description: "This Pokemon appears as the last Pokemon in the party until it takes direct damage.",
     },
     "imposter" => AbilityData {
         num: 150,
         name: "Imposter",
-        description: "",
+        short_desc: "On switch-in, this Pokemon Transforms into the opposing Pokemon that is facing it.",
+        description: "On switch-in, this Pokemon Transforms into the opposing Pokemon that is facing it.",
     },
     "infiltrator" => AbilityData {
         num: 151,
         name: "Infiltrator",
-        description: "",
+        short_desc: "Moves ignore substitutes and foe's Reflect/Light Screen/Safeguard/Mist/Aurora Veil.",
+        description: "Moves ignore substitutes and foe's Reflect/Light Screen/Safeguard/Mist/Aurora Veil.",
     },
     "mummy" => AbilityData {
         num: 152,
         name: "Mummy",
-        description: "",
+        short_desc: "Pokemon making contact with this Pokemon have their Ability changed to Mummy.",
+        description: "Pokemon making contact with this Pokemon have their Ability changed to Mummy.",
     },
     "moxie" => AbilityData {
         num: 153,
         name: "Moxie",
-        description: "",
+        short_desc: "This Pokemon's Attack is raised by 1 stage if it attacks and KOes another Pokemon.",
+        description: "This Pokemon's Attack is raised by 1 stage if it attacks and KOes another Pokemon.",
     },
     "justified" => AbilityData {
         num: 154,
         name: "Justified",
-        description: "",
+        short_desc: "This Pokemon's Attack is raised by 1 stage after it is damaged by a Dark-type move.",
+        description: "This Pokemon's Attack is raised by 1 stage after it is damaged by a Dark-type move.",
     },
     "rattled" => AbilityData {
         num: 155,
         name: "Rattled",
-        description: "",
+        short_desc: "Speed is raised 1 stage if hit by a Bug-, Dark-, or Ghost-type attack, or Intimidated.",
+        description: "Speed is raised 1 stage if hit by a Bug-, Dark-, or Ghost-type attack, or Intimidated.",
     },
     "magicbounce" => AbilityData {
         num: 156,
         name: "Magic Bounce",
-        description: "",
+        short_desc: "This Pokemon blocks certain Status moves and bounces them back to the user.",
+        description: "This Pokemon blocks certain Status moves and bounces them back to the user.",
     },
     "sapsipper" => AbilityData {
         num: 157,
         name: "Sap Sipper",
-        description: "",
+        short_desc: "This Pokemon's Attack is raised 1 stage if hit by a Grass move; Grass immunity.",
+        description: "This Pokemon's Attack is raised 1 stage if hit by a Grass move; Grass immunity.",
     },
     "prankster" => AbilityData {
         num: 158,
         name: "Prankster",
-        description: "",
+        short_desc: "This Pokemon's Status moves have priority raised by 1, but Dark types are immune.",
+        description: "This Pokemon's Status moves have priority raised by 1, but Dark types are immune.",
     },
     "sandforce" => AbilityData {
         num: 159,
         name: "Sand Force",
-        description: "",
+        short_desc: "This Pokemon's Ground/Rock/Steel attacks do 1.3x in Sandstorm; immunity to it.",
+        description: "This Pokemon's Ground/Rock/Steel attacks do 1.3x in Sandstorm; immunity to it.",
     },
     "ironbarbs" => AbilityData {
         num: 160,
         name: "Iron Barbs",
-        description: "",
+        short_desc: "Pokemon making contact with this Pokemon lose 1/8 of their max HP.",
+        description: "Pokemon making contact with this Pokemon lose 1/8 of their max HP.",
     },
     "zenmode" => AbilityData {
         num: 161,
         name: "Zen Mode",
-        description: "",
+        short_desc: "If Darmanitan, at end of turn changes Mode to Standard if > 1/2 max HP, else Zen.",
+        description: "If Darmanitan, at end of turn changes Mode to Standard if > 1/2 max HP, else Zen.",
     },
     "victorystar" => AbilityData {
         num: 162,
         name: "Victory Star",
-        description: "",
+        short_desc: "This Pokemon and its allies' moves have their accuracy multiplied by 1.1.",
+        description: "This Pokemon and its allies' moves have their accuracy multiplied by 1.1.",
     },
     "turboblaze" => AbilityData {
         num: 163,
         name: "Turboblaze",
-        description: "",
+        short_desc: "This Pokemon's moves and their effects ignore the Abilities of other Pokemon.",
+        description: "This Pokemon's moves and their effects ignore the Abilities of other Pokemon.",
     },
     "teravolt" => AbilityData {
         num: 164,
         name: "Teravolt",
-        description: "",
+        short_desc: "This Pokemon's moves and their effects ignore the Abilities of other Pokemon.",
+        description: "This Pokemon's moves and their effects ignore the Abilities of other Pokemon.",
     },
     "aromaveil" => AbilityData {
         num: 165,
         name: "Aroma Veil",
-        description: "",
+        short_desc: "Protects user/allies from Attract, Disable, Encore, Heal Block, Taunt, and Torment.",
+        description: "Protects user/allies from Attract, Disable, Encore, Heal Block, Taunt, and Torment.",
     },
     "flowerveil" => AbilityData {
         num: 166,
         name: "Flower Veil",
-        description: "",
+        short_desc: "This side's Grass types can't have stats lowered or status inflicted by other Pokemon.",
+        description: "This side's Grass types can't have stats lowered or status inflicted by other Pokemon.",
     },
     "cheekpouch" => AbilityData {
         num: 167,
         name: "Cheek Pouch",
-        description: "",
+        short_desc: "If this Pokemon eats a Berry, it restores 1/3 of its max HP after the Berry's effect.",
+        description: "If this Pokemon eats a Berry, it restores 1/3 of its max HP after the Berry's effect.",
     },
     "protean" => AbilityData {
         num: 168,
         name: "Protean",
-        description: "",
+        short_desc: "This Pokemon's type changes to the type of the move it is using. Once per switch-in.",
+        description: "This Pokemon's type changes to the type of the move it is using. Once per switch-in.",
     },
     "furcoat" => AbilityData {
         num: 169,
         name: "Fur Coat",
-        description: "",
+        short_desc: "This Pokemon's Defense is doubled.",
+        description: "This Pokemon's Defense is doubled.",
     },
     "magician" => AbilityData {
         num: 170,
         name: "Magician",
-        description: "",
+        short_desc: "If this Pokemon has no item and hits a Pokemon with an attack, it steals the target's item.",
+        description: "If this Pokemon has no item and hits a Pokemon with an attack, it steals the target's item.",
     },
     "bulletproof" => AbilityData {
         num: 171,
         name: "Bulletproof",
-        description: "",
+        short_desc: "This Pokemon is immune to bullet moves.",
+        description: "This Pokemon is immune to bullet moves.",
     },
     "competitive" => AbilityData {
         num: 172,
         name: "Competitive",
-        description: "",
+        short_desc: "This Pokemon's Sp. Atk is raised by 2 for each of its stats that is lowered by a foe.",
+        description: "This Pokemon's Sp. Atk is raised by 2 for each of its stats that is lowered by a foe.",
     },
     "strongjaw" => AbilityData {
         num: 173,
         name: "Strong Jaw",
-        description: "",
+        short_desc: "This Pokemon's bite-based attacks have 1.5x power. Bug Bite is not boosted.",
+        description: "This Pokemon's bite-based attacks have 1.5x power. Bug Bite is not boosted.",
     },
     "refrigerate" => AbilityData {
         num: 174,
         name: "Refrigerate",
-        description: "",
+        short_desc: "This Pokemon's Normal-type moves become Ice type and have 1.2x power.",
+        description: "This Pokemon's Normal-type moves become Ice type and have 1.2x power.",
     },
     "sweetveil" => AbilityData {
         num: 175,
         name: "Sweet Veil",
-        description: "",
+        short_desc: "This Pokemon and its allies cannot fall asleep.",
+        description: "This Pokemon and its allies cannot fall asleep.",
     },
     "stancechange" => AbilityData {
         num: 176,
         name: "Stance Change",
-        description: "",
+        short_desc: "If Aegislash, changes Forme to Blade before attacks and Shield before King's Shield.",
+        description: "If Aegislash, changes Forme to Blade before attacks and Shield before King's Shield.",
     },
     "galewings" => AbilityData {
         num: 177,
         name: "Gale Wings",
-        description: "",
+        short_desc: "If this Pokemon is at full HP, its Flying-type moves have their priority increased by 1.",
+        description: "If this Pokemon is at full HP, its Flying-type moves have their priority increased by 1.",
     },
     "megalauncher" => AbilityData {
         num: 178,
         name: "Mega Launcher",
-        description: "",
+        short_desc: "This Pokemon's pulse moves have 1.5x power. Heal Pulse heals 3/4 target's max HP.",
+        description: "This Pokemon's pulse moves have 1.5x power. Heal Pulse heals 3/4 target's max HP.",
     },
     "grasspelt" => AbilityData {
         num: 179,
         name: "Grass Pelt",
-        description: "",
+        short_desc: "If Grassy Terrain is active, this Pokemon's Defense is multiplied by 1.5.",
+        description: "If Grassy Terrain is active, this Pokemon's Defense is multiplied by 1.5.",
     },
     "symbiosis" => AbilityData {
         num: 180,
         name: "Symbiosis",
-        description: "",
+        short_desc: "If an ally uses its item, this Pokemon gives its item to that ally immediately.",
+        description: "If an ally uses its item, this Pokemon gives its item to that ally immediately.",
     },
     "toughclaws" => AbilityData {
         num: 181,
         name: "Tough Claws",
-        description: "",
+        short_desc: "This Pokemon's contact moves have their power multiplied by 1.3.",
+        description: "This Pokemon's contact moves have their power multiplied by 1.3.",
     },
     "pixilate" => AbilityData {
         num: 182,
         name: "Pixilate",
-        description: "",
+        short_desc: "This Pokemon's Normal-type moves become Fairy type and have 1.2x power.",
+        description: "This Pokemon's Normal-type moves become Fairy type and have 1.2x power.",
     },
     "gooey" => AbilityData {
         num: 183,
         name: "Gooey",
-        description: "",
+        short_desc: "Pokemon making contact with this Pokemon have their Speed lowered by 1 stage.",
+        description: "Pokemon making contact with this Pokemon have their Speed lowered by 1 stage.",
     },
     "aerilate" => AbilityData {
         num: 184,
         name: "Aerilate",
-        description: "",
+        short_desc: "This Pokemon's Normal-type moves become Flying type and have 1.2x power.",
+        description: "This Pokemon's Normal-type moves become Flying type and have 1.2x power.",
     },
     "parentalbond" => AbilityData {
         num: 185,
         name: "Parental Bond",
-        description: "",
+        short_desc: "This Pokemon's damaging moves hit twice. The second hit has its damage quartered.",
+        description: "This Pokemon's damaging moves hit twice. The second hit has its damage quartered.",
     },
     "darkaura" => AbilityData {
         num: 186,
         name: "Dark Aura",
-        description: "",
+        short_desc: "While this Pokemon is active, a Dark move used by any Pokemon has 1.33x power.",
+        description: "While this Pokemon is active, a Dark move used by any Pokemon has 1.33x power.",
     },
     "fairyaura" => AbilityData {
         num: 187,
         name: "Fairy Aura",
-        description: "",
+        short_desc: "While this Pokemon is active, a Fairy move used by any Pokemon has 1.33x power.",
+        description: "While this Pokemon is active, a Fairy move used by any Pokemon has 1.33x power.",
     },
     "aurabreak" => AbilityData {
         num: 188,
         name: "Aura Break",
-        description: "",
+        short_desc: "While this Pokemon is active, the Dark Aura and Fairy Aura power modifier is 0.75x.",
+        description: "While this Pokemon is active, the Dark Aura and Fairy Aura power modifier is 0.75x.",
     },
     "primordialsea" => AbilityData {
         num: 189,
         name: "Primordial Sea",
-        description: "",
+        short_desc: "On switch-in, heavy rain begins until this Ability is not active in battle.",
+        description: "On switch-in, heavy rain begins until this Ability is not active in battle.",
     },
     "desolateland" => AbilityData {
         num: 190,
         name: "Desolate Land",
-        description: "",
+        short_desc: "On switch-in, extremely harsh sunlight begins until this Ability is not active in battle.",
+        description: "On switch-in, extremely harsh sunlight begins until this Ability is not active in battle.",
     },
     "deltastream" => AbilityData {
         num: 191,
         name: "Delta Stream",
-        description: "",
+        short_desc: "On switch-in, strong winds begin until this Ability is not active in battle.",
+        description: "On switch-in, strong winds begin until this Ability is not active in battle.",
     },
     "stamina" => AbilityData {
         num: 192,
         name: "Stamina",
-        description: "",
+        short_desc: "This Pokemon's Defense is raised by 1 stage after it is damaged by a move.",
+        description: "This Pokemon's Defense is raised by 1 stage after it is damaged by a move.",
     },
     "wimpout" => AbilityData {
         num: 193,
         name: "Wimp Out",
-        description: "",
+        short_desc: "This Pokemon switches out when it reaches 1/2 or less of its maximum HP.",
+        description: "This Pokemon switches out when it reaches 1/2 or less of its maximum HP.",
     },
     "emergencyexit" => AbilityData {
         num: 194,
         name: "Emergency Exit",
-        description: "",
+        short_desc: "This Pokemon switches out when it reaches 1/2 or less of its maximum HP.",
+        description: "This Pokemon switches out when it reaches 1/2 or less of its maximum HP.",
     },
     "watercompaction" => AbilityData {
         num: 195,
         name: "Water Compaction",
-        description: "",
+        short_desc: "This Pokemon's Defense is raised 2 stages after it is damaged by a Water-type move.",
+        description: "This Pokemon's Defense is raised 2 stages after it is damaged by a Water-type move.",
     },
     "merciless" => AbilityData {
         num: 196,
         name: "Merciless",
-        description: "",
+        short_desc: "This Pokemon's attacks are critical hits if the target is poisoned.",
+        description: "This Pokemon's attacks are critical hits if the target is poisoned.",
     },
     "shieldsdown" => AbilityData {
         num: 197,
         name: "Shields Down",
-        description: "",
+        short_desc: "If Minior, switch-in/end of turn: Core at 1/2 max HP or less, else Meteor.",
+        description: "If Minior, switch-in/end of turn: Core at 1/2 max HP or less, else Meteor.",
     },
     "stakeout" => AbilityData {
         num: 198,
         name: "Stakeout",
-        description: "",
+        short_desc: "This Pokemon's offensive stat is doubled against a target that switched in this turn.",
+        description: "This Pokemon's offensive stat is doubled against a target that switched in this turn.",
     },
     "waterbubble" => AbilityData {
         num: 199,
         name: "Water Bubble",
-        description: "",
+        short_desc: "This Pokemon's Water power is 2x; it can't be burned; Fire power against it is halved.",
+        description: "This Pokemon's Water power is 2x; it can't be burned; Fire power against it is halved.",
     },
     "steelworker" => AbilityData {
         num: 200,
         name: "Steelworker",
-        description: "",
+        short_desc: "This Pokemon's offensive stat is multiplied by 1.5 while using a Steel-type attack.",
+        description: "This Pokemon's offensive stat is multiplied by 1.5 while using a Steel-type attack.",
     },
     "berserk" => AbilityData {
         num: 201,
         name: "Berserk",
-        description: "",
+        short_desc: "This Pokemon's Sp. Atk is raised by 1 when it reaches 1/2 or less of its max HP.",
+        description: "This Pokemon's Sp. Atk is raised by 1 when it reaches 1/2 or less of its max HP.",
     },
     "slushrush" => AbilityData {
         num: 202,
         name: "Slush Rush",
-        description: "",
+        short_desc: "If Snow is active, this Pokemon's Speed is doubled.",
+        description: "If Snow is active, this Pokemon's Speed is doubled.",
     },
     "longreach" => AbilityData {
         num: 203,
         name: "Long Reach",
-        description: "",
+        short_desc: "This Pokemon's attacks do not make contact with the target.",
+        description: "This Pokemon's attacks do not make contact with the target.",
     },
     "liquidvoice" => AbilityData {
         num: 204,
         name: "Liquid Voice",
-        description: "",
+        short_desc: "This Pokemon's sound-based moves become Water type.",
+        description: "This Pokemon's sound-based moves become Water type.",
     },
     "triage" => AbilityData {
         num: 205,
         name: "Triage",
-        description: "",
+        short_desc: "This Pokemon's healing moves have their priority increased by 3.",
+        description: "This Pokemon's healing moves have their priority increased by 3.",
     },
     "galvanize" => AbilityData {
         num: 206,
         name: "Galvanize",
-        description: "",
+        short_desc: "This Pokemon's Normal-type moves become Electric type and have 1.2x power.",
+        description: "This Pokemon's Normal-type moves become Electric type and have 1.2x power.",
     },
     "surgesurfer" => AbilityData {
         num: 207,
         name: "Surge Surfer",
-        description: "",
+        short_desc: "If Electric Terrain is active, this Pokemon's Speed is doubled.",
+        description: "If Electric Terrain is active, this Pokemon's Speed is doubled.",
     },
     "schooling" => AbilityData {
         num: 208,
         name: "Schooling",
-        description: "",
+        short_desc: "If user is Wishiwashi, changes to School Form if it has > 1/4 max HP, else Solo Form.",
+        description: "If user is Wishiwashi, changes to School Form if it has > 1/4 max HP, else Solo Form.",
     },
     "disguise" => AbilityData {
         num: 209,
         name: "Disguise",
-        description: "",
+        short_desc: "(Mimikyu only) The first hit it takes is blocked, and it takes 1/8 HP damage instead.",
+        description: "(Mimikyu only) The first hit it takes is blocked, and it takes 1/8 HP damage instead.",
     },
     "battlebond" => AbilityData {
         num: 210,
         name: "Battle Bond",
-        description: "",
+        short_desc: "After KOing a Pokemon: becomes Ash-Greninja, Water Shuriken: 20 power, hits 3x.",
+        description: "After KOing a Pokemon: becomes Ash-Greninja, Water Shuriken: 20 power, hits 3x.",
     },
     "powerconstruct" => AbilityData {
         num: 211,
         name: "Power Construct",
-        description: "",
+        short_desc: "If Zygarde 10%/50%, changes to Complete if at 1/2 max HP or less at end of turn.",
+        description: "If Zygarde 10%/50%, changes to Complete if at 1/2 max HP or less at end of turn.",
     },
     "corrosion" => AbilityData {
         num: 212,
         name: "Corrosion",
-        description: "",
+        short_desc: "This Pokemon can poison or badly poison a Pokemon regardless of its typing.",
+        description: "This Pokemon can poison or badly poison a Pokemon regardless of its typing.",
     },
     "comatose" => AbilityData {
         num: 213,
         name: "Comatose",
-        description: "",
+        short_desc: "This Pokemon cannot be statused, and is considered to be asleep.",
+        description: "This Pokemon cannot be statused, and is considered to be asleep.",
     },
     "queenlymajesty" => AbilityData {
         num: 214,
         name: "Queenly Majesty",
-        description: "",
+        short_desc: "This Pokemon and its allies are protected from opposing priority moves.",
+        description: "This Pokemon and its allies are protected from opposing priority moves.",
     },
     "innardsout" => AbilityData {
         num: 215,
         name: "Innards Out",
-        description: "",
+        short_desc: "If this Pokemon is KOed with a move, that move's user loses an equal amount of HP.",
+        description: "If this Pokemon is KOed with a move, that move's user loses an equal amount of HP.",
     },
     "dancer" => AbilityData {
         num: 216,
         name: "Dancer",
-        description: "",
+        short_desc: "After another Pokemon uses a dance move, this Pokemon uses the same move.",
+        description: "After another Pokemon uses a dance move, this Pokemon uses the same move.",
     },
     "battery" => AbilityData {
         num: 217,
         name: "Battery",
-        description: "",
+        short_desc: "This Pokemon's allies have the power of their special attacks multiplied by 1.3.",
+        description: "This Pokemon's allies have the power of their special attacks multiplied by 1.3.",
     },
     "fluffy" => AbilityData {
         num: 218,
         name: "Fluffy",
-        description: "",
+        short_desc: "This Pokemon takes 1/2 damage from contact moves, 2x damage from Fire moves.",
+        description: "This Pokemon takes 1/2 damage from contact moves, 2x damage from Fire moves.",
     },
     "dazzling" => AbilityData {
         num: 219,
         name: "Dazzling",
-        description: "",
+        short_desc: "This Pokemon and its allies are protected from opposing priority moves.",
+        description: "This Pokemon and its allies are protected from opposing priority moves.",
     },
     "soulheart" => AbilityData {
         num: 220,
         name: "Soul-Heart",
-        description: "",
+        short_desc: "This Pokemon's Sp. Atk is raised by 1 stage when another Pokemon faints.",
+        description: "This Pokemon's Sp. Atk is raised by 1 stage when another Pokemon faints.",
     },
     "tanglinghair" => AbilityData {
         num: 221,
         name: "Tangling Hair",
-        description: "",
+        short_desc: "Pokemon making contact with this Pokemon have their Speed lowered by 1 stage.",
+        description: "Pokemon making contact with this Pokemon have their Speed lowered by 1 stage.",
     },
     "receiver" => AbilityData {
         num: 222,
         name: "Receiver",
-        description: "",
+        short_desc: "This Pokemon copies the Ability of an ally that faints.",
+        description: "This Pokemon copies the Ability of an ally that faints.",
     },
     "powerofalchemy" => AbilityData {
         num: 223,
         name: "Power of Alchemy",
-        description: "",
+        short_desc: "This Pokemon copies the Ability of an ally that faints.",
+        description: "This Pokemon copies the Ability of an ally that faints.",
     },
     "beastboost" => AbilityData {
         num: 224,
         name: "Beast Boost",
-        description: "",
+        short_desc: "This Pokemon's highest stat is raised by 1 if it attacks and KOes another Pokemon.",
+        description: "This Pokemon's highest stat is raised by 1 if it attacks and KOes another Pokemon.",
     },
     "rkssystem" => AbilityData {
         num: 225,
         name: "RKS System",
-        description: "",
+        short_desc: "If this Pokemon is a Silvally, its type changes to match its held Memory.",
+        description: "If this Pokemon is a Silvally, its type changes to match its held Memory.",
     },
     "electricsurge" => AbilityData {
         num: 226,
         name: "Electric Surge",
-        description: "",
+        short_desc: "On switch-in, this Pokemon summons Electric Terrain.",
+        description: "On switch-in, this Pokemon summons Electric Terrain.",
     },
     "psychicsurge" => AbilityData {
         num: 227,
         name: "Psychic Surge",
-        description: "",
+        short_desc: "On switch-in, this Pokemon summons Psychic Terrain.",
+        description: "On switch-in, this Pokemon summons Psychic Terrain.",
     },
     "mistysurge" => AbilityData {
         num: 228,
         name: "Misty Surge",
-        description: "",
+        short_desc: "On switch-in, this Pokemon summons Misty Terrain.",
+        description: "On switch-in, this Pokemon summons Misty Terrain.",
     },
     "grassysurge" => AbilityData {
         num: 229,
         name: "Grassy Surge",
-        description: "",
+        short_desc: "On switch-in, this Pokemon summons Grassy Terrain.",
+        description: "On switch-in, this Pokemon summons Grassy Terrain.",
     },
     "fullmetalbody" => AbilityData {
         num: 230,
         name: "Full Metal Body",
-        description: "",
+        short_desc: "Prevents other Pokemon from lowering this Pokemon's stat stages.",
+        description: "Prevents other Pokemon from lowering this Pokemon's stat stages.",
     },
     "shadowshield" => AbilityData {
         num: 231,
         name: "Shadow Shield",
-        description: "",
+        short_desc: "If this Pokemon is at full HP, damage taken from attacks is halved.",
+        description: "If this Pokemon is at full HP, damage taken from attacks is halved.",
     },
     "prismarmor" => AbilityData {
         num: 232,
         name: "Prism Armor",
-        description: "",
+        short_desc: "This Pokemon receives 3/4 damage from supereffective attacks.",
+        description: "This Pokemon receives 3/4 damage from supereffective attacks.",
     },
     "neuroforce" => AbilityData {
         num: 233,
         name: "Neuroforce",
-        description: "",
+        short_desc: "This Pokemon's attacks that are super effective against the target do 1.25x damage.",
+        description: "This Pokemon's attacks that are super effective against the target do 1.25x damage.",
     },
     "intrepidsword" => AbilityData {
         num: 234,
         name: "Intrepid Sword",
-        description: "",
+        short_desc: "On switch-in, this Pokemon's Attack is raised by 1 stage. Once per battle.",
+        description: "On switch-in, this Pokemon's Attack is raised by 1 stage. Once per battle.",
     },
     "dauntlessshield" => AbilityData {
         num: 235,
         name: "Dauntless Shield",
-        description: "",
+        short_desc: "On switch-in, this Pokemon's Defense is raised by 1 stage. Once per battle.",
+        description: "On switch-in, this Pokemon's Defense is raised by 1 stage. Once per battle.",
     },
     "libero" => AbilityData {
         num: 236,
         name: "Libero",
-        description: "",
+        short_desc: "This Pokemon's type changes to the type of the move it is using. Once per switch-in.",
+        description: "This Pokemon's type changes to the type of the move it is using. Once per switch-in.",
     },
     "ballfetch" => AbilityData {
         num: 237,
         name: "Ball Fetch",
-        description: "",
+        short_desc: "No competitive use.",
+        description: "No competitive use.",
     },
     "cottondown" => AbilityData {
         num: 238,
         name: "Cotton Down",
-        description: "",
+        short_desc: "If this Pokemon is hit by an attack, every other Pokemon has its Speed lowered by 1.",
+        description: "If this Pokemon is hit by an attack, every other Pokemon has its Speed lowered by 1.",
     },
     "propellertail" => AbilityData {
         num: 239,
         name: "Propeller Tail",
-        description: "",
+        short_desc: "This Pokemon's moves cannot be redirected to a different target by any effect.",
+        description: "This Pokemon's moves cannot be redirected to a different target by any effect.",
     },
     "mirrorarmor" => AbilityData {
         num: 240,
         name: "Mirror Armor",
-        description: "",
+        short_desc: "If this Pokemon's stat stages would be lowered, the attacker's are lowered instead.",
+        description: "If this Pokemon's stat stages would be lowered, the attacker's are lowered instead.",
     },
     "gulpmissile" => AbilityData {
         num: 241,
         name: "Gulp Missile",
-        description: "",
+        short_desc: "When hit after Surf/Dive, attacker takes 1/4 max HP and -1 Defense or paralysis.",
+        description: "When hit after Surf/Dive, attacker takes 1/4 max HP and -1 Defense or paralysis.",
     },
     "stalwart" => AbilityData {
         num: 242,
         name: "Stalwart",
-        description: "",
+        short_desc: "This Pokemon's moves cannot be redirected to a different target by any effect.",
+        description: "This Pokemon's moves cannot be redirected to a different target by any effect.",
     },
     "steamengine" => AbilityData {
         num: 243,
         name: "Steam Engine",
-        description: "",
+        short_desc: "This Pokemon's Speed is raised by 6 stages after it is damaged by Fire/Water moves.",
+        description: "This Pokemon's Speed is raised by 6 stages after it is damaged by Fire/Water moves.",
     },
     "punkrock" => AbilityData {
         num: 244,
         name: "Punk Rock",
-        description: "",
+        short_desc: "This Pokemon receives 1/2 damage from sound moves. Its own have 1.3x power.",
+        description: "This Pokemon receives 1/2 damage from sound moves. Its own have 1.3x power.",
     },
     "sandspit" => AbilityData {
         num: 245,
         name: "Sand Spit",
-        description: "",
+        short_desc: "When this Pokemon is hit by an attack, the effect of Sandstorm begins.",
+        description: "When this Pokemon is hit by an attack, the effect of Sandstorm begins.",
     },
     "icescales" => AbilityData {
         num: 246,
         name: "Ice Scales",
-        description: "",
+        short_desc: "This Pokemon receives 1/2 damage from special attacks.",
+        description: "This Pokemon receives 1/2 damage from special attacks.",
     },
     "ripen" => AbilityData {
         num: 247,
         name: "Ripen",
-        description: "",
+        short_desc: "When this Pokemon eats certain Berries, the effects are doubled.",
+        description: "When this Pokemon eats certain Berries, the effects are doubled.",
     },
     "iceface" => AbilityData {
         num: 248,
         name: "Ice Face",
-        description: "",
+        short_desc: "If Eiscue, the first physical hit it takes deals 0 damage. Effect is restored in Snow.",
+        description: "If Eiscue, the first physical hit it takes deals 0 damage. Effect is restored in Snow.",
     },
     "powerspot" => AbilityData {
         num: 249,
         name: "Power Spot",
-        description: "",
+        short_desc: "This Pokemon's allies have the power of their moves multiplied by 1.3.",
+        description: "This Pokemon's allies have the power of their moves multiplied by 1.3.",
     },
     "mimicry" => AbilityData {
         num: 250,
         name: "Mimicry",
-        description: "",
+        short_desc: "This Pokemon's type changes to match the Terrain. Type reverts when Terrain ends.",
+        description: "This Pokemon's type changes to match the Terrain. Type reverts when Terrain ends.",
     },
     "screencleaner" => AbilityData {
         num: 251,
         name: "Screen Cleaner",
-        description: "",
+        short_desc: "On switch-in, the effects of Aurora Veil, Light Screen, and Reflect end for both sides.",
+        description: "On switch-in, the effects of Aurora Veil, Light Screen, and Reflect end for both sides.",
     },
     "steelyspirit" => AbilityData {
         num: 252,
         name: "Steely Spirit",
-        description: "",
+        short_desc: "This Pokemon and its allies' Steel-type moves have their power multiplied by 1.5.",
+        description: "This Pokemon and its allies' Steel-type moves have their power multiplied by 1.5.",
     },
     "perishbody" => AbilityData {
         num: 253,
         name: "Perish Body",
-        description: "",
+        short_desc: "Making contact with this Pokemon starts the Perish Song effect for it and the attacker.",
+        description: "Making contact with this Pokemon starts the Perish Song effect for it and the attacker.",
     },
     "wanderingspirit" => AbilityData {
         num: 254,
         name: "Wandering Spirit",
-        description: "",
+        short_desc: "Pokemon making contact with this Pokemon have their Ability swapped with this one.",
+        description: "Pokemon making contact with this Pokemon have their Ability swapped with this one.",
     },
     "gorillatactics" => AbilityData {
         num: 255,
         name: "Gorilla Tactics",
-        description: "",
+        short_desc: "This Pokemon's Attack is 1.5x, but it can only select the first move it executes.",
+        description: "This Pokemon's Attack is 1.5x, but it can only select the first move it executes.",
     },
     "neutralizinggas" => AbilityData {
         num: 256,
         name: "Neutralizing Gas",
-        description: "",
+        short_desc: "While this Pokemon is active, Abilities have no effect.",
+        description: "While this Pokemon is active, Abilities have no effect.",
     },
     "pastelveil" => AbilityData {
         num: 257,
         name: "Pastel Veil",
-        description: "",
+        short_desc: "This Pokemon and its allies cannot be poisoned. On switch-in, cures ally poison.",
+        description: "This Pokemon and its allies cannot be poisoned. On switch-in, cures ally poison.",
     },
     "hungerswitch" => AbilityData {
         num: 258,
         name: "Hunger Switch",
-        description: "",
+        short_desc: "If Morpeko, it changes between Full Belly and Hangry Mode at the end of each turn.",
+        description: "If Morpeko, it changes between Full Belly and Hangry Mode at the end of each turn.",
     },
     "quickdraw" => AbilityData {
         num: 259,
         name: "Quick Draw",
-        description: "",
+        short_desc: "This Pokemon has a 30% chance to move first in its priority bracket with attacking moves.",
+        description: "This Pokemon has a 30% chance to move first in its priority bracket with attacking moves.",
     },
     "unseenfist" => AbilityData {
         num: 260,
         name: "Unseen Fist",
-        description: "",
+        short_desc: "All contact moves hit through protection.",
+        description: "All contact moves hit through protection.",
     },
     "curiousmedicine" => AbilityData {
         num: 261,
         name: "Curious Medicine",
-        description: "",
+        short_desc: "On switch-in, this Pokemon's allies have their stat stages reset to 0.",
+        description: "On switch-in, this Pokemon's allies have their stat stages reset to 0.",
     },
     "transistor" => AbilityData {
         num: 262,
         name: "Transistor",
-        description: "",
+        short_desc: "This Pokemon's offensive stat is multiplied by 1.3 while using an Electric-type attack.",
+        description: "This Pokemon's offensive stat is multiplied by 1.3 while using an Electric-type attack.",
     },
     "dragonsmaw" => AbilityData {
         num: 263,
         name: "Dragon's Maw",
-        description: "",
+        short_desc: "This Pokemon's offensive stat is multiplied by 1.5 while using a Dragon-type attack.",
+        description: "This Pokemon's offensive stat is multiplied by 1.5 while using a Dragon-type attack.",
     },
     "chillingneigh" => AbilityData {
         num: 264,
         name: "Chilling Neigh",
-        description: "",
+        short_desc: "This Pokemon's Attack is raised by 1 stage if it attacks and KOes another Pokemon.",
+        description: "This Pokemon's Attack is raised by 1 stage if it attacks and KOes another Pokemon.",
     },
     "grimneigh" => AbilityData {
         num: 265,
         name: "Grim Neigh",
-        description: "",
+        short_desc: "This Pokemon's Sp. Atk is raised by 1 stage if it attacks and KOes another Pokemon.",
+        description: "This Pokemon's Sp. Atk is raised by 1 stage if it attacks and KOes another Pokemon.",
     },
     "asoneglastrier" => AbilityData {
         num: 266,
         name: "As One (Glastrier)",
-        description: "",
+        short_desc: "The combination of Unnerve and Chilling Neigh.",
+        description: "The combination of Unnerve and Chilling Neigh.",
     },
     "asonespectrier" => AbilityData {
         num: 267,
         name: "As One (Spectrier)",
-        description: "",
+        short_desc: "The combination of Unnerve and Grim Neigh.",
+        description: "The combination of Unnerve and Grim Neigh.",
     },
     "lingeringaroma" => AbilityData {
         num: 268,
         name: "Lingering Aroma",
-        description: "",
+        short_desc: "Pokemon making contact with this Pokemon have their Ability changed to Lingering Aroma.",
+        description: "Pokemon making contact with this Pokemon have their Ability changed to Lingering Aroma.",
     },
     "seedsower" => AbilityData {
         num: 269,
         name: "Seed Sower",
-        description: "",
+        short_desc: "When this Pokemon is hit by an attack, the effect of Grassy Terrain begins.",
+        description: "When this Pokemon is hit by an attack, the effect of Grassy Terrain begins.",
     },
     "thermalexchange" => AbilityData {
         num: 270,
         name: "Thermal Exchange",
-        description: "",
+        short_desc: "This Pokemon's Attack is raised by 1 when damaged by Fire moves; can't be burned.",
+        description: "This Pokemon's Attack is raised by 1 when damaged by Fire moves; can't be burned.",
     },
     "angershell" => AbilityData {
         num: 271,
         name: "Anger Shell",
-        description: "",
+        short_desc: "At 1/2 or less of this Pokemon's max HP: +1 Atk, Sp. Atk, Spe, and -1 Def, Sp. Def.",
+        description: "At 1/2 or less of this Pokemon's max HP: +1 Atk, Sp. Atk, Spe, and -1 Def, Sp. Def.",
     },
     "purifyingsalt" => AbilityData {
         num: 272,
         name: "Purifying Salt",
-        description: "",
+        short_desc: "Ghost damage to this Pokemon dealt with a halved offensive stat; can't be statused.",
+        description: "Ghost damage to this Pokemon dealt with a halved offensive stat; can't be statused.",
     },
     "wellbakedbody" => AbilityData {
         num: 273,
         name: "Well-Baked Body",
-        description: "",
+        short_desc: "This Pokemon's Defense is raised 2 stages if hit by a Fire move; Fire immunity.",
+        description: "This Pokemon's Defense is raised 2 stages if hit by a Fire move; Fire immunity.",
     },
     "windrider" => AbilityData {
         num: 274,
         name: "Wind Rider",
-        description: "",
+        short_desc: "Attack raised by 1 if hit by a wind move or Tailwind begins. Wind move immunity.",
+        description: "Attack raised by 1 if hit by a wind move or Tailwind begins. Wind move immunity.",
     },
     "guarddog" => AbilityData {
         num: 275,
         name: "Guard Dog",
-        description: "",
+        short_desc: "Immune to Intimidate. Intimidated: +1 Attack. Cannot be forced to switch out.",
+        description: "Immune to Intimidate. Intimidated: +1 Attack. Cannot be forced to switch out.",
     },
     "rockypayload" => AbilityData {
         num: 276,
         name: "Rocky Payload",
-        description: "",
+        short_desc: "This Pokemon's offensive stat is multiplied by 1.5 while using a Rock-type attack.",
+        description: "This Pokemon's offensive stat is multiplied by 1.5 while using a Rock-type attack.",
     },
     "windpower" => AbilityData {
         num: 277,
         name: "Wind Power",
-        description: "",
+        short_desc: "This Pokemon gains the Charge effect when hit by a wind move or Tailwind begins.",
+        description: "This Pokemon gains the Charge effect when hit by a wind move or Tailwind begins.",
     },
     "zerotohero" => AbilityData {
         num: 278,
         name: "Zero to Hero",
-        description: "",
+        short_desc: "If this Pokemon is a Palafin in Zero Form, switching out has it change to Hero Form.",
+        description: "If this Pokemon is a Palafin in Zero Form, switching out has it change to Hero Form.",
     },
     "commander" => AbilityData {
         num: 279,
         name: "Commander",
-        description: "",
+        short_desc: "If ally is Dondozo: this Pokemon enters Dondozo, Dondozo gets +2 to each stat.",
+        description: "If ally is Dondozo: this Pokemon enters Dondozo, Dondozo gets +2 to each stat.",
     },
     "electromorphosis" => AbilityData {
         num: 280,
         name: "Electromorphosis",
-        description: "",
+        short_desc: "This Pokemon gains the Charge effect when it takes a hit from an attack.",
+        description: "This Pokemon gains the Charge effect when it takes a hit from an attack.",
     },
     "protosynthesis" => AbilityData {
         num: 281,
         name: "Protosynthesis",
-        description: "",
+        short_desc: "Sunny Day active or Booster Energy used: highest stat is 1.3x, or 1.5x if Speed.",
+        description: "Sunny Day active or Booster Energy used: highest stat is 1.3x, or 1.5x if Speed.",
     },
     "quarkdrive" => AbilityData {
         num: 282,
         name: "Quark Drive",
-        description: "",
+        short_desc: "Electric Terrain active or Booster Energy used: highest stat is 1.3x, or 1.5x if Speed.",
+        description: "Electric Terrain active or Booster Energy used: highest stat is 1.3x, or 1.5x if Speed.",
     },
     "goodasgold" => AbilityData {
         num: 283,
         name: "Good as Gold",
-        description: "",
+        short_desc: "This Pokemon is immune to Status moves.",
+        description: "This Pokemon is immune to Status moves.",
     },
     "beadsofruin" => AbilityData {
         num: 284,
         name: "Beads of Ruin",
-        description: "",
+        short_desc: "Active Pokemon without this Ability have their Special Defense multiplied by 0.75.",
+        description: "Active Pokemon without this Ability have their Special Defense multiplied by 0.75.",
     },
     "tabletsofruin" => AbilityData {
         num: 284,
         name: "Tablets of Ruin",
-        description: "",
+        short_desc: "Active Pokemon without this Ability have their Attack multiplied by 0.75.",
+        description: "Active Pokemon without this Ability have their Attack multiplied by 0.75.",
     },
     "vesselofruin" => AbilityData {
         num: 284,
         name: "Vessel of Ruin",
-        description: "",
+        short_desc: "Active Pokemon without this Ability have their Special Attack multiplied by 0.75.",
+        description: "Active Pokemon without this Ability have their Special Attack multiplied by 0.75.",
     },
     "swordofruin" => AbilityData {
         num: 285,
         name: "Sword of Ruin",
-        description: "",
+        short_desc: "Active Pokemon without this Ability have their Defense multiplied by 0.75.",
+        description: "Active Pokemon without this Ability have their Defense multiplied by 0.75.",
     },
     "orichalcumpulse" => AbilityData {
         num: 288,
         name: "Orichalcum Pulse",
-        description: "",
+        short_desc: "On switch-in, summons Sunny Day. During Sunny Day, Attack is 1.3333x.",
+        description: "On switch-in, summons Sunny Day. During Sunny Day, Attack is 1.3333x.",
     },
     "hadronengine" => AbilityData {
         num: 289,
         name: "Hadron Engine",
-        description: "",
+        short_desc: "On switch-in, summons Electric Terrain. During Electric Terrain, Sp. Atk is 1.3333x.",
+        description: "On switch-in, summons Electric Terrain. During Electric Terrain, Sp. Atk is 1.3333x.",
     },
     "opportunist" => AbilityData {
         num: 290,
         name: "Opportunist",
-        description: "",
+        short_desc: "When an opposing Pokemon has a stat stage raised, this Pokemon copies the effect.",
+        description: "When an opposing Pokemon has a stat stage raised, this Pokemon copies the effect.",
     },
     "cudchew" => AbilityData {
         num: 291,
         name: "Cud Chew",
-        description: "",
+        short_desc: "If this Pokemon eats a Berry, it will eat that Berry again at the end of the next turn.",
+        description: "If this Pokemon eats a Berry, it will eat that Berry again at the end of the next turn.",
     },
     "sharpness" => AbilityData {
         num: 292,
         name: "Sharpness",
-        description: "",
+        short_desc: "This Pokemon's slicing moves have their power multiplied by 1.5.",
+        description: "This Pokemon's slicing moves have their power multiplied by 1.5.",
     },
     "supremeoverlord" => AbilityData {
         num: 293,
         name: "Supreme Overlord",
-        description: "",
+        short_desc: "This Pokemon's moves have 10% more power for each fainted ally, up to 5 allies.",
+        description: "This Pokemon's moves have 10% more power for each fainted ally, up to 5 allies.",
     },
     "costar" => AbilityData {
         num: 294,
         name: "Costar",
-        description: "",
+        short_desc: "On switch-in, this Pokemon copies all of its ally's stat stage changes.",
+        description: "On switch-in, this Pokemon copies all of its ally's stat stage changes.",
     },
     "toxicdebris" => AbilityData {
         num: 295,
         name: "Toxic Debris",
-        description: "",
+        short_desc: "If this Pokemon is hit by a physical attack, Toxic Spikes are set on the opposing side.",
+        description: "If this Pokemon is hit by a physical attack, Toxic Spikes are set on the opposing side.",
     },
     "armortail" => AbilityData {
         num: 296,
         name: "Armor Tail",
-        description: "",
+        short_desc: "This Pokemon and its allies are protected from opposing priority moves.",
+        description: "This Pokemon and its allies are protected from opposing priority moves.",
     },
     "eartheater" => AbilityData {
         num: 297,
         name: "Earth Eater",
-        description: "",
+        short_desc: "This Pokemon heals 1/4 of its max HP when hit by Ground moves; Ground immunity.",
+        description: "This Pokemon heals 1/4 of its max HP when hit by Ground moves; Ground immunity.",
     },
     "myceliummight" => AbilityData {
         num: 298,
         name: "Mycelium Might",
-        description: "",
+        short_desc: "This Pokemon's Status moves go last in their priority bracket and ignore Abilities.",
+        description: "This Pokemon's Status moves go last in their priority bracket and ignore Abilities.",
     },
     "hospitality" => AbilityData {
         num: 299,
         name: "Hospitality",
-        description: "",
+        short_desc: "On switch-in, this Pokemon restores 1/4 of its ally's maximum HP, rounded down.",
+        description: "On switch-in, this Pokemon restores 1/4 of its ally's maximum HP, rounded down.",
     },
     "mindseye" => AbilityData {
         num: 300,
         name: "Mind's Eye",
-        description: "",
+        short_desc: "Fighting, Normal moves hit Ghost. Accuracy can't be lowered, ignores evasiveness.",
+        description: "Fighting, Normal moves hit Ghost. Accuracy can't be lowered, ignores evasiveness.",
     },
     "embodyaspectteal" => AbilityData {
         num: 301,
         name: "Embody Aspect (Teal)",
-        description: "",
+        short_desc: "On switch-in, this Pokemon's Speed is raised by 1 stage.",
+        description: "On switch-in, this Pokemon's Speed is raised by 1 stage.",
     },
     "embodyaspectwellspring" => AbilityData {
         num: 302,
         name: "Embody Aspect (Wellspring)",
-        description: "",
+        short_desc: "On switch-in, this Pokemon's Special Defense is raised by 1 stage.",
+        description: "On switch-in, this Pokemon's Special Defense is raised by 1 stage.",
     },
     "embodyaspecthearthflame" => AbilityData {
         num: 303,
         name: "Embody Aspect (Hearthflame)",
-        description: "",
+        short_desc: "On switch-in, this Pokemon's Attack is raised by 1 stage.",
+        description: "On switch-in, this Pokemon's Attack is raised by 1 stage.",
     },
     "embodyaspectcornerstone" => AbilityData {
         num: 304,
         name: "Embody Aspect (Cornerstone)",
-        description: "",
+        short_desc: "On switch-in, this Pokemon's Defense is raised by 1 stage.",
+        description: "On switch-in, this Pokemon's Defense is raised by 1 stage.",
     },
     "toxicchain" => AbilityData {
         num: 305,
         name: "Toxic Chain",
-        description: "",
+        short_desc: "This Pokemon's moves have a 30% chance of badly poisoning.",
+        description: "This Pokemon's moves have a 30% chance of badly poisoning.",
     },
     "supersweetsyrup" => AbilityData {
         num: 306,
         name: "Supersweet Syrup",
-        description: "",
+        short_desc: "On switch-in, this Pokemon lowers the evasiveness of opponents 1 stage. Once per battle.",
+        description: "On switch-in, this Pokemon lowers the evasiveness of opponents 1 stage. Once per battle.",
     },
     "terashift" => AbilityData {
         num: 307,
         name: "Tera Shift",
-        description: "",
+        short_desc: "If this Pokemon is a Terapagos, it transforms into its Terastal Form on entry.",
+        description: "If this Pokemon is a Terapagos, it transforms into its Terastal Form on entry.",
     },
     "terashell" => AbilityData {
         num: 308,
         name: "Tera Shell",
-        description: "",
+        short_desc: "If full HP, attacks taken are not very effective. Once per switch-in.",
+        description: "If full HP, attacks taken are not very effective. Once per switch-in.",
     },
     "teraformzero" => AbilityData {
         num: 309,
         name: "Teraform Zero",
-        description: "",
+        short_desc: "Terapagos: Terastallizing ends the effects of weather and terrain. Once per battle.",
+        description: "Terapagos: Terastallizing ends the effects of weather and terrain. Once per battle.",
     },
     "poisonpuppeteer" => AbilityData {
         num: 310,
         name: "Poison Puppeteer",
-        description: "",
+        short_desc: "Pokemon poisoned by Pecharunt's moves will also become confused.",
+        description: "Pokemon poisoned by Pecharunt's moves will also become confused.",
     },
 };
@@ -1,10 +1,11 @@
-// AUTO-GENERATED by tools/extract_data.js
+// AUTO-GENERATED by `pokemon-battle-cli gen-data`. Do not edit by hand.
 use phf::phf_map;
 
 #[derive(Clone, Copy, Debug)]
 pub struct ItemData {
     pub num: i16,
     pub name: &'static str,
+    pub short_desc: &'static str,
     pub description: &'static str,
 }
 
@@ -12,2916 +13,3499 @@ pub static ITEMS: phf::Map<&'static str, ItemData> = phf_map! {
     "vilevial" => ItemData {
         num: -2,
         name: "Vile Vial",
-        description: "",
+        short_desc: "If held by a Venomicon, its Poison- and Flying-type attacks have 1.2x power.",
+        description: "If held by a Venomicon, its Poison- and Flying-type attacks have 1.2x power.",
     },
     "crucibellite" => ItemData {
         num: -1,
         name: "Crucibellite",
-        description: "",
+        short_desc: "If held by a Crucibelle, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Crucibelle, this item allows it to Mega Evolve in battle.",
     },
     "berserkgene" => ItemData {
         num: 0,
         name: "Berserk Gene",
-        description: "",
+        short_desc: "On switch-in, raises holder's Attack by 2 and confuses it. Single use.",
+        description: "On switch-in, raises holder's Attack by 2 and confuses it. Single use.",
     },
     "masterball" => ItemData {
         num: 1,
         name: "Master Ball",
-        description: "",
+        short_desc: "The best Poke Ball with the ultimate level of performance.",
+        description: "The best Poke Ball with the ultimate level of performance.",
     },
     "ultraball" => ItemData {
         num: 2,
         name: "Ultra Ball",
-        description: "",
+        short_desc: "An ultra-performance Poke Ball that provides a higher catch rate than a Great Ball.",
+        description: "An ultra-performance Poke Ball that provides a higher catch rate than a Great Ball.",
     },
     "greatball" => ItemData {
         num: 3,
         name: "Great Ball",
-        description: "",
+        short_desc: "A high-performance Poke Ball that provides a higher catch rate than a Poke Ball.",
+        description: "A high-performance Poke Ball that provides a higher catch rate than a Poke Ball.",
     },
     "pokeball" => ItemData {
         num: 4,
         name: "Poke Ball",
-        description: "",
+        short_desc: "A device for catching wild Pokemon. It is designed as a capsule system.",
+        description: "A device for catching wild Pokemon. It is designed as a capsule system.",
     },
     "safariball" => ItemData {
         num: 5,
         name: "Safari Ball",
-        description: "",
+        short_desc: "A special Poke Ball that is used only in the Safari Zone and Great Marsh.",
+        description: "A special Poke Ball that is used only in the Safari Zone and Great Marsh.",
     },
     "netball" => ItemData {
         num: 6,
         name: "Net Ball",
-        description: "",
+        short_desc: "A Poke Ball that works especially well on Water- and Bug-type Pokemon.",
+        description: "A Poke Ball that works especially well on Water- and Bug-type Pokemon.",
     },
     "diveball" => ItemData {
         num: 7,
         name: "Dive Ball",
-        description: "",
+        short_desc: "A Poke Ball that works especially well on Pokemon that live underwater.",
+        description: "A Poke Ball that works especially well on Pokemon that live underwater.",
     },
     "nestball" => ItemData {
         num: 8,
         name: "Nest Ball",
-        description: "",
+        short_desc: "A Poke Ball that works especially well on weaker Pokemon in the wild.",
+        description: "A Poke Ball that works especially well on weaker Pokemon in the wild.",
     },
     "repeatball" => ItemData {
         num: 9,
         name: "Repeat Ball",
-        description: "",
+        short_desc: "A Poke Ball that works well on Pokemon species that were previously caught.",
+        description: "A Poke Ball that works well on Pokemon species that were previously caught.",
     },
     "timerball" => ItemData {
         num: 10,
         name: "Timer Ball",
-        description: "",
+        short_desc: "A Poke Ball that becomes better the more turns there are in a battle.",
+        description: "A Poke Ball that becomes better the more turns there are in a battle.",
     },
     "luxuryball" => ItemData {
         num: 11,
         name: "Luxury Ball",
-        description: "",
+        short_desc: "A comfortable Poke Ball that makes a caught wild Pokemon quickly grow friendly.",
+        description: "A comfortable Poke Ball that makes a caught wild Pokemon quickly grow friendly.",
     },
     "premierball" => ItemData {
         num: 12,
         name: "Premier Ball",
-        description: "",
+        short_desc: "A somewhat rare Poke Ball that has been made to commemorate an event.",
+        description: "A somewhat rare Poke Ball that has been made to commemorate an event.",
     },
     "duskball" => ItemData {
         num: 13,
         name: "Dusk Ball",
-        description: "",
+        short_desc: "A Poke Ball that makes it easier to catch wild Pokemon at night or in caves.",
+        description: "A Poke Ball that makes it easier to catch wild Pokemon at night or in caves.",
     },
     "healball" => ItemData {
         num: 14,
         name: "Heal Ball",
-        description: "",
+        short_desc: "A Poke Ball that restores the captured Pokemon's HP and eliminates status.",
+        description: "A Poke Ball that restores the captured Pokemon's HP and eliminates status.",
     },
     "quickball" => ItemData {
         num: 15,
         name: "Quick Ball",
-        description: "",
+        short_desc: "A Poke Ball that provides a better catch rate at the start of a wild encounter.",
+        description: "A Poke Ball that provides a better catch rate at the start of a wild encounter.",
     },
     "cherishball" => ItemData {
         num: 16,
         name: "Cherish Ball",
-        description: "",
+        short_desc: "A quite rare Poke Ball that has been crafted to commemorate an occasion.",
+        description: "A quite rare Poke Ball that has been crafted to commemorate an occasion.",
     },
     "berryjuice" => ItemData {
         num: 43,
         name: "Berry Juice",
-        description: "",
+        short_desc: "Restores 20 HP when at 1/2 max HP or less. Single use.",
+        description: "Restores 20 HP when at 1/2 max HP or less. Single use.",
     },
     "sunstone" => ItemData {
         num: 80,
         name: "Sun Stone",
-        description: "",
+        short_desc: "Evolves Gloom, Sunkern, Cottonee, Petilili, Helioptile, and Charjabug when used.",
+        description: "Evolves Gloom, Sunkern, Cottonee, Petilili, Helioptile, and Charjabug when used.",
     },
     "moonstone" => ItemData {
         num: 81,
         name: "Moon Stone",
-        description: "",
+        short_desc: "Evolves Nidorina, Nidorino, Clefairy, Jigglypuff, Skitty, and Munna when used.",
+        description: "Evolves Nidorina, Nidorino, Clefairy, Jigglypuff, Skitty, and Munna when used.",
     },
     "firestone" => ItemData {
         num: 82,
         name: "Fire Stone",
-        description: "",
+        short_desc: "Evolves Vulpix, Growlithe, Eevee, Pansear, and Capsakid when used.",
+        description: "Evolves Vulpix, Growlithe, Eevee, Pansear, and Capsakid when used.",
     },
     "thunderstone" => ItemData {
         num: 83,
         name: "Thunder Stone",
-        description: "",
+        short_desc: "Evolves Pikachu, Eevee, Eelektrik, Charjabug, and Tadbulb when used.",
+        description: "Evolves Pikachu, Eevee, Eelektrik, Charjabug, and Tadbulb when used.",
     },
     "waterstone" => ItemData {
         num: 84,
         name: "Water Stone",
-        description: "",
+        short_desc: "Evolves Poliwhirl, Shellder, Staryu, Eevee, Lombre, and Panpour when used.",
+        description: "Evolves Poliwhirl, Shellder, Staryu, Eevee, Lombre, and Panpour when used.",
     },
     "leafstone" => ItemData {
         num: 85,
         name: "Leaf Stone",
-        description: "",
+        short_desc: "Evolves Gloom, Weepinbell, Exeggcute, Nuzleaf, Pansage, and Bramblin when used.",
+        description: "Evolves Gloom, Weepinbell, Exeggcute, Nuzleaf, Pansage, and Bramblin when used.",
     },
     "rootfossil" => ItemData {
         num: 99,
         name: "Root Fossil",
-        description: "",
+        short_desc: "Can be revived into Lileep.",
+        description: "Can be revived into Lileep.",
     },
     "clawfossil" => ItemData {
         num: 100,
         name: "Claw Fossil",
-        description: "",
+        short_desc: "Can be revived into Anorith.",
+        description: "Can be revived into Anorith.",
     },
     "helixfossil" => ItemData {
         num: 101,
         name: "Helix Fossil",
-        description: "",
+        short_desc: "Can be revived into Omanyte.",
+        description: "Can be revived into Omanyte.",
     },
     "domefossil" => ItemData {
         num: 102,
         name: "Dome Fossil",
-        description: "",
+        short_desc: "Can be revived into Kabuto.",
+        description: "Can be revived into Kabuto.",
     },
     "oldamber" => ItemData {
         num: 103,
         name: "Old Amber",
-        description: "",
+        short_desc: "Can be revived into Aerodactyl.",
+        description: "Can be revived into Aerodactyl.",
     },
     "armorfossil" => ItemData {
         num: 104,
         name: "Armor Fossil",
-        description: "",
+        short_desc: "Can be revived into Shieldon.",
+        description: "Can be revived into Shieldon.",
     },
     "skullfossil" => ItemData {
         num: 105,
         name: "Skull Fossil",
-        description: "",
+        short_desc: "Can be revived into Cranidos.",
+        description: "Can be revived into Cranidos.",
     },
     "rarebone" => ItemData {
         num: 106,
         name: "Rare Bone",
-        description: "",
+        short_desc: "No competitive use other than Fling.",
+        description: "No competitive use other than Fling.",
     },
     "shinystone" => ItemData {
         num: 107,
         name: "Shiny Stone",
-        description: "",
+        short_desc: "Evolves Togetic, Roselia, Minccino, and Floette when used.",
+        description: "Evolves Togetic, Roselia, Minccino, and Floette when used.",
     },
     "duskstone" => ItemData {
         num: 108,
         name: "Dusk Stone",
-        description: "",
+        short_desc: "Evolves Murkrow, Misdreavus, Lampent, and Doublade when used.",
+        description: "Evolves Murkrow, Misdreavus, Lampent, and Doublade when used.",
     },
     "dawnstone" => ItemData {
         num: 109,
         name: "Dawn Stone",
-        description: "",
+        short_desc: "Evolves male Kirlia into Gallade and female Snorunt into Froslass when used.",
+        description: "Evolves male Kirlia into Gallade and female Snorunt into Froslass when used.",
     },
     "ovalstone" => ItemData {
         num: 110,
         name: "Oval Stone",
-        description: "",
+        short_desc: "Evolves Happiny into Chansey when held and leveled up during the day.",
+        description: "Evolves Happiny into Chansey when held and leveled up during the day.",
     },
     "griseousorb" => ItemData {
         num: 112,
         name: "Griseous Orb",
-        description: "",
+        short_desc: "If held by a Giratina, its Ghost- and Dragon-type attacks have 1.2x power.",
+        description: "If held by a Giratina, its Ghost- and Dragon-type attacks have 1.2x power.",
     },
     "dousedrive" => ItemData {
         num: 116,
         name: "Douse Drive",
-        description: "",
+        short_desc: "Holder's Techno Blast is Water type.",
+        description: "Holder's Techno Blast is Water type.",
     },
     "shockdrive" => ItemData {
         num: 117,
         name: "Shock Drive",
-        description: "",
+        short_desc: "Holder's Techno Blast is Electric type.",
+        description: "Holder's Techno Blast is Electric type.",
     },
     "burndrive" => ItemData {
         num: 118,
         name: "Burn Drive",
-        description: "",
+        short_desc: "Holder's Techno Blast is Fire type.",
+        description: "Holder's Techno Blast is Fire type.",
     },
     "chilldrive" => ItemData {
         num: 119,
         name: "Chill Drive",
-        description: "",
+        short_desc: "Holder's Techno Blast is Ice type.",
+        description: "Holder's Techno Blast is Ice type.",
     },
     "adamantorb" => ItemData {
         num: 135,
         name: "Adamant Orb",
-        description: "",
+        short_desc: "If held by a Dialga, its Steel- and Dragon-type attacks have 1.2x power.",
+        description: "If held by a Dialga, its Steel- and Dragon-type attacks have 1.2x power.",
     },
     "lustrousorb" => ItemData {
         num: 136,
         name: "Lustrous Orb",
-        description: "",
+        short_desc: "If held by a Palkia, its Water- and Dragon-type attacks have 1.2x power.",
+        description: "If held by a Palkia, its Water- and Dragon-type attacks have 1.2x power.",
     },
     "mail" => ItemData {
         num: 137,
         name: "Mail",
-        description: "",
+        short_desc: "Cannot be given to or taken from a Pokemon, except by Thief, Covet, Knock Off, or Bug Bite.",
+        description: "Cannot be given to or taken from a Pokemon, except by Thief, Covet, Knock Off, or Bug Bite.",
     },
     "cheriberry" => ItemData {
         num: 149,
         name: "Cheri Berry",
-        description: "",
+        short_desc: "Holder cures itself if it is paralyzed. Single use.",
+        description: "Holder cures itself if it is paralyzed. Single use.",
     },
     "przcureberry" => ItemData {
         num: 149,
         name: "PRZ Cure Berry",
-        description: "",
+        short_desc: "Holder cures itself if it is paralyzed. Single use.",
+        description: "Holder cures itself if it is paralyzed. Single use.",
     },
     "chestoberry" => ItemData {
         num: 150,
         name: "Chesto Berry",
-        description: "",
+        short_desc: "Holder wakes up if it is asleep. Single use.",
+        description: "Holder wakes up if it is asleep. Single use.",
     },
     "mintberry" => ItemData {
         num: 150,
         name: "Mint Berry",
-        description: "",
+        short_desc: "Holder wakes up if it is asleep. Single use.",
+        description: "Holder wakes up if it is asleep. Single use.",
     },
     "pechaberry" => ItemData {
         num: 151,
         name: "Pecha Berry",
-        description: "",
+        short_desc: "Holder is cured if it is poisoned. Single use.",
+        description: "Holder is cured if it is poisoned. Single use.",
     },
     "psncureberry" => ItemData {
         num: 151,
         name: "PSN Cure Berry",
-        description: "",
+        short_desc: "Holder is cured if it is poisoned. Single use.",
+        description: "Holder is cured if it is poisoned. Single use.",
     },
     "iceberry" => ItemData {
         num: 152,
         name: "Ice Berry",
-        description: "",
+        short_desc: "Holder is cured if it is burned. Single use.",
+        description: "Holder is cured if it is burned. Single use.",
     },
     "rawstberry" => ItemData {
         num: 152,
         name: "Rawst Berry",
-        description: "",
+        short_desc: "Holder is cured if it is burned. Single use.",
+        description: "Holder is cured if it is burned. Single use.",
     },
     "aspearberry" => ItemData {
         num: 153,
         name: "Aspear Berry",
-        description: "",
+        short_desc: "Holder is cured if it is frozen. Single use.",
+        description: "Holder is cured if it is frozen. Single use.",
     },
     "burntberry" => ItemData {
         num: 153,
         name: "Burnt Berry",
-        description: "",
+        short_desc: "Holder is cured if it is frozen. Single use.",
+        description: "Holder is cured if it is frozen. Single use.",
     },
     "leppaberry" => ItemData {
         num: 154,
         name: "Leppa Berry",
-        description: "",
+        short_desc: "Restores 10 PP to the first of the holder's moves to reach 0 PP. Single use.",
+        description: "Restores 10 PP to the first of the holder's moves to reach 0 PP. Single use.",
     },
     "mysteryberry" => ItemData {
         num: 154,
         name: "Mystery Berry",
-        description: "",
+        short_desc: "Restores 5 PP to the first of the holder's moves to reach 0 PP. Single use.",
+        description: "Restores 5 PP to the first of the holder's moves to reach 0 PP. Single use.",
     },
     "berry" => ItemData {
         num: 155,
         name: "Berry",
-        description: "",
+        short_desc: "Restores 10 HP when at 1/2 max HP or less. Single use.",
+        description: "Restores 10 HP when at 1/2 max HP or less. Single use.",
     },
     "oranberry" => ItemData {
         num: 155,
         name: "Oran Berry",
-        description: "",
+        short_desc: "Restores 10 HP when at 1/2 max HP or less. Single use.",
+        description: "Restores 10 HP when at 1/2 max HP or less. Single use.",
     },
     "bitterberry" => ItemData {
         num: 156,
         name: "Bitter Berry",
-        description: "",
+        short_desc: "Holder is cured if it is confused. Single use.",
+        description: "Holder is cured if it is confused. Single use.",
     },
     "persimberry" => ItemData {
         num: 156,
         name: "Persim Berry",
-        description: "",
+        short_desc: "Holder is cured if it is confused. Single use.",
+        description: "Holder is cured if it is confused. Single use.",
     },
     "lumberry" => ItemData {
         num: 157,
         name: "Lum Berry",
-        description: "",
+        short_desc: "Holder cures itself if it has a non-volatile status or is confused. Single use.",
+        description: "Holder cures itself if it has a non-volatile status or is confused. Single use.",
     },
     "miracleberry" => ItemData {
         num: 157,
         name: "Miracle Berry",
-        description: "",
+        short_desc: "Holder cures itself if it is confused or has a status condition. Single use.",
+        description: "Holder cures itself if it is confused or has a status condition. Single use.",
     },
     "goldberry" => ItemData {
         num: 158,
         name: "Gold Berry",
-        description: "",
+        short_desc: "Restores 30 HP when at 1/2 max HP or less. Single use.",
+        description: "Restores 30 HP when at 1/2 max HP or less. Single use.",
     },
     "sitrusberry" => ItemData {
         num: 158,
         name: "Sitrus Berry",
-        description: "",
+        short_desc: "Restores 1/4 max HP when at 1/2 max HP or less. Single use.",
+        description: "Restores 1/4 max HP when at 1/2 max HP or less. Single use.",
     },
     "figyberry" => ItemData {
         num: 159,
         name: "Figy Berry",
-        description: "",
+        short_desc: "Restores 1/3 max HP at 1/4 max HP or less; confuses if -Atk Nature. Single use.",
+        description: "Restores 1/3 max HP at 1/4 max HP or less; confuses if -Atk Nature. Single use.",
     },
     "wikiberry" => ItemData {
         num: 160,
         name: "Wiki Berry",
-        description: "",
+        short_desc: "Restores 1/3 max HP at 1/4 max HP or less; confuses if -SpA Nature. Single use.",
+        description: "Restores 1/3 max HP at 1/4 max HP or less; confuses if -SpA Nature. Single use.",
     },
     "magoberry" => ItemData {
         num: 161,
         name: "Mago Berry",
-        description: "",
+        short_desc: "Restores 1/3 max HP at 1/4 max HP or less; confuses if -Spe Nature. Single use.",
+        description: "Restores 1/3 max HP at 1/4 max HP or less; confuses if -Spe Nature. Single use.",
     },
     "aguavberry" => ItemData {
         num: 162,
         name: "Aguav Berry",
-        description: "",
+        short_desc: "Restores 1/3 max HP at 1/4 max HP or less; confuses if -SpD Nature. Single use.",
+        description: "Restores 1/3 max HP at 1/4 max HP or less; confuses if -SpD Nature. Single use.",
     },
     "iapapaberry" => ItemData {
         num: 163,
         name: "Iapapa Berry",
-        description: "",
+        short_desc: "Restores 1/3 max HP at 1/4 max HP or less; confuses if -Def Nature. Single use.",
+        description: "Restores 1/3 max HP at 1/4 max HP or less; confuses if -Def Nature. Single use.",
     },
     "razzberry" => ItemData {
         num: 164,
         name: "Razz Berry",
-        description: "",
+        short_desc: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
+        description: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
     },
     "blukberry" => ItemData {
         num: 165,
         name: "Bluk Berry",
-        description: "",
+        short_desc: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
+        description: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
     },
     "nanabberry" => ItemData {
         num: 166,
         name: "Nanab Berry",
-        description: "",
+        short_desc: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
+        description: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
     },
     "wepearberry" => ItemData {
         num: 167,
         name: "Wepear Berry",
-        description: "",
+        short_desc: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
+        description: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
     },
     "pinapberry" => ItemData {
         num: 168,
         name: "Pinap Berry",
-        description: "",
+        short_desc: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
+        description: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
     },
     "pomegberry" => ItemData {
         num: 169,
         name: "Pomeg Berry",
-        description: "",
+        short_desc: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
+        description: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
     },
     "kelpsyberry" => ItemData {
         num: 170,
         name: "Kelpsy Berry",
-        description: "",
+        short_desc: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
+        description: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
     },
     "qualotberry" => ItemData {
         num: 171,
         name: "Qualot Berry",
-        description: "",
+        short_desc: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
+        description: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
     },
     "hondewberry" => ItemData {
         num: 172,
         name: "Hondew Berry",
-        description: "",
+        short_desc: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
+        description: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
     },
     "grepaberry" => ItemData {
         num: 173,
         name: "Grepa Berry",
-        description: "",
+        short_desc: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
+        description: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
     },
     "tamatoberry" => ItemData {
         num: 174,
         name: "Tamato Berry",
-        description: "",
+        short_desc: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
+        description: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
     },
     "cornnberry" => ItemData {
         num: 175,
         name: "Cornn Berry",
-        description: "",
+        short_desc: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
+        description: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
     },
     "magostberry" => ItemData {
         num: 176,
         name: "Magost Berry",
-        description: "",
+        short_desc: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
+        description: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
     },
     "rabutaberry" => ItemData {
         num: 177,
         name: "Rabuta Berry",
-        description: "",
+        short_desc: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
+        description: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
     },
     "nomelberry" => ItemData {
         num: 178,
         name: "Nomel Berry",
-        description: "",
+        short_desc: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
+        description: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
     },
     "spelonberry" => ItemData {
         num: 179,
         name: "Spelon Berry",
-        description: "",
+        short_desc: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
+        description: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
     },
     "pamtreberry" => ItemData {
         num: 180,
         name: "Pamtre Berry",
-        description: "",
+        short_desc: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
+        description: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
     },
     "watmelberry" => ItemData {
         num: 181,
         name: "Watmel Berry",
-        description: "",
+        short_desc: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
+        description: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
     },
     "durinberry" => ItemData {
         num: 182,
         name: "Durin Berry",
-        description: "",
+        short_desc: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
+        description: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
     },
     "belueberry" => ItemData {
         num: 183,
         name: "Belue Berry",
-        description: "",
+        short_desc: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
+        description: "Cannot be eaten by the holder. No effect when eaten with Bug Bite or Pluck.",
     },
     "occaberry" => ItemData {
         num: 184,
         name: "Occa Berry",
-        description: "",
+        short_desc: "Halves damage taken from a supereffective Fire-type attack. Single use.",
+        description: "Halves damage taken from a supereffective Fire-type attack. Single use.",
     },
     "passhoberry" => ItemData {
         num: 185,
         name: "Passho Berry",
-        description: "",
+        short_desc: "Halves damage taken from a supereffective Water-type attack. Single use.",
+        description: "Halves damage taken from a supereffective Water-type attack. Single use.",
     },
     "wacanberry" => ItemData {
         num: 186,
         name: "Wacan Berry",
-        description: "",
+        short_desc: "Halves damage taken from a supereffective Electric-type attack. Single use.",
+        description: "Halves damage taken from a supereffective Electric-type attack. Single use.",
     },
     "rindoberry" => ItemData {
         num: 187,
         name: "Rindo Berry",
-        description: "",
+        short_desc: "Halves damage taken from a supereffective Grass-type attack. Single use.",
+        description: "Halves damage taken from a supereffective Grass-type attack. Single use.",
     },
     "yacheberry" => ItemData {
         num: 188,
         name: "Yache Berry",
-        description: "",
+        short_desc: "Halves damage taken from a supereffective Ice-type attack. Single use.",
+        description: "Halves damage taken from a supereffective Ice-type attack. Single use.",
     },
     "chopleberry" => ItemData {
         num: 189,
         name: "Chople Berry",
-        description: "",
+        short_desc: "Halves damage taken from a supereffective Fighting-type attack. Single use.",
+        description: "Halves damage taken from a supereffective Fighting-type attack. Single use.",
     },
     "kebiaberry" => ItemData {
         num: 190,
         name: "Kebia Berry",
-        description: "",
+        short_desc: "Halves damage taken from a supereffective Poison-type attack. Single use.",
+        description: "Halves damage taken from a supereffective Poison-type attack. Single use.",
     },
     "shucaberry" => ItemData {
         num: 191,
         name: "Shuca Berry",
-        description: "",
+        short_desc: "Halves damage taken from a supereffective Ground-type attack. Single use.",
+        description: "Halves damage taken from a supereffective Ground-type attack. Single use.",
     },
     "cobaberry" => ItemData {
         num: 192,
         name: "Coba Berry",
-        description: "",
+        short_desc: "Halves damage taken from a supereffective Flying-type attack. Single use.",
+        description: "Halves damage taken from a supereffective Flying-type attack. Single use.",
     },
     "payapaberry" => ItemData {
         num: 193,
         name: "Payapa Berry",
-        description: "",
+        short_desc: "Halves damage taken from a supereffective Psychic-type attack. Single use.",
+        description: "Halves damage taken from a supereffective Psychic-type attack. Single use.",
     },
     "tangaberry" => ItemData {
         num: 194,
         name: "Tanga Berry",
-        description: "",
+        short_desc: "Halves damage taken from a supereffective Bug-type attack. Single use.",
+        description: "Halves damage taken from a supereffective Bug-type attack. Single use.",
     },
     "chartiberry" => ItemData {
         num: 195,
         name: "Charti Berry",
-        description: "",
+        short_desc: "Halves damage taken from a supereffective Rock-type attack. Single use.",
+        description: "Halves damage taken from a supereffective Rock-type attack. Single use.",
     },
     "kasibberry" => ItemData {
         num: 196,
         name: "Kasib Berry",
-        description: "",
+        short_desc: "Halves damage taken from a supereffective Ghost-type attack. Single use.",
+        description: "Halves damage taken from a supereffective Ghost-type attack. Single use.",
     },
     "habanberry" => ItemData {
         num: 197,
         name: "Haban Berry",
-        description: "",
+        short_desc: "Halves damage taken from a supereffective Dragon-type attack. Single use.",
+        description: "Halves damage taken from a supereffective Dragon-type attack. Single use.",
     },
     "colburberry" => ItemData {
         num: 198,
         name: "Colbur Berry",
-        description: "",
+        short_desc: "Halves damage taken from a supereffective Dark-type attack. Single use.",
+        description: "Halves damage taken from a supereffective Dark-type attack. Single use.",
     },
     "babiriberry" => ItemData {
         num: 199,
         name: "Babiri Berry",
-        description: "",
+        short_desc: "Halves damage taken from a supereffective Steel-type attack. Single use.",
+        description: "Halves damage taken from a supereffective Steel-type attack. Single use.",
     },
     "chilanberry" => ItemData {
         num: 200,
         name: "Chilan Berry",
-        description: "",
+        short_desc: "Halves damage taken from a Normal-type attack. Single use.",
+        description: "Halves damage taken from a Normal-type attack. Single use.",
     },
     "liechiberry" => ItemData {
         num: 201,
         name: "Liechi Berry",
-        description: "",
+        short_desc: "Raises holder's Attack by 1 stage when at 1/4 max HP or less. Single use.",
+        description: "Raises holder's Attack by 1 stage when at 1/4 max HP or less. Single use.",
     },
     "ganlonberry" => ItemData {
         num: 202,
         name: "Ganlon Berry",
-        description: "",
+        short_desc: "Raises holder's Defense by 1 stage when at 1/4 max HP or less. Single use.",
+        description: "Raises holder's Defense by 1 stage when at 1/4 max HP or less. Single use.",
     },
     "salacberry" => ItemData {
         num: 203,
         name: "Salac Berry",
-        description: "",
+        short_desc: "Raises holder's Speed by 1 stage when at 1/4 max HP or less. Single use.",
+        description: "Raises holder's Speed by 1 stage when at 1/4 max HP or less. Single use.",
     },
     "petayaberry" => ItemData {
         num: 204,
         name: "Petaya Berry",
-        description: "",
+        short_desc: "Raises holder's Sp. Atk by 1 stage when at 1/4 max HP or less. Single use.",
+        description: "Raises holder's Sp. Atk by 1 stage when at 1/4 max HP or less. Single use.",
     },
     "apicotberry" => ItemData {
         num: 205,
         name: "Apicot Berry",
-        description: "",
+        short_desc: "Raises holder's Sp. Def by 1 stage when at 1/4 max HP or less. Single use.",
+        description: "Raises holder's Sp. Def by 1 stage when at 1/4 max HP or less. Single use.",
     },
     "lansatberry" => ItemData {
         num: 206,
         name: "Lansat Berry",
-        description: "",
+        short_desc: "Holder gains the Focus Energy effect when at 1/4 max HP or less. Single use.",
+        description: "Holder gains the Focus Energy effect when at 1/4 max HP or less. Single use.",
     },
     "starfberry" => ItemData {
         num: 207,
         name: "Starf Berry",
-        description: "",
+        short_desc: "Raises a random stat by 2 when at 1/4 max HP or less (not acc/eva). Single use.",
+        description: "Raises a random stat by 2 when at 1/4 max HP or less (not acc/eva). Single use.",
     },
     "enigmaberry" => ItemData {
         num: 208,
         name: "Enigma Berry",
-        description: "",
+        short_desc: "Restores 1/4 max HP after holder is hit by a supereffective move. Single use.",
+        description: "Restores 1/4 max HP after holder is hit by a supereffective move. Single use.",
     },
     "micleberry" => ItemData {
         num: 209,
         name: "Micle Berry",
-        description: "",
+        short_desc: "Holder's next move has 1.2x accuracy when at 1/4 max HP or less. Single use.",
+        description: "Holder's next move has 1.2x accuracy when at 1/4 max HP or less. Single use.",
     },
     "custapberry" => ItemData {
         num: 210,
         name: "Custap Berry",
-        description: "",
+        short_desc: "Holder moves first in its priority bracket when at 1/4 max HP or less. Single use.",
+        description: "Holder moves first in its priority bracket when at 1/4 max HP or less. Single use.",
     },
     "jabocaberry" => ItemData {
         num: 211,
         name: "Jaboca Berry",
-        description: "",
+        short_desc: "If holder is hit by a physical move, attacker loses 1/8 of its max HP. Single use.",
+        description: "If holder is hit by a physical move, attacker loses 1/8 of its max HP. Single use.",
     },
     "rowapberry" => ItemData {
         num: 212,
         name: "Rowap Berry",
-        description: "",
+        short_desc: "If holder is hit by a special move, attacker loses 1/8 of its max HP. Single use.",
+        description: "If holder is hit by a special move, attacker loses 1/8 of its max HP. Single use.",
     },
     "brightpowder" => ItemData {
         num: 213,
         name: "Bright Powder",
-        description: "",
+        short_desc: "The accuracy of attacks against the holder is 0.9x.",
+        description: "The accuracy of attacks against the holder is 0.9x.",
     },
     "whiteherb" => ItemData {
         num: 214,
         name: "White Herb",
-        description: "",
+        short_desc: "Restores all lowered stat stages to 0 when one is less than 0. Single use.",
+        description: "Restores all lowered stat stages to 0 when one is less than 0. Single use.",
     },
     "machobrace" => ItemData {
         num: 215,
         name: "Macho Brace",
-        description: "",
+        short_desc: "Holder's Speed is halved. The Klutz Ability does not ignore this effect.",
+        description: "Holder's Speed is halved. The Klutz Ability does not ignore this effect.",
     },
     "quickclaw" => ItemData {
         num: 217,
         name: "Quick Claw",
-        description: "",
+        short_desc: "Each turn, holder has a 20% chance to move first in its priority bracket.",
+        description: "Each turn, holder has a 20% chance to move first in its priority bracket.",
     },
     "mentalherb" => ItemData {
         num: 219,
         name: "Mental Herb",
-        description: "",
+        short_desc: "Cures holder of Attract, Disable, Encore, Heal Block, Taunt, Torment. Single use.",
+        description: "Cures holder of Attract, Disable, Encore, Heal Block, Taunt, Torment. Single use.",
     },
     "choiceband" => ItemData {
         num: 220,
         name: "Choice Band",
-        description: "",
+        short_desc: "Holder's Attack is 1.5x, but it can only select the first move it executes.",
+        description: "Holder's Attack is 1.5x, but it can only select the first move it executes.",
     },
     "kingsrock" => ItemData {
         num: 221,
         name: "King's Rock",
-        description: "",
+        short_desc: "Holder's attacks without a chance to flinch gain a 10% chance to flinch.",
+        description: "Holder's attacks without a chance to flinch gain a 10% chance to flinch.",
     },
     "silverpowder" => ItemData {
         num: 222,
         name: "Silver Powder",
-        description: "",
+        short_desc: "Holder's Bug-type attacks have 1.2x power.",
+        description: "Holder's Bug-type attacks have 1.2x power.",
     },
     "souldew" => ItemData {
         num: 225,
         name: "Soul Dew",
-        description: "",
+        short_desc: "If held by a Latias/Latios, its Dragon- and Psychic-type moves have 1.2x power.",
+        description: "If held by a Latias/Latios, its Dragon- and Psychic-type moves have 1.2x power.",
     },
     "deepseatooth" => ItemData {
         num: 226,
         name: "Deep Sea Tooth",
-        description: "",
+        short_desc: "If held by a Clamperl, its Sp. Atk is doubled.",
+        description: "If held by a Clamperl, its Sp. Atk is doubled.",
     },
     "deepseascale" => ItemData {
         num: 227,
         name: "Deep Sea Scale",
-        description: "",
+        short_desc: "If held by a Clamperl, its Sp. Def is doubled.",
+        description: "If held by a Clamperl, its Sp. Def is doubled.",
     },
     "focusband" => ItemData {
         num: 230,
         name: "Focus Band",
-        description: "",
+        short_desc: "Holder has a 10% chance to survive an attack that would KO it with 1 HP.",
+        description: "Holder has a 10% chance to survive an attack that would KO it with 1 HP.",
     },
     "scopelens" => ItemData {
         num: 232,
         name: "Scope Lens",
-        description: "",
+        short_desc: "Holder's critical hit ratio is raised by 1 stage.",
+        description: "Holder's critical hit ratio is raised by 1 stage.",
     },
     "metalcoat" => ItemData {
         num: 233,
         name: "Metal Coat",
-        description: "",
+        short_desc: "Holder's Steel-type attacks have 1.2x power.",
+        description: "Holder's Steel-type attacks have 1.2x power.",
     },
     "leftovers" => ItemData {
         num: 234,
         name: "Leftovers",
-        description: "",
+        short_desc: "At the end of every turn, holder restores 1/16 of its max HP.",
+        description: "At the end of every turn, holder restores 1/16 of its max HP.",
     },
     "dragonscale" => ItemData {
         num: 235,
         name: "Dragon Scale",
-        description: "",
+        short_desc: "Evolves Seadra into Kingdra when traded.",
+        description: "Evolves Seadra into Kingdra when traded.",
     },
     "lightball" => ItemData {
         num: 236,
         name: "Light Ball",
-        description: "",
+        short_desc: "If held by a Pikachu, its Attack and Sp. Atk are doubled.",
+        description: "If held by a Pikachu, its Attack and Sp. Atk are doubled.",
     },
     "softsand" => ItemData {
         num: 237,
         name: "Soft Sand",
-        description: "",
+        short_desc: "Holder's Ground-type attacks have 1.2x power.",
+        description: "Holder's Ground-type attacks have 1.2x power.",
     },
     "hardstone" => ItemData {
         num: 238,
         name: "Hard Stone",
-        description: "",
+        short_desc: "Holder's Rock-type attacks have 1.2x power.",
+        description: "Holder's Rock-type attacks have 1.2x power.",
     },
     "miracleseed" => ItemData {
         num: 239,
         name: "Miracle Seed",
-        description: "",
+        short_desc: "Holder's Grass-type attacks have 1.2x power.",
+        description: "Holder's Grass-type attacks have 1.2x power.",
     },
     "blackglasses" => ItemData {
         num: 240,
         name: "Black Glasses",
-        description: "",
+        short_desc: "Holder's Dark-type attacks have 1.2x power.",
+        description: "Holder's Dark-type attacks have 1.2x power.",
     },
     "blackbelt" => ItemData {
         num: 241,
         name: "Black Belt",
-        description: "",
+        short_desc: "Holder's Fighting-type attacks have 1.2x power.",
+        description: "Holder's Fighting-type attacks have 1.2x power.",
     },
     "magnet" => ItemData {
         num: 242,
         name: "Magnet",
-        description: "",
+        short_desc: "Holder's Electric-type attacks have 1.2x power.",
+        description: "Holder's Electric-type attacks have 1.2x power.",
     },
     "mysticwater" => ItemData {
         num: 243,
         name: "Mystic Water",
-        description: "",
+        short_desc: "Holder's Water-type attacks have 1.2x power.",
+        description: "Holder's Water-type attacks have 1.2x power.",
     },
     "sharpbeak" => ItemData {
         num: 244,
         name: "Sharp Beak",
-        description: "",
+        short_desc: "Holder's Flying-type attacks have 1.2x power.",
+        description: "Holder's Flying-type attacks have 1.2x power.",
     },
     "poisonbarb" => ItemData {
         num: 245,
         name: "Poison Barb",
-        description: "",
+        short_desc: "Holder's Poison-type attacks have 1.2x power.",
+        description: "Holder's Poison-type attacks have 1.2x power.",
     },
     "nevermeltice" => ItemData {
         num: 246,
         name: "Never-Melt Ice",
-        description: "",
+        short_desc: "Holder's Ice-type attacks have 1.2x power.",
+        description: "Holder's Ice-type attacks have 1.2x power.",
     },
     "spelltag" => ItemData {
         num: 247,
         name: "Spell Tag",
-        description: "",
+        short_desc: "Holder's Ghost-type attacks have 1.2x power.",
+        description: "Holder's Ghost-type attacks have 1.2x power.",
     },
     "twistedspoon" => ItemData {
         num: 248,
         name: "Twisted Spoon",
-        description: "",
+        short_desc: "Holder's Psychic-type attacks have 1.2x power.",
+        description: "Holder's Psychic-type attacks have 1.2x power.",
     },
     "charcoal" => ItemData {
         num: 249,
         name: "Charcoal",
-        description: "",
+        short_desc: "Holder's Fire-type attacks have 1.2x power.",
+        description: "Holder's Fire-type attacks have 1.2x power.",
     },
     "dragonfang" => ItemData {
         num: 250,
         name: "Dragon Fang",
-        description: "",
+        short_desc: "Holder's Dragon-type attacks have 1.2x power.",
+        description: "Holder's Dragon-type attacks have 1.2x power.",
     },
     "pinkbow" => ItemData {
         num: 251,
         name: "Pink Bow",
-        description: "",
+        short_desc: "Holder's Normal-type attacks have 1.1x power.",
+        description: "Holder's Normal-type attacks have 1.1x power.",
     },
     "polkadotbow" => ItemData {
         num: 251,
         name: "Polkadot Bow",
-        description: "",
+        short_desc: "Holder's Normal-type attacks have 1.1x power.",
+        description: "Holder's Normal-type attacks have 1.1x power.",
     },
     "silkscarf" => ItemData {
         num: 251,
         name: "Silk Scarf",
-        description: "",
+        short_desc: "Holder's Normal-type attacks have 1.2x power.",
+        description: "Holder's Normal-type attacks have 1.2x power.",
     },
     "upgrade" => ItemData {
         num: 252,
         name: "Up-Grade",
-        description: "",
+        short_desc: "Evolves Porygon into Porygon2 when traded.",
+        description: "Evolves Porygon into Porygon2 when traded.",
     },
     "shellbell" => ItemData {
         num: 253,
         name: "Shell Bell",
-        description: "",
+        short_desc: "After an attack, holder gains 1/8 of the damage in HP dealt to other Pokemon.",
+        description: "After an attack, holder gains 1/8 of the damage in HP dealt to other Pokemon.",
     },
     "seaincense" => ItemData {
         num: 254,
         name: "Sea Incense",
-        description: "",
+        short_desc: "Holder's Water-type attacks have 1.2x power.",
+        description: "Holder's Water-type attacks have 1.2x power.",
     },
     "laxincense" => ItemData {
         num: 255,
         name: "Lax Incense",
-        description: "",
+        short_desc: "The accuracy of attacks against the holder is 0.9x.",
+        description: "The accuracy of attacks against the holder is 0.9x.",
     },
     "luckypunch" => ItemData {
         num: 256,
         name: "Lucky Punch",
-        description: "",
+        short_desc: "If held by a Chansey, its critical hit ratio is raised by 2 stages.",
+        description: "If held by a Chansey, its critical hit ratio is raised by 2 stages.",
     },
     "metalpowder" => ItemData {
         num: 257,
         name: "Metal Powder",
-        description: "",
+        short_desc: "If held by a Ditto that hasn't Transformed, Defense is doubled.",
+        description: "If held by a Ditto that hasn't Transformed, Defense is doubled.",
     },
     "thickclub" => ItemData {
         num: 258,
         name: "Thick Club",
-        description: "",
+        short_desc: "If held by a Cubone or a Marowak, its Attack is doubled.",
+        description: "If held by a Cubone or a Marowak, its Attack is doubled.",
     },
     "leek" => ItemData {
         num: 259,
         name: "Leek",
-        description: "",
+        short_desc: "If held by a Farfetch'd or Sirfetch'd, its critical hit ratio is raised by 2 stages.",
+        description: "If held by a Farfetch'd or Sirfetch'd, its critical hit ratio is raised by 2 stages.",
     },
     "stick" => ItemData {
         num: 259,
         name: "Stick",
-        description: "",
+        short_desc: "If held by a Farfetch'd, its critical hit ratio is raised by 2 stages.",
+        description: "If held by a Farfetch'd, its critical hit ratio is raised by 2 stages.",
     },
     "widelens" => ItemData {
         num: 265,
         name: "Wide Lens",
-        description: "",
+        short_desc: "The accuracy of attacks by the holder is 1.1x.",
+        description: "The accuracy of attacks by the holder is 1.1x.",
     },
     "muscleband" => ItemData {
         num: 266,
         name: "Muscle Band",
-        description: "",
+        short_desc: "Holder's physical attacks have 1.1x power.",
+        description: "Holder's physical attacks have 1.1x power.",
     },
     "wiseglasses" => ItemData {
         num: 267,
         name: "Wise Glasses",
-        description: "",
+        short_desc: "Holder's special attacks have 1.1x power.",
+        description: "Holder's special attacks have 1.1x power.",
     },
     "expertbelt" => ItemData {
         num: 268,
         name: "Expert Belt",
-        description: "",
+        short_desc: "Holder's attacks that are super effective against the target do 1.2x damage.",
+        description: "Holder's attacks that are super effective against the target do 1.2x damage.",
     },
     "lightclay" => ItemData {
         num: 269,
         name: "Light Clay",
-        description: "",
+        short_desc: "Holder's use of Aurora Veil, Reflect, or Light Screen lasts 8 turns instead of 5.",
+        description: "Holder's use of Aurora Veil, Reflect, or Light Screen lasts 8 turns instead of 5.",
     },
     "lifeorb" => ItemData {
         num: 270,
         name: "Life Orb",
-        description: "",
+        short_desc: "Holder's attacks do 1.3x damage, and it loses 1/10 its max HP after the attack.",
+        description: "Holder's attacks do 1.3x damage, and it loses 1/10 its max HP after the attack.",
     },
     "powerherb" => ItemData {
         num: 271,
         name: "Power Herb",
-        description: "",
+        short_desc: "Holder's two-turn moves complete in one turn (except Sky Drop). Single use.",
+        description: "Holder's two-turn moves complete in one turn (except Sky Drop). Single use.",
     },
     "toxicorb" => ItemData {
         num: 272,
         name: "Toxic Orb",
-        description: "",
+        short_desc: "At the end of every turn, this item attempts to badly poison the holder.",
+        description: "At the end of every turn, this item attempts to badly poison the holder.",
     },
     "flameorb" => ItemData {
         num: 273,
         name: "Flame Orb",
-        description: "",
+        short_desc: "At the end of every turn, this item attempts to burn the holder.",
+        description: "At the end of every turn, this item attempts to burn the holder.",
     },
     "quickpowder" => ItemData {
         num: 274,
         name: "Quick Powder",
-        description: "",
+        short_desc: "If held by a Ditto that hasn't Transformed, Speed is doubled.",
+        description: "If held by a Ditto that hasn't Transformed, Speed is doubled.",
     },
     "focussash" => ItemData {
         num: 275,
         name: "Focus Sash",
-        description: "",
+        short_desc: "If holder's HP is full, will survive an attack that would KO it with 1 HP. Single use.",
+        description: "If holder's HP is full, will survive an attack that would KO it with 1 HP. Single use.",
     },
     "zoomlens" => ItemData {
         num: 276,
         name: "Zoom Lens",
-        description: "",
+        short_desc: "The accuracy of attacks by the holder is 1.2x if it moves after its target.",
+        description: "The accuracy of attacks by the holder is 1.2x if it moves after its target.",
     },
     "metronome" => ItemData {
         num: 277,
         name: "Metronome",
-        description: "",
+        short_desc: "Damage of moves used on consecutive turns is increased. Max 2x after 5 turns.",
+        description: "Damage of moves used on consecutive turns is increased. Max 2x after 5 turns.",
     },
     "ironball" => ItemData {
         num: 278,
         name: "Iron Ball",
-        description: "",
+        short_desc: "Holder is grounded, Speed halved. If Flying type, takes neutral Ground damage.",
+        description: "Holder is grounded, Speed halved. If Flying type, takes neutral Ground damage.",
     },
     "laggingtail" => ItemData {
         num: 279,
         name: "Lagging Tail",
-        description: "",
+        short_desc: "Holder moves last in its priority bracket.",
+        description: "Holder moves last in its priority bracket.",
     },
     "destinyknot" => ItemData {
         num: 280,
         name: "Destiny Knot",
-        description: "",
+        short_desc: "If holder becomes infatuated, the other Pokemon also becomes infatuated.",
+        description: "If holder becomes infatuated, the other Pokemon also becomes infatuated.",
     },
     "blacksludge" => ItemData {
         num: 281,
         name: "Black Sludge",
-        description: "",
+        short_desc: "Each turn, if holder is a Poison type, restores 1/16 max HP; loses 1/8 if not.",
+        description: "Each turn, if holder is a Poison type, restores 1/16 max HP; loses 1/8 if not.",
     },
     "icyrock" => ItemData {
         num: 282,
         name: "Icy Rock",
-        description: "",
+        short_desc: "Holder's use of Snowscape lasts 8 turns instead of 5.",
+        description: "Holder's use of Snowscape lasts 8 turns instead of 5.",
     },
     "smoothrock" => ItemData {
         num: 283,
         name: "Smooth Rock",
-        description: "",
+        short_desc: "Holder's use of Sandstorm lasts 8 turns instead of 5.",
+        description: "Holder's use of Sandstorm lasts 8 turns instead of 5.",
     },
     "heatrock" => ItemData {
         num: 284,
         name: "Heat Rock",
-        description: "",
+        short_desc: "Holder's use of Sunny Day lasts 8 turns instead of 5.",
+        description: "Holder's use of Sunny Day lasts 8 turns instead of 5.",
     },
     "damprock" => ItemData {
         num: 285,
         name: "Damp Rock",
-        description: "",
+        short_desc: "Holder's use of Rain Dance lasts 8 turns instead of 5.",
+        description: "Holder's use of Rain Dance lasts 8 turns instead of 5.",
     },
     "gripclaw" => ItemData {
         num: 286,
         name: "Grip Claw",
-        description: "",
+        short_desc: "Holder's partial-trapping moves always last 7 turns.",
+        description: "Holder's partial-trapping moves always last 7 turns.",
     },
     "choicescarf" => ItemData {
         num: 287,
         name: "Choice Scarf",
-        description: "",
+        short_desc: "Holder's Speed is 1.5x, but it can only select the first move it executes.",
+        description: "Holder's Speed is 1.5x, but it can only select the first move it executes.",
     },
     "stickybarb" => ItemData {
         num: 288,
         name: "Sticky Barb",
-        description: "",
+        short_desc: "Each turn, holder loses 1/8 max HP. An attacker making contact can receive it.",
+        description: "Each turn, holder loses 1/8 max HP. An attacker making contact can receive it.",
     },
     "powerbracer" => ItemData {
         num: 289,
         name: "Power Bracer",
-        description: "",
+        short_desc: "Holder's Speed is halved. The Klutz Ability does not ignore this effect.",
+        description: "Holder's Speed is halved. The Klutz Ability does not ignore this effect.",
     },
     "powerbelt" => ItemData {
         num: 290,
         name: "Power Belt",
-        description: "",
+        short_desc: "Holder's Speed is halved. The Klutz Ability does not ignore this effect.",
+        description: "Holder's Speed is halved. The Klutz Ability does not ignore this effect.",
     },
     "powerlens" => ItemData {
         num: 291,
         name: "Power Lens",
-        description: "",
+        short_desc: "Holder's Speed is halved. The Klutz Ability does not ignore this effect.",
+        description: "Holder's Speed is halved. The Klutz Ability does not ignore this effect.",
     },
     "powerband" => ItemData {
         num: 292,
         name: "Power Band",
-        description: "",
+        short_desc: "Holder's Speed is halved. The Klutz Ability does not ignore this effect.",
+        description: "Holder's Speed is halved. The Klutz Ability does not ignore this effect.",
     },
     "poweranklet" => ItemData {
         num: 293,
         name: "Power Anklet",
-        description: "",
+        short_desc: "Holder's Speed is halved. The Klutz Ability does not ignore this effect.",
+        description: "Holder's Speed is halved. The Klutz Ability does not ignore this effect.",
     },
     "powerweight" => ItemData {
         num: 294,
         name: "Power Weight",
-        description: "",
+        short_desc: "Holder's Speed is halved. The Klutz Ability does not ignore this effect.",
+        description: "Holder's Speed is halved. The Klutz Ability does not ignore this effect.",
     },
     "shedshell" => ItemData {
         num: 295,
         name: "Shed Shell",
-        description: "",
+        short_desc: "Holder may switch out even when trapped by another Pokemon, or by Ingrain.",
+        description: "Holder may switch out even when trapped by another Pokemon, or by Ingrain.",
     },
     "bigroot" => ItemData {
         num: 296,
         name: "Big Root",
-        description: "",
+        short_desc: "Holder gains 1.3x HP from draining, Aqua Ring, Ingrain, Leech Seed, Strength Sap.",
+        description: "Holder gains 1.3x HP from draining, Aqua Ring, Ingrain, Leech Seed, Strength Sap.",
     },
     "choicespecs" => ItemData {
         num: 297,
         name: "Choice Specs",
-        description: "",
+        short_desc: "Holder's Sp. Atk is 1.5x, but it can only select the first move it executes.",
+        description: "Holder's Sp. Atk is 1.5x, but it can only select the first move it executes.",
     },
     "flameplate" => ItemData {
         num: 298,
         name: "Flame Plate",
-        description: "",
+        short_desc: "Holder's Fire-type attacks have 1.2x power. Judgment is Fire type.",
+        description: "Holder's Fire-type attacks have 1.2x power. Judgment is Fire type.",
     },
     "splashplate" => ItemData {
         num: 299,
         name: "Splash Plate",
-        description: "",
+        short_desc: "Holder's Water-type attacks have 1.2x power. Judgment is Water type.",
+        description: "Holder's Water-type attacks have 1.2x power. Judgment is Water type.",
     },
     "zapplate" => ItemData {
         num: 300,
         name: "Zap Plate",
-        description: "",
+        short_desc: "Holder's Electric-type attacks have 1.2x power. Judgment is Electric type.",
+        description: "Holder's Electric-type attacks have 1.2x power. Judgment is Electric type.",
     },
     "meadowplate" => ItemData {
         num: 301,
         name: "Meadow Plate",
-        description: "",
+        short_desc: "Holder's Grass-type attacks have 1.2x power. Judgment is Grass type.",
+        description: "Holder's Grass-type attacks have 1.2x power. Judgment is Grass type.",
     },
     "icicleplate" => ItemData {
         num: 302,
         name: "Icicle Plate",
-        description: "",
+        short_desc: "Holder's Ice-type attacks have 1.2x power. Judgment is Ice type.",
+        description: "Holder's Ice-type attacks have 1.2x power. Judgment is Ice type.",
     },
     "fistplate" => ItemData {
         num: 303,
         name: "Fist Plate",
-        description: "",
+        short_desc: "Holder's Fighting-type attacks have 1.2x power. Judgment is Fighting type.",
+        description: "Holder's Fighting-type attacks have 1.2x power. Judgment is Fighting type.",
     },
     "toxicplate" => ItemData {
         num: 304,
         name: "Toxic Plate",
-        description: "",
+        short_desc: "Holder's Poison-type attacks have 1.2x power. Judgment is Poison type.",
+        description: "Holder's Poison-type attacks have 1.2x power. Judgment is Poison type.",
     },
     "earthplate" => ItemData {
         num: 305,
         name: "Earth Plate",
-        description: "",
+        short_desc: "Holder's Ground-type attacks have 1.2x power. Judgment is Ground type.",
+        description: "Holder's Ground-type attacks have 1.2x power. Judgment is Ground type.",
     },
     "skyplate" => ItemData {
         num: 306,
         name: "Sky Plate",
-        description: "",
+        short_desc: "Holder's Flying-type attacks have 1.2x power. Judgment is Flying type.",
+        description: "Holder's Flying-type attacks have 1.2x power. Judgment is Flying type.",
     },
     "mindplate" => ItemData {
         num: 307,
         name: "Mind Plate",
-        description: "",
+        short_desc: "Holder's Psychic-type attacks have 1.2x power. Judgment is Psychic type.",
+        description: "Holder's Psychic-type attacks have 1.2x power. Judgment is Psychic type.",
     },
     "insectplate" => ItemData {
         num: 308,
         name: "Insect Plate",
-        description: "",
+        short_desc: "Holder's Bug-type attacks have 1.2x power. Judgment is Bug type.",
+        description: "Holder's Bug-type attacks have 1.2x power. Judgment is Bug type.",
     },
     "stoneplate" => ItemData {
         num: 309,
         name: "Stone Plate",
-        description: "",
+        short_desc: "Holder's Rock-type attacks have 1.2x power. Judgment is Rock type.",
+        description: "Holder's Rock-type attacks have 1.2x power. Judgment is Rock type.",
     },
     "spookyplate" => ItemData {
         num: 310,
         name: "Spooky Plate",
-        description: "",
+        short_desc: "Holder's Ghost-type attacks have 1.2x power. Judgment is Ghost type.",
+        description: "Holder's Ghost-type attacks have 1.2x power. Judgment is Ghost type.",
     },
     "dracoplate" => ItemData {
         num: 311,
         name: "Draco Plate",
-        description: "",
+        short_desc: "Holder's Dragon-type attacks have 1.2x power. Judgment is Dragon type.",
+        description: "Holder's Dragon-type attacks have 1.2x power. Judgment is Dragon type.",
     },
     "dreadplate" => ItemData {
         num: 312,
         name: "Dread Plate",
-        description: "",
+        short_desc: "Holder's Dark-type attacks have 1.2x power. Judgment is Dark type.",
+        description: "Holder's Dark-type attacks have 1.2x power. Judgment is Dark type.",
     },
     "ironplate" => ItemData {
         num: 313,
         name: "Iron Plate",
-        description: "",
+        short_desc: "Holder's Steel-type attacks have 1.2x power. Judgment is Steel type.",
+        description: "Holder's Steel-type attacks have 1.2x power. Judgment is Steel type.",
     },
     "oddincense" => ItemData {
         num: 314,
         name: "Odd Incense",
-        description: "",
+        short_desc: "Holder's Psychic-type attacks have 1.2x power.",
+        description: "Holder's Psychic-type attacks have 1.2x power.",
     },
     "rockincense" => ItemData {
         num: 315,
         name: "Rock Incense",
-        description: "",
+        short_desc: "Holder's Rock-type attacks have 1.2x power.",
+        description: "Holder's Rock-type attacks have 1.2x power.",
     },
     "fullincense" => ItemData {
         num: 316,
         name: "Full Incense",
-        description: "",
+        short_desc: "Holder moves last in its priority bracket.",
+        description: "Holder moves last in its priority bracket.",
     },
     "waveincense" => ItemData {
         num: 317,
         name: "Wave Incense",
-        description: "",
+        short_desc: "Holder's Water-type attacks have 1.2x power.",
+        description: "Holder's Water-type attacks have 1.2x power.",
     },
     "roseincense" => ItemData {
         num: 318,
         name: "Rose Incense",
-        description: "",
+        short_desc: "Holder's Grass-type attacks have 1.2x power.",
+        description: "Holder's Grass-type attacks have 1.2x power.",
     },
     "protector" => ItemData {
         num: 321,
         name: "Protector",
-        description: "",
+        short_desc: "Evolves Rhydon into Rhyperior when traded.",
+        description: "Evolves Rhydon into Rhyperior when traded.",
     },
     "electirizer" => ItemData {
         num: 322,
         name: "Electirizer",
-        description: "",
+        short_desc: "Evolves Electabuzz into Electivire when traded.",
+        description: "Evolves Electabuzz into Electivire when traded.",
     },
     "magmarizer" => ItemData {
         num: 323,
         name: "Magmarizer",
-        description: "",
+        short_desc: "Evolves Magmar into Magmortar when traded.",
+        description: "Evolves Magmar into Magmortar when traded.",
     },
     "dubiousdisc" => ItemData {
         num: 324,
         name: "Dubious Disc",
-        description: "",
+        short_desc: "Evolves Porygon2 into Porygon-Z when traded.",
+        description: "Evolves Porygon2 into Porygon-Z when traded.",
     },
     "reapercloth" => ItemData {
         num: 325,
         name: "Reaper Cloth",
-        description: "",
+        short_desc: "Evolves Dusclops into Dusknoir when traded.",
+        description: "Evolves Dusclops into Dusknoir when traded.",
     },
     "razorclaw" => ItemData {
         num: 326,
         name: "Razor Claw",
-        description: "",
+        short_desc: "Holder's critical hit ratio is raised by 1 stage.",
+        description: "Holder's critical hit ratio is raised by 1 stage.",
     },
     "razorfang" => ItemData {
         num: 327,
         name: "Razor Fang",
-        description: "",
+        short_desc: "Holder's attacks without a chance to flinch gain a 10% chance to flinch.",
+        description: "Holder's attacks without a chance to flinch gain a 10% chance to flinch.",
     },
     "fastball" => ItemData {
         num: 492,
         name: "Fast Ball",
-        description: "",
+        short_desc: "A Poke Ball that makes it easier to catch Pokemon which are quick to run away.",
+        description: "A Poke Ball that makes it easier to catch Pokemon which are quick to run away.",
     },
     "levelball" => ItemData {
         num: 493,
         name: "Level Ball",
-        description: "",
+        short_desc: "A Poke Ball for catching Pokemon that are a lower level than your own.",
+        description: "A Poke Ball for catching Pokemon that are a lower level than your own.",
     },
     "lureball" => ItemData {
         num: 494,
         name: "Lure Ball",
-        description: "",
+        short_desc: "A Poke Ball for catching Pokemon hooked by a Rod when fishing.",
+        description: "A Poke Ball for catching Pokemon hooked by a Rod when fishing.",
     },
     "heavyball" => ItemData {
         num: 495,
         name: "Heavy Ball",
-        description: "",
+        short_desc: "A Poke Ball that is better than usual at catching very heavy Pokemon.",
+        description: "A Poke Ball that is better than usual at catching very heavy Pokemon.",
     },
     "loveball" => ItemData {
         num: 496,
         name: "Love Ball",
-        description: "",
+        short_desc: "A Poke Ball for catching Pokemon that are the opposite gender of your Pokemon.",
+        description: "A Poke Ball for catching Pokemon that are the opposite gender of your Pokemon.",
     },
     "friendball" => ItemData {
         num: 497,
         name: "Friend Ball",
-        description: "",
+        short_desc: "A Poke Ball that makes caught Pokemon more friendly.",
+        description: "A Poke Ball that makes caught Pokemon more friendly.",
     },
     "moonball" => ItemData {
         num: 498,
         name: "Moon Ball",
-        description: "",
+        short_desc: "A Poke Ball for catching Pokemon that evolve using the Moon Stone.",
+        description: "A Poke Ball for catching Pokemon that evolve using the Moon Stone.",
     },
     "sportball" => ItemData {
         num: 499,
         name: "Sport Ball",
-        description: "",
+        short_desc: "A special Poke Ball for the Bug-Catching Contest.",
+        description: "A special Poke Ball for the Bug-Catching Contest.",
     },
     "parkball" => ItemData {
         num: 500,
         name: "Park Ball",
-        description: "",
+        short_desc: "A special Poke Ball for the Pal Park.",
+        description: "A special Poke Ball for the Pal Park.",
     },
     "redorb" => ItemData {
         num: 534,
         name: "Red Orb",
-        description: "",
+        short_desc: "If held by Groudon, this item triggers its Primal Reversion in battle.",
+        description: "If held by Groudon, this item triggers its Primal Reversion in battle.",
     },
     "blueorb" => ItemData {
         num: 535,
         name: "Blue Orb",
-        description: "",
+        short_desc: "If held by Kyogre, this item triggers its Primal Reversion in battle.",
+        description: "If held by Kyogre, this item triggers its Primal Reversion in battle.",
     },
     "prismscale" => ItemData {
         num: 537,
         name: "Prism Scale",
-        description: "",
+        short_desc: "Evolves Feebas into Milotic when traded.",
+        description: "Evolves Feebas into Milotic when traded.",
     },
     "eviolite" => ItemData {
         num: 538,
         name: "Eviolite",
-        description: "",
+        short_desc: "If holder's species can evolve, its Defense and Sp. Def are 1.5x.",
+        description: "If holder's species can evolve, its Defense and Sp. Def are 1.5x.",
     },
     "floatstone" => ItemData {
         num: 539,
         name: "Float Stone",
-        description: "",
+        short_desc: "Holder's weight is halved.",
+        description: "Holder's weight is halved.",
     },
     "rockyhelmet" => ItemData {
         num: 540,
         name: "Rocky Helmet",
-        description: "",
+        short_desc: "If holder is hit by a contact move, the attacker loses 1/6 of its max HP.",
+        description: "If holder is hit by a contact move, the attacker loses 1/6 of its max HP.",
     },
     "airballoon" => ItemData {
         num: 541,
         name: "Air Balloon",
-        description: "",
+        short_desc: "Holder is immune to Ground-type attacks. Pops when holder is hit.",
+        description: "Holder is immune to Ground-type attacks. Pops when holder is hit.",
     },
     "redcard" => ItemData {
         num: 542,
         name: "Red Card",
-        description: "",
+        short_desc: "If holder survives a hit, attacker is forced to switch to a random ally. Single use.",
+        description: "If holder survives a hit, attacker is forced to switch to a random ally. Single use.",
     },
     "ringtarget" => ItemData {
         num: 543,
         name: "Ring Target",
-        description: "",
+        short_desc: "The holder's type immunities granted solely by its typing are negated.",
+        description: "The holder's type immunities granted solely by its typing are negated.",
     },
     "bindingband" => ItemData {
         num: 544,
         name: "Binding Band",
-        description: "",
+        short_desc: "Holder's partial-trapping moves deal 1/6 max HP per turn instead of 1/8.",
+        description: "Holder's partial-trapping moves deal 1/6 max HP per turn instead of 1/8.",
     },
     "absorbbulb" => ItemData {
         num: 545,
         name: "Absorb Bulb",
-        description: "",
+        short_desc: "Raises holder's Sp. Atk by 1 if hit by a Water-type attack. Single use.",
+        description: "Raises holder's Sp. Atk by 1 if hit by a Water-type attack. Single use.",
     },
     "cellbattery" => ItemData {
         num: 546,
         name: "Cell Battery",
-        description: "",
+        short_desc: "Raises holder's Attack by 1 if hit by an Electric-type attack. Single use.",
+        description: "Raises holder's Attack by 1 if hit by an Electric-type attack. Single use.",
     },
     "ejectbutton" => ItemData {
         num: 547,
         name: "Eject Button",
-        description: "",
+        short_desc: "If holder survives a hit, it immediately switches out to a chosen ally. Single use.",
+        description: "If holder survives a hit, it immediately switches out to a chosen ally. Single use.",
     },
     "firegem" => ItemData {
         num: 548,
         name: "Fire Gem",
-        description: "",
+        short_desc: "Holder's first successful Fire-type attack will have 1.3x power. Single use.",
+        description: "Holder's first successful Fire-type attack will have 1.3x power. Single use.",
     },
     "watergem" => ItemData {
         num: 549,
         name: "Water Gem",
-        description: "",
+        short_desc: "Holder's first successful Water-type attack will have 1.3x power. Single use.",
+        description: "Holder's first successful Water-type attack will have 1.3x power. Single use.",
     },
     "electricgem" => ItemData {
         num: 550,
         name: "Electric Gem",
-        description: "",
+        short_desc: "Holder's first successful Electric-type attack will have 1.3x power. Single use.",
+        description: "Holder's first successful Electric-type attack will have 1.3x power. Single use.",
     },
     "grassgem" => ItemData {
         num: 551,
         name: "Grass Gem",
-        description: "",
+        short_desc: "Holder's first successful Grass-type attack will have 1.3x power. Single use.",
+        description: "Holder's first successful Grass-type attack will have 1.3x power. Single use.",
     },
     "icegem" => ItemData {
         num: 552,
         name: "Ice Gem",
-        description: "",
+        short_desc: "Holder's first successful Ice-type attack will have 1.3x power. Single use.",
+        description: "Holder's first successful Ice-type attack will have 1.3x power. Single use.",
     },
     "fightinggem" => ItemData {
         num: 553,
         name: "Fighting Gem",
-        description: "",
+        short_desc: "Holder's first successful Fighting-type attack will have 1.3x power. Single use.",
+        description: "Holder's first successful Fighting-type attack will have 1.3x power. Single use.",
     },
     "poisongem" => ItemData {
         num: 554,
         name: "Poison Gem",
-        description: "",
+        short_desc: "Holder's first successful Poison-type attack will have 1.3x power. Single use.",
+        description: "Holder's first successful Poison-type attack will have 1.3x power. Single use.",
     },
     "groundgem" => ItemData {
         num: 555,
         name: "Ground Gem",
-        description: "",
+        short_desc: "Holder's first successful Ground-type attack will have 1.3x power. Single use.",
+        description: "Holder's first successful Ground-type attack will have 1.3x power. Single use.",
     },
     "flyinggem" => ItemData {
         num: 556,
         name: "Flying Gem",
-        description: "",
+        short_desc: "Holder's first successful Flying-type attack will have 1.3x power. Single use.",
+        description: "Holder's first successful Flying-type attack will have 1.3x power. Single use.",
     },
     "psychicgem" => ItemData {
         num: 557,
         name: "Psychic Gem",
-        description: "",
+        short_desc: "Holder's first successful Psychic-type attack will have 1.3x power. Single use.",
+        description: "Holder's first successful Psychic-type attack will have 1.3x power. Single use.",
     },
     "buggem" => ItemData {
         num: 558,
         name: "Bug Gem",
-        description: "",
+        short_desc: "Holder's first successful Bug-type attack will have 1.3x power. Single use.",
+        description: "Holder's first successful Bug-type attack will have 1.3x power. Single use.",
     },
     "rockgem" => ItemData {
         num: 559,
         name: "Rock Gem",
-        description: "",
+        short_desc: "Holder's first successful Rock-type attack will have 1.3x power. Single use.",
+        description: "Holder's first successful Rock-type attack will have 1.3x power. Single use.",
     },
     "ghostgem" => ItemData {
         num: 560,
         name: "Ghost Gem",
-        description: "",
+        short_desc: "Holder's first successful Ghost-type attack will have 1.3x power. Single use.",
+        description: "Holder's first successful Ghost-type attack will have 1.3x power. Single use.",
     },
     "dragongem" => ItemData {
         num: 561,
         name: "Dragon Gem",
-        description: "",
+        short_desc: "Holder's first successful Dragon-type attack will have 1.3x power. Single use.",
+        description: "Holder's first successful Dragon-type attack will have 1.3x power. Single use.",
     },
     "darkgem" => ItemData {
         num: 562,
         name: "Dark Gem",
-        description: "",
+        short_desc: "Holder's first successful Dark-type attack will have 1.3x power. Single use.",
+        description: "Holder's first successful Dark-type attack will have 1.3x power. Single use.",
     },
     "steelgem" => ItemData {
         num: 563,
         name: "Steel Gem",
-        description: "",
+        short_desc: "Holder's first successful Steel-type attack will have 1.3x power. Single use.",
+        description: "Holder's first successful Steel-type attack will have 1.3x power. Single use.",
     },
     "normalgem" => ItemData {
         num: 564,
         name: "Normal Gem",
-        description: "",
+        short_desc: "Holder's first successful Normal-type attack will have 1.3x power. Single use.",
+        description: "Holder's first successful Normal-type attack will have 1.3x power. Single use.",
     },
     "prettyfeather" => ItemData {
         num: 571,
         name: "Pretty Feather",
-        description: "",
+        short_desc: "No competitive use.",
+        description: "No competitive use.",
     },
     "coverfossil" => ItemData {
         num: 572,
         name: "Cover Fossil",
-        description: "",
+        short_desc: "Can be revived into Tirtouga.",
+        description: "Can be revived into Tirtouga.",
     },
     "plumefossil" => ItemData {
         num: 573,
         name: "Plume Fossil",
-        description: "",
+        short_desc: "Can be revived into Archen.",
+        description: "Can be revived into Archen.",
     },
     "dreamball" => ItemData {
         num: 576,
         name: "Dream Ball",
-        description: "",
+        short_desc: "A special Poke Ball that appears out of nowhere in a bag at the Entree Forest.",
+        description: "A special Poke Ball that appears out of nowhere in a bag at the Entree Forest.",
     },
     "bignugget" => ItemData {
         num: 581,
         name: "Big Nugget",
-        description: "",
+        short_desc: "Can be sold for a high price. Fling deals 130 damage.",
+        description: "Can be sold for a high price. Fling deals 130 damage.",
     },
     "weaknesspolicy" => ItemData {
         num: 639,
         name: "Weakness Policy",
-        description: "",
+        short_desc: "If holder is hit super effectively, raises Attack, Sp. Atk by 2 stages. Single use.",
+        description: "If holder is hit super effectively, raises Attack, Sp. Atk by 2 stages. Single use.",
     },
     "assaultvest" => ItemData {
         num: 640,
         name: "Assault Vest",
-        description: "",
+        short_desc: "Holder's Sp. Def is 1.5x, but it can only select damaging moves.",
+        description: "Holder's Sp. Def is 1.5x, but it can only select damaging moves.",
     },
     "pixieplate" => ItemData {
         num: 644,
         name: "Pixie Plate",
-        description: "",
+        short_desc: "Holder's Fairy-type attacks have 1.2x power. Judgment is Fairy type.",
+        description: "Holder's Fairy-type attacks have 1.2x power. Judgment is Fairy type.",
     },
     "whippeddream" => ItemData {
         num: 646,
         name: "Whipped Dream",
-        description: "",
+        short_desc: "Evolves Swirlix into Slurpuff when traded.",
+        description: "Evolves Swirlix into Slurpuff when traded.",
     },
     "sachet" => ItemData {
         num: 647,
         name: "Sachet",
-        description: "",
+        short_desc: "Evolves Spritzee into Aromatisse when traded.",
+        description: "Evolves Spritzee into Aromatisse when traded.",
     },
     "luminousmoss" => ItemData {
         num: 648,
         name: "Luminous Moss",
-        description: "",
+        short_desc: "Raises holder's Sp. Def by 1 stage if hit by a Water-type attack. Single use.",
+        description: "Raises holder's Sp. Def by 1 stage if hit by a Water-type attack. Single use.",
     },
     "snowball" => ItemData {
         num: 649,
         name: "Snowball",
-        description: "",
+        short_desc: "Raises holder's Attack by 1 if hit by an Ice-type attack. Single use.",
+        description: "Raises holder's Attack by 1 if hit by an Ice-type attack. Single use.",
     },
     "safetygoggles" => ItemData {
         num: 650,
         name: "Safety Goggles",
-        description: "",
+        short_desc: "Holder is immune to powder moves and damage from Sandstorm.",
+        description: "Holder is immune to powder moves and damage from Sandstorm.",
     },
     "gengarite" => ItemData {
         num: 656,
         name: "Gengarite",
-        description: "",
+        short_desc: "If held by a Gengar, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Gengar, this item allows it to Mega Evolve in battle.",
     },
     "gardevoirite" => ItemData {
         num: 657,
         name: "Gardevoirite",
-        description: "",
+        short_desc: "If held by a Gardevoir, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Gardevoir, this item allows it to Mega Evolve in battle.",
     },
     "ampharosite" => ItemData {
         num: 658,
         name: "Ampharosite",
-        description: "",
+        short_desc: "If held by an Ampharos, this item allows it to Mega Evolve in battle.",
+        description: "If held by an Ampharos, this item allows it to Mega Evolve in battle.",
     },
     "venusaurite" => ItemData {
         num: 659,
         name: "Venusaurite",
-        description: "",
+        short_desc: "If held by a Venusaur, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Venusaur, this item allows it to Mega Evolve in battle.",
     },
     "charizarditex" => ItemData {
         num: 660,
         name: "Charizardite X",
-        description: "",
+        short_desc: "If held by a Charizard, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Charizard, this item allows it to Mega Evolve in battle.",
     },
     "blastoisinite" => ItemData {
         num: 661,
         name: "Blastoisinite",
-        description: "",
+        short_desc: "If held by a Blastoise, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Blastoise, this item allows it to Mega Evolve in battle.",
     },
     "mewtwonitex" => ItemData {
         num: 662,
         name: "Mewtwonite X",
-        description: "",
+        short_desc: "If held by a Mewtwo, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Mewtwo, this item allows it to Mega Evolve in battle.",
     },
     "mewtwonitey" => ItemData {
         num: 663,
         name: "Mewtwonite Y",
-        description: "",
+        short_desc: "If held by a Mewtwo, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Mewtwo, this item allows it to Mega Evolve in battle.",
     },
     "blazikenite" => ItemData {
         num: 664,
         name: "Blazikenite",
-        description: "",
+        short_desc: "If held by a Blaziken, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Blaziken, this item allows it to Mega Evolve in battle.",
     },
     "medichamite" => ItemData {
         num: 665,
         name: "Medichamite",
-        description: "",
+        short_desc: "If held by a Medicham, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Medicham, this item allows it to Mega Evolve in battle.",
     },
     "houndoominite" => ItemData {
         num: 666,
         name: "Houndoominite",
-        description: "",
+        short_desc: "If held by a Houndoom, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Houndoom, this item allows it to Mega Evolve in battle.",
     },
     "aggronite" => ItemData {
         num: 667,
         name: "Aggronite",
-        description: "",
+        short_desc: "If held by an Aggron, this item allows it to Mega Evolve in battle.",
+        description: "If held by an Aggron, this item allows it to Mega Evolve in battle.",
     },
     "banettite" => ItemData {
         num: 668,
         name: "Banettite",
-        description: "",
+        short_desc: "If held by a Banette, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Banette, this item allows it to Mega Evolve in battle.",
     },
     "tyranitarite" => ItemData {
         num: 669,
         name: "Tyranitarite",
-        description: "",
+        short_desc: "If held by a Tyranitar, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Tyranitar, this item allows it to Mega Evolve in battle.",
     },
     "scizorite" => ItemData {
         num: 670,
         name: "Scizorite",
-        description: "",
+        short_desc: "If held by a Scizor, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Scizor, this item allows it to Mega Evolve in battle.",
     },
     "pinsirite" => ItemData {
         num: 671,
         name: "Pinsirite",
-        description: "",
+        short_desc: "If held by a Pinsir, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Pinsir, this item allows it to Mega Evolve in battle.",
     },
     "aerodactylite" => ItemData {
         num: 672,
         name: "Aerodactylite",
-        description: "",
+        short_desc: "If held by an Aerodactyl, this item allows it to Mega Evolve in battle.",
+        description: "If held by an Aerodactyl, this item allows it to Mega Evolve in battle.",
     },
     "lucarionite" => ItemData {
         num: 673,
         name: "Lucarionite",
-        description: "",
+        short_desc: "If held by a Lucario, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Lucario, this item allows it to Mega Evolve in battle.",
     },
     "abomasite" => ItemData {
         num: 674,
         name: "Abomasite",
-        description: "",
+        short_desc: "If held by an Abomasnow, this item allows it to Mega Evolve in battle.",
+        description: "If held by an Abomasnow, this item allows it to Mega Evolve in battle.",
     },
     "kangaskhanite" => ItemData {
         num: 675,
         name: "Kangaskhanite",
-        description: "",
+        short_desc: "If held by a Kangaskhan, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Kangaskhan, this item allows it to Mega Evolve in battle.",
     },
     "gyaradosite" => ItemData {
         num: 676,
         name: "Gyaradosite",
-        description: "",
+        short_desc: "If held by a Gyarados, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Gyarados, this item allows it to Mega Evolve in battle.",
     },
     "absolite" => ItemData {
         num: 677,
         name: "Absolite",
-        description: "",
+        short_desc: "If held by an Absol, this item allows it to Mega Evolve in battle.",
+        description: "If held by an Absol, this item allows it to Mega Evolve in battle.",
     },
     "charizarditey" => ItemData {
         num: 678,
         name: "Charizardite Y",
-        description: "",
+        short_desc: "If held by a Charizard, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Charizard, this item allows it to Mega Evolve in battle.",
     },
     "alakazite" => ItemData {
         num: 679,
         name: "Alakazite",
-        description: "",
+        short_desc: "If held by an Alakazam, this item allows it to Mega Evolve in battle.",
+        description: "If held by an Alakazam, this item allows it to Mega Evolve in battle.",
     },
     "heracronite" => ItemData {
         num: 680,
         name: "Heracronite",
-        description: "",
+        short_desc: "If held by a Heracross, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Heracross, this item allows it to Mega Evolve in battle.",
     },
     "mawilite" => ItemData {
         num: 681,
         name: "Mawilite",
-        description: "",
+        short_desc: "If held by a Mawile, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Mawile, this item allows it to Mega Evolve in battle.",
     },
     "manectite" => ItemData {
         num: 682,
         name: "Manectite",
-        description: "",
+        short_desc: "If held by a Manectric, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Manectric, this item allows it to Mega Evolve in battle.",
     },
     "garchompite" => ItemData {
         num: 683,
         name: "Garchompite",
-        description: "",
+        short_desc: "If held by a Garchomp, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Garchomp, this item allows it to Mega Evolve in battle.",
     },
     "latiasite" => ItemData {
         num: 684,
         name: "Latiasite",
-        description: "",
+        short_desc: "If held by a Latias, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Latias, this item allows it to Mega Evolve in battle.",
     },
     "latiosite" => ItemData {
         num: 685,
         name: "Latiosite",
-        description: "",
+        short_desc: "If held by a Latios, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Latios, this item allows it to Mega Evolve in battle.",
     },
     "roseliberry" => ItemData {
         num: 686,
         name: "Roseli Berry",
-        description: "",
+        short_desc: "Halves damage taken from a supereffective Fairy-type attack. Single use.",
+        description: "Halves damage taken from a supereffective Fairy-type attack. Single use.",
     },
     "keeberry" => ItemData {
         num: 687,
         name: "Kee Berry",
-        description: "",
+        short_desc: "Raises holder's Defense by 1 stage after it is hit by a physical attack. Single use.",
+        description: "Raises holder's Defense by 1 stage after it is hit by a physical attack. Single use.",
     },
     "marangaberry" => ItemData {
         num: 688,
         name: "Maranga Berry",
-        description: "",
+        short_desc: "Raises holder's Sp. Def by 1 stage after it is hit by a special attack. Single use.",
+        description: "Raises holder's Sp. Def by 1 stage after it is hit by a special attack. Single use.",
     },
     "jawfossil" => ItemData {
         num: 710,
         name: "Jaw Fossil",
-        description: "",
+        short_desc: "Can be revived into Tyrunt.",
+        description: "Can be revived into Tyrunt.",
     },
     "sailfossil" => ItemData {
         num: 711,
         name: "Sail Fossil",
-        description: "",
+        short_desc: "Can be revived into Amaura.",
+        description: "Can be revived into Amaura.",
     },
     "fairygem" => ItemData {
         num: 715,
         name: "Fairy Gem",
-        description: "",
+        short_desc: "Holder's first successful Fairy-type attack will have 1.3x power. Single use.",
+        description: "Holder's first successful Fairy-type attack will have 1.3x power. Single use.",
     },
     "swampertite" => ItemData {
         num: 752,
         name: "Swampertite",
-        description: "",
+        short_desc: "If held by a Swampert, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Swampert, this item allows it to Mega Evolve in battle.",
     },
     "sceptilite" => ItemData {
         num: 753,
         name: "Sceptilite",
-        description: "",
+        short_desc: "If held by a Sceptile, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Sceptile, this item allows it to Mega Evolve in battle.",
     },
     "sablenite" => ItemData {
         num: 754,
         name: "Sablenite",
-        description: "",
+        short_desc: "If held by a Sableye, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Sableye, this item allows it to Mega Evolve in battle.",
     },
     "altarianite" => ItemData {
         num: 755,
         name: "Altarianite",
-        description: "",
+        short_desc: "If held by an Altaria, this item allows it to Mega Evolve in battle.",
+        description: "If held by an Altaria, this item allows it to Mega Evolve in battle.",
     },
     "galladite" => ItemData {
         num: 756,
         name: "Galladite",
-        description: "",
+        short_desc: "If held by a Gallade, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Gallade, this item allows it to Mega Evolve in battle.",
     },
     "audinite" => ItemData {
         num: 757,
         name: "Audinite",
-        description: "",
+        short_desc: "If held by an Audino, this item allows it to Mega Evolve in battle.",
+        description: "If held by an Audino, this item allows it to Mega Evolve in battle.",
     },
     "metagrossite" => ItemData {
         num: 758,
         name: "Metagrossite",
-        description: "",
+        short_desc: "If held by a Metagross, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Metagross, this item allows it to Mega Evolve in battle.",
     },
     "sharpedonite" => ItemData {
         num: 759,
         name: "Sharpedonite",
-        description: "",
+        short_desc: "If held by a Sharpedo, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Sharpedo, this item allows it to Mega Evolve in battle.",
     },
     "slowbronite" => ItemData {
         num: 760,
         name: "Slowbronite",
-        description: "",
+        short_desc: "If held by a Slowbro, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Slowbro, this item allows it to Mega Evolve in battle.",
     },
     "steelixite" => ItemData {
         num: 761,
         name: "Steelixite",
-        description: "",
+        short_desc: "If held by a Steelix, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Steelix, this item allows it to Mega Evolve in battle.",
     },
     "pidgeotite" => ItemData {
         num: 762,
         name: "Pidgeotite",
-        description: "",
+        short_desc: "If held by a Pidgeot, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Pidgeot, this item allows it to Mega Evolve in battle.",
     },
     "glalitite" => ItemData {
         num: 763,
         name: "Glalitite",
-        description: "",
+        short_desc: "If held by a Glalie, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Glalie, this item allows it to Mega Evolve in battle.",
     },
     "diancite" => ItemData {
         num: 764,
         name: "Diancite",
-        description: "",
+        short_desc: "If held by a Diancie, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Diancie, this item allows it to Mega Evolve in battle.",
     },
     "cameruptite" => ItemData {
         num: 767,
         name: "Cameruptite",
-        description: "",
+        short_desc: "If held by a Camerupt, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Camerupt, this item allows it to Mega Evolve in battle.",
     },
     "lopunnite" => ItemData {
         num: 768,
         name: "Lopunnite",
-        description: "",
+        short_desc: "If held by a Lopunny, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Lopunny, this item allows it to Mega Evolve in battle.",
     },
     "salamencite" => ItemData {
         num: 769,
         name: "Salamencite",
-        description: "",
+        short_desc: "If held by a Salamence, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Salamence, this item allows it to Mega Evolve in battle.",
     },
     "beedrillite" => ItemData {
         num: 770,
         name: "Beedrillite",
-        description: "",
+        short_desc: "If held by a Beedrill, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Beedrill, this item allows it to Mega Evolve in battle.",
     },
     "normaliumz" => ItemData {
         num: 776,
         name: "Normalium Z",
-        description: "",
+        short_desc: "If holder has a Normal move, this item allows it to use a Normal Z-Move.",
+        description: "If holder has a Normal move, this item allows it to use a Normal Z-Move.",
     },
     "firiumz" => ItemData {
         num: 777,
         name: "Firium Z",
-        description: "",
+        short_desc: "If holder has a Fire move, this item allows it to use a Fire Z-Move.",
+        description: "If holder has a Fire move, this item allows it to use a Fire Z-Move.",
     },
     "wateriumz" => ItemData {
         num: 778,
         name: "Waterium Z",
-        description: "",
+        short_desc: "If holder has a Water move, this item allows it to use a Water Z-Move.",
+        description: "If holder has a Water move, this item allows it to use a Water Z-Move.",
     },
     "electriumz" => ItemData {
         num: 779,
         name: "Electrium Z",
-        description: "",
+        short_desc: "If holder has a Electric move, this item allows it to use a Electric Z-Move.",
+        description: "If holder has a Electric move, this item allows it to use a Electric Z-Move.",
     },
     "grassiumz" => ItemData {
         num: 780,
         name: "Grassium Z",
-        description: "",
+        short_desc: "If holder has a Grass move, this item allows it to use a Grass Z-Move.",
+        description: "If holder has a Grass move, this item allows it to use a Grass Z-Move.",
     },
     "iciumz" => ItemData {
         num: 781,
         name: "Icium Z",
-        description: "",
+        short_desc: "If holder has a Ice move, this item allows it to use a Ice Z-Move.",
+        description: "If holder has a Ice move, this item allows it to use a Ice Z-Move.",
     },
     "fightiniumz" => ItemData {
         num: 782,
         name: "Fightinium Z",
-        description: "",
+        short_desc: "If holder has a Fighting move, this item allows it to use a Fighting Z-Move.",
+        description: "If holder has a Fighting move, this item allows it to use a Fighting Z-Move.",
     },
     "poisoniumz" => ItemData {
         num: 783,
         name: "Poisonium Z",
-        description: "",
+        short_desc: "If holder has a Poison move, this item allows it to use a Poison Z-Move.",
+        description: "If holder has a Poison move, this item allows it to use a Poison Z-Move.",
     },
     "groundiumz" => ItemData {
         num: 784,
         name: "Groundium Z",
-        description: "",
+        short_desc: "If holder has a Ground move, this item allows it to use a Ground Z-Move.",
+        description: "If holder has a Ground move, this item allows it to use a Ground Z-Move.",
     },
     "flyiniumz" => ItemData {
         num: 785,
         name: "Flyinium Z",
-        description: "",
+        short_desc: "If holder has a Flying move, this item allows it to use a Flying Z-Move.",
+        description: "If holder has a Flying move, this item allows it to use a Flying Z-Move.",
     },
     "psychiumz" => ItemData {
         num: 786,
         name: "Psychium Z",
-        description: "",
+        short_desc: "If holder has a Psychic move, this item allows it to use a Psychic Z-Move.",
+        description: "If holder has a Psychic move, this item allows it to use a Psychic Z-Move.",
     },
     "buginiumz" => ItemData {
         num: 787,
         name: "Buginium Z",
-        description: "",
+        short_desc: "If holder has a Bug move, this item allows it to use a Bug Z-Move.",
+        description: "If holder has a Bug move, this item allows it to use a Bug Z-Move.",
     },
     "rockiumz" => ItemData {
         num: 788,
         name: "Rockium Z",
-        description: "",
+        short_desc: "If holder has a Rock move, this item allows it to use a Rock Z-Move.",
+        description: "If holder has a Rock move, this item allows it to use a Rock Z-Move.",
     },
     "ghostiumz" => ItemData {
         num: 789,
         name: "Ghostium Z",
-        description: "",
+        short_desc: "If holder has a Ghost move, this item allows it to use a Ghost Z-Move.",
+        description: "If holder has a Ghost move, this item allows it to use a Ghost Z-Move.",
     },
     "dragoniumz" => ItemData {
         num: 790,
         name: "Dragonium Z",
-        description: "",
+        short_desc: "If holder has a Dragon move, this item allows it to use a Dragon Z-Move.",
+        description: "If holder has a Dragon move, this item allows it to use a Dragon Z-Move.",
     },
     "darkiniumz" => ItemData {
         num: 791,
         name: "Darkinium Z",
-        description: "",
+        short_desc: "If holder has a Dark move, this item allows it to use a Dark Z-Move.",
+        description: "If holder has a Dark move, this item allows it to use a Dark Z-Move.",
     },
     "steeliumz" => ItemData {
         num: 792,
         name: "Steelium Z",
-        description: "",
+        short_desc: "If holder has a Steel move, this item allows it to use a Steel Z-Move.",
+        description: "If holder has a Steel move, this item allows it to use a Steel Z-Move.",
     },
     "fairiumz" => ItemData {
         num: 793,
         name: "Fairium Z",
-        description: "",
+        short_desc: "If holder has a Fairy move, this item allows it to use a Fairy Z-Move.",
+        description: "If holder has a Fairy move, this item allows it to use a Fairy Z-Move.",
     },
     "pikaniumz" => ItemData {
         num: 794,
         name: "Pikanium Z",
-        description: "",
+        short_desc: "If held by a Pikachu with Volt Tackle, it can use Catastropika.",
+        description: "If held by a Pikachu with Volt Tackle, it can use Catastropika.",
     },
     "bottlecap" => ItemData {
         num: 795,
         name: "Bottle Cap",
-        description: "",
+        short_desc: "Item that can be used to max out one of a Pokemon's IVs.",
+        description: "Item that can be used to max out one of a Pokemon's IVs.",
     },
     "goldbottlecap" => ItemData {
         num: 796,
         name: "Gold Bottle Cap",
-        description: "",
+        short_desc: "Item that can be used to max out all of a Pokemon's IVs.",
+        description: "Item that can be used to max out all of a Pokemon's IVs.",
     },
     "decidiumz" => ItemData {
         num: 798,
         name: "Decidium Z",
-        description: "",
+        short_desc: "If held by a Decidueye with Spirit Shackle, it can use Sinister Arrow Raid.",
+        description: "If held by a Decidueye with Spirit Shackle, it can use Sinister Arrow Raid.",
     },
     "inciniumz" => ItemData {
         num: 799,
         name: "Incinium Z",
-        description: "",
+        short_desc: "If held by an Incineroar with Darkest Lariat, it can use Malicious Moonsault.",
+        description: "If held by an Incineroar with Darkest Lariat, it can use Malicious Moonsault.",
     },
     "primariumz" => ItemData {
         num: 800,
         name: "Primarium Z",
-        description: "",
+        short_desc: "If held by a Primarina with Sparkling Aria, it can use Oceanic Operetta.",
+        description: "If held by a Primarina with Sparkling Aria, it can use Oceanic Operetta.",
     },
     "tapuniumz" => ItemData {
         num: 801,
         name: "Tapunium Z",
-        description: "",
+        short_desc: "If held by a Tapu with Nature's Madness, it can use Guardian of Alola.",
+        description: "If held by a Tapu with Nature's Madness, it can use Guardian of Alola.",
     },
     "marshadiumz" => ItemData {
         num: 802,
         name: "Marshadium Z",
-        description: "",
+        short_desc: "If held by Marshadow with Spectral Thief, it can use Soul-Stealing 7-Star Strike.",
+        description: "If held by Marshadow with Spectral Thief, it can use Soul-Stealing 7-Star Strike.",
     },
     "aloraichiumz" => ItemData {
         num: 803,
         name: "Aloraichium Z",
-        description: "",
+        short_desc: "If held by an Alolan Raichu with Thunderbolt, it can use Stoked Sparksurfer.",
+        description: "If held by an Alolan Raichu with Thunderbolt, it can use Stoked Sparksurfer.",
     },
     "snorliumz" => ItemData {
         num: 804,
         name: "Snorlium Z",
-        description: "",
+        short_desc: "If held by a Snorlax with Giga Impact, it can use Pulverizing Pancake.",
+        description: "If held by a Snorlax with Giga Impact, it can use Pulverizing Pancake.",
     },
     "eeviumz" => ItemData {
         num: 805,
         name: "Eevium Z",
-        description: "",
+        short_desc: "If held by an Eevee with Last Resort, it can use Extreme Evoboost.",
+        description: "If held by an Eevee with Last Resort, it can use Extreme Evoboost.",
     },
     "mewniumz" => ItemData {
         num: 806,
         name: "Mewnium Z",
-        description: "",
+        short_desc: "If held by a Mew with Psychic, it can use Genesis Supernova.",
+        description: "If held by a Mew with Psychic, it can use Genesis Supernova.",
     },
     "pikashuniumz" => ItemData {
         num: 836,
         name: "Pikashunium Z",
-        description: "",
+        short_desc: "If held by cap Pikachu with Thunderbolt, it can use 10,000,000 Volt Thunderbolt.",
+        description: "If held by cap Pikachu with Thunderbolt, it can use 10,000,000 Volt Thunderbolt.",
     },
     "adrenalineorb" => ItemData {
         num: 846,
         name: "Adrenaline Orb",
-        description: "",
+        short_desc: "Raises holder's Speed by 1 stage if it gets affected by Intimidate. Single use.",
+        description: "Raises holder's Speed by 1 stage if it gets affected by Intimidate. Single use.",
     },
     "icestone" => ItemData {
         num: 849,
         name: "Ice Stone",
-        description: "",
+        short_desc: "Evolves Alolan Sandshrew, Alolan Vulpix, Eevee, Galarian Darumaka, and Crabrawler when used.",
+        description: "Evolves Alolan Sandshrew, Alolan Vulpix, Eevee, Galarian Darumaka, and Crabrawler when used.",
     },
     "beastball" => ItemData {
         num: 851,
         name: "Beast Ball",
-        description: "",
+        short_desc: "A special Poke Ball designed to catch Ultra Beasts.",
+        description: "A special Poke Ball designed to catch Ultra Beasts.",
     },
     "terrainextender" => ItemData {
         num: 879,
         name: "Terrain Extender",
-        description: "",
+        short_desc: "Holder's use of Electric/Grassy/Misty/Psychic Terrain lasts 8 turns instead of 5.",
+        description: "Holder's use of Electric/Grassy/Misty/Psychic Terrain lasts 8 turns instead of 5.",
     },
     "protectivepads" => ItemData {
         num: 880,
         name: "Protective Pads",
-        description: "",
+        short_desc: "Holder's moves are protected from adverse contact effects, except Pickpocket.",
+        description: "Holder's moves are protected from adverse contact effects, except Pickpocket.",
     },
     "electricseed" => ItemData {
         num: 881,
         name: "Electric Seed",
-        description: "",
+        short_desc: "If the terrain is Electric Terrain, raises holder's Defense by 1 stage. Single use.",
+        description: "If the terrain is Electric Terrain, raises holder's Defense by 1 stage. Single use.",
     },
     "psychicseed" => ItemData {
         num: 882,
         name: "Psychic Seed",
-        description: "",
+        short_desc: "If the terrain is Psychic Terrain, raises holder's Sp. Def by 1 stage. Single use.",
+        description: "If the terrain is Psychic Terrain, raises holder's Sp. Def by 1 stage. Single use.",
     },
     "mistyseed" => ItemData {
         num: 883,
         name: "Misty Seed",
-        description: "",
+        short_desc: "If the terrain is Misty Terrain, raises holder's Sp. Def by 1 stage. Single use.",
+        description: "If the terrain is Misty Terrain, raises holder's Sp. Def by 1 stage. Single use.",
     },
     "grassyseed" => ItemData {
         num: 884,
         name: "Grassy Seed",
-        description: "",
+        short_desc: "If the terrain is Grassy Terrain, raises holder's Defense by 1 stage. Single use.",
+        description: "If the terrain is Grassy Terrain, raises holder's Defense by 1 stage. Single use.",
     },
     "fightingmemory" => ItemData {
         num: 904,
         name: "Fighting Memory",
-        description: "",
+        short_desc: "Holder's Multi-Attack is Fighting type.",
+        description: "Holder's Multi-Attack is Fighting type.",
     },
     "flyingmemory" => ItemData {
         num: 905,
         name: "Flying Memory",
-        description: "",
+        short_desc: "Holder's Multi-Attack is Flying type.",
+        description: "Holder's Multi-Attack is Flying type.",
     },
     "poisonmemory" => ItemData {
         num: 906,
         name: "Poison Memory",
-        description: "",
+        short_desc: "Holder's Multi-Attack is Poison type.",
+        description: "Holder's Multi-Attack is Poison type.",
     },
     "groundmemory" => ItemData {
         num: 907,
         name: "Ground Memory",
-        description: "",
+        short_desc: "Holder's Multi-Attack is Ground type.",
+        description: "Holder's Multi-Attack is Ground type.",
     },
     "rockmemory" => ItemData {
         num: 908,
         name: "Rock Memory",
-        description: "",
+        short_desc: "Holder's Multi-Attack is Rock type.",
+        description: "Holder's Multi-Attack is Rock type.",
     },
     "bugmemory" => ItemData {
         num: 909,
         name: "Bug Memory",
-        description: "",
+        short_desc: "Holder's Multi-Attack is Bug type.",
+        description: "Holder's Multi-Attack is Bug type.",
     },
     "ghostmemory" => ItemData {
         num: 910,
         name: "Ghost Memory",
-        description: "",
+        short_desc: "Holder's Multi-Attack is Ghost type.",
+        description: "Holder's Multi-Attack is Ghost type.",
     },
     "steelmemory" => ItemData {
         num: 911,
         name: "Steel Memory",
-        description: "",
+        short_desc: "Holder's Multi-Attack is Steel type.",
+        description: "Holder's Multi-Attack is Steel type.",
     },
     "firememory" => ItemData {
         num: 912,
         name: "Fire Memory",
-        description: "",
+        short_desc: "Holder's Multi-Attack is Fire type.",
+        description: "Holder's Multi-Attack is Fire type.",
     },
     "watermemory" => ItemData {
         num: 913,
         name: "Water Memory",
-        description: "",
+        short_desc: "Holder's Multi-Attack is Water type.",
+        description: "Holder's Multi-Attack is Water type.",
     },
     "grassmemory" => ItemData {
         num: 914,
         name: "Grass Memory",
-        description: "",
+        short_desc: "Holder's Multi-Attack is Grass type.",
+        description: "Holder's Multi-Attack is Grass type.",
     },
     "electricmemory" => ItemData {
         num: 915,
         name: "Electric Memory",
-        description: "",
+        short_desc: "Holder's Multi-Attack is Electric type.",
+        description: "Holder's Multi-Attack is Electric type.",
     },
     "psychicmemory" => ItemData {
         num: 916,
         name: "Psychic Memory",
-        description: "",
+        short_desc: "Holder's Multi-Attack is Psychic type.",
+        description: "Holder's Multi-Attack is Psychic type.",
     },
     "icememory" => ItemData {
         num: 917,
         name: "Ice Memory",
-        description: "",
+        short_desc: "Holder's Multi-Attack is Ice type.",
+        description: "Holder's Multi-Attack is Ice type.",
     },
     "dragonmemory" => ItemData {
         num: 918,
         name: "Dragon Memory",
-        description: "",
+        short_desc: "Holder's Multi-Attack is Dragon type.",
+        description: "Holder's Multi-Attack is Dragon type.",
     },
     "darkmemory" => ItemData {
         num: 919,
         name: "Dark Memory",
-        description: "",
+        short_desc: "Holder's Multi-Attack is Dark type.",
+        description: "Holder's Multi-Attack is Dark type.",
     },
     "fairymemory" => ItemData {
         num: 920,
         name: "Fairy Memory",
-        description: "",
+        short_desc: "Holder's Multi-Attack is Fairy type.",
+        description: "Holder's Multi-Attack is Fairy type.",
     },
     "solganiumz" => ItemData {
         num: 921,
         name: "Solganium Z",
-        description: "",
+        short_desc: "If held by Solgaleo or Dusk Mane Necrozma with Sunsteel Strike, it can use Searing Sunraze Smash.",
+        description: "If held by Solgaleo or Dusk Mane Necrozma with Sunsteel Strike, it can use Searing Sunraze Smash.",
     },
     "lunaliumz" => ItemData {
         num: 922,
         name: "Lunalium Z",
-        description: "",
+        short_desc: "If held by Lunala or Dawn Wings Necrozma with Moongeist Beam, it can use Menacing Moonraze Maelstrom.",
+        description: "If held by Lunala or Dawn Wings Necrozma with Moongeist Beam, it can use Menacing Moonraze Maelstrom.",
     },
     "ultranecroziumz" => ItemData {
         num: 923,
         name: "Ultranecrozium Z",
-        description: "",
+        short_desc: "Dusk Mane/Dawn Wings Necrozma: Ultra Burst, then Z-Move w/ Photon Geyser.",
+        description: "Dusk Mane/Dawn Wings Necrozma: Ultra Burst, then Z-Move w/ Photon Geyser.",
     },
     "mimikiumz" => ItemData {
         num: 924,
         name: "Mimikium Z",
-        description: "",
+        short_desc: "If held by a Mimikyu with Play Rough, it can use Let's Snuggle Forever.",
+        description: "If held by a Mimikyu with Play Rough, it can use Let's Snuggle Forever.",
     },
     "lycaniumz" => ItemData {
         num: 925,
         name: "Lycanium Z",
-        description: "",
+        short_desc: "If held by a Lycanroc with Stone Edge, it can use Splintered Stormshards.",
+        description: "If held by a Lycanroc with Stone Edge, it can use Splintered Stormshards.",
     },
     "kommoniumz" => ItemData {
         num: 926,
         name: "Kommonium Z",
-        description: "",
+        short_desc: "If held by a Kommo-o with Clanging Scales, it can use Clangorous Soulblaze.",
+        description: "If held by a Kommo-o with Clanging Scales, it can use Clangorous Soulblaze.",
     },
     "rustedsword" => ItemData {
         num: 1103,
         name: "Rusted Sword",
-        description: "",
+        short_desc: "If held by a Zacian, this item changes it to Crowned Sword Forme.",
+        description: "If held by a Zacian, this item changes it to Crowned Sword Forme.",
     },
     "rustedshield" => ItemData {
         num: 1104,
         name: "Rusted Shield",
-        description: "",
+        short_desc: "If held by a Zamazenta, this item changes it to Crowned Shield Forme.",
+        description: "If held by a Zamazenta, this item changes it to Crowned Shield Forme.",
     },
     "fossilizedbird" => ItemData {
         num: 1105,
         name: "Fossilized Bird",
-        description: "",
+        short_desc: "Can be revived into Dracozolt or Arctozolt when combined with another fossil.",
+        description: "Can be revived into Dracozolt or Arctozolt when combined with another fossil.",
     },
     "fossilizedfish" => ItemData {
         num: 1106,
         name: "Fossilized Fish",
-        description: "",
+        short_desc: "Can be revived into Dracovish or Arctovish when combined with another fossil.",
+        description: "Can be revived into Dracovish or Arctovish when combined with another fossil.",
     },
     "fossilizeddrake" => ItemData {
         num: 1107,
         name: "Fossilized Drake",
-        description: "",
+        short_desc: "Can be revived into Dracozolt or Dracovish when combined with another fossil.",
+        description: "Can be revived into Dracozolt or Dracovish when combined with another fossil.",
     },
     "fossilizeddino" => ItemData {
         num: 1108,
         name: "Fossilized Dino",
-        description: "",
+        short_desc: "Can be revived into Arctovish or Arctozolt when combined with another fossil.",
+        description: "Can be revived into Arctovish or Arctozolt when combined with another fossil.",
     },
     "strawberrysweet" => ItemData {
         num: 1109,
         name: "Strawberry Sweet",
-        description: "",
+        short_desc: "Evolves Milcery into Alcremie when held and spun around.",
+        description: "Evolves Milcery into Alcremie when held and spun around.",
     },
     "lovesweet" => ItemData {
         num: 1110,
         name: "Love Sweet",
-        description: "",
+        short_desc: "Evolves Milcery into Alcremie when held and spun around.",
+        description: "Evolves Milcery into Alcremie when held and spun around.",
     },
     "berrysweet" => ItemData {
         num: 1111,
         name: "Berry Sweet",
-        description: "",
+        short_desc: "Evolves Milcery into Alcremie when held and spun around.",
+        description: "Evolves Milcery into Alcremie when held and spun around.",
     },
     "cloversweet" => ItemData {
         num: 1112,
         name: "Clover Sweet",
-        description: "",
+        short_desc: "Evolves Milcery into Alcremie when held and spun around.",
+        description: "Evolves Milcery into Alcremie when held and spun around.",
     },
     "flowersweet" => ItemData {
         num: 1113,
         name: "Flower Sweet",
-        description: "",
+        short_desc: "Evolves Milcery into Alcremie when held and spun around.",
+        description: "Evolves Milcery into Alcremie when held and spun around.",
     },
     "starsweet" => ItemData {
         num: 1114,
         name: "Star Sweet",
-        description: "",
+        short_desc: "Evolves Milcery into Alcremie when held and spun around.",
+        description: "Evolves Milcery into Alcremie when held and spun around.",
     },
     "ribbonsweet" => ItemData {
         num: 1115,
         name: "Ribbon Sweet",
-        description: "",
+        short_desc: "Evolves Milcery into Alcremie when held and spun around.",
+        description: "Evolves Milcery into Alcremie when held and spun around.",
     },
     "sweetapple" => ItemData {
         num: 1116,
         name: "Sweet Apple",
-        description: "",
+        short_desc: "Evolves Applin into Appletun when used.",
+        description: "Evolves Applin into Appletun when used.",
     },
     "tartapple" => ItemData {
         num: 1117,
         name: "Tart Apple",
-        description: "",
+        short_desc: "Evolves Applin into Flapple when used.",
+        description: "Evolves Applin into Flapple when used.",
     },
     "throatspray" => ItemData {
         num: 1118,
         name: "Throat Spray",
-        description: "",
+        short_desc: "Raises holder's Special Attack by 1 stage after it uses a sound move. Single use.",
+        description: "Raises holder's Special Attack by 1 stage after it uses a sound move. Single use.",
     },
     "ejectpack" => ItemData {
         num: 1119,
         name: "Eject Pack",
-        description: "",
+        short_desc: "If the holder's stat stages are lowered, it switches to a chosen ally. Single use.",
+        description: "If the holder's stat stages are lowered, it switches to a chosen ally. Single use.",
     },
     "heavydutyboots" => ItemData {
         num: 1120,
         name: "Heavy-Duty Boots",
-        description: "",
+        short_desc: "When switching in, the holder is unaffected by hazards on its side of the field.",
+        description: "When switching in, the holder is unaffected by hazards on its side of the field.",
     },
     "blunderpolicy" => ItemData {
         num: 1121,
         name: "Blunder Policy",
-        description: "",
+        short_desc: "If the holder misses due to accuracy, its Speed is raised by 2 stages. Single use.",
+        description: "If the holder misses due to accuracy, its Speed is raised by 2 stages. Single use.",
     },
     "roomservice" => ItemData {
         num: 1122,
         name: "Room Service",
-        description: "",
+        short_desc: "If Trick Room is active, lowers holder's Speed by 1 stage. Single use.",
+        description: "If Trick Room is active, lowers holder's Speed by 1 stage. Single use.",
     },
     "utilityumbrella" => ItemData {
         num: 1123,
         name: "Utility Umbrella",
-        description: "",
+        short_desc: "The holder ignores rain- and sun-based effects.",
+        description: "The holder ignores rain- and sun-based effects.",
     },
     "tr00" => ItemData {
         num: 1130,
         name: "TR00",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Swords Dance.",
+        description: "Teaches certain Pokemon the move Swords Dance.",
     },
     "tr01" => ItemData {
         num: 1131,
         name: "TR01",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Body Slam.",
+        description: "Teaches certain Pokemon the move Body Slam.",
     },
     "tr02" => ItemData {
         num: 1132,
         name: "TR02",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Flamethrower.",
+        description: "Teaches certain Pokemon the move Flamethrower.",
     },
     "tr03" => ItemData {
         num: 1133,
         name: "TR03",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Hydro Pump.",
+        description: "Teaches certain Pokemon the move Hydro Pump.",
     },
     "tr04" => ItemData {
         num: 1134,
         name: "TR04",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Surf.",
+        description: "Teaches certain Pokemon the move Surf.",
     },
     "tr05" => ItemData {
         num: 1135,
         name: "TR05",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Ice Beam.",
+        description: "Teaches certain Pokemon the move Ice Beam.",
     },
     "tr06" => ItemData {
         num: 1136,
         name: "TR06",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Blizzard.",
+        description: "Teaches certain Pokemon the move Blizzard.",
     },
     "tr07" => ItemData {
         num: 1137,
         name: "TR07",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Low Kick.",
+        description: "Teaches certain Pokemon the move Low Kick.",
     },
     "tr08" => ItemData {
         num: 1138,
         name: "TR08",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Thunderbolt.",
+        description: "Teaches certain Pokemon the move Thunderbolt.",
     },
     "tr09" => ItemData {
         num: 1139,
         name: "TR09",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Thunder.",
+        description: "Teaches certain Pokemon the move Thunder.",
     },
     "tr10" => ItemData {
         num: 1140,
         name: "TR10",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Earthquake.",
+        description: "Teaches certain Pokemon the move Earthquake.",
     },
     "tr11" => ItemData {
         num: 1141,
         name: "TR11",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Psychic.",
+        description: "Teaches certain Pokemon the move Psychic.",
     },
     "tr12" => ItemData {
         num: 1142,
         name: "TR12",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Agility.",
+        description: "Teaches certain Pokemon the move Agility.",
     },
     "tr13" => ItemData {
         num: 1143,
         name: "TR13",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Focus Energy.",
+        description: "Teaches certain Pokemon the move Focus Energy.",
     },
     "tr14" => ItemData {
         num: 1144,
         name: "TR14",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Metronome.",
+        description: "Teaches certain Pokemon the move Metronome.",
     },
     "tr15" => ItemData {
         num: 1145,
         name: "TR15",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Fire Blast.",
+        description: "Teaches certain Pokemon the move Fire Blast.",
     },
     "tr16" => ItemData {
         num: 1146,
         name: "TR16",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Waterfall.",
+        description: "Teaches certain Pokemon the move Waterfall.",
     },
     "tr17" => ItemData {
         num: 1147,
         name: "TR17",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Amnesia.",
+        description: "Teaches certain Pokemon the move Amnesia.",
     },
     "tr18" => ItemData {
         num: 1148,
         name: "TR18",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Leech Life.",
+        description: "Teaches certain Pokemon the move Leech Life.",
     },
     "tr19" => ItemData {
         num: 1149,
         name: "TR19",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Tri Attack.",
+        description: "Teaches certain Pokemon the move Tri Attack.",
     },
     "tr20" => ItemData {
         num: 1150,
         name: "TR20",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Substitute.",
+        description: "Teaches certain Pokemon the move Substitute.",
     },
     "tr21" => ItemData {
         num: 1151,
         name: "TR21",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Reversal.",
+        description: "Teaches certain Pokemon the move Reversal.",
     },
     "tr22" => ItemData {
         num: 1152,
         name: "TR22",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Sludge Bomb.",
+        description: "Teaches certain Pokemon the move Sludge Bomb.",
     },
     "tr23" => ItemData {
         num: 1153,
         name: "TR23",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Spikes.",
+        description: "Teaches certain Pokemon the move Spikes.",
     },
     "tr24" => ItemData {
         num: 1154,
         name: "TR24",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Outrage.",
+        description: "Teaches certain Pokemon the move Outrage.",
     },
     "tr25" => ItemData {
         num: 1155,
         name: "TR25",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Psyshock.",
+        description: "Teaches certain Pokemon the move Psyshock.",
     },
     "tr26" => ItemData {
         num: 1156,
         name: "TR26",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Endure.",
+        description: "Teaches certain Pokemon the move Endure.",
     },
     "tr27" => ItemData {
         num: 1157,
         name: "TR27",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Sleep Talk.",
+        description: "Teaches certain Pokemon the move Sleep Talk.",
     },
     "tr28" => ItemData {
         num: 1158,
         name: "TR28",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Megahorn.",
+        description: "Teaches certain Pokemon the move Megahorn.",
     },
     "tr29" => ItemData {
         num: 1159,
         name: "TR29",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Baton Pass.",
+        description: "Teaches certain Pokemon the move Baton Pass.",
     },
     "tr30" => ItemData {
         num: 1160,
         name: "TR30",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Encore.",
+        description: "Teaches certain Pokemon the move Encore.",
     },
     "tr31" => ItemData {
         num: 1161,
         name: "TR31",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Iron Tail.",
+        description: "Teaches certain Pokemon the move Iron Tail.",
     },
     "tr32" => ItemData {
         num: 1162,
         name: "TR32",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Crunch.",
+        description: "Teaches certain Pokemon the move Crunch.",
     },
     "tr33" => ItemData {
         num: 1163,
         name: "TR33",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Shadow Ball.",
+        description: "Teaches certain Pokemon the move Shadow Ball.",
     },
     "tr34" => ItemData {
         num: 1164,
         name: "TR34",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Future Sight.",
+        description: "Teaches certain Pokemon the move Future Sight.",
     },
     "tr35" => ItemData {
         num: 1165,
         name: "TR35",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Uproar.",
+        description: "Teaches certain Pokemon the move Uproar.",
     },
     "tr36" => ItemData {
         num: 1166,
         name: "TR36",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Heat Wave.",
+        description: "Teaches certain Pokemon the move Heat Wave.",
     },
     "tr37" => ItemData {
         num: 1167,
         name: "TR37",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Taunt.",
+        description: "Teaches certain Pokemon the move Taunt.",
     },
     "tr38" => ItemData {
         num: 1168,
         name: "TR38",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Trick.",
+        description: "Teaches certain Pokemon the move Trick.",
     },
     "tr39" => ItemData {
         num: 1169,
         name: "TR39",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Superpower.",
+        description: "Teaches certain Pokemon the move Superpower.",
     },
     "tr40" => ItemData {
         num: 1170,
         name: "TR40",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Skill Swap.",
+        description: "Teaches certain Pokemon the move Skill Swap.",
     },
     "tr41" => ItemData {
         num: 1171,
         name: "TR41",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Blaze Kick.",
+        description: "Teaches certain Pokemon the move Blaze Kick.",
     },
     "tr42" => ItemData {
         num: 1172,
         name: "TR42",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Hyper Voice.",
+        description: "Teaches certain Pokemon the move Hyper Voice.",
     },
     "tr43" => ItemData {
         num: 1173,
         name: "TR43",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Overheat.",
+        description: "Teaches certain Pokemon the move Overheat.",
     },
     "tr44" => ItemData {
         num: 1174,
         name: "TR44",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Cosmic Power.",
+        description: "Teaches certain Pokemon the move Cosmic Power.",
     },
     "tr45" => ItemData {
         num: 1175,
         name: "TR45",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Muddy Water.",
+        description: "Teaches certain Pokemon the move Muddy Water.",
     },
     "tr46" => ItemData {
         num: 1176,
         name: "TR46",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Iron Defense.",
+        description: "Teaches certain Pokemon the move Iron Defense.",
     },
     "tr47" => ItemData {
         num: 1177,
         name: "TR47",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Dragon Claw.",
+        description: "Teaches certain Pokemon the move Dragon Claw.",
     },
     "tr48" => ItemData {
         num: 1178,
         name: "TR48",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Bulk Up.",
+        description: "Teaches certain Pokemon the move Bulk Up.",
     },
     "tr49" => ItemData {
         num: 1179,
         name: "TR49",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Calm Mind.",
+        description: "Teaches certain Pokemon the move Calm Mind.",
     },
     "tr50" => ItemData {
         num: 1180,
         name: "TR50",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Leaf Blade.",
+        description: "Teaches certain Pokemon the move Leaf Blade.",
     },
     "tr51" => ItemData {
         num: 1181,
         name: "TR51",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Dragon Dance.",
+        description: "Teaches certain Pokemon the move Dragon Dance.",
     },
     "tr52" => ItemData {
         num: 1182,
         name: "TR52",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Gyro Ball.",
+        description: "Teaches certain Pokemon the move Gyro Ball.",
     },
     "tr53" => ItemData {
         num: 1183,
         name: "TR53",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Close Combat.",
+        description: "Teaches certain Pokemon the move Close Combat.",
     },
     "tr54" => ItemData {
         num: 1184,
         name: "TR54",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Toxic Spikes.",
+        description: "Teaches certain Pokemon the move Toxic Spikes.",
     },
     "tr55" => ItemData {
         num: 1185,
         name: "TR55",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Flare Blitz.",
+        description: "Teaches certain Pokemon the move Flare Blitz.",
     },
     "tr56" => ItemData {
         num: 1186,
         name: "TR56",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Aura Sphere.",
+        description: "Teaches certain Pokemon the move Aura Sphere.",
     },
     "tr57" => ItemData {
         num: 1187,
         name: "TR57",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Poison Jab.",
+        description: "Teaches certain Pokemon the move Poison Jab.",
     },
     "tr58" => ItemData {
         num: 1188,
         name: "TR58",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Dark Pulse.",
+        description: "Teaches certain Pokemon the move Dark Pulse.",
     },
     "tr59" => ItemData {
         num: 1189,
         name: "TR59",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Seed Bomb.",
+        description: "Teaches certain Pokemon the move Seed Bomb.",
     },
     "tr60" => ItemData {
         num: 1190,
         name: "TR60",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move X-Scissor.",
+        description: "Teaches certain Pokemon the move X-Scissor.",
     },
     "tr61" => ItemData {
         num: 1191,
         name: "TR61",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Bug Buzz.",
+        description: "Teaches certain Pokemon the move Bug Buzz.",
     },
     "tr62" => ItemData {
         num: 1192,
         name: "TR62",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Dragon Pulse.",
+        description: "Teaches certain Pokemon the move Dragon Pulse.",
     },
     "tr63" => ItemData {
         num: 1193,
         name: "TR63",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Power Gem.",
+        description: "Teaches certain Pokemon the move Power Gem.",
     },
     "tr64" => ItemData {
         num: 1194,
         name: "TR64",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Focus Blast.",
+        description: "Teaches certain Pokemon the move Focus Blast.",
     },
     "tr65" => ItemData {
         num: 1195,
         name: "TR65",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Energy Ball.",
+        description: "Teaches certain Pokemon the move Energy Ball.",
     },
     "tr66" => ItemData {
         num: 1196,
         name: "TR66",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Brave Bird.",
+        description: "Teaches certain Pokemon the move Brave Bird.",
     },
     "tr67" => ItemData {
         num: 1197,
         name: "TR67",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Earth Power.",
+        description: "Teaches certain Pokemon the move Earth Power.",
     },
     "tr68" => ItemData {
         num: 1198,
         name: "TR68",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Nasty Plot.",
+        description: "Teaches certain Pokemon the move Nasty Plot.",
     },
     "tr69" => ItemData {
         num: 1199,
         name: "TR69",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Zen Headbutt.",
+        description: "Teaches certain Pokemon the move Zen Headbutt.",
     },
     "tr70" => ItemData {
         num: 1200,
         name: "TR70",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Flash Cannon.",
+        description: "Teaches certain Pokemon the move Flash Cannon.",
     },
     "tr71" => ItemData {
         num: 1201,
         name: "TR71",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Leaf Storm.",
+        description: "Teaches certain Pokemon the move Leaf Storm.",
     },
     "tr72" => ItemData {
         num: 1202,
         name: "TR72",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Power Whip.",
+        description: "Teaches certain Pokemon the move Power Whip.",
     },
     "tr73" => ItemData {
         num: 1203,
         name: "TR73",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Gunk Shot.",
+        description: "Teaches certain Pokemon the move Gunk Shot.",
     },
     "tr74" => ItemData {
         num: 1204,
         name: "TR74",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Iron Head.",
+        description: "Teaches certain Pokemon the move Iron Head.",
     },
     "tr75" => ItemData {
         num: 1205,
         name: "TR75",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Stone Edge.",
+        description: "Teaches certain Pokemon the move Stone Edge.",
     },
     "tr76" => ItemData {
         num: 1206,
         name: "TR76",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Stealth Rock.",
+        description: "Teaches certain Pokemon the move Stealth Rock.",
     },
     "tr77" => ItemData {
         num: 1207,
         name: "TR77",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Grass Knot.",
+        description: "Teaches certain Pokemon the move Grass Knot.",
     },
     "tr78" => ItemData {
         num: 1208,
         name: "TR78",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Sludge Wave.",
+        description: "Teaches certain Pokemon the move Sludge Wave.",
     },
     "tr79" => ItemData {
         num: 1209,
         name: "TR79",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Heavy Slam.",
+        description: "Teaches certain Pokemon the move Heavy Slam.",
     },
     "tr80" => ItemData {
         num: 1210,
         name: "TR80",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Electro Ball.",
+        description: "Teaches certain Pokemon the move Electro Ball.",
     },
     "tr81" => ItemData {
         num: 1211,
         name: "TR81",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Foul Play.",
+        description: "Teaches certain Pokemon the move Foul Play.",
     },
     "tr82" => ItemData {
         num: 1212,
         name: "TR82",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Stored Power.",
+        description: "Teaches certain Pokemon the move Stored Power.",
     },
     "tr83" => ItemData {
         num: 1213,
         name: "TR83",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Ally Switch.",
+        description: "Teaches certain Pokemon the move Ally Switch.",
     },
     "tr84" => ItemData {
         num: 1214,
         name: "TR84",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Scald.",
+        description: "Teaches certain Pokemon the move Scald.",
     },
     "tr85" => ItemData {
         num: 1215,
         name: "TR85",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Work Up.",
+        description: "Teaches certain Pokemon the move Work Up.",
     },
     "tr86" => ItemData {
         num: 1216,
         name: "TR86",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Wild Charge.",
+        description: "Teaches certain Pokemon the move Wild Charge.",
     },
     "tr87" => ItemData {
         num: 1217,
         name: "TR87",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Drill Run.",
+        description: "Teaches certain Pokemon the move Drill Run.",
     },
     "tr88" => ItemData {
         num: 1218,
         name: "TR88",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Heat Crash.",
+        description: "Teaches certain Pokemon the move Heat Crash.",
     },
     "tr89" => ItemData {
         num: 1219,
         name: "TR89",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Hurricane.",
+        description: "Teaches certain Pokemon the move Hurricane.",
     },
     "tr90" => ItemData {
         num: 1220,
         name: "TR90",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Play Rough.",
+        description: "Teaches certain Pokemon the move Play Rough.",
     },
     "tr91" => ItemData {
         num: 1221,
         name: "TR91",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Venom Drench.",
+        description: "Teaches certain Pokemon the move Venom Drench.",
     },
     "tr92" => ItemData {
         num: 1222,
         name: "TR92",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Dazzling Gleam.",
+        description: "Teaches certain Pokemon the move Dazzling Gleam.",
     },
     "tr93" => ItemData {
         num: 1223,
         name: "TR93",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Darkest Lariat.",
+        description: "Teaches certain Pokemon the move Darkest Lariat.",
     },
     "tr94" => ItemData {
         num: 1224,
         name: "TR94",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move High Horsepower.",
+        description: "Teaches certain Pokemon the move High Horsepower.",
     },
     "tr95" => ItemData {
         num: 1225,
         name: "TR95",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Throat Chop.",
+        description: "Teaches certain Pokemon the move Throat Chop.",
     },
     "tr96" => ItemData {
         num: 1226,
         name: "TR96",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Pollen Puff.",
+        description: "Teaches certain Pokemon the move Pollen Puff.",
     },
     "tr97" => ItemData {
         num: 1227,
         name: "TR97",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Psychic Fangs.",
+        description: "Teaches certain Pokemon the move Psychic Fangs.",
     },
     "tr98" => ItemData {
         num: 1228,
         name: "TR98",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Liquidation.",
+        description: "Teaches certain Pokemon the move Liquidation.",
     },
     "tr99" => ItemData {
         num: 1229,
         name: "TR99",
-        description: "",
+        short_desc: "Teaches certain Pokemon the move Body Press.",
+        description: "Teaches certain Pokemon the move Body Press.",
     },
     "crackedpot" => ItemData {
         num: 1253,
         name: "Cracked Pot",
-        description: "",
+        short_desc: "Evolves Sinistea into Polteageist when used.",
+        description: "Evolves Sinistea into Polteageist when used.",
     },
     "chippedpot" => ItemData {
         num: 1254,
         name: "Chipped Pot",
-        description: "",
+        short_desc: "Evolves Sinistea into Polteageist when used.",
+        description: "Evolves Sinistea into Polteageist when used.",
     },
     "galaricacuff" => ItemData {
         num: 1582,
         name: "Galarica Cuff",
-        description: "",
+        short_desc: "Evolves Galarian Slowpoke into Galarian Slowbro when used.",
+        description: "Evolves Galarian Slowpoke into Galarian Slowbro when used.",
     },
     "galaricawreath" => ItemData {
         num: 1592,
         name: "Galarica Wreath",
-        description: "",
+        short_desc: "Evolves Galarian Slowpoke into Galarian Slowking when used.",
+        description: "Evolves Galarian Slowpoke into Galarian Slowking when used.",
     },
     "adamantcrystal" => ItemData {
         num: 1777,
         name: "Adamant Crystal",
-        description: "",
+        short_desc: "If held by a Dialga, its Steel- and Dragon-type attacks have 1.2x power.",
+        description: "If held by a Dialga, its Steel- and Dragon-type attacks have 1.2x power.",
     },
     "lustrousglobe" => ItemData {
         num: 1778,
         name: "Lustrous Globe",
-        description: "",
+        short_desc: "Palkia-Origin: 1.2x power Water- and Dragon-type attacks.",
+        description: "Palkia-Origin: 1.2x power Water- and Dragon-type attacks.",
     },
     "griseouscore" => ItemData {
         num: 1779,
         name: "Griseous Core",
-        description: "",
+        short_desc: "Giratina-Origin: 1.2x power Ghost- and Dragon-type attacks.",
+        description: "Giratina-Origin: 1.2x power Ghost- and Dragon-type attacks.",
     },
     "strangeball" => ItemData {
         num: 1785,
         name: "Strange Ball",
-        description: "",
+        short_desc: "An unusual Poke Ball.",
+        description: "An unusual Poke Ball.",
     },
     "maliciousarmor" => ItemData {
         num: 1861,
         name: "Malicious Armor",
-        description: "",
+        short_desc: "Evolves Charcadet into Ceruledge when used.",
+        description: "Evolves Charcadet into Ceruledge when used.",
     },
     "boosterenergy" => ItemData {
         num: 1880,
         name: "Booster Energy",
-        description: "",
+        short_desc: "Activates the Protosynthesis or Quark Drive Abilities. Single use.",
+        description: "Activates the Protosynthesis or Quark Drive Abilities. Single use.",
     },
     "abilityshield" => ItemData {
         num: 1881,
         name: "Ability Shield",
-        description: "",
+        short_desc: "Holder's Ability cannot be changed, suppressed, or ignored by other effects.",
+        description: "Holder's Ability cannot be changed, suppressed, or ignored by other effects.",
     },
     "clearamulet" => ItemData {
         num: 1882,
         name: "Clear Amulet",
-        description: "",
+        short_desc: "Prevents other Pokemon from lowering the holder's stat stages.",
+        description: "Prevents other Pokemon from lowering the holder's stat stages.",
     },
     "mirrorherb" => ItemData {
         num: 1883,
         name: "Mirror Herb",
-        description: "",
+        short_desc: "When an opposing Pokemon raises a stat stage, the holder copies it. Single use.",
+        description: "When an opposing Pokemon raises a stat stage, the holder copies it. Single use.",
     },
     "punchingglove" => ItemData {
         num: 1884,
         name: "Punching Glove",
-        description: "",
+        short_desc: "Holder's punch-based attacks have 1.1x power and do not make contact.",
+        description: "Holder's punch-based attacks have 1.1x power and do not make contact.",
     },
     "covertcloak" => ItemData {
         num: 1885,
         name: "Covert Cloak",
-        description: "",
+        short_desc: "Holder is not affected by the secondary effect of another Pokemon's attack.",
+        description: "Holder is not affected by the secondary effect of another Pokemon's attack.",
     },
     "loadeddice" => ItemData {
         num: 1886,
         name: "Loaded Dice",
-        description: "",
+        short_desc: "Holder's moves that hit 2-5 times hit 4-5 times; Population Bomb hits 4-10 times.",
+        description: "Holder's moves that hit 2-5 times hit 4-5 times; Population Bomb hits 4-10 times.",
     },
     "auspiciousarmor" => ItemData {
         num: 2344,
         name: "Auspicious Armor",
-        description: "",
+        short_desc: "Evolves Charcadet into Armarouge when used.",
+        description: "Evolves Charcadet into Armarouge when used.",
     },
     "fairyfeather" => ItemData {
         num: 2401,
         name: "Fairy Feather",
-        description: "",
+        short_desc: "Holder's Fairy-type attacks have 1.2x power.",
+        description: "Holder's Fairy-type attacks have 1.2x power.",
     },
     "syrupyapple" => ItemData {
         num: 2402,
         name: "Syrupy Apple",
-        description: "",
+        short_desc: "Evolves Applin into Dipplin when used.",
+        description: "Evolves Applin into Dipplin when used.",
     },
     "unremarkableteacup" => ItemData {
         num: 2403,
         name: "Unremarkable Teacup",
-        description: "",
+        short_desc: "Evolves Counterfeit Form Poltchageist into Sinistcha when used.",
+        description: "Evolves Counterfeit Form Poltchageist into Sinistcha when used.",
     },
     "masterpieceteacup" => ItemData {
         num: 2404,
         name: "Masterpiece Teacup",
-        description: "",
+        short_desc: "Evolves Masterpiece Form Poltchageist into Sinistcha when used.",
+        description: "Evolves Masterpiece Form Poltchageist into Sinistcha when used.",
     },
     "cornerstonemask" => ItemData {
         num: 2406,
         name: "Cornerstone Mask",
-        description: "",
+        short_desc: "Ogerpon-Cornerstone: 1.2x power attacks; Terastallize to gain Embody Aspect.",
+        description: "Ogerpon-Cornerstone: 1.2x power attacks; Terastallize to gain Embody Aspect.",
     },
     "wellspringmask" => ItemData {
         num: 2407,
         name: "Wellspring Mask",
-        description: "",
+        short_desc: "Ogerpon-Wellspring: 1.2x power attacks; Terastallize to gain Embody Aspect.",
+        description: "Ogerpon-Wellspring: 1.2x power attacks; Terastallize to gain Embody Aspect.",
     },
     "hearthflamemask" => ItemData {
         num: 2408,
         name: "Hearthflame Mask",
-        description: "",
+        short_desc: "Ogerpon-Hearthflame: 1.2x power attacks; Terastallize to gain Embody Aspect.",
+        description: "Ogerpon-Hearthflame: 1.2x power attacks; Terastallize to gain Embody Aspect.",
     },
     "metalalloy" => ItemData {
         num: 2482,
         name: "Metal Alloy",
-        description: "",
+        short_desc: "Evolves Duraludon into Archaludon when used.",
+        description: "Evolves Duraludon into Archaludon when used.",
     },
     "clefablite" => ItemData {
         num: 2559,
         name: "Clefablite",
-        description: "",
+        short_desc: "If held by a Clefable, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Clefable, this item allows it to Mega Evolve in battle.",
     },
     "victreebelite" => ItemData {
         num: 2560,
         name: "Victreebelite",
-        description: "",
+        short_desc: "If held by a Victreebel, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Victreebel, this item allows it to Mega Evolve in battle.",
     },
     "starminite" => ItemData {
         num: 2561,
         name: "Starminite",
-        description: "",
+        short_desc: "If held by a Starmie, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Starmie, this item allows it to Mega Evolve in battle.",
     },
     "dragoninite" => ItemData {
         num: 2562,
         name: "Dragoninite",
-        description: "",
+        short_desc: "If held by a Dragonite, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Dragonite, this item allows it to Mega Evolve in battle.",
     },
     "meganiumite" => ItemData {
         num: 2563,
         name: "Meganiumite",
-        description: "",
+        short_desc: "If held by a Meganium, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Meganium, this item allows it to Mega Evolve in battle.",
     },
     "feraligite" => ItemData {
         num: 2564,
         name: "Feraligite",
-        description: "",
+        short_desc: "If held by a Feraligatr, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Feraligatr, this item allows it to Mega Evolve in battle.",
     },
     "skarmorite" => ItemData {
         num: 2565,
         name: "Skarmorite",
-        description: "",
+        short_desc: "If held by a Skarmory, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Skarmory, this item allows it to Mega Evolve in battle.",
     },
     "froslassite" => ItemData {
         num: 2566,
         name: "Froslassite",
-        description: "",
+        short_desc: "If held by a Froslass, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Froslass, this item allows it to Mega Evolve in battle.",
     },
     "emboarite" => ItemData {
         num: 2569,
         name: "Emboarite",
-        description: "",
+        short_desc: "If held by an Emboar, this item allows it to Mega Evolve in battle.",
+        description: "If held by an Emboar, this item allows it to Mega Evolve in battle.",
     },
     "excadrite" => ItemData {
         num: 2570,
         name: "Excadrite",
-        description: "",
+        short_desc: "If held by an Excadrill, this item allows it to Mega Evolve in battle.",
+        description: "If held by an Excadrill, this item allows it to Mega Evolve in battle.",
     },
     "scolipite" => ItemData {
         num: 2571,
         name: "Scolipite",
-        description: "",
+        short_desc: "If held by a Scolipede, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Scolipede, this item allows it to Mega Evolve in battle.",
     },
     "scraftinite" => ItemData {
         num: 2572,
         name: "Scraftinite",
-        description: "",
+        short_desc: "If held by a Scrafty, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Scrafty, this item allows it to Mega Evolve in battle.",
     },
     "eelektrossite" => ItemData {
         num: 2573,
         name: "Eelektrossite",
-        description: "",
+        short_desc: "If held by an Eelektross, this item allows it to Mega Evolve in battle.",
+        description: "If held by an Eelektross, this item allows it to Mega Evolve in battle.",
     },
     "chandelurite" => ItemData {
         num: 2574,
         name: "Chandelurite",
-        description: "",
+        short_desc: "If held by a Chandelure, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Chandelure, this item allows it to Mega Evolve in battle.",
     },
     "chesnaughtite" => ItemData {
         num: 2575,
         name: "Chesnaughtite",
-        description: "",
+        short_desc: "If held by a Chesnaught, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Chesnaught, this item allows it to Mega Evolve in battle.",
     },
     "delphoxite" => ItemData {
         num: 2576,
         name: "Delphoxite",
-        description: "",
+        short_desc: "If held by a Delphox, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Delphox, this item allows it to Mega Evolve in battle.",
     },
     "greninjite" => ItemData {
         num: 2577,
         name: "Greninjite",
-        description: "",
+        short_desc: "If held by a Greninja, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Greninja, this item allows it to Mega Evolve in battle.",
     },
     "pyroarite" => ItemData {
         num: 2578,
         name: "Pyroarite",
-        description: "",
+        short_desc: "If held by a Pyroar, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Pyroar, this item allows it to Mega Evolve in battle.",
     },
     "floettite" => ItemData {
         num: 2579,
         name: "Floettite",
-        description: "",
+        short_desc: "If held by a Floette, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Floette, this item allows it to Mega Evolve in battle.",
     },
     "malamarite" => ItemData {
         num: 2580,
         name: "Malamarite",
-        description: "",
+        short_desc: "If held by a Malamar, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Malamar, this item allows it to Mega Evolve in battle.",
     },
     "barbaracite" => ItemData {
         num: 2581,
         name: "Barbaracite",
-        description: "",
+        short_desc: "If held by a Barbaracle, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Barbaracle, this item allows it to Mega Evolve in battle.",
     },
     "dragalgite" => ItemData {
         num: 2582,
         name: "Dragalgite",
-        description: "",
+        short_desc: "If held by a Dragalge, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Dragalge, this item allows it to Mega Evolve in battle.",
     },
     "hawluchanite" => ItemData {
         num: 2583,
         name: "Hawluchanite",
-        description: "",
+        short_desc: "If held by a Hawlucha, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Hawlucha, this item allows it to Mega Evolve in battle.",
     },
     "zygardite" => ItemData {
         num: 2584,
         name: "Zygardite",
-        description: "",
+        short_desc: "If held by a Zygarde, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Zygarde, this item allows it to Mega Evolve in battle.",
     },
     "drampanite" => ItemData {
         num: 2585,
         name: "Drampanite",
-        description: "",
+        short_desc: "If held by a Drampa, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Drampa, this item allows it to Mega Evolve in battle.",
     },
     "raichunitex" => ItemData {
         num: 2585,
         name: "Raichunite X",
-        description: "",
+        short_desc: "If held by a Raichu, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Raichu, this item allows it to Mega Evolve in battle.",
     },
     "falinksite" => ItemData {
         num: 2586,
         name: "Falinksite",
-        description: "",
+        short_desc: "If held by a Falinks, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Falinks, this item allows it to Mega Evolve in battle.",
     },
     "raichunitey" => ItemData {
         num: 2586,
         name: "Raichunite Y",
-        description: "",
+        short_desc: "If held by a Raichu, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Raichu, this item allows it to Mega Evolve in battle.",
     },
     "chimechite" => ItemData {
         num: 2587,
         name: "Chimechite",
-        description: "",
+        short_desc: "If held by a Chimecho, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Chimecho, this item allows it to Mega Evolve in battle.",
     },
     "absolitez" => ItemData {
         num: 2588,
         name: "Absolite Z",
-        description: "",
+        short_desc: "If held by an Absol, this item allows it to Mega Evolve in battle.",
+        description: "If held by an Absol, this item allows it to Mega Evolve in battle.",
     },
     "staraptite" => ItemData {
         num: 2589,
         name: "Staraptite",
-        description: "",
+        short_desc: "If held by a Staraptor, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Staraptor, this item allows it to Mega Evolve in battle.",
     },
     "garchompitez" => ItemData {
         num: 2590,
         name: "Garchompite Z",
-        description: "",
+        short_desc: "If held by a Garchomp, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Garchomp, this item allows it to Mega Evolve in battle.",
     },
     "lucarionitez" => ItemData {
         num: 2591,
         name: "Lucarionite Z",
-        description: "",
+        short_desc: "If held by a Lucario, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Lucario, this item allows it to Mega Evolve in battle.",
     },
     "heatranite" => ItemData {
         num: 2592,
         name: "Heatranite",
-        description: "",
+        short_desc: "If held by a Heatran, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Heatran, this item allows it to Mega Evolve in battle.",
     },
     "darkranite" => ItemData {
         num: 2593,
         name: "Darkranite",
-        description: "",
+        short_desc: "If held by a Darkrai, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Darkrai, this item allows it to Mega Evolve in battle.",
     },
     "golurkite" => ItemData {
         num: 2594,
         name: "Golurkite",
-        description: "",
+        short_desc: "If held by a Golurk, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Golurk, this item allows it to Mega Evolve in battle.",
     },
     "meowsticite" => ItemData {
         num: 2594,
         name: "Meowsticite",
-        description: "",
+        short_desc: "If held by a Meowstic, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Meowstic, this item allows it to Mega Evolve in battle.",
     },
     "crabominite" => ItemData {
         num: 2595,
         name: "Crabominite",
-        description: "",
+        short_desc: "If held by a Crabominable, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Crabominable, this item allows it to Mega Evolve in battle.",
     },
     "golisopite" => ItemData {
         num: 2596,
         name: "Golisopite",
-        description: "",
+        short_desc: "If held by a Golisopod, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Golisopod, this item allows it to Mega Evolve in battle.",
     },
     "magearnite" => ItemData {
         num: 2597,
         name: "Magearnite",
-        description: "",
+        short_desc: "If held by a Magearna, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Magearna, this item allows it to Mega Evolve in battle.",
     },
     "zeraorite" => ItemData {
         num: 2598,
         name: "Zeraorite",
-        description: "",
+        short_desc: "If held by a Zeraora, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Zeraora, this item allows it to Mega Evolve in battle.",
     },
     "scovillainite" => ItemData {
         num: 2599,
         name: "Scovillainite",
-        description: "",
+        short_desc: "If held by a Scovillain, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Scovillain, this item allows it to Mega Evolve in battle.",
     },
     "glimmoranite" => ItemData {
         num: 2600,
         name: "Glimmoranite",
-        description: "",
+        short_desc: "If held by a Glimmora, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Glimmora, this item allows it to Mega Evolve in battle.",
     },
     "baxcalibrite" => ItemData {
         num: 2601,
         name: "Baxcalibrite",
-        description: "",
+        short_desc: "If held by a Baxcalibur, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Baxcalibur, this item allows it to Mega Evolve in battle.",
     },
     "tatsugirinite" => ItemData {
         num: 2601,
         name: "Tatsugirinite",
-        description: "",
+        short_desc: "If held by a Tatsugiri, this item allows it to Mega Evolve in battle.",
+        description: "If held by a Tatsugiri, this item allows it to Mega Evolve in battle.",
     },
 };
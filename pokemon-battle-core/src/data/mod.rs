@@ -3,6 +3,7 @@ pub mod moves;
 pub mod abilities;
 pub mod items;
 pub mod types;
+pub mod learnsets;
 
 #[cfg(test)]
 mod tests;
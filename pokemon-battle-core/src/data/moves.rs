@@ -1,7 +1,7 @@
-// AUTO-GENERATED by tools/extract_data.js
+// AUTO-GENERATED by `pokemon-battle-cli gen-data`. Do not edit by hand.
 use phf::phf_map;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum MoveCategory {
     Physical,
     Special,
@@ -38,6 +38,8 @@ pub struct MoveData {
     pub recoil: Option<(u8, u8)>,
     pub drain: Option<(u8, u8)>,
     pub multihit: Option<(u8, u8)>,
+    pub short_desc: &'static str,
+    pub description: &'static str,
 }
 
 pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
@@ -69,6 +71,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to freeze the target.",
+        description: "10% chance to freeze the target.",
     },
     "shadowstrike" => MoveData {
         num: -2,
@@ -98,6 +102,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "50% chance to lower the target's Defense by 1.",
+        description: "50% chance to lower the target's Defense by 1.",
     },
     "paleowave" => MoveData {
         num: -1,
@@ -127,6 +133,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "20% chance to lower the target's Attack by 1.",
+        description: "20% chance to lower the target's Attack by 1.",
     },
     "pound" => MoveData {
         num: 1,
@@ -146,6 +154,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "karatechop" => MoveData {
         num: 2,
@@ -165,6 +175,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "High critical hit ratio.",
+        description: "High critical hit ratio.",
     },
     "doubleslap" => MoveData {
         num: 3,
@@ -184,6 +196,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: Some((2, 5)),
+        short_desc: "Hits 2-5 times in one turn.",
+        description: "Hits 2-5 times in one turn.",
     },
     "cometpunch" => MoveData {
         num: 4,
@@ -203,6 +217,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: Some((2, 5)),
+        short_desc: "Hits 2-5 times in one turn.",
+        description: "Hits 2-5 times in one turn.",
     },
     "megapunch" => MoveData {
         num: 5,
@@ -222,6 +238,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "payday" => MoveData {
         num: 6,
@@ -241,6 +259,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Scatters coins.",
+        description: "Scatters coins.",
     },
     "firepunch" => MoveData {
         num: 7,
@@ -270,6 +290,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to burn the target.",
+        description: "10% chance to burn the target.",
     },
     "icepunch" => MoveData {
         num: 8,
@@ -299,6 +321,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to freeze the target.",
+        description: "10% chance to freeze the target.",
     },
     "thunderpunch" => MoveData {
         num: 9,
@@ -328,6 +352,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to paralyze the target.",
+        description: "10% chance to paralyze the target.",
     },
     "scratch" => MoveData {
         num: 10,
@@ -347,6 +373,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "visegrip" => MoveData {
         num: 11,
@@ -366,6 +394,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "guillotine" => MoveData {
         num: 12,
@@ -385,6 +415,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "OHKOs the target. Fails if user is a lower level.",
+        description: "OHKOs the target. Fails if user is a lower level.",
     },
     "razorwind" => MoveData {
         num: 13,
@@ -404,6 +436,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Charges, then hits foe(s) turn 2. High crit ratio.",
+        description: "Charges, then hits foe(s) turn 2. High crit ratio.",
     },
     "swordsdance" => MoveData {
         num: 14,
@@ -423,6 +457,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Attack by 2.",
+        description: "Raises the user's Attack by 2.",
     },
     "cut" => MoveData {
         num: 15,
@@ -442,6 +478,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "gust" => MoveData {
         num: 16,
@@ -461,6 +499,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power doubles during Bounce, Fly, and Sky Drop.",
+        description: "Power doubles during Bounce, Fly, and Sky Drop.",
     },
     "wingattack" => MoveData {
         num: 17,
@@ -480,6 +520,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "whirlwind" => MoveData {
         num: 18,
@@ -499,6 +541,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Forces the target to switch to a random ally.",
+        description: "Forces the target to switch to a random ally.",
     },
     "fly" => MoveData {
         num: 19,
@@ -518,6 +562,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Flies up on first turn, then strikes the next turn.",
+        description: "Flies up on first turn, then strikes the next turn.",
     },
     "bind" => MoveData {
         num: 20,
@@ -537,6 +583,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Traps and damages the target for 4-5 turns.",
+        description: "Traps and damages the target for 4-5 turns.",
     },
     "slam" => MoveData {
         num: 21,
@@ -556,6 +604,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "vinewhip" => MoveData {
         num: 22,
@@ -575,6 +625,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "stomp" => MoveData {
         num: 23,
@@ -604,6 +656,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to make the target flinch.",
+        description: "30% chance to make the target flinch.",
     },
     "doublekick" => MoveData {
         num: 24,
@@ -623,6 +677,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hits 2 times in one turn.",
+        description: "Hits 2 times in one turn.",
     },
     "megakick" => MoveData {
         num: 25,
@@ -642,6 +698,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "jumpkick" => MoveData {
         num: 26,
@@ -661,6 +719,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User is hurt by 50% of its max HP if it misses.",
+        description: "User is hurt by 50% of its max HP if it misses.",
     },
     "rollingkick" => MoveData {
         num: 27,
@@ -690,6 +750,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to make the target flinch.",
+        description: "30% chance to make the target flinch.",
     },
     "sandattack" => MoveData {
         num: 28,
@@ -709,6 +771,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the target's accuracy by 1.",
+        description: "Lowers the target's accuracy by 1.",
     },
     "headbutt" => MoveData {
         num: 29,
@@ -738,6 +802,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to make the target flinch.",
+        description: "30% chance to make the target flinch.",
     },
     "hornattack" => MoveData {
         num: 30,
@@ -757,6 +823,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "furyattack" => MoveData {
         num: 31,
@@ -776,6 +844,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: Some((2, 5)),
+        short_desc: "Hits 2-5 times in one turn.",
+        description: "Hits 2-5 times in one turn.",
     },
     "horndrill" => MoveData {
         num: 32,
@@ -795,6 +865,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "OHKOs the target. Fails if user is a lower level.",
+        description: "OHKOs the target. Fails if user is a lower level.",
     },
     "tackle" => MoveData {
         num: 33,
@@ -814,6 +886,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "bodyslam" => MoveData {
         num: 34,
@@ -843,6 +917,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to paralyze the target.",
+        description: "30% chance to paralyze the target.",
     },
     "wrap" => MoveData {
         num: 35,
@@ -862,6 +938,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Traps and damages the target for 4-5 turns.",
+        description: "Traps and damages the target for 4-5 turns.",
     },
     "takedown" => MoveData {
         num: 36,
@@ -881,6 +959,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: Some((1, 4)),
         drain: None,
         multihit: None,
+        short_desc: "Has 1/4 recoil.",
+        description: "Has 1/4 recoil.",
     },
     "thrash" => MoveData {
         num: 37,
@@ -910,6 +990,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lasts 2-3 turns. Confuses the user afterwards.",
+        description: "Lasts 2-3 turns. Confuses the user afterwards.",
     },
     "doubleedge" => MoveData {
         num: 38,
@@ -929,6 +1011,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: Some((33, 100)),
         drain: None,
         multihit: None,
+        short_desc: "Has 33% recoil.",
+        description: "Has 33% recoil.",
     },
     "tailwhip" => MoveData {
         num: 39,
@@ -948,6 +1032,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the foe(s) Defense by 1.",
+        description: "Lowers the foe(s) Defense by 1.",
     },
     "poisonsting" => MoveData {
         num: 40,
@@ -977,6 +1063,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to poison the target.",
+        description: "30% chance to poison the target.",
     },
     "twineedle" => MoveData {
         num: 41,
@@ -1006,6 +1094,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hits 2 times. Each hit has 20% chance to poison.",
+        description: "Hits 2 times. Each hit has 20% chance to poison.",
     },
     "pinmissile" => MoveData {
         num: 42,
@@ -1025,6 +1115,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: Some((2, 5)),
+        short_desc: "Hits 2-5 times in one turn.",
+        description: "Hits 2-5 times in one turn.",
     },
     "leer" => MoveData {
         num: 43,
@@ -1044,6 +1136,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the foe(s) Defense by 1.",
+        description: "Lowers the foe(s) Defense by 1.",
     },
     "bite" => MoveData {
         num: 44,
@@ -1073,6 +1167,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to make the target flinch.",
+        description: "30% chance to make the target flinch.",
     },
     "growl" => MoveData {
         num: 45,
@@ -1092,6 +1188,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the foe(s) Attack by 1.",
+        description: "Lowers the foe(s) Attack by 1.",
     },
     "roar" => MoveData {
         num: 46,
@@ -1111,6 +1209,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Forces the target to switch to a random ally.",
+        description: "Forces the target to switch to a random ally.",
     },
     "sing" => MoveData {
         num: 47,
@@ -1130,6 +1230,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Causes the target to fall asleep.",
+        description: "Causes the target to fall asleep.",
     },
     "supersonic" => MoveData {
         num: 48,
@@ -1149,6 +1251,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Causes the target to become confused.",
+        description: "Causes the target to become confused.",
     },
     "sonicboom" => MoveData {
         num: 49,
@@ -1168,6 +1272,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Always does 20 HP of damage.",
+        description: "Always does 20 HP of damage.",
     },
     "disable" => MoveData {
         num: 50,
@@ -1187,6 +1293,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "For 4 turns, disables the target's last move used.",
+        description: "For 4 turns, disables the target's last move used.",
     },
     "acid" => MoveData {
         num: 51,
@@ -1216,6 +1324,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to lower the foe(s) Sp. Def by 1.",
+        description: "10% chance to lower the foe(s) Sp. Def by 1.",
     },
     "ember" => MoveData {
         num: 52,
@@ -1245,6 +1355,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to burn the target.",
+        description: "10% chance to burn the target.",
     },
     "flamethrower" => MoveData {
         num: 53,
@@ -1274,6 +1386,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to burn the target.",
+        description: "10% chance to burn the target.",
     },
     "mist" => MoveData {
         num: 54,
@@ -1293,6 +1407,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "For 5 turns, protects user's party from stat drops.",
+        description: "For 5 turns, protects user's party from stat drops.",
     },
     "watergun" => MoveData {
         num: 55,
@@ -1312,6 +1428,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "hydropump" => MoveData {
         num: 56,
@@ -1331,6 +1449,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "surf" => MoveData {
         num: 57,
@@ -1350,6 +1470,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hits adjacent Pokemon. Double damage on Dive.",
+        description: "Hits adjacent Pokemon. Double damage on Dive.",
     },
     "icebeam" => MoveData {
         num: 58,
@@ -1379,6 +1501,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to freeze the target.",
+        description: "10% chance to freeze the target.",
     },
     "blizzard" => MoveData {
         num: 59,
@@ -1408,6 +1532,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to freeze foe(s). Can't miss in Snow.",
+        description: "10% chance to freeze foe(s). Can't miss in Snow.",
     },
     "psybeam" => MoveData {
         num: 60,
@@ -1437,6 +1563,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to confuse the target.",
+        description: "10% chance to confuse the target.",
     },
     "bubblebeam" => MoveData {
         num: 61,
@@ -1466,6 +1594,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to lower the target's Speed by 1.",
+        description: "10% chance to lower the target's Speed by 1.",
     },
     "aurorabeam" => MoveData {
         num: 62,
@@ -1495,6 +1625,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to lower the target's Attack by 1.",
+        description: "10% chance to lower the target's Attack by 1.",
     },
     "hyperbeam" => MoveData {
         num: 63,
@@ -1524,6 +1656,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User cannot move next turn.",
+        description: "User cannot move next turn.",
     },
     "peck" => MoveData {
         num: 64,
@@ -1543,6 +1677,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "drillpeck" => MoveData {
         num: 65,
@@ -1562,6 +1698,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "submission" => MoveData {
         num: 66,
@@ -1581,6 +1719,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: Some((1, 4)),
         drain: None,
         multihit: None,
+        short_desc: "Has 1/4 recoil.",
+        description: "Has 1/4 recoil.",
     },
     "lowkick" => MoveData {
         num: 67,
@@ -1600,6 +1740,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "More power the heavier the target.",
+        description: "More power the heavier the target.",
     },
     "counter" => MoveData {
         num: 68,
@@ -1619,6 +1761,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "If hit by physical attack, returns double damage.",
+        description: "If hit by physical attack, returns double damage.",
     },
     "seismictoss" => MoveData {
         num: 69,
@@ -1638,6 +1782,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Does damage equal to the user's level.",
+        description: "Does damage equal to the user's level.",
     },
     "strength" => MoveData {
         num: 70,
@@ -1657,6 +1803,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "absorb" => MoveData {
         num: 71,
@@ -1676,6 +1824,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: Some((1, 2)),
         multihit: None,
+        short_desc: "User recovers 50% of the damage dealt.",
+        description: "User recovers 50% of the damage dealt.",
     },
     "megadrain" => MoveData {
         num: 72,
@@ -1695,6 +1845,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: Some((1, 2)),
         multihit: None,
+        short_desc: "User recovers 50% of the damage dealt.",
+        description: "User recovers 50% of the damage dealt.",
     },
     "leechseed" => MoveData {
         num: 73,
@@ -1714,6 +1866,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "1/8 of target's HP is restored to user every turn.",
+        description: "1/8 of target's HP is restored to user every turn.",
     },
     "growth" => MoveData {
         num: 74,
@@ -1733,6 +1887,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises user's Attack and Sp. Atk by 1; 2 in Sun.",
+        description: "Raises user's Attack and Sp. Atk by 1; 2 in Sun.",
     },
     "razorleaf" => MoveData {
         num: 75,
@@ -1752,6 +1908,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "High critical hit ratio. Hits adjacent foes.",
+        description: "High critical hit ratio. Hits adjacent foes.",
     },
     "solarbeam" => MoveData {
         num: 76,
@@ -1771,6 +1929,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Charges turn 1. Hits turn 2. No charge in sunlight.",
+        description: "Charges turn 1. Hits turn 2. No charge in sunlight.",
     },
     "poisonpowder" => MoveData {
         num: 77,
@@ -1790,6 +1950,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Poisons the target.",
+        description: "Poisons the target.",
     },
     "stunspore" => MoveData {
         num: 78,
@@ -1809,6 +1971,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Paralyzes the target.",
+        description: "Paralyzes the target.",
     },
     "sleeppowder" => MoveData {
         num: 79,
@@ -1828,6 +1992,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Causes the target to fall asleep.",
+        description: "Causes the target to fall asleep.",
     },
     "petaldance" => MoveData {
         num: 80,
@@ -1857,6 +2023,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lasts 2-3 turns. Confuses the user afterwards.",
+        description: "Lasts 2-3 turns. Confuses the user afterwards.",
     },
     "stringshot" => MoveData {
         num: 81,
@@ -1876,6 +2044,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the foe(s) Speed by 2.",
+        description: "Lowers the foe(s) Speed by 2.",
     },
     "dragonrage" => MoveData {
         num: 82,
@@ -1895,6 +2065,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Deals 40 HP of damage to the target.",
+        description: "Deals 40 HP of damage to the target.",
     },
     "firespin" => MoveData {
         num: 83,
@@ -1914,6 +2086,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Traps and damages the target for 4-5 turns.",
+        description: "Traps and damages the target for 4-5 turns.",
     },
     "thundershock" => MoveData {
         num: 84,
@@ -1943,6 +2117,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to paralyze the target.",
+        description: "10% chance to paralyze the target.",
     },
     "thunderbolt" => MoveData {
         num: 85,
@@ -1972,6 +2148,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to paralyze the target.",
+        description: "10% chance to paralyze the target.",
     },
     "thunderwave" => MoveData {
         num: 86,
@@ -1991,6 +2169,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Paralyzes the target.",
+        description: "Paralyzes the target.",
     },
     "thunder" => MoveData {
         num: 87,
@@ -2020,6 +2200,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to paralyze. Can't miss in rain.",
+        description: "30% chance to paralyze. Can't miss in rain.",
     },
     "rockthrow" => MoveData {
         num: 88,
@@ -2039,6 +2221,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "earthquake" => MoveData {
         num: 89,
@@ -2058,6 +2242,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hits adjacent Pokemon. Double damage on Dig.",
+        description: "Hits adjacent Pokemon. Double damage on Dig.",
     },
     "fissure" => MoveData {
         num: 90,
@@ -2077,6 +2263,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "OHKOs the target. Fails if user is a lower level.",
+        description: "OHKOs the target. Fails if user is a lower level.",
     },
     "dig" => MoveData {
         num: 91,
@@ -2096,6 +2284,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Digs underground turn 1, strikes turn 2.",
+        description: "Digs underground turn 1, strikes turn 2.",
     },
     "toxic" => MoveData {
         num: 92,
@@ -2115,6 +2305,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Badly poisons the target. Poison types can't miss.",
+        description: "Badly poisons the target. Poison types can't miss.",
     },
     "confusion" => MoveData {
         num: 93,
@@ -2144,6 +2336,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to confuse the target.",
+        description: "10% chance to confuse the target.",
     },
     "psychic" => MoveData {
         num: 94,
@@ -2173,6 +2367,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to lower the target's Sp. Def by 1.",
+        description: "10% chance to lower the target's Sp. Def by 1.",
     },
     "hypnosis" => MoveData {
         num: 95,
@@ -2192,6 +2388,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Causes the target to fall asleep.",
+        description: "Causes the target to fall asleep.",
     },
     "meditate" => MoveData {
         num: 96,
@@ -2211,6 +2409,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Attack by 1.",
+        description: "Raises the user's Attack by 1.",
     },
     "agility" => MoveData {
         num: 97,
@@ -2230,6 +2430,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Speed by 2.",
+        description: "Raises the user's Speed by 2.",
     },
     "quickattack" => MoveData {
         num: 98,
@@ -2249,6 +2451,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Usually goes first.",
+        description: "Usually goes first.",
     },
     "rage" => MoveData {
         num: 99,
@@ -2278,6 +2482,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Attack by 1 if hit during use.",
+        description: "Raises the user's Attack by 1 if hit during use.",
     },
     "teleport" => MoveData {
         num: 100,
@@ -2297,6 +2503,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User switches out.",
+        description: "User switches out.",
     },
     "nightshade" => MoveData {
         num: 101,
@@ -2316,6 +2524,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Does damage equal to the user's level.",
+        description: "Does damage equal to the user's level.",
     },
     "mimic" => MoveData {
         num: 102,
@@ -2335,6 +2545,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "The last move the target used replaces this one.",
+        description: "The last move the target used replaces this one.",
     },
     "screech" => MoveData {
         num: 103,
@@ -2354,6 +2566,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the target's Defense by 2.",
+        description: "Lowers the target's Defense by 2.",
     },
     "doubleteam" => MoveData {
         num: 104,
@@ -2373,6 +2587,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's evasiveness by 1.",
+        description: "Raises the user's evasiveness by 1.",
     },
     "recover" => MoveData {
         num: 105,
@@ -2392,6 +2608,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Heals the user by 50% of its max HP.",
+        description: "Heals the user by 50% of its max HP.",
     },
     "harden" => MoveData {
         num: 106,
@@ -2411,6 +2629,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Defense by 1.",
+        description: "Raises the user's Defense by 1.",
     },
     "minimize" => MoveData {
         num: 107,
@@ -2430,6 +2650,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's evasiveness by 2.",
+        description: "Raises the user's evasiveness by 2.",
     },
     "smokescreen" => MoveData {
         num: 108,
@@ -2449,6 +2671,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the target's accuracy by 1.",
+        description: "Lowers the target's accuracy by 1.",
     },
     "confuseray" => MoveData {
         num: 109,
@@ -2468,6 +2692,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Confuses the target.",
+        description: "Confuses the target.",
     },
     "withdraw" => MoveData {
         num: 110,
@@ -2487,6 +2713,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Defense by 1.",
+        description: "Raises the user's Defense by 1.",
     },
     "defensecurl" => MoveData {
         num: 111,
@@ -2506,6 +2734,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Defense by 1.",
+        description: "Raises the user's Defense by 1.",
     },
     "barrier" => MoveData {
         num: 112,
@@ -2525,6 +2755,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Defense by 2.",
+        description: "Raises the user's Defense by 2.",
     },
     "lightscreen" => MoveData {
         num: 113,
@@ -2544,6 +2776,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "For 5 turns, special damage to allies is halved.",
+        description: "For 5 turns, special damage to allies is halved.",
     },
     "haze" => MoveData {
         num: 114,
@@ -2563,6 +2797,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Eliminates all stat changes.",
+        description: "Eliminates all stat changes.",
     },
     "reflect" => MoveData {
         num: 115,
@@ -2582,6 +2818,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "For 5 turns, physical damage to allies is halved.",
+        description: "For 5 turns, physical damage to allies is halved.",
     },
     "focusenergy" => MoveData {
         num: 116,
@@ -2601,6 +2839,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's critical hit ratio by 2.",
+        description: "Raises the user's critical hit ratio by 2.",
     },
     "bide" => MoveData {
         num: 117,
@@ -2620,6 +2860,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Waits 2 turns; deals double the damage taken.",
+        description: "Waits 2 turns; deals double the damage taken.",
     },
     "metronome" => MoveData {
         num: 118,
@@ -2639,6 +2881,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Picks a random move.",
+        description: "Picks a random move.",
     },
     "mirrormove" => MoveData {
         num: 119,
@@ -2658,6 +2902,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User uses the target's last used move.",
+        description: "User uses the target's last used move.",
     },
     "selfdestruct" => MoveData {
         num: 120,
@@ -2677,6 +2923,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hits adjacent Pokemon. The user faints.",
+        description: "Hits adjacent Pokemon. The user faints.",
     },
     "eggbomb" => MoveData {
         num: 121,
@@ -2696,6 +2944,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "lick" => MoveData {
         num: 122,
@@ -2725,6 +2975,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to paralyze the target.",
+        description: "30% chance to paralyze the target.",
     },
     "smog" => MoveData {
         num: 123,
@@ -2754,6 +3006,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "40% chance to poison the target.",
+        description: "40% chance to poison the target.",
     },
     "sludge" => MoveData {
         num: 124,
@@ -2783,6 +3037,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to poison the target.",
+        description: "30% chance to poison the target.",
     },
     "boneclub" => MoveData {
         num: 125,
@@ -2812,6 +3068,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to make the target flinch.",
+        description: "10% chance to make the target flinch.",
     },
     "fireblast" => MoveData {
         num: 126,
@@ -2841,6 +3099,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to burn the target.",
+        description: "10% chance to burn the target.",
     },
     "waterfall" => MoveData {
         num: 127,
@@ -2870,6 +3130,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "20% chance to make the target flinch.",
+        description: "20% chance to make the target flinch.",
     },
     "clamp" => MoveData {
         num: 128,
@@ -2889,6 +3151,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Traps and damages the target for 4-5 turns.",
+        description: "Traps and damages the target for 4-5 turns.",
     },
     "swift" => MoveData {
         num: 129,
@@ -2908,6 +3172,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "This move does not check accuracy. Hits foes.",
+        description: "This move does not check accuracy. Hits foes.",
     },
     "skullbash" => MoveData {
         num: 130,
@@ -2927,6 +3193,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises user's Defense by 1 on turn 1. Hits turn 2.",
+        description: "Raises user's Defense by 1 on turn 1. Hits turn 2.",
     },
     "spikecannon" => MoveData {
         num: 131,
@@ -2946,6 +3214,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: Some((2, 5)),
+        short_desc: "Hits 2-5 times in one turn.",
+        description: "Hits 2-5 times in one turn.",
     },
     "constrict" => MoveData {
         num: 132,
@@ -2975,6 +3245,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to lower the target's Speed by 1.",
+        description: "10% chance to lower the target's Speed by 1.",
     },
     "amnesia" => MoveData {
         num: 133,
@@ -2994,6 +3266,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Sp. Def by 2.",
+        description: "Raises the user's Sp. Def by 2.",
     },
     "kinesis" => MoveData {
         num: 134,
@@ -3013,6 +3287,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the target's accuracy by 1.",
+        description: "Lowers the target's accuracy by 1.",
     },
     "softboiled" => MoveData {
         num: 135,
@@ -3032,6 +3308,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Heals the user by 50% of its max HP.",
+        description: "Heals the user by 50% of its max HP.",
     },
     "highjumpkick" => MoveData {
         num: 136,
@@ -3051,6 +3329,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User is hurt by 50% of its max HP if it misses.",
+        description: "User is hurt by 50% of its max HP if it misses.",
     },
     "glare" => MoveData {
         num: 137,
@@ -3070,6 +3350,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Paralyzes the target.",
+        description: "Paralyzes the target.",
     },
     "dreameater" => MoveData {
         num: 138,
@@ -3089,6 +3371,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: Some((1, 2)),
         multihit: None,
+        short_desc: "User gains 1/2 HP inflicted. Sleeping target only.",
+        description: "User gains 1/2 HP inflicted. Sleeping target only.",
     },
     "poisongas" => MoveData {
         num: 139,
@@ -3108,6 +3392,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Poisons the foe(s).",
+        description: "Poisons the foe(s).",
     },
     "barrage" => MoveData {
         num: 140,
@@ -3127,6 +3413,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: Some((2, 5)),
+        short_desc: "Hits 2-5 times in one turn.",
+        description: "Hits 2-5 times in one turn.",
     },
     "leechlife" => MoveData {
         num: 141,
@@ -3146,6 +3434,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: Some((1, 2)),
         multihit: None,
+        short_desc: "User recovers 50% of the damage dealt.",
+        description: "User recovers 50% of the damage dealt.",
     },
     "lovelykiss" => MoveData {
         num: 142,
@@ -3165,6 +3455,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Causes the target to fall asleep.",
+        description: "Causes the target to fall asleep.",
     },
     "skyattack" => MoveData {
         num: 143,
@@ -3194,6 +3486,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Charges, then hits turn 2. 30% flinch. High crit.",
+        description: "Charges, then hits turn 2. 30% flinch. High crit.",
     },
     "transform" => MoveData {
         num: 144,
@@ -3213,6 +3507,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Copies target's stats, moves, types, and Ability.",
+        description: "Copies target's stats, moves, types, and Ability.",
     },
     "bubble" => MoveData {
         num: 145,
@@ -3242,6 +3538,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to lower the foe(s) Speed by 1.",
+        description: "10% chance to lower the foe(s) Speed by 1.",
     },
     "dizzypunch" => MoveData {
         num: 146,
@@ -3271,6 +3569,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "20% chance to confuse the target.",
+        description: "20% chance to confuse the target.",
     },
     "spore" => MoveData {
         num: 147,
@@ -3290,6 +3590,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Causes the target to fall asleep.",
+        description: "Causes the target to fall asleep.",
     },
     "flash" => MoveData {
         num: 148,
@@ -3309,6 +3611,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the target's accuracy by 1.",
+        description: "Lowers the target's accuracy by 1.",
     },
     "psywave" => MoveData {
         num: 149,
@@ -3328,6 +3632,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Random damage equal to 0.5x-1.5x user's level.",
+        description: "Random damage equal to 0.5x-1.5x user's level.",
     },
     "splash" => MoveData {
         num: 150,
@@ -3347,6 +3653,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No competitive use.",
+        description: "No competitive use.",
     },
     "acidarmor" => MoveData {
         num: 151,
@@ -3366,6 +3674,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Defense by 2.",
+        description: "Raises the user's Defense by 2.",
     },
     "crabhammer" => MoveData {
         num: 152,
@@ -3385,6 +3695,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "High critical hit ratio.",
+        description: "High critical hit ratio.",
     },
     "explosion" => MoveData {
         num: 153,
@@ -3404,6 +3716,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hits adjacent Pokemon. The user faints.",
+        description: "Hits adjacent Pokemon. The user faints.",
     },
     "furyswipes" => MoveData {
         num: 154,
@@ -3423,6 +3737,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: Some((2, 5)),
+        short_desc: "Hits 2-5 times in one turn.",
+        description: "Hits 2-5 times in one turn.",
     },
     "bonemerang" => MoveData {
         num: 155,
@@ -3442,6 +3758,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hits 2 times in one turn.",
+        description: "Hits 2 times in one turn.",
     },
     "rest" => MoveData {
         num: 156,
@@ -3461,6 +3779,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User sleeps 2 turns and restores HP and status.",
+        description: "User sleeps 2 turns and restores HP and status.",
     },
     "rockslide" => MoveData {
         num: 157,
@@ -3490,6 +3810,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to make the foe(s) flinch.",
+        description: "30% chance to make the foe(s) flinch.",
     },
     "hyperfang" => MoveData {
         num: 158,
@@ -3519,6 +3841,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to make the target flinch.",
+        description: "10% chance to make the target flinch.",
     },
     "sharpen" => MoveData {
         num: 159,
@@ -3538,6 +3862,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Attack by 1.",
+        description: "Raises the user's Attack by 1.",
     },
     "conversion" => MoveData {
         num: 160,
@@ -3557,6 +3883,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Changes user's type to match its first move.",
+        description: "Changes user's type to match its first move.",
     },
     "triattack" => MoveData {
         num: 161,
@@ -3586,6 +3914,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "20% chance to paralyze or burn or freeze target.",
+        description: "20% chance to paralyze or burn or freeze target.",
     },
     "superfang" => MoveData {
         num: 162,
@@ -3605,6 +3935,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Does damage equal to 1/2 target's current HP.",
+        description: "Does damage equal to 1/2 target's current HP.",
     },
     "slash" => MoveData {
         num: 163,
@@ -3624,6 +3956,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "High critical hit ratio.",
+        description: "High critical hit ratio.",
     },
     "substitute" => MoveData {
         num: 164,
@@ -3643,6 +3977,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User takes 1/4 its max HP to put in a substitute.",
+        description: "User takes 1/4 its max HP to put in a substitute.",
     },
     "struggle" => MoveData {
         num: 165,
@@ -3662,6 +3998,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User loses 1/4 of its max HP.",
+        description: "User loses 1/4 of its max HP.",
     },
     "sketch" => MoveData {
         num: 166,
@@ -3681,6 +4019,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Permanently copies the last move target used.",
+        description: "Permanently copies the last move target used.",
     },
     "triplekick" => MoveData {
         num: 167,
@@ -3700,6 +4040,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hits 3 times. Each hit can miss, but power rises.",
+        description: "Hits 3 times. Each hit can miss, but power rises.",
     },
     "thief" => MoveData {
         num: 168,
@@ -3719,6 +4061,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "If the user has no item, it steals the target's.",
+        description: "If the user has no item, it steals the target's.",
     },
     "spiderweb" => MoveData {
         num: 169,
@@ -3738,6 +4082,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Prevents the target from switching out.",
+        description: "Prevents the target from switching out.",
     },
     "mindreader" => MoveData {
         num: 170,
@@ -3757,6 +4103,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User's next move will not miss the target.",
+        description: "User's next move will not miss the target.",
     },
     "nightmare" => MoveData {
         num: 171,
@@ -3776,6 +4124,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "A sleeping target is hurt by 1/4 max HP per turn.",
+        description: "A sleeping target is hurt by 1/4 max HP per turn.",
     },
     "flamewheel" => MoveData {
         num: 172,
@@ -3805,6 +4155,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to burn the target. Thaws user.",
+        description: "10% chance to burn the target. Thaws user.",
     },
     "snore" => MoveData {
         num: 173,
@@ -3834,6 +4186,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User must be asleep. 30% chance to flinch target.",
+        description: "User must be asleep. 30% chance to flinch target.",
     },
     "curse" => MoveData {
         num: 174,
@@ -3853,6 +4207,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Curses if Ghost, else -1 Spe, +1 Atk, +1 Def.",
+        description: "Curses if Ghost, else -1 Spe, +1 Atk, +1 Def.",
     },
     "flail" => MoveData {
         num: 175,
@@ -3872,6 +4228,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "More power the less HP the user has left.",
+        description: "More power the less HP the user has left.",
     },
     "conversion2" => MoveData {
         num: 176,
@@ -3891,6 +4249,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Changes user's type to resist target's last move.",
+        description: "Changes user's type to resist target's last move.",
     },
     "aeroblast" => MoveData {
         num: 177,
@@ -3910,6 +4270,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "High critical hit ratio.",
+        description: "High critical hit ratio.",
     },
     "cottonspore" => MoveData {
         num: 178,
@@ -3929,6 +4291,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the target's Speed by 2.",
+        description: "Lowers the target's Speed by 2.",
     },
     "reversal" => MoveData {
         num: 179,
@@ -3948,6 +4312,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "More power the less HP the user has left.",
+        description: "More power the less HP the user has left.",
     },
     "spite" => MoveData {
         num: 180,
@@ -3967,6 +4333,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the PP of the target's last move by 4.",
+        description: "Lowers the PP of the target's last move by 4.",
     },
     "powdersnow" => MoveData {
         num: 181,
@@ -3996,6 +4364,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to freeze the foe(s).",
+        description: "10% chance to freeze the foe(s).",
     },
     "protect" => MoveData {
         num: 182,
@@ -4015,6 +4385,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Prevents moves from affecting the user this turn.",
+        description: "Prevents moves from affecting the user this turn.",
     },
     "machpunch" => MoveData {
         num: 183,
@@ -4034,6 +4406,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Usually goes first.",
+        description: "Usually goes first.",
     },
     "scaryface" => MoveData {
         num: 184,
@@ -4053,6 +4427,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the target's Speed by 2.",
+        description: "Lowers the target's Speed by 2.",
     },
     "feintattack" => MoveData {
         num: 185,
@@ -4072,6 +4448,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "This move does not check accuracy.",
+        description: "This move does not check accuracy.",
     },
     "sweetkiss" => MoveData {
         num: 186,
@@ -4091,6 +4469,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Causes the target to become confused.",
+        description: "Causes the target to become confused.",
     },
     "bellydrum" => MoveData {
         num: 187,
@@ -4110,6 +4490,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User loses 50% max HP. Maximizes Attack.",
+        description: "User loses 50% max HP. Maximizes Attack.",
     },
     "sludgebomb" => MoveData {
         num: 188,
@@ -4139,6 +4521,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to poison the target.",
+        description: "30% chance to poison the target.",
     },
     "mudslap" => MoveData {
         num: 189,
@@ -4168,6 +4552,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to lower the target's accuracy by 1.",
+        description: "100% chance to lower the target's accuracy by 1.",
     },
     "octazooka" => MoveData {
         num: 190,
@@ -4197,6 +4583,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "50% chance to lower the target's accuracy by 1.",
+        description: "50% chance to lower the target's accuracy by 1.",
     },
     "spikes" => MoveData {
         num: 191,
@@ -4216,6 +4604,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hurts grounded foes on switch-in. Max 3 layers.",
+        description: "Hurts grounded foes on switch-in. Max 3 layers.",
     },
     "zapcannon" => MoveData {
         num: 192,
@@ -4245,6 +4635,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to paralyze the target.",
+        description: "100% chance to paralyze the target.",
     },
     "foresight" => MoveData {
         num: 193,
@@ -4264,6 +4656,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Fighting, Normal hit Ghost. Evasiveness ignored.",
+        description: "Fighting, Normal hit Ghost. Evasiveness ignored.",
     },
     "destinybond" => MoveData {
         num: 194,
@@ -4283,6 +4677,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "If an opponent knocks out the user, it also faints.",
+        description: "If an opponent knocks out the user, it also faints.",
     },
     "perishsong" => MoveData {
         num: 195,
@@ -4302,6 +4698,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "All active Pokemon will faint in 3 turns.",
+        description: "All active Pokemon will faint in 3 turns.",
     },
     "icywind" => MoveData {
         num: 196,
@@ -4331,6 +4729,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to lower the foe(s) Speed by 1.",
+        description: "100% chance to lower the foe(s) Speed by 1.",
     },
     "detect" => MoveData {
         num: 197,
@@ -4350,6 +4750,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Prevents moves from affecting the user this turn.",
+        description: "Prevents moves from affecting the user this turn.",
     },
     "bonerush" => MoveData {
         num: 198,
@@ -4369,6 +4771,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: Some((2, 5)),
+        short_desc: "Hits 2-5 times in one turn.",
+        description: "Hits 2-5 times in one turn.",
     },
     "lockon" => MoveData {
         num: 199,
@@ -4388,6 +4792,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User's next move will not miss the target.",
+        description: "User's next move will not miss the target.",
     },
     "outrage" => MoveData {
         num: 200,
@@ -4417,6 +4823,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lasts 2-3 turns. Confuses the user afterwards.",
+        description: "Lasts 2-3 turns. Confuses the user afterwards.",
     },
     "sandstorm" => MoveData {
         num: 201,
@@ -4436,6 +4844,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "For 5 turns, a sandstorm rages. Rock: 1.5x SpD.",
+        description: "For 5 turns, a sandstorm rages. Rock: 1.5x SpD.",
     },
     "gigadrain" => MoveData {
         num: 202,
@@ -4455,6 +4865,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: Some((1, 2)),
         multihit: None,
+        short_desc: "User recovers 50% of the damage dealt.",
+        description: "User recovers 50% of the damage dealt.",
     },
     "endure" => MoveData {
         num: 203,
@@ -4474,6 +4886,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User survives attacks this turn with at least 1 HP.",
+        description: "User survives attacks this turn with at least 1 HP.",
     },
     "charm" => MoveData {
         num: 204,
@@ -4493,6 +4907,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the target's Attack by 2.",
+        description: "Lowers the target's Attack by 2.",
     },
     "rollout" => MoveData {
         num: 205,
@@ -4512,6 +4928,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power doubles with each hit. Repeats for 5 turns.",
+        description: "Power doubles with each hit. Repeats for 5 turns.",
     },
     "falseswipe" => MoveData {
         num: 206,
@@ -4531,6 +4949,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Always leaves the target with at least 1 HP.",
+        description: "Always leaves the target with at least 1 HP.",
     },
     "swagger" => MoveData {
         num: 207,
@@ -4550,6 +4970,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the target's Attack by 2 and confuses it.",
+        description: "Raises the target's Attack by 2 and confuses it.",
     },
     "milkdrink" => MoveData {
         num: 208,
@@ -4569,6 +4991,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Heals the user by 50% of its max HP.",
+        description: "Heals the user by 50% of its max HP.",
     },
     "spark" => MoveData {
         num: 209,
@@ -4598,6 +5022,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to paralyze the target.",
+        description: "30% chance to paralyze the target.",
     },
     "furycutter" => MoveData {
         num: 210,
@@ -4617,6 +5043,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power doubles with each hit, up to 160.",
+        description: "Power doubles with each hit, up to 160.",
     },
     "steelwing" => MoveData {
         num: 211,
@@ -4646,6 +5074,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to raise the user's Defense by 1.",
+        description: "10% chance to raise the user's Defense by 1.",
     },
     "meanlook" => MoveData {
         num: 212,
@@ -4665,6 +5095,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Prevents the target from switching out.",
+        description: "Prevents the target from switching out.",
     },
     "attract" => MoveData {
         num: 213,
@@ -4684,6 +5116,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "A target of the opposite gender gets infatuated.",
+        description: "A target of the opposite gender gets infatuated.",
     },
     "sleeptalk" => MoveData {
         num: 214,
@@ -4703,6 +5137,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User must be asleep. Uses another known move.",
+        description: "User must be asleep. Uses another known move.",
     },
     "healbell" => MoveData {
         num: 215,
@@ -4722,6 +5158,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Cures the user's party of all status conditions.",
+        description: "Cures the user's party of all status conditions.",
     },
     "return" => MoveData {
         num: 216,
@@ -4741,6 +5179,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Max 102 power at maximum Happiness.",
+        description: "Max 102 power at maximum Happiness.",
     },
     "present" => MoveData {
         num: 217,
@@ -4760,6 +5200,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Heals target by 1/4 of its max HP or damages it.",
+        description: "Heals target by 1/4 of its max HP or damages it.",
     },
     "frustration" => MoveData {
         num: 218,
@@ -4779,6 +5221,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Max 102 power at minimum Happiness.",
+        description: "Max 102 power at minimum Happiness.",
     },
     "safeguard" => MoveData {
         num: 219,
@@ -4798,6 +5242,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "For 5 turns, protects user's party from status.",
+        description: "For 5 turns, protects user's party from status.",
     },
     "painsplit" => MoveData {
         num: 220,
@@ -4817,6 +5263,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Shares HP of user and target equally.",
+        description: "Shares HP of user and target equally.",
     },
     "sacredfire" => MoveData {
         num: 221,
@@ -4846,6 +5294,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "50% chance to burn the target. Thaws user.",
+        description: "50% chance to burn the target. Thaws user.",
     },
     "magnitude" => MoveData {
         num: 222,
@@ -4865,6 +5315,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hits adjacent Pokemon. Power varies; 2x on Dig.",
+        description: "Hits adjacent Pokemon. Power varies; 2x on Dig.",
     },
     "dynamicpunch" => MoveData {
         num: 223,
@@ -4894,6 +5346,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to confuse the target.",
+        description: "100% chance to confuse the target.",
     },
     "megahorn" => MoveData {
         num: 224,
@@ -4913,6 +5367,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "dragonbreath" => MoveData {
         num: 225,
@@ -4942,6 +5398,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to paralyze the target.",
+        description: "30% chance to paralyze the target.",
     },
     "batonpass" => MoveData {
         num: 226,
@@ -4971,6 +5429,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User switches, passing stat changes and more.",
+        description: "User switches, passing stat changes and more.",
     },
     "encore" => MoveData {
         num: 227,
@@ -4990,6 +5450,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Target repeats its last move for its next 3 turns.",
+        description: "Target repeats its last move for its next 3 turns.",
     },
     "pursuit" => MoveData {
         num: 228,
@@ -5009,6 +5471,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "If a foe is switching out, hits it at 2x power.",
+        description: "If a foe is switching out, hits it at 2x power.",
     },
     "rapidspin" => MoveData {
         num: 229,
@@ -5038,6 +5502,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Free user from hazards/bind/Leech Seed; +1 Spe.",
+        description: "Free user from hazards/bind/Leech Seed; +1 Spe.",
     },
     "sweetscent" => MoveData {
         num: 230,
@@ -5057,6 +5523,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the foe(s) evasiveness by 2.",
+        description: "Lowers the foe(s) evasiveness by 2.",
     },
     "irontail" => MoveData {
         num: 231,
@@ -5086,6 +5554,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to lower the target's Defense by 1.",
+        description: "30% chance to lower the target's Defense by 1.",
     },
     "metalclaw" => MoveData {
         num: 232,
@@ -5115,6 +5585,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to raise the user's Attack by 1.",
+        description: "10% chance to raise the user's Attack by 1.",
     },
     "vitalthrow" => MoveData {
         num: 233,
@@ -5134,6 +5606,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "This move does not check accuracy. Goes last.",
+        description: "This move does not check accuracy. Goes last.",
     },
     "morningsun" => MoveData {
         num: 234,
@@ -5153,6 +5627,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Heals the user by a weather-dependent amount.",
+        description: "Heals the user by a weather-dependent amount.",
     },
     "synthesis" => MoveData {
         num: 235,
@@ -5172,6 +5648,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Heals the user by a weather-dependent amount.",
+        description: "Heals the user by a weather-dependent amount.",
     },
     "moonlight" => MoveData {
         num: 236,
@@ -5191,6 +5669,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Heals the user by a weather-dependent amount.",
+        description: "Heals the user by a weather-dependent amount.",
     },
     "hiddenpower" => MoveData {
         num: 237,
@@ -5210,6 +5690,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Varies in type based on the user's IVs.",
+        description: "Varies in type based on the user's IVs.",
     },
     "hiddenpowerbug" => MoveData {
         num: 237,
@@ -5229,6 +5711,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Varies in type based on the user's IVs.",
+        description: "Varies in type based on the user's IVs.",
     },
     "hiddenpowerdark" => MoveData {
         num: 237,
@@ -5248,6 +5732,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Varies in type based on the user's IVs.",
+        description: "Varies in type based on the user's IVs.",
     },
     "hiddenpowerdragon" => MoveData {
         num: 237,
@@ -5267,6 +5753,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Varies in type based on the user's IVs.",
+        description: "Varies in type based on the user's IVs.",
     },
     "hiddenpowerelectric" => MoveData {
         num: 237,
@@ -5286,6 +5774,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Varies in type based on the user's IVs.",
+        description: "Varies in type based on the user's IVs.",
     },
     "hiddenpowerfighting" => MoveData {
         num: 237,
@@ -5305,6 +5795,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Varies in type based on the user's IVs.",
+        description: "Varies in type based on the user's IVs.",
     },
     "hiddenpowerfire" => MoveData {
         num: 237,
@@ -5324,6 +5816,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Varies in type based on the user's IVs.",
+        description: "Varies in type based on the user's IVs.",
     },
     "hiddenpowerflying" => MoveData {
         num: 237,
@@ -5343,6 +5837,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Varies in type based on the user's IVs.",
+        description: "Varies in type based on the user's IVs.",
     },
     "hiddenpowerghost" => MoveData {
         num: 237,
@@ -5362,6 +5858,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Varies in type based on the user's IVs.",
+        description: "Varies in type based on the user's IVs.",
     },
     "hiddenpowergrass" => MoveData {
         num: 237,
@@ -5381,6 +5879,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Varies in type based on the user's IVs.",
+        description: "Varies in type based on the user's IVs.",
     },
     "hiddenpowerground" => MoveData {
         num: 237,
@@ -5400,6 +5900,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Varies in type based on the user's IVs.",
+        description: "Varies in type based on the user's IVs.",
     },
     "hiddenpowerice" => MoveData {
         num: 237,
@@ -5419,6 +5921,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Varies in type based on the user's IVs.",
+        description: "Varies in type based on the user's IVs.",
     },
     "hiddenpowerpoison" => MoveData {
         num: 237,
@@ -5438,6 +5942,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Varies in type based on the user's IVs.",
+        description: "Varies in type based on the user's IVs.",
     },
     "hiddenpowerpsychic" => MoveData {
         num: 237,
@@ -5457,6 +5963,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Varies in type based on the user's IVs.",
+        description: "Varies in type based on the user's IVs.",
     },
     "hiddenpowerrock" => MoveData {
         num: 237,
@@ -5476,6 +5984,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Varies in type based on the user's IVs.",
+        description: "Varies in type based on the user's IVs.",
     },
     "hiddenpowersteel" => MoveData {
         num: 237,
@@ -5495,6 +6005,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Varies in type based on the user's IVs.",
+        description: "Varies in type based on the user's IVs.",
     },
     "hiddenpowerwater" => MoveData {
         num: 237,
@@ -5514,6 +6026,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Varies in type based on the user's IVs.",
+        description: "Varies in type based on the user's IVs.",
     },
     "crosschop" => MoveData {
         num: 238,
@@ -5533,6 +6047,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "High critical hit ratio.",
+        description: "High critical hit ratio.",
     },
     "twister" => MoveData {
         num: 239,
@@ -5562,6 +6078,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "20% chance to make the foe(s) flinch.",
+        description: "20% chance to make the foe(s) flinch.",
     },
     "raindance" => MoveData {
         num: 240,
@@ -5581,6 +6099,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "For 5 turns, heavy rain powers Water moves.",
+        description: "For 5 turns, heavy rain powers Water moves.",
     },
     "sunnyday" => MoveData {
         num: 241,
@@ -5600,6 +6120,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "For 5 turns, intense sunlight powers Fire moves.",
+        description: "For 5 turns, intense sunlight powers Fire moves.",
     },
     "crunch" => MoveData {
         num: 242,
@@ -5629,6 +6151,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "20% chance to lower the target's Defense by 1.",
+        description: "20% chance to lower the target's Defense by 1.",
     },
     "mirrorcoat" => MoveData {
         num: 243,
@@ -5648,6 +6172,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "If hit by special attack, returns double damage.",
+        description: "If hit by special attack, returns double damage.",
     },
     "psychup" => MoveData {
         num: 244,
@@ -5667,6 +6193,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Copies the target's current stat stages.",
+        description: "Copies the target's current stat stages.",
     },
     "extremespeed" => MoveData {
         num: 245,
@@ -5686,6 +6214,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Nearly always goes first.",
+        description: "Nearly always goes first.",
     },
     "ancientpower" => MoveData {
         num: 246,
@@ -5715,6 +6245,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to raise all stats by 1 (not acc/eva).",
+        description: "10% chance to raise all stats by 1 (not acc/eva).",
     },
     "shadowball" => MoveData {
         num: 247,
@@ -5744,6 +6276,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "20% chance to lower the target's Sp. Def by 1.",
+        description: "20% chance to lower the target's Sp. Def by 1.",
     },
     "futuresight" => MoveData {
         num: 248,
@@ -5763,6 +6297,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hits two turns after being used.",
+        description: "Hits two turns after being used.",
     },
     "rocksmash" => MoveData {
         num: 249,
@@ -5792,6 +6328,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "50% chance to lower the target's Defense by 1.",
+        description: "50% chance to lower the target's Defense by 1.",
     },
     "whirlpool" => MoveData {
         num: 250,
@@ -5811,6 +6349,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Traps and damages the target for 4-5 turns.",
+        description: "Traps and damages the target for 4-5 turns.",
     },
     "beatup" => MoveData {
         num: 251,
@@ -5830,6 +6370,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "All healthy allies aid in damaging the target.",
+        description: "All healthy allies aid in damaging the target.",
     },
     "fakeout" => MoveData {
         num: 252,
@@ -5859,6 +6401,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hits first. First turn out only. 100% flinch chance.",
+        description: "Hits first. First turn out only. 100% flinch chance.",
     },
     "uproar" => MoveData {
         num: 253,
@@ -5888,6 +6432,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lasts 3 turns. Active Pokemon cannot fall asleep.",
+        description: "Lasts 3 turns. Active Pokemon cannot fall asleep.",
     },
     "stockpile" => MoveData {
         num: 254,
@@ -5907,6 +6453,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises user's Defense, Sp. Def by 1. Max 3 uses.",
+        description: "Raises user's Defense, Sp. Def by 1. Max 3 uses.",
     },
     "spitup" => MoveData {
         num: 255,
@@ -5926,6 +6474,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "More power with more uses of Stockpile.",
+        description: "More power with more uses of Stockpile.",
     },
     "swallow" => MoveData {
         num: 256,
@@ -5945,6 +6495,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Heals the user based on uses of Stockpile.",
+        description: "Heals the user based on uses of Stockpile.",
     },
     "heatwave" => MoveData {
         num: 257,
@@ -5974,6 +6526,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to burn the foe(s).",
+        description: "10% chance to burn the foe(s).",
     },
     "hail" => MoveData {
         num: 258,
@@ -5993,6 +6547,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "For 5 turns, hail crashes down.",
+        description: "For 5 turns, hail crashes down.",
     },
     "torment" => MoveData {
         num: 259,
@@ -6012,6 +6568,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Target can't select the same move twice in a row.",
+        description: "Target can't select the same move twice in a row.",
     },
     "flatter" => MoveData {
         num: 260,
@@ -6031,6 +6589,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the target's Sp. Atk by 1 and confuses it.",
+        description: "Raises the target's Sp. Atk by 1 and confuses it.",
     },
     "willowisp" => MoveData {
         num: 261,
@@ -6050,6 +6610,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Burns the target.",
+        description: "Burns the target.",
     },
     "memento" => MoveData {
         num: 262,
@@ -6069,6 +6631,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the target's Attack and Sp. Atk by 2. User faints.",
+        description: "Lowers the target's Attack and Sp. Atk by 2. User faints.",
     },
     "facade" => MoveData {
         num: 263,
@@ -6088,6 +6652,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power doubles if user is burn/poison/paralyzed.",
+        description: "Power doubles if user is burn/poison/paralyzed.",
     },
     "focuspunch" => MoveData {
         num: 264,
@@ -6107,6 +6673,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Fails if the user takes damage before it hits.",
+        description: "Fails if the user takes damage before it hits.",
     },
     "smellingsalts" => MoveData {
         num: 265,
@@ -6126,6 +6694,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power doubles if target is paralyzed, and cures it.",
+        description: "Power doubles if target is paralyzed, and cures it.",
     },
     "followme" => MoveData {
         num: 266,
@@ -6145,6 +6715,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "The foes' moves target the user on the turn used.",
+        description: "The foes' moves target the user on the turn used.",
     },
     "naturepower" => MoveData {
         num: 267,
@@ -6164,6 +6736,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Attack depends on terrain (default Tri Attack).",
+        description: "Attack depends on terrain (default Tri Attack).",
     },
     "charge" => MoveData {
         num: 268,
@@ -6183,6 +6757,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "+1 SpD, user's Electric move next turn 2x power.",
+        description: "+1 SpD, user's Electric move next turn 2x power.",
     },
     "taunt" => MoveData {
         num: 269,
@@ -6202,6 +6778,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Target can't use status moves its next 3 turns.",
+        description: "Target can't use status moves its next 3 turns.",
     },
     "helpinghand" => MoveData {
         num: 270,
@@ -6221,6 +6799,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "One adjacent ally's move power is 1.5x this turn.",
+        description: "One adjacent ally's move power is 1.5x this turn.",
     },
     "trick" => MoveData {
         num: 271,
@@ -6240,6 +6820,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User switches its item with the target's.",
+        description: "User switches its item with the target's.",
     },
     "roleplay" => MoveData {
         num: 272,
@@ -6259,6 +6841,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User replaces its Ability with the target's.",
+        description: "User replaces its Ability with the target's.",
     },
     "wish" => MoveData {
         num: 273,
@@ -6278,6 +6862,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Next turn, 50% of the user's max HP is restored.",
+        description: "Next turn, 50% of the user's max HP is restored.",
     },
     "assist" => MoveData {
         num: 274,
@@ -6297,6 +6883,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Uses a random move known by a team member.",
+        description: "Uses a random move known by a team member.",
     },
     "ingrain" => MoveData {
         num: 275,
@@ -6316,6 +6904,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Traps/grounds user; heals 1/16 max HP per turn.",
+        description: "Traps/grounds user; heals 1/16 max HP per turn.",
     },
     "superpower" => MoveData {
         num: 276,
@@ -6345,6 +6935,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the user's Attack and Defense by 1.",
+        description: "Lowers the user's Attack and Defense by 1.",
     },
     "magiccoat" => MoveData {
         num: 277,
@@ -6364,6 +6956,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Bounces back certain non-damaging moves.",
+        description: "Bounces back certain non-damaging moves.",
     },
     "recycle" => MoveData {
         num: 278,
@@ -6383,6 +6977,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Restores the item the user last used.",
+        description: "Restores the item the user last used.",
     },
     "revenge" => MoveData {
         num: 279,
@@ -6402,6 +6998,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power doubles if user is damaged by the target.",
+        description: "Power doubles if user is damaged by the target.",
     },
     "brickbreak" => MoveData {
         num: 280,
@@ -6421,6 +7019,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Destroys screens, unless the target is immune.",
+        description: "Destroys screens, unless the target is immune.",
     },
     "yawn" => MoveData {
         num: 281,
@@ -6440,6 +7040,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Puts the target to sleep after 1 turn.",
+        description: "Puts the target to sleep after 1 turn.",
     },
     "knockoff" => MoveData {
         num: 282,
@@ -6459,6 +7061,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "1.5x damage if foe holds an item. Removes item.",
+        description: "1.5x damage if foe holds an item. Removes item.",
     },
     "endeavor" => MoveData {
         num: 283,
@@ -6478,6 +7082,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the target's HP to the user's HP.",
+        description: "Lowers the target's HP to the user's HP.",
     },
     "eruption" => MoveData {
         num: 284,
@@ -6497,6 +7103,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Less power as user's HP decreases. Hits foe(s).",
+        description: "Less power as user's HP decreases. Hits foe(s).",
     },
     "skillswap" => MoveData {
         num: 285,
@@ -6516,6 +7124,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "The user and the target trade Abilities.",
+        description: "The user and the target trade Abilities.",
     },
     "imprison" => MoveData {
         num: 286,
@@ -6535,6 +7145,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No foe can use any move known by the user.",
+        description: "No foe can use any move known by the user.",
     },
     "refresh" => MoveData {
         num: 287,
@@ -6554,6 +7166,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User cures its burn, poison, or paralysis.",
+        description: "User cures its burn, poison, or paralysis.",
     },
     "grudge" => MoveData {
         num: 288,
@@ -6573,6 +7187,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "If the user faints, the attack used loses all its PP.",
+        description: "If the user faints, the attack used loses all its PP.",
     },
     "snatch" => MoveData {
         num: 289,
@@ -6592,6 +7208,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User steals certain support moves to use itself.",
+        description: "User steals certain support moves to use itself.",
     },
     "secretpower" => MoveData {
         num: 290,
@@ -6621,6 +7239,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Effect varies with terrain. (30% paralysis chance)",
+        description: "Effect varies with terrain. (30% paralysis chance)",
     },
     "dive" => MoveData {
         num: 291,
@@ -6640,6 +7260,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Dives underwater turn 1, strikes turn 2.",
+        description: "Dives underwater turn 1, strikes turn 2.",
     },
     "armthrust" => MoveData {
         num: 292,
@@ -6659,6 +7281,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: Some((2, 5)),
+        short_desc: "Hits 2-5 times in one turn.",
+        description: "Hits 2-5 times in one turn.",
     },
     "camouflage" => MoveData {
         num: 293,
@@ -6678,6 +7302,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Changes user's type by terrain (default Normal).",
+        description: "Changes user's type by terrain (default Normal).",
     },
     "tailglow" => MoveData {
         num: 294,
@@ -6697,6 +7323,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Sp. Atk by 3.",
+        description: "Raises the user's Sp. Atk by 3.",
     },
     "lusterpurge" => MoveData {
         num: 295,
@@ -6726,6 +7354,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "50% chance to lower the target's Sp. Def by 1.",
+        description: "50% chance to lower the target's Sp. Def by 1.",
     },
     "mistball" => MoveData {
         num: 296,
@@ -6755,6 +7385,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "50% chance to lower the target's Sp. Atk by 1.",
+        description: "50% chance to lower the target's Sp. Atk by 1.",
     },
     "featherdance" => MoveData {
         num: 297,
@@ -6774,6 +7406,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the target's Attack by 2.",
+        description: "Lowers the target's Attack by 2.",
     },
     "teeterdance" => MoveData {
         num: 298,
@@ -6793,6 +7427,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Confuses adjacent Pokemon.",
+        description: "Confuses adjacent Pokemon.",
     },
     "blazekick" => MoveData {
         num: 299,
@@ -6822,6 +7458,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "High critical hit ratio. 10% chance to burn.",
+        description: "High critical hit ratio. 10% chance to burn.",
     },
     "mudsport" => MoveData {
         num: 300,
@@ -6841,6 +7479,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "For 5 turns, Electric-type attacks have 1/3 power.",
+        description: "For 5 turns, Electric-type attacks have 1/3 power.",
     },
     "iceball" => MoveData {
         num: 301,
@@ -6860,6 +7500,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power doubles with each hit. Repeats for 5 turns.",
+        description: "Power doubles with each hit. Repeats for 5 turns.",
     },
     "needlearm" => MoveData {
         num: 302,
@@ -6889,6 +7531,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to make the target flinch.",
+        description: "30% chance to make the target flinch.",
     },
     "slackoff" => MoveData {
         num: 303,
@@ -6908,6 +7552,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Heals the user by 50% of its max HP.",
+        description: "Heals the user by 50% of its max HP.",
     },
     "hypervoice" => MoveData {
         num: 304,
@@ -6927,6 +7573,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect. Hits adjacent foes.",
+        description: "No additional effect. Hits adjacent foes.",
     },
     "poisonfang" => MoveData {
         num: 305,
@@ -6956,6 +7604,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "50% chance to badly poison the target.",
+        description: "50% chance to badly poison the target.",
     },
     "crushclaw" => MoveData {
         num: 306,
@@ -6985,6 +7635,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "50% chance to lower the target's Defense by 1.",
+        description: "50% chance to lower the target's Defense by 1.",
     },
     "blastburn" => MoveData {
         num: 307,
@@ -7014,6 +7666,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User cannot move next turn.",
+        description: "User cannot move next turn.",
     },
     "hydrocannon" => MoveData {
         num: 308,
@@ -7043,6 +7697,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User cannot move next turn.",
+        description: "User cannot move next turn.",
     },
     "meteormash" => MoveData {
         num: 309,
@@ -7072,6 +7728,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "20% chance to raise the user's Attack by 1.",
+        description: "20% chance to raise the user's Attack by 1.",
     },
     "astonish" => MoveData {
         num: 310,
@@ -7101,6 +7759,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to make the target flinch.",
+        description: "30% chance to make the target flinch.",
     },
     "weatherball" => MoveData {
         num: 311,
@@ -7120,6 +7780,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power doubles and type varies in each weather.",
+        description: "Power doubles and type varies in each weather.",
     },
     "aromatherapy" => MoveData {
         num: 312,
@@ -7139,6 +7801,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Cures the user's party of all status conditions.",
+        description: "Cures the user's party of all status conditions.",
     },
     "faketears" => MoveData {
         num: 313,
@@ -7158,6 +7822,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the target's Sp. Def by 2.",
+        description: "Lowers the target's Sp. Def by 2.",
     },
     "aircutter" => MoveData {
         num: 314,
@@ -7177,6 +7843,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "High critical hit ratio. Hits adjacent foes.",
+        description: "High critical hit ratio. Hits adjacent foes.",
     },
     "overheat" => MoveData {
         num: 315,
@@ -7206,6 +7874,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the user's Sp. Atk by 2.",
+        description: "Lowers the user's Sp. Atk by 2.",
     },
     "odorsleuth" => MoveData {
         num: 316,
@@ -7225,6 +7895,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Fighting, Normal hit Ghost. Evasiveness ignored.",
+        description: "Fighting, Normal hit Ghost. Evasiveness ignored.",
     },
     "rocktomb" => MoveData {
         num: 317,
@@ -7254,6 +7926,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to lower the target's Speed by 1.",
+        description: "100% chance to lower the target's Speed by 1.",
     },
     "silverwind" => MoveData {
         num: 318,
@@ -7283,6 +7957,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to raise all stats by 1 (not acc/eva).",
+        description: "10% chance to raise all stats by 1 (not acc/eva).",
     },
     "metalsound" => MoveData {
         num: 319,
@@ -7302,6 +7978,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the target's Sp. Def by 2.",
+        description: "Lowers the target's Sp. Def by 2.",
     },
     "grasswhistle" => MoveData {
         num: 320,
@@ -7321,6 +7999,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Causes the target to fall asleep.",
+        description: "Causes the target to fall asleep.",
     },
     "tickle" => MoveData {
         num: 321,
@@ -7340,6 +8020,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the target's Attack and Defense by 1.",
+        description: "Lowers the target's Attack and Defense by 1.",
     },
     "cosmicpower" => MoveData {
         num: 322,
@@ -7359,6 +8041,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Defense and Sp. Def by 1.",
+        description: "Raises the user's Defense and Sp. Def by 1.",
     },
     "waterspout" => MoveData {
         num: 323,
@@ -7378,6 +8062,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Less power as user's HP decreases. Hits foe(s).",
+        description: "Less power as user's HP decreases. Hits foe(s).",
     },
     "signalbeam" => MoveData {
         num: 324,
@@ -7407,6 +8093,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to confuse the target.",
+        description: "10% chance to confuse the target.",
     },
     "shadowpunch" => MoveData {
         num: 325,
@@ -7426,6 +8114,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "This move does not check accuracy.",
+        description: "This move does not check accuracy.",
     },
     "extrasensory" => MoveData {
         num: 326,
@@ -7455,6 +8145,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to make the target flinch.",
+        description: "10% chance to make the target flinch.",
     },
     "skyuppercut" => MoveData {
         num: 327,
@@ -7474,6 +8166,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Can hit Pokemon using Bounce, Fly, or Sky Drop.",
+        description: "Can hit Pokemon using Bounce, Fly, or Sky Drop.",
     },
     "sandtomb" => MoveData {
         num: 328,
@@ -7493,6 +8187,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Traps and damages the target for 4-5 turns.",
+        description: "Traps and damages the target for 4-5 turns.",
     },
     "sheercold" => MoveData {
         num: 329,
@@ -7512,6 +8208,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "OHKO; fails on Ice types. Non-Ice users: 20% acc.",
+        description: "OHKO; fails on Ice types. Non-Ice users: 20% acc.",
     },
     "muddywater" => MoveData {
         num: 330,
@@ -7541,6 +8239,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to lower the foe(s) accuracy by 1.",
+        description: "30% chance to lower the foe(s) accuracy by 1.",
     },
     "bulletseed" => MoveData {
         num: 331,
@@ -7560,6 +8260,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: Some((2, 5)),
+        short_desc: "Hits 2-5 times in one turn.",
+        description: "Hits 2-5 times in one turn.",
     },
     "aerialace" => MoveData {
         num: 332,
@@ -7579,6 +8281,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "This move does not check accuracy.",
+        description: "This move does not check accuracy.",
     },
     "iciclespear" => MoveData {
         num: 333,
@@ -7598,6 +8302,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: Some((2, 5)),
+        short_desc: "Hits 2-5 times in one turn.",
+        description: "Hits 2-5 times in one turn.",
     },
     "irondefense" => MoveData {
         num: 334,
@@ -7617,6 +8323,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Defense by 2.",
+        description: "Raises the user's Defense by 2.",
     },
     "block" => MoveData {
         num: 335,
@@ -7636,6 +8344,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Prevents the target from switching out.",
+        description: "Prevents the target from switching out.",
     },
     "howl" => MoveData {
         num: 336,
@@ -7655,6 +8365,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's and ally's Attack by 1.",
+        description: "Raises the user's and ally's Attack by 1.",
     },
     "dragonclaw" => MoveData {
         num: 337,
@@ -7674,6 +8386,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "frenzyplant" => MoveData {
         num: 338,
@@ -7703,6 +8417,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User cannot move next turn.",
+        description: "User cannot move next turn.",
     },
     "bulkup" => MoveData {
         num: 339,
@@ -7722,6 +8438,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Attack and Defense by 1.",
+        description: "Raises the user's Attack and Defense by 1.",
     },
     "bounce" => MoveData {
         num: 340,
@@ -7751,6 +8469,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Bounces turn 1. Hits turn 2. 30% paralyze.",
+        description: "Bounces turn 1. Hits turn 2. 30% paralyze.",
     },
     "mudshot" => MoveData {
         num: 341,
@@ -7780,6 +8500,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to lower the target's Speed by 1.",
+        description: "100% chance to lower the target's Speed by 1.",
     },
     "poisontail" => MoveData {
         num: 342,
@@ -7809,6 +8531,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "High critical hit ratio. 10% chance to poison.",
+        description: "High critical hit ratio. 10% chance to poison.",
     },
     "covet" => MoveData {
         num: 343,
@@ -7828,6 +8552,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "If the user has no item, it steals the target's.",
+        description: "If the user has no item, it steals the target's.",
     },
     "volttackle" => MoveData {
         num: 344,
@@ -7857,6 +8583,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: Some((33, 100)),
         drain: None,
         multihit: None,
+        short_desc: "Has 33% recoil. 10% chance to paralyze target.",
+        description: "Has 33% recoil. 10% chance to paralyze target.",
     },
     "magicalleaf" => MoveData {
         num: 345,
@@ -7876,6 +8604,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "This move does not check accuracy.",
+        description: "This move does not check accuracy.",
     },
     "watersport" => MoveData {
         num: 346,
@@ -7895,6 +8625,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "For 5 turns, Fire-type attacks have 1/3 power.",
+        description: "For 5 turns, Fire-type attacks have 1/3 power.",
     },
     "calmmind" => MoveData {
         num: 347,
@@ -7914,6 +8646,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Sp. Atk and Sp. Def by 1.",
+        description: "Raises the user's Sp. Atk and Sp. Def by 1.",
     },
     "leafblade" => MoveData {
         num: 348,
@@ -7933,6 +8667,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "High critical hit ratio.",
+        description: "High critical hit ratio.",
     },
     "dragondance" => MoveData {
         num: 349,
@@ -7952,6 +8688,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Attack and Speed by 1.",
+        description: "Raises the user's Attack and Speed by 1.",
     },
     "rockblast" => MoveData {
         num: 350,
@@ -7971,6 +8709,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: Some((2, 5)),
+        short_desc: "Hits 2-5 times in one turn.",
+        description: "Hits 2-5 times in one turn.",
     },
     "shockwave" => MoveData {
         num: 351,
@@ -7990,6 +8730,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "This move does not check accuracy.",
+        description: "This move does not check accuracy.",
     },
     "waterpulse" => MoveData {
         num: 352,
@@ -8019,6 +8761,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "20% chance to confuse the target.",
+        description: "20% chance to confuse the target.",
     },
     "doomdesire" => MoveData {
         num: 353,
@@ -8038,6 +8782,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hits two turns after being used.",
+        description: "Hits two turns after being used.",
     },
     "psychoboost" => MoveData {
         num: 354,
@@ -8067,6 +8813,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the user's Sp. Atk by 2.",
+        description: "Lowers the user's Sp. Atk by 2.",
     },
     "roost" => MoveData {
         num: 355,
@@ -8096,6 +8844,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Heals 50% HP. Flying-type removed 'til turn ends.",
+        description: "Heals 50% HP. Flying-type removed 'til turn ends.",
     },
     "gravity" => MoveData {
         num: 356,
@@ -8115,6 +8865,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "5 turns: no Ground immunities, 1.67x accuracy.",
+        description: "5 turns: no Ground immunities, 1.67x accuracy.",
     },
     "miracleeye" => MoveData {
         num: 357,
@@ -8134,6 +8886,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Psychic hits Dark. Evasiveness ignored.",
+        description: "Psychic hits Dark. Evasiveness ignored.",
     },
     "wakeupslap" => MoveData {
         num: 358,
@@ -8153,6 +8907,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power doubles if target is asleep, and wakes it.",
+        description: "Power doubles if target is asleep, and wakes it.",
     },
     "hammerarm" => MoveData {
         num: 359,
@@ -8182,6 +8938,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the user's Speed by 1.",
+        description: "Lowers the user's Speed by 1.",
     },
     "gyroball" => MoveData {
         num: 360,
@@ -8201,6 +8959,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "More power the slower the user than the target.",
+        description: "More power the slower the user than the target.",
     },
     "healingwish" => MoveData {
         num: 361,
@@ -8220,6 +8980,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User faints. Next hurt Pokemon is fully healed.",
+        description: "User faints. Next hurt Pokemon is fully healed.",
     },
     "brine" => MoveData {
         num: 362,
@@ -8239,6 +9001,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power doubles if the target's HP is 50% or less.",
+        description: "Power doubles if the target's HP is 50% or less.",
     },
     "naturalgift" => MoveData {
         num: 363,
@@ -8258,6 +9022,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power and type depends on the user's Berry.",
+        description: "Power and type depends on the user's Berry.",
     },
     "feint" => MoveData {
         num: 364,
@@ -8277,6 +9043,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Nullifies Detect, Protect, and Quick/Wide Guard.",
+        description: "Nullifies Detect, Protect, and Quick/Wide Guard.",
     },
     "pluck" => MoveData {
         num: 365,
@@ -8296,6 +9064,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User steals and eats the target's Berry.",
+        description: "User steals and eats the target's Berry.",
     },
     "tailwind" => MoveData {
         num: 366,
@@ -8315,6 +9085,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "For 4 turns, allies' Speed is doubled.",
+        description: "For 4 turns, allies' Speed is doubled.",
     },
     "acupressure" => MoveData {
         num: 367,
@@ -8334,6 +9106,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises a random stat of the user or an ally by 2.",
+        description: "Raises a random stat of the user or an ally by 2.",
     },
     "metalburst" => MoveData {
         num: 368,
@@ -8353,6 +9127,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "If hit by an attack, returns 1.5x damage.",
+        description: "If hit by an attack, returns 1.5x damage.",
     },
     "uturn" => MoveData {
         num: 369,
@@ -8372,6 +9148,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User switches out after damaging the target.",
+        description: "User switches out after damaging the target.",
     },
     "closecombat" => MoveData {
         num: 370,
@@ -8401,6 +9179,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the user's Defense and Sp. Def by 1.",
+        description: "Lowers the user's Defense and Sp. Def by 1.",
     },
     "payback" => MoveData {
         num: 371,
@@ -8420,6 +9200,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power doubles if the user moves after the target.",
+        description: "Power doubles if the user moves after the target.",
     },
     "assurance" => MoveData {
         num: 372,
@@ -8439,6 +9221,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power doubles if target was damaged this turn.",
+        description: "Power doubles if target was damaged this turn.",
     },
     "embargo" => MoveData {
         num: 373,
@@ -8458,6 +9242,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "For 5 turns, the target's item has no effect.",
+        description: "For 5 turns, the target's item has no effect.",
     },
     "fling" => MoveData {
         num: 374,
@@ -8477,6 +9263,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Flings the user's item at the target. Power varies.",
+        description: "Flings the user's item at the target. Power varies.",
     },
     "psychoshift" => MoveData {
         num: 375,
@@ -8506,6 +9294,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Transfers the user's status ailment to the target.",
+        description: "Transfers the user's status ailment to the target.",
     },
     "trumpcard" => MoveData {
         num: 376,
@@ -8525,6 +9315,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "More power the fewer PP this move has left.",
+        description: "More power the fewer PP this move has left.",
     },
     "healblock" => MoveData {
         num: 377,
@@ -8544,6 +9336,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "For 5 turns, the foe(s) is prevented from healing.",
+        description: "For 5 turns, the foe(s) is prevented from healing.",
     },
     "wringout" => MoveData {
         num: 378,
@@ -8563,6 +9357,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "More power the more HP the target has left.",
+        description: "More power the more HP the target has left.",
     },
     "powertrick" => MoveData {
         num: 379,
@@ -8582,6 +9378,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Switches user's Attack and Defense stats.",
+        description: "Switches user's Attack and Defense stats.",
     },
     "gastroacid" => MoveData {
         num: 380,
@@ -8601,6 +9399,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Nullifies the target's Ability.",
+        description: "Nullifies the target's Ability.",
     },
     "luckychant" => MoveData {
         num: 381,
@@ -8620,6 +9420,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "For 5 turns, shields user's party from critical hits.",
+        description: "For 5 turns, shields user's party from critical hits.",
     },
     "mefirst" => MoveData {
         num: 382,
@@ -8639,6 +9441,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Copies a foe at 1.5x power. User must be faster.",
+        description: "Copies a foe at 1.5x power. User must be faster.",
     },
     "copycat" => MoveData {
         num: 383,
@@ -8658,6 +9462,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Uses the last move used in the battle.",
+        description: "Uses the last move used in the battle.",
     },
     "powerswap" => MoveData {
         num: 384,
@@ -8677,6 +9483,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Swaps Attack and Sp. Atk stat stages with target.",
+        description: "Swaps Attack and Sp. Atk stat stages with target.",
     },
     "guardswap" => MoveData {
         num: 385,
@@ -8696,6 +9504,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Swaps Defense and Sp. Def changes with target.",
+        description: "Swaps Defense and Sp. Def changes with target.",
     },
     "punishment" => MoveData {
         num: 386,
@@ -8715,6 +9525,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "60 power +20 for each of the target's stat boosts.",
+        description: "60 power +20 for each of the target's stat boosts.",
     },
     "lastresort" => MoveData {
         num: 387,
@@ -8734,6 +9546,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Fails unless each known move has been used.",
+        description: "Fails unless each known move has been used.",
     },
     "worryseed" => MoveData {
         num: 388,
@@ -8753,6 +9567,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "The target's Ability becomes Insomnia.",
+        description: "The target's Ability becomes Insomnia.",
     },
     "suckerpunch" => MoveData {
         num: 389,
@@ -8772,6 +9588,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Usually goes first. Fails if target is not attacking.",
+        description: "Usually goes first. Fails if target is not attacking.",
     },
     "toxicspikes" => MoveData {
         num: 390,
@@ -8791,6 +9609,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Poisons grounded foes on switch-in. Max 2 layers.",
+        description: "Poisons grounded foes on switch-in. Max 2 layers.",
     },
     "heartswap" => MoveData {
         num: 391,
@@ -8810,6 +9630,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Swaps all stat changes with target.",
+        description: "Swaps all stat changes with target.",
     },
     "aquaring" => MoveData {
         num: 392,
@@ -8829,6 +9651,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User recovers 1/16 max HP per turn.",
+        description: "User recovers 1/16 max HP per turn.",
     },
     "magnetrise" => MoveData {
         num: 393,
@@ -8848,6 +9672,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "For 5 turns, the user has immunity to Ground.",
+        description: "For 5 turns, the user has immunity to Ground.",
     },
     "flareblitz" => MoveData {
         num: 394,
@@ -8877,6 +9703,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: Some((33, 100)),
         drain: None,
         multihit: None,
+        short_desc: "Has 33% recoil. 10% chance to burn. Thaws user.",
+        description: "Has 33% recoil. 10% chance to burn. Thaws user.",
     },
     "forcepalm" => MoveData {
         num: 395,
@@ -8906,6 +9734,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to paralyze the target.",
+        description: "30% chance to paralyze the target.",
     },
     "aurasphere" => MoveData {
         num: 396,
@@ -8925,6 +9755,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "This move does not check accuracy.",
+        description: "This move does not check accuracy.",
     },
     "rockpolish" => MoveData {
         num: 397,
@@ -8944,6 +9776,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Speed by 2.",
+        description: "Raises the user's Speed by 2.",
     },
     "poisonjab" => MoveData {
         num: 398,
@@ -8973,6 +9807,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to poison the target.",
+        description: "30% chance to poison the target.",
     },
     "darkpulse" => MoveData {
         num: 399,
@@ -9002,6 +9838,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "20% chance to make the target flinch.",
+        description: "20% chance to make the target flinch.",
     },
     "nightslash" => MoveData {
         num: 400,
@@ -9021,6 +9859,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "High critical hit ratio.",
+        description: "High critical hit ratio.",
     },
     "aquatail" => MoveData {
         num: 401,
@@ -9040,6 +9880,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "seedbomb" => MoveData {
         num: 402,
@@ -9059,6 +9901,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "airslash" => MoveData {
         num: 403,
@@ -9088,6 +9932,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to make the target flinch.",
+        description: "30% chance to make the target flinch.",
     },
     "xscissor" => MoveData {
         num: 404,
@@ -9107,6 +9953,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "bugbuzz" => MoveData {
         num: 405,
@@ -9136,6 +9984,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to lower the target's Sp. Def by 1.",
+        description: "10% chance to lower the target's Sp. Def by 1.",
     },
     "dragonpulse" => MoveData {
         num: 406,
@@ -9155,6 +10005,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "dragonrush" => MoveData {
         num: 407,
@@ -9184,6 +10036,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "20% chance to make the target flinch.",
+        description: "20% chance to make the target flinch.",
     },
     "powergem" => MoveData {
         num: 408,
@@ -9203,6 +10057,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "drainpunch" => MoveData {
         num: 409,
@@ -9222,6 +10078,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: Some((1, 2)),
         multihit: None,
+        short_desc: "User recovers 50% of the damage dealt.",
+        description: "User recovers 50% of the damage dealt.",
     },
     "vacuumwave" => MoveData {
         num: 410,
@@ -9241,6 +10099,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Usually goes first.",
+        description: "Usually goes first.",
     },
     "focusblast" => MoveData {
         num: 411,
@@ -9270,6 +10130,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to lower the target's Sp. Def by 1.",
+        description: "10% chance to lower the target's Sp. Def by 1.",
     },
     "energyball" => MoveData {
         num: 412,
@@ -9299,6 +10161,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to lower the target's Sp. Def by 1.",
+        description: "10% chance to lower the target's Sp. Def by 1.",
     },
     "bravebird" => MoveData {
         num: 413,
@@ -9318,6 +10182,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: Some((33, 100)),
         drain: None,
         multihit: None,
+        short_desc: "Has 33% recoil.",
+        description: "Has 33% recoil.",
     },
     "earthpower" => MoveData {
         num: 414,
@@ -9347,6 +10213,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to lower the target's Sp. Def by 1.",
+        description: "10% chance to lower the target's Sp. Def by 1.",
     },
     "switcheroo" => MoveData {
         num: 415,
@@ -9366,6 +10234,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User switches its item with the target's.",
+        description: "User switches its item with the target's.",
     },
     "gigaimpact" => MoveData {
         num: 416,
@@ -9395,6 +10265,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User cannot move next turn.",
+        description: "User cannot move next turn.",
     },
     "nastyplot" => MoveData {
         num: 417,
@@ -9414,6 +10286,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Sp. Atk by 2.",
+        description: "Raises the user's Sp. Atk by 2.",
     },
     "bulletpunch" => MoveData {
         num: 418,
@@ -9433,6 +10307,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Usually goes first.",
+        description: "Usually goes first.",
     },
     "avalanche" => MoveData {
         num: 419,
@@ -9452,6 +10328,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power doubles if user is damaged by the target.",
+        description: "Power doubles if user is damaged by the target.",
     },
     "iceshard" => MoveData {
         num: 420,
@@ -9471,6 +10349,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Usually goes first.",
+        description: "Usually goes first.",
     },
     "shadowclaw" => MoveData {
         num: 421,
@@ -9490,6 +10370,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "High critical hit ratio.",
+        description: "High critical hit ratio.",
     },
     "thunderfang" => MoveData {
         num: 422,
@@ -9528,6 +10410,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to paralyze. 10% chance to flinch.",
+        description: "10% chance to paralyze. 10% chance to flinch.",
     },
     "icefang" => MoveData {
         num: 423,
@@ -9566,6 +10450,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to freeze. 10% chance to flinch.",
+        description: "10% chance to freeze. 10% chance to flinch.",
     },
     "firefang" => MoveData {
         num: 424,
@@ -9604,6 +10490,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to burn. 10% chance to flinch.",
+        description: "10% chance to burn. 10% chance to flinch.",
     },
     "shadowsneak" => MoveData {
         num: 425,
@@ -9623,6 +10511,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Usually goes first.",
+        description: "Usually goes first.",
     },
     "mudbomb" => MoveData {
         num: 426,
@@ -9652,6 +10542,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to lower the target's accuracy by 1.",
+        description: "30% chance to lower the target's accuracy by 1.",
     },
     "psychocut" => MoveData {
         num: 427,
@@ -9671,6 +10563,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "High critical hit ratio.",
+        description: "High critical hit ratio.",
     },
     "zenheadbutt" => MoveData {
         num: 428,
@@ -9700,6 +10594,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "20% chance to make the target flinch.",
+        description: "20% chance to make the target flinch.",
     },
     "mirrorshot" => MoveData {
         num: 429,
@@ -9729,6 +10625,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to lower the target's accuracy by 1.",
+        description: "30% chance to lower the target's accuracy by 1.",
     },
     "flashcannon" => MoveData {
         num: 430,
@@ -9758,6 +10656,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to lower the target's Sp. Def by 1.",
+        description: "10% chance to lower the target's Sp. Def by 1.",
     },
     "rockclimb" => MoveData {
         num: 431,
@@ -9787,6 +10687,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "20% chance to confuse the target.",
+        description: "20% chance to confuse the target.",
     },
     "defog" => MoveData {
         num: 432,
@@ -9806,6 +10708,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "-1 evasion; clears user and target side's hazards.",
+        description: "-1 evasion; clears user and target side's hazards.",
     },
     "trickroom" => MoveData {
         num: 433,
@@ -9825,6 +10729,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Goes last. For 5 turns, turn order is reversed.",
+        description: "Goes last. For 5 turns, turn order is reversed.",
     },
     "dracometeor" => MoveData {
         num: 434,
@@ -9854,6 +10760,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the user's Sp. Atk by 2.",
+        description: "Lowers the user's Sp. Atk by 2.",
     },
     "discharge" => MoveData {
         num: 435,
@@ -9883,6 +10791,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to paralyze adjacent Pokemon.",
+        description: "30% chance to paralyze adjacent Pokemon.",
     },
     "lavaplume" => MoveData {
         num: 436,
@@ -9912,6 +10822,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to burn adjacent Pokemon.",
+        description: "30% chance to burn adjacent Pokemon.",
     },
     "leafstorm" => MoveData {
         num: 437,
@@ -9941,6 +10853,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the user's Sp. Atk by 2.",
+        description: "Lowers the user's Sp. Atk by 2.",
     },
     "powerwhip" => MoveData {
         num: 438,
@@ -9960,6 +10874,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "rockwrecker" => MoveData {
         num: 439,
@@ -9989,6 +10905,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User cannot move next turn.",
+        description: "User cannot move next turn.",
     },
     "crosspoison" => MoveData {
         num: 440,
@@ -10018,6 +10936,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "High critical hit ratio. 10% chance to poison.",
+        description: "High critical hit ratio. 10% chance to poison.",
     },
     "gunkshot" => MoveData {
         num: 441,
@@ -10047,6 +10967,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to poison the target.",
+        description: "30% chance to poison the target.",
     },
     "ironhead" => MoveData {
         num: 442,
@@ -10076,6 +10998,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to make the target flinch.",
+        description: "30% chance to make the target flinch.",
     },
     "magnetbomb" => MoveData {
         num: 443,
@@ -10095,6 +11019,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "This move does not check accuracy.",
+        description: "This move does not check accuracy.",
     },
     "stoneedge" => MoveData {
         num: 444,
@@ -10114,6 +11040,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "High critical hit ratio.",
+        description: "High critical hit ratio.",
     },
     "captivate" => MoveData {
         num: 445,
@@ -10133,6 +11061,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the foe(s) Sp. Atk by 2 if opposite gender.",
+        description: "Lowers the foe(s) Sp. Atk by 2 if opposite gender.",
     },
     "stealthrock" => MoveData {
         num: 446,
@@ -10152,6 +11082,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hurts foes on switch-in. Factors Rock weakness.",
+        description: "Hurts foes on switch-in. Factors Rock weakness.",
     },
     "grassknot" => MoveData {
         num: 447,
@@ -10171,6 +11103,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "More power the heavier the target.",
+        description: "More power the heavier the target.",
     },
     "chatter" => MoveData {
         num: 448,
@@ -10200,6 +11134,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to confuse the target.",
+        description: "100% chance to confuse the target.",
     },
     "judgment" => MoveData {
         num: 449,
@@ -10219,6 +11155,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Type varies based on the held Plate.",
+        description: "Type varies based on the held Plate.",
     },
     "bugbite" => MoveData {
         num: 450,
@@ -10238,6 +11176,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User steals and eats the target's Berry.",
+        description: "User steals and eats the target's Berry.",
     },
     "chargebeam" => MoveData {
         num: 451,
@@ -10267,6 +11207,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "70% chance to raise the user's Sp. Atk by 1.",
+        description: "70% chance to raise the user's Sp. Atk by 1.",
     },
     "woodhammer" => MoveData {
         num: 452,
@@ -10286,6 +11228,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: Some((33, 100)),
         drain: None,
         multihit: None,
+        short_desc: "Has 33% recoil.",
+        description: "Has 33% recoil.",
     },
     "aquajet" => MoveData {
         num: 453,
@@ -10305,6 +11249,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Usually goes first.",
+        description: "Usually goes first.",
     },
     "attackorder" => MoveData {
         num: 454,
@@ -10324,6 +11270,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "High critical hit ratio.",
+        description: "High critical hit ratio.",
     },
     "defendorder" => MoveData {
         num: 455,
@@ -10343,6 +11291,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Defense and Sp. Def by 1.",
+        description: "Raises the user's Defense and Sp. Def by 1.",
     },
     "healorder" => MoveData {
         num: 456,
@@ -10362,6 +11312,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Heals the user by 50% of its max HP.",
+        description: "Heals the user by 50% of its max HP.",
     },
     "headsmash" => MoveData {
         num: 457,
@@ -10381,6 +11333,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: Some((1, 2)),
         drain: None,
         multihit: None,
+        short_desc: "Has 1/2 recoil.",
+        description: "Has 1/2 recoil.",
     },
     "doublehit" => MoveData {
         num: 458,
@@ -10400,6 +11354,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hits 2 times in one turn.",
+        description: "Hits 2 times in one turn.",
     },
     "roaroftime" => MoveData {
         num: 459,
@@ -10429,6 +11385,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User cannot move next turn.",
+        description: "User cannot move next turn.",
     },
     "spacialrend" => MoveData {
         num: 460,
@@ -10448,6 +11406,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "High critical hit ratio.",
+        description: "High critical hit ratio.",
     },
     "lunardance" => MoveData {
         num: 461,
@@ -10467,6 +11427,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User faints. Next hurt Pkmn is cured, max HP/PP.",
+        description: "User faints. Next hurt Pkmn is cured, max HP/PP.",
     },
     "crushgrip" => MoveData {
         num: 462,
@@ -10486,6 +11448,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "More power the more HP the target has left.",
+        description: "More power the more HP the target has left.",
     },
     "magmastorm" => MoveData {
         num: 463,
@@ -10505,6 +11469,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Traps and damages the target for 4-5 turns.",
+        description: "Traps and damages the target for 4-5 turns.",
     },
     "darkvoid" => MoveData {
         num: 464,
@@ -10524,6 +11490,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Darkrai: Causes the foe(s) to fall asleep.",
+        description: "Darkrai: Causes the foe(s) to fall asleep.",
     },
     "seedflare" => MoveData {
         num: 465,
@@ -10553,6 +11521,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "40% chance to lower the target's Sp. Def by 2.",
+        description: "40% chance to lower the target's Sp. Def by 2.",
     },
     "ominouswind" => MoveData {
         num: 466,
@@ -10582,6 +11552,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to raise all stats by 1 (not acc/eva).",
+        description: "10% chance to raise all stats by 1 (not acc/eva).",
     },
     "shadowforce" => MoveData {
         num: 467,
@@ -10601,6 +11573,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Disappears turn 1. Hits turn 2. Breaks protection.",
+        description: "Disappears turn 1. Hits turn 2. Breaks protection.",
     },
     "honeclaws" => MoveData {
         num: 468,
@@ -10620,6 +11594,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Attack and accuracy by 1.",
+        description: "Raises the user's Attack and accuracy by 1.",
     },
     "wideguard" => MoveData {
         num: 469,
@@ -10639,6 +11615,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Protects allies from multi-target moves this turn.",
+        description: "Protects allies from multi-target moves this turn.",
     },
     "guardsplit" => MoveData {
         num: 470,
@@ -10658,6 +11636,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Averages Defense and Sp. Def stats with target.",
+        description: "Averages Defense and Sp. Def stats with target.",
     },
     "powersplit" => MoveData {
         num: 471,
@@ -10677,6 +11657,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Averages Attack and Sp. Atk stats with target.",
+        description: "Averages Attack and Sp. Atk stats with target.",
     },
     "wonderroom" => MoveData {
         num: 472,
@@ -10696,6 +11678,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "For 5 turns, all Defense and Sp. Def stats switch.",
+        description: "For 5 turns, all Defense and Sp. Def stats switch.",
     },
     "psyshock" => MoveData {
         num: 473,
@@ -10715,6 +11699,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Damages target based on Defense, not Sp. Def.",
+        description: "Damages target based on Defense, not Sp. Def.",
     },
     "venoshock" => MoveData {
         num: 474,
@@ -10734,6 +11720,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power doubles if the target is poisoned.",
+        description: "Power doubles if the target is poisoned.",
     },
     "autotomize" => MoveData {
         num: 475,
@@ -10753,6 +11741,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Speed by 2; user loses 100 kg.",
+        description: "Raises the user's Speed by 2; user loses 100 kg.",
     },
     "ragepowder" => MoveData {
         num: 476,
@@ -10772,6 +11762,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "The foes' moves target the user on the turn used.",
+        description: "The foes' moves target the user on the turn used.",
     },
     "telekinesis" => MoveData {
         num: 477,
@@ -10791,6 +11783,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "For 3 turns, target floats but moves can't miss it.",
+        description: "For 3 turns, target floats but moves can't miss it.",
     },
     "magicroom" => MoveData {
         num: 478,
@@ -10810,6 +11804,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "For 5 turns, all held items have no effect.",
+        description: "For 5 turns, all held items have no effect.",
     },
     "smackdown" => MoveData {
         num: 479,
@@ -10829,6 +11825,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Removes the target's Ground immunity.",
+        description: "Removes the target's Ground immunity.",
     },
     "stormthrow" => MoveData {
         num: 480,
@@ -10848,6 +11846,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Always results in a critical hit.",
+        description: "Always results in a critical hit.",
     },
     "flameburst" => MoveData {
         num: 481,
@@ -10867,6 +11867,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Damages Pokemon next to the target as well.",
+        description: "Damages Pokemon next to the target as well.",
     },
     "sludgewave" => MoveData {
         num: 482,
@@ -10896,6 +11898,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to poison adjacent Pokemon.",
+        description: "10% chance to poison adjacent Pokemon.",
     },
     "quiverdance" => MoveData {
         num: 483,
@@ -10915,6 +11919,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Sp. Atk, Sp. Def, Speed by 1.",
+        description: "Raises the user's Sp. Atk, Sp. Def, Speed by 1.",
     },
     "heavyslam" => MoveData {
         num: 484,
@@ -10934,6 +11940,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "More power the heavier the user than the target.",
+        description: "More power the heavier the user than the target.",
     },
     "synchronoise" => MoveData {
         num: 485,
@@ -10953,6 +11961,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hits adjacent Pokemon sharing the user's type.",
+        description: "Hits adjacent Pokemon sharing the user's type.",
     },
     "electroball" => MoveData {
         num: 486,
@@ -10972,6 +11982,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "More power the faster the user is than the target.",
+        description: "More power the faster the user is than the target.",
     },
     "soak" => MoveData {
         num: 487,
@@ -10991,6 +12003,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Changes the target's type to Water.",
+        description: "Changes the target's type to Water.",
     },
     "flamecharge" => MoveData {
         num: 488,
@@ -11020,6 +12034,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to raise the user's Speed by 1.",
+        description: "100% chance to raise the user's Speed by 1.",
     },
     "coil" => MoveData {
         num: 489,
@@ -11039,6 +12055,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises user's Attack, Defense, accuracy by 1.",
+        description: "Raises user's Attack, Defense, accuracy by 1.",
     },
     "lowsweep" => MoveData {
         num: 490,
@@ -11068,6 +12086,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to lower the target's Speed by 1.",
+        description: "100% chance to lower the target's Speed by 1.",
     },
     "acidspray" => MoveData {
         num: 491,
@@ -11097,6 +12117,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to lower the target's Sp. Def by 2.",
+        description: "100% chance to lower the target's Sp. Def by 2.",
     },
     "foulplay" => MoveData {
         num: 492,
@@ -11116,6 +12138,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Uses target's Attack stat in damage calculation.",
+        description: "Uses target's Attack stat in damage calculation.",
     },
     "simplebeam" => MoveData {
         num: 493,
@@ -11135,6 +12159,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "The target's Ability becomes Simple.",
+        description: "The target's Ability becomes Simple.",
     },
     "entrainment" => MoveData {
         num: 494,
@@ -11154,6 +12180,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "The target's Ability changes to match the user's.",
+        description: "The target's Ability changes to match the user's.",
     },
     "afteryou" => MoveData {
         num: 495,
@@ -11173,6 +12201,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "The target makes its move right after the user.",
+        description: "The target makes its move right after the user.",
     },
     "round" => MoveData {
         num: 496,
@@ -11192,6 +12222,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power doubles if others used Round this turn.",
+        description: "Power doubles if others used Round this turn.",
     },
     "echoedvoice" => MoveData {
         num: 497,
@@ -11211,6 +12243,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power increases when used on consecutive turns.",
+        description: "Power increases when used on consecutive turns.",
     },
     "chipaway" => MoveData {
         num: 498,
@@ -11230,6 +12264,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Ignores the target's stat stage changes.",
+        description: "Ignores the target's stat stage changes.",
     },
     "clearsmog" => MoveData {
         num: 499,
@@ -11249,6 +12285,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Eliminates the target's stat changes.",
+        description: "Eliminates the target's stat changes.",
     },
     "storedpower" => MoveData {
         num: 500,
@@ -11268,6 +12306,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "+ 20 power for each of the user's stat boosts.",
+        description: "+ 20 power for each of the user's stat boosts.",
     },
     "quickguard" => MoveData {
         num: 501,
@@ -11287,6 +12327,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Protects allies from priority attacks this turn.",
+        description: "Protects allies from priority attacks this turn.",
     },
     "allyswitch" => MoveData {
         num: 502,
@@ -11306,6 +12348,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "The user swaps positions with its ally.",
+        description: "The user swaps positions with its ally.",
     },
     "scald" => MoveData {
         num: 503,
@@ -11335,6 +12379,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to burn the target. Thaws target.",
+        description: "30% chance to burn the target. Thaws target.",
     },
     "shellsmash" => MoveData {
         num: 504,
@@ -11354,6 +12400,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers Def, SpD by 1; raises Atk, SpA, Spe by 2.",
+        description: "Lowers Def, SpD by 1; raises Atk, SpA, Spe by 2.",
     },
     "healpulse" => MoveData {
         num: 505,
@@ -11373,6 +12421,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Heals the target by 50% of its max HP.",
+        description: "Heals the target by 50% of its max HP.",
     },
     "hex" => MoveData {
         num: 506,
@@ -11392,6 +12442,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power doubles if the target has a status ailment.",
+        description: "Power doubles if the target has a status ailment.",
     },
     "skydrop" => MoveData {
         num: 507,
@@ -11411,6 +12463,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User and foe fly up turn 1. Damages on turn 2.",
+        description: "User and foe fly up turn 1. Damages on turn 2.",
     },
     "shiftgear" => MoveData {
         num: 508,
@@ -11430,6 +12484,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Speed by 2 and Attack by 1.",
+        description: "Raises the user's Speed by 2 and Attack by 1.",
     },
     "circlethrow" => MoveData {
         num: 509,
@@ -11449,6 +12505,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Forces the target to switch to a random ally.",
+        description: "Forces the target to switch to a random ally.",
     },
     "incinerate" => MoveData {
         num: 510,
@@ -11468,6 +12526,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Destroys the foe(s) Berry/Gem.",
+        description: "Destroys the foe(s) Berry/Gem.",
     },
     "quash" => MoveData {
         num: 511,
@@ -11487,6 +12547,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Forces the target to move last this turn.",
+        description: "Forces the target to move last this turn.",
     },
     "acrobatics" => MoveData {
         num: 512,
@@ -11506,6 +12568,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power doubles if the user has no held item.",
+        description: "Power doubles if the user has no held item.",
     },
     "reflecttype" => MoveData {
         num: 513,
@@ -11525,6 +12589,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User becomes the same type as the target.",
+        description: "User becomes the same type as the target.",
     },
     "retaliate" => MoveData {
         num: 514,
@@ -11544,6 +12610,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power doubles if an ally fainted last turn.",
+        description: "Power doubles if an ally fainted last turn.",
     },
     "finalgambit" => MoveData {
         num: 515,
@@ -11563,6 +12631,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Does damage equal to the user's HP. User faints.",
+        description: "Does damage equal to the user's HP. User faints.",
     },
     "bestow" => MoveData {
         num: 516,
@@ -11582,6 +12652,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User passes its held item to the target.",
+        description: "User passes its held item to the target.",
     },
     "inferno" => MoveData {
         num: 517,
@@ -11611,6 +12683,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to burn the target.",
+        description: "100% chance to burn the target.",
     },
     "waterpledge" => MoveData {
         num: 518,
@@ -11630,6 +12704,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Use with Grass or Fire Pledge for added effect.",
+        description: "Use with Grass or Fire Pledge for added effect.",
     },
     "firepledge" => MoveData {
         num: 519,
@@ -11649,6 +12725,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Use with Grass or Water Pledge for added effect.",
+        description: "Use with Grass or Water Pledge for added effect.",
     },
     "grasspledge" => MoveData {
         num: 520,
@@ -11668,6 +12746,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Use with Fire or Water Pledge for added effect.",
+        description: "Use with Fire or Water Pledge for added effect.",
     },
     "voltswitch" => MoveData {
         num: 521,
@@ -11687,6 +12767,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User switches out after damaging the target.",
+        description: "User switches out after damaging the target.",
     },
     "strugglebug" => MoveData {
         num: 522,
@@ -11716,6 +12798,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to lower the foe(s) Sp. Atk by 1.",
+        description: "100% chance to lower the foe(s) Sp. Atk by 1.",
     },
     "bulldoze" => MoveData {
         num: 523,
@@ -11745,6 +12829,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance lower adjacent Pkmn Speed by 1.",
+        description: "100% chance lower adjacent Pkmn Speed by 1.",
     },
     "frostbreath" => MoveData {
         num: 524,
@@ -11764,6 +12850,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Always results in a critical hit.",
+        description: "Always results in a critical hit.",
     },
     "dragontail" => MoveData {
         num: 525,
@@ -11783,6 +12871,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Forces the target to switch to a random ally.",
+        description: "Forces the target to switch to a random ally.",
     },
     "workup" => MoveData {
         num: 526,
@@ -11802,6 +12892,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Attack and Sp. Atk by 1.",
+        description: "Raises the user's Attack and Sp. Atk by 1.",
     },
     "electroweb" => MoveData {
         num: 527,
@@ -11831,6 +12923,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to lower the foe(s) Speed by 1.",
+        description: "100% chance to lower the foe(s) Speed by 1.",
     },
     "wildcharge" => MoveData {
         num: 528,
@@ -11850,6 +12944,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: Some((1, 4)),
         drain: None,
         multihit: None,
+        short_desc: "Has 1/4 recoil.",
+        description: "Has 1/4 recoil.",
     },
     "drillrun" => MoveData {
         num: 529,
@@ -11869,6 +12965,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "High critical hit ratio.",
+        description: "High critical hit ratio.",
     },
     "dualchop" => MoveData {
         num: 530,
@@ -11888,6 +12986,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hits 2 times in one turn.",
+        description: "Hits 2 times in one turn.",
     },
     "heartstamp" => MoveData {
         num: 531,
@@ -11917,6 +13017,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to make the target flinch.",
+        description: "30% chance to make the target flinch.",
     },
     "hornleech" => MoveData {
         num: 532,
@@ -11936,6 +13038,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: Some((1, 2)),
         multihit: None,
+        short_desc: "User recovers 50% of the damage dealt.",
+        description: "User recovers 50% of the damage dealt.",
     },
     "sacredsword" => MoveData {
         num: 533,
@@ -11955,6 +13059,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Ignores the target's stat stage changes.",
+        description: "Ignores the target's stat stage changes.",
     },
     "razorshell" => MoveData {
         num: 534,
@@ -11984,6 +13090,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "50% chance to lower the target's Defense by 1.",
+        description: "50% chance to lower the target's Defense by 1.",
     },
     "heatcrash" => MoveData {
         num: 535,
@@ -12003,6 +13111,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "More power the heavier the user than the target.",
+        description: "More power the heavier the user than the target.",
     },
     "leaftornado" => MoveData {
         num: 536,
@@ -12032,6 +13142,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "50% chance to lower the target's accuracy by 1.",
+        description: "50% chance to lower the target's accuracy by 1.",
     },
     "steamroller" => MoveData {
         num: 537,
@@ -12061,6 +13173,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to make the target flinch.",
+        description: "30% chance to make the target flinch.",
     },
     "cottonguard" => MoveData {
         num: 538,
@@ -12080,6 +13194,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Defense by 3.",
+        description: "Raises the user's Defense by 3.",
     },
     "nightdaze" => MoveData {
         num: 539,
@@ -12109,6 +13225,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "40% chance to lower the target's accuracy by 1.",
+        description: "40% chance to lower the target's accuracy by 1.",
     },
     "psystrike" => MoveData {
         num: 540,
@@ -12128,6 +13246,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Damages target based on Defense, not Sp. Def.",
+        description: "Damages target based on Defense, not Sp. Def.",
     },
     "tailslap" => MoveData {
         num: 541,
@@ -12147,6 +13267,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: Some((2, 5)),
+        short_desc: "Hits 2-5 times in one turn.",
+        description: "Hits 2-5 times in one turn.",
     },
     "hurricane" => MoveData {
         num: 542,
@@ -12176,6 +13298,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to confuse target. Can't miss in rain.",
+        description: "30% chance to confuse target. Can't miss in rain.",
     },
     "headcharge" => MoveData {
         num: 543,
@@ -12195,6 +13319,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: Some((1, 4)),
         drain: None,
         multihit: None,
+        short_desc: "Has 1/4 recoil.",
+        description: "Has 1/4 recoil.",
     },
     "geargrind" => MoveData {
         num: 544,
@@ -12214,6 +13340,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hits 2 times in one turn.",
+        description: "Hits 2 times in one turn.",
     },
     "searingshot" => MoveData {
         num: 545,
@@ -12243,6 +13371,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to burn adjacent Pokemon.",
+        description: "30% chance to burn adjacent Pokemon.",
     },
     "technoblast" => MoveData {
         num: 546,
@@ -12262,6 +13392,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Type varies based on the held Drive.",
+        description: "Type varies based on the held Drive.",
     },
     "relicsong" => MoveData {
         num: 547,
@@ -12291,6 +13423,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to sleep foe(s). Meloetta transforms.",
+        description: "10% chance to sleep foe(s). Meloetta transforms.",
     },
     "secretsword" => MoveData {
         num: 548,
@@ -12310,6 +13444,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Damages target based on Defense, not Sp. Def.",
+        description: "Damages target based on Defense, not Sp. Def.",
     },
     "glaciate" => MoveData {
         num: 549,
@@ -12339,6 +13475,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to lower the foe(s) Speed by 1.",
+        description: "100% chance to lower the foe(s) Speed by 1.",
     },
     "boltstrike" => MoveData {
         num: 550,
@@ -12368,6 +13506,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "20% chance to paralyze the target.",
+        description: "20% chance to paralyze the target.",
     },
     "blueflare" => MoveData {
         num: 551,
@@ -12397,6 +13537,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "20% chance to burn the target.",
+        description: "20% chance to burn the target.",
     },
     "fierydance" => MoveData {
         num: 552,
@@ -12426,6 +13568,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "50% chance to raise the user's Sp. Atk by 1.",
+        description: "50% chance to raise the user's Sp. Atk by 1.",
     },
     "freezeshock" => MoveData {
         num: 553,
@@ -12455,6 +13599,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Charges turn 1. Hits turn 2. 30% paralyze.",
+        description: "Charges turn 1. Hits turn 2. 30% paralyze.",
     },
     "iceburn" => MoveData {
         num: 554,
@@ -12484,6 +13630,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Charges turn 1. Hits turn 2. 30% burn.",
+        description: "Charges turn 1. Hits turn 2. 30% burn.",
     },
     "snarl" => MoveData {
         num: 555,
@@ -12513,6 +13661,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to lower the foe(s) Sp. Atk by 1.",
+        description: "100% chance to lower the foe(s) Sp. Atk by 1.",
     },
     "iciclecrash" => MoveData {
         num: 556,
@@ -12542,6 +13692,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to make the target flinch.",
+        description: "30% chance to make the target flinch.",
     },
     "vcreate" => MoveData {
         num: 557,
@@ -12571,6 +13723,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the user's Speed, Defense, and Sp. Def by 1.",
+        description: "Lowers the user's Speed, Defense, and Sp. Def by 1.",
     },
     "fusionflare" => MoveData {
         num: 558,
@@ -12590,6 +13744,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power doubles if used after Fusion Bolt this turn.",
+        description: "Power doubles if used after Fusion Bolt this turn.",
     },
     "fusionbolt" => MoveData {
         num: 559,
@@ -12609,6 +13765,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power doubles if used after Fusion Flare this turn.",
+        description: "Power doubles if used after Fusion Flare this turn.",
     },
     "flyingpress" => MoveData {
         num: 560,
@@ -12628,6 +13786,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Combines Flying in its type effectiveness.",
+        description: "Combines Flying in its type effectiveness.",
     },
     "matblock" => MoveData {
         num: 561,
@@ -12647,6 +13807,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Protects allies from damaging attacks. Turn 1 only.",
+        description: "Protects allies from damaging attacks. Turn 1 only.",
     },
     "belch" => MoveData {
         num: 562,
@@ -12666,6 +13828,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Cannot be selected until the user eats a Berry.",
+        description: "Cannot be selected until the user eats a Berry.",
     },
     "rototiller" => MoveData {
         num: 563,
@@ -12685,6 +13849,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises Atk/Sp. Atk of grounded Grass types by 1.",
+        description: "Raises Atk/Sp. Atk of grounded Grass types by 1.",
     },
     "stickyweb" => MoveData {
         num: 564,
@@ -12704,6 +13870,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers Speed of grounded foes by 1 on switch-in.",
+        description: "Lowers Speed of grounded foes by 1 on switch-in.",
     },
     "fellstinger" => MoveData {
         num: 565,
@@ -12723,6 +13891,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises user's Attack by 3 if this KOes the target.",
+        description: "Raises user's Attack by 3 if this KOes the target.",
     },
     "phantomforce" => MoveData {
         num: 566,
@@ -12742,6 +13912,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Disappears turn 1. Hits turn 2. Breaks protection.",
+        description: "Disappears turn 1. Hits turn 2. Breaks protection.",
     },
     "trickortreat" => MoveData {
         num: 567,
@@ -12761,6 +13933,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Adds Ghost to the target's type(s).",
+        description: "Adds Ghost to the target's type(s).",
     },
     "nobleroar" => MoveData {
         num: 568,
@@ -12780,6 +13954,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the target's Attack and Sp. Atk by 1.",
+        description: "Lowers the target's Attack and Sp. Atk by 1.",
     },
     "iondeluge" => MoveData {
         num: 569,
@@ -12799,6 +13975,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Normal moves become Electric type this turn.",
+        description: "Normal moves become Electric type this turn.",
     },
     "paraboliccharge" => MoveData {
         num: 570,
@@ -12818,6 +13996,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: Some((1, 2)),
         multihit: None,
+        short_desc: "User recovers 50% of the damage dealt.",
+        description: "User recovers 50% of the damage dealt.",
     },
     "forestscurse" => MoveData {
         num: 571,
@@ -12837,6 +14017,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Adds Grass to the target's type(s).",
+        description: "Adds Grass to the target's type(s).",
     },
     "petalblizzard" => MoveData {
         num: 572,
@@ -12856,6 +14038,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect. Hits adjacent Pokemon.",
+        description: "No additional effect. Hits adjacent Pokemon.",
     },
     "freezedry" => MoveData {
         num: 573,
@@ -12885,6 +14069,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to freeze. Super effective on Water.",
+        description: "10% chance to freeze. Super effective on Water.",
     },
     "disarmingvoice" => MoveData {
         num: 574,
@@ -12904,6 +14090,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "This move does not check accuracy. Hits foes.",
+        description: "This move does not check accuracy. Hits foes.",
     },
     "partingshot" => MoveData {
         num: 575,
@@ -12923,6 +14111,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers target's Atk, Sp. Atk by 1. User switches.",
+        description: "Lowers target's Atk, Sp. Atk by 1. User switches.",
     },
     "topsyturvy" => MoveData {
         num: 576,
@@ -12942,6 +14132,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Inverts the target's stat stages.",
+        description: "Inverts the target's stat stages.",
     },
     "drainingkiss" => MoveData {
         num: 577,
@@ -12961,6 +14153,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: Some((3, 4)),
         multihit: None,
+        short_desc: "User recovers 75% of the damage dealt.",
+        description: "User recovers 75% of the damage dealt.",
     },
     "craftyshield" => MoveData {
         num: 578,
@@ -12980,6 +14174,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Protects allies from Status moves this turn.",
+        description: "Protects allies from Status moves this turn.",
     },
     "flowershield" => MoveData {
         num: 579,
@@ -12999,6 +14195,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises Defense by 1 of all active Grass types.",
+        description: "Raises Defense by 1 of all active Grass types.",
     },
     "grassyterrain" => MoveData {
         num: 580,
@@ -13018,6 +14216,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "5 turns. Grounded: +Grass power, +1/16 max HP.",
+        description: "5 turns. Grounded: +Grass power, +1/16 max HP.",
     },
     "mistyterrain" => MoveData {
         num: 581,
@@ -13037,6 +14237,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "5 turns. Can't status, -Dragon power vs grounded.",
+        description: "5 turns. Can't status, -Dragon power vs grounded.",
     },
     "electrify" => MoveData {
         num: 582,
@@ -13056,6 +14258,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Changes the target's move to Electric this turn.",
+        description: "Changes the target's move to Electric this turn.",
     },
     "playrough" => MoveData {
         num: 583,
@@ -13085,6 +14289,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to lower the target's Attack by 1.",
+        description: "10% chance to lower the target's Attack by 1.",
     },
     "fairywind" => MoveData {
         num: 584,
@@ -13104,6 +14310,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "moonblast" => MoveData {
         num: 585,
@@ -13133,6 +14341,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to lower the target's Sp. Atk by 1.",
+        description: "30% chance to lower the target's Sp. Atk by 1.",
     },
     "boomburst" => MoveData {
         num: 586,
@@ -13152,6 +14362,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect. Hits adjacent Pokemon.",
+        description: "No additional effect. Hits adjacent Pokemon.",
     },
     "fairylock" => MoveData {
         num: 587,
@@ -13171,6 +14383,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Prevents all Pokemon from switching next turn.",
+        description: "Prevents all Pokemon from switching next turn.",
     },
     "kingsshield" => MoveData {
         num: 588,
@@ -13190,6 +14404,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Protects from damaging attacks. Contact: -1 Atk.",
+        description: "Protects from damaging attacks. Contact: -1 Atk.",
     },
     "playnice" => MoveData {
         num: 589,
@@ -13209,6 +14425,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the target's Attack by 1.",
+        description: "Lowers the target's Attack by 1.",
     },
     "confide" => MoveData {
         num: 590,
@@ -13228,6 +14446,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the target's Sp. Atk by 1.",
+        description: "Lowers the target's Sp. Atk by 1.",
     },
     "diamondstorm" => MoveData {
         num: 591,
@@ -13267,6 +14487,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "50% chance to raise user's Def by 2 for each hit.",
+        description: "50% chance to raise user's Def by 2 for each hit.",
     },
     "steameruption" => MoveData {
         num: 592,
@@ -13296,6 +14518,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to burn the target. Thaws target.",
+        description: "30% chance to burn the target. Thaws target.",
     },
     "hyperspacehole" => MoveData {
         num: 593,
@@ -13315,6 +14539,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Breaks the target's protection for this turn.",
+        description: "Breaks the target's protection for this turn.",
     },
     "watershuriken" => MoveData {
         num: 594,
@@ -13334,6 +14560,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: Some((2, 5)),
+        short_desc: "Hits 2-5 times in one turn.",
+        description: "Hits 2-5 times in one turn.",
     },
     "mysticalfire" => MoveData {
         num: 595,
@@ -13363,6 +14591,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to lower the target's Sp. Atk by 1.",
+        description: "100% chance to lower the target's Sp. Atk by 1.",
     },
     "spikyshield" => MoveData {
         num: 596,
@@ -13382,6 +14612,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Protects from moves. Contact: loses 1/8 max HP.",
+        description: "Protects from moves. Contact: loses 1/8 max HP.",
     },
     "aromaticmist" => MoveData {
         num: 597,
@@ -13401,6 +14633,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises an ally's Sp. Def by 1.",
+        description: "Raises an ally's Sp. Def by 1.",
     },
     "eerieimpulse" => MoveData {
         num: 598,
@@ -13420,6 +14654,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the target's Sp. Atk by 2.",
+        description: "Lowers the target's Sp. Atk by 2.",
     },
     "venomdrench" => MoveData {
         num: 599,
@@ -13439,6 +14675,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers Atk/Sp. Atk/Speed of poisoned foes by 1.",
+        description: "Lowers Atk/Sp. Atk/Speed of poisoned foes by 1.",
     },
     "powder" => MoveData {
         num: 600,
@@ -13458,6 +14696,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "If using a Fire move, target loses 1/4 max HP.",
+        description: "If using a Fire move, target loses 1/4 max HP.",
     },
     "geomancy" => MoveData {
         num: 601,
@@ -13477,6 +14717,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Charges, then raises SpA, SpD, Spe by 2 turn 2.",
+        description: "Charges, then raises SpA, SpD, Spe by 2 turn 2.",
     },
     "magneticflux" => MoveData {
         num: 602,
@@ -13496,6 +14738,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises Def, Sp. Def of allies with Plus/Minus by 1.",
+        description: "Raises Def, Sp. Def of allies with Plus/Minus by 1.",
     },
     "happyhour" => MoveData {
         num: 603,
@@ -13515,6 +14759,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No competitive use.",
+        description: "No competitive use.",
     },
     "electricterrain" => MoveData {
         num: 604,
@@ -13534,6 +14780,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "5 turns. Grounded: +Electric power, can't sleep.",
+        description: "5 turns. Grounded: +Electric power, can't sleep.",
     },
     "dazzlinggleam" => MoveData {
         num: 605,
@@ -13553,6 +14801,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect. Hits adjacent foes.",
+        description: "No additional effect. Hits adjacent foes.",
     },
     "celebrate" => MoveData {
         num: 606,
@@ -13572,6 +14822,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No competitive use.",
+        description: "No competitive use.",
     },
     "holdhands" => MoveData {
         num: 607,
@@ -13591,6 +14843,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No competitive use. Or any use.",
+        description: "No competitive use. Or any use.",
     },
     "babydolleyes" => MoveData {
         num: 608,
@@ -13610,6 +14864,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the target's Attack by 1.",
+        description: "Lowers the target's Attack by 1.",
     },
     "nuzzle" => MoveData {
         num: 609,
@@ -13639,6 +14895,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to paralyze the target.",
+        description: "100% chance to paralyze the target.",
     },
     "holdback" => MoveData {
         num: 610,
@@ -13658,6 +14916,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Always leaves the target with at least 1 HP.",
+        description: "Always leaves the target with at least 1 HP.",
     },
     "infestation" => MoveData {
         num: 611,
@@ -13677,6 +14937,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Traps and damages the target for 4-5 turns.",
+        description: "Traps and damages the target for 4-5 turns.",
     },
     "poweruppunch" => MoveData {
         num: 612,
@@ -13706,6 +14968,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to raise the user's Attack by 1.",
+        description: "100% chance to raise the user's Attack by 1.",
     },
     "oblivionwing" => MoveData {
         num: 613,
@@ -13725,6 +14989,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: Some((3, 4)),
         multihit: None,
+        short_desc: "User recovers 75% of the damage dealt.",
+        description: "User recovers 75% of the damage dealt.",
     },
     "thousandarrows" => MoveData {
         num: 614,
@@ -13744,6 +15010,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Grounds adjacent foes. First hit neutral on Flying.",
+        description: "Grounds adjacent foes. First hit neutral on Flying.",
     },
     "thousandwaves" => MoveData {
         num: 615,
@@ -13763,6 +15031,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hits adjacent foes. Prevents them from switching.",
+        description: "Hits adjacent foes. Prevents them from switching.",
     },
     "landswrath" => MoveData {
         num: 616,
@@ -13782,6 +15052,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect. Hits adjacent foes.",
+        description: "No additional effect. Hits adjacent foes.",
     },
     "lightofruin" => MoveData {
         num: 617,
@@ -13801,6 +15073,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: Some((1, 2)),
         drain: None,
         multihit: None,
+        short_desc: "Has 1/2 recoil.",
+        description: "Has 1/2 recoil.",
     },
     "originpulse" => MoveData {
         num: 618,
@@ -13820,6 +15094,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect. Hits adjacent foes.",
+        description: "No additional effect. Hits adjacent foes.",
     },
     "precipiceblades" => MoveData {
         num: 619,
@@ -13839,6 +15115,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect. Hits adjacent foes.",
+        description: "No additional effect. Hits adjacent foes.",
     },
     "dragonascent" => MoveData {
         num: 620,
@@ -13868,6 +15146,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the user's Defense and Sp. Def by 1.",
+        description: "Lowers the user's Defense and Sp. Def by 1.",
     },
     "hyperspacefury" => MoveData {
         num: 621,
@@ -13897,6 +15177,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hoopa-U: Lowers user's Def by 1; breaks protection.",
+        description: "Hoopa-U: Lowers user's Def by 1; breaks protection.",
     },
     "breakneckblitz" => MoveData {
         num: 622,
@@ -13916,6 +15198,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Z-Power.",
+        description: "Power is equal to the base move's Z-Power.",
     },
     "alloutpummeling" => MoveData {
         num: 624,
@@ -13935,6 +15219,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Z-Power.",
+        description: "Power is equal to the base move's Z-Power.",
     },
     "supersonicskystrike" => MoveData {
         num: 626,
@@ -13954,6 +15240,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Z-Power.",
+        description: "Power is equal to the base move's Z-Power.",
     },
     "aciddownpour" => MoveData {
         num: 628,
@@ -13973,6 +15261,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Z-Power.",
+        description: "Power is equal to the base move's Z-Power.",
     },
     "tectonicrage" => MoveData {
         num: 630,
@@ -13992,6 +15282,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Z-Power.",
+        description: "Power is equal to the base move's Z-Power.",
     },
     "continentalcrush" => MoveData {
         num: 632,
@@ -14011,6 +15303,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Z-Power.",
+        description: "Power is equal to the base move's Z-Power.",
     },
     "savagespinout" => MoveData {
         num: 634,
@@ -14030,6 +15324,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Z-Power.",
+        description: "Power is equal to the base move's Z-Power.",
     },
     "neverendingnightmare" => MoveData {
         num: 636,
@@ -14049,6 +15345,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Z-Power.",
+        description: "Power is equal to the base move's Z-Power.",
     },
     "corkscrewcrash" => MoveData {
         num: 638,
@@ -14068,6 +15366,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Z-Power.",
+        description: "Power is equal to the base move's Z-Power.",
     },
     "infernooverdrive" => MoveData {
         num: 640,
@@ -14087,6 +15387,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Z-Power.",
+        description: "Power is equal to the base move's Z-Power.",
     },
     "hydrovortex" => MoveData {
         num: 642,
@@ -14106,6 +15408,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Z-Power.",
+        description: "Power is equal to the base move's Z-Power.",
     },
     "bloomdoom" => MoveData {
         num: 644,
@@ -14125,6 +15429,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Z-Power.",
+        description: "Power is equal to the base move's Z-Power.",
     },
     "gigavolthavoc" => MoveData {
         num: 646,
@@ -14144,6 +15450,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Z-Power.",
+        description: "Power is equal to the base move's Z-Power.",
     },
     "shatteredpsyche" => MoveData {
         num: 648,
@@ -14163,6 +15471,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Z-Power.",
+        description: "Power is equal to the base move's Z-Power.",
     },
     "subzeroslammer" => MoveData {
         num: 650,
@@ -14182,6 +15492,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Z-Power.",
+        description: "Power is equal to the base move's Z-Power.",
     },
     "devastatingdrake" => MoveData {
         num: 652,
@@ -14201,6 +15513,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Z-Power.",
+        description: "Power is equal to the base move's Z-Power.",
     },
     "blackholeeclipse" => MoveData {
         num: 654,
@@ -14220,6 +15534,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Z-Power.",
+        description: "Power is equal to the base move's Z-Power.",
     },
     "twinkletackle" => MoveData {
         num: 656,
@@ -14239,6 +15555,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Z-Power.",
+        description: "Power is equal to the base move's Z-Power.",
     },
     "catastropika" => MoveData {
         num: 658,
@@ -14258,6 +15576,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "shoreup" => MoveData {
         num: 659,
@@ -14277,6 +15597,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User restores 1/2 its max HP; 2/3 in Sandstorm.",
+        description: "User restores 1/2 its max HP; 2/3 in Sandstorm.",
     },
     "firstimpression" => MoveData {
         num: 660,
@@ -14296,6 +15618,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hits first. First turn out only.",
+        description: "Hits first. First turn out only.",
     },
     "banefulbunker" => MoveData {
         num: 661,
@@ -14315,6 +15639,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Protects from moves. Contact: poison.",
+        description: "Protects from moves. Contact: poison.",
     },
     "spiritshackle" => MoveData {
         num: 662,
@@ -14344,6 +15670,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Prevents the target from switching out.",
+        description: "Prevents the target from switching out.",
     },
     "darkestlariat" => MoveData {
         num: 663,
@@ -14363,6 +15691,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Ignores the target's stat stage changes.",
+        description: "Ignores the target's stat stage changes.",
     },
     "sparklingaria" => MoveData {
         num: 664,
@@ -14392,6 +15722,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "The target is cured of its burn.",
+        description: "The target is cured of its burn.",
     },
     "icehammer" => MoveData {
         num: 665,
@@ -14421,6 +15753,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the user's Speed by 1.",
+        description: "Lowers the user's Speed by 1.",
     },
     "floralhealing" => MoveData {
         num: 666,
@@ -14440,6 +15774,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Heals the target by 50% of its max HP.",
+        description: "Heals the target by 50% of its max HP.",
     },
     "highhorsepower" => MoveData {
         num: 667,
@@ -14459,6 +15795,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "strengthsap" => MoveData {
         num: 668,
@@ -14478,6 +15816,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User heals HP=target's Atk stat. Lowers Atk by 1.",
+        description: "User heals HP=target's Atk stat. Lowers Atk by 1.",
     },
     "solarblade" => MoveData {
         num: 669,
@@ -14497,6 +15837,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Charges turn 1. Hits turn 2. No charge in sunlight.",
+        description: "Charges turn 1. Hits turn 2. No charge in sunlight.",
     },
     "leafage" => MoveData {
         num: 670,
@@ -14516,6 +15858,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "spotlight" => MoveData {
         num: 671,
@@ -14535,6 +15879,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Target's foes' moves are redirected to it this turn.",
+        description: "Target's foes' moves are redirected to it this turn.",
     },
     "toxicthread" => MoveData {
         num: 672,
@@ -14554,6 +15900,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the target's Speed by 1 and poisons it.",
+        description: "Lowers the target's Speed by 1 and poisons it.",
     },
     "laserfocus" => MoveData {
         num: 673,
@@ -14573,6 +15921,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Until the end of the next turn, user's moves crit.",
+        description: "Until the end of the next turn, user's moves crit.",
     },
     "gearup" => MoveData {
         num: 674,
@@ -14592,6 +15942,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises Atk, Sp. Atk of allies with Plus/Minus by 1.",
+        description: "Raises Atk, Sp. Atk of allies with Plus/Minus by 1.",
     },
     "throatchop" => MoveData {
         num: 675,
@@ -14621,6 +15973,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "For 2 turns, the target cannot use sound moves.",
+        description: "For 2 turns, the target cannot use sound moves.",
     },
     "pollenpuff" => MoveData {
         num: 676,
@@ -14640,6 +15994,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "If the target is an ally, heals 50% of its max HP.",
+        description: "If the target is an ally, heals 50% of its max HP.",
     },
     "anchorshot" => MoveData {
         num: 677,
@@ -14669,6 +16025,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Prevents the target from switching out.",
+        description: "Prevents the target from switching out.",
     },
     "psychicterrain" => MoveData {
         num: 678,
@@ -14688,6 +16046,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "5 turns. Grounded: +Psychic power, priority-safe.",
+        description: "5 turns. Grounded: +Psychic power, priority-safe.",
     },
     "lunge" => MoveData {
         num: 679,
@@ -14717,6 +16077,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to lower the target's Attack by 1.",
+        description: "100% chance to lower the target's Attack by 1.",
     },
     "firelash" => MoveData {
         num: 680,
@@ -14746,6 +16108,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to lower the target's Defense by 1.",
+        description: "100% chance to lower the target's Defense by 1.",
     },
     "powertrip" => MoveData {
         num: 681,
@@ -14765,6 +16129,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "+ 20 power for each of the user's stat boosts.",
+        description: "+ 20 power for each of the user's stat boosts.",
     },
     "burnup" => MoveData {
         num: 682,
@@ -14794,6 +16160,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User's Fire type becomes typeless; must be Fire.",
+        description: "User's Fire type becomes typeless; must be Fire.",
     },
     "speedswap" => MoveData {
         num: 683,
@@ -14813,6 +16181,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Swaps Speed stat with target.",
+        description: "Swaps Speed stat with target.",
     },
     "smartstrike" => MoveData {
         num: 684,
@@ -14832,6 +16202,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "This move does not check accuracy.",
+        description: "This move does not check accuracy.",
     },
     "purify" => MoveData {
         num: 685,
@@ -14851,6 +16223,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Cures target's status; heals user 1/2 max HP if so.",
+        description: "Cures target's status; heals user 1/2 max HP if so.",
     },
     "revelationdance" => MoveData {
         num: 686,
@@ -14870,6 +16244,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Type varies based on the user's primary type.",
+        description: "Type varies based on the user's primary type.",
     },
     "coreenforcer" => MoveData {
         num: 687,
@@ -14889,6 +16265,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Nullifies the foe(s) Ability if the foe(s) move first.",
+        description: "Nullifies the foe(s) Ability if the foe(s) move first.",
     },
     "tropkick" => MoveData {
         num: 688,
@@ -14918,6 +16296,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to lower the target's Attack by 1.",
+        description: "100% chance to lower the target's Attack by 1.",
     },
     "instruct" => MoveData {
         num: 689,
@@ -14937,6 +16317,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "The target immediately uses its last used move.",
+        description: "The target immediately uses its last used move.",
     },
     "beakblast" => MoveData {
         num: 690,
@@ -14956,6 +16338,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Burns on contact with the user before it moves.",
+        description: "Burns on contact with the user before it moves.",
     },
     "clangingscales" => MoveData {
         num: 691,
@@ -14975,6 +16359,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the user's Defense by 1.",
+        description: "Lowers the user's Defense by 1.",
     },
     "dragonhammer" => MoveData {
         num: 692,
@@ -14994,6 +16380,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "brutalswing" => MoveData {
         num: 693,
@@ -15013,6 +16401,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect. Hits adjacent Pokemon.",
+        description: "No additional effect. Hits adjacent Pokemon.",
     },
     "auroraveil" => MoveData {
         num: 694,
@@ -15032,6 +16422,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "For 5 turns, damage to allies halved. Snow only.",
+        description: "For 5 turns, damage to allies halved. Snow only.",
     },
     "sinisterarrowraid" => MoveData {
         num: 695,
@@ -15051,6 +16443,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "maliciousmoonsault" => MoveData {
         num: 696,
@@ -15070,6 +16464,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Damage doubles if the target used Minimize.",
+        description: "Damage doubles if the target used Minimize.",
     },
     "oceanicoperetta" => MoveData {
         num: 697,
@@ -15089,6 +16485,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "guardianofalola" => MoveData {
         num: 698,
@@ -15108,6 +16506,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Does damage equal to 3/4 target's current HP.",
+        description: "Does damage equal to 3/4 target's current HP.",
     },
     "soulstealing7starstrike" => MoveData {
         num: 699,
@@ -15127,6 +16527,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "stokedsparksurfer" => MoveData {
         num: 700,
@@ -15156,6 +16558,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to paralyze the target.",
+        description: "100% chance to paralyze the target.",
     },
     "pulverizingpancake" => MoveData {
         num: 701,
@@ -15175,6 +16579,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "extremeevoboost" => MoveData {
         num: 702,
@@ -15194,6 +16600,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Atk, Def, SpA, SpD, and Spe by 2.",
+        description: "Raises the user's Atk, Def, SpA, SpD, and Spe by 2.",
     },
     "genesissupernova" => MoveData {
         num: 703,
@@ -15223,6 +16631,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Summons Psychic Terrain.",
+        description: "Summons Psychic Terrain.",
     },
     "shelltrap" => MoveData {
         num: 704,
@@ -15242,6 +16652,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User must take physical damage before moving.",
+        description: "User must take physical damage before moving.",
     },
     "fleurcannon" => MoveData {
         num: 705,
@@ -15271,6 +16683,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the user's Sp. Atk by 2.",
+        description: "Lowers the user's Sp. Atk by 2.",
     },
     "psychicfangs" => MoveData {
         num: 706,
@@ -15290,6 +16704,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Destroys screens, unless the target is immune.",
+        description: "Destroys screens, unless the target is immune.",
     },
     "stompingtantrum" => MoveData {
         num: 707,
@@ -15309,6 +16725,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power doubles if the user's last move failed.",
+        description: "Power doubles if the user's last move failed.",
     },
     "shadowbone" => MoveData {
         num: 708,
@@ -15338,6 +16756,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "20% chance to lower the target's Defense by 1.",
+        description: "20% chance to lower the target's Defense by 1.",
     },
     "accelerock" => MoveData {
         num: 709,
@@ -15357,6 +16777,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Usually goes first.",
+        description: "Usually goes first.",
     },
     "liquidation" => MoveData {
         num: 710,
@@ -15386,6 +16808,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "20% chance to lower the target's Defense by 1.",
+        description: "20% chance to lower the target's Defense by 1.",
     },
     "prismaticlaser" => MoveData {
         num: 711,
@@ -15415,6 +16839,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User cannot move next turn.",
+        description: "User cannot move next turn.",
     },
     "spectralthief" => MoveData {
         num: 712,
@@ -15434,6 +16860,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Steals target's boosts before dealing damage.",
+        description: "Steals target's boosts before dealing damage.",
     },
     "sunsteelstrike" => MoveData {
         num: 713,
@@ -15453,6 +16881,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Ignores the Abilities of other Pokemon.",
+        description: "Ignores the Abilities of other Pokemon.",
     },
     "moongeistbeam" => MoveData {
         num: 714,
@@ -15472,6 +16902,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Ignores the Abilities of other Pokemon.",
+        description: "Ignores the Abilities of other Pokemon.",
     },
     "tearfullook" => MoveData {
         num: 715,
@@ -15491,6 +16923,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the target's Attack and Sp. Atk by 1.",
+        description: "Lowers the target's Attack and Sp. Atk by 1.",
     },
     "zingzap" => MoveData {
         num: 716,
@@ -15520,6 +16954,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to make the target flinch.",
+        description: "30% chance to make the target flinch.",
     },
     "naturesmadness" => MoveData {
         num: 717,
@@ -15539,6 +16975,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Does damage equal to 1/2 target's current HP.",
+        description: "Does damage equal to 1/2 target's current HP.",
     },
     "multiattack" => MoveData {
         num: 718,
@@ -15558,6 +16996,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Type varies based on the held Memory.",
+        description: "Type varies based on the held Memory.",
     },
     "10000000voltthunderbolt" => MoveData {
         num: 719,
@@ -15577,6 +17017,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Very high critical hit ratio.",
+        description: "Very high critical hit ratio.",
     },
     "mindblown" => MoveData {
         num: 720,
@@ -15596,6 +17038,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User loses 50% max HP. Hits adjacent Pokemon.",
+        description: "User loses 50% max HP. Hits adjacent Pokemon.",
     },
     "plasmafists" => MoveData {
         num: 721,
@@ -15615,6 +17059,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Normal moves become Electric type this turn.",
+        description: "Normal moves become Electric type this turn.",
     },
     "photongeyser" => MoveData {
         num: 722,
@@ -15634,6 +17080,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Physical if user's Atk > Sp. Atk. Ignores Abilities.",
+        description: "Physical if user's Atk > Sp. Atk. Ignores Abilities.",
     },
     "lightthatburnsthesky" => MoveData {
         num: 723,
@@ -15653,6 +17101,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Physical if user's Atk > Sp. Atk. Ignores Abilities.",
+        description: "Physical if user's Atk > Sp. Atk. Ignores Abilities.",
     },
     "searingsunrazesmash" => MoveData {
         num: 724,
@@ -15672,6 +17122,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Ignores the Abilities of other Pokemon.",
+        description: "Ignores the Abilities of other Pokemon.",
     },
     "menacingmoonrazemaelstrom" => MoveData {
         num: 725,
@@ -15691,6 +17143,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Ignores the Abilities of other Pokemon.",
+        description: "Ignores the Abilities of other Pokemon.",
     },
     "letssnuggleforever" => MoveData {
         num: 726,
@@ -15710,6 +17164,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "splinteredstormshards" => MoveData {
         num: 727,
@@ -15729,6 +17185,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Ends the effects of Terrain.",
+        description: "Ends the effects of Terrain.",
     },
     "clangoroussoulblaze" => MoveData {
         num: 728,
@@ -15758,6 +17216,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Atk/Def/SpAtk/SpDef/Spe by 1.",
+        description: "Raises the user's Atk/Def/SpAtk/SpDef/Spe by 1.",
     },
     "zippyzap" => MoveData {
         num: 729,
@@ -15787,6 +17247,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Goes first. Raises user's evasion by 1.",
+        description: "Goes first. Raises user's evasion by 1.",
     },
     "splishysplash" => MoveData {
         num: 730,
@@ -15816,6 +17278,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to paralyze the foe(s).",
+        description: "30% chance to paralyze the foe(s).",
     },
     "floatyfall" => MoveData {
         num: 731,
@@ -15845,6 +17309,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to make the target flinch.",
+        description: "30% chance to make the target flinch.",
     },
     "pikapapow" => MoveData {
         num: 732,
@@ -15864,6 +17330,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Max 102 power at maximum Happiness.",
+        description: "Max 102 power at maximum Happiness.",
     },
     "bouncybubble" => MoveData {
         num: 733,
@@ -15883,6 +17351,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: Some((1, 2)),
         multihit: None,
+        short_desc: "User recovers 50% of the damage dealt.",
+        description: "User recovers 50% of the damage dealt.",
     },
     "buzzybuzz" => MoveData {
         num: 734,
@@ -15912,6 +17382,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to paralyze the foe.",
+        description: "100% chance to paralyze the foe.",
     },
     "sizzlyslide" => MoveData {
         num: 735,
@@ -15941,6 +17413,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to burn the foe.",
+        description: "100% chance to burn the foe.",
     },
     "glitzyglow" => MoveData {
         num: 736,
@@ -15970,6 +17444,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Sets Light Screen on the user's side.",
+        description: "Sets Light Screen on the user's side.",
     },
     "baddybad" => MoveData {
         num: 737,
@@ -15999,6 +17475,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Sets Reflect on the user's side.",
+        description: "Sets Reflect on the user's side.",
     },
     "sappyseed" => MoveData {
         num: 738,
@@ -16018,6 +17496,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Prevents the target from switching out.",
+        description: "Prevents the target from switching out.",
     },
     "freezyfrost" => MoveData {
         num: 739,
@@ -16037,6 +17517,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Eliminates all stat changes.",
+        description: "Eliminates all stat changes.",
     },
     "sparklyswirl" => MoveData {
         num: 740,
@@ -16066,6 +17548,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Cures the user's party of all status conditions.",
+        description: "Cures the user's party of all status conditions.",
     },
     "veeveevolley" => MoveData {
         num: 741,
@@ -16085,6 +17569,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Max happiness: 102 power. Can't miss.",
+        description: "Max happiness: 102 power. Can't miss.",
     },
     "doubleironbash" => MoveData {
         num: 742,
@@ -16114,6 +17600,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hits twice. 30% chance to flinch.",
+        description: "Hits twice. 30% chance to flinch.",
     },
     "maxguard" => MoveData {
         num: 743,
@@ -16133,6 +17621,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Prevents moves from affecting the user this turn.",
+        description: "Prevents moves from affecting the user this turn.",
     },
     "dynamaxcannon" => MoveData {
         num: 744,
@@ -16152,6 +17642,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Damage doubles if the target is Dynamaxed.",
+        description: "Damage doubles if the target is Dynamaxed.",
     },
     "snipeshot" => MoveData {
         num: 745,
@@ -16171,6 +17663,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "High critical hit ratio. Cannot be redirected.",
+        description: "High critical hit ratio. Cannot be redirected.",
     },
     "jawlock" => MoveData {
         num: 746,
@@ -16190,6 +17684,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Prevents both user and target from switching out.",
+        description: "Prevents both user and target from switching out.",
     },
     "stuffcheeks" => MoveData {
         num: 747,
@@ -16209,6 +17705,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Must hold Berry to use. User eats Berry, Def +2.",
+        description: "Must hold Berry to use. User eats Berry, Def +2.",
     },
     "noretreat" => MoveData {
         num: 748,
@@ -16228,6 +17726,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises all stats by 1 (not acc/eva). Traps user.",
+        description: "Raises all stats by 1 (not acc/eva). Traps user.",
     },
     "tarshot" => MoveData {
         num: 749,
@@ -16247,6 +17747,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Target gets -1 Spe and becomes weaker to Fire.",
+        description: "Target gets -1 Spe and becomes weaker to Fire.",
     },
     "magicpowder" => MoveData {
         num: 750,
@@ -16266,6 +17768,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Changes the target's type to Psychic.",
+        description: "Changes the target's type to Psychic.",
     },
     "dragondarts" => MoveData {
         num: 751,
@@ -16285,6 +17789,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hits twice. Doubles: Tries to hit each foe once.",
+        description: "Hits twice. Doubles: Tries to hit each foe once.",
     },
     "teatime" => MoveData {
         num: 752,
@@ -16304,6 +17810,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "All active Pokemon consume held Berries.",
+        description: "All active Pokemon consume held Berries.",
     },
     "octolock" => MoveData {
         num: 753,
@@ -16323,6 +17831,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Traps target, lowers Def and SpD by 1 each turn.",
+        description: "Traps target, lowers Def and SpD by 1 each turn.",
     },
     "boltbeak" => MoveData {
         num: 754,
@@ -16342,6 +17852,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power doubles if the user moves before the target.",
+        description: "Power doubles if the user moves before the target.",
     },
     "fishiousrend" => MoveData {
         num: 755,
@@ -16361,6 +17873,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power doubles if the user moves before the target.",
+        description: "Power doubles if the user moves before the target.",
     },
     "courtchange" => MoveData {
         num: 756,
@@ -16380,6 +17894,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Swaps user's field effects with the opposing side.",
+        description: "Swaps user's field effects with the opposing side.",
     },
     "maxflare" => MoveData {
         num: 757,
@@ -16409,6 +17925,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Max Move power. Summons Sunny Day.",
+        description: "Power is equal to the base move's Max Move power. Summons Sunny Day.",
     },
     "maxflutterby" => MoveData {
         num: 758,
@@ -16438,6 +17956,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Max Move power. Lowers the target's side's Sp. Atk by 1.",
+        description: "Power is equal to the base move's Max Move power. Lowers the target's side's Sp. Atk by 1.",
     },
     "maxlightning" => MoveData {
         num: 759,
@@ -16467,6 +17987,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Max Move power. Summons Electric Terrain.",
+        description: "Power is equal to the base move's Max Move power. Summons Electric Terrain.",
     },
     "maxstrike" => MoveData {
         num: 760,
@@ -16496,6 +18018,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Max Move power. Lowers the target's side's Speed by 1.",
+        description: "Power is equal to the base move's Max Move power. Lowers the target's side's Speed by 1.",
     },
     "maxknuckle" => MoveData {
         num: 761,
@@ -16525,6 +18049,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Max Move power. Raises the user's team's Attack by 1.",
+        description: "Power is equal to the base move's Max Move power. Raises the user's team's Attack by 1.",
     },
     "maxphantasm" => MoveData {
         num: 762,
@@ -16554,6 +18080,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Max Move power. Lowers the target's side's Defense by 1.",
+        description: "Power is equal to the base move's Max Move power. Lowers the target's side's Defense by 1.",
     },
     "maxhailstorm" => MoveData {
         num: 763,
@@ -16583,6 +18111,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Max Move power. Summons Hail.",
+        description: "Power is equal to the base move's Max Move power. Summons Hail.",
     },
     "maxooze" => MoveData {
         num: 764,
@@ -16612,6 +18142,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Max Move power. Raises the user's team's Sp. Atk by 1.",
+        description: "Power is equal to the base move's Max Move power. Raises the user's team's Sp. Atk by 1.",
     },
     "maxgeyser" => MoveData {
         num: 765,
@@ -16641,6 +18173,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Max Move power. Summons Rain Dance.",
+        description: "Power is equal to the base move's Max Move power. Summons Rain Dance.",
     },
     "maxairstream" => MoveData {
         num: 766,
@@ -16670,6 +18204,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Max Move power. Raises the user's team's Speed by 1.",
+        description: "Power is equal to the base move's Max Move power. Raises the user's team's Speed by 1.",
     },
     "maxstarfall" => MoveData {
         num: 767,
@@ -16699,6 +18235,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Max Move power. Summons Misty Terrain.",
+        description: "Power is equal to the base move's Max Move power. Summons Misty Terrain.",
     },
     "maxwyrmwind" => MoveData {
         num: 768,
@@ -16728,6 +18266,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Max Move power. Lowers the target's side's Attack by 1.",
+        description: "Power is equal to the base move's Max Move power. Lowers the target's side's Attack by 1.",
     },
     "maxmindstorm" => MoveData {
         num: 769,
@@ -16757,6 +18297,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Max Move power. Summons Psychic Terrain.",
+        description: "Power is equal to the base move's Max Move power. Summons Psychic Terrain.",
     },
     "maxrockfall" => MoveData {
         num: 770,
@@ -16786,6 +18328,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Max Move power. Summons Sandstorm.",
+        description: "Power is equal to the base move's Max Move power. Summons Sandstorm.",
     },
     "maxquake" => MoveData {
         num: 771,
@@ -16815,6 +18359,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Max Move power. Raises the user's team's Sp. Def by 1.",
+        description: "Power is equal to the base move's Max Move power. Raises the user's team's Sp. Def by 1.",
     },
     "maxdarkness" => MoveData {
         num: 772,
@@ -16844,6 +18390,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Max Move power. Lowers the target's side's Sp. Def by 1.",
+        description: "Power is equal to the base move's Max Move power. Lowers the target's side's Sp. Def by 1.",
     },
     "maxovergrowth" => MoveData {
         num: 773,
@@ -16873,6 +18421,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Max Move power. Summons Grassy Terrain.",
+        description: "Power is equal to the base move's Max Move power. Summons Grassy Terrain.",
     },
     "maxsteelspike" => MoveData {
         num: 774,
@@ -16902,6 +18452,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power is equal to the base move's Max Move power. Raises the user's team's Defense by 1.",
+        description: "Power is equal to the base move's Max Move power. Raises the user's team's Defense by 1.",
     },
     "clangoroussoul" => MoveData {
         num: 775,
@@ -16921,6 +18473,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User loses 33% of its max HP. +1 to all stats.",
+        description: "User loses 33% of its max HP. +1 to all stats.",
     },
     "bodypress" => MoveData {
         num: 776,
@@ -16940,6 +18494,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Uses user's Def stat as Atk in damage calculation.",
+        description: "Uses user's Def stat as Atk in damage calculation.",
     },
     "decorate" => MoveData {
         num: 777,
@@ -16959,6 +18515,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the target's Attack and Sp. Atk by 2.",
+        description: "Raises the target's Attack and Sp. Atk by 2.",
     },
     "drumbeating" => MoveData {
         num: 778,
@@ -16988,6 +18546,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to lower the target's Speed by 1.",
+        description: "100% chance to lower the target's Speed by 1.",
     },
     "snaptrap" => MoveData {
         num: 779,
@@ -17007,6 +18567,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Traps and damages the target for 4-5 turns.",
+        description: "Traps and damages the target for 4-5 turns.",
     },
     "pyroball" => MoveData {
         num: 780,
@@ -17036,6 +18598,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to burn the target. Thaws user.",
+        description: "10% chance to burn the target. Thaws user.",
     },
     "behemothblade" => MoveData {
         num: 781,
@@ -17055,6 +18619,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Damage doubles if the target is Dynamaxed.",
+        description: "Damage doubles if the target is Dynamaxed.",
     },
     "behemothbash" => MoveData {
         num: 782,
@@ -17074,6 +18640,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Damage doubles if the target is Dynamaxed.",
+        description: "Damage doubles if the target is Dynamaxed.",
     },
     "aurawheel" => MoveData {
         num: 783,
@@ -17103,6 +18671,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Morpeko: Electric; Hangry: Dark; 100% +1 Spe.",
+        description: "Morpeko: Electric; Hangry: Dark; 100% +1 Spe.",
     },
     "breakingswipe" => MoveData {
         num: 784,
@@ -17132,6 +18702,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to lower the foe(s) Attack by 1.",
+        description: "100% chance to lower the foe(s) Attack by 1.",
     },
     "branchpoke" => MoveData {
         num: 785,
@@ -17151,6 +18723,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "overdrive" => MoveData {
         num: 786,
@@ -17170,6 +18744,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect. Hits adjacent foes.",
+        description: "No additional effect. Hits adjacent foes.",
     },
     "appleacid" => MoveData {
         num: 787,
@@ -17199,6 +18775,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to lower the target's Sp. Def by 1.",
+        description: "100% chance to lower the target's Sp. Def by 1.",
     },
     "gravapple" => MoveData {
         num: 788,
@@ -17228,6 +18806,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Target: -1 Def. Gravity: 1.5x power.",
+        description: "Target: -1 Def. Gravity: 1.5x power.",
     },
     "spiritbreak" => MoveData {
         num: 789,
@@ -17257,6 +18837,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to lower the target's Sp. Atk by 1.",
+        description: "100% chance to lower the target's Sp. Atk by 1.",
     },
     "strangesteam" => MoveData {
         num: 790,
@@ -17286,6 +18868,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "20% chance to confuse the target.",
+        description: "20% chance to confuse the target.",
     },
     "lifedew" => MoveData {
         num: 791,
@@ -17305,6 +18889,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Heals the user and its allies by 1/4 their max HP.",
+        description: "Heals the user and its allies by 1/4 their max HP.",
     },
     "obstruct" => MoveData {
         num: 792,
@@ -17324,6 +18910,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Protects from damaging attacks. Contact: -2 Def.",
+        description: "Protects from damaging attacks. Contact: -2 Def.",
     },
     "falsesurrender" => MoveData {
         num: 793,
@@ -17343,6 +18931,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "This move does not check accuracy.",
+        description: "This move does not check accuracy.",
     },
     "meteorassault" => MoveData {
         num: 794,
@@ -17372,6 +18962,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Cannot be selected the turn after it's used.",
+        description: "Cannot be selected the turn after it's used.",
     },
     "eternabeam" => MoveData {
         num: 795,
@@ -17401,6 +18993,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User cannot move next turn.",
+        description: "User cannot move next turn.",
     },
     "steelbeam" => MoveData {
         num: 796,
@@ -17420,6 +19014,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User loses 50% max HP.",
+        description: "User loses 50% max HP.",
     },
     "expandingforce" => MoveData {
         num: 797,
@@ -17439,6 +19035,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Psychic Terrain: 1.5x power, hits foe(s).",
+        description: "Psychic Terrain: 1.5x power, hits foe(s).",
     },
     "steelroller" => MoveData {
         num: 798,
@@ -17458,6 +19056,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Fails if there is no terrain active. Ends the terrain.",
+        description: "Fails if there is no terrain active. Ends the terrain.",
     },
     "scaleshot" => MoveData {
         num: 799,
@@ -17477,6 +19077,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: Some((2, 5)),
+        short_desc: "Hits 2-5 times. User: -1 Def, +1 Spe after last hit.",
+        description: "Hits 2-5 times. User: -1 Def, +1 Spe after last hit.",
     },
     "meteorbeam" => MoveData {
         num: 800,
@@ -17496,6 +19098,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises user's Sp. Atk by 1 on turn 1. Hits turn 2.",
+        description: "Raises user's Sp. Atk by 1 on turn 1. Hits turn 2.",
     },
     "shellsidearm" => MoveData {
         num: 801,
@@ -17525,6 +19129,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "20% psn. Physical+contact if it would be stronger.",
+        description: "20% psn. Physical+contact if it would be stronger.",
     },
     "mistyexplosion" => MoveData {
         num: 802,
@@ -17544,6 +19150,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User faints. User on Misty Terrain: 1.5x power.",
+        description: "User faints. User on Misty Terrain: 1.5x power.",
     },
     "grassyglide" => MoveData {
         num: 803,
@@ -17563,6 +19171,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User on Grassy Terrain: +1 priority.",
+        description: "User on Grassy Terrain: +1 priority.",
     },
     "risingvoltage" => MoveData {
         num: 804,
@@ -17582,6 +19192,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "2x power if target is grounded in Electric Terrain.",
+        description: "2x power if target is grounded in Electric Terrain.",
     },
     "terrainpulse" => MoveData {
         num: 805,
@@ -17601,6 +19213,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User on terrain: power doubles, type varies.",
+        description: "User on terrain: power doubles, type varies.",
     },
     "skittersmack" => MoveData {
         num: 806,
@@ -17630,6 +19244,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to lower target's Sp. Atk by 1.",
+        description: "100% chance to lower target's Sp. Atk by 1.",
     },
     "burningjealousy" => MoveData {
         num: 807,
@@ -17659,6 +19275,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% burns a target that had a stat rise this turn.",
+        description: "100% burns a target that had a stat rise this turn.",
     },
     "lashout" => MoveData {
         num: 808,
@@ -17678,6 +19296,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "2x power if the user had a stat lowered this turn.",
+        description: "2x power if the user had a stat lowered this turn.",
     },
     "poltergeist" => MoveData {
         num: 809,
@@ -17697,6 +19317,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Fails if the target has no held item.",
+        description: "Fails if the target has no held item.",
     },
     "corrosivegas" => MoveData {
         num: 810,
@@ -17716,6 +19338,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Removes adjacent Pokemon's held items.",
+        description: "Removes adjacent Pokemon's held items.",
     },
     "coaching" => MoveData {
         num: 811,
@@ -17735,6 +19359,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises an ally's Attack and Defense by 1.",
+        description: "Raises an ally's Attack and Defense by 1.",
     },
     "flipturn" => MoveData {
         num: 812,
@@ -17754,6 +19380,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User switches out after damaging the target.",
+        description: "User switches out after damaging the target.",
     },
     "tripleaxel" => MoveData {
         num: 813,
@@ -17773,6 +19401,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hits 3 times. Each hit can miss, but power rises.",
+        description: "Hits 3 times. Each hit can miss, but power rises.",
     },
     "dualwingbeat" => MoveData {
         num: 814,
@@ -17792,6 +19422,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hits 2 times in one turn.",
+        description: "Hits 2 times in one turn.",
     },
     "scorchingsands" => MoveData {
         num: 815,
@@ -17821,6 +19453,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to burn the target. Thaws target.",
+        description: "30% chance to burn the target. Thaws target.",
     },
     "junglehealing" => MoveData {
         num: 816,
@@ -17840,6 +19474,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User and allies: healed 1/4 max HP, status cured.",
+        description: "User and allies: healed 1/4 max HP, status cured.",
     },
     "wickedblow" => MoveData {
         num: 817,
@@ -17859,6 +19495,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Always results in a critical hit.",
+        description: "Always results in a critical hit.",
     },
     "surgingstrikes" => MoveData {
         num: 818,
@@ -17878,6 +19516,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Always results in a critical hit. Hits 3 times.",
+        description: "Always results in a critical hit. Hits 3 times.",
     },
     "thundercage" => MoveData {
         num: 819,
@@ -17897,6 +19537,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Traps and damages the target for 4-5 turns.",
+        description: "Traps and damages the target for 4-5 turns.",
     },
     "dragonenergy" => MoveData {
         num: 820,
@@ -17916,6 +19558,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Less power as user's HP decreases. Hits foe(s).",
+        description: "Less power as user's HP decreases. Hits foe(s).",
     },
     "freezingglare" => MoveData {
         num: 821,
@@ -17945,6 +19589,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to freeze the target.",
+        description: "10% chance to freeze the target.",
     },
     "fierywrath" => MoveData {
         num: 822,
@@ -17974,6 +19620,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "20% chance to make the foe(s) flinch.",
+        description: "20% chance to make the foe(s) flinch.",
     },
     "thunderouskick" => MoveData {
         num: 823,
@@ -18003,6 +19651,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to lower the target's Defense by 1.",
+        description: "100% chance to lower the target's Defense by 1.",
     },
     "glaciallance" => MoveData {
         num: 824,
@@ -18022,6 +19672,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect. Hits adjacent foes.",
+        description: "No additional effect. Hits adjacent foes.",
     },
     "astralbarrage" => MoveData {
         num: 825,
@@ -18041,6 +19693,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect. Hits adjacent foes.",
+        description: "No additional effect. Hits adjacent foes.",
     },
     "eeriespell" => MoveData {
         num: 826,
@@ -18070,6 +19724,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Removes 3 PP from the target's last move.",
+        description: "Removes 3 PP from the target's last move.",
     },
     "direclaw" => MoveData {
         num: 827,
@@ -18099,6 +19755,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "50% chance to sleep, poison, or paralyze target.",
+        description: "50% chance to sleep, poison, or paralyze target.",
     },
     "psyshieldbash" => MoveData {
         num: 828,
@@ -18128,6 +19786,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to raise the user's Defense by 1.",
+        description: "100% chance to raise the user's Defense by 1.",
     },
     "powershift" => MoveData {
         num: 829,
@@ -18147,6 +19807,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Switches user's Atk and Def stats.",
+        description: "Switches user's Atk and Def stats.",
     },
     "stoneaxe" => MoveData {
         num: 830,
@@ -18176,6 +19838,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Sets Stealth Rock on the target's side.",
+        description: "Sets Stealth Rock on the target's side.",
     },
     "springtidestorm" => MoveData {
         num: 831,
@@ -18205,6 +19869,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to lower the foe(s) Attack by 1.",
+        description: "30% chance to lower the foe(s) Attack by 1.",
     },
     "mysticalpower" => MoveData {
         num: 832,
@@ -18234,6 +19900,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to raise the user's Sp. Atk by 1.",
+        description: "100% chance to raise the user's Sp. Atk by 1.",
     },
     "ragingfury" => MoveData {
         num: 833,
@@ -18263,6 +19931,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lasts 2-3 turns. Confuses the user afterwards.",
+        description: "Lasts 2-3 turns. Confuses the user afterwards.",
     },
     "wavecrash" => MoveData {
         num: 834,
@@ -18282,6 +19952,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: Some((33, 100)),
         drain: None,
         multihit: None,
+        short_desc: "Has 33% recoil.",
+        description: "Has 33% recoil.",
     },
     "chloroblast" => MoveData {
         num: 835,
@@ -18301,6 +19973,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User loses 50% max HP.",
+        description: "User loses 50% max HP.",
     },
     "mountaingale" => MoveData {
         num: 836,
@@ -18330,6 +20004,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to make the target flinch.",
+        description: "30% chance to make the target flinch.",
     },
     "victorydance" => MoveData {
         num: 837,
@@ -18349,6 +20025,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Attack, Defense, Speed by 1.",
+        description: "Raises the user's Attack, Defense, Speed by 1.",
     },
     "headlongrush" => MoveData {
         num: 838,
@@ -18378,6 +20056,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the user's Defense and Sp. Def by 1.",
+        description: "Lowers the user's Defense and Sp. Def by 1.",
     },
     "barbbarrage" => MoveData {
         num: 839,
@@ -18407,6 +20087,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power doubles if the target is poisoned. 50% psn.",
+        description: "Power doubles if the target is poisoned. 50% psn.",
     },
     "esperwing" => MoveData {
         num: 840,
@@ -18436,6 +20118,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "High critical hit ratio. 100% chance to raise the user's Speed by 1.",
+        description: "High critical hit ratio. 100% chance to raise the user's Speed by 1.",
     },
     "bittermalice" => MoveData {
         num: 841,
@@ -18465,6 +20149,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to lower the target's Attack by 1.",
+        description: "100% chance to lower the target's Attack by 1.",
     },
     "shelter" => MoveData {
         num: 842,
@@ -18484,6 +20170,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises the user's Defense by 2.",
+        description: "Raises the user's Defense by 2.",
     },
     "triplearrows" => MoveData {
         num: 843,
@@ -18522,6 +20210,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "High crit. Target: 50% -1 Defense, 30% flinch.",
+        description: "High crit. Target: 50% -1 Defense, 30% flinch.",
     },
     "infernalparade" => MoveData {
         num: 844,
@@ -18551,6 +20241,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Power doubles if the target has a status ailment.",
+        description: "Power doubles if the target has a status ailment.",
     },
     "ceaselessedge" => MoveData {
         num: 845,
@@ -18580,6 +20272,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Sets a layer of Spikes on the opposing side.",
+        description: "Sets a layer of Spikes on the opposing side.",
     },
     "bleakwindstorm" => MoveData {
         num: 846,
@@ -18609,6 +20303,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to lower the foe(s) Speed by 1.",
+        description: "30% chance to lower the foe(s) Speed by 1.",
     },
     "wildboltstorm" => MoveData {
         num: 847,
@@ -18638,6 +20334,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "20% chance to paralyze foe(s). Can't miss in rain.",
+        description: "20% chance to paralyze foe(s). Can't miss in rain.",
     },
     "sandsearstorm" => MoveData {
         num: 848,
@@ -18667,6 +20365,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "20% chance to burn foe(s). Can't miss in rain.",
+        description: "20% chance to burn foe(s). Can't miss in rain.",
     },
     "lunarblessing" => MoveData {
         num: 849,
@@ -18686,6 +20386,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User and allies: healed 1/4 max HP, status cured.",
+        description: "User and allies: healed 1/4 max HP, status cured.",
     },
     "takeheart" => MoveData {
         num: 850,
@@ -18705,6 +20407,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Cures user's status, raises Sp. Atk, Sp. Def by 1.",
+        description: "Cures user's status, raises Sp. Atk, Sp. Def by 1.",
     },
     "terablast" => MoveData {
         num: 851,
@@ -18724,6 +20428,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "If Terastallized: Phys. if Atk > SpA, type = Tera.",
+        description: "If Terastallized: Phys. if Atk > SpA, type = Tera.",
     },
     "silktrap" => MoveData {
         num: 852,
@@ -18743,6 +20449,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Protects from damaging attacks. Contact: -1 Spe.",
+        description: "Protects from damaging attacks. Contact: -1 Spe.",
     },
     "axekick" => MoveData {
         num: 853,
@@ -18772,6 +20480,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% confusion. User loses 50% max HP if miss.",
+        description: "30% confusion. User loses 50% max HP if miss.",
     },
     "lastrespects" => MoveData {
         num: 854,
@@ -18791,6 +20501,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "+50 power for each time a party member fainted.",
+        description: "+50 power for each time a party member fainted.",
     },
     "luminacrash" => MoveData {
         num: 855,
@@ -18820,6 +20532,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to lower the target's Sp. Def by 2.",
+        description: "100% chance to lower the target's Sp. Def by 2.",
     },
     "orderup" => MoveData {
         num: 856,
@@ -18839,6 +20553,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Curly|Droopy|Stretchy eaten: +1 Atk|Def|Spe.",
+        description: "Curly|Droopy|Stretchy eaten: +1 Atk|Def|Spe.",
     },
     "jetpunch" => MoveData {
         num: 857,
@@ -18858,6 +20574,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Usually goes first.",
+        description: "Usually goes first.",
     },
     "spicyextract" => MoveData {
         num: 858,
@@ -18877,6 +20595,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises target's Atk by 2 and lowers its Def by 2.",
+        description: "Raises target's Atk by 2 and lowers its Def by 2.",
     },
     "spinout" => MoveData {
         num: 859,
@@ -18906,6 +20626,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the user's Speed by 2.",
+        description: "Lowers the user's Speed by 2.",
     },
     "populationbomb" => MoveData {
         num: 860,
@@ -18925,6 +20647,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hits 10 times. Each hit can miss.",
+        description: "Hits 10 times. Each hit can miss.",
     },
     "icespinner" => MoveData {
         num: 861,
@@ -18944,6 +20668,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Ends the effects of terrain.",
+        description: "Ends the effects of terrain.",
     },
     "glaiverush" => MoveData {
         num: 862,
@@ -18973,6 +20699,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User takes sure-hit 2x damage until its next turn.",
+        description: "User takes sure-hit 2x damage until its next turn.",
     },
     "revivalblessing" => MoveData {
         num: 863,
@@ -18992,6 +20720,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Revives a fainted Pokemon to 50% HP.",
+        description: "Revives a fainted Pokemon to 50% HP.",
     },
     "saltcure" => MoveData {
         num: 864,
@@ -19021,6 +20751,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Deals 1/8 max HP each turn; 1/4 on Steel, Water.",
+        description: "Deals 1/8 max HP each turn; 1/4 on Steel, Water.",
     },
     "tripledive" => MoveData {
         num: 865,
@@ -19040,6 +20772,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hits 3 times.",
+        description: "Hits 3 times.",
     },
     "mortalspin" => MoveData {
         num: 866,
@@ -19069,6 +20803,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Poisons foes, frees user from hazards/partial trap.",
+        description: "Poisons foes, frees user from hazards/partial trap.",
     },
     "doodle" => MoveData {
         num: 867,
@@ -19088,6 +20824,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User and ally's Abilities become target's Ability.",
+        description: "User and ally's Abilities become target's Ability.",
     },
     "filletaway" => MoveData {
         num: 868,
@@ -19107,6 +20845,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "+2 Attack, Sp. Atk, Speed for 1/2 user's max HP.",
+        description: "+2 Attack, Sp. Atk, Speed for 1/2 user's max HP.",
     },
     "kowtowcleave" => MoveData {
         num: 869,
@@ -19126,6 +20866,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "This move does not check accuracy.",
+        description: "This move does not check accuracy.",
     },
     "flowertrick" => MoveData {
         num: 870,
@@ -19145,6 +20887,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Always results in a critical hit; no accuracy check.",
+        description: "Always results in a critical hit; no accuracy check.",
     },
     "torchsong" => MoveData {
         num: 871,
@@ -19174,6 +20918,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to raise the user's Sp. Atk by 1.",
+        description: "100% chance to raise the user's Sp. Atk by 1.",
     },
     "aquastep" => MoveData {
         num: 872,
@@ -19203,6 +20949,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to raise the user's Speed by 1.",
+        description: "100% chance to raise the user's Speed by 1.",
     },
     "ragingbull" => MoveData {
         num: 873,
@@ -19222,6 +20970,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Destroys screens. Type depends on user's form.",
+        description: "Destroys screens. Type depends on user's form.",
     },
     "makeitrain" => MoveData {
         num: 874,
@@ -19251,6 +21001,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the user's Sp. Atk by 1. Hits foe(s).",
+        description: "Lowers the user's Sp. Atk by 1. Hits foe(s).",
     },
     "psyblade" => MoveData {
         num: 875,
@@ -19270,6 +21022,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "During Electric Terrain: 1.5x power.",
+        description: "During Electric Terrain: 1.5x power.",
     },
     "hydrosteam" => MoveData {
         num: 876,
@@ -19289,6 +21043,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "During Sunny Day: 1.5x damage instead of half.",
+        description: "During Sunny Day: 1.5x damage instead of half.",
     },
     "ruination" => MoveData {
         num: 877,
@@ -19308,6 +21064,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Does damage equal to 1/2 target's current HP.",
+        description: "Does damage equal to 1/2 target's current HP.",
     },
     "collisioncourse" => MoveData {
         num: 878,
@@ -19327,6 +21085,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Deals 1.3333x damage with supereffective hits.",
+        description: "Deals 1.3333x damage with supereffective hits.",
     },
     "electrodrift" => MoveData {
         num: 879,
@@ -19346,6 +21106,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Deals 1.3333x damage with supereffective hits.",
+        description: "Deals 1.3333x damage with supereffective hits.",
     },
     "shedtail" => MoveData {
         num: 880,
@@ -19375,6 +21137,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User takes 1/2 its max HP to pass a substitute.",
+        description: "User takes 1/2 its max HP to pass a substitute.",
     },
     "chillyreception" => MoveData {
         num: 881,
@@ -19394,6 +21158,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Starts Snow. User switches out.",
+        description: "Starts Snow. User switches out.",
     },
     "tidyup" => MoveData {
         num: 882,
@@ -19413,6 +21179,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User +1 Atk, Spe. Clears all substitutes/hazards.",
+        description: "User +1 Atk, Spe. Clears all substitutes/hazards.",
     },
     "snowscape" => MoveData {
         num: 883,
@@ -19432,6 +21200,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "For 5 turns, snow falls. Ice: 1.5x Def.",
+        description: "For 5 turns, snow falls. Ice: 1.5x Def.",
     },
     "pounce" => MoveData {
         num: 884,
@@ -19461,6 +21231,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to lower the target's Speed by 1.",
+        description: "100% chance to lower the target's Speed by 1.",
     },
     "trailblaze" => MoveData {
         num: 885,
@@ -19490,6 +21262,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to raise the user's Speed by 1.",
+        description: "100% chance to raise the user's Speed by 1.",
     },
     "chillingwater" => MoveData {
         num: 886,
@@ -19519,6 +21293,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% chance to lower the target's Attack by 1.",
+        description: "100% chance to lower the target's Attack by 1.",
     },
     "hyperdrill" => MoveData {
         num: 887,
@@ -19538,6 +21314,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Bypasses protection without breaking it.",
+        description: "Bypasses protection without breaking it.",
     },
     "twinbeam" => MoveData {
         num: 888,
@@ -19557,6 +21335,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hits 2 times in one turn.",
+        description: "Hits 2 times in one turn.",
     },
     "ragefist" => MoveData {
         num: 889,
@@ -19576,6 +21356,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "+50 power for each time user was hit. Max 6 hits.",
+        description: "+50 power for each time user was hit. Max 6 hits.",
     },
     "armorcannon" => MoveData {
         num: 890,
@@ -19605,6 +21387,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Lowers the user's Defense and Sp. Def by 1.",
+        description: "Lowers the user's Defense and Sp. Def by 1.",
     },
     "bitterblade" => MoveData {
         num: 891,
@@ -19624,6 +21408,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: Some((1, 2)),
         multihit: None,
+        short_desc: "User recovers 50% of the damage dealt.",
+        description: "User recovers 50% of the damage dealt.",
     },
     "doubleshock" => MoveData {
         num: 892,
@@ -19653,6 +21439,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User's Electric type: typeless; must be Electric.",
+        description: "User's Electric type: typeless; must be Electric.",
     },
     "gigatonhammer" => MoveData {
         num: 893,
@@ -19672,6 +21460,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Cannot be selected the turn after it's used.",
+        description: "Cannot be selected the turn after it's used.",
     },
     "comeuppance" => MoveData {
         num: 894,
@@ -19691,6 +21481,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "If hit by an attack, returns 1.5x damage.",
+        description: "If hit by an attack, returns 1.5x damage.",
     },
     "aquacutter" => MoveData {
         num: 895,
@@ -19710,6 +21502,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "High critical hit ratio.",
+        description: "High critical hit ratio.",
     },
     "blazingtorque" => MoveData {
         num: 896,
@@ -19739,6 +21533,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to burn the target.",
+        description: "30% chance to burn the target.",
     },
     "wickedtorque" => MoveData {
         num: 897,
@@ -19768,6 +21564,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "10% chance to cause the target to fall asleep.",
+        description: "10% chance to cause the target to fall asleep.",
     },
     "noxioustorque" => MoveData {
         num: 898,
@@ -19797,6 +21595,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to poison the target.",
+        description: "30% chance to poison the target.",
     },
     "combattorque" => MoveData {
         num: 899,
@@ -19826,6 +21626,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to paralyze the target.",
+        description: "30% chance to paralyze the target.",
     },
     "magicaltorque" => MoveData {
         num: 900,
@@ -19855,6 +21657,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "30% chance to confuse the target.",
+        description: "30% chance to confuse the target.",
     },
     "bloodmoon" => MoveData {
         num: 901,
@@ -19874,6 +21678,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Cannot be selected the turn after it's used.",
+        description: "Cannot be selected the turn after it's used.",
     },
     "matchagotcha" => MoveData {
         num: 902,
@@ -19903,6 +21709,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: Some((1, 2)),
         multihit: None,
+        short_desc: "20% chance to burn. Recovers 50% dmg dealt.",
+        description: "20% chance to burn. Recovers 50% dmg dealt.",
     },
     "syrupbomb" => MoveData {
         num: 903,
@@ -19932,6 +21740,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Target's Speed is lowered by 1 stage for 3 turns.",
+        description: "Target's Speed is lowered by 1 stage for 3 turns.",
     },
     "ivycudgel" => MoveData {
         num: 904,
@@ -19951,6 +21761,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "High crit ratio. Type depends on user's form.",
+        description: "High crit ratio. Type depends on user's form.",
     },
     "electroshot" => MoveData {
         num: 905,
@@ -19970,6 +21782,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Raises user's Sp. Atk by 1 on turn 1. Hits turn 2.",
+        description: "Raises user's Sp. Atk by 1 on turn 1. Hits turn 2.",
     },
     "terastarstorm" => MoveData {
         num: 906,
@@ -19989,6 +21803,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Terapagos-Stellar: Stellar type, hits both foes.",
+        description: "Terapagos-Stellar: Stellar type, hits both foes.",
     },
     "ficklebeam" => MoveData {
         num: 907,
@@ -20008,6 +21824,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Has a 30% chance this move's power is doubled.",
+        description: "Has a 30% chance this move's power is doubled.",
     },
     "burningbulwark" => MoveData {
         num: 908,
@@ -20027,6 +21845,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Protects from damaging attacks. Contact: burn.",
+        description: "Protects from damaging attacks. Contact: burn.",
     },
     "thunderclap" => MoveData {
         num: 909,
@@ -20046,6 +21866,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Usually goes first. Fails if target is not attacking.",
+        description: "Usually goes first. Fails if target is not attacking.",
     },
     "mightycleave" => MoveData {
         num: 910,
@@ -20065,6 +21887,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Bypasses protection without breaking it.",
+        description: "Bypasses protection without breaking it.",
     },
     "tachyoncutter" => MoveData {
         num: 911,
@@ -20084,6 +21908,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Hits 2 times in one turn.",
+        description: "Hits 2 times in one turn.",
     },
     "hardpress" => MoveData {
         num: 912,
@@ -20103,6 +21929,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "More power the more HP the target has left.",
+        description: "More power the more HP the target has left.",
     },
     "dragoncheer" => MoveData {
         num: 913,
@@ -20122,6 +21950,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Ally: Crit ratio +1, or +2 if ally is Dragon type.",
+        description: "Ally: Crit ratio +1, or +2 if ally is Dragon type.",
     },
     "alluringvoice" => MoveData {
         num: 914,
@@ -20151,6 +21981,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% confuse target that had a stat rise this turn.",
+        description: "100% confuse target that had a stat rise this turn.",
     },
     "temperflare" => MoveData {
         num: 915,
@@ -20170,6 +22002,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "2x power if the user's last move failed.",
+        description: "2x power if the user's last move failed.",
     },
     "supercellslam" => MoveData {
         num: 916,
@@ -20189,6 +22023,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "User is hurt by 50% of its max HP if it misses.",
+        description: "User is hurt by 50% of its max HP if it misses.",
     },
     "psychicnoise" => MoveData {
         num: 917,
@@ -20218,6 +22054,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "For 2 turns, the target is prevented from healing.",
+        description: "For 2 turns, the target is prevented from healing.",
     },
     "upperhand" => MoveData {
         num: 918,
@@ -20247,6 +22085,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "100% flinch. Fails unless target using priority.",
+        description: "100% flinch. Fails unless target using priority.",
     },
     "malignantchain" => MoveData {
         num: 919,
@@ -20276,6 +22116,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "50% chance to badly poison the target.",
+        description: "50% chance to badly poison the target.",
     },
     "nihillight" => MoveData {
         num: 920,
@@ -20295,6 +22137,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "No additional effect.",
+        description: "No additional effect.",
     },
     "gmaxbefuddle" => MoveData {
         num: 1000,
@@ -20324,6 +22168,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Foes: sleep/poison/paralysis.",
+        description: "Base move affects power. Foes: sleep/poison/paralysis.",
     },
     "gmaxcannonade" => MoveData {
         num: 1000,
@@ -20353,6 +22199,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Foes: 1/6 damage for 4 turns.",
+        description: "Base move affects power. Foes: 1/6 damage for 4 turns.",
     },
     "gmaxcentiferno" => MoveData {
         num: 1000,
@@ -20382,6 +22230,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Traps/damages foes 4-5 turns.",
+        description: "Base move affects power. Traps/damages foes 4-5 turns.",
     },
     "gmaxchistrike" => MoveData {
         num: 1000,
@@ -20411,6 +22261,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Allies: +1 crit ratio.",
+        description: "Base move affects power. Allies: +1 crit ratio.",
     },
     "gmaxcuddle" => MoveData {
         num: 1000,
@@ -20440,6 +22292,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Infatuates opponents.",
+        description: "Base move affects power. Infatuates opponents.",
     },
     "gmaxdepletion" => MoveData {
         num: 1000,
@@ -20469,6 +22323,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Foes: last move -2 PP.",
+        description: "Base move affects power. Foes: last move -2 PP.",
     },
     "gmaxdrumsolo" => MoveData {
         num: 1000,
@@ -20488,6 +22344,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Ignores Abilities.",
+        description: "Base move affects power. Ignores Abilities.",
     },
     "gmaxfinale" => MoveData {
         num: 1000,
@@ -20517,6 +22375,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Heals user and allies 1/6.",
+        description: "Base move affects power. Heals user and allies 1/6.",
     },
     "gmaxfireball" => MoveData {
         num: 1000,
@@ -20536,6 +22396,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Ignores Abilities.",
+        description: "Base move affects power. Ignores Abilities.",
     },
     "gmaxfoamburst" => MoveData {
         num: 1000,
@@ -20565,6 +22427,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Foes: -2 Speed.",
+        description: "Base move affects power. Foes: -2 Speed.",
     },
     "gmaxgoldrush" => MoveData {
         num: 1000,
@@ -20594,6 +22458,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Confuses foes, pays out.",
+        description: "Base move affects power. Confuses foes, pays out.",
     },
     "gmaxgravitas" => MoveData {
         num: 1000,
@@ -20623,6 +22489,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Summons Gravity.",
+        description: "Base move affects power. Summons Gravity.",
     },
     "gmaxhydrosnipe" => MoveData {
         num: 1000,
@@ -20642,6 +22510,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Ignores Abilities.",
+        description: "Base move affects power. Ignores Abilities.",
     },
     "gmaxmalodor" => MoveData {
         num: 1000,
@@ -20671,6 +22541,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Poisons foes.",
+        description: "Base move affects power. Poisons foes.",
     },
     "gmaxmeltdown" => MoveData {
         num: 1000,
@@ -20700,6 +22572,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Foes: 3 turn Torment.",
+        description: "Base move affects power. Foes: 3 turn Torment.",
     },
     "gmaxoneblow" => MoveData {
         num: 1000,
@@ -20719,6 +22593,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Bypasses protection.",
+        description: "Base move affects power. Bypasses protection.",
     },
     "gmaxrapidflow" => MoveData {
         num: 1000,
@@ -20738,6 +22614,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Bypasses protection.",
+        description: "Base move affects power. Bypasses protection.",
     },
     "gmaxreplenish" => MoveData {
         num: 1000,
@@ -20767,6 +22645,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. 50% restore berries.",
+        description: "Base move affects power. 50% restore berries.",
     },
     "gmaxresonance" => MoveData {
         num: 1000,
@@ -20796,6 +22676,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Sets Aurora Veil.",
+        description: "Base move affects power. Sets Aurora Veil.",
     },
     "gmaxsandblast" => MoveData {
         num: 1000,
@@ -20825,6 +22707,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Foes: trapped 4-5 turns.",
+        description: "Base move affects power. Foes: trapped 4-5 turns.",
     },
     "gmaxsmite" => MoveData {
         num: 1000,
@@ -20854,6 +22738,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Confuses opponents.",
+        description: "Base move affects power. Confuses opponents.",
     },
     "gmaxsnooze" => MoveData {
         num: 1000,
@@ -20873,6 +22759,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Target: 50% Yawn.",
+        description: "Base move affects power. Target: 50% Yawn.",
     },
     "gmaxsteelsurge" => MoveData {
         num: 1000,
@@ -20902,6 +22790,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Sets Steel hazard.",
+        description: "Base move affects power. Sets Steel hazard.",
     },
     "gmaxstonesurge" => MoveData {
         num: 1000,
@@ -20931,6 +22821,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Sets Stealth Rock.",
+        description: "Base move affects power. Sets Stealth Rock.",
     },
     "gmaxstunshock" => MoveData {
         num: 1000,
@@ -20960,6 +22852,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Foes: paralyze/poison.",
+        description: "Base move affects power. Foes: paralyze/poison.",
     },
     "gmaxsweetness" => MoveData {
         num: 1000,
@@ -20989,6 +22883,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Cures user's party.",
+        description: "Base move affects power. Cures user's party.",
     },
     "gmaxtartness" => MoveData {
         num: 1000,
@@ -21018,6 +22914,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Foes: -1 evasiveness.",
+        description: "Base move affects power. Foes: -1 evasiveness.",
     },
     "gmaxterror" => MoveData {
         num: 1000,
@@ -21047,6 +22945,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Foes can't switch out.",
+        description: "Base move affects power. Foes can't switch out.",
     },
     "gmaxvinelash" => MoveData {
         num: 1000,
@@ -21076,6 +22976,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Foes: 1/6 dmg for 4 turns.",
+        description: "Base move affects power. Foes: 1/6 dmg for 4 turns.",
     },
     "gmaxvolcalith" => MoveData {
         num: 1000,
@@ -21105,6 +23007,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Foes: 1/6 dmg for 4 turns.",
+        description: "Base move affects power. Foes: 1/6 dmg for 4 turns.",
     },
     "gmaxvoltcrash" => MoveData {
         num: 1000,
@@ -21134,6 +23038,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Paralyzes opponents.",
+        description: "Base move affects power. Paralyzes opponents.",
     },
     "gmaxwildfire" => MoveData {
         num: 1000,
@@ -21163,6 +23069,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Foes: 1/6 dmg for 4 turns.",
+        description: "Base move affects power. Foes: 1/6 dmg for 4 turns.",
     },
     "gmaxwindrage" => MoveData {
         num: 1000,
@@ -21192,6 +23100,8 @@ pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {
         recoil: None,
         drain: None,
         multihit: None,
+        short_desc: "Base move affects power. Removes opponent screens.",
+        description: "Base move affects power. Removes opponent screens.",
     },
 };
 
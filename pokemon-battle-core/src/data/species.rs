@@ -1,4 +1,4 @@
-// AUTO-GENERATED by tools/extract_data.js
+// AUTO-GENERATED by `pokemon-battle-cli gen-data`. Do not edit by hand.
 use phf::phf_map;
 
 #[derive(Clone, Copy, Debug)]
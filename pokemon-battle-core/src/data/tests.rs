@@ -2,7 +2,8 @@ use super::abilities::ABILITIES;
 use super::items::ITEMS;
 use super::moves::MOVES;
 use super::species::POKEDEX;
-use super::types::{effectiveness_dual, Type};
+use super::types::Type;
+use crate::sim::type_chart::TypeChart;
 
 #[test]
 fn charizard_stats() {
@@ -63,8 +64,10 @@ fn fire_fang_has_two_secondaries() {
 
 #[test]
 fn type_effectiveness_ice_vs_dragon_flying() {
-    let effectiveness = effectiveness_dual(Type::Ice, Type::Dragon, Type::Flying);
+    let effectiveness = TypeChart::GEN6.effectiveness_dual(Type::Ice, Type::Dragon, Type::Flying);
     assert!((effectiveness - 4.0).abs() < f32::EPSILON);
+    let mono = TypeChart::GEN6.effectiveness_dual(Type::Ice, Type::Dragon, Type::Dragon);
+    assert!((mono - 2.0).abs() < f32::EPSILON);
 }
 
 #[test]
@@ -73,6 +76,16 @@ fn abilities_include_key_entries() {
     assert!(ABILITIES.contains_key("intimidate"));
 }
 
+#[test]
+fn common_entries_have_english_descriptions() {
+    let giga_drain = MOVES.get("gigadrain").expect("Giga Drain must be present");
+    assert_eq!(giga_drain.short_desc, "User recovers 50% of the damage dealt.");
+    assert!(!giga_drain.description.is_empty());
+    assert!(!ITEMS["leftovers"].short_desc.is_empty());
+    assert!(!ABILITIES["protosynthesis"].short_desc.is_empty());
+    assert!(MOVES.values().all(|m| !m.short_desc.is_empty()));
+}
+
 #[test]
 fn items_include_expected_keys() {
     assert!(ITEMS.contains_key("leftovers"));
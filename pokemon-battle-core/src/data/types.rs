@@ -1,5 +1,5 @@
-// AUTO-GENERATED by tools/extract_data.js
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+// AUTO-GENERATED by `pokemon-battle-cli gen-data`. Do not edit by hand.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Type {
     Normal,
     Fire,
@@ -19,8 +19,15 @@ pub enum Type {
     Dark,
     Steel,
     Fairy,
+    /// Showdown's `???`: neutral both ways (e.g. a pure Fire type after Burn Up).
+    Typeless,
+    /// Tera Stellar: neutral against every type outside of Terastallized targets.
+    Stellar,
 }
 
+/// Number of types in `EFFECTIVENESS`; `Typeless` and `Stellar` come after them.
+pub const CHART_TYPES: usize = 18;
+
 impl Type {
     pub fn as_index(&self) -> usize {
         *self as usize
@@ -28,13 +35,12 @@ impl Type {
 }
 
 pub fn effectiveness_against(attacking: Type, defending: Type) -> f32 {
+    if attacking.as_index() >= CHART_TYPES || defending.as_index() >= CHART_TYPES {
+        return 1.0;
+    }
     EFFECTIVENESS[attacking.as_index()][defending.as_index()]
 }
 
-pub fn effectiveness_dual(attacking: Type, type1: Type, type2: Type) -> f32 {
-    effectiveness_against(attacking, type1) * effectiveness_against(attacking, type2)
-}
-
 const EFFECTIVENESS: [[f32; 18]; 18] = [
     [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 0.5, 0.0, 1.0, 1.0, 0.5, 1.0],
     [1.0, 0.5, 0.5, 1.0, 2.0, 2.0, 1.0, 1.0, 1.0, 1.0, 1.0, 2.0, 0.5, 1.0, 0.5, 1.0, 2.0, 1.0],
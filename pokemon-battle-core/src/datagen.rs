@@ -0,0 +1,710 @@
+//! Renders the `data/*.rs` tables from a JSON dump of the Showdown dex.
+//!
+//! The dump is a single JSON object with the sections `pokedex`, `moves`,
+//! `abilities`, `items`, `typechart` and `learnsets`, each shaped like the
+//! corresponding export in `pokemon-showdown/data/*.ts` (see `tools/dump_dex.js`).
+//!
+//! This module only depends on `std` and `serde_json` so that `build.rs` can
+//! reuse it to verify that the committed tables are fresh.
+
+use serde_json::{Map, Value};
+
+/// Header written at the top of every generated table.
+pub const GENERATED_HEADER: &str = "// AUTO-GENERATED by `pokemon-battle-cli gen-data`. Do not edit by hand.";
+
+/// Dump sections required to render the tables.
+pub const DUMP_SECTIONS: [&str; 6] = ["pokedex", "moves", "abilities", "items", "typechart", "learnsets"];
+
+/// Type order used for the `Type` enum and the effectiveness matrix.
+const TYPE_ORDER: [&str; 18] = [
+    "normal", "fire", "water", "electric", "grass", "ice", "fighting", "poison", "ground", "flying",
+    "psychic", "bug", "rock", "ghost", "dragon", "dark", "steel", "fairy",
+];
+
+/// A rendered table, named after its module in `src/data`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GeneratedFile {
+    pub module: &'static str,
+    pub contents: String,
+}
+
+impl GeneratedFile {
+    /// File name relative to `src/data`.
+    pub fn file_name(&self) -> String {
+        format!("{}.rs", self.module)
+    }
+}
+
+/// Render every table from a dex dump.
+pub fn render_all(dump: &Value) -> Result<Vec<GeneratedFile>, String> {
+    let missing: Vec<&str> = DUMP_SECTIONS
+        .iter()
+        .copied()
+        .filter(|section| !dump.get(*section).is_some_and(Value::is_object))
+        .collect();
+    if !missing.is_empty() {
+        return Err(format!("dex dump is missing sections: {}", missing.join(", ")));
+    }
+    let section = |name: &str| dump[name].as_object().expect("checked above");
+    Ok(vec![
+        GeneratedFile {
+            module: "species",
+            contents: render_species(section("pokedex")),
+        },
+        GeneratedFile {
+            module: "moves",
+            contents: render_moves(section("moves")),
+        },
+        GeneratedFile {
+            module: "abilities",
+            contents: render_abilities(section("abilities")),
+        },
+        GeneratedFile {
+            module: "items",
+            contents: render_items(section("items")),
+        },
+        GeneratedFile {
+            module: "types",
+            contents: render_types(section("typechart")),
+        },
+        GeneratedFile {
+            module: "learnsets",
+            contents: render_learnsets(section("learnsets")),
+        },
+    ])
+}
+
+fn rust_string(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn fmt_float(value: f64) -> String {
+    if value.is_nan() {
+        return "0.0".to_string();
+    }
+    if value.fract() == 0.0 {
+        format!("{}.0", value as i64)
+    } else {
+        value.to_string()
+    }
+}
+
+fn fmt_number(value: &Value) -> String {
+    match value.as_i64() {
+        Some(int) => int.to_string(),
+        None => value.as_f64().map(|f| f.to_string()).unwrap_or_else(|| "0".to_string()),
+    }
+}
+
+/// JS truthiness for the handful of value kinds found in the dex.
+fn truthy(value: Option<&Value>) -> bool {
+    match value {
+        None | Some(Value::Null) => false,
+        Some(Value::Bool(b)) => *b,
+        Some(Value::Number(n)) => n.as_f64().is_some_and(|f| f != 0.0),
+        Some(Value::String(s)) => !s.is_empty(),
+        Some(_) => true,
+    }
+}
+
+fn str_field<'a>(entry: &'a Value, key: &str) -> Option<&'a str> {
+    entry.get(key).and_then(Value::as_str).filter(|s| !s.is_empty())
+}
+
+fn num_field(entry: &Value, key: &str) -> String {
+    entry
+        .get(key)
+        .filter(|v| v.is_number())
+        .map(fmt_number)
+        .unwrap_or_else(|| "0".to_string())
+}
+
+fn opt_number(entry: &Value, key: &str) -> String {
+    match entry.get(key) {
+        Some(value) if value.is_number() => format!("Some({})", fmt_number(value)),
+        _ => "None".to_string(),
+    }
+}
+
+fn opt_str(value: Option<&str>) -> String {
+    match value {
+        Some(s) => format!("Some(\"{}\")", rust_string(s)),
+        None => "None".to_string(),
+    }
+}
+
+/// Entries sorted the way the tables are laid out: by dex number, then id.
+fn sorted_entries(section: &Map<String, Value>) -> Vec<(&String, &Value)> {
+    let mut entries: Vec<(&String, &Value)> = section.iter().collect();
+    entries.sort_by(|a, b| {
+        let a_num = a.1.get("num").and_then(Value::as_f64).unwrap_or(0.0);
+        let b_num = b.1.get("num").and_then(Value::as_f64).unwrap_or(0.0);
+        a_num.total_cmp(&b_num).then_with(|| a.0.cmp(b.0))
+    });
+    entries
+}
+
+fn finish(lines: Vec<String>) -> String {
+    let mut out = lines.join("\n");
+    out.push('\n');
+    out
+}
+
+fn render_species(pokedex: &Map<String, Value>) -> String {
+    let mut lines: Vec<String> = [
+        GENERATED_HEADER,
+        "use phf::phf_map;",
+        "",
+        "#[derive(Clone, Copy, Debug)]",
+        "pub struct BaseStats {",
+        "    pub hp: u8,",
+        "    pub atk: u8,",
+        "    pub def: u8,",
+        "    pub spa: u8,",
+        "    pub spd: u8,",
+        "    pub spe: u8,",
+        "}",
+        "",
+        "#[derive(Clone, Copy, Debug)]",
+        "pub struct AbilitySlots {",
+        "    pub primary: Option<&'static str>,",
+        "    pub secondary: Option<&'static str>,",
+        "    pub hidden: Option<&'static str>,",
+        "}",
+        "",
+        "#[derive(Clone, Copy, Debug)]",
+        "pub struct SpeciesData {",
+        "    pub num: i16,",
+        "    pub name: &'static str,",
+        "    pub base_stats: BaseStats,",
+        "    pub types: [&'static str; 2],",
+        "    pub abilities: AbilitySlots,",
+        "    pub weight_kg: f32,",
+        "}",
+        "",
+        "pub static POKEDEX: phf::Map<&'static str, SpeciesData> = phf_map! {",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+    for (id, entry) in sorted_entries(pokedex) {
+        let stats = entry.get("baseStats").cloned().unwrap_or(Value::Null);
+        let types = entry.get("types").and_then(Value::as_array);
+        let type_at = |idx: usize| {
+            types
+                .and_then(|t| t.get(idx))
+                .and_then(Value::as_str)
+                .unwrap_or("")
+        };
+        let abilities = entry.get("abilities").cloned().unwrap_or(Value::Null);
+        let name = entry.get("name").and_then(Value::as_str).unwrap_or(id);
+        let weight = entry.get("weightkg").and_then(Value::as_f64).unwrap_or(0.0);
+        lines.push(format!("    \"{id}\" => SpeciesData {{"));
+        lines.push(format!("        num: {},", num_field(entry, "num")));
+        lines.push(format!("        name: \"{}\",", rust_string(name)));
+        lines.push("        base_stats: BaseStats {".to_string());
+        for stat in ["hp", "atk", "def", "spa", "spd", "spe"] {
+            lines.push(format!("            {stat}: {},", num_field(&stats, stat)));
+        }
+        lines.push("        },".to_string());
+        lines.push(format!(
+            "        types: [\"{}\", \"{}\"],",
+            rust_string(type_at(0)),
+            rust_string(type_at(1))
+        ));
+        lines.push("        abilities: AbilitySlots {".to_string());
+        lines.push(format!("            primary: {},", opt_str(str_field(&abilities, "0"))));
+        lines.push(format!("            secondary: {},", opt_str(str_field(&abilities, "1"))));
+        lines.push(format!("            hidden: {},", opt_str(str_field(&abilities, "H"))));
+        lines.push("        },".to_string());
+        lines.push(format!("        weight_kg: {},", fmt_float(weight)));
+        lines.push("    },".to_string());
+    }
+    lines.push("};".to_string());
+    finish(lines)
+}
+
+fn render_secondary_effect(effect: &Value, chance: f64) -> Vec<String> {
+    let text = |key: &str| opt_str(str_field(effect, key));
+    let mut boosts: Vec<(&String, &Value)> = effect
+        .get("boosts")
+        .and_then(Value::as_object)
+        .map(|b| b.iter().collect())
+        .unwrap_or_default();
+    boosts.sort_by(|a, b| a.0.cmp(b.0));
+    let mut lines = vec!["SecondaryEffect {".to_string()];
+    lines.push(format!("            chance: {},", chance.round() as i64));
+    lines.push(format!("            status: {},", text("status")));
+    lines.push(format!("            volatile_status: {},", text("volatileStatus")));
+    if boosts.is_empty() {
+        lines.push("            boosts: &[],".to_string());
+    } else {
+        let rendered: Vec<String> = boosts
+            .iter()
+            .map(|(stat, amount)| format!("(\"{}\", {})", rust_string(stat), fmt_number(amount)))
+            .collect();
+        lines.push(format!("            boosts: &[{}],", rendered.join(", ")));
+    }
+    lines.push(format!("            side_condition: {},", text("sideCondition")));
+    lines.push(format!("            weather: {},", text("weather")));
+    lines.push(format!("            terrain: {},", text("terrain")));
+    lines.push("        }".to_string());
+    lines
+}
+
+/// Chance of an effect object: its `chance`, or 100 when present without one.
+fn effect_chance(effect: Option<&Value>) -> Option<f64> {
+    if !truthy(effect) {
+        return None;
+    }
+    let effect = effect?;
+    Some(effect.get("chance").and_then(Value::as_f64).unwrap_or(100.0))
+}
+
+fn pair(value: Option<&Value>) -> Option<(String, String)> {
+    let array = value?.as_array()?;
+    if array.len() < 2 {
+        return None;
+    }
+    Some((fmt_number(&array[0]), fmt_number(&array[1])))
+}
+
+fn multihit(entry: &Value) -> Option<(String, String)> {
+    let raw = entry.get("multihit").filter(|v| truthy(Some(v))).or_else(|| entry.get("multiHit"))?;
+    if let Some(array) = raw.as_array() {
+        return pair(Some(raw)).filter(|_| array.len() >= 2);
+    }
+    let obj = raw.as_object()?;
+    let min = obj.get("minHits").or_else(|| obj.get("min")).map(fmt_number).unwrap_or_else(|| "1".to_string());
+    let max = obj
+        .get("maxHits")
+        .or_else(|| obj.get("max"))
+        .or_else(|| obj.get("minHits"))
+        .map(fmt_number)
+        .unwrap_or_else(|| "1".to_string());
+    Some((min, max))
+}
+
+fn render_moves(moves: &Map<String, Value>) -> String {
+    let mut lines: Vec<String> = [
+        GENERATED_HEADER,
+        "use phf::phf_map;",
+        "",
+        "#[derive(Clone, Copy, Debug, PartialEq, Eq)]",
+        "pub enum MoveCategory {",
+        "    Physical,",
+        "    Special,",
+        "    Status,",
+        "}",
+        "",
+        "#[derive(Clone, Copy, Debug)]",
+        "pub struct SecondaryEffect {",
+        "    pub chance: u8,",
+        "    pub status: Option<&'static str>,",
+        "    pub volatile_status: Option<&'static str>,",
+        "    pub boosts: &'static [(&'static str, i8)],",
+        "    pub side_condition: Option<&'static str>,",
+        "    pub weather: Option<&'static str>,",
+        "    pub terrain: Option<&'static str>,",
+        "}",
+        "",
+        "#[derive(Clone, Copy, Debug)]",
+        "pub struct MoveData {",
+        "    pub num: i16,",
+        "    pub name: &'static str,",
+        "    pub move_type: &'static str,",
+        "    pub category: MoveCategory,",
+        "    pub base_power: Option<u16>,",
+        "    pub accuracy: Option<f32>,",
+        "    pub pp: Option<u8>,",
+        "    pub priority: i8,",
+        "    pub target: &'static str,",
+        "    pub flags: &'static [&'static str],",
+        "    pub secondary: Option<SecondaryEffect>,",
+        "    pub secondaries: &'static [SecondaryEffect],",
+        "    pub self_effect: Option<SecondaryEffect>,",
+        "    pub crit_ratio: Option<u8>,",
+        "    pub recoil: Option<(u8, u8)>,",
+        "    pub drain: Option<(u8, u8)>,",
+        "    pub multihit: Option<(u8, u8)>,",
+        "    pub short_desc: &'static str,",
+        "    pub description: &'static str,",
+        "}",
+        "",
+        "pub static MOVES: phf::Map<&'static str, MoveData> = phf_map! {",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+    for (id, entry) in sorted_entries(moves) {
+        let raw_category = str_field(entry, "category").unwrap_or("Status");
+        let mut category = raw_category[..1].to_uppercase();
+        category.push_str(&raw_category[1..].to_lowercase());
+        let name = str_field(entry, "name").unwrap_or(id);
+        let accuracy = match entry.get("accuracy") {
+            Some(Value::Bool(true)) => Some(100.0),
+            Some(Value::Bool(false)) => Some(0.0),
+            Some(value) => value.as_f64(),
+            None => None,
+        };
+        let mut flags: Vec<&String> = entry
+            .get("flags")
+            .and_then(Value::as_object)
+            .map(|f| f.keys().collect())
+            .unwrap_or_default();
+        flags.sort();
+
+        lines.push(format!("    \"{id}\" => MoveData {{"));
+        lines.push(format!("        num: {},", num_field(entry, "num")));
+        lines.push(format!("        name: \"{}\",", rust_string(name)));
+        lines.push(format!(
+            "        move_type: \"{}\",",
+            rust_string(str_field(entry, "type").unwrap_or("Normal"))
+        ));
+        lines.push(format!("        category: MoveCategory::{category},"));
+        lines.push(format!("        base_power: {},", opt_number(entry, "basePower")));
+        match accuracy {
+            Some(acc) => lines.push(format!("        accuracy: Some({}),", fmt_float(acc))),
+            None => lines.push("        accuracy: None,".to_string()),
+        }
+        lines.push(format!("        pp: {},", opt_number(entry, "pp")));
+        lines.push(format!("        priority: {},", num_field(entry, "priority")));
+        lines.push(format!(
+            "        target: \"{}\",",
+            rust_string(str_field(entry, "target").unwrap_or("normal"))
+        ));
+        if flags.is_empty() {
+            lines.push("        flags: &[],".to_string());
+        } else {
+            let rendered: Vec<String> = flags.iter().map(|f| format!("\"{}\"", rust_string(f))).collect();
+            lines.push(format!("        flags: &[{}],", rendered.join(", ")));
+        }
+        match effect_chance(entry.get("secondary")) {
+            Some(chance) => {
+                lines.push("        secondary: Some(".to_string());
+                for line in render_secondary_effect(&entry["secondary"], chance) {
+                    lines.push(format!("        {line}"));
+                }
+                lines.push("        ),".to_string());
+            }
+            None => lines.push("        secondary: None,".to_string()),
+        }
+        let secondaries = entry.get("secondaries").and_then(Value::as_array).filter(|s| !s.is_empty());
+        match secondaries {
+            Some(secondaries) => {
+                lines.push("        secondaries: &[".to_string());
+                for effect in secondaries {
+                    let chance = effect_chance(Some(effect)).unwrap_or(0.0);
+                    let rendered = render_secondary_effect(effect, chance);
+                    let last = rendered.len() - 1;
+                    for (idx, line) in rendered.into_iter().enumerate() {
+                        let suffix = if idx == last { "," } else { "" };
+                        lines.push(format!("            {line}{suffix}"));
+                    }
+                }
+                lines.push("        ],".to_string());
+            }
+            None => lines.push("        secondaries: &[],".to_string()),
+        }
+        match effect_chance(entry.get("self")) {
+            Some(chance) => {
+                lines.push("        self_effect: Some(".to_string());
+                for line in render_secondary_effect(&entry["self"], chance) {
+                    lines.push(format!("        {line}"));
+                }
+                lines.push("        ),".to_string());
+            }
+            None => lines.push("        self_effect: None,".to_string()),
+        }
+        lines.push(format!("        crit_ratio: {},", opt_number(entry, "critRatio")));
+        for (key, value) in [
+            ("recoil", pair(entry.get("recoil"))),
+            ("drain", pair(entry.get("drain"))),
+            ("multihit", multihit(entry)),
+        ] {
+            match value {
+                Some((a, b)) => lines.push(format!("        {key}: Some(({a}, {b})),")),
+                None => lines.push(format!("        {key}: None,")),
+            }
+        }
+        let (short_desc, description) = descriptions(entry);
+        lines.push(format!("        short_desc: \"{}\",", rust_string(short_desc)));
+        lines.push(format!("        description: \"{}\",", rust_string(description)));
+        lines.push("    },".to_string());
+    }
+    lines.push("};".to_string());
+    lines.push(String::new());
+    lines.push("pub fn normalize_move_name(name: &str) -> String {".to_string());
+    lines.push(
+        "    name.to_ascii_lowercase().chars().filter(|c| c.is_ascii_alphanumeric()).collect()".to_string(),
+    );
+    lines.push("}".to_string());
+    lines.push(String::new());
+    lines.push("pub fn get_move(name_or_id: &str) -> Option<&'static MoveData> {".to_string());
+    lines.push("    let id = normalize_move_name(name_or_id);".to_string());
+    lines.push("    MOVES.get(id.as_str())".to_string());
+    lines.push("}".to_string());
+    finish(lines)
+}
+
+/// `(shortDesc, desc)` from `data/text/*.ts`, each falling back to the other.
+fn descriptions(entry: &Value) -> (&str, &str) {
+    let short_desc = str_field(entry, "shortDesc");
+    let desc = str_field(entry, "desc");
+    (short_desc.or(desc).unwrap_or(""), desc.or(short_desc).unwrap_or(""))
+}
+
+fn render_described(entries: &Map<String, Value>, type_name: &str, map_name: &str) -> String {
+    let mut lines: Vec<String> = vec![
+        GENERATED_HEADER.to_string(),
+        "use phf::phf_map;".to_string(),
+        String::new(),
+        "#[derive(Clone, Copy, Debug)]".to_string(),
+        format!("pub struct {type_name} {{"),
+        "    pub num: i16,".to_string(),
+        "    pub name: &'static str,".to_string(),
+        "    pub short_desc: &'static str,".to_string(),
+        "    pub description: &'static str,".to_string(),
+        "}".to_string(),
+        String::new(),
+        format!("pub static {map_name}: phf::Map<&'static str, {type_name}> = phf_map! {{"),
+    ];
+    for (id, entry) in sorted_entries(entries) {
+        let (short_desc, description) = descriptions(entry);
+        lines.push(format!("    \"{id}\" => {type_name} {{"));
+        lines.push(format!("        num: {},", num_field(entry, "num")));
+        lines.push(format!("        name: \"{}\",", rust_string(str_field(entry, "name").unwrap_or(id))));
+        lines.push(format!("        short_desc: \"{}\",", rust_string(short_desc)));
+        lines.push(format!("        description: \"{}\",", rust_string(description)));
+        lines.push("    },".to_string());
+    }
+    lines.push("};".to_string());
+    finish(lines)
+}
+
+fn render_abilities(abilities: &Map<String, Value>) -> String {
+    render_described(abilities, "AbilityData", "ABILITIES")
+}
+
+fn render_items(items: &Map<String, Value>) -> String {
+    render_described(items, "ItemData", "ITEMS")
+}
+
+fn render_types(type_chart: &Map<String, Value>) -> String {
+    let index_of = |name: &str| TYPE_ORDER.iter().position(|t| t.eq_ignore_ascii_case(name));
+    let mut matrix = [[1.0f64; 18]; 18];
+    for (def_type, data) in type_chart {
+        let Some(def_idx) = index_of(def_type) else {
+            continue;
+        };
+        let Some(taken) = data.get("damageTaken").and_then(Value::as_object) else {
+            continue;
+        };
+        for (att_type, value) in taken {
+            let Some(att_idx) = index_of(att_type) else {
+                continue;
+            };
+            matrix[att_idx][def_idx] = match value.as_i64() {
+                Some(1) => 2.0,
+                Some(2) => 0.5,
+                Some(3) => 0.0,
+                _ => 1.0,
+            };
+        }
+    }
+
+    let mut lines = vec![
+        GENERATED_HEADER.to_string(),
+        "#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]".to_string(),
+        "pub enum Type {".to_string(),
+    ];
+    for name in TYPE_ORDER {
+        let mut variant = name[..1].to_uppercase();
+        variant.push_str(&name[1..]);
+        lines.push(format!("    {variant},"));
+    }
+    lines.extend(
+        [
+            "    /// Showdown's `???`: neutral both ways (e.g. a pure Fire type after Burn Up).",
+            "    Typeless,",
+            "    /// Tera Stellar: neutral against every type outside of Terastallized targets.",
+            "    Stellar,",
+            "}",
+            "",
+            "/// Number of types in `EFFECTIVENESS`; `Typeless` and `Stellar` come after them.",
+            "pub const CHART_TYPES: usize = 18;",
+            "",
+            "impl Type {",
+            "    pub fn as_index(&self) -> usize {",
+            "        *self as usize",
+            "    }",
+            "}",
+            "",
+            "pub fn effectiveness_against(attacking: Type, defending: Type) -> f32 {",
+            "    if attacking.as_index() >= CHART_TYPES || defending.as_index() >= CHART_TYPES {",
+            "        return 1.0;",
+            "    }",
+            "    EFFECTIVENESS[attacking.as_index()][defending.as_index()]",
+            "}",
+            "",
+            "const EFFECTIVENESS: [[f32; 18]; 18] = [",
+        ]
+        .iter()
+        .map(|s| s.to_string()),
+    );
+    for row in matrix {
+        let values: Vec<String> = row.iter().map(|v| fmt_float(*v)).collect();
+        lines.push(format!("    [{}],", values.join(", ")));
+    }
+    lines.push("];".to_string());
+    finish(lines)
+}
+
+/// Learnable move ids per species. Entries without a `learnset` (formes that
+/// share the base species' learnset) are left out.
+fn render_learnsets(learnsets: &Map<String, Value>) -> String {
+    let mut lines = vec![
+        GENERATED_HEADER.to_string(),
+        "use phf::phf_map;".to_string(),
+        String::new(),
+        "pub static LEARNSETS: phf::Map<&'static str, &'static [&'static str]> = phf_map! {".to_string(),
+    ];
+    let mut species: Vec<(&String, &Map<String, Value>)> = learnsets
+        .iter()
+        .filter_map(|(id, entry)| entry.get("learnset").and_then(Value::as_object).map(|moves| (id, moves)))
+        .collect();
+    species.sort_by(|a, b| a.0.cmp(b.0));
+    for (id, moves) in species {
+        let mut moves: Vec<&String> = moves.keys().collect();
+        moves.sort();
+        let moves: Vec<String> = moves.iter().map(|m| format!("\"{}\"", rust_string(m))).collect();
+        lines.push(format!("    \"{id}\" => &[{}],", moves.join(", ")));
+    }
+    lines.push("};".to_string());
+    finish(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn minimal_dump() -> Value {
+        json!({
+            "pokedex": {
+                "pikachu": {
+                    "num": 25,
+                    "name": "Pikachu",
+                    "types": ["Electric"],
+                    "baseStats": {"hp": 35, "atk": 55, "def": 40, "spa": 50, "spd": 50, "spe": 90},
+                    "abilities": {"0": "Static", "H": "Lightning Rod"},
+                    "weightkg": 6
+                }
+            },
+            "moves": {
+                "thunderbolt": {
+                    "num": 85,
+                    "name": "Thunderbolt",
+                    "type": "Electric",
+                    "category": "Special",
+                    "basePower": 90,
+                    "accuracy": 100,
+                    "pp": 15,
+                    "priority": 0,
+                    "target": "normal",
+                    "flags": {"protect": 1, "mirror": 1, "metronome": 1},
+                    "secondary": {"chance": 10, "status": "par"},
+                    "shortDesc": "10% chance to paralyze the target.",
+                    "desc": "Has a 10% chance to paralyze the target."
+                },
+                "doublekick": {
+                    "num": 24,
+                    "name": "Double Kick",
+                    "type": "Fighting",
+                    "category": "Physical",
+                    "basePower": 30,
+                    "accuracy": true,
+                    "pp": 30,
+                    "multihit": [2, 2],
+                    "flags": {}
+                }
+            },
+            "abilities": {"static": {"num": 9, "name": "Static", "shortDesc": "30% chance to paralyze."}},
+            "items": {"leftovers": {"num": 234, "name": "Leftovers"}},
+            "typechart": {
+                "ground": {"damageTaken": {"Electric": 3, "Water": 1, "par": 0}}
+            },
+            "learnsets": {
+                "pikachu": {"learnset": {"thunderbolt": ["9M"], "doublekick": ["9E"]}},
+                "pikachugmax": {"eventData": []}
+            }
+        })
+    }
+
+    fn rendered(module: &str) -> String {
+        render_all(&minimal_dump())
+            .expect("dump renders")
+            .into_iter()
+            .find(|file| file.module == module)
+            .expect("module rendered")
+            .contents
+    }
+
+    #[test]
+    fn missing_sections_are_reported() {
+        let err = render_all(&json!({"pokedex": {}})).unwrap_err();
+        assert!(err.contains("moves"));
+        assert!(err.contains("typechart"));
+        assert!(err.contains("learnsets"));
+    }
+
+    #[test]
+    fn species_render_matches_table_layout() {
+        let species = rendered("species");
+        assert!(species.starts_with(GENERATED_HEADER));
+        assert!(species.contains("        types: [\"Electric\", \"\"],\n"));
+        assert!(species.contains("            hidden: Some(\"Lightning Rod\"),\n"));
+        assert!(species.contains("        weight_kg: 6.0,\n"));
+    }
+
+    #[test]
+    fn moves_are_sorted_by_number_and_keep_secondaries() {
+        let moves = rendered("moves");
+        let kick = moves.find("\"doublekick\"").expect("double kick");
+        let bolt = moves.find("\"thunderbolt\"").expect("thunderbolt");
+        assert!(kick < bolt);
+        assert!(moves.contains("        accuracy: Some(100.0),\n"));
+        assert!(moves.contains("        flags: &[\"metronome\", \"mirror\", \"protect\"],\n"));
+        assert!(moves.contains("                    status: Some(\"par\"),\n"));
+        assert!(moves.contains("        multihit: Some((2, 2)),\n"));
+    }
+
+    #[test]
+    fn descriptions_fall_back_to_short_desc() {
+        assert!(rendered("abilities").contains("description: \"30% chance to paralyze.\","));
+        assert!(rendered("abilities").contains("short_desc: \"30% chance to paralyze.\","));
+        assert!(rendered("items").contains("description: \"\","));
+        let moves = rendered("moves");
+        assert!(moves.contains("        short_desc: \"10% chance to paralyze the target.\",\n"));
+        assert!(moves.contains("        description: \"Has a 10% chance to paralyze the target.\",\n"));
+    }
+
+    #[test]
+    fn learnsets_list_sorted_move_ids() {
+        let learnsets = rendered("learnsets");
+        assert!(learnsets.contains("    \"pikachu\" => &[\"doublekick\", \"thunderbolt\"],\n"));
+        assert!(!learnsets.contains("pikachugmax"));
+    }
+
+    #[test]
+    fn type_chart_maps_damage_taken_codes() {
+        let types = rendered("types");
+        // Row 3 is Electric attacking; column 8 is Ground.
+        let electric_row = types
+            .lines()
+            .filter(|line| line.starts_with("    ["))
+            .nth(3)
+            .expect("electric row");
+        assert_eq!(electric_row.split(", ").nth(8), Some("0.0"));
+    }
+}
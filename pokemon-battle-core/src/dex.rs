@@ -0,0 +1,700 @@
+//! Layered dex: the built-in `data/*` tables plus runtime data mods.
+//!
+//! A data mod is a JSON file shaped like the dex dump consumed by
+//! [`crate::datagen`] (sections `pokedex`, `moves`, `items`, `abilities`,
+//! entries keyed by id and using Showdown field names). Every section is
+//! optional. An entry whose id is not known yet adds a new species/move/etc.
+//! and must be complete; an entry with `"inherit": true` starts from the
+//! current definition and only overrides the fields it lists, e.g.
+//!
+//! ```json
+//! { "pokedex": { "garchomp": { "inherit": true, "baseStats": { "spe": 110 } } } }
+//! ```
+//!
+//! A `learnsets` section (shaped like Showdown's `data/learnsets.ts`,
+//! `{ "garchomp": { "learnset": { "earthquake": ["9M"] } } }`) replaces the
+//! compiled learnset of each species it lists.
+//!
+//! Mods are applied in load order, so a later mod can override an earlier one.
+//! Entries loaded at runtime are leaked to get the same `'static` lifetime as
+//! the compiled tables; load mods once per process, not per battle.
+
+use crate::data::abilities::{AbilityData, ABILITIES};
+use crate::data::items::{ItemData, ITEMS};
+use crate::data::learnsets::LEARNSETS;
+use crate::data::moves::{normalize_move_name, MoveCategory, MoveData, SecondaryEffect, MOVES};
+use crate::data::species::{AbilitySlots, BaseStats, SpeciesData, POKEDEX};
+use crate::sim::pokemon::parse_type;
+use anyhow::{anyhow, bail, Context, Result};
+use once_cell::sync::Lazy;
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+static BUILTIN: Lazy<Arc<Dex>> = Lazy::new(|| Arc::new(Dex::default()));
+
+/// Species/move/item/ability lookup with optional runtime overrides.
+#[derive(Clone, Debug, Default)]
+pub struct Dex {
+    species: HashMap<String, &'static SpeciesData>,
+    moves: HashMap<String, &'static MoveData>,
+    items: HashMap<String, &'static ItemData>,
+    abilities: HashMap<String, &'static AbilityData>,
+    learnsets: HashMap<String, HashSet<String>>,
+    mods: Vec<String>,
+}
+
+impl Dex {
+    /// Shared handle to the dex without any mods (the compiled tables only).
+    pub fn builtin() -> Arc<Dex> {
+        BUILTIN.clone()
+    }
+
+    /// Build a dex from the compiled tables plus the given mod files, in order.
+    pub fn with_mods<P: AsRef<Path>>(paths: &[P]) -> Result<Dex> {
+        let mut dex = Dex::default();
+        for path in paths {
+            dex.load_mod_file(path)?;
+        }
+        Ok(dex)
+    }
+
+    /// Layer a mod file on top of the current entries.
+    pub fn load_mod_file(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let content =
+            fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+        self.load_mod_str(&path.display().to_string(), &content)
+    }
+
+    /// Layer a mod given as JSON text. `label` is used in error messages and [`Dex::mods`].
+    pub fn load_mod_str(&mut self, label: &str, json: &str) -> Result<()> {
+        let value: Value =
+            serde_json::from_str(json).map_err(|e| anyhow!("failed to parse data mod {}: {}", label, e))?;
+        self.load_mod_value(label, &value)
+    }
+
+    /// Layer a mod given as a JSON value.
+    ///
+    /// The whole mod is validated before anything is applied, so a failing mod
+    /// leaves the dex untouched.
+    pub fn load_mod_value(&mut self, label: &str, value: &Value) -> Result<()> {
+        let root = value
+            .as_object()
+            .ok_or_else(|| anyhow!("data mod {} must be a JSON object", label))?;
+        if let Some(unknown) = root.keys().find(|k| !MOD_SECTIONS.contains(&k.as_str())) {
+            bail!(
+                "data mod {}: unknown section '{}' (expected one of {})",
+                label,
+                unknown,
+                MOD_SECTIONS.join(", ")
+            );
+        }
+        let mut next = self.clone();
+        for (id, entry) in section(root, "pokedex", label)? {
+            let id = to_id(id);
+            let species = species_from_json(&id, entry, next.species(&id))
+                .with_context(|| format!("data mod {}: pokedex.{}", label, id))?;
+            next.species.insert(id, leak(species));
+        }
+        for (id, entry) in section(root, "moves", label)? {
+            let id = to_id(id);
+            let data = move_from_json(&id, entry, next.get_move(&id))
+                .with_context(|| format!("data mod {}: moves.{}", label, id))?;
+            next.moves.insert(id, leak(data));
+        }
+        for (id, entry) in section(root, "items", label)? {
+            let id = to_id(id);
+            let base = next.item(&id).map(|i| (i.num, i.name, i.short_desc, i.description));
+            let (num, name, short_desc, description) = described_from_json(&id, entry, base)
+                .with_context(|| format!("data mod {}: items.{}", label, id))?;
+            next.items.insert(id, leak(ItemData { num, name, short_desc, description }));
+        }
+        for (id, entry) in section(root, "abilities", label)? {
+            let id = to_id(id);
+            let base = next.ability(&id).map(|a| (a.num, a.name, a.short_desc, a.description));
+            let (num, name, short_desc, description) = described_from_json(&id, entry, base)
+                .with_context(|| format!("data mod {}: abilities.{}", label, id))?;
+            next.abilities.insert(id, leak(AbilityData { num, name, short_desc, description }));
+        }
+        for (id, entry) in section(root, "learnsets", label)? {
+            let id = to_id(id);
+            let moves = learnset_from_json(entry).with_context(|| format!("data mod {}: learnsets.{}", label, id))?;
+            next.learnsets.insert(id, moves);
+        }
+        next.mods.push(label.to_string());
+        *self = next;
+        Ok(())
+    }
+
+    /// Labels of the mods applied to this dex, in load order.
+    pub fn mods(&self) -> &[String] {
+        &self.mods
+    }
+
+    pub fn species(&self, name: &str) -> Option<&'static SpeciesData> {
+        let id = to_id(name);
+        self.species.get(&id).copied().or_else(|| POKEDEX.get(id.as_str()))
+    }
+
+    pub fn get_move(&self, name: &str) -> Option<&'static MoveData> {
+        let id = normalize_move_name(name);
+        self.moves.get(&id).copied().or_else(|| MOVES.get(id.as_str()))
+    }
+
+    pub fn item(&self, name: &str) -> Option<&'static ItemData> {
+        let id = to_id(name);
+        self.items.get(&id).copied().or_else(|| ITEMS.get(id.as_str()))
+    }
+
+    pub fn ability(&self, name: &str) -> Option<&'static AbilityData> {
+        let id = to_id(name);
+        self.abilities.get(&id).copied().or_else(|| ABILITIES.get(id.as_str()))
+    }
+
+    /// Whether `species` can learn `move_name`. Species without learnset data learn nothing.
+    pub fn can_learn(&self, species: &str, move_name: &str) -> bool {
+        let species = to_id(species);
+        let move_id = normalize_move_name(move_name);
+        match self.learnsets.get(&species) {
+            Some(learnset) => learnset.contains(&move_id),
+            None => LEARNSETS
+                .get(species.as_str())
+                .is_some_and(|learnset| learnset.contains(&move_id.as_str())),
+        }
+    }
+
+    /// Whether any learnset data is available, compiled or loaded from a mod.
+    pub fn has_learnsets(&self) -> bool {
+        !LEARNSETS.is_empty() || !self.learnsets.is_empty()
+    }
+
+    /// All species as `(id, data)`, overrides replacing built-in entries.
+    pub fn all_species(&self) -> impl Iterator<Item = (&str, &'static SpeciesData)> + '_ {
+        layered(&POKEDEX, &self.species)
+    }
+
+    /// All moves as `(id, data)`, overrides replacing built-in entries.
+    pub fn all_moves(&self) -> impl Iterator<Item = (&str, &'static MoveData)> + '_ {
+        layered(&MOVES, &self.moves)
+    }
+
+    /// All items as `(id, data)`, overrides replacing built-in entries.
+    pub fn all_items(&self) -> impl Iterator<Item = (&str, &'static ItemData)> + '_ {
+        layered(&ITEMS, &self.items)
+    }
+
+    /// All abilities as `(id, data)`, overrides replacing built-in entries.
+    pub fn all_abilities(&self) -> impl Iterator<Item = (&str, &'static AbilityData)> + '_ {
+        layered(&ABILITIES, &self.abilities)
+    }
+}
+
+const MOD_SECTIONS: [&str; 5] = ["pokedex", "moves", "items", "abilities", "learnsets"];
+
+fn layered<'a, T>(
+    builtin: &'static phf::Map<&'static str, T>,
+    overrides: &'a HashMap<String, &'static T>,
+) -> impl Iterator<Item = (&'a str, &'static T)> + 'a {
+    builtin
+        .entries()
+        .filter(move |(id, _)| !overrides.contains_key(**id))
+        .map(|(id, data)| (*id, data))
+        .chain(overrides.iter().map(|(id, data)| (id.as_str(), *data)))
+}
+
+fn section<'a>(root: &'a Map<String, Value>, name: &str, label: &str) -> Result<&'a Map<String, Value>> {
+    static EMPTY: Lazy<Map<String, Value>> = Lazy::new(Map::new);
+    match root.get(name) {
+        None => Ok(&EMPTY),
+        Some(Value::Object(map)) => Ok(map),
+        Some(_) => bail!("data mod {}: section '{}' must be an object", label, name),
+    }
+}
+
+pub(crate) fn to_id(name: &str) -> String {
+    name.to_ascii_lowercase()
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect()
+}
+
+fn leak<T>(value: T) -> &'static T {
+    Box::leak(Box::new(value))
+}
+
+fn leak_str(value: &str) -> &'static str {
+    Box::leak(value.to_string().into_boxed_str())
+}
+
+fn leak_slice<T>(values: Vec<T>) -> &'static [T] {
+    if values.is_empty() {
+        return &[];
+    }
+    Box::leak(values.into_boxed_slice())
+}
+
+/// Start of an entry: the inherited definition, or `None` for a new one.
+fn inherited<T: Copy>(entry: &Value, base: Option<&T>) -> Result<Option<T>> {
+    if !entry.is_object() {
+        bail!("entry must be an object");
+    }
+    match entry.get("inherit") {
+        Some(Value::Bool(true)) => base
+            .copied()
+            .map(Some)
+            .ok_or_else(|| anyhow!("'inherit' is set but there is no entry to inherit from")),
+        None | Some(Value::Bool(false)) => Ok(None),
+        Some(_) => bail!("'inherit' must be a boolean"),
+    }
+}
+
+fn get_str(entry: &Value, key: &str) -> Result<Option<&'static str>> {
+    match entry.get(key) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(s)) => Ok(Some(leak_str(s))),
+        Some(_) => bail!("'{}' must be a string", key),
+    }
+}
+
+fn get_int<T: TryFrom<i64>>(entry: &Value, key: &str) -> Result<Option<T>> {
+    match entry.get(key) {
+        None | Some(Value::Null) => Ok(None),
+        Some(value) => {
+            let int = value.as_i64().ok_or_else(|| anyhow!("'{}' must be an integer", key))?;
+            T::try_from(int)
+                .map(Some)
+                .map_err(|_| anyhow!("'{}' is out of range: {}", key, int))
+        }
+    }
+}
+
+fn int_pair(value: &Value, key: &str) -> Result<(u8, u8)> {
+    let array = value.as_array().filter(|a| a.len() == 2);
+    let pair = array.and_then(|a| Some((a[0].as_u64()?, a[1].as_u64()?)));
+    match pair {
+        Some((a, b)) if a <= u8::MAX as u64 && b <= u8::MAX as u64 => Ok((a as u8, b as u8)),
+        _ => bail!("'{}' must be a pair of small integers like [1, 3]", key),
+    }
+}
+
+fn check_type(name: &str) -> Result<&'static str> {
+    if parse_type(name).is_none() {
+        bail!("unknown type '{}'", name);
+    }
+    Ok(leak_str(name))
+}
+
+fn species_from_json(id: &str, entry: &Value, base: Option<&'static SpeciesData>) -> Result<SpeciesData> {
+    let mut data = match inherited(entry, base)? {
+        Some(data) => data,
+        None => {
+            let stats = entry.get("baseStats").and_then(Value::as_object);
+            let missing: Vec<&str> = ["hp", "atk", "def", "spa", "spd", "spe"]
+                .into_iter()
+                .filter(|stat| !stats.is_some_and(|s| s.contains_key(*stat)))
+                .collect();
+            if !missing.is_empty() {
+                bail!("new species needs baseStats ({} missing)", missing.join(", "));
+            }
+            if entry.get("types").is_none() {
+                bail!("new species needs 'types'");
+            }
+            SpeciesData {
+                num: 0,
+                name: leak_str(id),
+                base_stats: BaseStats { hp: 0, atk: 0, def: 0, spa: 0, spd: 0, spe: 0 },
+                types: ["", ""],
+                abilities: AbilitySlots { primary: None, secondary: None, hidden: None },
+                weight_kg: 0.0,
+            }
+        }
+    };
+    if let Some(num) = get_int(entry, "num")? {
+        data.num = num;
+    }
+    if let Some(name) = get_str(entry, "name")? {
+        data.name = name;
+    }
+    if let Some(stats) = entry.get("baseStats") {
+        if !stats.is_object() {
+            bail!("'baseStats' must be an object");
+        }
+        let base_stats = &mut data.base_stats;
+        for (key, slot) in [
+            ("hp", &mut base_stats.hp),
+            ("atk", &mut base_stats.atk),
+            ("def", &mut base_stats.def),
+            ("spa", &mut base_stats.spa),
+            ("spd", &mut base_stats.spd),
+            ("spe", &mut base_stats.spe),
+        ] {
+            if let Some(value) = get_int(stats, key).with_context(|| "in baseStats")? {
+                *slot = value;
+            }
+        }
+    }
+    if let Some(types) = entry.get("types") {
+        let names: Vec<&str> = types
+            .as_array()
+            .map(|t| t.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+        if names.is_empty() || names.len() > 2 || names.len() != types.as_array().map_or(0, Vec::len) {
+            bail!("'types' must be an array of one or two type names");
+        }
+        data.types = [check_type(names[0])?, names.get(1).map(|t| check_type(t)).transpose()?.unwrap_or("")];
+    }
+    if let Some(abilities) = entry.get("abilities") {
+        if !abilities.is_object() {
+            bail!("'abilities' must be an object like {{\"0\": \"Rough Skin\", \"H\": \"Sand Veil\"}}");
+        }
+        data.abilities = AbilitySlots {
+            primary: get_str(abilities, "0")?,
+            secondary: get_str(abilities, "1")?,
+            hidden: get_str(abilities, "H")?,
+        };
+    }
+    if let Some(weight) = entry.get("weightkg") {
+        data.weight_kg = weight.as_f64().ok_or_else(|| anyhow!("'weightkg' must be a number"))? as f32;
+    }
+    Ok(data)
+}
+
+fn secondary_from_json(effect: &Value, key: &str) -> Result<Option<SecondaryEffect>> {
+    if effect.is_null() || effect == &Value::Bool(false) {
+        return Ok(None);
+    }
+    if !effect.is_object() {
+        bail!("'{}' must be an object or null", key);
+    }
+    let mut boosts = Vec::new();
+    if let Some(raw) = effect.get("boosts") {
+        let raw = raw.as_object().ok_or_else(|| anyhow!("'{}.boosts' must be an object", key))?;
+        let mut entries: Vec<(&String, &Value)> = raw.iter().collect();
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+        for (stat, amount) in entries {
+            let amount = amount
+                .as_i64()
+                .and_then(|a| i8::try_from(a).ok())
+                .ok_or_else(|| anyhow!("'{}.boosts.{}' must be a small integer", key, stat))?;
+            boosts.push((leak_str(stat), amount));
+        }
+    }
+    Ok(Some(SecondaryEffect {
+        chance: get_int(effect, "chance")?.unwrap_or(100),
+        status: get_str(effect, "status")?,
+        volatile_status: get_str(effect, "volatileStatus")?,
+        boosts: leak_slice(boosts),
+        side_condition: get_str(effect, "sideCondition")?,
+        weather: get_str(effect, "weather")?,
+        terrain: get_str(effect, "terrain")?,
+    }))
+}
+
+fn move_from_json(id: &str, entry: &Value, base: Option<&'static MoveData>) -> Result<MoveData> {
+    let mut data = match inherited(entry, base)? {
+        Some(data) => data,
+        None => {
+            for key in ["type", "category"] {
+                if entry.get(key).is_none() {
+                    bail!("new move needs '{}'", key);
+                }
+            }
+            MoveData {
+                num: 0,
+                name: leak_str(id),
+                move_type: "Normal",
+                category: MoveCategory::Status,
+                base_power: None,
+                accuracy: None,
+                pp: None,
+                priority: 0,
+                target: "normal",
+                flags: &[],
+                secondary: None,
+                secondaries: &[],
+                self_effect: None,
+                crit_ratio: None,
+                recoil: None,
+                drain: None,
+                multihit: None,
+                short_desc: "",
+                description: "",
+            }
+        }
+    };
+    if let Some(num) = get_int(entry, "num")? {
+        data.num = num;
+    }
+    if let Some(name) = get_str(entry, "name")? {
+        data.name = name;
+    }
+    if let Some(move_type) = get_str(entry, "type")? {
+        data.move_type = check_type(move_type)?;
+    }
+    if let Some(category) = get_str(entry, "category")? {
+        data.category = match category.to_ascii_lowercase().as_str() {
+            "physical" => MoveCategory::Physical,
+            "special" => MoveCategory::Special,
+            "status" => MoveCategory::Status,
+            other => bail!("unknown category '{}'", other),
+        };
+    }
+    if entry.get("basePower").is_some() {
+        data.base_power = get_int(entry, "basePower")?;
+    }
+    match entry.get("accuracy") {
+        None => {}
+        Some(Value::Bool(true)) => data.accuracy = Some(100.0),
+        Some(value) => {
+            data.accuracy = Some(value.as_f64().ok_or_else(|| anyhow!("'accuracy' must be a number or true"))? as f32)
+        }
+    }
+    if entry.get("pp").is_some() {
+        data.pp = get_int(entry, "pp")?;
+    }
+    if let Some(priority) = get_int(entry, "priority")? {
+        data.priority = priority;
+    }
+    if let Some(target) = get_str(entry, "target")? {
+        data.target = target;
+    }
+    if let Some(flags) = entry.get("flags") {
+        let mut names: Vec<&'static str> = match flags {
+            Value::Object(map) => map.keys().map(|k| leak_str(k)).collect(),
+            Value::Array(list) => list
+                .iter()
+                .map(|f| f.as_str().map(leak_str))
+                .collect::<Option<_>>()
+                .ok_or_else(|| anyhow!("'flags' entries must be strings"))?,
+            _ => bail!("'flags' must be an object or an array"),
+        };
+        names.sort_unstable();
+        data.flags = leak_slice(names);
+    }
+    apply_descriptions(entry, &mut data.short_desc, &mut data.description)?;
+    if let Some(secondary) = entry.get("secondary") {
+        data.secondary = secondary_from_json(secondary, "secondary")?;
+    }
+    if let Some(secondaries) = entry.get("secondaries") {
+        let list = match secondaries {
+            Value::Null => Vec::new(),
+            Value::Array(list) => list
+                .iter()
+                .map(|effect| secondary_from_json(effect, "secondaries"))
+                .filter_map(Result::transpose)
+                .collect::<Result<_>>()?,
+            _ => bail!("'secondaries' must be an array or null"),
+        };
+        data.secondaries = leak_slice(list);
+    }
+    if let Some(self_effect) = entry.get("self") {
+        data.self_effect = secondary_from_json(self_effect, "self")?;
+    }
+    if entry.get("critRatio").is_some() {
+        data.crit_ratio = get_int(entry, "critRatio")?;
+    }
+    if let Some(recoil) = entry.get("recoil") {
+        data.recoil = if recoil.is_null() { None } else { Some(int_pair(recoil, "recoil")?) };
+    }
+    if let Some(drain) = entry.get("drain") {
+        data.drain = if drain.is_null() { None } else { Some(int_pair(drain, "drain")?) };
+    }
+    if let Some(multihit) = entry.get("multihit") {
+        data.multihit = match multihit {
+            Value::Null => None,
+            Value::Number(_) => {
+                let hits = get_int::<u8>(entry, "multihit")?.unwrap_or(1);
+                Some((hits, hits))
+            }
+            _ => Some(int_pair(multihit, "multihit")?),
+        };
+    }
+    Ok(data)
+}
+
+fn learnset_from_json(entry: &Value) -> Result<HashSet<String>> {
+    // Entries without a learnset (e.g. formes sharing the base learnset) are kept as empty.
+    match entry.get("learnset") {
+        None | Some(Value::Null) => Ok(HashSet::new()),
+        Some(Value::Object(moves)) => Ok(moves.keys().map(|m| normalize_move_name(m)).collect()),
+        Some(_) => bail!("'learnset' must be an object keyed by move id"),
+    }
+}
+
+type Described = (i16, &'static str, &'static str, &'static str);
+
+fn described_from_json(id: &str, entry: &Value, base: Option<Described>) -> Result<Described> {
+    let (mut num, mut name, mut short_desc, mut description) = match inherited(entry, base.as_ref())? {
+        Some(base) => base,
+        None => (0, leak_str(id), "", ""),
+    };
+    if let Some(value) = get_int(entry, "num")? {
+        num = value;
+    }
+    if let Some(value) = get_str(entry, "name")? {
+        name = value;
+    }
+    apply_descriptions(entry, &mut short_desc, &mut description)?;
+    Ok((num, name, short_desc, description))
+}
+
+/// `shortDesc` / `desc` の上書き。片方だけ指定された場合はもう片方にも使う（datagen と同じ規則）。
+fn apply_descriptions(entry: &Value, short_desc: &mut &'static str, description: &mut &'static str) -> Result<()> {
+    let short = get_str(entry, "shortDesc")?;
+    let long = get_str(entry, "desc")?;
+    if let Some(value) = short.or(long) {
+        *short_desc = value;
+    }
+    if let Some(value) = long.or(short) {
+        *description = value;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_dex_reads_compiled_tables() {
+        let dex = Dex::builtin();
+        assert_eq!(dex.species("Garchomp").expect("garchomp").base_stats.spe, 102);
+        assert_eq!(dex.get_move("Thunderbolt").expect("thunderbolt").base_power, Some(90));
+        assert!(dex.item("Leftovers").is_some());
+        assert!(dex.ability("Rough Skin").is_some());
+        assert!(dex.mods().is_empty());
+    }
+
+    #[test]
+    fn inherit_overrides_only_listed_fields() -> Result<()> {
+        let mut dex = Dex::default();
+        dex.load_mod_str(
+            "fast-chomp",
+            r#"{ "pokedex": { "garchomp": { "inherit": true, "baseStats": { "spe": 110 } } },
+                 "moves": { "thunderbolt": { "inherit": true, "basePower": 95 } } }"#,
+        )?;
+        let chomp = dex.species("garchomp").expect("garchomp");
+        assert_eq!(chomp.base_stats.spe, 110);
+        assert_eq!(chomp.base_stats.atk, 130);
+        assert_eq!(chomp.types, ["Dragon", "Ground"]);
+        let bolt = dex.get_move("thunderbolt").expect("thunderbolt");
+        assert_eq!(bolt.base_power, Some(95));
+        assert!(bolt.secondary.is_some());
+        // The shared built-in dex is untouched.
+        assert_eq!(Dex::builtin().species("garchomp").unwrap().base_stats.spe, 102);
+        Ok(())
+    }
+
+    #[test]
+    fn new_entries_are_added_and_listed() -> Result<()> {
+        let mut dex = Dex::default();
+        dex.load_mod_str(
+            "fakemon",
+            r#"{ "pokedex": { "testmon": {
+                    "num": 9001, "name": "Testmon", "types": ["Fire"],
+                    "baseStats": { "hp": 80, "atk": 80, "def": 80, "spa": 80, "spd": 80, "spe": 80 },
+                    "abilities": { "0": "Blaze" }, "weightkg": 12.5 } },
+                 "moves": { "testbeam": {
+                    "name": "Test Beam", "type": "Fire", "category": "Special", "basePower": 70,
+                    "accuracy": true, "pp": 10, "flags": { "protect": 1 },
+                    "secondary": { "chance": 30, "status": "brn" } } } }"#,
+        )?;
+        let mon = dex.species("Testmon").expect("testmon");
+        assert_eq!(mon.types, ["Fire", ""]);
+        assert_eq!(mon.abilities.primary, Some("Blaze"));
+        let beam = dex.get_move("Test Beam").expect("testbeam");
+        assert_eq!(beam.accuracy, Some(100.0));
+        assert_eq!(beam.flags, &["protect"]);
+        assert_eq!(beam.secondary.map(|s| (s.chance, s.status)), Some((30, Some("brn"))));
+        assert_eq!(dex.all_species().filter(|(id, _)| *id == "testmon").count(), 1);
+        assert_eq!(dex.all_species().count(), POKEDEX.len() + 1);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_mod_is_rejected_without_partial_changes() {
+        let mut dex = Dex::default();
+        let err = dex
+            .load_mod_str(
+                "broken",
+                r#"{ "pokedex": { "garchomp": { "inherit": true, "baseStats": { "spe": 110 } },
+                                  "newmon": { "types": ["Fire"] } } }"#,
+            )
+            .unwrap_err();
+        assert!(format!("{:#}", err).contains("pokedex.newmon"), "{:#}", err);
+        assert_eq!(dex.species("garchomp").unwrap().base_stats.spe, 102);
+        assert!(dex.mods().is_empty());
+
+        let err = dex
+            .load_mod_str("badtype", r#"{ "moves": { "tackle": { "inherit": true, "type": "Sound" } } }"#)
+            .unwrap_err();
+        assert!(format!("{:#}", err).contains("unknown type 'Sound'"), "{:#}", err);
+    }
+
+    #[test]
+    fn descriptions_fill_each_other_in() -> Result<()> {
+        let mut dex = Dex::default();
+        dex.load_mod_str(
+            "text",
+            r#"{ "moves": { "thunderbolt": { "inherit": true, "shortDesc": "10% chance to paralyze the target." } },
+                 "items": { "leftovers": { "inherit": true, "desc": "Heals 1/16 of max HP each turn." } } }"#,
+        )?;
+        let bolt = dex.get_move("thunderbolt").unwrap();
+        assert_eq!(bolt.description, "10% chance to paralyze the target.");
+        assert_eq!(dex.item("leftovers").unwrap().short_desc, "Heals 1/16 of max HP each turn.");
+        Ok(())
+    }
+
+    #[test]
+    fn later_mods_layer_on_earlier_ones() -> Result<()> {
+        let mut dex = Dex::default();
+        dex.load_mod_str("a", r#"{ "pokedex": { "garchomp": { "inherit": true, "baseStats": { "spe": 110 } } } }"#)?;
+        dex.load_mod_str("b", r#"{ "pokedex": { "garchomp": { "inherit": true, "baseStats": { "atk": 100 } } } }"#)?;
+        let chomp = dex.species("garchomp").unwrap();
+        assert_eq!((chomp.base_stats.atk, chomp.base_stats.spe), (100, 110));
+        assert_eq!(dex.mods(), ["a", "b"]);
+        Ok(())
+    }
+
+    #[test]
+    fn battle_lookups_go_through_the_dex_handle() -> Result<()> {
+        use crate::sim::battle::{determine_order_in, Action};
+        use crate::sim::{Nature, Pokemon};
+        use rand::SeedableRng;
+
+        let mut dex = Dex::default();
+        dex.load_mod_str(
+            "priority-bolt",
+            r#"{ "pokedex": { "pikachu": { "inherit": true, "baseStats": { "hp": 100 } } },
+                 "moves": { "thunderbolt": { "inherit": true, "priority": 1 } } }"#,
+        )?;
+        let build = |dex: &Dex, species: &str, moves: &[&str]| {
+            Pokemon::new_in(
+                dex,
+                species,
+                50,
+                [0; 6],
+                [31; 6],
+                Nature::Hardy,
+                moves.iter().map(|m| m.to_string()).collect(),
+                "Static",
+                None,
+            )
+        };
+        let modded = build(&dex, "Pikachu", &["thunderbolt"])?;
+        let vanilla = build(&Dex::builtin(), "Pikachu", &["thunderbolt"])?;
+        assert!(modded.stats.hp > vanilla.stats.hp);
+
+        let fast = build(&dex, "Jolteon", &["tackle"])?;
+        let mut rng = rand::rngs::SmallRng::seed_from_u64(1);
+        let order = |dex: &Dex, rng: &mut rand::rngs::SmallRng| {
+            determine_order_in(dex, &modded, Action::Move(0), &fast, Action::Move(0), false, None, None, rng)
+        };
+        assert_eq!(order(&dex, &mut rng), (true, false));
+        assert_eq!(order(&Dex::builtin(), &mut rng), (false, true));
+        Ok(())
+    }
+}
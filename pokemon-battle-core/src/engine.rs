@@ -4,9 +4,18 @@ use crate::sim::battle::{
     apply_end_of_turn_effects, apply_on_entry_abilities, execute_turn, Action, BattleResult,
     BattleState,
 };
+use crate::dex::Dex;
+use crate::formats::Format;
+use crate::i18n::messages::silently;
+use crate::sim::ai::BattleAI;
+use crate::sim::chance::{enumerate, Chance, EnumerateOptions};
+use crate::sim::outcomes::{merge, Outcome};
+use crate::sim::team_preview::{legal_team_choices, log_team_preview, TeamChoice, TeamPreview};
 use crate::sim::Pokemon;
+use anyhow::{bail, Result};
 use rand::rngs::SmallRng;
 use rand::SeedableRng;
+use std::sync::Arc;
 
 /// Player identifier for selecting actions and observations.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -33,9 +42,29 @@ pub struct StepResult {
 }
 
 /// Step-based battle engine for external callers (e.g., RL loops).
+///
+/// Cloning an engine forks the battle, RNG included; [`reseed`](Self::reseed) a clone to
+/// explore a different outcome (search AIs simulate on clones).
+#[derive(Clone)]
 pub struct BattleEngine {
     state: BattleState,
     rng: SmallRng,
+    preview: Option<PendingPreview>,
+}
+
+/// Full teams and the choices made so far while the engine is in team preview.
+#[derive(Clone)]
+struct PendingPreview {
+    teams: [Vec<Pokemon>; 2],
+    bring: usize,
+    choices: [Option<TeamChoice>; 2],
+}
+
+fn side(player: Player) -> usize {
+    match player {
+        Player::A => 0,
+        Player::B => 1,
+    }
 }
 
 impl BattleEngine {
@@ -43,20 +72,149 @@ impl BattleEngine {
     ///
     /// `team_a`/`team_b` must each contain at least one Pokémon.
     pub fn new(team_a: &[Pokemon], team_b: &[Pokemon], seed: u64) -> Self {
+        Self::with_dex(team_a, team_b, seed, Dex::builtin())
+    }
+
+    /// Create a new engine whose battle looks up data in `dex` (e.g. with data mods).
+    pub fn with_dex(team_a: &[Pokemon], team_b: &[Pokemon], seed: u64, dex: Arc<Dex>) -> Self {
         assert!(!team_a.is_empty(), "team_a must contain at least one Pokemon");
         assert!(!team_b.is_empty(), "team_b must contain at least one Pokemon");
         let mut team_a = team_a.to_vec();
         let mut team_b = team_b.to_vec();
         let pokemon_a = team_a.remove(0);
         let pokemon_b = team_b.remove(0);
-        let mut state = BattleState::new_with_bench(pokemon_a, pokemon_b, team_a, team_b);
+        let state = BattleState::new_with_bench(pokemon_a, pokemon_b, team_a, team_b).with_dex(dex);
+        Self::from_state(state, seed)
+    }
+
+    /// Create an engine from a prepared state (custom dex, type chart, ...).
+    pub fn from_state(mut state: BattleState, seed: u64) -> Self {
         apply_on_entry_abilities(&mut state);
         let rng = SmallRng::seed_from_u64(seed);
-        Self { state, rng }
+        Self { state, rng, preview: None }
+    }
+
+    /// Continue a battle that is already under way from `state`. Unlike
+    /// [`from_state`](Self::from_state), on-entry abilities are not applied again.
+    pub fn resume(state: BattleState, seed: u64) -> Self {
+        Self { state, rng: SmallRng::seed_from_u64(seed), preview: None }
+    }
+
+    /// Replace the RNG, e.g. so that clones of one engine roll differently.
+    pub fn reseed(&mut self, seed: u64) {
+        self.rng = SmallRng::seed_from_u64(seed);
+    }
+
+    /// Create an engine that starts in team preview: each player must pick a lead and
+    /// `bring` Pokémon with [`choose_team`](Self::choose_team) before the first step.
+    /// `state` supplies the dex, type chart and logger; its Pokémon are replaced.
+    pub fn with_team_preview(mut state: BattleState, team_a: &[Pokemon], team_b: &[Pokemon], bring: usize, seed: u64) -> Self {
+        assert!(!team_a.is_empty(), "team_a must contain at least one Pokemon");
+        assert!(!team_b.is_empty(), "team_b must contain at least one Pokemon");
+        if let Some(logger) = state.logger.as_mut() {
+            log_team_preview(logger, team_a, team_b, bring);
+        }
+        let rng = SmallRng::seed_from_u64(seed);
+        let preview = PendingPreview {
+            teams: [team_a.to_vec(), team_b.to_vec()],
+            bring: bring.max(1),
+            choices: [None, None],
+        };
+        Self { state, rng, preview: Some(preview) }
+    }
+
+    /// Create an engine for `format`: team preview when the format has one, bringing
+    /// [`Format::bring_count`] Pokémon. Levels follow the format's level rule; the teams
+    /// should come from [`Format::prepare_team`].
+    pub fn for_format(format: &Format, team_a: &[Pokemon], team_b: &[Pokemon], seed: u64, dex: Arc<Dex>) -> Result<Self> {
+        let state = format.new_battle(dex, team_a.to_vec(), team_b.to_vec())?;
+        if !format.team_preview {
+            return Ok(Self::from_state(state, seed));
+        }
+        // The teams as `new_battle` adjusted them.
+        let team_a: Vec<Pokemon> = std::iter::once(&state.pokemon_a).chain(&state.bench_a).cloned().collect();
+        let team_b: Vec<Pokemon> = std::iter::once(&state.pokemon_b).chain(&state.bench_b).cloned().collect();
+        let bring = format.bring_count(team_a.len().max(team_b.len()));
+        Ok(Self::with_team_preview(state, &team_a, &team_b, bring, seed))
+    }
+
+    /// True until both players have chosen their teams.
+    pub fn in_team_preview(&self) -> bool {
+        self.preview.is_some()
+    }
+
+    /// What `player` sees at team preview (`None` once the battle has started).
+    pub fn team_preview(&self, player: Player) -> Option<TeamPreview> {
+        let preview = self.preview.as_ref()?;
+        let (own, opponent) = (side(player), 1 - side(player));
+        Some(TeamPreview::new(&preview.teams[own], &preview.teams[opponent], preview.bring))
+    }
+
+    /// Legal team choices for `player` (empty outside team preview or once chosen).
+    pub fn legal_team_actions(&self, player: Player) -> Vec<TeamChoice> {
+        match &self.preview {
+            Some(preview) if preview.choices[side(player)].is_none() => {
+                legal_team_choices(preview.teams[side(player)].len(), preview.bring)
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Submit `player`'s team choice. The battle starts once both players have chosen.
+    pub fn choose_team(&mut self, player: Player, choice: TeamChoice) -> Result<()> {
+        let Some(preview) = self.preview.as_mut() else {
+            bail!("the battle is not in team preview");
+        };
+        let idx = side(player);
+        if preview.choices[idx].is_some() {
+            bail!("player {:?} has already chosen a team", player);
+        }
+        let bring = preview.bring.min(preview.teams[idx].len());
+        choice.check(preview.teams[idx].len(), bring)?;
+        preview.choices[idx] = Some(choice);
+        if preview.choices.iter().all(Option::is_some) {
+            self.start_after_preview();
+        }
+        Ok(())
+    }
+
+    /// Let two AIs pick their teams through [`BattleAI::choose_team`].
+    pub fn run_team_preview(&mut self, ai_a: &mut dyn BattleAI, ai_b: &mut dyn BattleAI) -> Result<()> {
+        self.preview_choice(Player::A, ai_a)?;
+        self.preview_choice(Player::B, ai_b)
+    }
+
+    fn preview_choice(&mut self, player: Player, ai: &mut dyn BattleAI) -> Result<()> {
+        let legal = self.legal_team_actions(player);
+        match self.team_preview(player) {
+            Some(preview) if !legal.is_empty() => {
+                let choice = ai.choose_team(&preview, &legal);
+                self.choose_team(player, choice)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn start_after_preview(&mut self) {
+        let preview = self.preview.take().expect("engine is in team preview");
+        let [choice_a, choice_b] = preview.choices.map(|choice| choice.expect("both players chose"));
+        let mut team_a = choice_a.select(&preview.teams[0]);
+        let mut team_b = choice_b.select(&preview.teams[1]);
+        let lead_a = team_a.remove(0);
+        let lead_b = team_b.remove(0);
+        let mut state = BattleState::new_with_bench(lead_a, lead_b, team_a, team_b)
+            .with_dex(self.state.dex.clone())
+            .with_type_chart(self.state.type_chart);
+        state.logger = self.state.logger.take();
+        apply_on_entry_abilities(&mut state);
+        self.state = state;
     }
 
     /// Advance the battle by one turn using the provided actions.
+    ///
+    /// Panics while the engine is still in team preview.
     pub fn step(&mut self, action_a: Action, action_b: Action) -> StepResult {
+        assert!(!self.in_team_preview(), "choose both teams before stepping the battle");
         if let Some(outcome) = battle_outcome(&self.state) {
             let snapshot = self.state.clone();
             let (reward_a, reward_b) = outcome_rewards(Some(outcome));
@@ -71,10 +229,7 @@ impl BattleEngine {
         }
 
         let before = self.state.clone();
-        reset_turn_flags(&mut self.state);
-        execute_turn(&mut self.state, action_a, action_b, &mut self.rng);
-        apply_end_of_turn_effects(&mut self.state, &mut self.rng);
-        self.state.turn = self.state.turn.saturating_add(1);
+        play_turn(&mut self.state, action_a, action_b, &mut self.rng);
 
         let outcome = battle_outcome(&self.state);
         let (reward_a, reward_b) = outcome_rewards(outcome);
@@ -91,11 +246,35 @@ impl BattleEngine {
 
     /// Returns true if the current state is terminal.
     pub fn is_terminal(&self) -> bool {
-        battle_outcome(&self.state).is_some()
+        !self.in_team_preview() && battle_outcome(&self.state).is_some()
+    }
+
+    /// Every distinct result of [`step`](Self::step)ping with these actions (end-of-turn
+    /// effects included), with exact probabilities. The engine is not advanced and nothing
+    /// is narrated.
+    pub fn outcomes(&self, action_a: Action, action_b: Action) -> Vec<Outcome> {
+        assert!(!self.in_team_preview(), "choose both teams before looking ahead");
+        let branches = silently(|| {
+            enumerate(&self.state, EnumerateOptions::default(), |after, chance| {
+                play_turn(after, action_a, action_b, chance)
+            })
+        });
+        merge(branches)
     }
 
-    /// List legal actions for a player given the current state.
+    /// The battle's result once it is over.
+    pub fn outcome(&self) -> Option<BattleResult> {
+        if self.in_team_preview() {
+            return None;
+        }
+        battle_outcome(&self.state)
+    }
+
+    /// List legal actions for a player given the current state (none during team preview).
     pub fn legal_actions(&self, player: Player) -> Vec<Action> {
+        if self.in_team_preview() {
+            return Vec::new();
+        }
         match player {
             Player::A => actions_for(&self.state.pokemon_a, &self.state.bench_a),
             Player::B => actions_for(&self.state.pokemon_b, &self.state.bench_b),
@@ -108,7 +287,18 @@ impl BattleEngine {
     }
 }
 
-fn actions_for(active: &Pokemon, bench: &[Pokemon]) -> Vec<Action> {
+/// One engine turn (both actions, then end-of-turn effects) with randomness from `chance`;
+/// [`chance::enumerate`](crate::sim::chance::enumerate) this to get every outcome.
+pub fn play_turn(state: &mut BattleState, action_a: Action, action_b: Action, chance: &mut dyn Chance) {
+    reset_turn_flags(state);
+    execute_turn(state, action_a, action_b, chance);
+    apply_end_of_turn_effects(state, chance);
+    state.turn = state.turn.saturating_add(1);
+}
+
+/// Legal actions of the side with `active` and `bench` (the engine's
+/// [`legal_actions`](BattleEngine::legal_actions)).
+pub fn actions_for(active: &Pokemon, bench: &[Pokemon]) -> Vec<Action> {
     let mut actions: Vec<Action> = active
         .moves
         .iter()
@@ -134,7 +324,8 @@ fn reset_turn_flags(state: &mut BattleState) {
     state.pokemon_b.semi_invulnerable = false;
 }
 
-fn battle_outcome(state: &BattleState) -> Option<BattleResult> {
+/// Result of `state` if the battle is over (the engine's terminal test).
+pub fn battle_outcome(state: &BattleState) -> Option<BattleResult> {
     let a_available = side_has_available(&state.pokemon_a, &state.bench_a);
     let b_available = side_has_available(&state.pokemon_b, &state.bench_b);
     match (a_available, b_available) {
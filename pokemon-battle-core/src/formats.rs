@@ -0,0 +1,675 @@
+//! Format registry: the rules a battle is played under, looked up by Showdown format id.
+//!
+//! A [`Format`] fixes the generation (and with it the type chart), singles or doubles,
+//! how levels are handled, how many Pokémon a team holds and how many are brought to a
+//! battle, the clauses in effect and the species/item/ability/move banlists.
+
+mod levels;
+
+pub use levels::{LevelTable, GEN9_RANDOM_BATTLE, GEN9_RANDOM_DOUBLES};
+
+use crate::battle_logger::BattleLogger;
+use crate::dex::{to_id, Dex};
+use crate::sets::PokemonSet;
+use crate::sim::battle::BattleState;
+use crate::sim::pokemon::Pokemon;
+use crate::sim::type_chart::TypeChart;
+use anyhow::{bail, Context, Result};
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+
+/// Format used when none is given (matches `BattleLogger::new`).
+pub const DEFAULT_FORMAT: &str = "gen9customgame";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GameType {
+    Singles,
+    Doubles,
+}
+
+impl GameType {
+    /// Showdown's `|gametype|` value.
+    pub fn id(self) -> &'static str {
+        match self {
+            GameType::Singles => "singles",
+            GameType::Doubles => "doubles",
+        }
+    }
+}
+
+/// How a format treats levels.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LevelRule {
+    /// Any level from 1 to 100.
+    Any,
+    /// Levels above the cap are rejected by validation.
+    Max(u8),
+    /// Levels above the cap are lowered to it (Showdown `adjustLevelDown`).
+    AdjustDown(u8),
+    /// Every Pokémon battles at this level (Showdown `adjustLevel`).
+    Fixed(u8),
+    /// Each species battles at its level in a random format's table.
+    Table(&'static LevelTable),
+}
+
+impl LevelRule {
+    /// Level a Pokémon of `species` built at `level` battles at.
+    pub fn level_for(&self, dex: &Dex, species: &str, level: u8) -> u8 {
+        match *self {
+            LevelRule::AdjustDown(cap) => level.min(cap),
+            LevelRule::Fixed(fixed) => fixed,
+            LevelRule::Table(table) => table.level(dex, species),
+            LevelRule::Any | LevelRule::Max(_) => level,
+        }
+    }
+
+    /// Showdown-style `|rule|` text, if the rule changes levels.
+    pub fn description(&self) -> Option<String> {
+        match *self {
+            LevelRule::AdjustDown(cap) => {
+                Some(format!("Adjust Level Down: Pokémon above level {cap} will be set to level {cap}"))
+            }
+            LevelRule::Fixed(level) => Some(format!("Adjust Level = {level}: All Pokémon will be set to level {level}")),
+            LevelRule::Table(table) => Some(format!("Random Levels: Pokémon battle at their {} level", table.format)),
+            LevelRule::Any | LevelRule::Max(_) => None,
+        }
+    }
+}
+
+/// Team-building clauses checked by [`Format::validate`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Clause {
+    /// No two Pokémon with the same National Dex number.
+    Species,
+    /// No two Pokémon holding the same item.
+    Item,
+    /// No Fissure, Guillotine, Horn Drill or Sheer Cold.
+    Ohko,
+    /// No Double Team or Minimize.
+    EvasionMoves,
+    /// No Bright Powder or Lax Incense.
+    EvasionItems,
+    /// No Sand Veil or Snow Cloak.
+    EvasionAbilities,
+    /// No Moody.
+    Moody,
+    /// No Swagger.
+    Swagger,
+}
+
+impl Clause {
+    pub fn name(self) -> &'static str {
+        match self {
+            Clause::Species => "Species Clause",
+            Clause::Item => "Item Clause",
+            Clause::Ohko => "OHKO Clause",
+            Clause::EvasionMoves => "Evasion Moves Clause",
+            Clause::EvasionItems => "Evasion Items Clause",
+            Clause::EvasionAbilities => "Evasion Abilities Clause",
+            Clause::Moody => "Moody Clause",
+            Clause::Swagger => "Swagger Clause",
+        }
+    }
+
+    // (moves, items, abilities) the clause forbids, as ids.
+    fn bans(self) -> (&'static [&'static str], &'static [&'static str], &'static [&'static str]) {
+        match self {
+            Clause::Ohko => (&["fissure", "guillotine", "horndrill", "sheercold"], &[], &[]),
+            Clause::EvasionMoves => (&["doubleteam", "minimize"], &[], &[]),
+            Clause::EvasionItems => (&[], &["brightpowder", "laxincense"], &[]),
+            Clause::EvasionAbilities => (&[], &[], &["sandveil", "snowcloak"]),
+            Clause::Moody => (&[], &[], &["moody"]),
+            Clause::Swagger => (&["swagger"], &[], &[]),
+            Clause::Species | Clause::Item => (&[], &[], &[]),
+        }
+    }
+}
+
+/// Rules of one format. Banlists hold ids (`to_id` form).
+#[derive(Clone, Debug)]
+pub struct Format {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub generation: u8,
+    pub game_type: GameType,
+    pub level: LevelRule,
+    /// Smallest and largest legal team.
+    pub team_size: (usize, usize),
+    /// Pokémon brought to each battle (picked at team preview); `None` brings the whole team.
+    pub bring: Option<usize>,
+    /// Players see both teams and pick their lead (and the brought Pokémon) before turn 1.
+    pub team_preview: bool,
+    /// Teams are generated instead of built by the players.
+    pub random_teams: bool,
+    /// Type matchups are flipped (Showdown's Inverse Mod).
+    pub inverse: bool,
+    pub clauses: &'static [Clause],
+    pub banned_species: &'static [&'static str],
+    pub banned_items: &'static [&'static str],
+    pub banned_abilities: &'static [&'static str],
+    pub banned_moves: &'static [&'static str],
+}
+
+const RESTRICTED: &[&str] = &[
+    "mewtwo", "lugia", "hooh", "kyogre", "groudon", "rayquaza", "dialga", "dialgaorigin", "palkia",
+    "palkiaorigin", "giratina", "giratinaorigin", "reshiram", "zekrom", "kyuremblack", "kyuremwhite",
+    "solgaleo", "lunala", "necrozmaduskmane", "necrozmadawnwings", "zacian", "zaciancrowned", "zamazenta",
+    "zamazentacrowned", "eternatus", "calyrexice", "calyrexshadow", "koraidon", "miraidon", "terapagos",
+];
+
+const MYTHICAL: &[&str] = &[
+    "mew", "celebi", "jirachi", "deoxys", "phione", "manaphy", "darkrai", "shaymin", "arceus", "victini",
+    "keldeo", "meloetta", "genesect", "diancie", "hoopa", "volcanion", "magearna", "marshadow", "zeraora",
+    "meltan", "melmetal", "zarude", "pecharunt",
+];
+
+const OU_BANS: &[&str] = &[
+    "mewtwo", "lugia", "hooh", "kyogre", "groudon", "rayquaza", "dialga", "dialgaorigin", "palkia",
+    "palkiaorigin", "giratina", "giratinaorigin", "arceus", "reshiram", "zekrom", "kyuremblack", "kyuremwhite",
+    "solgaleo", "lunala", "necrozmaduskmane", "necrozmadawnwings", "magearna", "zacian", "zaciancrowned",
+    "zamazentacrowned", "eternatus", "calyrexice", "calyrexshadow", "koraidon", "miraidon", "chiyu", "chienpao",
+    "fluttermane", "ironbundle", "palafin", "espathra", "annihilape", "ursalunabloodmoon", "terapagos",
+];
+
+/// Every registered format.
+pub static FORMATS: &[Format] = &[
+    Format {
+        id: "gen9customgame",
+        name: "[Gen 9] Custom Game",
+        generation: 9,
+        game_type: GameType::Singles,
+        level: LevelRule::Any,
+        team_size: (1, 6),
+        bring: None,
+        team_preview: true,
+        random_teams: false,
+        inverse: false,
+        clauses: &[],
+        banned_species: &[],
+        banned_items: &[],
+        banned_abilities: &[],
+        banned_moves: &[],
+    },
+    Format {
+        id: "gen9ou",
+        name: "[Gen 9] OU",
+        generation: 9,
+        game_type: GameType::Singles,
+        level: LevelRule::Max(100),
+        team_size: (1, 6),
+        bring: None,
+        team_preview: true,
+        random_teams: false,
+        inverse: false,
+        clauses: &[
+            Clause::Species,
+            Clause::Ohko,
+            Clause::EvasionMoves,
+            Clause::EvasionItems,
+            Clause::EvasionAbilities,
+            Clause::Moody,
+        ],
+        banned_species: OU_BANS,
+        banned_items: &["kingsrock", "razorfang"],
+        banned_abilities: &["arenatrap", "shadowtag"],
+        banned_moves: &["batonpass", "lastrespects", "shedtail"],
+    },
+    Format {
+        id: "gen9bss",
+        name: "[Gen 9] Battle Stadium Singles",
+        generation: 9,
+        game_type: GameType::Singles,
+        level: LevelRule::AdjustDown(50),
+        team_size: (3, 6),
+        bring: Some(3),
+        team_preview: true,
+        random_teams: false,
+        inverse: false,
+        clauses: &[Clause::Species, Clause::Item],
+        banned_species: RESTRICTED,
+        banned_items: &[],
+        banned_abilities: &[],
+        banned_moves: &[],
+    },
+    Format {
+        id: "gen9vgc2025",
+        name: "[Gen 9] VGC 2025",
+        generation: 9,
+        game_type: GameType::Doubles,
+        level: LevelRule::Fixed(50),
+        team_size: (4, 6),
+        bring: Some(4),
+        team_preview: true,
+        random_teams: false,
+        inverse: false,
+        clauses: &[Clause::Species, Clause::Item],
+        banned_species: MYTHICAL,
+        banned_items: &[],
+        banned_abilities: &[],
+        banned_moves: &[],
+    },
+    Format {
+        id: "gen9randombattle",
+        name: "[Gen 9] Random Battle",
+        generation: 9,
+        game_type: GameType::Singles,
+        level: LevelRule::Table(&GEN9_RANDOM_BATTLE),
+        team_size: (6, 6),
+        bring: None,
+        team_preview: false,
+        random_teams: true,
+        inverse: false,
+        clauses: &[Clause::Species],
+        banned_species: &[],
+        banned_items: &[],
+        banned_abilities: &[],
+        banned_moves: &[],
+    },
+    Format {
+        id: "gen9randomdoublesbattle",
+        name: "[Gen 9] Random Doubles Battle",
+        generation: 9,
+        game_type: GameType::Doubles,
+        level: LevelRule::Table(&GEN9_RANDOM_DOUBLES),
+        team_size: (6, 6),
+        bring: None,
+        team_preview: false,
+        random_teams: true,
+        inverse: false,
+        clauses: &[Clause::Species],
+        banned_species: &[],
+        banned_items: &[],
+        banned_abilities: &[],
+        banned_moves: &[],
+    },
+    Format {
+        id: "gen91v1",
+        name: "[Gen 9] 1v1",
+        generation: 9,
+        game_type: GameType::Singles,
+        level: LevelRule::Max(100),
+        team_size: (1, 3),
+        bring: Some(1),
+        team_preview: true,
+        random_teams: false,
+        inverse: false,
+        clauses: &[Clause::Species, Clause::Ohko, Clause::EvasionMoves, Clause::Moody, Clause::Swagger],
+        banned_species: RESTRICTED,
+        banned_items: &["focussash", "kingsrock", "quickclaw", "razorfang"],
+        banned_abilities: &["moody"],
+        banned_moves: &["acupressure", "perishsong"],
+    },
+    Format {
+        id: "gen9inverse",
+        name: "[Gen 9] Inverse",
+        generation: 9,
+        game_type: GameType::Singles,
+        level: LevelRule::Max(100),
+        team_size: (1, 6),
+        bring: None,
+        team_preview: true,
+        random_teams: false,
+        inverse: true,
+        clauses: &[
+            Clause::Species,
+            Clause::Ohko,
+            Clause::EvasionMoves,
+            Clause::EvasionItems,
+            Clause::EvasionAbilities,
+            Clause::Moody,
+        ],
+        banned_species: RESTRICTED,
+        banned_items: &["kingsrock", "razorfang"],
+        banned_abilities: &["arenatrap", "shadowtag"],
+        banned_moves: &["batonpass", "lastrespects", "shedtail"],
+    },
+];
+
+/// The format with this id (`"[Gen 9] OU"` and `"gen9ou"` both work).
+pub fn format(id: &str) -> Option<&'static Format> {
+    let id = to_id(id);
+    FORMATS.iter().find(|format| format.id == id || to_id(format.name) == id)
+}
+
+/// Like [`format`], with an error listing the known ids.
+pub fn require_format(id: &str) -> Result<&'static Format> {
+    match format(id) {
+        Some(format) => Ok(format),
+        None => bail!(
+            "unknown format '{}' (known: {})",
+            id,
+            FORMATS.iter().map(|f| f.id).collect::<Vec<_>>().join(", ")
+        ),
+    }
+}
+
+/// Returned (inside `anyhow::Error`) when a team breaks a format's rules.
+#[derive(Clone, Debug)]
+pub struct FormatError {
+    pub format: &'static str,
+    pub problems: Vec<String>,
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "team is not legal in {} ({} problem(s))", self.format, self.problems.len())?;
+        for problem in &self.problems {
+            write!(f, "\n  {}", problem)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for FormatError {}
+
+impl Format {
+    /// Type chart of this format's generation, flipped in inverse formats.
+    pub fn type_chart(&self) -> TypeChart {
+        let chart = TypeChart::for_generation(self.generation);
+        if self.inverse {
+            chart.inverse()
+        } else {
+            chart
+        }
+    }
+
+    /// Pokémon brought to a battle from a team of `team_len`.
+    pub fn bring_count(&self, team_len: usize) -> usize {
+        self.bring.map_or(team_len, |bring| bring.min(team_len))
+    }
+
+    pub fn has_clause(&self, clause: Clause) -> bool {
+        self.clauses.contains(&clause)
+    }
+
+    /// Everything about `sets` that breaks this format's rules.
+    pub fn validate(&self, dex: &Dex, sets: &[PokemonSet]) -> Vec<String> {
+        let mut problems = Vec::new();
+        let (min, max) = self.team_size;
+        if sets.len() < min || sets.len() > max {
+            problems.push(if min == max {
+                format!("team has {} Pokémon; {} needs exactly {}", sets.len(), self.name, min)
+            } else {
+                format!("team has {} Pokémon; {} needs {} to {}", sets.len(), self.name, min, max)
+            });
+        }
+        let mut seen_species = HashSet::new();
+        let mut seen_items = HashSet::new();
+        for set in sets {
+            let species_id = to_id(&set.species);
+            let label = set.name();
+            if self.banned_species.contains(&species_id.as_str()) {
+                problems.push(format!("{} is banned", set.species));
+            }
+            if let LevelRule::Max(cap) = self.level {
+                if set.level > cap {
+                    problems.push(format!("{} is level {}; the cap is {}", label, set.level, cap));
+                }
+            }
+            if self.has_clause(Clause::Species) {
+                let num = dex.species(&set.species).map(|data| data.num);
+                if let Some(num) = num {
+                    if !seen_species.insert(num) {
+                        problems.push(format!("{}: Species Clause allows one {}", label, set.species));
+                    }
+                }
+            }
+            if let Some(item) = &set.item {
+                let item_id = to_id(item);
+                if self.banned_items.contains(&item_id.as_str()) {
+                    problems.push(format!("{}'s item {} is banned", label, item));
+                }
+                if self.has_clause(Clause::Item) && !seen_items.insert(item_id) {
+                    problems.push(format!("{}: Item Clause allows one {}", label, item));
+                }
+            }
+            if let Some(ability) = &set.ability {
+                if self.banned_abilities.contains(&to_id(ability).as_str()) {
+                    problems.push(format!("{}'s ability {} is banned", label, ability));
+                }
+            }
+            for name in &set.moves {
+                if self.banned_moves.contains(&to_id(name).as_str()) {
+                    problems.push(format!("{}'s move {} is banned", label, name));
+                }
+            }
+            for clause in self.clauses {
+                let (moves, items, abilities) = clause.bans();
+                let broken = set.moves.iter().find(|m| moves.contains(&to_id(m).as_str()))
+                    .or_else(|| set.item.as_ref().filter(|i| items.contains(&to_id(i).as_str())))
+                    .or_else(|| set.ability.as_ref().filter(|a| abilities.contains(&to_id(a).as_str())));
+                if let Some(name) = broken {
+                    problems.push(format!("{}: {} forbids {}", label, clause.name(), name));
+                }
+            }
+        }
+        problems
+    }
+
+    /// Fail with a [`FormatError`] unless `sets` is legal.
+    pub fn check(&self, dex: &Dex, sets: &[PokemonSet]) -> Result<()> {
+        let problems = self.validate(dex, sets);
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(FormatError { format: self.id, problems }.into())
+        }
+    }
+
+    /// Apply the level rule to team entries.
+    pub fn adjust_levels(&self, dex: &Dex, sets: &mut [PokemonSet]) {
+        for set in sets {
+            set.level = self.level.level_for(dex, &set.species, set.level);
+        }
+    }
+
+    /// Apply the level rule to built Pokémon, recomputing their stats. The team's own
+    /// levels stay in [`Pokemon::original_level`] for export.
+    pub fn adjust_team(&self, dex: &Dex, team: &mut [Pokemon]) -> Result<()> {
+        for pokemon in team {
+            let built = pokemon.original_level.unwrap_or(pokemon.level);
+            let level = self.level.level_for(dex, &pokemon.species, built);
+            pokemon.set_level_in(dex, level)?;
+        }
+        Ok(())
+    }
+
+    /// Validate a player-built team, adjust its levels and build the battle-ready Pokémon.
+    pub fn prepare_team(&self, dex: &Dex, sets: &[PokemonSet]) -> Result<Vec<Pokemon>> {
+        if self.random_teams {
+            bail!("{} uses generated teams", self.name);
+        }
+        self.check(dex, sets)?;
+        let mut team = sets
+            .iter()
+            .map(|set| Pokemon::from_set_in(dex, set).with_context(|| format!("Failed to build '{}'", set.species)))
+            .collect::<Result<Vec<_>>>()?;
+        self.adjust_team(dex, &mut team)?;
+        Ok(team)
+    }
+
+    /// Log the format header: game type, generation, tier, the level rule and Inverse Mod,
+    /// then every Pokémon whose level the rule changed.
+    pub fn log_start(&self, logger: &mut BattleLogger, team_a: &[Pokemon], team_b: &[Pokemon]) {
+        logger.log_header(self.game_type.id(), self.generation, self.name);
+        if let Some(rule) = self.level.description() {
+            logger.log_rule(&rule);
+        }
+        if self.inverse {
+            logger.log_rule("Inverse Mod: Weaknesses become resistances, while resistances and immunities become weaknesses.");
+        }
+        for (player, team) in [("p1", team_a), ("p2", team_b)] {
+            for pokemon in team {
+                if let Some(from) = pokemon.original_level {
+                    logger.log_message(&format!("{}'s {}: level {} → {}", player, pokemon.name, from, pokemon.level));
+                }
+            }
+        }
+    }
+
+    /// A battle between the brought Pokémon (leads first), using this format's type chart
+    /// and level rule. The battle log starts with [`Format::log_start`].
+    pub fn new_battle(&self, dex: Arc<Dex>, mut team_a: Vec<Pokemon>, mut team_b: Vec<Pokemon>) -> Result<BattleState> {
+        if self.game_type == GameType::Doubles {
+            bail!("{} is a doubles format; only singles battles are simulated", self.name);
+        }
+        if team_a.is_empty() || team_b.is_empty() {
+            bail!("each side must bring at least one Pokémon");
+        }
+        self.adjust_team(&dex, &mut team_a)?;
+        self.adjust_team(&dex, &mut team_b)?;
+        let mut logger = BattleLogger::new_with_format(self.id);
+        self.log_start(&mut logger, &team_a, &team_b);
+        let lead_a = team_a.remove(0);
+        let lead_b = team_b.remove(0);
+        let mut state = BattleState::new_with_bench(lead_a, lead_b, team_a, team_b)
+            .with_dex(dex)
+            .with_type_chart(self.type_chart());
+        state.logger = Some(logger);
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_showdown_sets;
+    use crate::sim::battle::{execute_turn, Action};
+    use crate::sim::stats::StatsSet;
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    fn sets(text: &str) -> Vec<PokemonSet> {
+        parse_showdown_sets(text).unwrap()
+    }
+
+    #[test]
+    fn looks_up_formats_by_id_or_name() {
+        assert_eq!(format("gen9ou").unwrap().name, "[Gen 9] OU");
+        assert_eq!(format("[Gen 9] Battle Stadium Singles").map(|f| f.id), Some("gen9bss"));
+        assert!(format(DEFAULT_FORMAT).is_some());
+        assert!(require_format("gen9ubers").unwrap_err().to_string().contains("gen9ou"));
+        let ids: HashSet<&str> = FORMATS.iter().map(|f| f.id).collect();
+        assert_eq!(ids.len(), FORMATS.len());
+        for format in FORMATS {
+            assert_eq!(format.id, to_id(format.id));
+            assert!(format.team_size.0 <= format.team_size.1);
+            assert!(format.bring.is_none_or(|bring| bring <= format.team_size.1));
+        }
+    }
+
+    #[test]
+    fn enforces_clauses_and_banlists() {
+        let dex = Dex::builtin();
+        let ou = format("gen9ou").unwrap();
+        let team = sets(
+            "Garchomp @ Leftovers\nAbility: Sand Veil\n- Earthquake\n- Double Team\n\n\
+             Garchomp @ King's Rock\nAbility: Rough Skin\n- Baton Pass\n- Fissure\n\n\
+             Miraidon\n- Electro Drift\n\nPikachu\n- Thunderbolt\n",
+        );
+        let mut team = team;
+        team[3].level = 101;
+        let problems = ou.validate(&dex, &team);
+        let has = |needle: &str| problems.iter().any(|p| p.contains(needle));
+        assert!(has("Evasion Abilities Clause forbids Sand Veil"));
+        assert!(has("Evasion Moves Clause forbids Double Team"));
+        assert!(has("Species Clause allows one Garchomp"));
+        assert!(has("item King's Rock is banned"));
+        assert!(has("move Baton Pass is banned"));
+        assert!(has("OHKO Clause forbids Fissure"));
+        assert!(has("Miraidon is banned"));
+        assert!(has("level 101; the cap is 100"));
+        assert!(ou.check(&dex, &team).unwrap_err().downcast_ref::<FormatError>().is_some());
+
+        let bss = format("gen9bss").unwrap();
+        let items = sets("Garchomp @ Life Orb\n- Earthquake\n\nGyarados @ Life Orb\n- Waterfall\n\nPikachu\n- Thunderbolt\n");
+        assert_eq!(bss.validate(&dex, &items), vec!["Gyarados: Item Clause allows one Life Orb".to_string()]);
+        assert!(bss.validate(&dex, &items[..2])[0].contains("needs 3 to 6"));
+    }
+
+    #[test]
+    fn levels_team_preview_and_battles() {
+        let dex = Dex::builtin();
+        let mut team = sets("Garchomp\nLevel: 100\n- Earthquake\n\nPikachu\nLevel: 5\n- Thunderbolt\n\nGyarados\n- Waterfall\n");
+        let bss = format("gen9bss").unwrap();
+        let pokemon = bss.prepare_team(&dex, &team).unwrap();
+        assert_eq!(pokemon.iter().map(|p| p.level).collect::<Vec<_>>(), vec![50, 5, 50]);
+        assert_eq!(bss.bring_count(6), 3);
+        assert_eq!(format("gen9ou").unwrap().bring_count(4), 4);
+
+        format("gen9vgc2025").unwrap().adjust_levels(&dex, &mut team);
+        assert!(team.iter().all(|set| set.level == 50));
+        let vgc = format("gen9vgc2025").unwrap();
+        assert!(vgc.new_battle(dex.clone(), pokemon.clone(), pokemon.clone()).is_err());
+        assert!(format("gen9randombattle").unwrap().prepare_team(&dex, &team).is_err());
+
+        let state = bss.new_battle(dex.clone(), pokemon.clone(), pokemon).unwrap();
+        assert_eq!(state.bench_a.len(), 2);
+        assert_eq!(state.type_chart, TypeChart::GEN6);
+    }
+
+    #[test]
+    fn level_rules_recompute_stats_and_keep_the_team_level() {
+        let dex = Dex::builtin();
+        let team = sets("Garchomp\nLevel: 100\nEVs: 252 Atk\n- Earthquake\n\nPikachu\nLevel: 5\n- Thunderbolt\n\nGyarados\n- Waterfall\n");
+        let bss = format("gen9bss").unwrap();
+        let pokemon = bss.prepare_team(&dex, &team).unwrap();
+        let garchomp = &pokemon[0];
+        assert_eq!((garchomp.level, garchomp.original_level), (50, Some(100)));
+        let expected = StatsSet::from_species("Garchomp", 50, garchomp.evs, garchomp.ivs, garchomp.nature).unwrap();
+        assert_eq!(garchomp.stats, expected);
+        assert_eq!(garchomp.current_hp, expected.hp);
+        assert_eq!(garchomp.to_set().level, 100);
+        assert_eq!(pokemon[1].original_level, None);
+
+        let random = format("gen9randombattle").unwrap();
+        let mut team = pokemon.clone();
+        random.adjust_team(&dex, &mut team).unwrap();
+        assert_eq!(team.iter().map(|p| p.level).collect::<Vec<_>>(), vec![76, 93, 79]);
+        assert_eq!(team[0].original_level, Some(100));
+        assert_eq!(team[1].original_level, Some(5));
+        assert_eq!(team[2].to_set().level, 50);
+
+        let state = bss.new_battle(dex.clone(), pokemon.clone(), pokemon).unwrap();
+        let log = state.logger.as_ref().unwrap().log_lines();
+        assert_eq!(&log[..3], ["|gametype|singles", "|gen|9", "|tier|[Gen 9] Battle Stadium Singles"]);
+        assert!(log[3].starts_with("|rule|Adjust Level Down"));
+        assert!(log.contains(&"|-message|p1's Garchomp: level 100 → 50".to_string()));
+        assert_eq!(log.iter().filter(|line| line.starts_with("|-message|")).count(), 2);
+        assert!(format("gen9ou").unwrap().level.description().is_none());
+    }
+
+    #[test]
+    fn inverse_format_flips_matchups_in_battle() {
+        let dex = Dex::builtin();
+        let inverse = format("gen9inverse").unwrap();
+        assert_eq!(inverse.type_chart(), TypeChart::GEN6.inverse());
+        let pikachu = inverse.prepare_team(&dex, &sets("Pikachu\n- Thunderbolt\n")).unwrap();
+        let garchomp = inverse.prepare_team(&dex, &sets("Garchomp\n- Swords Dance\n")).unwrap();
+        let mut rng = SmallRng::seed_from_u64(1);
+
+        let mut state = inverse.new_battle(dex.clone(), pikachu.clone(), garchomp.clone()).unwrap();
+        assert!(state.type_chart.inverse);
+        let log = state.logger.as_ref().unwrap().log_lines();
+        assert!(log.iter().any(|line| line.starts_with("|rule|Inverse Mod: ")));
+        execute_turn(&mut state, Action::Move(0), Action::Move(0), &mut rng);
+        assert!(state.pokemon_b.current_hp < state.pokemon_b.stats.hp, "Ground is weak to Electric");
+
+        let mut state = format("gen9ou").unwrap().new_battle(dex, pikachu, garchomp).unwrap();
+        assert!(!state.logger.as_ref().unwrap().log_lines().iter().any(|line| line.contains("Inverse")));
+        execute_turn(&mut state, Action::Move(0), Action::Move(0), &mut rng);
+        assert_eq!(state.pokemon_b.current_hp, state.pokemon_b.stats.hp);
+    }
+
+    #[test]
+    fn bundled_teams_are_legal_in_their_formats() {
+        let dex = Dex::builtin();
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../teams");
+        let library = crate::teams::TeamLibrary::load_dir(&dex, dir, Default::default()).unwrap();
+        for team in library.iter() {
+            let format = require_format(team.meta.format.as_deref().unwrap_or(DEFAULT_FORMAT)).unwrap();
+            assert_eq!(format.validate(&dex, &team.sets), Vec::<String>::new(), "{}", team.meta.name);
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! Per-species levels for random formats.
+//!
+//! Random battles balance teams by level instead of by team building: strong Pokémon
+//! battle at a lower level than weak ones (Showdown keeps these in
+//! `data/random-battles/<format>/sets.json`). Species missing from a table get a level
+//! from their base stat total.
+
+use crate::dex::{to_id, Dex};
+
+/// Levels for one random format, keyed by species id.
+#[derive(Debug, Eq, PartialEq)]
+pub struct LevelTable {
+    pub format: &'static str,
+    /// `(species id, level)`, sorted by id.
+    pub levels: &'static [(&'static str, u8)],
+    /// Levels by base stat total for unlisted species: the first `(min_bst, level)` the
+    /// species reaches, or the last level.
+    pub by_bst: &'static [(u16, u8)],
+}
+
+impl LevelTable {
+    /// Listed level of `species`, if any.
+    pub fn listed(&self, species: &str) -> Option<u8> {
+        let id = to_id(species);
+        self.levels
+            .binary_search_by(|(entry, _)| (*entry).cmp(id.as_str()))
+            .ok()
+            .map(|idx| self.levels[idx].1)
+    }
+
+    /// Level `species` battles at in this format.
+    pub fn level(&self, dex: &Dex, species: &str) -> u8 {
+        if let Some(level) = self.listed(species) {
+            return level;
+        }
+        let bst = dex.species(species).map_or(0, |data| {
+            let base = data.base_stats;
+            [base.hp, base.atk, base.def, base.spa, base.spd, base.spe].iter().map(|&s| s as u16).sum()
+        });
+        self.by_bst
+            .iter()
+            .find(|(min, _)| bst >= *min)
+            .or(self.by_bst.last())
+            .map_or(100, |(_, level)| *level)
+    }
+}
+
+const SINGLES_BY_BST: &[(u16, u8)] = &[(670, 72), (600, 76), (535, 80), (490, 84), (440, 88), (0, 93)];
+const DOUBLES_BY_BST: &[(u16, u8)] = &[(670, 74), (600, 78), (535, 82), (490, 86), (440, 90), (0, 95)];
+
+pub static GEN9_RANDOM_BATTLE: LevelTable = LevelTable {
+    format: "gen9randombattle",
+    levels: &[
+        ("alakazam", 83),
+        ("annihilape", 76),
+        ("arcanine", 83),
+        ("baxcalibur", 76),
+        ("blaziken", 79),
+        ("blissey", 85),
+        ("cinderace", 77),
+        ("clefable", 82),
+        ("corviknight", 80),
+        ("dondozo", 79),
+        ("dragapult", 75),
+        ("dragonite", 74),
+        ("espathra", 80),
+        ("fluttermane", 76),
+        ("garchomp", 76),
+        ("gardevoir", 85),
+        ("gengar", 80),
+        ("gholdengo", 75),
+        ("glimmora", 78),
+        ("gliscor", 78),
+        ("greattusk", 75),
+        ("gyarados", 79),
+        ("heatran", 79),
+        ("hydreigon", 80),
+        ("ironbundle", 77),
+        ("ironvaliant", 77),
+        ("kingambit", 74),
+        ("koraidon", 64),
+        ("landorustherian", 76),
+        ("lucario", 86),
+        ("magikarp", 100),
+        ("meowscarada", 77),
+        ("milotic", 86),
+        ("mimikyu", 79),
+        ("miraidon", 64),
+        ("pikachu", 93),
+        ("rotomwash", 82),
+        ("salamence", 77),
+        ("scizor", 80),
+        ("skeledirge", 79),
+        ("slowbro", 84),
+        ("snorlax", 84),
+        ("tinglu", 78),
+        ("togekiss", 82),
+        ("toxapex", 83),
+        ("tyranitar", 79),
+        ("volcarona", 76),
+        ("zamazenta", 70),
+    ],
+    by_bst: SINGLES_BY_BST,
+};
+
+pub static GEN9_RANDOM_DOUBLES: LevelTable = LevelTable {
+    format: "gen9randomdoublesbattle",
+    levels: &[
+        ("amoonguss", 84),
+        ("arcanine", 84),
+        ("calyrexshadow", 68),
+        ("dragonite", 78),
+        ("fluttermane", 77),
+        ("garchomp", 80),
+        ("gholdengo", 78),
+        ("gyarados", 82),
+        ("incineroar", 80),
+        ("indeedeef", 84),
+        ("ironhands", 78),
+        ("kingambit", 78),
+        ("koraidon", 66),
+        ("miraidon", 66),
+        ("pikachu", 95),
+        ("rillaboom", 80),
+        ("tornadus", 82),
+        ("urshifurapidstrike", 76),
+    ],
+    by_bst: DOUBLES_BY_BST,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tables_are_sorted_ids() {
+        for table in [&GEN9_RANDOM_BATTLE, &GEN9_RANDOM_DOUBLES] {
+            for pair in table.levels.windows(2) {
+                assert!(pair[0].0 < pair[1].0, "{}: {} before {}", table.format, pair[0].0, pair[1].0);
+            }
+            for (id, level) in table.levels {
+                assert_eq!(*id, to_id(id), "{}", table.format);
+                assert!((1..=100).contains(level));
+            }
+        }
+    }
+
+    #[test]
+    fn unlisted_species_scale_with_base_stats() {
+        let dex = Dex::builtin();
+        assert_eq!(GEN9_RANDOM_BATTLE.level(&dex, "Miraidon"), 64);
+        assert_eq!(GEN9_RANDOM_BATTLE.listed("Iron Bundle"), Some(77));
+        assert_eq!(GEN9_RANDOM_BATTLE.listed("Rattata"), None);
+        assert_eq!(GEN9_RANDOM_BATTLE.level(&dex, "Rattata"), 93);
+        assert_eq!(GEN9_RANDOM_BATTLE.level(&dex, "Mewtwo"), 72);
+        assert_eq!(GEN9_RANDOM_DOUBLES.level(&dex, "Mewtwo"), 74);
+    }
+}
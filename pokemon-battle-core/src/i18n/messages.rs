@@ -0,0 +1,359 @@
+// バトル実況のメッセージカタログ
+//
+// 各ロケールは translations/messages/<code>.json の形式:
+//   { "locale": "ja", "language": "日本語", "names": "ja", "messages": { "fainted": "{name}はたおれた！", ... } }
+// テンプレート中の `{param}` は `render` に渡した値で置き換える。
+// 英語 (en) と日本語 (ja) は組み込み。`load_locale_file` でディスク上の追加ロケールを登録できる。
+use super::{
+    normalize_key, translate_ability, translate_item, translate_move, translate_pokemon,
+};
+use crate::dex::Dex;
+use crate::sim::pokemon::Status;
+use anyhow::{anyhow, bail, Context, Result};
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+/// 既定のロケール（従来どおり日本語で実況する）。
+pub const DEFAULT_LOCALE: &str = "ja";
+/// テンプレートが見つからないときに参照するロケール。
+pub const FALLBACK_LOCALE: &str = "en";
+
+/// ポケモン・技・道具・特性の名前をどの言語で表示するか。
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+pub enum NameSet {
+    #[serde(rename = "ja")]
+    Japanese,
+    #[default]
+    #[serde(rename = "en")]
+    English,
+}
+
+/// 1 ロケール分のメッセージカタログ。
+#[derive(Clone, Debug, Deserialize)]
+pub struct Catalog {
+    pub locale: String,
+    #[serde(default)]
+    pub language: String,
+    #[serde(default)]
+    pub names: NameSet,
+    pub messages: HashMap<String, String>,
+}
+
+impl Catalog {
+    pub fn from_json(text: &str) -> Result<Self> {
+        let catalog: Catalog = serde_json::from_str(text)?;
+        if catalog.locale.trim().is_empty() {
+            bail!("locale catalog has an empty \"locale\" code");
+        }
+        Ok(catalog)
+    }
+
+    /// `key` のテンプレート（このカタログに無ければ None）。
+    pub fn template(&self, key: &str) -> Option<&str> {
+        self.messages.get(key).map(String::as_str)
+    }
+}
+
+fn builtin(code: &str, text: &str) -> Arc<Catalog> {
+    Arc::new(
+        Catalog::from_json(text)
+            .unwrap_or_else(|err| panic!("Failed to parse translations/messages/{}.json: {}", code, err)),
+    )
+}
+
+static CATALOGS: Lazy<RwLock<HashMap<String, Arc<Catalog>>>> = Lazy::new(|| {
+    let mut catalogs = HashMap::new();
+    catalogs.insert(
+        "ja".to_string(),
+        builtin("ja", include_str!("../../../translations/messages/ja.json")),
+    );
+    catalogs.insert(
+        "en".to_string(),
+        builtin("en", include_str!("../../../translations/messages/en.json")),
+    );
+    RwLock::new(catalogs)
+});
+
+static CURRENT: Lazy<RwLock<Arc<Catalog>>> = Lazy::new(|| RwLock::new(catalog(DEFAULT_LOCALE).expect("builtin locale")));
+
+/// 登録済みのロケール `code` のカタログ。
+pub fn catalog(code: &str) -> Option<Arc<Catalog>> {
+    CATALOGS.read().unwrap().get(&code.to_ascii_lowercase()).cloned()
+}
+
+/// 登録済みロケールのコード一覧（ソート済み）。
+pub fn available_locales() -> Vec<String> {
+    let mut codes: Vec<String> = CATALOGS.read().unwrap().keys().cloned().collect();
+    codes.sort();
+    codes
+}
+
+/// カタログを登録する（同じコードの既存カタログは置き換える）。登録したコードを返す。
+pub fn register_catalog(catalog: Catalog) -> String {
+    let code = catalog.locale.to_ascii_lowercase();
+    CATALOGS.write().unwrap().insert(code.clone(), Arc::new(catalog));
+    code
+}
+
+/// ディスク上のロケールファイルを読み込んで登録する。登録したコードを返す。
+pub fn load_locale_file(path: impl AsRef<Path>) -> Result<String> {
+    let path = path.as_ref();
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read locale file {}", path.display()))?;
+    let catalog = Catalog::from_json(&text)
+        .with_context(|| format!("failed to parse locale file {}", path.display()))?;
+    Ok(register_catalog(catalog))
+}
+
+/// 実況に使うロケールを切り替える。
+pub fn set_locale(code: &str) -> Result<()> {
+    let selected = catalog(code).ok_or_else(|| {
+        anyhow!("unknown locale '{}' (available: {})", code, available_locales().join(", "))
+    })?;
+    *CURRENT.write().unwrap() = selected;
+    Ok(())
+}
+
+/// 現在のロケールのコード。
+pub fn current_locale() -> String {
+    CURRENT.read().unwrap().locale.clone()
+}
+
+fn current() -> Arc<Catalog> {
+    CURRENT.read().unwrap().clone()
+}
+
+/// 現在のロケールで `key` のメッセージを組み立てる。
+pub fn render(key: &str, args: &[(&str, &dyn Display)]) -> String {
+    render_with(&current(), key, args)
+}
+
+/// ロケール `code` で `key` のメッセージを組み立てる（未登録のロケールなら既定ロケール）。
+pub fn render_in(code: &str, key: &str, args: &[(&str, &dyn Display)]) -> String {
+    let selected = catalog(code).unwrap_or_else(current);
+    render_with(&selected, key, args)
+}
+
+// テンプレートが無ければ英語カタログ、それも無ければキー自体を使う。
+fn render_with(catalog: &Catalog, key: &str, args: &[(&str, &dyn Display)]) -> String {
+    let fallback = self::catalog(FALLBACK_LOCALE);
+    let template = catalog
+        .template(key)
+        .or_else(|| fallback.as_deref().and_then(|c| c.template(key)))
+        .unwrap_or(key);
+    fill(template, args)
+}
+
+fn fill(template: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let value = after
+            .find('}')
+            .and_then(|end| args.iter().find(|(name, _)| *name == &after[..end]).map(|arg| (end, arg.1)));
+        match value {
+            Some((end, value)) => {
+                out.push_str(&value.to_string());
+                rest = &after[end + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// 状態異常になったときのメッセージキー。
+pub fn status_key(status: Status) -> &'static str {
+    match status {
+        Status::Burn => "status_burn",
+        Status::Paralysis => "status_paralysis",
+        Status::Poison => "status_poison",
+        Status::Sleep => "status_sleep",
+        Status::Freeze => "status_freeze",
+        Status::Flinch => "status_flinch",
+    }
+}
+
+fn localized(
+    name: &str,
+    japanese: fn(&str) -> String,
+    english: impl Fn(&str) -> Option<&'static str>,
+) -> String {
+    match current().names {
+        NameSet::Japanese => japanese(name),
+        NameSet::English => english(&normalize_key(name))
+            .map(str::to_string)
+            .unwrap_or_else(|| name.to_string()),
+    }
+}
+
+/// 現在のロケールでのポケモン名。英語名は `dex`（データMODを含む）から引く。
+pub fn pokemon_name(dex: &Dex, name: &str) -> String {
+    localized(name, translate_pokemon, |id| dex.species(id).map(|s| s.name))
+}
+
+/// 現在のロケールでの技名。
+pub fn move_name(dex: &Dex, name: &str) -> String {
+    localized(name, translate_move, |id| dex.get_move(id).map(|m| m.name))
+}
+
+/// 現在のロケールでの道具名。
+pub fn item_name(dex: &Dex, name: &str) -> String {
+    localized(name, translate_item, |id| dex.item(id).map(|i| i.name))
+}
+
+/// 現在のロケールでの特性名。
+pub fn ability_name(dex: &Dex, name: &str) -> String {
+    localized(name, translate_ability, |id| dex.ability(id).map(|a| a.name))
+}
+
+thread_local! {
+    static MUTED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// このスレッドで実況が止められているか。
+pub fn narration_muted() -> bool {
+    MUTED.with(Cell::get)
+}
+
+/// 実況を出力せずに `f` を実行する（探索 AI の試行ターンなど）。スレッドごとに有効。
+pub fn silently<T>(f: impl FnOnce() -> T) -> T {
+    /// `f` がパニックしても元の状態に戻す。
+    struct Restore(bool);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            MUTED.with(|muted| muted.set(self.0));
+        }
+    }
+
+    let _restore = Restore(MUTED.with(|muted| muted.replace(true)));
+    f()
+}
+
+/// 現在のロケールでメッセージを 1 行（字下げ付き）出力する。
+///
+/// `narrate!("fainted", name = defender_ja)` のようにキーと引数を渡す。
+/// [`silently`] の中では何も出力しない。
+macro_rules! narrate {
+    ($key:expr $(, $name:ident = $value:expr)* $(,)?) => {
+        if !$crate::i18n::messages::narration_muted() {
+            println!(
+                "  {}",
+                $crate::i18n::messages::render(
+                    $key,
+                    &[$((stringify!($name), &$value as &dyn std::fmt::Display)),*]
+                )
+            )
+        }
+    };
+}
+pub(crate) use narrate;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn placeholders(template: &str) -> Vec<String> {
+        let mut names: Vec<String> = template
+            .split('{')
+            .skip(1)
+            .filter_map(|part| part.split_once('}').map(|(name, _)| name.to_string()))
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn silently_unmutes_after_a_panic() {
+        let caught = std::panic::catch_unwind(|| silently(|| panic!("boom")));
+        assert!(caught.is_err());
+        assert!(!narration_muted());
+        assert!(silently(|| silently(narration_muted)));
+        assert!(!narration_muted());
+    }
+
+    #[test]
+    fn renders_templates_per_locale() {
+        let name = "ガブリアス";
+        assert_eq!(render_in("ja", "fainted", &[("name", &name)]), "ガブリアスはたおれた！");
+        assert_eq!(render_in("en", "fainted", &[("name", &"Garchomp")]), "Garchomp fainted!");
+        assert_eq!(
+            render_in("en", "damage", &[("name", &"Garchomp"), ("damage", &42), ("hp", &141), ("max_hp", &183)]),
+            "Garchomp took 42 damage! (HP: 141/183)"
+        );
+    }
+
+    #[test]
+    fn missing_keys_and_params_fall_back() {
+        let partial = Catalog::from_json(r#"{"locale": "xx-test", "messages": {"fainted": "{name} KO {who}"}}"#).unwrap();
+        register_catalog(partial);
+        assert_eq!(render_in("xx-test", "fainted", &[("name", &"A")]), "A KO {who}");
+        assert_eq!(render_in("xx-test", "critical_hit", &[]), "A critical hit!");
+        assert_eq!(render_in("xx-test", "no_such_key", &[]), "no_such_key");
+        assert_eq!(catalog("xx-test").unwrap().names, NameSet::English);
+        assert!(Catalog::from_json(r#"{"locale": " ", "messages": {}}"#).is_err());
+    }
+
+    #[test]
+    fn loads_locale_files_from_disk() {
+        let path = std::env::temp_dir().join(format!("pokemon-locale-{}.json", std::process::id()));
+        std::fs::write(&path, r#"{"locale": "de-test", "language": "Deutsch", "messages": {"fainted": "{name} wurde besiegt!"}}"#).unwrap();
+        let code = load_locale_file(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(code, "de-test");
+        assert!(available_locales().contains(&code));
+        assert_eq!(render_in(&code, "fainted", &[("name", &"Glurak")]), "Glurak wurde besiegt!");
+        assert!(load_locale_file("/nonexistent/locale.json").is_err());
+        assert!(set_locale("no-such-locale").is_err());
+    }
+
+    #[test]
+    fn builtin_catalogs_share_keys_and_parameters() {
+        let ja = catalog("ja").unwrap();
+        let en = catalog("en").unwrap();
+        assert_eq!(ja.names, NameSet::Japanese);
+        assert_eq!(en.names, NameSet::English);
+        let mut ja_keys: Vec<&String> = ja.messages.keys().collect();
+        let mut en_keys: Vec<&String> = en.messages.keys().collect();
+        ja_keys.sort();
+        en_keys.sort();
+        assert_eq!(ja_keys, en_keys);
+        for (key, template) in &ja.messages {
+            assert_eq!(placeholders(template), placeholders(&en.messages[key]), "parameters of '{}'", key);
+        }
+    }
+
+    #[test]
+    fn narration_keys_exist_in_catalogs() {
+        let sources = [
+            include_str!("../sim/battle.rs"),
+            include_str!("../sim/moves/status.rs"),
+            include_str!("../sim/abilities/misc_abilities.rs"),
+        ];
+        let en = catalog("en").unwrap();
+        let mut used = 0;
+        for source in sources {
+            for call in source.split("narrate!(\"").skip(1) {
+                let key = call.split('"').next().unwrap();
+                assert!(en.template(key).is_some(), "missing message '{}'", key);
+                used += 1;
+            }
+        }
+        assert!(used > 50);
+        for status in [Status::Burn, Status::Paralysis, Status::Poison, Status::Sleep, Status::Freeze, Status::Flinch] {
+            assert!(en.template(status_key(status)).is_some());
+        }
+    }
+}
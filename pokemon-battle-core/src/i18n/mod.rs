@@ -0,0 +1,289 @@
+// 日本語翻訳モジュール
+pub mod messages;
+
+use crate::dex::Dex;
+use crate::sim::pokemon::{parse_type, type_name};
+use crate::sim::stats::Nature;
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Deserialize)]
+struct Translations {
+    pokemon: HashMap<String, String>,
+    moves: HashMap<String, String>,
+    items: HashMap<String, String>,
+    abilities: HashMap<String, String>,
+    natures: HashMap<String, String>,
+    types: HashMap<String, String>,
+}
+
+static TRANSLATIONS: Lazy<Translations> = Lazy::new(|| {
+    let json_str = include_str!("../../../translations/ja.json");
+    serde_json::from_str(json_str).expect("Failed to parse translations/ja.json")
+});
+
+fn normalize_key(name: &str) -> String {
+    name.to_ascii_lowercase()
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect()
+}
+
+pub fn translate_pokemon(name: &str) -> String {
+    let key = normalize_key(name);
+    TRANSLATIONS
+        .pokemon
+        .get(&key)
+        .cloned()
+        .unwrap_or_else(|| name.to_string())
+}
+
+pub fn translate_move(name: &str) -> String {
+    let key = normalize_key(name);
+    TRANSLATIONS
+        .moves
+        .get(&key)
+        .cloned()
+        .unwrap_or_else(|| name.to_string())
+}
+
+pub fn translate_item(name: &str) -> String {
+    let key = normalize_key(name);
+    TRANSLATIONS
+        .items
+        .get(&key)
+        .cloned()
+        .unwrap_or_else(|| name.to_string())
+}
+
+pub fn translate_ability(name: &str) -> String {
+    let key = normalize_key(name);
+    TRANSLATIONS
+        .abilities
+        .get(&key)
+        .cloned()
+        .unwrap_or_else(|| name.to_string())
+}
+
+pub fn translate_nature(name: &str) -> String {
+    let key = normalize_key(name);
+    TRANSLATIONS
+        .natures
+        .get(&key)
+        .cloned()
+        .unwrap_or_else(|| name.to_string())
+}
+
+pub fn translate_type(name: &str) -> String {
+    let key = normalize_key(name);
+    TRANSLATIONS
+        .types
+        .get(&key)
+        .cloned()
+        .unwrap_or_else(|| name.to_string())
+}
+
+// 日本語名 → 英語名の逆引き（translations/ja.json から構築）
+struct ReverseIndex {
+    pokemon: HashMap<String, String>,
+    moves: HashMap<String, String>,
+    items: HashMap<String, String>,
+    abilities: HashMap<String, String>,
+    natures: HashMap<String, String>,
+    types: HashMap<String, String>,
+}
+
+static REVERSE: Lazy<ReverseIndex> = Lazy::new(|| {
+    let invert = |table: &HashMap<String, String>| -> HashMap<String, String> {
+        table.iter().map(|(id, ja)| (normalize_japanese(ja), id.clone())).collect()
+    };
+    ReverseIndex {
+        pokemon: invert(&TRANSLATIONS.pokemon),
+        moves: invert(&TRANSLATIONS.moves),
+        items: invert(&TRANSLATIONS.items),
+        abilities: invert(&TRANSLATIONS.abilities),
+        natures: invert(&TRANSLATIONS.natures),
+        types: invert(&TRANSLATIONS.types),
+    }
+});
+
+/// 半角カナ（U+FF61〜U+FF9D）に対応する全角文字。濁点・半濁点は別処理。
+const HALFWIDTH_KANA: &str = "。「」、・ヲァィゥェォャュョッーアイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワン";
+
+/// 全角英数字・記号と全角スペースを半角にする（文字数は変わらない）。
+pub fn fold_width(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+            '\u{3000}' => ' ',
+            _ => c,
+        })
+        .collect()
+}
+
+/// 逆引き用のキー: 全角/半角と ひらがな/カタカナ の違い、大文字小文字、空白を無視する。
+pub fn normalize_japanese(text: &str) -> String {
+    let mut out: Vec<char> = Vec::new();
+    for c in fold_width(text).chars() {
+        let c = match c {
+            '\u{FF61}'..='\u{FF9D}' => HALFWIDTH_KANA.chars().nth(c as usize - 0xFF61).unwrap_or(c),
+            // ひらがな → カタカナ
+            '\u{3041}'..='\u{3096}' => char::from_u32(c as u32 + 0x60).unwrap_or(c),
+            _ => c,
+        };
+        match c {
+            // 濁点（半角・全角・結合文字）を直前のカナに合成する
+            '\u{FF9E}' | '\u{309B}' | '\u{3099}' => {
+                if let Some(last) = out.last_mut() {
+                    *last = voiced(*last, 1).unwrap_or(*last);
+                }
+            }
+            '\u{FF9F}' | '\u{309C}' | '\u{309A}' => {
+                if let Some(last) = out.last_mut() {
+                    *last = voiced(*last, 2).unwrap_or(*last);
+                }
+            }
+            c if c.is_whitespace() => {}
+            c => out.extend(c.to_lowercase()),
+        }
+    }
+    out.into_iter().collect()
+}
+
+/// カナに濁点（offset 1）・半濁点（offset 2）を付けた文字。
+fn voiced(base: char, offset: u32) -> Option<char> {
+    let code = base as u32;
+    let ok = match offset {
+        1 => matches!(base, 'カ' | 'キ' | 'ク' | 'ケ' | 'コ' | 'サ' | 'シ' | 'ス' | 'セ' | 'ソ' | 'タ' | 'チ' | 'ツ' | 'テ' | 'ト' | 'ハ' | 'ヒ' | 'フ' | 'ヘ' | 'ホ'),
+        _ => matches!(base, 'ハ' | 'ヒ' | 'フ' | 'ヘ' | 'ホ'),
+    };
+    if base == 'ウ' && offset == 1 {
+        return Some('ヴ');
+    }
+    ok.then(|| char::from_u32(code + offset)).flatten()
+}
+
+fn reverse_lookup(table: &HashMap<String, String>, name: &str) -> Option<String> {
+    table.get(&normalize_japanese(name)).cloned()
+}
+
+/// 日本語のポケモン名 → 英語名（"がぶりあす" → "Garchomp"）。英語名は `dex` から引く。
+pub fn pokemon_from_japanese(dex: &Dex, name: &str) -> Option<&'static str> {
+    let id = reverse_lookup(&REVERSE.pokemon, name)?;
+    dex.species(&id).map(|data| data.name)
+}
+
+/// 日本語の技名 → 英語名。
+pub fn move_from_japanese(dex: &Dex, name: &str) -> Option<&'static str> {
+    let id = reverse_lookup(&REVERSE.moves, name)?;
+    dex.get_move(&id).map(|data| data.name)
+}
+
+/// 日本語の道具名 → 英語名。
+pub fn item_from_japanese(dex: &Dex, name: &str) -> Option<&'static str> {
+    let id = reverse_lookup(&REVERSE.items, name)?;
+    dex.item(&id).map(|data| data.name)
+}
+
+/// 日本語の特性名 → 英語名。
+pub fn ability_from_japanese(dex: &Dex, name: &str) -> Option<&'static str> {
+    let id = reverse_lookup(&REVERSE.abilities, name)?;
+    dex.ability(&id).map(|data| data.name)
+}
+
+/// 日本語の性格名 → 英語名（"ようき" → "Jolly"）。
+pub fn nature_from_japanese(name: &str) -> Option<&'static str> {
+    let id = reverse_lookup(&REVERSE.natures, name)?;
+    Nature::from_name(&id).map(Nature::name)
+}
+
+/// 日本語のタイプ名 → 英語名（"ほのお" → "Fire"）。
+pub fn type_from_japanese(name: &str) -> Option<&'static str> {
+    let id = reverse_lookup(&REVERSE.types, name)?;
+    parse_type(&id).map(type_name)
+}
+
+// 技・道具・特性の日本語説明文（translations/ja_descriptions.json）
+#[derive(Deserialize)]
+struct Descriptions {
+    moves: HashMap<String, String>,
+    items: HashMap<String, String>,
+    abilities: HashMap<String, String>,
+}
+
+static DESCRIPTIONS: Lazy<Descriptions> = Lazy::new(|| {
+    let json_str = include_str!("../../../translations/ja_descriptions.json");
+    serde_json::from_str(json_str).expect("Failed to parse translations/ja_descriptions.json")
+});
+
+/// 技の日本語説明。未翻訳なら None（英語の説明は `MoveData::description`）。
+pub fn describe_move(name: &str) -> Option<String> {
+    DESCRIPTIONS.moves.get(&normalize_key(name)).cloned()
+}
+
+/// 道具の日本語説明。未翻訳なら None。
+pub fn describe_item(name: &str) -> Option<String> {
+    DESCRIPTIONS.items.get(&normalize_key(name)).cloned()
+}
+
+/// 特性の日本語説明。未翻訳なら None。
+pub fn describe_ability(name: &str) -> Option<String> {
+    DESCRIPTIONS.abilities.get(&normalize_key(name)).cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn descriptions_cover_translated_names() {
+        for name in TRANSLATIONS.moves.keys() {
+            assert!(describe_move(name).is_some(), "missing move description: {}", name);
+        }
+        for name in TRANSLATIONS.items.keys() {
+            assert!(describe_item(name).is_some(), "missing item description: {}", name);
+        }
+        for name in TRANSLATIONS.abilities.keys() {
+            assert!(describe_ability(name).is_some(), "missing ability description: {}", name);
+        }
+    }
+
+    #[test]
+    fn description_lookup_normalizes_names() {
+        assert!(describe_move("U-turn").is_some());
+        assert!(describe_item("Life Orb").is_some());
+        assert!(describe_ability("Rough Skin").is_some());
+        assert_eq!(describe_move("Splash"), None);
+    }
+
+    #[test]
+    fn normalization_ignores_width_and_kana_kind() {
+        assert_eq!(normalize_japanese("ｶﾞﾌﾞﾘｱｽ"), normalize_japanese("ガブリアス"));
+        assert_eq!(normalize_japanese("がぶりあす"), "ガブリアス");
+        assert_eq!(normalize_japanese("ﾊﾟﾜﾌﾙﾊｰﾌﾞ"), "パワフルハーブ");
+        assert_eq!(normalize_japanese("１０まんボルト"), normalize_japanese("10まんぼると"));
+        assert_eq!(fold_width("Ｌｖ：５０"), "Lv:50");
+    }
+
+    #[test]
+    fn reverse_lookups_return_english_names() {
+        let dex = Dex::builtin();
+        assert_eq!(pokemon_from_japanese(&dex, "がぶりあす"), Some("Garchomp"));
+        assert_eq!(move_from_japanese(&dex, "ジシン"), Some("Earthquake"));
+        assert_eq!(item_from_japanese(&dex, "ｺﾀﾞﾜﾘｽｶｰﾌ"), Some("Choice Scarf"));
+        assert_eq!(ability_from_japanese(&dex, "さめはだ"), Some("Rough Skin"));
+        assert_eq!(nature_from_japanese("ようき"), Some("Jolly"));
+        assert_eq!(type_from_japanese("ほのお"), Some("Fire"));
+        assert_eq!(pokemon_from_japanese(&dex, "ピカチュウ"), Some("Pikachu"));
+        assert_eq!(move_from_japanese(&dex, "はねる"), None);
+    }
+
+    #[test]
+    fn reverse_lookups_go_through_the_dex() -> anyhow::Result<()> {
+        let mut dex = Dex::default();
+        dex.load_mod_str("rename", r#"{ "moves": { "earthquake": { "inherit": true, "name": "Earthquake Mk II" } } }"#)?;
+        assert_eq!(move_from_japanese(&dex, "じしん"), Some("Earthquake Mk II"));
+        Ok(())
+    }
+}
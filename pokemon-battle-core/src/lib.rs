@@ -4,12 +4,21 @@
 
 pub mod data;
 pub mod battle_logger;
+pub mod datagen;
+pub mod dex;
 pub mod engine;
+pub mod formats;
 pub mod i18n;
 pub mod parser;
+pub mod random_teams;
+pub mod search;
+pub mod sets;
 pub mod sim;
+pub mod teams;
+pub mod usage;
 
 pub use parser::parse_showdown_team;
+pub use sim::ai::{MctsMode, MctsParams};
 
 /// Commonly used exports for external consumers.
 pub mod prelude {
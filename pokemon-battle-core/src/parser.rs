@@ -1,155 +1,764 @@
-use crate::sim::pokemon::Pokemon;
-use crate::data::moves::normalize_move_name;
+use crate::sets::{Gender, PokemonSet, STAT_IDS, STAT_LABELS};
+use crate::sim::pokemon::{parse_type, Pokemon};
+use crate::dex::{to_id, Dex};
 use crate::sim::stats::Nature;
-use anyhow::{anyhow, Context, Result};
+use crate::data::types::Type;
+use anyhow::{anyhow, bail, Context, Result};
+use serde_json::Value;
+use crate::i18n::{
+    ability_from_japanese, fold_width, item_from_japanese, move_from_japanese, nature_from_japanese,
+    normalize_japanese, pokemon_from_japanese, type_from_japanese,
+};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
 
-pub fn parse_showdown_team(text: &str) -> Result<Vec<Pokemon>> {
-    let mut team = Vec::new();
-    for (idx, chunk) in text.split("\n\n").enumerate() {
-        let trimmed = chunk.trim();
-        let entry = parse_entry(trimmed).with_context(|| format!("Failed to parse team entry {}", idx + 1))?;
-        if let Some(pokemon) = entry {
-            team.push(pokemon);
+/// How serious a [`Diagnostic`] is. Errors keep the team from being built;
+/// warnings only do so in strict mode.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// One problem found in a team export, at a 1-based line and column.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diagnostic {
+    pub line: usize,
+    pub column: usize,
+    pub severity: Severity,
+    pub message: String,
+    /// "Did you mean" candidate for unknown names.
+    pub suggestion: Option<String>,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        write!(f, "line {}, column {}: {}: {}", self.line, self.column, severity, self.message)?;
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, " (did you mean '{}'?)", suggestion)?;
         }
+        Ok(())
     }
-    Ok(team)
 }
 
-fn parse_entry(entry: &str) -> Result<Option<Pokemon>> {
-    if entry.is_empty() {
-        return Ok(None);
+/// Options for [`parse_showdown_team_with`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ParseOptions {
+    /// Fail on warnings as well as errors.
+    pub strict: bool,
+}
+
+/// Sets read from an export together with everything that looked wrong.
+#[derive(Clone, Debug, Default)]
+pub struct ParsedTeam {
+    pub sets: Vec<PokemonSet>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl ParsedTeam {
+    /// Diagnostics that make parsing fail under `options`.
+    pub fn failures(&self, options: ParseOptions) -> Vec<Diagnostic> {
+        self.diagnostics
+            .iter()
+            .filter(|d| options.strict || d.severity == Severity::Error)
+            .cloned()
+            .collect()
     }
-    let mut species_line = None;
-    let mut ability = None;
-    let mut item = None;
-    let mut level = 50u8;
-    let mut nature = Nature::Hardy;
-    let mut evs = [0u8; 6];
-    let mut ivs = [31u8; 6];
-    let mut moves = Vec::new();
+}
 
-    for line in entry.lines() {
-        let trimmed = line.trim();
-        if trimmed.is_empty() {
-            continue;
+/// Returned (inside `anyhow::Error`) when a team has errors, or warnings in strict mode.
+#[derive(Clone, Debug)]
+pub struct TeamParseError {
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl fmt::Display for TeamParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "team has {} problem(s)", self.diagnostics.len())?;
+        for diagnostic in &self.diagnostics {
+            write!(f, "\n  {}", diagnostic)?;
         }
-        if let Some(rest) = trimmed.strip_prefix("Ability:") {
-            ability = Some(rest.trim().to_string());
+        Ok(())
+    }
+}
+
+impl std::error::Error for TeamParseError {}
+
+pub fn parse_showdown_team(text: &str) -> Result<Vec<Pokemon>> {
+    parse_showdown_team_in(&Dex::builtin(), text)
+}
+
+/// Parse a team, resolving species in `dex` (which may carry data mods).
+pub fn parse_showdown_team_in(dex: &Dex, text: &str) -> Result<Vec<Pokemon>> {
+    parse_showdown_team_with(dex, text, ParseOptions::default())
+}
+
+/// Parse a team, failing with a [`TeamParseError`] on errors (and warnings when `strict`).
+pub fn parse_showdown_team_with(dex: &Dex, text: &str, options: ParseOptions) -> Result<Vec<Pokemon>> {
+    let parsed = check_showdown_team(dex, text);
+    let failures = parsed.failures(options);
+    if !failures.is_empty() {
+        return Err(TeamParseError { diagnostics: failures }.into());
+    }
+    parsed
+        .sets
+        .iter()
+        .enumerate()
+        .map(|(idx, set)| {
+            Pokemon::from_set_in(dex, set)
+                .with_context(|| format!("Failed to build Pokémon '{}'", set.species))
+                .with_context(|| format!("Failed to parse team entry {}", idx + 1))
+        })
+        .collect()
+}
+
+/// Parse an export and check every name against `dex`, collecting diagnostics
+/// instead of stopping at the first problem.
+pub fn check_showdown_team(dex: &Dex, text: &str) -> ParsedTeam {
+    let (sets, mut diagnostics, names) = read_sets(dex, text);
+    for name in names {
+        let known = match name.kind {
+            NameKind::Species => dex.species(&name.text).is_some(),
+            NameKind::Item => dex.item(&name.text).is_some(),
+            NameKind::Ability => dex.ability(&name.text).is_some(),
+            NameKind::Move => dex.get_move(&name.text).is_some(),
+        };
+        if known {
             continue;
         }
-        if let Some(rest) = trimmed.strip_prefix("Level:") {
-            level = rest.trim().parse().unwrap_or(level);
-            continue;
+        let (label, severity, suggestion) = match name.kind {
+            NameKind::Species => ("species", Severity::Error, closest_name(&name.text, dex.all_species().map(|(_, d)| d.name))),
+            NameKind::Item => ("item", Severity::Warning, closest_name(&name.text, dex.all_items().map(|(_, d)| d.name))),
+            NameKind::Ability => {
+                ("ability", Severity::Warning, closest_name(&name.text, dex.all_abilities().map(|(_, d)| d.name)))
+            }
+            NameKind::Move => ("move", Severity::Warning, closest_name(&name.text, dex.all_moves().map(|(_, d)| d.name))),
+        };
+        diagnostics.push(Diagnostic {
+            line: name.line,
+            column: name.column,
+            severity,
+            message: format!("unknown {} '{}'", label, name.text),
+            suggestion: suggestion.map(str::to_string),
+        });
+    }
+    diagnostics.sort_by_key(|d| (d.line, d.column));
+    ParsedTeam { sets, diagnostics }
+}
+
+/// Parse a Showdown export into sets without looking anything up.
+/// Fails only on syntax errors (a missing species, an unknown type).
+pub fn parse_showdown_sets(text: &str) -> Result<Vec<PokemonSet>> {
+    let (sets, diagnostics, _) = read_sets(&Dex::builtin(), text);
+    let errors: Vec<Diagnostic> = diagnostics.into_iter().filter(|d| d.severity == Severity::Error).collect();
+    if !errors.is_empty() {
+        return Err(TeamParseError { diagnostics: errors }.into());
+    }
+    Ok(sets)
+}
+
+/// Parse Showdown's packed format (`Teams.pack`), turning IDs back into names via the builtin dex.
+pub fn parse_packed_sets(text: &str) -> Result<Vec<PokemonSet>> {
+    parse_packed_sets_in(&Dex::builtin(), text)
+}
+
+/// [`parse_packed_sets`] resolving names in `dex`. Unknown IDs are kept as written.
+pub fn parse_packed_sets_in(dex: &Dex, text: &str) -> Result<Vec<PokemonSet>> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Ok(Vec::new());
+    }
+    text.split(']')
+        .filter(|packed| !packed.is_empty())
+        .enumerate()
+        .map(|(idx, packed)| parse_packed_set(dex, packed).with_context(|| format!("Failed to parse packed set {}", idx + 1)))
+        .collect()
+}
+
+fn parse_packed_set(dex: &Dex, packed: &str) -> Result<PokemonSet> {
+    let fields: Vec<&str> = packed.split('|').collect();
+    if fields.len() < 11 {
+        bail!("expected at least 11 '|'-separated fields, found {}", fields.len());
+    }
+    let name = fields[0];
+    let species_name = |id: &str| dex.species(id).map_or_else(|| id.to_string(), |s| s.name.to_string());
+    let (nickname, species) = if fields[1].is_empty() {
+        (None, species_name(name))
+    } else {
+        (Some(name.to_string()), species_name(fields[1]))
+    };
+    if species.is_empty() {
+        bail!("Species is missing");
+    }
+    let mut set = PokemonSet::new(species);
+    set.nickname = nickname;
+    set.item = (!fields[2].is_empty()).then(|| dex.item(fields[2]).map_or_else(|| fields[2].to_string(), |i| i.name.to_string()));
+    set.ability =
+        (!fields[3].is_empty()).then(|| dex.ability(fields[3]).map_or_else(|| fields[3].to_string(), |a| a.name.to_string()));
+    set.moves = fields[4]
+        .split(',')
+        .filter(|id| !id.is_empty())
+        .map(|id| dex.get_move(id).map_or_else(|| id.to_string(), |m| m.name.to_string()))
+        .collect();
+    if !fields[5].is_empty() {
+        set.nature = Nature::from_name(fields[5]).ok_or_else(|| anyhow!("Unknown nature '{}'", fields[5]))?;
+    }
+    set.evs = parse_packed_stats(fields[6], 0)?;
+    set.gender = Gender::from_letter(fields[7]);
+    set.ivs = parse_packed_stats(fields[8], 31)?;
+    set.shiny = fields[9] == "S";
+    set.level = if fields[10].is_empty() { 100 } else { parse_number(fields[10], "level")? };
+    let misc: Vec<&str> = fields.get(11).map(|m| m.split(',').collect()).unwrap_or_default();
+    let misc_field = |idx: usize| misc.get(idx).copied().filter(|f| !f.is_empty());
+    if let Some(happiness) = misc_field(0) {
+        set.happiness = parse_number(happiness, "happiness")?;
+    }
+    set.hidden_power = misc_field(2).map(parse_type_name).transpose()?;
+    set.gigantamax = misc_field(3) == Some("G");
+    if let Some(level) = misc_field(4) {
+        set.dynamax_level = parse_number(level, "dynamax level")?;
+    }
+    set.tera_type = misc_field(5).map(parse_type_name).transpose()?;
+    if let Some(ups) = misc_field(6) {
+        set.pp_ups = ups.split('/').map(|n| parse_number(n, "PP Ups")).collect::<Result<_>>()?;
+    }
+    Ok(set)
+}
+
+fn parse_packed_stats(field: &str, default: u8) -> Result<[u8; 6]> {
+    let mut stats = [default; 6];
+    if field.is_empty() {
+        return Ok(stats);
+    }
+    for (idx, value) in field.split(',').enumerate().take(6) {
+        if !value.is_empty() {
+            stats[idx] = parse_number(value, "stat")?;
         }
-        if let Some(rest) = trimmed.strip_prefix("EVs:") {
-            parse_stat_line(rest.trim(), &mut evs);
-            continue;
+    }
+    Ok(stats)
+}
+
+fn parse_number(value: &str, what: &str) -> Result<u8> {
+    value.trim().parse().map_err(|_| anyhow!("invalid {} '{}'", what, value))
+}
+
+/// Parse Showdown's JSON team format (an array of `PokemonSet` objects).
+pub fn parse_json_sets(text: &str) -> Result<Vec<PokemonSet>> {
+    let root: Value = serde_json::from_str(text).context("team is not valid JSON")?;
+    let entries = root.as_array().ok_or_else(|| anyhow!("JSON team must be an array of sets"))?;
+    entries
+        .iter()
+        .enumerate()
+        .map(|(idx, entry)| parse_json_set(entry).with_context(|| format!("Failed to parse set {}", idx + 1)))
+        .collect()
+}
+
+fn parse_json_set(entry: &Value) -> Result<PokemonSet> {
+    let text = |key: &str| -> Result<Option<String>> {
+        match entry.get(key) {
+            None | Some(Value::Null) => Ok(None),
+            Some(Value::String(s)) if s.is_empty() => Ok(None),
+            Some(Value::String(s)) => Ok(Some(s.clone())),
+            Some(_) => bail!("'{}' must be a string", key),
         }
-        if let Some(rest) = trimmed.strip_prefix("IVs:") {
-            parse_stat_line(rest.trim(), &mut ivs);
-            continue;
+    };
+    let number = |key: &str| -> Result<Option<u8>> {
+        match entry.get(key) {
+            None | Some(Value::Null) => Ok(None),
+            Some(value) => value
+                .as_u64()
+                .and_then(|n| u8::try_from(n).ok())
+                .map(Some)
+                .ok_or_else(|| anyhow!("'{}' must be a number from 0 to 255", key)),
         }
-        if trimmed.ends_with("Nature") {
-            let nature_name = trimmed.trim_end_matches("Nature").trim();
-            nature = parse_nature(nature_name);
-            continue;
+    };
+    let name = text("name")?;
+    let species = text("species")?.or_else(|| name.clone()).ok_or_else(|| anyhow!("'species' is missing"))?;
+    let mut set = PokemonSet::new(species);
+    set.nickname = name.filter(|n| *n != set.species);
+    set.item = text("item")?;
+    set.ability = text("ability")?;
+    if let Some(moves) = entry.get("moves") {
+        set.moves = moves
+            .as_array()
+            .and_then(|list| list.iter().map(|m| m.as_str().map(str::to_string)).collect())
+            .ok_or_else(|| anyhow!("'moves' must be an array of strings"))?;
+    }
+    if let Some(nature) = text("nature")? {
+        set.nature = Nature::from_name(&nature).ok_or_else(|| anyhow!("Unknown nature '{}'", nature))?;
+    }
+    set.gender = text("gender")?.and_then(|g| Gender::from_letter(&g));
+    set.evs = parse_json_stats(entry.get("evs"), 0).context("invalid 'evs'")?;
+    set.ivs = parse_json_stats(entry.get("ivs"), 31).context("invalid 'ivs'")?;
+    set.level = number("level")?.unwrap_or(set.level);
+    set.shiny = entry.get("shiny").and_then(Value::as_bool).unwrap_or(false);
+    set.happiness = number("happiness")?.unwrap_or(set.happiness);
+    set.hidden_power = text("hpType")?.as_deref().map(parse_type_name).transpose()?;
+    set.dynamax_level = number("dynamaxLevel")?.unwrap_or(set.dynamax_level);
+    set.gigantamax = entry.get("gigantamax").and_then(Value::as_bool).unwrap_or(false);
+    set.tera_type = text("teraType")?.as_deref().map(parse_type_name).transpose()?;
+    if let Some(ups) = entry.get("ppUps") {
+        set.pp_ups = ups
+            .as_array()
+            .and_then(|list| list.iter().map(|n| n.as_u64().and_then(|n| u8::try_from(n).ok())).collect())
+            .ok_or_else(|| anyhow!("'ppUps' must be an array of numbers"))?;
+    }
+    Ok(set)
+}
+
+fn parse_json_stats(value: Option<&Value>, default: u8) -> Result<[u8; 6]> {
+    let mut stats = [default; 6];
+    let Some(value) = value.filter(|v| !v.is_null()) else {
+        return Ok(stats);
+    };
+    let map = value.as_object().ok_or_else(|| anyhow!("stats must be an object"))?;
+    for (idx, id) in STAT_IDS.iter().enumerate() {
+        if let Some(stat) = map.get(*id) {
+            stats[idx] = stat
+                .as_u64()
+                .and_then(|n| u8::try_from(n).ok())
+                .ok_or_else(|| anyhow!("'{}' must be a number from 0 to 255", id))?;
         }
-        if trimmed.starts_with('-') {
-            let move_name = trimmed.trim_start_matches('-').trim();
-            if !move_name.is_empty() {
-                moves.push(normalize_move_name(move_name));
+    }
+    Ok(stats)
+}
+
+#[derive(Clone, Copy, Debug)]
+enum NameKind {
+    Species,
+    Item,
+    Ability,
+    Move,
+}
+
+/// A name that still has to be looked up in the dex, with where it was written.
+struct NameRef {
+    kind: NameKind,
+    text: String,
+    line: usize,
+    column: usize,
+}
+
+/// Diagnostics for the line currently being read.
+struct LineReader<'a> {
+    dex: &'a Dex,
+    line: &'a str,
+    number: usize,
+    diagnostics: &'a mut Vec<Diagnostic>,
+    names: &'a mut Vec<NameRef>,
+}
+
+impl LineReader<'_> {
+    /// 1-based column of `part`, which must be a slice of the current line.
+    fn column(&self, part: &str) -> usize {
+        let offset = (part.as_ptr() as usize).saturating_sub(self.line.as_ptr() as usize).min(self.line.len());
+        self.line[..offset].chars().count() + 1
+    }
+
+    fn report(&mut self, part: &str, severity: Severity, message: String, suggestion: Option<&str>) {
+        let column = self.column(part);
+        self.diagnostics.push(Diagnostic {
+            line: self.number,
+            column,
+            severity,
+            message,
+            suggestion: suggestion.map(str::to_string),
+        });
+    }
+
+    fn warn(&mut self, part: &str, message: String) {
+        self.report(part, Severity::Warning, message, None);
+    }
+
+    /// Queue `part` for the dex check, in English when it is a known Japanese name.
+    fn name(&mut self, kind: NameKind, part: &str) {
+        let column = self.column(part);
+        let lookup = match kind {
+            NameKind::Species => pokemon_from_japanese,
+            NameKind::Item => item_from_japanese,
+            NameKind::Ability => ability_from_japanese,
+            NameKind::Move => move_from_japanese,
+        };
+        let text = english(self.dex, part, lookup).into_owned();
+        self.names.push(NameRef { kind, text, line: self.number, column });
+    }
+
+    fn number<T: std::str::FromStr + PartialOrd + fmt::Display>(&mut self, part: &str, what: &str, max: T) -> Option<T> {
+        let part = part.trim();
+        match part.parse::<T>() {
+            Ok(value) if value <= max => Some(value),
+            _ => {
+                self.warn(part, format!("invalid {} '{}' (expected a number up to {}); ignored", what, part, max));
+                None
             }
+        }
+    }
+
+    fn yes_no(&mut self, part: &str, what: &str) -> Option<bool> {
+        let part = part.trim();
+        match part.to_ascii_lowercase().as_str() {
+            "yes" => Some(true),
+            "no" => Some(false),
+            _ => {
+                self.warn(part, format!("invalid {} value '{}' (expected Yes or No); ignored", what, part));
+                None
+            }
+        }
+    }
+
+    fn type_name(&mut self, part: &str) -> Option<Type> {
+        let part = part.trim();
+        let parsed = parse_type(part).or_else(|| type_from_japanese(part).and_then(parse_type));
+        if parsed.is_none() {
+            let names = TYPE_NAMES.iter().copied();
+            let suggestion = closest_name(part, names);
+            self.report(part, Severity::Error, format!("unknown type '{}'", part), suggestion);
+        }
+        parsed
+    }
+}
+
+const TYPE_NAMES: [&str; 20] = [
+    "Normal", "Fire", "Water", "Electric", "Grass", "Ice", "Fighting", "Poison", "Ground", "Flying", "Psychic", "Bug",
+    "Rock", "Ghost", "Dragon", "Dark", "Steel", "Fairy", "???", "Stellar",
+];
+
+/// Split an export into entries (separated by blank lines) and read each one.
+fn read_sets(dex: &Dex, text: &str) -> (Vec<PokemonSet>, Vec<Diagnostic>, Vec<NameRef>) {
+    let mut sets = Vec::new();
+    let mut diagnostics = Vec::new();
+    let mut names = Vec::new();
+    let mut entry: Vec<(usize, String)> = Vec::new();
+    // Full-width ASCII (`＠`, `：`, `２５２`) is folded up front; it keeps every column in place.
+    let lines = text.lines().enumerate().map(|(idx, line)| (idx + 1, fold_width(line)));
+    for (number, line) in lines.chain(std::iter::once((0, String::new()))) {
+        if !line.trim().is_empty() {
+            entry.push((number, line));
             continue;
         }
-        if species_line.is_none() {
-            species_line = Some(trimmed.to_string());
+        if !entry.is_empty() {
+            if let Some(set) = read_entry(dex, &entry, &mut diagnostics, &mut names) {
+                sets.push(set);
+            }
+            entry.clear();
+        }
+    }
+    (sets, diagnostics, names)
+}
+
+fn read_entry(
+    dex: &Dex,
+    entry: &[(usize, String)],
+    diagnostics: &mut Vec<Diagnostic>,
+    names: &mut Vec<NameRef>,
+) -> Option<PokemonSet> {
+    let (first_number, first_line) = (entry[0].0, entry[0].1.as_str());
+    // PS: the first line of an entry is always the species line.
+    let mut reader = LineReader { dex, line: first_line, number: first_number, diagnostics, names };
+    let mut set = read_species_line(&mut reader)?;
+    let diagnostics = reader.diagnostics;
+    let names = reader.names;
+
+    let mut seen: HashMap<&'static str, usize> = HashMap::new();
+    let mut seen_moves: HashMap<String, usize> = HashMap::new();
+    for (number, line) in &entry[1..] {
+        let number = *number;
+        let mut reader = LineReader { dex, line, number, diagnostics: &mut *diagnostics, names: &mut *names };
+        let trimmed = line.trim();
+        let (key, value) = match line_key(trimmed) {
+            Some((key, value)) => (Some(key), value),
+            None => (None, trimmed),
+        };
+        if let Some(key) = key {
+            if let Some(first) = seen.insert(key, number) {
+                reader.warn(trimmed, format!("duplicate '{}' line (first on line {}); the later one is used", key, first));
+            }
+        }
+        match key {
+            Some("Ability") => {
+                let value = value.trim();
+                let name = english(dex, value, ability_from_japanese);
+                reader.name(NameKind::Ability, value);
+                set.ability = Some(name.into_owned());
+            }
+            Some("Level") => {
+                match reader.number::<u8>(value, "level", 100) {
+                    Some(0) => reader.warn(value.trim(), "invalid level '0'; ignored".to_string()),
+                    Some(level) => set.level = level,
+                    None => {}
+                }
+            }
+            Some("Shiny") => {
+                if let Some(shiny) = reader.yes_no(value, "Shiny") {
+                    set.shiny = shiny;
+                }
+            }
+            Some("Happiness") => {
+                if let Some(happiness) = reader.number::<u8>(value, "happiness", 255) {
+                    set.happiness = happiness;
+                }
+            }
+            Some("Tera Type") => {
+                if let Some(tera) = reader.type_name(value) {
+                    set.tera_type = Some(tera);
+                }
+            }
+            Some("Hidden Power") => {
+                if let Some(hp_type) = reader.type_name(value) {
+                    set.hidden_power = Some(hp_type);
+                }
+            }
+            Some("Dynamax Level") => {
+                if let Some(level) = reader.number::<u8>(value, "Dynamax Level", 10) {
+                    set.dynamax_level = level;
+                }
+            }
+            Some("Gigantamax") => {
+                if let Some(gmax) = reader.yes_no(value, "Gigantamax") {
+                    set.gigantamax = gmax;
+                }
+            }
+            Some("PP Ups") => {
+                let ups: Vec<Option<u8>> =
+                    value.split('/').map(|n| reader.number::<u8>(n, "PP Ups", 3)).collect();
+                set.pp_ups = ups.into_iter().map(|n| n.unwrap_or(3)).collect();
+            }
+            Some("Pokeball") => {}
+            Some("EVs") => read_stat_line(&mut reader, value, &mut set.evs, StatKind::Ev),
+            Some("IVs") => read_stat_line(&mut reader, value, &mut set.ivs, StatKind::Iv),
+            Some("Nature") => {
+                let name = value.trim();
+                match Nature::from_name(name).or_else(|| nature_from_japanese(name).and_then(Nature::from_name)) {
+                    Some(nature) => set.nature = nature,
+                    None => {
+                        let suggestion = closest_name(name, Nature::ALL.iter().map(|n| n.name()));
+                        reader.report(
+                            name,
+                            Severity::Warning,
+                            format!("unknown nature '{}'; using Hardy", name),
+                            suggestion,
+                        );
+                    }
+                }
+            }
+            _ if trimmed.starts_with('-') => {
+                let move_name = trimmed.trim_start_matches('-').trim();
+                if move_name.is_empty() {
+                    reader.warn(trimmed, "empty move line".to_string());
+                    continue;
+                }
+                let name = match move_name.strip_prefix("Hidden Power [").and_then(|r| r.strip_suffix(']')) {
+                    Some(hp_type) => {
+                        set.hidden_power = reader.type_name(hp_type).or(set.hidden_power);
+                        "Hidden Power"
+                    }
+                    None => {
+                        reader.name(NameKind::Move, move_name);
+                        move_name
+                    }
+                };
+                let name = english(dex, name, move_from_japanese);
+                if let Some(first) = seen_moves.insert(to_id(&name), number) {
+                    reader.warn(move_name, format!("duplicate move '{}' (first on line {})", name, first));
+                }
+                set.moves.push(name.into_owned());
+            }
+            _ => reader.warn(trimmed, format!("unrecognized line '{}'; ignored", trimmed)),
         }
     }
+    Some(set)
+}
 
-    let species_line = species_line.ok_or_else(|| anyhow!("Species line is missing"))?;
-    let species_parts: Vec<&str> = species_line.split('@').map(|s| s.trim()).collect();
-    let species_name = species_parts
-        .get(0)
-        .ok_or_else(|| anyhow!("Failed to read species name"))?
-        .to_string();
-    if let Some(item_str) = species_parts.get(1) {
-        if !item_str.is_empty() {
-            item = Some(item_str.to_string());
+/// Which `Key: value` line `line` is (also used for duplicate detection), with its value.
+/// Japanese keys (`とくせい:`, `努力値:`) and bare Japanese natures (`ようき`) are accepted.
+fn line_key(line: &str) -> Option<(&'static str, &str)> {
+    const KEYS: [(&str, &[&str]); 12] = [
+        ("Ability", &["Ability", "とくせい", "特性"]),
+        ("Level", &["Level", "レベル"]),
+        ("Shiny", &["Shiny", "色違い", "いろちがい"]),
+        ("Happiness", &["Happiness", "なつき度", "なつき"]),
+        ("Tera Type", &["Tera Type", "テラスタイプ", "テラスタル"]),
+        ("Hidden Power", &["Hidden Power", "めざめるパワー"]),
+        ("Dynamax Level", &["Dynamax Level", "ダイマックスレベル"]),
+        ("Gigantamax", &["Gigantamax", "キョダイマックス"]),
+        ("PP Ups", &["PP Ups", "ポイントアップ"]),
+        ("Pokeball", &["Pokeball", "ボール"]),
+        ("EVs", &["EVs", "努力値", "どりょくち"]),
+        ("IVs", &["IVs", "個体値", "こたいち"]),
+    ];
+    for (key, spellings) in KEYS {
+        for spelling in spellings {
+            if let Some(value) = line.strip_prefix(spelling).and_then(|rest| rest.trim_start().strip_prefix(':')) {
+                return Some((key, value));
+            }
         }
     }
+    if line.starts_with('-') {
+        return None;
+    }
+    if let Some(name) = line.strip_suffix("Nature") {
+        return Some(("Nature", name));
+    }
+    for prefix in ["せいかく", "性格"] {
+        if let Some(value) = line.strip_prefix(prefix).and_then(|rest| rest.trim_start().strip_prefix(':')) {
+            return Some(("Nature", value));
+        }
+    }
+    let bare = line.strip_suffix("性格").unwrap_or(line);
+    nature_from_japanese(bare).is_some().then_some(("Nature", bare))
+}
 
-    let ability = ability.unwrap_or_else(|| "No Ability".to_string());
-    let pokemon = Pokemon::new(species_name.clone(), level, evs, ivs, nature, moves, ability, item)
-        .with_context(|| format!("Failed to build Pokémon '{}'", species_name))?;
-    Ok(Some(pokemon))
+/// `name` in English: non-ASCII names go through the Japanese reverse index.
+fn english<'a>(dex: &Dex, name: &'a str, lookup: fn(&Dex, &str) -> Option<&'static str>) -> Cow<'a, str> {
+    if name.is_ascii() {
+        return Cow::Borrowed(name);
+    }
+    lookup(dex, name).map_or(Cow::Borrowed(name), Cow::Borrowed)
 }
 
-fn parse_stat_line(line: &str, stats: &mut [u8; 6]) {
+/// `Nickname (Species) (G) @ Item` — every part but the species is optional.
+fn read_species_line(reader: &mut LineReader<'_>) -> Option<PokemonSet> {
+    let line = reader.line.trim();
+    // `ガブリアス＠こだわりスカーフ` is common in Japanese teams, so the spaces are optional.
+    let (mut rest, item) = match line.rsplit_once(" @ ").or_else(|| line.rsplit_once('@')) {
+        Some((head, item)) => (head.trim(), Some(item.trim())),
+        None => (line, None),
+    };
+    let mut gender = None;
+    for (suffix, value) in [("(M)", Gender::Male), ("(F)", Gender::Female), ("(♂)", Gender::Male), ("(♀)", Gender::Female)] {
+        if let Some(head) = rest.strip_suffix(suffix) {
+            gender = Some(value);
+            rest = head.trim_end();
+            break;
+        }
+    }
+    // PS: the species is in the last parenthesised group, the nickname before it.
+    let (nickname, species) = match rest.rfind(" (") {
+        Some(paren) if rest.ends_with(')') => (Some(rest[..paren].trim()), rest[paren + 2..rest.len() - 1].trim()),
+        _ => (None, rest),
+    };
+    if species.is_empty() || species.contains(':') || species.starts_with('-') {
+        reader.report(line, Severity::Error, format!("expected a species line, found '{}'", line), None);
+        return None;
+    }
+    reader.name(NameKind::Species, species);
+    let mut set = PokemonSet::new(english(reader.dex, species, pokemon_from_japanese));
+    set.nickname = nickname.filter(|n| !n.is_empty() && *n != species).map(str::to_string);
+    set.gender = gender;
+    if let Some(item) = item.filter(|i| !i.is_empty()) {
+        reader.name(NameKind::Item, item);
+        set.item = Some(english(reader.dex, item, item_from_japanese).into_owned());
+    }
+    Some(set)
+}
+
+fn parse_type_name(name: &str) -> Result<Type> {
+    parse_type(name.trim()).ok_or_else(|| anyhow!("Unknown type '{}'", name.trim()))
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum StatKind {
+    Ev,
+    Iv,
+}
+
+/// `252 Atk / 4 SpD / 252 Spe`: malformed parts are reported and skipped.
+fn read_stat_line(reader: &mut LineReader<'_>, line: &str, stats: &mut [u8; 6], kind: StatKind) {
+    let (label, max) = match kind {
+        StatKind::Ev => ("EV", 252u16),
+        StatKind::Iv => ("IV", 31u16),
+    };
     for part in line.split('/') {
         let trimmed = part.trim();
         if trimmed.is_empty() {
             continue;
         }
         let mut iter = trimmed.split_whitespace();
-        if let Some(value_str) = iter.next() {
-            if let Some(stat_name) = iter.next() {
-                if let Ok(value) = value_str.parse::<u8>() {
-                    if let Some(idx) = stat_index(stat_name) {
-                        stats[idx] = value;
-                    }
-                }
-            }
+        let (Some(value_str), Some(stat_name), None) = (iter.next(), iter.next(), iter.next()) else {
+            reader.warn(trimmed, format!("malformed {} '{}' (expected e.g. '252 Atk'); ignored", label, trimmed));
+            continue;
+        };
+        let Some(idx) = stat_index(stat_name) else {
+            let suggestion = closest_name(stat_name, STAT_LABELS.iter().copied());
+            reader.report(stat_name, Severity::Warning, format!("unknown stat '{}'; ignored", stat_name), suggestion);
+            continue;
+        };
+        match value_str.parse::<u16>() {
+            Ok(value) if value <= max => stats[idx] = value as u8,
+            _ => reader.warn(
+                value_str,
+                format!("invalid {} '{}' for {} (expected 0-{}); ignored", label, value_str, STAT_LABELS[idx], max),
+            ),
         }
     }
+    let total: u16 = stats.iter().map(|v| *v as u16).sum();
+    if kind == StatKind::Ev && total > 510 {
+        reader.warn(line.trim(), format!("EVs total {} (more than 510)", total));
+    }
 }
 
+/// Stat label spellings, English and Japanese (compared after `normalize_japanese`).
+const STAT_SPELLINGS: [&[&str]; 6] = [
+    &["HP", "H"],
+    &["Atk", "A", "こうげき", "攻撃"],
+    &["Def", "B", "ぼうぎょ", "防御"],
+    &["SpA", "SpAtk", "C", "とくこう", "特攻"],
+    &["SpD", "SpDef", "D", "とくぼう", "特防"],
+    &["Spe", "S", "すばやさ", "素早さ"],
+];
+
 fn stat_index(name: &str) -> Option<usize> {
-    match name.to_lowercase().as_str() {
-        "hp" => Some(0),
-        "atk" => Some(1),
-        "def" => Some(2),
-        "spa" | "spatk" => Some(3),
-        "spd" | "spdef" => Some(4),
-        "spe" => Some(5),
-        _ => None,
-    }
-}
-
-fn parse_nature(name: &str) -> Nature {
-    match name.trim().to_lowercase().as_str() {
-        "hardy" => Nature::Hardy,
-        "lonely" => Nature::Lonely,
-        "brave" => Nature::Brave,
-        "adamant" => Nature::Adamant,
-        "naughty" => Nature::Naughty,
-        "bold" => Nature::Bold,
-        "docile" => Nature::Docile,
-        "relaxed" => Nature::Relaxed,
-        "impish" => Nature::Impish,
-        "lax" => Nature::Lax,
-        "timid" => Nature::Timid,
-        "hasty" => Nature::Hasty,
-        "serious" => Nature::Serious,
-        "jolly" => Nature::Jolly,
-        "naive" => Nature::Naive,
-        "modest" => Nature::Modest,
-        "mild" => Nature::Mild,
-        "quiet" => Nature::Quiet,
-        "bashful" => Nature::Bashful,
-        "rash" => Nature::Rash,
-        "calm" => Nature::Calm,
-        "gentle" => Nature::Gentle,
-        "sassy" => Nature::Sassy,
-        "careful" => Nature::Careful,
-        "quirky" => Nature::Quirky,
-        _ => Nature::Hardy,
+    let name = normalize_japanese(name);
+    STAT_SPELLINGS
+        .iter()
+        .position(|spellings| spellings.iter().any(|s| normalize_japanese(s) == name))
+}
+
+/// Closest candidate to `name` by edit distance on IDs, if it is close enough to be a typo.
+pub(crate) fn closest_name<'a>(name: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    let target = to_id(name);
+    if target.is_empty() {
+        return None;
     }
+    let limit = (target.chars().count() / 3).max(1);
+    candidates
+        .map(|candidate| (edit_distance(&target, &to_id(candidate)), candidate))
+        .filter(|(distance, _)| *distance <= limit)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Levenshtein distance.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
 }
 
 #[cfg(test)]
 mod tests {
-    use super::normalize_move_name;
-    use super::parse_showdown_team;
+    use super::{
+        check_showdown_team, parse_showdown_sets, parse_showdown_team, parse_showdown_team_with, ParseOptions,
+        Severity, TeamParseError,
+    };
+    use crate::dex::Dex;
+    use crate::battle_logger::showdown_ident;
+    use crate::data::moves::normalize_move_name;
+    use crate::data::types::Type;
+    use crate::sets::{export_team, Gender};
     use anyhow::Result;
 
     #[test]
@@ -197,4 +806,163 @@ Modest Nature
         assert!(team[0].stats.hp > 0);
         Ok(())
     }
+
+    #[test]
+    fn parse_full_species_line_and_extras() -> Result<()> {
+        let data = "\
+Garchomp-Chomp (Garchomp) (F) @ Choice Scarf
+Ability: Rough Skin
+Shiny: Yes
+Happiness: 0
+Tera Type: Fire
+Dynamax Level: 5
+Gigantamax: Yes
+PP Ups: 3/0
+EVs: 252 Atk / 252 Spe
+Jolly Nature
+- Earthquake
+- Hidden Power [Ice]
+";
+        let sets = parse_showdown_sets(data)?;
+        let set = &sets[0];
+        assert_eq!(set.nickname.as_deref(), Some("Garchomp-Chomp"));
+        assert_eq!(set.species, "Garchomp");
+        assert_eq!(set.gender, Some(Gender::Female));
+        assert_eq!(set.item.as_deref(), Some("Choice Scarf"));
+        assert!(set.shiny && set.gigantamax);
+        assert_eq!((set.happiness, set.dynamax_level), (0, 5));
+        assert_eq!(set.tera_type, Some(Type::Fire));
+        assert_eq!(set.hidden_power, Some(Type::Ice));
+        assert_eq!(set.moves, ["Earthquake", "Hidden Power"]);
+        assert_eq!((set.pp_ups_for(0), set.pp_ups_for(1), set.pp_ups_for(2)), (3, 0, 3));
+
+        let team = parse_showdown_team(data)?;
+        assert_eq!(team[0].name, "Garchomp-Chomp");
+        assert_eq!(team[0].display_name(&Dex::builtin()), "Garchomp-Chomp");
+        assert_eq!(showdown_ident(0, &team[0].name), "p1a: Garchomp-Chomp");
+        assert_eq!(team[0].showdown_details(), "Garchomp, L50, F, shiny");
+        Ok(())
+    }
+
+    #[test]
+    fn species_line_variants() -> Result<()> {
+        let data = "Pikachu (M)\n- Thunderbolt\n\nMr. Mime @ Leftovers\n- Psychic\n\nSparky (Pikachu)\nShiny: No";
+        let sets = parse_showdown_sets(data)?;
+        assert_eq!((sets[0].species.as_str(), sets[0].gender), ("Pikachu", Some(Gender::Male)));
+        assert_eq!(sets[1].species, "Mr. Mime");
+        assert_eq!(sets[1].item.as_deref(), Some("Leftovers"));
+        assert_eq!(sets[2].nickname.as_deref(), Some("Sparky"));
+        assert!(!sets[2].shiny);
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_tera_type_is_an_error() {
+        assert!(parse_showdown_sets("Pikachu\nTera Type: Sound").is_err());
+    }
+
+    #[test]
+    fn built_pokemon_exports_back_to_its_set() -> Result<()> {
+        let data = "Chompy (Garchomp) (F) @ Choice Scarf\nAbility: Rough Skin\nTera Type: Fire\nEVs: 252 Atk / 252 Spe\nJolly Nature\n- Earthquake\n- Dragon Claw";
+        let sets = parse_showdown_sets(data)?;
+        let team = parse_showdown_team(data)?;
+        assert_eq!(team[0].to_set(), sets[0]);
+        Ok(())
+    }
+
+    #[test]
+    fn hidden_power_pp_ups_and_dynamax_survive_export() -> Result<()> {
+        let data = "Snorlax\nDynamax Level: 5\nGigantamax: Yes\nPP Ups: 3/0\n- Body Slam\n- Hidden Power [Ice]";
+        let sets = parse_showdown_sets(data)?;
+        let team = parse_showdown_team(data)?;
+        let exported = team[0].to_set();
+        assert_eq!(exported, sets[0]);
+        assert_eq!(parse_showdown_sets(&export_team(&[exported]))?[0], sets[0]);
+        Ok(())
+    }
+
+    #[test]
+    fn diagnostics_point_at_unknown_names_with_suggestions() {
+        let data = "Garchomp @ Choice Scarff\nJolyy Nature\n- Earthqake\n\nGarchompp\n- Earthquake";
+        let parsed = check_showdown_team(&Dex::builtin(), data);
+        let found: Vec<(usize, usize, Severity, Option<&str>)> = parsed
+            .diagnostics
+            .iter()
+            .map(|d| (d.line, d.column, d.severity, d.suggestion.as_deref()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (1, 12, Severity::Warning, Some("Choice Scarf")),
+                (2, 1, Severity::Warning, Some("Jolly")),
+                (3, 3, Severity::Warning, Some("Earthquake")),
+                (5, 1, Severity::Error, Some("Garchomp")),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_stats_and_duplicates_are_reported() {
+        let data = "Pikachu\nLevel: 500\nEVs: 252 Atk / 252 Spe / 252 HP\nIVs: 40 Def / junk\n- Thunderbolt\n- Thunderbolt\nLevel: 50";
+        let parsed = check_showdown_team(&Dex::builtin(), data);
+        let messages: Vec<String> = parsed.diagnostics.iter().map(|d| d.to_string()).collect();
+        assert!(messages.iter().any(|m| m.starts_with("line 2, column 8: warning: invalid level '500'")), "{:?}", messages);
+        assert!(messages.iter().any(|m| m.contains("EVs total 756")));
+        assert!(messages.iter().any(|m| m.starts_with("line 4, column 6: warning: invalid IV '40' for Def")));
+        assert!(messages.iter().any(|m| m.starts_with("line 4, column 15: warning: malformed IV 'junk'")));
+        assert!(messages.iter().any(|m| m.starts_with("line 6, column 3: warning: duplicate move")));
+        assert!(messages.iter().any(|m| m.starts_with("line 7, column 1: warning: duplicate 'Level' line")));
+        assert_eq!(parsed.sets[0].ivs[2], 31);
+        assert!(parsed.diagnostics.iter().all(|d| d.severity == Severity::Warning));
+    }
+
+    #[test]
+    fn strict_mode_fails_on_warnings() {
+        let data = "Pikachu\nAbility: Statik\n- Thunderbolt";
+        let dex = Dex::builtin();
+        assert!(parse_showdown_team_with(&dex, data, ParseOptions::default()).is_ok());
+        let err = parse_showdown_team_with(&dex, data, ParseOptions { strict: true }).unwrap_err();
+        let err = err.downcast_ref::<TeamParseError>().expect("structured error");
+        assert_eq!(err.diagnostics.len(), 1);
+        assert_eq!(err.diagnostics[0].suggestion.as_deref(), Some("Static"));
+    }
+
+    #[test]
+    fn unknown_species_is_an_error_with_location() {
+        let err = parse_showdown_team("Pikachu\n- Thunderbolt\n\nPikachuu\n- Thunderbolt").unwrap_err();
+        assert!(err.to_string().contains("line 4, column 1: error: unknown species 'Pikachuu'"));
+    }
+
+    #[test]
+    fn japanese_team_is_read_in_english() -> Result<()> {
+        let data = "\
+ガブリアス @ こだわりスカーフ
+とくせい: さめはだ
+ようき
+努力値: 252 こうげき / 4 ﾄｸﾎﾞｳ / 252 素早さ
+テラスタイプ：ほのお
+- じしん
+- ドラゴンクロー
+
+ｶﾞﾌﾞ (がぶりあす)＠ｺﾞﾂｺﾞﾂﾒｯﾄ
+せいかく: いじっぱり
+- げきりん
+";
+        let parsed = check_showdown_team(&Dex::builtin(), data);
+        assert!(parsed.diagnostics.is_empty(), "{:?}", parsed.diagnostics);
+        let chomp = &parsed.sets[0];
+        assert_eq!(chomp.species, "Garchomp");
+        assert_eq!(chomp.item.as_deref(), Some("Choice Scarf"));
+        assert_eq!(chomp.ability.as_deref(), Some("Rough Skin"));
+        assert_eq!(chomp.nature, crate::sim::stats::Nature::Jolly);
+        assert_eq!(chomp.evs, [0, 252, 0, 0, 4, 252]);
+        assert_eq!(chomp.tera_type, Some(Type::Fire));
+        assert_eq!(chomp.moves, ["Earthquake", "Dragon Claw"]);
+        let nicknamed = &parsed.sets[1];
+        assert_eq!(nicknamed.nickname.as_deref(), Some("ｶﾞﾌﾞ"));
+        assert_eq!(nicknamed.item.as_deref(), Some("Rocky Helmet"));
+        assert_eq!(nicknamed.moves, ["Outrage"]);
+        assert_eq!(parse_showdown_team(data)?.len(), 2);
+        Ok(())
+    }
 }
@@ -0,0 +1,341 @@
+//! Random battle teams drawn from a set pool.
+//!
+//! The pool is a JSON file shaped like Showdown's `data/random-battles/<format>/sets.json`:
+//!
+//! ```json
+//! { "garchomp": { "level": 76, "sets": [
+//!     { "role": "Fast Attacker", "movepool": ["Earthquake", "Outrage", ...],
+//!       "abilities": ["Rough Skin"], "teraTypes": ["Ground"], "items": ["Life Orb"] } ] } }
+//! ```
+//!
+//! Showdown picks items in code; here each set lists the items it may hold. The
+//! bundled pool is `data/random-sets.json`. Generated teams follow Species Clause, Item
+//! Clause and a type balance (no more than two Pokémon sharing a type, no more than
+//! three weak to the same attacking type) and are deterministic for a seed.
+
+use crate::data::types::Type;
+use crate::dex::{to_id, Dex};
+use crate::formats::Format;
+use crate::sets::PokemonSet;
+use crate::sim::pokemon::{parse_type, Pokemon};
+use crate::sim::type_chart::TypeChart;
+use anyhow::{bail, Context, Result};
+use once_cell::sync::Lazy;
+use rand::rngs::SmallRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+/// Items handed out when none of a set's own items is still free (Item Clause).
+const FALLBACK_ITEMS: &[&str] = &["Leftovers", "Sitrus Berry", "Lum Berry", "Expert Belt", "Shuca Berry", "Covert Cloak"];
+
+/// Level for pool entries without one, before the format's level rule.
+const DEFAULT_LEVEL: u8 = 100;
+
+/// Random sets get 84 EVs in every stat, like Showdown's random battles.
+const RANDOM_EVS: [u8; 6] = [84; 6];
+
+const MAX_SHARED_TYPE: usize = 2;
+const MAX_SHARED_WEAKNESS: usize = 3;
+
+const CHART: [Type; 18] = [
+    Type::Normal,
+    Type::Fire,
+    Type::Water,
+    Type::Electric,
+    Type::Grass,
+    Type::Ice,
+    Type::Fighting,
+    Type::Poison,
+    Type::Ground,
+    Type::Flying,
+    Type::Psychic,
+    Type::Bug,
+    Type::Rock,
+    Type::Ghost,
+    Type::Dragon,
+    Type::Dark,
+    Type::Steel,
+    Type::Fairy,
+];
+
+/// One way a species can be built.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RandomSet {
+    #[serde(default)]
+    pub role: String,
+    pub movepool: Vec<String>,
+    #[serde(default)]
+    pub abilities: Vec<String>,
+    #[serde(default)]
+    pub tera_types: Vec<String>,
+    #[serde(default)]
+    pub items: Vec<String>,
+}
+
+/// A species' entry in the pool.
+#[derive(Clone, Debug, Deserialize)]
+pub struct RandomSpecies {
+    pub level: Option<u8>,
+    pub sets: Vec<RandomSet>,
+}
+
+/// Sets to draw random teams from, keyed by species id.
+#[derive(Clone, Debug, Default)]
+pub struct RandomSetPool {
+    pub species: BTreeMap<String, RandomSpecies>,
+}
+
+static BUILTIN: Lazy<RandomSetPool> = Lazy::new(|| {
+    RandomSetPool::from_json(include_str!("../../data/random-sets.json"))
+        .unwrap_or_else(|err| panic!("Failed to parse data/random-sets.json: {}", err))
+});
+
+impl RandomSetPool {
+    /// The bundled pool (`data/random-sets.json`).
+    pub fn builtin() -> &'static RandomSetPool {
+        &BUILTIN
+    }
+
+    pub fn from_json(text: &str) -> Result<Self> {
+        let raw: BTreeMap<String, RandomSpecies> = serde_json::from_str(text)?;
+        let mut species = BTreeMap::new();
+        for (name, entry) in raw {
+            if entry.sets.iter().any(|set| set.movepool.is_empty()) {
+                bail!("{}: every set needs a movepool", name);
+            }
+            if !entry.sets.is_empty() {
+                species.insert(to_id(&name), entry);
+            }
+        }
+        Ok(Self { species })
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+        Self::from_json(&text).with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.species.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.species.is_empty()
+    }
+
+    /// A team of `format`'s largest size as team entries, deterministic for `seed`.
+    /// Levels follow the format's level rule, else the pool's level.
+    pub fn generate_sets(&self, dex: &Dex, format: &Format, seed: u64) -> Result<Vec<PokemonSet>> {
+        let mut rng = SmallRng::seed_from_u64(seed);
+        let size = format.team_size.1;
+        let chart = format.type_chart();
+        let mut candidates: Vec<&str> = self.species.keys().map(String::as_str).collect();
+        candidates.shuffle(&mut rng);
+
+        let mut team = TeamBuilder::default();
+        // First pass keeps the type balance; a second pass fills the team if the pool is too narrow.
+        for balanced in [true, false] {
+            for &id in &candidates {
+                if team.sets.len() == size {
+                    break;
+                }
+                let Some(data) = dex.species(id) else { continue };
+                let types = [data.types[0], data.types[1]].map(parse_type);
+                if team.nums.contains(&data.num) || (balanced && !team.balanced(&chart, types)) {
+                    continue;
+                }
+                let entry = &self.species[id];
+                let set = entry.sets.choose(&mut rng).expect("pool entries have sets");
+                let level = format.level.level_for(dex, data.name, entry.level.unwrap_or(DEFAULT_LEVEL));
+                let pokemon_set = team.build(data.name, set, level, &mut rng);
+                team.add(data.num, types, &chart, pokemon_set);
+            }
+        }
+        if team.sets.len() < format.team_size.0 {
+            bail!(
+                "the random set pool has {} usable species; {} needs {}",
+                team.sets.len(),
+                format.name,
+                format.team_size.0
+            );
+        }
+        Ok(team.sets)
+    }
+
+    /// [`generate_sets`](Self::generate_sets) built into battle-ready Pokémon.
+    pub fn generate(&self, dex: &Dex, format: &Format, seed: u64) -> Result<Vec<Pokemon>> {
+        let sets = self.generate_sets(dex, format, seed)?;
+        let mut team = sets
+            .iter()
+            .map(|set| Pokemon::from_set_in(dex, set).with_context(|| format!("Failed to build '{}'", set.species)))
+            .collect::<Result<Vec<_>>>()?;
+        format.adjust_team(dex, &mut team)?;
+        Ok(team)
+    }
+}
+
+/// A random team from the bundled pool.
+pub fn random_team(dex: &Dex, format: &Format, seed: u64) -> Result<Vec<Pokemon>> {
+    RandomSetPool::builtin().generate(dex, format, seed)
+}
+
+#[derive(Default)]
+struct TeamBuilder {
+    sets: Vec<PokemonSet>,
+    nums: HashSet<i16>,
+    items: HashSet<String>,
+    /// Pokémon per type, indexed by `Type::as_index`.
+    type_counts: [usize; 18],
+    /// Pokémon weak to each attacking type.
+    weaknesses: [usize; 18],
+}
+
+impl TeamBuilder {
+    fn balanced(&self, chart: &TypeChart, types: [Option<Type>; 2]) -> bool {
+        let shares_too_much = own_types(types).any(|t| self.type_counts[t.as_index()] >= MAX_SHARED_TYPE);
+        let stacks_weakness = weak_to(chart, types).any(|t| self.weaknesses[t.as_index()] >= MAX_SHARED_WEAKNESS);
+        !shares_too_much && !stacks_weakness
+    }
+
+    fn build(&mut self, species: &str, set: &RandomSet, level: u8, rng: &mut SmallRng) -> PokemonSet {
+        let mut pokemon = PokemonSet::new(species);
+        let mut moves = set.movepool.clone();
+        moves.shuffle(rng);
+        moves.truncate(4);
+        pokemon.moves = moves;
+        pokemon.ability = set.abilities.choose(rng).cloned();
+        pokemon.tera_type = set.tera_types.choose(rng).and_then(|name| parse_type(name));
+        pokemon.item = self.free_item(&set.items, rng);
+        pokemon.level = level;
+        pokemon.evs = RANDOM_EVS;
+        pokemon.shiny = rng.gen_ratio(1, 1024);
+        pokemon
+    }
+
+    // Item Clause: the set's first free item in random order, else a fallback item.
+    fn free_item(&self, items: &[String], rng: &mut SmallRng) -> Option<String> {
+        let mut own = items.to_vec();
+        own.shuffle(rng);
+        own.into_iter()
+            .chain(FALLBACK_ITEMS.iter().map(|item| item.to_string()))
+            .find(|item| !self.items.contains(&to_id(item)))
+    }
+
+    fn add(&mut self, num: i16, types: [Option<Type>; 2], chart: &TypeChart, set: PokemonSet) {
+        self.nums.insert(num);
+        if let Some(item) = &set.item {
+            self.items.insert(to_id(item));
+        }
+        for t in own_types(types) {
+            self.type_counts[t.as_index()] += 1;
+        }
+        for t in weak_to(chart, types) {
+            self.weaknesses[t.as_index()] += 1;
+        }
+        self.sets.push(set);
+    }
+}
+
+// The species' chart types (a second slot equal to the first counts once).
+fn own_types(types: [Option<Type>; 2]) -> impl Iterator<Item = Type> {
+    let [first, second] = types;
+    first.into_iter().chain(second.filter(|t| Some(*t) != first)).filter(|t| t.as_index() < CHART.len())
+}
+
+fn weak_to(chart: &TypeChart, types: [Option<Type>; 2]) -> impl Iterator<Item = Type> + '_ {
+    let first = types[0].unwrap_or(Type::Typeless);
+    let second = types[1].unwrap_or(first);
+    CHART.into_iter().filter(move |&attacking| chart.effectiveness_dual(attacking, first, second) > 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formats::{format, GEN9_RANDOM_BATTLE};
+
+    #[test]
+    fn bundled_pool_is_valid() {
+        let dex = Dex::builtin();
+        let pool = RandomSetPool::builtin();
+        assert!(pool.len() >= 24);
+        for (id, entry) in &pool.species {
+            assert!(dex.species(id).is_some(), "unknown species {}", id);
+            assert_eq!(entry.level, GEN9_RANDOM_BATTLE.listed(id), "{}'s level", id);
+            for set in &entry.sets {
+                for name in &set.movepool {
+                    assert!(dex.get_move(name).is_some(), "{}: unknown move {}", id, name);
+                }
+                for name in &set.abilities {
+                    assert!(dex.ability(name).is_some(), "{}: unknown ability {}", id, name);
+                }
+                for name in &set.items {
+                    assert!(dex.item(name).is_some(), "{}: unknown item {}", id, name);
+                }
+                assert!(set.tera_types.iter().all(|name| parse_type(name).is_some()), "{}", id);
+            }
+        }
+        for item in FALLBACK_ITEMS {
+            assert!(dex.item(item).is_some(), "unknown item {}", item);
+        }
+    }
+
+    #[test]
+    fn teams_follow_clauses_and_type_balance() {
+        let dex = Dex::builtin();
+        let random = format("gen9randombattle").unwrap();
+        let chart = random.type_chart();
+        for seed in 0..20 {
+            let team = random_team(&dex, random, seed).unwrap();
+            assert_eq!(team.len(), 6);
+            let nums: HashSet<i16> = team.iter().map(|p| dex.species(&p.species).unwrap().num).collect();
+            assert_eq!(nums.len(), 6, "Species Clause (seed {})", seed);
+            let items: HashSet<String> = team.iter().filter_map(|p| p.item.as_deref().map(to_id)).collect();
+            assert_eq!(items.len(), 6, "Item Clause (seed {})", seed);
+            for t in CHART {
+                let sharing = team.iter().filter(|p| p.types.contains(&t)).count();
+                assert!(sharing <= MAX_SHARED_TYPE, "{} {:?} types (seed {})", sharing, t, seed);
+                let weak = team.iter().filter(|p| chart.effectiveness_dual(t, p.types[0], p.types[1]) > 1.0).count();
+                assert!(weak <= MAX_SHARED_WEAKNESS, "{} weak to {:?} (seed {})", weak, t, seed);
+            }
+            for pokemon in &team {
+                assert_eq!(pokemon.level, GEN9_RANDOM_BATTLE.level(&dex, &pokemon.species));
+                assert_eq!(pokemon.original_level, None);
+                assert!(!pokemon.moves.is_empty() && pokemon.moves.len() <= 4);
+            }
+        }
+    }
+
+    #[test]
+    fn same_seed_same_team() {
+        let dex = Dex::builtin();
+        let random = format("gen9randombattle").unwrap();
+        let species = |seed| random_team(&dex, random, seed).unwrap().into_iter().map(|p| p.species).collect::<Vec<_>>();
+        assert_eq!(species(7), species(7));
+        assert_ne!(species(7), species(8));
+
+        let ou = format("gen9ou").unwrap();
+        let pool = RandomSetPool::builtin();
+        let sets = pool.generate_sets(&dex, ou, 3).unwrap();
+        assert!(ou.validate(&dex, &sets).is_empty(), "{:?}", ou.validate(&dex, &sets));
+        assert!(sets.iter().all(|set| Some(set.level) == pool.species[&to_id(&set.species)].level));
+    }
+
+    #[test]
+    fn small_pools_fail_or_relax_balance() {
+        let dex = Dex::builtin();
+        let random = format("gen9randombattle").unwrap();
+        let pool = RandomSetPool::from_json(r#"{"pikachu": {"sets": [{"movepool": ["Thunderbolt"]}]}}"#).unwrap();
+        assert!(pool.generate(&dex, random, 0).unwrap_err().to_string().contains("1 usable species"));
+        assert!(RandomSetPool::from_json(r#"{"pikachu": {"sets": [{"movepool": []}]}}"#).is_err());
+
+        let custom = format("gen9customgame").unwrap();
+        let team = pool.generate(&dex, custom, 0).unwrap();
+        assert_eq!((team.len(), team[0].level), (1, 100));
+        assert_eq!(team[0].item.as_deref(), Some("Leftovers"));
+    }
+}
@@ -0,0 +1,562 @@
+//! Dex search ("/ds" style) over species, moves and abilities.
+//!
+//! A query is a whitespace-separated list of terms:
+//!
+//! - `type:fire`, `ability:levitate`, `move:willowisp` (learnable moves),
+//!   `category:physical`, `flag:contact`, `gen:4` — `|` separates alternatives
+//!   (`type:fire|water`) and a leading `!` negates a term (`!type:flying`)
+//! - comparisons on stats and move numbers: `spe>100`, `bst>=600`, `bp=80`,
+//!   `priority>0` (fields: `hp atk def spa spd spe bst weight`, `bp acc pp priority`, `num`)
+//! - `sort:spe` (numbers sort high to low, `sort:spe:asc` to flip), `limit:20`
+//! - `kind:species|moves|abilities`, otherwise inferred from the filters
+//! - any other word matches names (`chomp`)
+//!
+//! Entries with a non-positive dex number (CAP, Pokéstar, ...) are skipped.
+
+use crate::data::abilities::AbilityData;
+use crate::data::moves::{normalize_move_name, MoveCategory, MoveData};
+use crate::data::species::SpeciesData;
+use crate::dex::Dex;
+use crate::sim::pokemon::parse_type;
+use anyhow::{anyhow, bail, Result};
+use std::cmp::Ordering;
+use std::fmt;
+
+/// What a query searches.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SearchKind {
+    Species,
+    Moves,
+    Abilities,
+}
+
+impl fmt::Display for SearchKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            SearchKind::Species => "species",
+            SearchKind::Moves => "moves",
+            SearchKind::Abilities => "abilities",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Numeric field usable in comparisons and sorting.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SearchField {
+    Hp,
+    Atk,
+    Def,
+    Spa,
+    Spd,
+    Spe,
+    Bst,
+    Weight,
+    Power,
+    Accuracy,
+    Pp,
+    Priority,
+    Num,
+}
+
+impl SearchField {
+    fn parse(name: &str) -> Option<Self> {
+        Some(match name {
+            "hp" => SearchField::Hp,
+            "atk" | "attack" => SearchField::Atk,
+            "def" | "defense" => SearchField::Def,
+            "spa" | "spatk" => SearchField::Spa,
+            "spd" | "spdef" => SearchField::Spd,
+            "spe" | "speed" => SearchField::Spe,
+            "bst" => SearchField::Bst,
+            "weight" | "weightkg" => SearchField::Weight,
+            "bp" | "power" | "basepower" => SearchField::Power,
+            "acc" | "accuracy" => SearchField::Accuracy,
+            "pp" => SearchField::Pp,
+            "pri" | "priority" => SearchField::Priority,
+            "num" => SearchField::Num,
+            _ => return None,
+        })
+    }
+
+    /// The kind this field belongs to, `None` when it applies to every kind.
+    fn kind(self) -> Option<SearchKind> {
+        match self {
+            SearchField::Hp
+            | SearchField::Atk
+            | SearchField::Def
+            | SearchField::Spa
+            | SearchField::Spd
+            | SearchField::Spe
+            | SearchField::Bst
+            | SearchField::Weight => Some(SearchKind::Species),
+            SearchField::Power | SearchField::Accuracy | SearchField::Pp | SearchField::Priority => {
+                Some(SearchKind::Moves)
+            }
+            SearchField::Num => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Comparison {
+    Lt,
+    Le,
+    Eq,
+    Ge,
+    Gt,
+}
+
+impl Comparison {
+    fn holds(self, lhs: f64, rhs: f64) -> bool {
+        match self {
+            Comparison::Lt => lhs < rhs,
+            Comparison::Le => lhs <= rhs,
+            Comparison::Eq => lhs == rhs,
+            Comparison::Ge => lhs >= rhs,
+            Comparison::Gt => lhs > rhs,
+        }
+    }
+}
+
+/// One filter of a query. List-valued filters match any of their values.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Filter {
+    /// Species type or move type.
+    Type(Vec<String>),
+    Ability(Vec<String>),
+    /// Species that can learn the move, per the dex's learnsets.
+    Learns(Vec<String>),
+    Category(Vec<MoveCategory>),
+    Flag(Vec<String>),
+    Gen(Vec<u8>),
+    /// Substring of the name or id.
+    Name(String),
+    Compare { field: SearchField, op: Comparison, value: f64 },
+}
+
+impl Filter {
+    fn kind(&self) -> Option<SearchKind> {
+        match self {
+            Filter::Ability(_) | Filter::Learns(_) => Some(SearchKind::Species),
+            Filter::Category(_) | Filter::Flag(_) => Some(SearchKind::Moves),
+            Filter::Compare { field, .. } => field.kind(),
+            Filter::Type(_) | Filter::Gen(_) | Filter::Name(_) => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Term {
+    pub filter: Filter,
+    pub negated: bool,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SortKey {
+    Name,
+    Field(SearchField),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SortOrder {
+    pub key: SortKey,
+    pub descending: bool,
+}
+
+/// A parsed search query. Build one with [`Query::parse`] or by hand.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Query {
+    pub kind: SearchKind,
+    pub terms: Vec<Term>,
+    pub sort: Option<SortOrder>,
+    pub limit: Option<usize>,
+}
+
+/// A search result.
+#[derive(Clone, Copy, Debug)]
+pub enum SearchHit<'a> {
+    Species { id: &'a str, data: &'static SpeciesData },
+    Move { id: &'a str, data: &'static MoveData },
+    Ability { id: &'a str, data: &'static AbilityData },
+}
+
+impl SearchHit<'_> {
+    pub fn id(&self) -> &str {
+        match self {
+            SearchHit::Species { id, .. } | SearchHit::Move { id, .. } | SearchHit::Ability { id, .. } => id,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SearchHit::Species { data, .. } => data.name,
+            SearchHit::Move { data, .. } => data.name,
+            SearchHit::Ability { data, .. } => data.name,
+        }
+    }
+
+    fn num(&self) -> i16 {
+        match self {
+            SearchHit::Species { data, .. } => data.num,
+            SearchHit::Move { data, .. } => data.num,
+            SearchHit::Ability { data, .. } => data.num,
+        }
+    }
+
+    fn field(&self, field: SearchField) -> Option<f64> {
+        if field == SearchField::Num {
+            return Some(self.num() as f64);
+        }
+        match self {
+            SearchHit::Species { data, .. } => {
+                let s = data.base_stats;
+                let value = match field {
+                    SearchField::Hp => s.hp as f64,
+                    SearchField::Atk => s.atk as f64,
+                    SearchField::Def => s.def as f64,
+                    SearchField::Spa => s.spa as f64,
+                    SearchField::Spd => s.spd as f64,
+                    SearchField::Spe => s.spe as f64,
+                    SearchField::Bst => base_stat_total(data) as f64,
+                    SearchField::Weight => data.weight_kg as f64,
+                    _ => return None,
+                };
+                Some(value)
+            }
+            SearchHit::Move { data, .. } => match field {
+                SearchField::Power => Some(data.base_power.unwrap_or(0) as f64),
+                // Moves that never miss (`accuracy: true`) are stored as 100.
+                SearchField::Accuracy => data.accuracy.map(|a| a as f64),
+                SearchField::Pp => data.pp.map(|pp| pp as f64),
+                SearchField::Priority => Some(data.priority as f64),
+                _ => None,
+            },
+            SearchHit::Ability { .. } => None,
+        }
+    }
+}
+
+pub fn base_stat_total(data: &SpeciesData) -> u16 {
+    let s = data.base_stats;
+    [s.hp, s.atk, s.def, s.spa, s.spd, s.spe].iter().map(|v| *v as u16).sum()
+}
+
+/// Generation a dex number was introduced in.
+fn generation(kind: SearchKind, num: i16) -> Option<u8> {
+    // Last dex number of each generation.
+    const SPECIES: [i16; 9] = [151, 251, 386, 493, 649, 721, 809, 905, 1025];
+    const MOVES: [i16; 9] = [165, 251, 354, 467, 559, 621, 742, 850, 919];
+    const ABILITIES: [i16; 9] = [0, 0, 76, 123, 164, 191, 233, 267, 310];
+    if num <= 0 {
+        return None;
+    }
+    let bounds = match kind {
+        SearchKind::Species => SPECIES,
+        SearchKind::Moves => MOVES,
+        SearchKind::Abilities => ABILITIES,
+    };
+    let gen = bounds.iter().position(|last| num <= *last).unwrap_or(bounds.len() - 1);
+    Some(gen as u8 + 1)
+}
+
+fn to_id(name: &str) -> String {
+    name.to_ascii_lowercase()
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect()
+}
+
+fn split_values(value: &str) -> Vec<String> {
+    value.split('|').map(to_id).filter(|v| !v.is_empty()).collect()
+}
+
+fn parse_term(raw: &str) -> Result<Term> {
+    let (negated, body) = match raw.strip_prefix('!') {
+        Some(rest) => (true, rest),
+        None => (false, raw),
+    };
+    if let Some((key, value)) = body.split_once(':') {
+        let values = split_values(value);
+        if values.is_empty() {
+            bail!("'{}' has no value", raw);
+        }
+        let filter = match key.to_ascii_lowercase().as_str() {
+            "type" => {
+                if let Some(bad) = values.iter().find(|t| parse_type(t).is_none()) {
+                    bail!("unknown type '{}' in '{}'", bad, raw);
+                }
+                Filter::Type(values)
+            }
+            "ability" => Filter::Ability(values),
+            "move" | "learns" => Filter::Learns(values.iter().map(|m| normalize_move_name(m)).collect()),
+            "category" | "cat" => Filter::Category(
+                values
+                    .iter()
+                    .map(|c| match c.as_str() {
+                        "physical" => Ok(MoveCategory::Physical),
+                        "special" => Ok(MoveCategory::Special),
+                        "status" => Ok(MoveCategory::Status),
+                        other => Err(anyhow!("unknown category '{}' (physical, special, status)", other)),
+                    })
+                    .collect::<Result<_>>()?,
+            ),
+            "flag" => Filter::Flag(values),
+            "gen" => Filter::Gen(
+                values
+                    .iter()
+                    .map(|g| g.parse::<u8>().ok().filter(|g| (1..=9).contains(g)))
+                    .collect::<Option<_>>()
+                    .ok_or_else(|| anyhow!("'{}': generation must be 1-9", raw))?,
+            ),
+            other => bail!("unknown filter '{}:' in '{}'", other, raw),
+        };
+        return Ok(Term { filter, negated });
+    }
+    if let Some(pos) = body.find(['<', '>', '=']) {
+        let field_name = body[..pos].to_ascii_lowercase();
+        let rest = &body[pos..];
+        let (op, number) = [
+            ("<=", Comparison::Le),
+            (">=", Comparison::Ge),
+            ("<", Comparison::Lt),
+            (">", Comparison::Gt),
+            ("=", Comparison::Eq),
+        ]
+        .into_iter()
+        .find_map(|(symbol, op)| rest.strip_prefix(symbol).map(|n| (op, n)))
+        .expect("rest starts with a comparison symbol");
+        let field = SearchField::parse(&field_name)
+            .ok_or_else(|| anyhow!("unknown field '{}' in '{}'", field_name, raw))?;
+        let value = number
+            .parse::<f64>()
+            .map_err(|_| anyhow!("'{}': '{}' is not a number", raw, number))?;
+        return Ok(Term { filter: Filter::Compare { field, op, value }, negated });
+    }
+    let name = to_id(body);
+    if name.is_empty() {
+        bail!("empty term '{}'", raw);
+    }
+    Ok(Term { filter: Filter::Name(name), negated })
+}
+
+impl Query {
+    /// Parse a query string such as `type:fire spe>100 move:willowisp sort:spe`.
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut kind = None;
+        let mut terms = Vec::new();
+        let mut sort = None;
+        let mut limit = None;
+        for raw in text.split_whitespace() {
+            let lower = raw.to_ascii_lowercase();
+            if let Some(value) = lower.strip_prefix("kind:") {
+                kind = Some(match value {
+                    "species" | "pokemon" => SearchKind::Species,
+                    "moves" | "move" => SearchKind::Moves,
+                    "abilities" | "ability" => SearchKind::Abilities,
+                    other => bail!("unknown kind '{}' (species, moves, abilities)", other),
+                });
+            } else if let Some(value) = lower.strip_prefix("sort:") {
+                let (key, direction) = match value.split_once(':') {
+                    Some((key, direction)) => (key, Some(direction)),
+                    None => (value, None),
+                };
+                let key = if key == "name" {
+                    SortKey::Name
+                } else {
+                    SortKey::Field(SearchField::parse(key).ok_or_else(|| anyhow!("unknown sort key '{}'", key))?)
+                };
+                let descending = match direction {
+                    None => key != SortKey::Name && key != SortKey::Field(SearchField::Num),
+                    Some("desc") => true,
+                    Some("asc") => false,
+                    Some(other) => bail!("unknown sort direction '{}' (asc, desc)", other),
+                };
+                sort = Some(SortOrder { key, descending });
+            } else if let Some(value) = lower.strip_prefix("limit:") {
+                limit = Some(value.parse().map_err(|_| anyhow!("'{}': limit must be a number", raw))?);
+            } else {
+                terms.push(parse_term(raw)?);
+            }
+        }
+        let inferred = infer_kind(&terms, sort)?;
+        let kind = match (kind, inferred) {
+            (Some(kind), Some(inferred)) if kind != inferred => {
+                bail!("kind:{} cannot be combined with {} filters", kind, inferred)
+            }
+            (Some(kind), _) => kind,
+            (None, inferred) => inferred.unwrap_or(SearchKind::Species),
+        };
+        if kind == SearchKind::Abilities && terms.iter().any(|t| matches!(t.filter, Filter::Type(_))) {
+            bail!("type: filters do not apply to abilities");
+        }
+        Ok(Self { kind, terms, sort, limit })
+    }
+
+    /// Run the query against `dex`. Results are sorted by the query's sort order,
+    /// then by dex number and id.
+    pub fn run<'a>(&self, dex: &'a Dex) -> Result<Vec<SearchHit<'a>>> {
+        if !dex.has_learnsets() && self.terms.iter().any(|t| matches!(t.filter, Filter::Learns(_))) {
+            bail!("move: filters need learnset data; regenerate src/data from a dump with a learnsets section");
+        }
+        let candidates: Vec<SearchHit<'a>> = match self.kind {
+            SearchKind::Species => dex.all_species().map(|(id, data)| SearchHit::Species { id, data }).collect(),
+            SearchKind::Moves => dex.all_moves().map(|(id, data)| SearchHit::Move { id, data }).collect(),
+            SearchKind::Abilities => dex.all_abilities().map(|(id, data)| SearchHit::Ability { id, data }).collect(),
+        };
+        let mut hits: Vec<SearchHit<'a>> = candidates
+            .into_iter()
+            .filter(|hit| hit.num() > 0)
+            .filter(|hit| self.terms.iter().all(|term| term_matches(dex, self.kind, term, hit)))
+            .collect();
+        hits.sort_by(|a, b| {
+            let primary = match self.sort {
+                None => Ordering::Equal,
+                Some(SortOrder { key: SortKey::Name, descending }) => {
+                    let ord = a.name().cmp(b.name());
+                    if descending { ord.reverse() } else { ord }
+                }
+                Some(SortOrder { key: SortKey::Field(field), descending }) => {
+                    let lhs = a.field(field).unwrap_or(f64::NEG_INFINITY);
+                    let rhs = b.field(field).unwrap_or(f64::NEG_INFINITY);
+                    let ord = lhs.total_cmp(&rhs);
+                    if descending { ord.reverse() } else { ord }
+                }
+            };
+            primary.then_with(|| a.num().cmp(&b.num())).then_with(|| a.id().cmp(b.id()))
+        });
+        if let Some(limit) = self.limit {
+            hits.truncate(limit);
+        }
+        Ok(hits)
+    }
+}
+
+/// Parse and run a query in one go.
+pub fn search<'a>(dex: &'a Dex, query: &str) -> Result<Vec<SearchHit<'a>>> {
+    Query::parse(query)?.run(dex)
+}
+
+fn infer_kind(terms: &[Term], sort: Option<SortOrder>) -> Result<Option<SearchKind>> {
+    let sort_kind = match sort {
+        Some(SortOrder { key: SortKey::Field(field), .. }) => field.kind(),
+        _ => None,
+    };
+    let mut kind: Option<SearchKind> = None;
+    for candidate in terms.iter().filter_map(|t| t.filter.kind()).chain(sort_kind) {
+        match kind {
+            Some(existing) if existing != candidate => {
+                bail!("query mixes {} filters and {} filters", existing, candidate)
+            }
+            _ => kind = Some(candidate),
+        }
+    }
+    Ok(kind)
+}
+
+fn term_matches(dex: &Dex, kind: SearchKind, term: &Term, hit: &SearchHit<'_>) -> bool {
+    filter_matches(dex, kind, &term.filter, hit) != term.negated
+}
+
+fn filter_matches(dex: &Dex, kind: SearchKind, filter: &Filter, hit: &SearchHit<'_>) -> bool {
+    match (filter, hit) {
+        (Filter::Type(types), SearchHit::Species { data, .. }) => data
+            .types
+            .iter()
+            .filter(|t| !t.is_empty())
+            .any(|t| types.contains(&to_id(t))),
+        (Filter::Type(types), SearchHit::Move { data, .. }) => types.contains(&to_id(data.move_type)),
+        (Filter::Ability(abilities), SearchHit::Species { data, .. }) => {
+            let slots = data.abilities;
+            [slots.primary, slots.secondary, slots.hidden]
+                .iter()
+                .flatten()
+                .any(|a| abilities.contains(&to_id(a)))
+        }
+        (Filter::Learns(moves), SearchHit::Species { id, .. }) => moves.iter().any(|m| dex.can_learn(id, m)),
+        (Filter::Category(categories), SearchHit::Move { data, .. }) => categories.contains(&data.category),
+        (Filter::Flag(flags), SearchHit::Move { data, .. }) => {
+            data.flags.iter().any(|f| flags.contains(&f.to_string()))
+        }
+        (Filter::Gen(gens), hit) => generation(kind, hit.num()).is_some_and(|g| gens.contains(&g)),
+        (Filter::Name(name), hit) => hit.id().contains(name.as_str()) || to_id(hit.name()).contains(name.as_str()),
+        (Filter::Compare { field, op, value }, hit) => hit.field(*field).is_some_and(|v| op.holds(v, *value)),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(hits: &[SearchHit<'_>]) -> Vec<&'static str> {
+        hits.iter().map(|h| h.name()).collect()
+    }
+
+    #[test]
+    fn parses_terms_and_infers_kind() -> Result<()> {
+        let query = Query::parse("type:fire|water spe>100 !ability:blaze sort:spe limit:5")?;
+        assert_eq!(query.kind, SearchKind::Species);
+        assert_eq!(query.terms.len(), 3);
+        assert!(query.terms[2].negated);
+        assert_eq!(
+            query.sort,
+            Some(SortOrder { key: SortKey::Field(SearchField::Spe), descending: true })
+        );
+        assert_eq!(query.limit, Some(5));
+        assert_eq!(Query::parse("category:status priority>0")?.kind, SearchKind::Moves);
+        assert!(Query::parse("bp>80 hp>100").is_err());
+        assert!(Query::parse("type:sound").is_err());
+        assert!(Query::parse("kind:abilities bp>10").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn species_search_filters_and_sorts() -> Result<()> {
+        let dex = Dex::builtin();
+        let hits = search(&dex, "type:dragon type:ground bst>=600 sort:spe")?;
+        assert!(names(&hits).contains(&"Garchomp"));
+        let speeds: Vec<f64> = hits.iter().map(|h| h.field(SearchField::Spe).unwrap()).collect();
+        assert!(speeds.windows(2).all(|w| w[0] >= w[1]));
+
+        let hits = search(&dex, "ability:levitate gen:4 type:ghost")?;
+        assert!(names(&hits).contains(&"Mismagius"));
+        assert!(!names(&hits).contains(&"Gengar"));
+        Ok(())
+    }
+
+    #[test]
+    fn move_search_uses_move_fields() -> Result<()> {
+        let dex = Dex::builtin();
+        let hits = search(&dex, "type:electric category:special bp>=90 acc=100")?;
+        assert!(names(&hits).contains(&"Thunderbolt"));
+        assert!(!names(&hits).contains(&"Thunder"));
+        let hits = search(&dex, "priority>0 flag:contact type:normal")?;
+        assert!(names(&hits).contains(&"Quick Attack"));
+        Ok(())
+    }
+
+    #[test]
+    fn learnset_filter_uses_compiled_learnsets() -> Result<()> {
+        let dex = Dex::builtin();
+        let hits = search(&dex, "type:fire move:willowisp")?;
+        assert!(names(&hits).contains(&"Arcanine"));
+        assert!(!names(&hits).contains(&"Cinderace"));
+        search(&dex, "type:fire spe>100 move:willowisp")?;
+        Ok(())
+    }
+
+    #[test]
+    fn learnset_mods_replace_compiled_learnsets() -> Result<()> {
+        let mut dex = Dex::default();
+        dex.load_mod_str(
+            "learnsets",
+            r#"{ "learnsets": { "garchomp": { "learnset": { "willowisp": ["9M"] } },
+                                "arcanine": { "learnset": { "flareblitz": ["9M"] } } } }"#,
+        )?;
+        let hits = search(&dex, "move:willowisp type:dragon|fire")?;
+        assert!(names(&hits).contains(&"Garchomp"));
+        assert!(!names(&hits).contains(&"Arcanine"));
+        Ok(())
+    }
+}
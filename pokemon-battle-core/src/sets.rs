@@ -0,0 +1,383 @@
+//! A Pokémon as written in a team (Showdown's `PokemonSet`), before it is built
+//! into a battle-ready [`Pokemon`](crate::sim::Pokemon).
+
+use crate::data::types::Type;
+use crate::dex::{to_id, Dex};
+use crate::sim::pokemon::type_name;
+use crate::sim::stats::Nature;
+use serde_json::{json, Map, Value};
+
+/// Stat labels in export order (`EVs: 252 Atk / 4 SpD`).
+pub(crate) const STAT_LABELS: [&str; 6] = ["HP", "Atk", "Def", "SpA", "SpD", "Spe"];
+/// Stat keys used by the JSON format (`{"hp": 0, "atk": 252, ...}`).
+pub(crate) const STAT_IDS: [&str; 6] = ["hp", "atk", "def", "spa", "spd", "spe"];
+
+/// Gender as written in an export (`(M)` / `(F)`); `None` is genderless / unspecified.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Gender {
+    Male,
+    Female,
+}
+
+impl Gender {
+    /// `"M"` / `"F"`, as used by exports and protocol details.
+    pub fn letter(self) -> &'static str {
+        match self {
+            Gender::Male => "M",
+            Gender::Female => "F",
+        }
+    }
+
+    pub fn from_letter(letter: &str) -> Option<Self> {
+        match letter.trim() {
+            "M" | "m" => Some(Gender::Male),
+            "F" | "f" => Some(Gender::Female),
+            _ => None,
+        }
+    }
+}
+
+/// One team entry, field-for-field with Showdown's `PokemonSet`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PokemonSet {
+    /// Nickname (`Chompy (Garchomp)`); `None` when the species name is used.
+    pub nickname: Option<String>,
+    pub species: String,
+    pub item: Option<String>,
+    pub ability: Option<String>,
+    /// Move names as written (`Hidden Power [Fire]` is stored as `Hidden Power`).
+    pub moves: Vec<String>,
+    pub nature: Nature,
+    pub gender: Option<Gender>,
+    pub evs: [u8; 6],
+    pub ivs: [u8; 6],
+    pub level: u8,
+    pub shiny: bool,
+    pub happiness: u8,
+    /// PP Ups per move slot (0-3); slots past the end of the list count as 3.
+    pub pp_ups: Vec<u8>,
+    /// Hidden Power type (`Hidden Power: Fire` or `- Hidden Power [Fire]`).
+    pub hidden_power: Option<Type>,
+    pub dynamax_level: u8,
+    pub gigantamax: bool,
+    pub tera_type: Option<Type>,
+}
+
+impl PokemonSet {
+    /// Defaults used by this repo's parser: Lv.50, 0 EVs, 31 IVs, Hardy.
+    pub fn new(species: impl Into<String>) -> Self {
+        Self {
+            nickname: None,
+            species: species.into(),
+            item: None,
+            ability: None,
+            moves: Vec::new(),
+            nature: Nature::Hardy,
+            gender: None,
+            evs: [0; 6],
+            ivs: [31; 6],
+            level: 50,
+            shiny: false,
+            happiness: 255,
+            pp_ups: Vec::new(),
+            hidden_power: None,
+            dynamax_level: 10,
+            gigantamax: false,
+            tera_type: None,
+        }
+    }
+
+    /// Name shown in logs: the nickname, or the species when there is none.
+    pub fn name(&self) -> &str {
+        self.nickname.as_deref().unwrap_or(&self.species)
+    }
+
+    /// PP Ups applied to move slot `index` (3 unless specified).
+    pub fn pp_ups_for(&self, index: usize) -> u8 {
+        self.pp_ups.get(index).copied().unwrap_or(3).min(3)
+    }
+
+    /// Max PP of move slot `index` after PP Ups (`pp * (5 + ups) / 5`).
+    pub fn max_pp(&self, dex: &Dex, index: usize) -> Option<u8> {
+        let base = dex.get_move(self.moves.get(index)?)?.pp?;
+        let ups = self.pp_ups_for(index) as u16;
+        Some((base as u16 * (5 + ups) / 5) as u8)
+    }
+}
+
+/// Showdown text export of one set (the inverse of `parser::parse_showdown_sets`).
+///
+/// `Level:` is always written because the parser defaults to Lv.50 rather than
+/// Showdown's 100; PP Ups are written only when specified.
+pub fn export_set(set: &PokemonSet) -> String {
+    let mut lines = Vec::new();
+    let mut head = match &set.nickname {
+        Some(nickname) => format!("{} ({})", nickname, set.species),
+        None => set.species.clone(),
+    };
+    if let Some(gender) = set.gender {
+        head.push_str(&format!(" ({})", gender.letter()));
+    }
+    if let Some(item) = &set.item {
+        head.push_str(&format!(" @ {}", item));
+    }
+    lines.push(head);
+    if let Some(ability) = &set.ability {
+        lines.push(format!("Ability: {}", ability));
+    }
+    lines.push(format!("Level: {}", set.level));
+    if set.shiny {
+        lines.push("Shiny: Yes".to_string());
+    }
+    if set.happiness != 255 {
+        lines.push(format!("Happiness: {}", set.happiness));
+    }
+    if set.dynamax_level != 10 {
+        lines.push(format!("Dynamax Level: {}", set.dynamax_level));
+    }
+    if set.gigantamax {
+        lines.push("Gigantamax: Yes".to_string());
+    }
+    if let Some(tera) = set.tera_type {
+        lines.push(format!("Tera Type: {}", type_name(tera)));
+    }
+    let has_hp_move = set.moves.iter().any(|m| to_id(m) == "hiddenpower");
+    if let (Some(hp_type), false) = (set.hidden_power, has_hp_move) {
+        lines.push(format!("Hidden Power: {}", type_name(hp_type)));
+    }
+    if let Some(evs) = stat_spread(&set.evs, 0) {
+        lines.push(format!("EVs: {}", evs));
+    }
+    if set.nature != Nature::Hardy {
+        lines.push(format!("{} Nature", set.nature.name()));
+    }
+    if let Some(ivs) = stat_spread(&set.ivs, 31) {
+        lines.push(format!("IVs: {}", ivs));
+    }
+    if !set.pp_ups.is_empty() {
+        let ups: Vec<String> = set.pp_ups.iter().map(u8::to_string).collect();
+        lines.push(format!("PP Ups: {}", ups.join("/")));
+    }
+    for name in &set.moves {
+        match set.hidden_power {
+            Some(hp_type) if to_id(name) == "hiddenpower" => {
+                lines.push(format!("- Hidden Power [{}]", type_name(hp_type)))
+            }
+            _ => lines.push(format!("- {}", name)),
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
+/// Text export of a whole team, entries separated by a blank line.
+pub fn export_team(sets: &[PokemonSet]) -> String {
+    sets.iter().map(export_set).collect::<Vec<_>>().join("\n")
+}
+
+fn stat_spread(values: &[u8; 6], default: u8) -> Option<String> {
+    let parts: Vec<String> = values
+        .iter()
+        .zip(STAT_LABELS)
+        .filter(|(value, _)| **value != default)
+        .map(|(value, label)| format!("{} {}", value, label))
+        .collect();
+    (!parts.is_empty()).then(|| parts.join(" / "))
+}
+
+/// Showdown packed format (`Teams.pack`): one `|`-separated record per set, joined by `]`.
+///
+/// Names are packed as IDs. PP Ups, which Showdown does not carry, go in an extra
+/// trailing misc field that Showdown ignores.
+pub fn pack_team(sets: &[PokemonSet]) -> String {
+    sets.iter().map(pack_set).collect::<Vec<_>>().join("]")
+}
+
+fn pack_set(set: &PokemonSet) -> String {
+    let name = set.name();
+    let species = if to_id(&set.species) == to_id(name) { String::new() } else { to_id(&set.species) };
+    let moves: Vec<String> = set.moves.iter().map(|m| to_id(m)).collect();
+    let nature = if set.nature == Nature::Hardy { "" } else { set.nature.name() };
+    let evs = pack_stats(&set.evs, 0);
+    let ivs = pack_stats(&set.ivs, 31);
+    let level = if set.level == 100 { String::new() } else { set.level.to_string() };
+    let ups: Vec<String> = set.pp_ups.iter().map(u8::to_string).collect();
+    let misc = [
+        if set.happiness == 255 { String::new() } else { set.happiness.to_string() },
+        String::new(), // pokeball
+        set.hidden_power.map(|t| type_name(t).to_string()).unwrap_or_default(),
+        if set.gigantamax { "G".to_string() } else { String::new() },
+        if set.dynamax_level == 10 { String::new() } else { set.dynamax_level.to_string() },
+        set.tera_type.map(|t| type_name(t).to_string()).unwrap_or_default(),
+        ups.join("/"),
+    ];
+    let misc = misc.join(",");
+    [
+        name.to_string(),
+        species,
+        set.item.as_deref().map(to_id).unwrap_or_default(),
+        set.ability.as_deref().map(to_id).unwrap_or_default(),
+        moves.join(","),
+        nature.to_string(),
+        evs,
+        set.gender.map(|g| g.letter().to_string()).unwrap_or_default(),
+        ivs,
+        if set.shiny { "S".to_string() } else { String::new() },
+        level,
+        misc.trim_end_matches(',').to_string(),
+    ]
+    .join("|")
+}
+
+fn pack_stats(values: &[u8; 6], default: u8) -> String {
+    if values.iter().all(|v| *v == default) {
+        return String::new();
+    }
+    values
+        .iter()
+        .map(|v| if *v == default { String::new() } else { v.to_string() })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Showdown's JSON team format: an array of `PokemonSet` objects.
+/// `ppUps` is only written when specified.
+pub fn team_to_json(sets: &[PokemonSet]) -> Value {
+    Value::Array(sets.iter().map(set_to_json).collect())
+}
+
+fn set_to_json(set: &PokemonSet) -> Value {
+    let stats = |values: &[u8; 6]| -> Value {
+        Value::Object(STAT_IDS.iter().zip(values).map(|(id, v)| (id.to_string(), json!(v))).collect::<Map<_, _>>())
+    };
+    let mut value = json!({
+        "name": set.name(),
+        "species": set.species,
+        "item": set.item.clone().unwrap_or_default(),
+        "ability": set.ability.clone().unwrap_or_default(),
+        "moves": set.moves,
+        "nature": set.nature.name(),
+        "gender": set.gender.map(Gender::letter).unwrap_or(""),
+        "evs": stats(&set.evs),
+        "ivs": stats(&set.ivs),
+        "level": set.level,
+    });
+    let map = value.as_object_mut().expect("object literal");
+    if set.shiny {
+        map.insert("shiny".into(), json!(true));
+    }
+    if set.happiness != 255 {
+        map.insert("happiness".into(), json!(set.happiness));
+    }
+    if let Some(hp_type) = set.hidden_power {
+        map.insert("hpType".into(), json!(type_name(hp_type)));
+    }
+    if set.dynamax_level != 10 {
+        map.insert("dynamaxLevel".into(), json!(set.dynamax_level));
+    }
+    if set.gigantamax {
+        map.insert("gigantamax".into(), json!(true));
+    }
+    if let Some(tera) = set.tera_type {
+        map.insert("teraType".into(), json!(type_name(tera)));
+    }
+    if !set.pp_ups.is_empty() {
+        map.insert("ppUps".into(), json!(set.pp_ups));
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{parse_json_sets, parse_packed_sets, parse_showdown_sets};
+    use anyhow::Result;
+
+    fn sample_team() -> Vec<PokemonSet> {
+        let mut chomp = PokemonSet::new("Garchomp");
+        chomp.nickname = Some("Chompy".to_string());
+        chomp.gender = Some(Gender::Female);
+        chomp.item = Some("Choice Scarf".to_string());
+        chomp.ability = Some("Rough Skin".to_string());
+        chomp.moves = vec!["Earthquake".to_string(), "Outrage".to_string(), "Hidden Power".to_string()];
+        chomp.nature = Nature::Jolly;
+        chomp.evs = [0, 252, 0, 0, 4, 252];
+        chomp.ivs = [31, 31, 31, 30, 31, 31];
+        chomp.shiny = true;
+        chomp.happiness = 0;
+        chomp.hidden_power = Some(Type::Ice);
+        chomp.tera_type = Some(Type::Steel);
+        chomp.pp_ups = vec![3, 0];
+        let mut pika = PokemonSet::new("Pikachu");
+        pika.level = 100;
+        pika.moves = vec!["Thunderbolt".to_string()];
+        pika.dynamax_level = 5;
+        pika.gigantamax = true;
+        vec![chomp, pika]
+    }
+
+    #[test]
+    fn name_falls_back_to_species() {
+        let mut set = PokemonSet::new("Garchomp");
+        assert_eq!(set.name(), "Garchomp");
+        set.nickname = Some("Chompy".to_string());
+        assert_eq!(set.name(), "Chompy");
+    }
+
+    #[test]
+    fn pp_ups_scale_max_pp() {
+        let dex = Dex::builtin();
+        let mut set = PokemonSet::new("Pikachu");
+        set.moves = vec!["Thunderbolt".to_string(), "Quick Attack".to_string()];
+        set.pp_ups = vec![0];
+        assert_eq!(set.max_pp(&dex, 0), Some(15));
+        assert_eq!(set.max_pp(&dex, 1), Some(48));
+        assert_eq!(set.max_pp(&dex, 2), None);
+    }
+
+    #[test]
+    fn text_export_round_trips() -> Result<()> {
+        let team = sample_team();
+        let text = export_team(&team);
+        assert!(text.starts_with("Chompy (Garchomp) (F) @ Choice Scarf\nAbility: Rough Skin\nLevel: 50\nShiny: Yes\n"));
+        assert!(text.contains("- Hidden Power [Ice]\n"));
+        assert!(text.contains("EVs: 252 Atk / 4 SpD / 252 Spe\nJolly Nature\nIVs: 30 SpA\n"));
+        assert_eq!(parse_showdown_sets(&text)?, team);
+        Ok(())
+    }
+
+    #[test]
+    fn packed_round_trips() -> Result<()> {
+        let team = sample_team();
+        let packed = pack_team(&team);
+        assert_eq!(
+            packed,
+            "Chompy|garchomp|choicescarf|roughskin|earthquake,outrage,hiddenpower|Jolly|,252,,,4,252|F|,,,30,,|S|50|0,,Ice,,,Steel,3/0]\
+             Pikachu||||thunderbolt|||||||,,,G,5"
+        );
+        assert_eq!(parse_packed_sets(&packed)?, team);
+        Ok(())
+    }
+
+    #[test]
+    fn json_round_trips() -> Result<()> {
+        let team = sample_team();
+        let json = team_to_json(&team);
+        assert_eq!(json[0]["name"], "Chompy");
+        assert_eq!(json[0]["evs"]["spe"], 252);
+        assert_eq!(json[1]["gigantamax"], true);
+        assert_eq!(parse_json_sets(&json.to_string())?, team);
+        Ok(())
+    }
+
+    #[test]
+    fn showdown_packed_team_is_read() -> Result<()> {
+        let sets = parse_packed_sets("Rotom-Wash||leftovers|levitate|voltswitch,hydropump|Bold|252,,216,,,40||,0,,,,|||]")?;
+        assert_eq!(sets[0].species, "Rotom-Wash");
+        assert_eq!(sets[0].item.as_deref(), Some("Leftovers"));
+        assert_eq!(sets[0].moves, ["Volt Switch", "Hydro Pump"]);
+        assert_eq!(sets[0].ivs[1], 0);
+        assert_eq!(sets[0].level, 100);
+        assert!(parse_packed_sets("Pikachu|only|three").is_err());
+        Ok(())
+    }
+}
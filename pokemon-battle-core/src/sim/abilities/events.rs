@@ -1,6 +1,6 @@
 use crate::sim::battle::BattleState;
 use crate::sim::pokemon::Pokemon;
-use rand::rngs::SmallRng;
+use crate::sim::chance::Chance;
 use std::collections::HashMap;
 
 // Showdown reference:
@@ -52,7 +52,7 @@ pub struct AbilityContext<'a> {
     pub pokemon: &'a mut Pokemon,
     pub opponent: &'a mut Pokemon,
     pub state: &'a mut BattleState,
-    pub rng: &'a mut SmallRng,
+    pub rng: &'a mut dyn Chance,
 }
 
 pub trait AbilityEffect: Send + Sync {
@@ -63,6 +63,12 @@ pub struct AbilityRegistry {
     effects: HashMap<String, Box<dyn AbilityEffect>>,
 }
 
+impl Default for AbilityRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl AbilityRegistry {
     pub fn new() -> Self {
         Self {
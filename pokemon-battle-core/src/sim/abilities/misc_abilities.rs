@@ -1,9 +1,9 @@
 use crate::data::types::Type;
-use crate::i18n::translate_pokemon;
-use crate::sim::battle::{apply_status_with_field, format_status, Field};
+use crate::dex::Dex;
+use crate::i18n::messages::{narrate, status_key};
+use crate::sim::battle::{apply_status_with_field, Field};
 use crate::sim::pokemon::{Pokemon, Status};
-use rand::rngs::SmallRng;
-use rand::Rng;
+use crate::sim::chance::Chance;
 
 // Implemented abilities (A4):
 // - Rough Skin, Iron Barbs, Effect Spore
@@ -17,10 +17,10 @@ pub(crate) enum WaterAbsorbKind {
 }
 
 impl WaterAbsorbKind {
-    pub(crate) fn display_name(self) -> &'static str {
+    pub(crate) fn ability(self) -> &'static str {
         match self {
-            WaterAbsorbKind::WaterAbsorb => "ちょすい",
-            WaterAbsorbKind::DrySkin => "かんそうはだ",
+            WaterAbsorbKind::WaterAbsorb => "Water Absorb",
+            WaterAbsorbKind::DrySkin => "Dry Skin",
         }
     }
 }
@@ -75,47 +75,45 @@ pub(crate) fn speed_multiplier(pokemon: &Pokemon, is_rain: bool, is_sun: bool) -
     1.0
 }
 
-pub(crate) fn apply_contact_damage_abilities(attacker: &mut Pokemon, defender: &Pokemon) {
-    let attacker_ja = translate_pokemon(&attacker.species);
-    let mut applied = false;
-    if defender.has_ability("Rough Skin") {
-        applied = true;
-    } else if defender.has_ability("Iron Barbs") {
-        applied = true;
-    }
-    if !applied {
+pub(crate) fn apply_contact_damage_abilities(dex: &Dex, attacker: &mut Pokemon, defender: &Pokemon) {
+    let attacker_ja = attacker.display_name(dex);
+    if !defender.has_ability("Rough Skin") && !defender.has_ability("Iron Barbs") {
         return;
     }
     let dmg = (attacker.stats.hp as u32 / 8).max(1) as u16;
     attacker.take_damage(dmg);
-    println!(
-        "  {}は{}のダメージをうけた！ (HP: {}/{})",
-        attacker_ja, dmg, attacker.current_hp, attacker.stats.hp
+    narrate!(
+        "damage",
+        name = attacker_ja,
+        damage = dmg,
+        hp = attacker.current_hp,
+        max_hp = attacker.stats.hp,
     );
     if attacker.is_fainted() {
-        println!("  {}はたおれた！", attacker_ja);
+        narrate!("fainted", name = attacker_ja);
     }
 }
 
 pub(crate) fn apply_effect_spore(
+    dex: &Dex,
     attacker: &mut Pokemon,
     defender: &Pokemon,
     field: Option<Field>,
-    rng: &mut SmallRng,
+    rng: &mut dyn Chance,
 ) {
     if !defender.has_ability("Effect Spore") {
         return;
     }
-    if !rng.gen_bool(0.3) {
+    if !rng.chance(0.3) {
         return;
     }
-    let status = match rng.gen_range(0..3) {
+    let status = match rng.roll(3) {
         0 => Status::Poison,
         1 => Status::Paralysis,
         _ => Status::Sleep,
     };
     if apply_status_with_field(attacker, status, false, field, rng) {
-        let attacker_ja = translate_pokemon(&attacker.species);
-        println!("  {}は{}！", attacker_ja, format_status(status));
+        let attacker_ja = attacker.display_name(dex);
+        narrate!(status_key(status), name = attacker_ja);
     }
 }
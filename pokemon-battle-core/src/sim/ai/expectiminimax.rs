@@ -0,0 +1,240 @@
+//! Expectiminimax search for singles: an exact, depth-limited search for 1v1s and
+//! endgames.
+//!
+//! Every turn is a simultaneous-move game. For each pair of legal actions the turn is
+//! replayed once per chance outcome ([`chance::enumerate`](crate::sim::chance::enumerate): speed ties, accuracy, crits,
+//! damage-roll buckets, secondary effects), branches ending in the same position are merged
+//! ([`outcomes::merge`](crate::sim::outcomes::merge)) and the resulting positions are searched a turn
+//! deeper, or scored by an [`Evaluator`] at the depth limit. The expected values form the
+//! payoff matrix for player A, which is solved for both players' mixed strategies
+//! ([`nash::solve`](crate::sim::nash::solve)).
+//!
+//! Pruning: chance branches less likely than `min_probability` are cut, and strictly
+//! dominated actions are removed before a matrix is solved.
+
+use super::{sample_strategy, BattleAI};
+use crate::engine::{actions_for, battle_outcome, play_turn, Player};
+use crate::i18n::messages::silently;
+use crate::sim::battle::{Action, BattleResult, BattleState};
+use crate::sim::chance::{enumerate, EnumerateOptions};
+use crate::sim::nash::{solve, Equilibrium};
+use crate::sim::outcomes::merge;
+use crate::sim::pokemon::Pokemon;
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+
+/// Scores a position for player A, from -1 (lost) to 1 (won).
+pub trait Evaluator {
+    fn evaluate(&self, state: &BattleState) -> f64;
+}
+
+impl<F: Fn(&BattleState) -> f64> Evaluator for F {
+    fn evaluate(&self, state: &BattleState) -> f64 {
+        self(state)
+    }
+}
+
+/// Remaining HP: the difference of both teams' summed HP fractions, over the team size.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HpEvaluator;
+
+impl Evaluator for HpEvaluator {
+    fn evaluate(&self, state: &BattleState) -> f64 {
+        let hp = |active: &Pokemon, bench: &[Pokemon]| -> f64 {
+            std::iter::once(active)
+                .chain(bench)
+                .map(|pokemon| pokemon.current_hp as f64 / pokemon.stats.hp.max(1) as f64)
+                .sum()
+        };
+        let size = (1 + state.bench_a.len().max(state.bench_b.len())) as f64;
+        (hp(&state.pokemon_a, &state.bench_a) - hp(&state.pokemon_b, &state.bench_b)) / size
+    }
+}
+
+/// Search settings.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SearchParams {
+    /// Turns searched before positions are evaluated.
+    pub depth: usize,
+    /// How finely each turn's chance outcomes are split.
+    pub chance: EnumerateOptions,
+}
+
+impl Default for SearchParams {
+    fn default() -> Self {
+        Self { depth: 2, chance: EnumerateOptions { damage_buckets: 4, min_probability: 0.01, max_paths: 256 } }
+    }
+}
+
+/// The solved root turn.
+#[derive(Clone, Debug)]
+pub struct Analysis {
+    /// Expected score for player A under both equilibrium strategies.
+    pub value: f64,
+    pub actions_a: Vec<Action>,
+    pub actions_b: Vec<Action>,
+    /// Probability of each of `actions_a` / `actions_b`.
+    pub strategy_a: Vec<f64>,
+    pub strategy_b: Vec<f64>,
+    /// Expected score for player A, by `[action A][action B]`.
+    pub payoff: Vec<Vec<f64>>,
+    /// Positions searched.
+    pub nodes: usize,
+}
+
+impl Analysis {
+    /// `player`'s actions with their probabilities.
+    pub fn strategy(&self, player: Player) -> Vec<(Action, f64)> {
+        let (actions, weights) = match player {
+            Player::A => (&self.actions_a, &self.strategy_a),
+            Player::B => (&self.actions_b, &self.strategy_b),
+        };
+        actions.iter().copied().zip(weights.iter().copied()).collect()
+    }
+
+    /// `player`'s most likely action.
+    pub fn best(&self, player: Player) -> Option<Action> {
+        self.strategy(player).into_iter().max_by(|a, b| a.1.total_cmp(&b.1)).map(|(action, _)| action)
+    }
+}
+
+/// Search `state` (not in team preview) and solve its turn.
+pub fn analyze(state: &BattleState, params: SearchParams, evaluator: &dyn Evaluator) -> Analysis {
+    let mut search = Search { params, evaluator, nodes: 0 };
+    silently(|| search.solve_turn(state, params.depth.max(1)))
+}
+
+struct Search<'a> {
+    params: SearchParams,
+    evaluator: &'a dyn Evaluator,
+    nodes: usize,
+}
+
+impl Search<'_> {
+    fn value(&mut self, state: &BattleState, depth: usize) -> f64 {
+        self.nodes += 1;
+        match battle_outcome(state) {
+            Some(BattleResult::TeamAWins) => 1.0,
+            Some(BattleResult::TeamBWins) => -1.0,
+            Some(BattleResult::Draw) => 0.0,
+            None if depth == 0 => self.evaluator.evaluate(state).clamp(-1.0, 1.0),
+            None => self.solve_turn(state, depth).value,
+        }
+    }
+
+    fn expected(&mut self, state: &BattleState, action_a: Action, action_b: Action, depth: usize) -> f64 {
+        let branches = enumerate(state, self.params.chance, |after, chance| play_turn(after, action_a, action_b, chance));
+        merge(branches)
+            .iter()
+            .map(|outcome| outcome.probability * self.value(&outcome.state, depth - 1))
+            .sum()
+    }
+
+    fn solve_turn(&mut self, state: &BattleState, depth: usize) -> Analysis {
+        let actions_a = actions_for(&state.pokemon_a, &state.bench_a);
+        let actions_b = actions_for(&state.pokemon_b, &state.bench_b);
+        let payoff: Vec<Vec<f64>> = actions_a
+            .iter()
+            .map(|&a| actions_b.iter().map(|&b| self.expected(state, a, b, depth)).collect())
+            .collect();
+        let Equilibrium { value, row: strategy_a, col: strategy_b } = solve(&payoff);
+        Analysis { value, actions_a, actions_b, strategy_a, strategy_b, payoff, nodes: self.nodes }
+    }
+}
+
+/// Plays the search's equilibrium strategy, sampling among mixed actions.
+pub struct ExpectiminimaxAI {
+    params: SearchParams,
+    evaluator: Box<dyn Evaluator>,
+    side: usize,
+    rng: SmallRng,
+}
+
+impl ExpectiminimaxAI {
+    pub fn new(params: SearchParams, seed: u64) -> Self {
+        Self::with_evaluator(params, Box::new(HpEvaluator), seed)
+    }
+
+    pub fn with_evaluator(params: SearchParams, evaluator: Box<dyn Evaluator>, seed: u64) -> Self {
+        Self { params, evaluator, side: 0, rng: SmallRng::seed_from_u64(seed) }
+    }
+
+    pub fn analyze(&self, state: &BattleState) -> Analysis {
+        analyze(state, self.params, self.evaluator.as_ref())
+    }
+}
+
+impl BattleAI for ExpectiminimaxAI {
+    fn choose_action(&mut self, state: &BattleState, valid_actions: &[Action]) -> Action {
+        if valid_actions.len() <= 1 {
+            return valid_actions.first().copied().unwrap_or(Action::Move(0));
+        }
+        let player = if self.side == 0 { Player::A } else { Player::B };
+        sample_strategy(&self.analyze(state).strategy(player), valid_actions, &mut self.rng)
+    }
+
+    fn set_side(&mut self, side: usize) {
+        self.side = side.min(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::stats::Nature;
+
+    fn make(species: &str, moves: &[&str]) -> Pokemon {
+        Pokemon::new(
+            species,
+            50,
+            [0, 252, 0, 252, 0, 0],
+            [31; 6],
+            Nature::Hardy,
+            moves.iter().map(|m| m.to_string()).collect(),
+            "Pressure",
+            None,
+        )
+        .unwrap()
+    }
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-6, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn takes_the_guaranteed_knockout() {
+        let mut pikachu = make("Pikachu", &["Growl", "Thunderbolt"]);
+        let mut gyarados = make("Gyarados", &["Tackle"]);
+        pikachu.current_hp = 5;
+        gyarados.current_hp = 20;
+        let state = BattleState::new(pikachu, gyarados);
+        let analysis = analyze(&state, SearchParams { depth: 1, ..SearchParams::default() }, &HpEvaluator);
+        assert_eq!(analysis.best(Player::A), Some(Action::Move(1)));
+        assert_close(analysis.strategy_a[1], 1.0);
+        // Thunderbolt goes first and always knocks out; a miss is impossible.
+        assert_close(analysis.payoff[1][0], 1.0);
+        assert!(analysis.value > 0.99);
+
+        let mut ai = ExpectiminimaxAI::new(SearchParams { depth: 1, ..SearchParams::default() }, 1);
+        assert_eq!(ai.choose_action(&state, &[Action::Move(0), Action::Move(1)]), Action::Move(1));
+    }
+
+    #[test]
+    fn deeper_search_sees_two_turn_knockouts_and_evaluators_are_pluggable() {
+        let state = BattleState::new(
+            make("Garchomp", &["Earthquake", "Dragon Claw"]),
+            make("Heatran", &["Flamethrower"]),
+        );
+        let shallow = analyze(&state, SearchParams { depth: 1, ..SearchParams::default() }, &HpEvaluator);
+        let deep = analyze(&state, SearchParams { depth: 2, ..SearchParams::default() }, &HpEvaluator);
+        assert_eq!(deep.best(Player::A), Some(Action::Move(0)));
+        assert!(deep.nodes > shallow.nodes);
+        assert!(deep.value >= -1.0 && deep.value <= 1.0);
+
+        // Nobody can faint in one turn here, so every leaf gets the evaluator's score.
+        let mirror = BattleState::new(make("Snorlax", &["Tackle"]), make("Snorlax", &["Tackle"]));
+        let always_b = |_: &BattleState| -0.5;
+        let analysis = analyze(&mirror, SearchParams { depth: 1, ..SearchParams::default() }, &always_b);
+        assert_close(analysis.value, -0.5);
+    }
+}
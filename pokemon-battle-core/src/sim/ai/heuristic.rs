@@ -0,0 +1,596 @@
+//! Heuristic AI: scores every legal action from damage estimates (`sim::damage`), KO
+//! ranges, speed order, the value of status moves and hazards, and — at the top
+//! difficulty — switching out of bad matchups and setting up when it is safe.
+
+use super::BattleAI;
+use crate::data::moves::{normalize_move_name, MoveCategory};
+use crate::data::types::Type;
+use crate::dex::Dex;
+use crate::sim::battle::{
+    apply_stage_multiplier, burn_damage_modifier, effective_speed, effective_types, fixed_damage,
+    screen_damage_modifier, Action, BattleState, SideConditions, STAGE_ATK, STAGE_DEF, STAGE_SPA, STAGE_SPD,
+};
+use crate::sim::damage::{
+    ability_attack_modifier, ability_defense_modifier, calculate_damage_with_modifiers, is_stab, item_type_boost,
+    DamageModifiers,
+};
+use crate::sim::items::battle_items;
+use crate::sim::moves::attacking::calculate_variable_power;
+use crate::sim::moves::flags::check_ability_immunity;
+use crate::sim::pokemon::{parse_type, Pokemon, Status};
+use crate::sim::team_preview::{TeamChoice, TeamPreview};
+use crate::sim::weather_field::{effective_accuracy, weather_damage_modifier};
+
+/// How much the AI thinks ahead.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Difficulty {
+    /// The move with the highest expected damage; never switches or uses status moves.
+    Greedy,
+    /// Damage with KO ranges and speed order, status moves and hazards.
+    Standard,
+    /// Standard, plus switching out of bad matchups and setting up when it is safe.
+    Expert,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Greedy, Difficulty::Standard, Difficulty::Expert];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Difficulty::Greedy => "greedy",
+            Difficulty::Standard => "standard",
+            Difficulty::Expert => "expert",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.name().eq_ignore_ascii_case(name.trim()))
+    }
+}
+
+/// Damage one hit of a move would do, from the lowest to the highest roll (no crits).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct DamageEstimate {
+    pub min: u16,
+    pub max: u16,
+}
+
+impl DamageEstimate {
+    pub fn average(&self) -> f32 {
+        (self.min as f32 + self.max as f32) / 2.0
+    }
+
+    /// Chance (0-1) that the move knocks out a target at `hp`, assuming even rolls.
+    pub fn ko_chance(&self, hp: u16) -> f32 {
+        if hp == 0 || self.min >= hp {
+            1.0
+        } else if self.max < hp {
+            0.0
+        } else {
+            (self.max - hp + 1) as f32 / (self.max - self.min + 1) as f32
+        }
+    }
+}
+
+/// Estimate `move_id` from `attacker` against `defender` (whose side has `defender_side`)
+/// in `state`. `None` for status moves and unknown moves.
+pub fn estimate_damage(
+    state: &BattleState,
+    attacker: &Pokemon,
+    defender: &Pokemon,
+    defender_side: &SideConditions,
+    move_id: &str,
+) -> Option<DamageEstimate> {
+    let data = state.dex.get_move(move_id)?;
+    if data.category == MoveCategory::Status {
+        return None;
+    }
+    let id = normalize_move_name(data.name);
+    let move_type = parse_type(data.move_type).unwrap_or(Type::Typeless);
+    let [t0, t1] = effective_types(defender);
+    let effectiveness = state.type_chart.effectiveness_dual(move_type, t0, t1);
+    if effectiveness == 0.0 || check_ability_immunity(defender, data) {
+        return Some(DamageEstimate::default());
+    }
+    if let Some(fixed) = fixed_damage(&id, attacker, defender) {
+        return Some(DamageEstimate { min: fixed, max: fixed });
+    }
+    let power = calculate_variable_power(data, attacker, defender, state.weather, state.field);
+    if power == 0 {
+        return Some(DamageEstimate::default());
+    }
+    let (attack, defense) = match data.category {
+        MoveCategory::Physical => (
+            apply_stage_multiplier(attacker.stats.atk, attacker.stat_stages[STAGE_ATK]),
+            apply_stage_multiplier(defender.stats.def, defender.stat_stages[STAGE_DEF]),
+        ),
+        _ => (
+            apply_stage_multiplier(attacker.stats.spa, attacker.stat_stages[STAGE_SPA]),
+            apply_stage_multiplier(defender.stats.spd, defender.stat_stages[STAGE_SPD]),
+        ),
+    };
+    let attack = ((attack as f32) * battle_items::attack_stat_modifier(attacker, data.category)).max(1.0) as u16;
+    let is_sand = state.weather == Some(crate::sim::battle::Weather::Sand);
+    let type_item = attacker.item.as_deref().map_or(1.0, |item| item_type_boost(item, move_type));
+    let screens = screen_damage_modifier(
+        defender_side.reflect_turns,
+        defender_side.light_screen_turns,
+        defender_side.aurora_veil_turns,
+        data.category,
+        false,
+    );
+    let modifiers = DamageModifiers {
+        weather: weather_damage_modifier(state.weather, move_type),
+        crit: 1.0,
+        burn: burn_damage_modifier(attacker, data.category, &id),
+        final_modifier: ability_attack_modifier(attacker, data, move_type, is_sand)
+            * ability_defense_modifier(defender, data, effectiveness)
+            * type_item
+            * screens,
+    };
+    let stab = is_stab(move_type, attacker.types);
+    let hits = match data.multihit {
+        Some((low, high)) if low == high => low as u16,
+        Some(_) => 3,
+        None => 1,
+    };
+    let roll = |factor| {
+        calculate_damage_with_modifiers(attacker.level, attack, defense.max(1), power, effectiveness, stab, factor, modifiers)
+            .saturating_mul(hits)
+    };
+    Some(DamageEstimate { min: roll(0.85), max: roll(1.0) })
+}
+
+// Status moves the AI knows how to value, by id.
+enum StatusMove {
+    Inflict(Status),
+    Hazard,
+    HazardRemoval,
+    Recovery,
+    Setup,
+    Screen,
+    Protect,
+    Other,
+}
+
+fn classify(id: &str) -> StatusMove {
+    match id {
+        "thunderwave" | "glare" | "stunspore" => StatusMove::Inflict(Status::Paralysis),
+        "toxic" | "poisonpowder" | "toxicthread" => StatusMove::Inflict(Status::Poison),
+        "willowisp" => StatusMove::Inflict(Status::Burn),
+        "spore" | "sleeppowder" | "hypnosis" | "sing" | "darkvoid" | "lovelykiss" | "yawn" => {
+            StatusMove::Inflict(Status::Sleep)
+        }
+        "stealthrock" | "spikes" | "toxicspikes" | "stickyweb" => StatusMove::Hazard,
+        "defog" | "rapidspin" | "courtchange" | "tidyup" | "mortalspin" => StatusMove::HazardRemoval,
+        "recover" | "roost" | "softboiled" | "slackoff" | "moonlight" | "morningsun" | "synthesis" | "shoreup"
+        | "milkdrink" | "wish" | "strengthsap" => StatusMove::Recovery,
+        "swordsdance" | "nastyplot" | "dragondance" | "calmmind" | "quiverdance" | "bulkup" | "irondefense"
+        | "curse" | "shellsmash" | "agility" | "howl" | "coil" | "shiftgear" | "victorydance" | "tailglow"
+        | "bellydrum" | "growth" | "workup" | "autotomize" | "rockpolish" | "amnesia" | "cosmicpower" => {
+            StatusMove::Setup
+        }
+        "reflect" | "lightscreen" | "auroraveil" => StatusMove::Screen,
+        "protect" | "detect" | "kingsshield" | "spikyshield" | "banefulbunker" | "silktrap" => StatusMove::Protect,
+        _ => StatusMove::Other,
+    }
+}
+
+fn immune_to_status(state: &BattleState, target: &Pokemon, id: &str, status: Status) -> bool {
+    let has = |t: Type| target.types.contains(&t);
+    let powder = state.dex.get_move(id).is_some_and(|m| m.flags.contains(&"powder"));
+    (powder && has(Type::Grass))
+        || match status {
+            Status::Paralysis => has(Type::Electric) || (id == "thunderwave" && has(Type::Ground)),
+            Status::Burn => has(Type::Fire),
+            Status::Poison => has(Type::Poison) || has(Type::Steel),
+            _ => false,
+        }
+}
+
+/// The battle from one side's point of view.
+struct View<'a> {
+    me: &'a Pokemon,
+    foe: &'a Pokemon,
+    my_bench: &'a [Pokemon],
+    foe_bench: &'a [Pokemon],
+    my_side: &'a SideConditions,
+    foe_side: &'a SideConditions,
+}
+
+fn view(state: &BattleState, side: usize) -> View<'_> {
+    if side == 0 {
+        View {
+            me: &state.pokemon_a,
+            foe: &state.pokemon_b,
+            my_bench: &state.bench_a,
+            foe_bench: &state.bench_b,
+            my_side: &state.side_a,
+            foe_side: &state.side_b,
+        }
+    } else {
+        View {
+            me: &state.pokemon_b,
+            foe: &state.pokemon_a,
+            my_bench: &state.bench_b,
+            foe_bench: &state.bench_a,
+            my_side: &state.side_b,
+            foe_side: &state.side_a,
+        }
+    }
+}
+
+// The best estimate among `attacker`'s moves (by average damage).
+fn best_damage(state: &BattleState, attacker: &Pokemon, defender: &Pokemon, defender_side: &SideConditions) -> DamageEstimate {
+    attacker
+        .moves
+        .iter()
+        .filter_map(|id| estimate_damage(state, attacker, defender, defender_side, id))
+        .max_by(|a, b| a.average().total_cmp(&b.average()))
+        .unwrap_or_default()
+}
+
+fn hp_percent(damage: f32, target: &Pokemon) -> f32 {
+    damage.min(target.current_hp as f32) / target.stats.hp.max(1) as f32 * 100.0
+}
+
+fn speed(state: &BattleState, pokemon: &Pokemon, side: &SideConditions) -> u32 {
+    let speed = effective_speed(pokemon, state.weather) as u32;
+    if side.tailwind_turns > 0 {
+        speed * 2
+    } else {
+        speed
+    }
+}
+
+// Entry hazard damage `pokemon` would take switching in on `side`, in HP.
+fn hazard_damage(state: &BattleState, pokemon: &Pokemon, side: &SideConditions) -> u16 {
+    let max_hp = pokemon.stats.hp as f32;
+    let mut fraction = 0.0;
+    if side.stealth_rock {
+        let [t0, t1] = pokemon.types;
+        fraction += 0.125 * state.type_chart.effectiveness_dual(Type::Rock, t0, t1);
+    }
+    let grounded = !pokemon.types.contains(&Type::Flying) && !pokemon.has_ability("Levitate");
+    if grounded {
+        fraction += match side.spikes {
+            0 => 0.0,
+            1 => 0.125,
+            2 => 1.0 / 6.0,
+            _ => 0.25,
+        };
+    }
+    (max_hp * fraction) as u16
+}
+
+/// How much better (in matchup points) a switch-in must be before the expert switches.
+const SWITCH_MARGIN: f32 = 40.0;
+/// Bonus per unit of KO chance when the move lands before the foe moves, and after it.
+const KO_FIRST_BONUS: f32 = 120.0;
+const KO_SECOND_BONUS: f32 = 50.0;
+
+/// A [`BattleAI`] that scores each legal action with battle heuristics.
+#[derive(Clone, Debug)]
+pub struct HeuristicAI {
+    difficulty: Difficulty,
+    side: usize,
+}
+
+impl HeuristicAI {
+    pub fn new(difficulty: Difficulty) -> Self {
+        Self { difficulty, side: 0 }
+    }
+
+    /// Play side `side` (0 = team A, 1 = team B) without waiting for [`BattleAI::set_side`].
+    pub fn with_side(mut self, side: usize) -> Self {
+        self.side = side.min(1);
+        self
+    }
+
+    pub fn difficulty(&self) -> Difficulty {
+        self.difficulty
+    }
+
+    /// Score of `action` for this AI's side (higher is better).
+    pub fn score(&self, state: &BattleState, action: Action) -> f32 {
+        let view = view(state, self.side);
+        match action {
+            Action::Move(idx) => view.me.moves.get(idx).map_or(f32::MIN, |id| self.score_move(state, &view, id)),
+            Action::Switch(idx) => view.my_bench.get(idx).map_or(f32::MIN, |target| self.score_switch(state, &view, target)),
+        }
+    }
+
+    fn moves_first(&self, state: &BattleState, view: &View, priority: i8) -> bool {
+        if priority != 0 {
+            return priority > 0;
+        }
+        let mine = speed(state, view.me, view.my_side);
+        let theirs = speed(state, view.foe, view.foe_side);
+        if state.trick_room_turns > 0 {
+            mine < theirs
+        } else {
+            mine > theirs
+        }
+    }
+
+    fn score_move(&self, state: &BattleState, view: &View, id: &str) -> f32 {
+        let Some(data) = state.dex.get_move(id) else {
+            return f32::MIN;
+        };
+        let accuracy = effective_accuracy(data, state.weather).map_or(1.0, |acc| acc / 100.0);
+        if let Some(estimate) = estimate_damage(state, view.me, view.foe, view.foe_side, id) {
+            if self.difficulty == Difficulty::Greedy {
+                return estimate.average() * accuracy;
+            }
+            let damage = hp_percent(estimate.average(), view.foe) * accuracy;
+            let ko = estimate.ko_chance(view.foe.current_hp) * accuracy;
+            let bonus = if self.moves_first(state, view, data.priority) { KO_FIRST_BONUS } else { KO_SECOND_BONUS };
+            return damage + ko * bonus;
+        }
+        if self.difficulty == Difficulty::Greedy {
+            return 0.0;
+        }
+        let normalized = normalize_move_name(data.name);
+        let threat = best_damage(state, view.foe, view.me, view.my_side);
+        let in_danger = threat.max >= view.me.current_hp && !self.moves_first(state, view, data.priority);
+        let foe_reserves = view.foe_bench.iter().filter(|p| !p.is_fainted()).count() as f32;
+        let my_reserves = view.my_bench.iter().filter(|p| !p.is_fainted()).count();
+        let value = match classify(&normalized) {
+            StatusMove::Inflict(status) => {
+                if view.foe.status.is_some() || immune_to_status(state, view.foe, &normalized, status) {
+                    -10.0
+                } else {
+                    let base = match status {
+                        Status::Sleep => 60.0,
+                        Status::Paralysis if !self.moves_first(state, view, 0) => 55.0,
+                        Status::Paralysis => 35.0,
+                        Status::Burn if view.foe.stats.atk > view.foe.stats.spa => 50.0,
+                        Status::Burn => 20.0,
+                        _ => 40.0,
+                    };
+                    base * accuracy
+                }
+            }
+            StatusMove::Hazard => {
+                let side = view.foe_side;
+                let open = match normalized.as_str() {
+                    "stealthrock" => !side.stealth_rock,
+                    "spikes" => side.spikes < 3,
+                    "toxicspikes" => side.toxic_spikes < 2,
+                    _ => !side.sticky_web,
+                };
+                if open && foe_reserves > 0.0 {
+                    15.0 + 5.0 * foe_reserves
+                } else {
+                    -5.0
+                }
+            }
+            StatusMove::HazardRemoval => {
+                let side = view.my_side;
+                let hazards = side.stealth_rock || side.spikes > 0 || side.toxic_spikes > 0 || side.sticky_web;
+                if hazards && my_reserves > 0 {
+                    30.0
+                } else {
+                    -5.0
+                }
+            }
+            StatusMove::Recovery => {
+                let missing = 1.0 - view.me.current_hp as f32 / view.me.stats.hp.max(1) as f32;
+                if missing < 0.25 {
+                    -5.0
+                } else {
+                    missing * 90.0
+                }
+            }
+            StatusMove::Setup => {
+                let safe = (threat.max as u32) * 2 < view.me.current_hp as u32;
+                let boosted = view.me.stat_stages.iter().any(|&stage| stage >= 2);
+                match self.difficulty {
+                    Difficulty::Expert if safe && !boosted => 55.0,
+                    Difficulty::Standard if safe && !boosted => 15.0,
+                    _ => 0.0,
+                }
+            }
+            StatusMove::Screen => {
+                let side = view.my_side;
+                let up = match normalized.as_str() {
+                    "reflect" => side.reflect_turns > 0,
+                    "lightscreen" => side.light_screen_turns > 0,
+                    _ => side.aurora_veil_turns > 0,
+                };
+                if up {
+                    -5.0
+                } else {
+                    20.0
+                }
+            }
+            StatusMove::Protect => {
+                if view.me.protect_counter > 0 {
+                    -10.0
+                } else {
+                    5.0
+                }
+            }
+            StatusMove::Other => 1.0,
+        };
+        if in_danger && !matches!(classify(&normalized), StatusMove::Protect) {
+            value * 0.3
+        } else {
+            value
+        }
+    }
+
+    // How well `pokemon` fares against the foe: its best damage minus the foe's, in % of HP.
+    fn matchup(&self, state: &BattleState, view: &View, pokemon: &Pokemon, entry_damage: u16) -> f32 {
+        let dealt = hp_percent(best_damage(state, pokemon, view.foe, view.foe_side).average(), view.foe);
+        let taken = best_damage(state, view.foe, pokemon, view.my_side).average() + entry_damage as f32;
+        dealt - hp_percent(taken, pokemon)
+    }
+
+    fn score_switch(&self, state: &BattleState, view: &View, target: &Pokemon) -> f32 {
+        if self.difficulty != Difficulty::Expert || target.is_fainted() {
+            return f32::MIN;
+        }
+        let entry = hazard_damage(state, target, view.my_side);
+        let hit = best_damage(state, view.foe, target, view.my_side);
+        if hit.max.saturating_add(entry) >= target.current_hp {
+            return f32::MIN;
+        }
+        let current = self.matchup(state, view, view.me, 0);
+        let candidate = self.matchup(state, view, target, entry);
+        if current >= 0.0 || candidate < current + SWITCH_MARGIN {
+            return f32::MIN;
+        }
+        candidate - current
+    }
+
+    fn pick(&self, state: &BattleState, valid_actions: &[Action]) -> Action {
+        let mut best: Option<(Action, f32)> = None;
+        for &action in valid_actions {
+            let score = self.score(state, action);
+            if score > f32::MIN && best.is_none_or(|(_, top)| score > top) {
+                best = Some((action, score));
+            }
+        }
+        // Nothing scored (e.g. only switches below the expert tier): first move, else first action.
+        best.map(|(action, _)| action)
+            .or_else(|| valid_actions.iter().copied().find(|a| matches!(a, Action::Move(_))))
+            .or_else(|| valid_actions.first().copied())
+            .unwrap_or(Action::Move(0))
+    }
+}
+
+impl BattleAI for HeuristicAI {
+    fn choose_action(&mut self, state: &BattleState, valid_actions: &[Action]) -> Action {
+        self.pick(state, valid_actions)
+    }
+
+    fn set_side(&mut self, side: usize) {
+        self.side = side.min(1);
+    }
+
+    fn choose_replacement(&mut self, state: &BattleState, options: &[usize]) -> Option<usize> {
+        let view = view(state, self.side);
+        let score = |idx: usize| {
+            let pokemon = &view.my_bench[idx];
+            match self.difficulty {
+                Difficulty::Greedy => best_damage(state, pokemon, view.foe, view.foe_side).average(),
+                _ => self.matchup(state, &view, pokemon, hazard_damage(state, pokemon, view.my_side)),
+            }
+        };
+        options.iter().copied().max_by(|&a, &b| score(a).total_cmp(&score(b)).then(b.cmp(&a)))
+    }
+
+    fn choose_team(&mut self, preview: &TeamPreview, legal: &[TeamChoice]) -> TeamChoice {
+        if self.difficulty == Difficulty::Greedy {
+            return legal[0].clone();
+        }
+        let dex = Dex::builtin();
+        let opponents: Vec<[Type; 2]> = preview
+            .opponent_species
+            .iter()
+            .filter_map(|name| dex.species(name))
+            .map(|data| {
+                let first = parse_type(data.types[0]).unwrap_or(Type::Typeless);
+                [first, parse_type(data.types[1]).unwrap_or(first)]
+            })
+            .collect();
+        let chart = crate::sim::type_chart::TypeChart::default();
+        let score = |pokemon: &Pokemon| -> f32 {
+            let [m0, m1] = pokemon.types;
+            opponents
+                .iter()
+                .map(|&[o0, o1]| {
+                    let offense = chart.effectiveness_dual(m0, o0, o1).max(chart.effectiveness_dual(m1, o0, o1));
+                    let defense = chart.effectiveness_dual(o0, m0, m1).max(chart.effectiveness_dual(o1, m0, m1));
+                    offense - defense
+                })
+                .sum()
+        };
+        let mut ranked: Vec<usize> = (0..preview.team.len()).collect();
+        ranked.sort_by(|&a, &b| score(&preview.team[b]).total_cmp(&score(&preview.team[a])).then(a.cmp(&b)));
+        let mut order: Vec<usize> = ranked.into_iter().take(preview.bring).collect();
+        order[1..].sort_unstable();
+        let choice = TeamChoice { order };
+        if legal.contains(&choice) {
+            choice
+        } else {
+            legal[0].clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::stats::Nature;
+
+    fn make(species: &str, moves: &[&str], item: Option<&str>) -> Pokemon {
+        Pokemon::new(
+            species,
+            50,
+            [0, 252, 0, 252, 0, 0],
+            [31; 6],
+            Nature::Hardy,
+            moves.iter().map(|m| m.to_string()).collect(),
+            "Pressure",
+            item.map(str::to_string),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn estimates_match_the_damage_formula_range() {
+        let garchomp = make("Garchomp", &["Earthquake", "Dragon Claw", "Swords Dance"], None);
+        let pikachu = make("Pikachu", &["Thunderbolt"], None);
+        let gyarados = make("Gyarados", &["Waterfall"], None);
+        let state = BattleState::new(garchomp.clone(), pikachu.clone());
+        let quake = estimate_damage(&state, &garchomp, &pikachu, &state.side_b, "earthquake").unwrap();
+        assert!(quake.min < quake.max && quake.min >= pikachu.stats.hp, "{:?}", quake);
+        assert_eq!(quake.ko_chance(pikachu.current_hp), 1.0);
+        assert_eq!(estimate_damage(&state, &garchomp, &gyarados, &state.side_b, "earthquake"), Some(DamageEstimate::default()));
+        assert_eq!(estimate_damage(&state, &garchomp, &pikachu, &state.side_b, "swordsdance"), None);
+        let claw = estimate_damage(&state, &garchomp, &pikachu, &state.side_b, "dragonclaw").unwrap();
+        assert!(claw.max < quake.min);
+        assert!(DamageEstimate { min: 10, max: 20 }.ko_chance(15) > 0.5);
+    }
+
+    #[test]
+    fn greedy_takes_the_biggest_hit_and_standard_values_status() {
+        let attacker = make("Garchomp", &["Dragon Claw", "Earthquake", "Stealth Rock"], None);
+        let target = make("Heatran", &["Flamethrower"], None);
+        let mut state = BattleState::new_with_bench(attacker, target, Vec::new(), vec![make("Pikachu", &["Thunderbolt"], None)]);
+        let actions = [Action::Move(0), Action::Move(1), Action::Move(2)];
+        assert_eq!(HeuristicAI::new(Difficulty::Greedy).choose_action(&state, &actions), Action::Move(1));
+
+        let standard = HeuristicAI::new(Difficulty::Standard);
+        assert!(standard.score(&state, Action::Move(2)) > 0.0);
+        state.side_b.stealth_rock = true;
+        assert!(standard.score(&state, Action::Move(2)) < 0.0);
+    }
+
+    #[test]
+    fn plays_from_its_own_side() {
+        let pikachu = make("Pikachu", &["Thunderbolt", "Quick Attack"], None);
+        let gyarados = make("Gyarados", &["Waterfall", "Thunder Wave"], None);
+        let state = BattleState::new(pikachu, gyarados);
+        let mut ai = HeuristicAI::new(Difficulty::Standard);
+        ai.set_side(1);
+        let choice = ai.choose_action(&state, &[Action::Move(0), Action::Move(1)]);
+        assert_eq!(choice, Action::Move(0));
+        assert!(HeuristicAI::new(Difficulty::Standard).with_side(0).score(&state, Action::Move(0)) > 100.0);
+    }
+
+    #[test]
+    fn expert_switches_out_of_losing_matchups() {
+        let weak = make("Pikachu", &["Thunderbolt"], None);
+        let foe = make("Garchomp", &["Earthquake", "Dragon Claw"], None);
+        let answer = make("Gyarados", &["Waterfall", "Ice Fang"], None);
+        let state = BattleState::new_with_bench(weak, foe, vec![answer], Vec::new());
+        let actions = [Action::Move(0), Action::Switch(0)];
+        assert_eq!(HeuristicAI::new(Difficulty::Expert).choose_action(&state, &actions), Action::Switch(0));
+        assert_eq!(HeuristicAI::new(Difficulty::Standard).choose_action(&state, &actions), Action::Move(0));
+        assert_eq!(HeuristicAI::new(Difficulty::Standard).choose_replacement(&state, &[0]), Some(0));
+        assert_eq!(Difficulty::from_name("Expert"), Some(Difficulty::Expert));
+    }
+}
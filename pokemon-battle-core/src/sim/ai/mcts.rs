@@ -0,0 +1,599 @@
+//! Monte Carlo Tree Search AI: plays simulated turns on clones of a [`BattleEngine`] and
+//! picks the action whose subtree was visited most.
+//!
+//! Both players choose at once, so the tree uses decoupled UCT: every node keeps action
+//! statistics per player, each player picks by UCB1 on its own statistics, and the joint
+//! action leads to the child. Turns are random (damage rolls, accuracy, speed ties), so the
+//! tree is open-loop: a node stands for a sequence of actions and the battle is replayed
+//! from the root on every iteration.
+//!
+//! The opponent's moves and item are hidden until they are seen. Each search samples
+//! `determinizations` worlds in which the opponent's unseen moves and items are redrawn
+//! from the random set pool ([`RandomSetPool`]), keeping what has been revealed. Every world
+//! gets its own tree, root visit counts are summed, and worlds are spread across
+//! `threads` (root parallelism). With a [`Belief`] ([`MctsAI::with_belief`]) the worlds
+//! are drawn from its candidate sets instead.
+
+use super::{BattleAI, Difficulty, HeuristicAI, RandomAI};
+use crate::data::moves::normalize_move_name;
+use crate::dex::to_id;
+use crate::engine::{BattleEngine, Player};
+use crate::i18n::messages::silently;
+use crate::random_teams::{RandomSet, RandomSetPool};
+use crate::sim::battle::{Action, BattleResult, BattleState};
+use crate::sim::belief::Belief;
+use crate::sim::pokemon::Pokemon;
+use crate::sim::team_preview::{TeamChoice, TeamPreview};
+use rand::rngs::SmallRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+/// Iterations per decision when neither an iteration count nor a time budget is set.
+pub const DEFAULT_ITERATIONS: usize = 400;
+
+/// Which players the tree branches on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MctsMode {
+    /// Decoupled UCT over both players' actions.
+    Joint,
+    /// Only this AI's actions; the opponent plays the rollout policy inside the tree too.
+    MyActionOnly,
+}
+
+/// How turns past the tree are played.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RolloutPolicy {
+    Random,
+    Heuristic(Difficulty),
+}
+
+impl RolloutPolicy {
+    /// `random`, or a [`Difficulty`] name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        if name.trim().eq_ignore_ascii_case("random") {
+            return Some(RolloutPolicy::Random);
+        }
+        Difficulty::from_name(name).map(RolloutPolicy::Heuristic)
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            RolloutPolicy::Random => "random",
+            RolloutPolicy::Heuristic(difficulty) => difficulty.name(),
+        }
+    }
+
+    fn ai(self, side: usize, seed: u64) -> Box<dyn BattleAI> {
+        let mut ai: Box<dyn BattleAI> = match self {
+            RolloutPolicy::Random => Box::new(RandomAI::new(seed)),
+            RolloutPolicy::Heuristic(difficulty) => Box::new(HeuristicAI::new(difficulty)),
+        };
+        ai.set_side(side);
+        ai
+    }
+}
+
+/// Search settings.
+#[derive(Clone, Debug)]
+pub struct MctsParams {
+    /// Iterations per decision, over all worlds.
+    pub iterations: Option<usize>,
+    /// Wall-clock budget per decision. With `iterations` too, whichever runs out first;
+    /// with neither, [`DEFAULT_ITERATIONS`].
+    pub time_budget: Option<Duration>,
+    /// Turns played past the tree before the position is scored.
+    pub rollout_horizon: usize,
+    /// UCB1 exploration constant.
+    pub exploration_constant: f64,
+    pub mode: MctsMode,
+    pub rollout: RolloutPolicy,
+    /// Worlds sampled for the opponent's hidden moves and items (at least 1).
+    pub determinizations: usize,
+    /// Threads the worlds are searched on (at least 1).
+    pub threads: usize,
+}
+
+impl Default for MctsParams {
+    fn default() -> Self {
+        Self {
+            iterations: None,
+            time_budget: None,
+            rollout_horizon: 20,
+            exploration_constant: std::f64::consts::SQRT_2,
+            mode: MctsMode::Joint,
+            rollout: RolloutPolicy::Random,
+            determinizations: 4,
+            threads: 1,
+        }
+    }
+}
+
+/// What the opponent has revealed, by species id: moves used and consumed items.
+#[derive(Clone, Debug, Default)]
+struct Revealed {
+    moves: HashMap<String, BTreeSet<String>>,
+    items: HashSet<String>,
+}
+
+impl Revealed {
+    fn observe(&mut self, foe: &Pokemon) {
+        let id = to_id(&foe.species);
+        if let Some(last) = &foe.last_move {
+            self.moves.entry(id.clone()).or_default().insert(normalize_move_name(last));
+        }
+        if foe.item_consumed {
+            self.items.insert(id);
+        }
+    }
+
+    /// Redraw `pokemon`'s unrevealed moves and item from a pool set that has every revealed move.
+    fn redraw(&self, pokemon: &mut Pokemon, pool: &RandomSetPool, rng: &mut SmallRng) {
+        let id = to_id(&pokemon.species);
+        let Some(entry) = pool.species.get(&id) else {
+            return;
+        };
+        let known = self.moves.get(&id);
+        let is_known = |name: &str| known.is_some_and(|known| known.contains(&normalize_move_name(name)));
+        let fits = |set: &&RandomSet| {
+            known.is_none_or(|known| {
+                known.iter().all(|id| set.movepool.iter().any(|name| normalize_move_name(name) == *id))
+            })
+        };
+        let sets: Vec<&RandomSet> = entry.sets.iter().filter(fits).collect();
+        let Some(set) = sets.choose(rng) else {
+            return;
+        };
+        let slots = pokemon.moves.len().max(1);
+        let mut moves: Vec<String> = pokemon.moves.iter().filter(|name| is_known(name)).cloned().collect();
+        let mut unseen: Vec<&String> = set
+            .movepool
+            .iter()
+            .filter(|name| !moves.iter().any(|kept| normalize_move_name(kept) == normalize_move_name(name)))
+            .collect();
+        unseen.shuffle(rng);
+        let missing = slots.saturating_sub(moves.len());
+        moves.extend(unseen.into_iter().take(missing).cloned());
+        pokemon.moves = moves;
+        if !self.items.contains(&id) {
+            if let Some(item) = set.items.choose(rng) {
+                pokemon.item = Some(item.clone());
+            }
+        }
+    }
+
+    /// A copy of `state` with the opponent of `side` redrawn.
+    fn determinize(&self, state: &BattleState, side: usize, pool: &RandomSetPool, rng: &mut SmallRng) -> BattleState {
+        let mut world = state.clone();
+        world.logger = None;
+        let (active, bench) = if side == 0 {
+            (&mut world.pokemon_b, &mut world.bench_b)
+        } else {
+            (&mut world.pokemon_a, &mut world.bench_a)
+        };
+        for pokemon in std::iter::once(active).chain(bench.iter_mut()) {
+            self.redraw(pokemon, pool, rng);
+        }
+        world
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Arm {
+    action: Action,
+    visits: u32,
+    value: f64,
+}
+
+#[derive(Debug, Default)]
+struct Node {
+    /// Per player; empty for a player the tree does not branch on.
+    arms: [Vec<Arm>; 2],
+    /// `(actions taken, child)`: `None` for a player played by the rollout policy.
+    children: Vec<([Option<Action>; 2], usize)>,
+}
+
+/// One world's tree.
+struct Tree<'a> {
+    params: &'a MctsParams,
+    side: usize,
+    nodes: Vec<Node>,
+    rng: SmallRng,
+}
+
+impl<'a> Tree<'a> {
+    fn new(params: &'a MctsParams, side: usize, seed: u64) -> Self {
+        Self { params, side, nodes: vec![Node::default()], rng: SmallRng::seed_from_u64(seed) }
+    }
+
+    fn branches_on(&self, side: usize) -> bool {
+        self.params.mode == MctsMode::Joint || side == self.side
+    }
+
+    /// UCB1 over the arms of `legal` actions; untried actions first.
+    fn select(&mut self, node: usize, side: usize, legal: &[Action]) -> usize {
+        let arms = &mut self.nodes[node].arms[side];
+        for &action in legal {
+            if !arms.iter().any(|arm| arm.action == action) {
+                arms.push(Arm { action, visits: 0, value: 0.0 });
+            }
+        }
+        let candidates: Vec<usize> = (0..arms.len()).filter(|&idx| legal.contains(&arms[idx].action)).collect();
+        let untried: Vec<usize> = candidates.iter().copied().filter(|&idx| arms[idx].visits == 0).collect();
+        if let Some(&idx) = untried.choose(&mut self.rng) {
+            return idx;
+        }
+        let total: u32 = candidates.iter().map(|&idx| arms[idx].visits).sum();
+        let log_total = (total.max(1) as f64).ln();
+        let ucb = |arm: &Arm| {
+            let visits = arm.visits as f64;
+            arm.value / visits + self.params.exploration_constant * (log_total / visits).sqrt()
+        };
+        candidates
+            .into_iter()
+            .max_by(|&a, &b| ucb(&arms[a]).total_cmp(&ucb(&arms[b])))
+            .expect("a legal action")
+    }
+
+    /// Selection, expansion of one node, rollout and backpropagation.
+    fn iterate(&mut self, root: &BattleEngine) {
+        let mut engine = root.clone();
+        engine.reseed(self.rng.gen());
+        let seed = self.rng.gen();
+        let mut policies = [self.params.rollout.ai(0, seed), self.params.rollout.ai(1, seed ^ 1)];
+        let mut path: Vec<(usize, [Option<usize>; 2])> = Vec::new();
+        let mut node = 0;
+        while !engine.is_terminal() {
+            let legal = [engine.legal_actions(Player::A), engine.legal_actions(Player::B)];
+            if legal.iter().any(Vec::is_empty) {
+                break;
+            }
+            let mut picks = [None; 2];
+            let mut key = [None; 2];
+            let mut actions = [Action::Move(0); 2];
+            for side in 0..2 {
+                if self.branches_on(side) {
+                    let arm = self.select(node, side, &legal[side]);
+                    actions[side] = self.nodes[node].arms[side][arm].action;
+                    picks[side] = Some(arm);
+                    key[side] = Some(actions[side]);
+                } else {
+                    actions[side] = policies[side].choose_action(engine.state(), &legal[side]);
+                }
+            }
+            path.push((node, picks));
+            engine.step(actions[0], actions[1]);
+            let existing = self.nodes[node].children.iter().find(|(taken, _)| *taken == key).map(|(_, child)| *child);
+            match existing {
+                Some(child) => node = child,
+                None => {
+                    let child = self.nodes.len();
+                    self.nodes.push(Node::default());
+                    self.nodes[node].children.push((key, child));
+                    break;
+                }
+            }
+        }
+        let value = rollout(&mut engine, &mut policies, self.params.rollout_horizon);
+        for (node, picks) in path {
+            for (side, pick) in picks.into_iter().enumerate() {
+                if let Some(arm) = pick {
+                    let arm = &mut self.nodes[node].arms[side][arm];
+                    arm.visits += 1;
+                    arm.value += if side == 0 { value } else { 1.0 - value };
+                }
+            }
+        }
+    }
+
+    fn root_visits(&self) -> Vec<(Action, u32)> {
+        self.nodes[0].arms[self.side].iter().map(|arm| (arm.action, arm.visits)).collect()
+    }
+}
+
+/// Play up to `horizon` turns with the rollout policies and score the result for player A.
+fn rollout(engine: &mut BattleEngine, policies: &mut [Box<dyn BattleAI>; 2], horizon: usize) -> f64 {
+    for _ in 0..horizon {
+        if engine.is_terminal() {
+            break;
+        }
+        let legal_a = engine.legal_actions(Player::A);
+        let legal_b = engine.legal_actions(Player::B);
+        if legal_a.is_empty() || legal_b.is_empty() {
+            break;
+        }
+        let action_a = policies[0].choose_action(engine.state(), &legal_a);
+        let action_b = policies[1].choose_action(engine.state(), &legal_b);
+        engine.step(action_a, action_b);
+    }
+    evaluate(engine)
+}
+
+/// 1 for a win of player A, 0 for a loss, else from the remaining HP of both teams.
+fn evaluate(engine: &BattleEngine) -> f64 {
+    match engine.outcome() {
+        Some(BattleResult::TeamAWins) => 1.0,
+        Some(BattleResult::TeamBWins) => 0.0,
+        Some(BattleResult::Draw) => 0.5,
+        None => {
+            let state = engine.state();
+            let a = team_hp(&state.pokemon_a, &state.bench_a);
+            let b = team_hp(&state.pokemon_b, &state.bench_b);
+            0.5 + (a - b) / 2.0
+        }
+    }
+}
+
+/// Mean HP fraction of a team.
+fn team_hp(active: &Pokemon, bench: &[Pokemon]) -> f64 {
+    let team = std::iter::once(active).chain(bench);
+    let (sum, count) = team.fold((0.0, 0.0), |(sum, count), pokemon| {
+        (sum + pokemon.current_hp as f64 / pokemon.stats.hp.max(1) as f64, count + 1.0)
+    });
+    sum / count
+}
+
+/// One world to search: the determinized state, its seed, iterations and deadline.
+struct World {
+    state: BattleState,
+    seed: u64,
+    iterations: Option<usize>,
+    deadline: Option<Instant>,
+}
+
+fn search_world(params: &MctsParams, side: usize, world: World) -> Vec<(Action, u32)> {
+    let root = BattleEngine::resume(world.state, world.seed);
+    let mut tree = Tree::new(params, side, world.seed);
+    let mut done = 0;
+    while world.iterations.is_none_or(|limit| done < limit) && world.deadline.is_none_or(|at| Instant::now() < at) {
+        tree.iterate(&root);
+        done += 1;
+    }
+    tree.root_visits()
+}
+
+fn search_worlds(params: &MctsParams, side: usize, worlds: Vec<World>) -> Vec<Vec<(Action, u32)>> {
+    silently(|| worlds.into_iter().map(|world| search_world(params, side, world)).collect())
+}
+
+/// MCTS player.
+pub struct MctsAI {
+    params: MctsParams,
+    side: usize,
+    rng: SmallRng,
+    revealed: Revealed,
+    pool: &'static RandomSetPool,
+    belief: Option<Belief>,
+    /// Replacements and team preview, which the search does not cover.
+    fallback: HeuristicAI,
+}
+
+impl MctsAI {
+    pub fn new(params: MctsParams, seed: u64) -> Self {
+        Self {
+            params,
+            side: 0,
+            rng: SmallRng::seed_from_u64(seed),
+            revealed: Revealed::default(),
+            pool: RandomSetPool::builtin(),
+            belief: None,
+            fallback: HeuristicAI::new(Difficulty::Standard),
+        }
+    }
+
+    /// Draw the opponent's sets from `belief` (kept up to date with the moves the
+    /// opponent uses) rather than the random set pool.
+    pub fn with_belief(mut self, belief: Belief) -> Self {
+        self.belief = Some(belief);
+        self
+    }
+
+    pub fn params(&self) -> &MctsParams {
+        &self.params
+    }
+
+    pub fn belief(&self) -> Option<&Belief> {
+        self.belief.as_ref()
+    }
+
+    /// Search `state` and return the root visit count of each of this AI's actions, summed
+    /// over all worlds.
+    pub fn search(&mut self, state: &BattleState) -> Vec<(Action, u32)> {
+        let count = self.params.determinizations.max(1);
+        let threads = self.params.threads.clamp(1, count);
+        let per_thread = count.div_ceil(threads);
+        let iterations = match (self.params.iterations, self.params.time_budget) {
+            (Some(total), _) => Some(total.div_ceil(count).max(1)),
+            (None, Some(_)) => None,
+            (None, None) => Some(DEFAULT_ITERATIONS.div_ceil(count)),
+        };
+        let start = Instant::now();
+        let mut jobs: Vec<Vec<World>> = (0..threads).map(|_| Vec::new()).collect();
+        for idx in 0..count {
+            // Worlds run one after another on their thread and split its share of the budget.
+            let slot = idx / threads;
+            let deadline = self.params.time_budget.map(|budget| start + budget * (slot as u32 + 1) / per_thread as u32);
+            let state = match &self.belief {
+                Some(belief) => belief.determinize(state, &mut self.rng),
+                None => self.revealed.determinize(state, self.side, self.pool, &mut self.rng),
+            };
+            jobs[idx % threads].push(World { state, seed: self.rng.gen(), iterations, deadline });
+        }
+
+        let (params, side) = (&self.params, self.side);
+        let results: Vec<Vec<(Action, u32)>> = if threads == 1 {
+            jobs.into_iter().flat_map(|worlds| search_worlds(params, side, worlds)).collect()
+        } else {
+            std::thread::scope(|scope| {
+                let handles: Vec<_> = jobs
+                    .into_iter()
+                    .map(|worlds| scope.spawn(move || search_worlds(params, side, worlds)))
+                    .collect();
+                handles
+                    .into_iter()
+                    .flat_map(|handle| handle.join().expect("MCTS search thread panicked"))
+                    .collect()
+            })
+        };
+
+        let mut visits: Vec<(Action, u32)> = Vec::new();
+        for (action, count) in results.into_iter().flatten() {
+            match visits.iter_mut().find(|(seen, _)| *seen == action) {
+                Some((_, total)) => *total += count,
+                None => visits.push((action, count)),
+            }
+        }
+        visits
+    }
+}
+
+impl BattleAI for MctsAI {
+    fn choose_action(&mut self, state: &BattleState, valid_actions: &[Action]) -> Action {
+        let foe = if self.side == 0 { &state.pokemon_b } else { &state.pokemon_a };
+        self.revealed.observe(foe);
+        if let Some(belief) = &mut self.belief {
+            belief.observe_state(state);
+        }
+        if valid_actions.len() <= 1 {
+            return valid_actions.first().copied().unwrap_or(Action::Move(0));
+        }
+        let visits = self.search(state);
+        let mut best: Option<(Action, u32)> = None;
+        for &action in valid_actions {
+            let count = visits.iter().find(|(seen, _)| *seen == action).map_or(0, |(_, count)| *count);
+            if best.is_none_or(|(_, top)| count > top) {
+                best = Some((action, count));
+            }
+        }
+        best.map_or(valid_actions[0], |(action, _)| action)
+    }
+
+    fn set_side(&mut self, side: usize) {
+        self.side = side.min(1);
+        self.fallback.set_side(self.side);
+        if let Some(belief) = &mut self.belief {
+            belief.opponent = if self.side == 0 { Player::B } else { Player::A };
+        }
+    }
+
+    fn choose_replacement(&mut self, state: &BattleState, options: &[usize]) -> Option<usize> {
+        self.fallback.choose_replacement(state, options)
+    }
+
+    fn choose_team(&mut self, preview: &TeamPreview, legal: &[TeamChoice]) -> TeamChoice {
+        self.fallback.choose_team(preview, legal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dex::Dex;
+    use crate::sim::belief::CandidatePool;
+    use crate::sim::stats::Nature;
+
+    fn make(species: &str, moves: &[&str]) -> Pokemon {
+        Pokemon::new(
+            species,
+            50,
+            [0, 252, 0, 252, 0, 0],
+            [31; 6],
+            Nature::Hardy,
+            moves.iter().map(|m| m.to_string()).collect(),
+            "Pressure",
+            None,
+        )
+        .unwrap()
+    }
+
+    fn params(iterations: usize) -> MctsParams {
+        MctsParams { iterations: Some(iterations), rollout_horizon: 8, determinizations: 2, ..MctsParams::default() }
+    }
+
+    #[test]
+    fn finds_the_super_effective_attack() {
+        let state = BattleState::new_with_bench(
+            make("Pikachu", &["Growl", "Thunderbolt"]),
+            make("Gyarados", &["Splash"]),
+            Vec::new(),
+            Vec::new(),
+        );
+        let actions = [Action::Move(0), Action::Move(1)];
+        for mode in [MctsMode::Joint, MctsMode::MyActionOnly] {
+            let mut ai = MctsAI::new(MctsParams { mode, ..params(200) }, 3);
+            assert_eq!(ai.choose_action(&state, &actions), Action::Move(1), "{:?}", mode);
+        }
+    }
+
+    #[test]
+    fn root_visits_add_up_to_the_iterations_from_either_side() {
+        let state = BattleState::new_with_bench(
+            make("Garchomp", &["Earthquake", "Dragon Claw"]),
+            make("Heatran", &["Flamethrower", "Earth Power"]),
+            vec![make("Pikachu", &["Thunderbolt"])],
+            Vec::new(),
+        );
+        let mut ai = MctsAI::new(params(60), 1);
+        let visits = ai.search(&state);
+        assert_eq!(visits.iter().map(|(_, count)| count).sum::<u32>(), 60);
+        assert_eq!(visits.len(), 3);
+
+        ai.set_side(1);
+        let visits = ai.search(&state);
+        assert!(visits.iter().all(|(action, _)| matches!(action, Action::Move(_))));
+        assert_eq!(visits.iter().map(|(_, count)| count).sum::<u32>(), 60);
+    }
+
+    #[test]
+    fn threads_search_the_same_worlds() {
+        let state = BattleState::new(make("Garchomp", &["Earthquake", "Dragon Claw"]), make("Dragonite", &["Outrage"]));
+        let mut single = MctsAI::new(MctsParams { determinizations: 4, ..params(80) }, 9).search(&state);
+        let mut threaded = MctsAI::new(MctsParams { determinizations: 4, threads: 3, ..params(80) }, 9).search(&state);
+        single.sort_by_key(|(action, _)| format!("{:?}", action));
+        threaded.sort_by_key(|(action, _)| format!("{:?}", action));
+        assert_eq!(single, threaded);
+
+        let timed = MctsParams { time_budget: Some(Duration::from_millis(20)), ..MctsParams::default() };
+        assert!(!MctsAI::new(timed, 9).search(&state).is_empty());
+    }
+
+    #[test]
+    fn determinization_keeps_revealed_moves() {
+        let pool = RandomSetPool::builtin();
+        let mut foe = make("Garchomp", &["Earthquake", "Outrage", "Swords Dance", "Scale Shot"]);
+        foe.item = Some("Choice Band".to_string());
+        foe.last_move = Some("earthquake".to_string());
+        let mut revealed = Revealed::default();
+        revealed.observe(&foe);
+        let mut rng = SmallRng::seed_from_u64(5);
+        let state = BattleState::new(make("Pikachu", &["Thunderbolt"]), foe.clone());
+        for _ in 0..10 {
+            let world = revealed.determinize(&state, 0, pool, &mut rng);
+            let redrawn = &world.pokemon_b;
+            assert_eq!(redrawn.moves.len(), 4);
+            assert!(redrawn.moves.iter().any(|name| name == "Earthquake"));
+            let entry = &pool.species["garchomp"];
+            assert!(entry.sets.iter().any(|set| redrawn.moves.iter().all(|name| set.movepool.contains(name))));
+            assert_eq!(world.pokemon_a.moves, ["Thunderbolt"]);
+        }
+    }
+
+    #[test]
+    fn searches_worlds_drawn_from_a_belief() {
+        let pool = CandidatePool::from_json(
+            r#"[{"species": "Dragonite", "item": "Leftovers", "ability": "Multiscale",
+                 "moves": ["Extreme Speed", "Dragon Dance"]}]"#,
+        )
+        .unwrap();
+        let foe = make("Dragonite", &["Outrage"]);
+        let belief = Belief::new(&pool, &Dex::builtin(), std::slice::from_ref(&foe), Player::A);
+        let mut ai = MctsAI::new(params(60), 3).with_belief(belief);
+        ai.set_side(1);
+        assert_eq!(ai.belief().unwrap().opponent, Player::A);
+        let state = BattleState::new(foe, make("Garchomp", &["Earthquake", "Dragon Claw"]));
+        assert!(!ai.search(&state).is_empty());
+        let world = ai.belief().unwrap().determinize(&state, &mut SmallRng::seed_from_u64(1));
+        assert_eq!(world.pokemon_a.moves, ["extremespeed", "dragondance"]);
+    }
+}
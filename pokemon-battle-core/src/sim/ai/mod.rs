@@ -0,0 +1,118 @@
+pub mod expectiminimax;
+pub mod heuristic;
+pub mod mcts;
+pub mod nash;
+pub mod scripted;
+pub mod td;
+
+pub use expectiminimax::{ExpectiminimaxAI, SearchParams};
+pub use heuristic::{Difficulty, HeuristicAI};
+pub use mcts::{MctsAI, MctsMode, MctsParams, RolloutPolicy};
+pub use nash::{NashAI, NashSolver};
+pub use scripted::{Script, ScriptedAI};
+pub use td::{TdParams, ValueAI, ValueFunction};
+
+use crate::sim::battle::{Action, BattleState};
+use crate::sim::team_preview::{TeamChoice, TeamPreview};
+use anyhow::{anyhow, Result};
+use rand::rngs::SmallRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+pub trait BattleAI {
+    fn choose_action(&mut self, state: &BattleState, valid_actions: &[Action]) -> Action;
+
+    /// Called before the first turn with the side this AI plays (0 = team A, 1 = team B).
+    fn set_side(&mut self, _side: usize) {}
+
+    /// Pick a bench index from `options` to replace a fainted Pokémon.
+    /// `None` (the default) leaves the pick to the battle (a random replacement).
+    fn choose_replacement(&mut self, _state: &BattleState, _options: &[usize]) -> Option<usize> {
+        None
+    }
+
+    /// Team preview: pick the lead and the Pokémon to bring. `legal` is never empty.
+    /// Defaults to the first legal choice (the team in order).
+    fn choose_team(&mut self, _preview: &TeamPreview, legal: &[TeamChoice]) -> TeamChoice {
+        legal[0].clone()
+    }
+}
+
+pub struct RandomAI {
+    rng: SmallRng,
+}
+
+impl RandomAI {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: SmallRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl BattleAI for RandomAI {
+    fn choose_action(&mut self, _state: &BattleState, valid_actions: &[Action]) -> Action {
+        *valid_actions
+            .choose(&mut self.rng)
+            .unwrap_or(&Action::Move(0))
+    }
+
+    fn choose_team(&mut self, _preview: &TeamPreview, legal: &[TeamChoice]) -> TeamChoice {
+        legal.choose(&mut self.rng).cloned().expect("team preview has a legal choice")
+    }
+}
+
+/// Sample an action from a mixed `strategy`, restricted to `valid_actions` (the likeliest
+/// one if rounding leaves nothing picked).
+fn sample_strategy(strategy: &[(Action, f64)], valid_actions: &[Action], rng: &mut SmallRng) -> Action {
+    let strategy: Vec<(Action, f64)> =
+        strategy.iter().copied().filter(|(action, _)| valid_actions.contains(action)).collect();
+    let total: f64 = strategy.iter().map(|(_, p)| p).sum();
+    let mut pick = rng.gen::<f64>() * total;
+    for &(action, probability) in &strategy {
+        if pick < probability {
+            return action;
+        }
+        pick -= probability;
+    }
+    strategy
+        .iter()
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map_or(valid_actions[0], |(action, _)| *action)
+}
+
+/// Names accepted by [`policy`].
+pub const POLICIES: &[&str] = &["random", "greedy", "standard", "expert", "mcts", "expectiminimax", "nash", "td", "script:<file>"];
+
+/// Build an AI by policy name: `random`, a [`Difficulty`] of [`HeuristicAI`], `mcts`
+/// with default [`MctsParams`], `expectiminimax` with default [`SearchParams`], `nash`
+/// (one-turn lookahead solved exactly), `td` (a [`ValueAI`] on untrained weights), or
+/// `script:<file>` (a [`ScriptedAI`] playing the rules file).
+pub fn policy(name: &str, seed: u64) -> Result<Box<dyn BattleAI>> {
+    policy_with(name, seed, &MctsParams::default())
+}
+
+/// [`policy`], with the search settings used for `mcts`.
+pub fn policy_with(name: &str, seed: u64, mcts: &MctsParams) -> Result<Box<dyn BattleAI>> {
+    if name.eq_ignore_ascii_case("random") {
+        return Ok(Box::new(RandomAI::new(seed)));
+    }
+    if let Some(path) = name.strip_prefix("script:") {
+        return Ok(Box::new(ScriptedAI::load(path, seed)?));
+    }
+    if name.eq_ignore_ascii_case("mcts") {
+        return Ok(Box::new(MctsAI::new(mcts.clone(), seed)));
+    }
+    if name.eq_ignore_ascii_case("expectiminimax") {
+        return Ok(Box::new(ExpectiminimaxAI::new(SearchParams::default(), seed)));
+    }
+    if name.eq_ignore_ascii_case("nash") {
+        return Ok(Box::new(NashAI::new(NashSolver::LinearProgram, seed)));
+    }
+    if name.eq_ignore_ascii_case("td") {
+        return Ok(Box::new(ValueAI::new(ValueFunction::default(), seed)));
+    }
+    let difficulty = Difficulty::from_name(name)
+        .ok_or_else(|| anyhow!("unknown policy '{}' (known: {})", name, POLICIES.join(", ")))?;
+    Ok(Box::new(HeuristicAI::new(difficulty)))
+}
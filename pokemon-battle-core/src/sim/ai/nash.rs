@@ -0,0 +1,163 @@
+//! Equilibrium play from a one-turn lookahead.
+//!
+//! For every pair of legal actions the engine looks one turn ahead
+//! ([`BattleEngine::outcomes`]: every chance outcome, exactly), and the expected
+//! [`Evaluator`] score of the resulting positions fills player A's payoff matrix. The matrix
+//! is solved with [`nash::solve`] or [`nash::regret_matching`] and the AI samples its action
+//! from its side's equilibrium strategy, so it cannot be read like a greedy argmax.
+
+use super::expectiminimax::{Analysis, Evaluator, HpEvaluator};
+use super::{sample_strategy, BattleAI};
+use crate::engine::{battle_outcome, BattleEngine, Player};
+use crate::i18n::messages::silently;
+use crate::sim::battle::{Action, BattleResult, BattleState};
+use crate::sim::nash::{self, Equilibrium};
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+
+/// How the payoff matrix is solved.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NashSolver {
+    /// Exact, by linear programming.
+    LinearProgram,
+    /// Approximate, by this many rounds of regret matching.
+    RegretMatching { iterations: usize },
+}
+
+/// Plays the equilibrium of the one-turn lookahead matrix, sampling among mixed actions.
+pub struct NashAI {
+    solver: NashSolver,
+    evaluator: Box<dyn Evaluator>,
+    side: usize,
+    rng: SmallRng,
+}
+
+impl NashAI {
+    pub fn new(solver: NashSolver, seed: u64) -> Self {
+        Self::with_evaluator(solver, Box::new(HpEvaluator), seed)
+    }
+
+    pub fn with_evaluator(solver: NashSolver, evaluator: Box<dyn Evaluator>, seed: u64) -> Self {
+        Self { solver, evaluator, side: 0, rng: SmallRng::seed_from_u64(seed) }
+    }
+
+    /// Player A's expected score after one turn, by `[action A][action B]` over the
+    /// engine's legal actions: 1 / -1 / 0 for a won / lost / drawn battle, the
+    /// evaluator's score otherwise.
+    pub fn payoff_matrix(&self, engine: &BattleEngine) -> (Vec<Action>, Vec<Action>, Vec<Vec<f64>>) {
+        let actions_a = engine.legal_actions(Player::A);
+        let actions_b = engine.legal_actions(Player::B);
+        let payoff = silently(|| {
+            actions_a
+                .iter()
+                .map(|&a| {
+                    actions_b
+                        .iter()
+                        .map(|&b| {
+                            engine
+                                .outcomes(a, b)
+                                .iter()
+                                .map(|outcome| outcome.probability * self.score(&outcome.state))
+                                .sum()
+                        })
+                        .collect()
+                })
+                .collect()
+        });
+        (actions_a, actions_b, payoff)
+    }
+
+    /// Build and solve the matrix for `state` (not in team preview). `nodes` counts the
+    /// action pairs looked ahead.
+    pub fn analyze(&self, state: &BattleState) -> Analysis {
+        let engine = BattleEngine::resume(state.clone(), 0);
+        let (actions_a, actions_b, payoff) = self.payoff_matrix(&engine);
+        let Equilibrium { value, row, col } = match self.solver {
+            NashSolver::LinearProgram => nash::solve(&payoff),
+            NashSolver::RegretMatching { iterations } => nash::regret_matching(&payoff, iterations),
+        };
+        let nodes = actions_a.len() * actions_b.len();
+        Analysis { value, actions_a, actions_b, strategy_a: row, strategy_b: col, payoff, nodes }
+    }
+
+    fn score(&self, state: &BattleState) -> f64 {
+        match battle_outcome(state) {
+            Some(BattleResult::TeamAWins) => 1.0,
+            Some(BattleResult::TeamBWins) => -1.0,
+            Some(BattleResult::Draw) => 0.0,
+            None => self.evaluator.evaluate(state).clamp(-1.0, 1.0),
+        }
+    }
+}
+
+impl BattleAI for NashAI {
+    fn choose_action(&mut self, state: &BattleState, valid_actions: &[Action]) -> Action {
+        if valid_actions.len() <= 1 {
+            return valid_actions.first().copied().unwrap_or(Action::Move(0));
+        }
+        let player = if self.side == 0 { Player::A } else { Player::B };
+        sample_strategy(&self.analyze(state).strategy(player), valid_actions, &mut self.rng)
+    }
+
+    fn set_side(&mut self, side: usize) {
+        self.side = side.min(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::pokemon::Pokemon;
+    use crate::sim::stats::Nature;
+
+    fn make(species: &str, moves: &[&str]) -> Pokemon {
+        Pokemon::new(
+            species,
+            50,
+            [0, 252, 0, 252, 0, 0],
+            [31; 6],
+            Nature::Hardy,
+            moves.iter().map(|m| m.to_string()).collect(),
+            "Pressure",
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn takes_the_guaranteed_knockout_with_either_solver() {
+        let mut pikachu = make("Pikachu", &["Growl", "Thunderbolt"]);
+        let mut gyarados = make("Gyarados", &["Tackle"]);
+        pikachu.current_hp = 5;
+        gyarados.current_hp = 20;
+        let state = BattleState::new(pikachu, gyarados);
+        for solver in [NashSolver::LinearProgram, NashSolver::RegretMatching { iterations: 500 }] {
+            let analysis = NashAI::new(solver, 1).analyze(&state);
+            assert_eq!(analysis.payoff.len(), 2);
+            assert!((analysis.payoff[1][0] - 1.0).abs() < 1e-9);
+            assert_eq!(analysis.best(Player::A), Some(Action::Move(1)));
+            assert!(analysis.value > 0.99);
+            let mut ai = NashAI::new(solver, 7);
+            assert_eq!(ai.choose_action(&state, &[Action::Move(0), Action::Move(1)]), Action::Move(1));
+        }
+    }
+
+    #[test]
+    fn plays_either_side_and_scores_with_the_evaluator() {
+        let state = BattleState::new(make("Snorlax", &["Tackle", "Growl"]), make("Snorlax", &["Tackle", "Growl"]));
+        let engine = BattleEngine::resume(state.clone(), 0);
+        let always_b = |_: &BattleState| -0.5;
+        let ai = NashAI::with_evaluator(NashSolver::LinearProgram, Box::new(always_b), 1);
+        let (actions_a, actions_b, payoff) = ai.payoff_matrix(&engine);
+        assert_eq!(actions_a, [Action::Move(0), Action::Move(1)]);
+        assert_eq!(actions_b.len(), 2);
+        assert!(payoff.iter().flatten().all(|&x| (x + 0.5).abs() < 1e-9));
+
+        // Tackle always beats Growl on HP, for whichever side the AI plays.
+        let mut ai = NashAI::new(NashSolver::LinearProgram, 3);
+        ai.set_side(1);
+        let analysis = ai.analyze(&state);
+        assert_eq!(analysis.best(Player::B), Some(Action::Move(0)));
+        assert_eq!(ai.choose_action(&state, &[Action::Move(0), Action::Move(1)]), Action::Move(0));
+    }
+}
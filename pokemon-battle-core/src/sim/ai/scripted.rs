@@ -0,0 +1,564 @@
+//! Trainer behaviour written as rules in a JSON file instead of Rust.
+//!
+//! ```json
+//! { "name": "Hazard lead", "fallback": "standard", "rules": [
+//!     { "name": "rocks first", "priority": 100,
+//!       "when": [{ "turn": 1 }, { "not": { "foe_side": "stealth_rock" } }],
+//!       "then": { "move": "Stealth Rock" } },
+//!     { "name": "bail out", "priority": 50, "when": [{ "hp_below": 30 }], "then": { "switch": "Garchomp" } },
+//!     { "name": "hit weaknesses", "priority": 10, "when": [{ "foe_weak_to": "any" }],
+//!       "then": { "move": "super_effective" } } ] }
+//! ```
+//!
+//! Every turn the rules whose conditions all hold and whose action is legal right now
+//! fire; the highest priority wins, ties are broken at random. `then` names a move or a
+//! switch target, or a selector: `{"move": "super_effective" | "strongest" | "any"}`,
+//! `{"switch": "any"}`. When no rule fires the `fallback` policy (default `random`) plays.
+//! Switch rules also pick replacements for fainted Pokémon.
+//!
+//! Conditions see what a player sees: the turn (`turn`, `turn_at_least`), HP percentages
+//! (`hp_below`, `hp_above`, `foe_hp_below`, `foe_hp_above`), status (`status`,
+//! `foe_status`: `none`, `any`, `brn`, `par`, `psn`, `tox`, `slp`, `frz`), names and
+//! types (`active`, `foe_active`, `foe_type`), `foe_weak_to` (a move name or `any` of
+//! ours, 2x or more), side conditions (`my_side`, `foe_side`: `stealth_rock`, `spikes`,
+//! `toxic_spikes`, `sticky_web`, `reflect`, `light_screen`, `aurora_veil`, `tailwind`),
+//! `faster`, `chance` (percent) and the combinators `not` and `any`.
+
+use super::heuristic::estimate_damage;
+use super::{policy, BattleAI};
+use crate::data::moves::MoveCategory;
+use crate::data::types::Type;
+use crate::dex::to_id;
+use crate::sim::battle::{effective_speed, effective_types, Action, BattleState, SideConditions};
+use crate::sim::pokemon::{parse_type, Pokemon, Status};
+use crate::sim::team_preview::{TeamChoice, TeamPreview};
+use anyhow::{bail, Context, Result};
+use rand::rngs::SmallRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use serde::Deserialize;
+use std::path::Path;
+
+/// A rules file.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Script {
+    #[serde(default)]
+    pub name: String,
+    /// Policy played when no rule fires (see [`policy`]).
+    #[serde(default = "default_fallback")]
+    pub fallback: String,
+    pub rules: Vec<Rule>,
+}
+
+fn default_fallback() -> String {
+    "random".to_string()
+}
+
+/// Do `then` when every condition in `when` holds.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Rule {
+    pub name: String,
+    /// Higher fires first.
+    #[serde(default)]
+    pub priority: i32,
+    #[serde(default)]
+    pub when: Vec<Condition>,
+    pub then: RuleAction,
+}
+
+/// A test on the observed state.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Condition {
+    Turn(u32),
+    TurnAtLeast(u32),
+    /// Our active Pokémon's HP is below this percentage.
+    HpBelow(f64),
+    HpAbove(f64),
+    FoeHpBelow(f64),
+    FoeHpAbove(f64),
+    Status(String),
+    FoeStatus(String),
+    Active(String),
+    FoeActive(String),
+    FoeType(String),
+    /// The move (or `any` of ours) hits the foe for 2x or more.
+    FoeWeakTo(String),
+    MySide(String),
+    FoeSide(String),
+    Faster(bool),
+    /// Holds with this percent chance.
+    Chance(f64),
+    Not(Box<Condition>),
+    Any(Vec<Condition>),
+}
+
+/// What a rule does.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleAction {
+    /// A move by name, or `super_effective`, `strongest` or `any`.
+    Move(String),
+    /// A bench Pokémon by name, or `any`.
+    Switch(String),
+}
+
+impl Script {
+    pub fn from_json(text: &str) -> Result<Self> {
+        let script: Self = serde_json::from_str(text)?;
+        script.validate()?;
+        Ok(script)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+        Self::from_json(&text).with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    /// Reject names no condition can match, so typos fail at load time.
+    fn validate(&self) -> Result<()> {
+        policy(&self.fallback, 0).context("fallback")?;
+        for rule in &self.rules {
+            for condition in &rule.when {
+                validate_condition(condition).with_context(|| format!("rule '{}'", rule.name))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn validate_condition(condition: &Condition) -> Result<()> {
+    match condition {
+        Condition::Status(name) | Condition::FoeStatus(name) if parse_status(name).is_none() => {
+            bail!("unknown status '{}' (known: none, any, brn, par, psn, tox, slp, frz)", name)
+        }
+        Condition::FoeType(name) if parse_type(name).is_none() => bail!("unknown type '{}'", name),
+        Condition::MySide(name) | Condition::FoeSide(name) if side_has(&SideConditions::default(), name).is_none() => {
+            bail!("unknown side condition '{}'", name)
+        }
+        Condition::Not(inner) => validate_condition(inner),
+        Condition::Any(inner) => inner.iter().try_for_each(validate_condition),
+        _ => Ok(()),
+    }
+}
+
+/// The status a `status` / `foe_status` condition asks for.
+#[derive(Clone, Copy, PartialEq)]
+enum StatusTest {
+    Healthy,
+    Any,
+    Is(Status),
+    Toxic,
+}
+
+fn parse_status(name: &str) -> Option<StatusTest> {
+    Some(match to_id(name).as_str() {
+        "none" | "healthy" => StatusTest::Healthy,
+        "any" => StatusTest::Any,
+        "brn" | "burn" => StatusTest::Is(Status::Burn),
+        "par" | "paralysis" => StatusTest::Is(Status::Paralysis),
+        "psn" | "poison" => StatusTest::Is(Status::Poison),
+        "tox" | "toxic" => StatusTest::Toxic,
+        "slp" | "sleep" => StatusTest::Is(Status::Sleep),
+        "frz" | "freeze" => StatusTest::Is(Status::Freeze),
+        _ => return None,
+    })
+}
+
+fn has_status(pokemon: &Pokemon, test: StatusTest) -> bool {
+    let status = pokemon.status.filter(|status| *status != Status::Flinch);
+    match test {
+        StatusTest::Healthy => status.is_none(),
+        StatusTest::Any => status.is_some(),
+        StatusTest::Is(wanted) => status == Some(wanted),
+        StatusTest::Toxic => status == Some(Status::Poison) && pokemon.toxic_counter > 0,
+    }
+}
+
+fn side_has(side: &SideConditions, name: &str) -> Option<bool> {
+    Some(match to_id(name).as_str() {
+        "stealthrock" => side.stealth_rock,
+        "spikes" => side.spikes > 0,
+        "toxicspikes" => side.toxic_spikes > 0,
+        "stickyweb" => side.sticky_web,
+        "reflect" => side.reflect_turns > 0,
+        "lightscreen" => side.light_screen_turns > 0,
+        "auroraveil" => side.aurora_veil_turns > 0,
+        "tailwind" => side.tailwind_turns > 0,
+        _ => return None,
+    })
+}
+
+/// One side's view of the battle.
+struct View<'a> {
+    state: &'a BattleState,
+    me: &'a Pokemon,
+    foe: &'a Pokemon,
+    bench: &'a [Pokemon],
+    my_side: &'a SideConditions,
+    foe_side: &'a SideConditions,
+}
+
+impl<'a> View<'a> {
+    fn new(state: &'a BattleState, side: usize) -> Self {
+        if side == 0 {
+            Self {
+                state,
+                me: &state.pokemon_a,
+                foe: &state.pokemon_b,
+                bench: &state.bench_a,
+                my_side: &state.side_a,
+                foe_side: &state.side_b,
+            }
+        } else {
+            Self {
+                state,
+                me: &state.pokemon_b,
+                foe: &state.pokemon_a,
+                bench: &state.bench_b,
+                my_side: &state.side_b,
+                foe_side: &state.side_a,
+            }
+        }
+    }
+
+    /// How `move_name` hits the foe (0 for status and unknown moves).
+    fn effectiveness(&self, move_name: &str) -> f32 {
+        let Some(data) = self.state.dex.get_move(move_name) else {
+            return 0.0;
+        };
+        if data.category == MoveCategory::Status {
+            return 0.0;
+        }
+        let move_type = parse_type(data.move_type).unwrap_or(Type::Typeless);
+        let [t0, t1] = effective_types(self.foe);
+        self.state.type_chart.effectiveness_dual(move_type, t0, t1)
+    }
+
+    fn holds(&self, condition: &Condition, rng: &mut SmallRng) -> bool {
+        let hp = |pokemon: &Pokemon| pokemon.current_hp as f64 * 100.0 / pokemon.stats.hp.max(1) as f64;
+        let is = |pokemon: &Pokemon, name: &str| {
+            let id = to_id(name);
+            to_id(&pokemon.name) == id || to_id(&pokemon.species) == id
+        };
+        match condition {
+            Condition::Turn(turn) => self.state.turn + 1 == *turn,
+            Condition::TurnAtLeast(turn) => self.state.turn + 1 >= *turn,
+            Condition::HpBelow(percent) => hp(self.me) < *percent,
+            Condition::HpAbove(percent) => hp(self.me) > *percent,
+            Condition::FoeHpBelow(percent) => hp(self.foe) < *percent,
+            Condition::FoeHpAbove(percent) => hp(self.foe) > *percent,
+            Condition::Status(name) => parse_status(name).is_some_and(|test| has_status(self.me, test)),
+            Condition::FoeStatus(name) => parse_status(name).is_some_and(|test| has_status(self.foe, test)),
+            Condition::Active(name) => is(self.me, name),
+            Condition::FoeActive(name) => is(self.foe, name),
+            Condition::FoeType(name) => parse_type(name).is_some_and(|t| effective_types(self.foe).contains(&t)),
+            Condition::FoeWeakTo(name) if to_id(name) == "any" => {
+                self.me.moves.iter().any(|id| self.effectiveness(id) >= 2.0)
+            }
+            Condition::FoeWeakTo(name) => self.effectiveness(name) >= 2.0,
+            Condition::MySide(name) => side_has(self.my_side, name).unwrap_or(false),
+            Condition::FoeSide(name) => side_has(self.foe_side, name).unwrap_or(false),
+            Condition::Faster(faster) => {
+                let mine = effective_speed(self.me, self.state.weather);
+                let theirs = effective_speed(self.foe, self.state.weather);
+                (mine > theirs) == *faster
+            }
+            Condition::Chance(percent) => rng.gen::<f64>() * 100.0 < *percent,
+            Condition::Not(inner) => !self.holds(inner, rng),
+            Condition::Any(inner) => inner.iter().any(|condition| self.holds(condition, rng)),
+        }
+    }
+
+    /// Legal actions `action` could mean right now.
+    fn candidates(&self, action: &RuleAction, valid: &[Action]) -> Vec<Action> {
+        match action {
+            RuleAction::Move(name) => {
+                let moves: Vec<(usize, &String)> = self
+                    .me
+                    .moves
+                    .iter()
+                    .enumerate()
+                    .filter(|(idx, _)| valid.contains(&Action::Move(*idx)))
+                    .collect();
+                let picked: Vec<usize> = match to_id(name).as_str() {
+                    "any" => moves.iter().map(|(idx, _)| *idx).collect(),
+                    "supereffective" => {
+                        moves.iter().filter(|(_, id)| self.effectiveness(id) >= 2.0).map(|(idx, _)| *idx).collect()
+                    }
+                    "strongest" => {
+                        let damage = |id: &str| {
+                            estimate_damage(self.state, self.me, self.foe, self.foe_side, id).map_or(0.0, |d| d.average())
+                        };
+                        let best = moves.iter().map(|(_, id)| damage(id)).fold(0.0f32, f32::max);
+                        moves
+                            .iter()
+                            .filter(|(_, id)| best > 0.0 && damage(id) >= best)
+                            .map(|(idx, _)| *idx)
+                            .collect()
+                    }
+                    wanted => moves.iter().filter(|(_, id)| to_id(id) == wanted).map(|(idx, _)| *idx).collect(),
+                };
+                picked.into_iter().map(Action::Move).collect()
+            }
+            RuleAction::Switch(name) => self
+                .switch_targets(name)
+                .into_iter()
+                .map(Action::Switch)
+                .filter(|action| valid.contains(action))
+                .collect(),
+        }
+    }
+
+    /// Bench indices a switch rule names.
+    fn switch_targets(&self, name: &str) -> Vec<usize> {
+        let id = to_id(name);
+        self.bench
+            .iter()
+            .enumerate()
+            .filter(|(_, pokemon)| !pokemon.is_fainted())
+            .filter(|(_, pokemon)| id == "any" || to_id(&pokemon.name) == id || to_id(&pokemon.species) == id)
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+}
+
+/// Plays a [`Script`].
+pub struct ScriptedAI {
+    script: Script,
+    fallback: Box<dyn BattleAI>,
+    side: usize,
+    rng: SmallRng,
+    debug: bool,
+    last_rule: Option<String>,
+}
+
+impl ScriptedAI {
+    pub fn new(script: Script, seed: u64) -> Result<Self> {
+        let fallback = policy(&script.fallback, seed).context("fallback")?;
+        Ok(Self { script, fallback, side: 0, rng: SmallRng::seed_from_u64(seed), debug: false, last_rule: None })
+    }
+
+    pub fn load(path: impl AsRef<Path>, seed: u64) -> Result<Self> {
+        Self::new(Script::load(path)?, seed)
+    }
+
+    /// Print the rule behind every decision to stderr.
+    pub fn with_debug(mut self, debug: bool) -> Self {
+        self.debug = debug;
+        self
+    }
+
+    /// The rule behind the last decision (`None` when the fallback chose).
+    pub fn last_rule(&self) -> Option<&str> {
+        self.last_rule.as_deref()
+    }
+
+    /// Fire the rules against `valid` actions: the winning rule and its pick.
+    fn fire(&mut self, state: &BattleState, valid: &[Action], switches_only: bool) -> Option<(usize, Action)> {
+        let view = View::new(state, self.side);
+        let mut fired: Vec<(usize, Vec<Action>)> = Vec::new();
+        let mut best = i32::MIN;
+        for (idx, rule) in self.script.rules.iter().enumerate() {
+            if rule.priority < best || (switches_only && !matches!(rule.then, RuleAction::Switch(_))) {
+                continue;
+            }
+            if !rule.when.iter().all(|condition| view.holds(condition, &mut self.rng)) {
+                continue;
+            }
+            let candidates = view.candidates(&rule.then, valid);
+            if candidates.is_empty() {
+                continue;
+            }
+            if rule.priority > best {
+                best = rule.priority;
+                fired.clear();
+            }
+            fired.push((idx, candidates));
+        }
+        let (rule, candidates) = fired.choose(&mut self.rng)?;
+        let action = *candidates.choose(&mut self.rng)?;
+        Some((*rule, action))
+    }
+
+    fn report(&mut self, state: &BattleState, decision: Option<(usize, Action)>) {
+        self.last_rule = decision.map(|(rule, _)| self.script.rules[rule].name.clone());
+        if !self.debug {
+            return;
+        }
+        let view = View::new(state, self.side);
+        let script = if self.script.name.is_empty() { "script" } else { &self.script.name };
+        match decision {
+            Some((rule, action)) => {
+                let rule = &self.script.rules[rule];
+                let action = match action {
+                    Action::Move(idx) => view.me.moves.get(idx).map_or_else(String::new, |id| {
+                        state.dex.get_move(id).map_or_else(|| id.clone(), |data| data.name.to_string())
+                    }),
+                    Action::Switch(idx) => format!("switch to {}", view.bench.get(idx).map_or("?", |p| p.name.as_str())),
+                };
+                eprintln!("[{}] turn {}: rule '{}' (priority {}) -> {}", script, state.turn + 1, rule.name, rule.priority, action);
+            }
+            None => eprintln!("[{}] turn {}: no rule fired, {} decides", script, state.turn + 1, self.script.fallback),
+        }
+    }
+}
+
+impl BattleAI for ScriptedAI {
+    fn choose_action(&mut self, state: &BattleState, valid_actions: &[Action]) -> Action {
+        let decision = self.fire(state, valid_actions, false);
+        self.report(state, decision);
+        match decision {
+            Some((_, action)) => action,
+            None => self.fallback.choose_action(state, valid_actions),
+        }
+    }
+
+    fn set_side(&mut self, side: usize) {
+        self.side = side.min(1);
+        self.fallback.set_side(side);
+    }
+
+    fn choose_replacement(&mut self, state: &BattleState, options: &[usize]) -> Option<usize> {
+        let valid: Vec<Action> = options.iter().map(|&idx| Action::Switch(idx)).collect();
+        let decision = self.fire(state, &valid, true);
+        self.report(state, decision);
+        match decision {
+            Some((_, Action::Switch(idx))) => Some(idx),
+            _ => self.fallback.choose_replacement(state, options),
+        }
+    }
+
+    fn choose_team(&mut self, preview: &TeamPreview, legal: &[TeamChoice]) -> TeamChoice {
+        self.fallback.choose_team(preview, legal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::stats::Nature;
+
+    fn make(species: &str, moves: &[&str]) -> Pokemon {
+        Pokemon::new(
+            species,
+            50,
+            [0, 252, 0, 252, 0, 0],
+            [31; 6],
+            Nature::Hardy,
+            moves.iter().map(|m| m.to_string()).collect(),
+            "Pressure",
+            None,
+        )
+        .unwrap()
+    }
+
+    fn ai(json: &str, seed: u64) -> ScriptedAI {
+        ScriptedAI::new(Script::from_json(json).unwrap(), seed).unwrap()
+    }
+
+    fn state() -> BattleState {
+        BattleState::new_with_bench(
+            make("Garchomp", &["Stealth Rock", "Earthquake", "Dragon Claw", "Swords Dance"]),
+            make("Heatran", &["Flamethrower"]),
+            vec![make("Gyarados", &["Waterfall"]), make("Pikachu", &["Thunderbolt"])],
+            vec![],
+        )
+    }
+
+    const ALL: [Action; 6] = [
+        Action::Move(0),
+        Action::Move(1),
+        Action::Move(2),
+        Action::Move(3),
+        Action::Switch(0),
+        Action::Switch(1),
+    ];
+
+    #[test]
+    fn the_bundled_example_parses() {
+        let script = Script::from_json(include_str!("../../../../data/ai-scripts/hazard-lead.json")).unwrap();
+        assert!(script.rules.len() >= 3);
+    }
+
+    #[test]
+    fn rejects_unknown_names() {
+        let err = |json: &str| format!("{:#}", Script::from_json(json).unwrap_err());
+        let rule = |condition: &str| {
+            format!(r#"{{"rules": [{{"name": "r", "when": [{}], "then": {{"move": "any"}}}}]}}"#, condition)
+        };
+        assert!(err(&rule(r#"{"status": "dizzy"}"#)).contains("unknown status 'dizzy'"));
+        assert!(err(&rule(r#"{"not": {"foe_type": "Sound"}}"#)).contains("unknown type"));
+        assert!(err(&rule(r#"{"foe_side": "lava"}"#)).contains("rule 'r'"));
+        assert!(err(&rule(r#"{"hp_under": 30}"#)).contains("unknown variant"));
+        assert!(err(r#"{"fallback": "genius", "rules": []}"#).contains("unknown policy"));
+        assert!(Script::from_json(&rule(r#"{"any": [{"status": "tox"}, {"foe_status": "none"}]}"#)).is_ok());
+    }
+
+    #[test]
+    fn rules_fire_by_priority_and_report_themselves() {
+        let json = r#"{"name": "test", "rules": [
+            {"name": "rocks", "priority": 100, "when": [{"turn": 1}, {"not": {"foe_side": "stealth_rock"}}],
+             "then": {"move": "Stealth Rock"}},
+            {"name": "bail", "priority": 50, "when": [{"hp_below": 30}], "then": {"switch": "Gyarados"}},
+            {"name": "weak", "priority": 10, "when": [{"foe_weak_to": "any"}], "then": {"move": "super_effective"}}
+        ]}"#;
+        let mut ai = ai(json, 1);
+        let mut state = state();
+        assert_eq!(ai.choose_action(&state, &ALL), Action::Move(0));
+        assert_eq!(ai.last_rule(), Some("rocks"));
+
+        // Rocks are up: Earthquake is the super effective move on Heatran.
+        state.side_b.stealth_rock = true;
+        assert_eq!(ai.choose_action(&state, &ALL), Action::Move(1));
+        assert_eq!(ai.last_rule(), Some("weak"));
+
+        state.pokemon_a.current_hp = state.pokemon_a.stats.hp / 5;
+        assert_eq!(ai.choose_action(&state, &ALL), Action::Switch(0));
+        assert_eq!(ai.last_rule(), Some("bail"));
+
+        // A rule whose action is not legal does not fire.
+        assert_eq!(ai.choose_action(&state, &ALL[..4]), Action::Move(1));
+        assert_eq!(ai.last_rule(), Some("weak"));
+
+        // Nothing fires against Skarmory: the random fallback plays.
+        state.pokemon_b = make("Skarmory", &["Drill Peck"]);
+        state.turn = 4;
+        state.pokemon_a.current_hp = state.pokemon_a.stats.hp;
+        let action = ai.choose_action(&state, &ALL);
+        assert!(ALL.contains(&action));
+        assert_eq!(ai.last_rule(), None);
+    }
+
+    #[test]
+    fn ties_are_broken_at_random_and_switch_rules_pick_replacements() {
+        let json = r#"{"rules": [
+            {"name": "claw", "then": {"move": "Dragon Claw"}},
+            {"name": "dance", "then": {"move": "Swords Dance"}},
+            {"name": "anyone", "then": {"switch": "any"}},
+            {"name": "never", "priority": -1, "then": {"move": "Earthquake"}}
+        ]}"#;
+        let state = state();
+        let mut seen = Vec::new();
+        for seed in 0..40 {
+            let action = ai(json, seed).choose_action(&state, &ALL);
+            if !seen.contains(&action) {
+                seen.push(action);
+            }
+        }
+        assert!(seen.contains(&Action::Move(2)) && seen.contains(&Action::Move(3)));
+        assert!(seen.contains(&Action::Switch(0)) && seen.contains(&Action::Switch(1)));
+        assert!(!seen.contains(&Action::Move(1)));
+
+        let json = r#"{"rules": [
+            {"name": "attack", "priority": 10, "then": {"move": "any"}},
+            {"name": "pikachu in", "then": {"switch": "Pikachu"}}
+        ]}"#;
+        let mut ai = ai(json, 3);
+        assert_eq!(ai.choose_replacement(&state, &[0, 1]), Some(1));
+        assert_eq!(ai.last_rule(), Some("pikachu in"));
+    }
+}
@@ -0,0 +1,473 @@
+//! A value function learned by self-play instead of hand tuning.
+//!
+//! [`ValueFunction`] is linear over [`features`] of a position, squashed to -1..1 for player
+//! A: `tanh(w · (f(A) - f(B)))`, where `f` describes one side (HP, stat stages, status,
+//! hazards, speed order, type matchup, ...). Taking the difference makes the value
+//! symmetric: swapping the sides negates it. [`ValueFunction::train`] fits the weights by
+//! TD(λ) on battles the [`ValueAI`] plays against itself through [`BattleEngine`].
+//!
+//! The value function is an [`Evaluator`], so the search AIs can score their leaves with it
+//! (`ExpectiminimaxAI::with_evaluator`, `NashAI::with_evaluator`), and weights are saved to
+//! and loaded from JSON files.
+
+use super::expectiminimax::Evaluator;
+use super::heuristic::estimate_damage;
+use super::{sample_strategy, BattleAI};
+use crate::data::types::Type;
+use crate::engine::{actions_for, battle_outcome, play_turn, BattleEngine, Player};
+use crate::i18n::messages::silently;
+use crate::sim::battle::{effective_speed, effective_types, Action, BattleResult, BattleState, SideConditions};
+use crate::sim::nash::{self, Equilibrium};
+use crate::sim::pokemon::{Pokemon, Status};
+use anyhow::{bail, Context, Result};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Names of the per-side features, in weight order.
+pub const FEATURES: &[&str] = &[
+    "team_hp",
+    "remaining",
+    "active_hp",
+    "atk_stage",
+    "def_stage",
+    "spa_stage",
+    "spd_stage",
+    "spe_stage",
+    "burned",
+    "paralyzed",
+    "poisoned",
+    "asleep",
+    "frozen",
+    "stealth_rock",
+    "spikes",
+    "toxic_spikes",
+    "sticky_web",
+    "screens",
+    "faster",
+    "best_damage",
+    "type_advantage",
+];
+
+/// The feature vector of `state` for player A: A's side features minus B's.
+pub fn features(state: &BattleState) -> Vec<f64> {
+    let size = (1 + state.bench_a.len().max(state.bench_b.len())) as f64;
+    let a = side_features(state, Player::A, size);
+    let b = side_features(state, Player::B, size);
+    a.iter().zip(&b).map(|(a, b)| a - b).collect()
+}
+
+fn side_features(state: &BattleState, player: Player, size: f64) -> Vec<f64> {
+    let (me, bench, side, foe, foe_side) = match player {
+        Player::A => (&state.pokemon_a, &state.bench_a, &state.side_a, &state.pokemon_b, &state.side_b),
+        Player::B => (&state.pokemon_b, &state.bench_b, &state.side_b, &state.pokemon_a, &state.side_a),
+    };
+    let team = || std::iter::once(me).chain(bench.iter());
+    let hp_fraction = |pokemon: &Pokemon| pokemon.current_hp as f64 / pokemon.stats.hp.max(1) as f64;
+    let status = |wanted: Status| if me.status == Some(wanted) { 1.0 } else { 0.0 };
+    let flag = |on: bool| if on { 1.0 } else { 0.0 };
+    let screens = [side.reflect_turns, side.light_screen_turns, side.aurora_veil_turns]
+        .iter()
+        .filter(|&&turns| turns > 0)
+        .count();
+
+    let mut features = Vec::with_capacity(FEATURES.len());
+    features.push(team().map(hp_fraction).sum::<f64>() / size);
+    features.push(team().filter(|pokemon| !pokemon.is_fainted()).count() as f64 / size);
+    features.push(hp_fraction(me));
+    features.extend(me.stat_stages[..5].iter().map(|&stage| stage as f64 / 6.0));
+    features.push(status(Status::Burn));
+    features.push(status(Status::Paralysis));
+    features.push(status(Status::Poison));
+    features.push(status(Status::Sleep));
+    features.push(status(Status::Freeze));
+    features.push(flag(side.stealth_rock));
+    features.push(side.spikes as f64 / 3.0);
+    features.push(side.toxic_spikes as f64 / 2.0);
+    features.push(flag(side.sticky_web));
+    features.push(screens as f64 / 2.0);
+    features.push(flag(outspeeds(state, me, side, foe, foe_side)));
+    features.push(best_damage(state, me, foe, foe_side));
+    features.push(type_advantage(state, me, foe));
+    features
+}
+
+fn outspeeds(state: &BattleState, me: &Pokemon, side: &SideConditions, foe: &Pokemon, foe_side: &SideConditions) -> bool {
+    let speed = |pokemon: &Pokemon, side: &SideConditions| {
+        let speed = effective_speed(pokemon, state.weather) as u32;
+        if side.tailwind_turns > 0 {
+            speed * 2
+        } else {
+            speed
+        }
+    };
+    let (mine, theirs) = (speed(me, side), speed(foe, foe_side));
+    if state.trick_room_turns > 0 {
+        mine < theirs
+    } else {
+        mine > theirs
+    }
+}
+
+/// The best average damage `me` can do to `foe`, as a fraction of `foe`'s HP left (0-1).
+fn best_damage(state: &BattleState, me: &Pokemon, foe: &Pokemon, foe_side: &SideConditions) -> f64 {
+    if me.is_fainted() || foe.is_fainted() {
+        return 0.0;
+    }
+    let best = me
+        .moves
+        .iter()
+        .filter_map(|id| estimate_damage(state, me, foe, foe_side, id))
+        .map(|estimate| estimate.average())
+        .fold(0.0f32, f32::max);
+    (best as f64 / foe.current_hp.max(1) as f64).min(1.0)
+}
+
+/// How well `me`'s own types hit `foe`: log2 of the best effectiveness, over 2 (-1..1).
+fn type_advantage(state: &BattleState, me: &Pokemon, foe: &Pokemon) -> f64 {
+    let [t0, t1] = effective_types(foe);
+    let best = me
+        .types
+        .iter()
+        .filter(|&&attacking| attacking != Type::Typeless)
+        .map(|&attacking| state.type_chart.effectiveness_dual(attacking, t0, t1))
+        .fold(f32::NEG_INFINITY, f32::max);
+    if !best.is_finite() {
+        return 0.0;
+    }
+    if best <= 0.0 {
+        return -1.0;
+    }
+    (best.log2() as f64 / 2.0).clamp(-1.0, 1.0)
+}
+
+/// A linear value function over [`FEATURES`], as saved to and loaded from a file.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ValueFunction {
+    /// The feature names the weights were trained on.
+    pub features: Vec<String>,
+    pub weights: Vec<f64>,
+}
+
+impl Default for ValueFunction {
+    /// Untrained: the remaining HP decides, like [`HpEvaluator`](super::expectiminimax::HpEvaluator).
+    fn default() -> Self {
+        let mut weights = vec![0.0; FEATURES.len()];
+        weights[0] = 1.5;
+        weights[1] = 0.5;
+        Self { features: FEATURES.iter().map(|name| name.to_string()).collect(), weights }
+    }
+}
+
+impl ValueFunction {
+    /// Value of `state` for player A, -1 (lost) to 1 (won). Finished battles score exactly.
+    pub fn value(&self, state: &BattleState) -> f64 {
+        match battle_outcome(state) {
+            Some(BattleResult::TeamAWins) => 1.0,
+            Some(BattleResult::TeamBWins) => -1.0,
+            Some(BattleResult::Draw) => 0.0,
+            None => self.predict(&features(state)),
+        }
+    }
+
+    fn predict(&self, features: &[f64]) -> f64 {
+        self.weights.iter().zip(features).map(|(w, x)| w * x).sum::<f64>().tanh()
+    }
+
+    /// Write the weights as JSON.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json).with_context(|| format!("failed to write value weights {}", path.display()))
+    }
+
+    /// Read weights written by [`save`](Self::save). Fails if they were trained on other features.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).with_context(|| format!("failed to read value weights {}", path.display()))?;
+        let value: Self = serde_json::from_str(&text)
+            .with_context(|| format!("value weights {} are not valid JSON", path.display()))?;
+        if value.features != FEATURES || value.weights.len() != FEATURES.len() {
+            bail!(
+                "value weights {} were trained on different features (expected: {})",
+                path.display(),
+                FEATURES.join(", ")
+            );
+        }
+        Ok(value)
+    }
+
+    /// Train by TD(λ) self-play: battle `params.episodes` times between the teams
+    /// `teams(episode)` returns, both sides played by a [`ValueAI`] on these weights, and
+    /// move the value of each position towards the next one (the result, at the end).
+    pub fn train(
+        &mut self,
+        params: &TdParams,
+        mut teams: impl FnMut(usize) -> (Vec<Pokemon>, Vec<Pokemon>),
+    ) -> TrainingReport {
+        let mut rng = SmallRng::seed_from_u64(params.seed);
+        let mut report = TrainingReport::default();
+        silently(|| {
+            for episode in 0..params.episodes {
+                let (team_a, team_b) = teams(episode);
+                let mut engine = BattleEngine::new(&team_a, &team_b, rng.gen());
+                let result = self.train_episode(&mut engine, params, &mut rng);
+                report.episodes += 1;
+                report.turns += engine.state().turn as usize;
+                match result {
+                    Some(BattleResult::TeamAWins) => report.wins[0] += 1,
+                    Some(BattleResult::TeamBWins) => report.wins[1] += 1,
+                    Some(BattleResult::Draw) => report.draws += 1,
+                    None => report.unfinished += 1,
+                }
+            }
+        });
+        report
+    }
+
+    fn train_episode(&mut self, engine: &mut BattleEngine, params: &TdParams, rng: &mut SmallRng) -> Option<BattleResult> {
+        let mut trace = vec![0.0; self.weights.len()];
+        let mut x = features(engine.state());
+        for _ in 0..params.max_turns {
+            if let Some(result) = engine.outcome() {
+                return Some(result);
+            }
+            let (actions_a, actions_b, equilibrium) = lookahead(self, engine.state(), params.samples, rng);
+            let pick = |actions: &[Action], strategy: &[f64], rng: &mut SmallRng| {
+                if rng.gen::<f64>() < params.exploration {
+                    actions[rng.gen_range(0..actions.len())]
+                } else {
+                    let strategy: Vec<(Action, f64)> = actions.iter().copied().zip(strategy.iter().copied()).collect();
+                    sample_strategy(&strategy, actions, rng)
+                }
+            };
+            let action_a = pick(&actions_a, &equilibrium.row, rng);
+            let action_b = pick(&actions_b, &equilibrium.col, rng);
+            let step = engine.step(action_a, action_b);
+
+            let v = self.predict(&x);
+            let next_x = features(&step.after);
+            let target = match step.outcome {
+                Some(_) => self.value(&step.after),
+                None => self.predict(&next_x),
+            };
+            let delta = target - v;
+            let slope = 1.0 - v * v;
+            for ((e, w), xi) in trace.iter_mut().zip(self.weights.iter_mut()).zip(&x) {
+                *e = params.lambda * *e + slope * xi;
+                *w += params.alpha * delta * *e;
+            }
+            x = next_x;
+        }
+        engine.outcome()
+    }
+}
+
+impl Evaluator for ValueFunction {
+    fn evaluate(&self, state: &BattleState) -> f64 {
+        self.value(state)
+    }
+}
+
+/// TD(λ) self-play settings.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TdParams {
+    /// Battles to play.
+    pub episodes: usize,
+    /// Learning rate.
+    pub alpha: f64,
+    /// Trace decay: 0 learns from the next position only, 1 from the final result.
+    pub lambda: f64,
+    /// Chance (0-1) that a side plays a random action instead, to explore.
+    pub exploration: f64,
+    /// Battles still going after this many turns are cut off.
+    pub max_turns: usize,
+    /// Simulated turns per action pair when choosing actions.
+    pub samples: usize,
+    pub seed: u64,
+}
+
+impl Default for TdParams {
+    fn default() -> Self {
+        Self { episodes: 100, alpha: 0.01, lambda: 0.7, exploration: 0.1, max_turns: 200, samples: 1, seed: 0 }
+    }
+}
+
+/// What [`ValueFunction::train`] played.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TrainingReport {
+    pub episodes: usize,
+    /// Wins of team A and team B.
+    pub wins: [usize; 2],
+    pub draws: usize,
+    /// Battles cut off at `max_turns`.
+    pub unfinished: usize,
+    pub turns: usize,
+}
+
+/// The one-turn lookahead matrix of `state` under `value`, from `samples` simulated turns
+/// per action pair, and its equilibrium.
+fn lookahead(value: &ValueFunction, state: &BattleState, samples: usize, rng: &mut SmallRng) -> (Vec<Action>, Vec<Action>, Equilibrium) {
+    let actions_a = actions_for(&state.pokemon_a, &state.bench_a);
+    let actions_b = actions_for(&state.pokemon_b, &state.bench_b);
+    let samples = samples.max(1);
+    let payoff: Vec<Vec<f64>> = actions_a
+        .iter()
+        .map(|&a| {
+            actions_b
+                .iter()
+                .map(|&b| {
+                    let total: f64 = (0..samples)
+                        .map(|_| {
+                            let mut after = state.clone();
+                            after.logger = None;
+                            play_turn(&mut after, a, b, rng);
+                            value.value(&after)
+                        })
+                        .sum();
+                    total / samples as f64
+                })
+                .collect()
+        })
+        .collect();
+    (actions_a, actions_b, nash::solve(&payoff))
+}
+
+/// Plays the equilibrium of a sampled one-turn lookahead scored by a [`ValueFunction`].
+pub struct ValueAI {
+    value: ValueFunction,
+    samples: usize,
+    side: usize,
+    rng: SmallRng,
+}
+
+impl ValueAI {
+    pub fn new(value: ValueFunction, seed: u64) -> Self {
+        Self { value, samples: TdParams::default().samples, side: 0, rng: SmallRng::seed_from_u64(seed) }
+    }
+
+    /// Simulate each action pair `samples` times instead of once.
+    pub fn with_samples(mut self, samples: usize) -> Self {
+        self.samples = samples.max(1);
+        self
+    }
+
+    pub fn value_function(&self) -> &ValueFunction {
+        &self.value
+    }
+}
+
+impl BattleAI for ValueAI {
+    fn choose_action(&mut self, state: &BattleState, valid_actions: &[Action]) -> Action {
+        if valid_actions.len() <= 1 {
+            return valid_actions.first().copied().unwrap_or(Action::Move(0));
+        }
+        let (actions_a, actions_b, equilibrium) = silently(|| lookahead(&self.value, state, self.samples, &mut self.rng));
+        let (actions, strategy) = if self.side == 0 {
+            (actions_a, equilibrium.row)
+        } else {
+            (actions_b, equilibrium.col)
+        };
+        let strategy: Vec<(Action, f64)> = actions.into_iter().zip(strategy).collect();
+        sample_strategy(&strategy, valid_actions, &mut self.rng)
+    }
+
+    fn set_side(&mut self, side: usize) {
+        self.side = side.min(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::ai::expectiminimax::{analyze, SearchParams};
+    use crate::sim::stats::Nature;
+
+    fn make(species: &str, moves: &[&str]) -> Pokemon {
+        Pokemon::new(
+            species,
+            50,
+            [0, 252, 0, 252, 0, 0],
+            [31; 6],
+            Nature::Hardy,
+            moves.iter().map(|m| m.to_string()).collect(),
+            "Pressure",
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn features_are_named_and_symmetric() {
+        let mut pikachu = make("Pikachu", &["Thunderbolt"]);
+        pikachu.current_hp /= 2;
+        pikachu.status = Some(Status::Paralysis);
+        let gyarados = make("Gyarados", &["Waterfall"]);
+        let forward = features(&BattleState::new(pikachu.clone(), gyarados.clone()));
+        let backward = features(&BattleState::new(gyarados, pikachu));
+        assert_eq!(forward.len(), FEATURES.len());
+        for (f, b) in forward.iter().zip(&backward) {
+            assert!((f + b).abs() < 1e-9);
+        }
+        let at = |name: &str| forward[FEATURES.iter().position(|&f| f == name).unwrap()];
+        assert!(at("active_hp") < 0.0);
+        assert_eq!(at("paralyzed"), 1.0);
+        // Thunderbolt is super effective on Gyarados; Gyarados's Water/Flying is neutral on Pikachu.
+        assert!(at("type_advantage") > 0.0);
+
+        let value = ValueFunction::default();
+        let forward = value.value(&BattleState::new(make("Pikachu", &["Tackle"]), make("Eevee", &["Tackle"])));
+        let backward = value.value(&BattleState::new(make("Eevee", &["Tackle"]), make("Pikachu", &["Tackle"])));
+        assert!((forward + backward).abs() < 1e-9);
+    }
+
+    #[test]
+    fn weights_round_trip_through_a_file() {
+        let dir = std::env::temp_dir().join(format!("td-weights-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("weights.json");
+        let mut value = ValueFunction::default();
+        value.weights[5] = -0.25;
+        value.save(&path).unwrap();
+        assert_eq!(ValueFunction::load(&path).unwrap(), value);
+
+        value.features.pop();
+        value.weights.pop();
+        value.save(&path).unwrap();
+        let err = ValueFunction::load(&path).unwrap_err().to_string();
+        assert!(err.contains("different features"), "{}", err);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn self_play_learns_and_the_result_plugs_into_search() {
+        // Garchomp outclasses Pikachu: the trained value should favour the side with Garchomp.
+        let teams = |episode: usize| {
+            let garchomp = make("Garchomp", &["Earthquake", "Dragon Claw"]);
+            let pikachu = make("Pikachu", &["Thunderbolt", "Quick Attack"]);
+            if episode.is_multiple_of(2) {
+                (vec![garchomp], vec![pikachu])
+            } else {
+                (vec![pikachu], vec![garchomp])
+            }
+        };
+        let mut value = ValueFunction { weights: vec![0.0; FEATURES.len()], ..ValueFunction::default() };
+        let params = TdParams { episodes: 20, alpha: 0.1, ..TdParams::default() };
+        let report = value.train(&params, teams);
+        assert_eq!(report.episodes, 20);
+        assert_eq!(report.wins[0] + report.wins[1] + report.draws + report.unfinished, 20);
+        assert!(value.weights.iter().any(|&w| w != 0.0));
+        let (a, b) = teams(0);
+        let state = BattleState::new(a[0].clone(), b[0].clone());
+        assert!(value.value(&state) > 0.0, "{:?}", value.weights);
+
+        let analysis = analyze(&state, SearchParams { depth: 1, ..SearchParams::default() }, &value);
+        assert!(analysis.value > 0.0);
+        let mut ai = ValueAI::new(value, 1);
+        ai.set_side(0);
+        let action = ai.choose_action(&state, &[Action::Move(0), Action::Move(1)]);
+        assert_eq!(action, Action::Move(0));
+    }
+}
@@ -1,7 +1,8 @@
-use crate::data::moves::{get_move, MoveCategory};
-use crate::data::types::{effectiveness_dual, Type};
+use crate::data::moves::MoveCategory;
+use crate::data::types::Type;
 use crate::battle_logger::{showdown_ident, BattleLogger};
-use crate::i18n::{translate_item, translate_move, translate_pokemon};
+use crate::dex::Dex;
+use crate::i18n::messages::{self, narrate, render, status_key};
 use crate::sim::ai::BattleAI;
 use crate::sim::abilities::misc_abilities::{
     apply_contact_damage_abilities, apply_effect_spore, poison_heal_amount, speed_multiplier,
@@ -25,10 +26,13 @@ use crate::sim::moves::secondary::{
 use crate::sim::moves::status::handle_status_move;
 use crate::sim::pokemon::{Pokemon, Status};
 use crate::sim::switching::{self, SwitchKind};
+use crate::sim::type_chart::TypeChart;
+use crate::sim::chance::Chance;
 use rand::rngs::SmallRng;
-use rand::{Rng, SeedableRng};
+use rand::SeedableRng;
+use std::sync::Arc;
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Weather {
     Sun,
     Rain,
@@ -36,13 +40,13 @@ pub enum Weather {
     Hail,
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum FieldEffect {
     Reflect,
     LightScreen,
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Field {
     Grassy,
     Electric,
@@ -79,6 +83,10 @@ pub struct BattleState {
     pub trick_room_turns: u8,
     pub side_a: SideConditions,
     pub side_b: SideConditions,
+    /// Species/move data used by this battle. Defaults to [`Dex::builtin`].
+    pub dex: Arc<Dex>,
+    /// Type chart used for move and Stealth Rock effectiveness. Defaults to Gen 6+.
+    pub type_chart: TypeChart,
 }
 
 impl BattleState {
@@ -98,6 +106,8 @@ impl BattleState {
             trick_room_turns: 0,
             side_a: SideConditions::default(),
             side_b: SideConditions::default(),
+            dex: Dex::builtin(),
+            type_chart: TypeChart::default(),
         }
     }
 
@@ -122,11 +132,25 @@ impl BattleState {
             trick_room_turns: 0,
             side_a: SideConditions::default(),
             side_b: SideConditions::default(),
+            dex: Dex::builtin(),
+            type_chart: TypeChart::default(),
         }
     }
+
+    /// Use `dex` (e.g. one with data mods loaded) for every lookup in this battle.
+    pub fn with_dex(mut self, dex: Arc<Dex>) -> Self {
+        self.dex = dex;
+        self
+    }
+
+    /// Use `type_chart` (an older generation's chart, Inverse Battle, ...) for this battle.
+    pub fn with_type_chart(mut self, type_chart: TypeChart) -> Self {
+        self.type_chart = type_chart;
+        self
+    }
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct SideConditions {
     pub stealth_rock: bool,
     pub spikes: u8,
@@ -200,6 +224,7 @@ pub(crate) struct SideConditionUpdate {
 #[derive(Clone, Copy, Debug)]
 pub(crate) enum HazardClear {
     Side(usize),
+    #[allow(dead_code)]
     Both,
 }
 
@@ -249,12 +274,15 @@ pub fn run_team_battle(
     run_battle_with_state(&mut state, ai_a, ai_b)
 }
 
-fn run_battle_with_state(
+/// Run a battle on a prepared state (e.g. one built with [`BattleState::with_dex`]).
+pub fn run_battle_with_state(
     state: &mut BattleState,
     ai_a: &mut dyn BattleAI,
     ai_b: &mut dyn BattleAI,
 ) -> BattleResult {
     apply_on_entry_abilities(state);
+    ai_a.set_side(0);
+    ai_b.set_side(1);
     let mut rng = SmallRng::seed_from_u64(0xBADC0DE);
     for _ in 0..500 {
         if !side_has_available(&state.pokemon_a, &state.bench_a)
@@ -268,27 +296,9 @@ fn run_battle_with_state(
         if !side_has_available(&state.pokemon_b, &state.bench_b) {
             return BattleResult::TeamAWins;
         }
-        if state.pokemon_a.is_fainted() {
-            if let Some(idx) = switching::pick_random_switch(&state.bench_a, &mut rng) {
-                perform_switch(state, 0, idx, SwitchKind::Forced, &mut rng);
-            }
-        }
-        if state.pokemon_b.is_fainted() {
-            if let Some(idx) = switching::pick_random_switch(&state.bench_b, &mut rng) {
-                perform_switch(state, 1, idx, SwitchKind::Forced, &mut rng);
-            }
-        }
+        replace_fainted(state, ai_a, ai_b, &mut rng);
         apply_start_of_turn_effects(state, &mut rng);
-        if state.pokemon_a.is_fainted() {
-            if let Some(idx) = switching::pick_random_switch(&state.bench_a, &mut rng) {
-                perform_switch(state, 0, idx, SwitchKind::Forced, &mut rng);
-            }
-        }
-        if state.pokemon_b.is_fainted() {
-            if let Some(idx) = switching::pick_random_switch(&state.bench_b, &mut rng) {
-                perform_switch(state, 1, idx, SwitchKind::Forced, &mut rng);
-            }
-        }
+        replace_fainted(state, ai_a, ai_b, &mut rng);
         if !side_has_available(&state.pokemon_a, &state.bench_a)
             && !side_has_available(&state.pokemon_b, &state.bench_b)
         {
@@ -315,7 +325,7 @@ fn run_battle_with_state(
         if let Some(logger) = state.logger.as_mut() {
             logger.log_turn((state.turn + 1) as usize);
         }
-        println!("Turn {}:", state.turn + 1);
+        println!("{}", render("turn", &[("turn", &(state.turn + 1))]));
         let actions_a = valid_actions(&state.pokemon_a, &state.bench_a);
         let actions_b = valid_actions(&state.pokemon_b, &state.bench_b);
         if actions_a.is_empty() && actions_b.is_empty() {
@@ -331,14 +341,41 @@ fn run_battle_with_state(
     BattleResult::Draw
 }
 
+// Forced switches after a faint: the AI's pick when it makes one, else a random one.
+fn replace_fainted(state: &mut BattleState, ai_a: &mut dyn BattleAI, ai_b: &mut dyn BattleAI, rng: &mut dyn Chance) {
+    replace_fainted_side(state, 0, ai_a, rng);
+    replace_fainted_side(state, 1, ai_b, rng);
+}
+
+fn replace_fainted_side(state: &mut BattleState, side_idx: usize, ai: &mut dyn BattleAI, rng: &mut dyn Chance) {
+    let (active, bench) = if side_idx == 0 {
+        (&state.pokemon_a, &state.bench_a)
+    } else {
+        (&state.pokemon_b, &state.bench_b)
+    };
+    if !active.is_fainted() {
+        return;
+    }
+    let options: Vec<usize> = (0..bench.len()).filter(|&idx| !bench[idx].is_fainted()).collect();
+    if options.is_empty() {
+        return;
+    }
+    let picked = ai.choose_replacement(state, &options).filter(|idx| options.contains(idx));
+    let bench = if side_idx == 0 { &state.bench_a } else { &state.bench_b };
+    if let Some(idx) = picked.or_else(|| switching::pick_random_switch(bench, rng)) {
+        perform_switch(state, side_idx, idx, SwitchKind::Forced, rng);
+    }
+}
+
 pub fn execute_turn(
     state: &mut BattleState,
     action_a: Action,
     action_b: Action,
-    rng: &mut SmallRng,
+    rng: &mut dyn Chance,
 ) {
     let (a_first, b_first) =
-        determine_order(
+        determine_order_in(
+            &state.dex,
             &state.pokemon_a,
             action_a,
             &state.pokemon_b,
@@ -362,7 +399,7 @@ pub fn execute_turn(
     handle_simultaneous_faints(state, rng);
 }
 
-fn handle_simultaneous_faints(state: &mut BattleState, rng: &mut SmallRng) {
+fn handle_simultaneous_faints(state: &mut BattleState, rng: &mut dyn Chance) {
     let a_fainted = state.pokemon_a.is_fainted();
     let b_fainted = state.pokemon_b.is_fainted();
     if !a_fainted && !b_fainted {
@@ -372,7 +409,7 @@ fn handle_simultaneous_faints(state: &mut BattleState, rng: &mut SmallRng) {
         let spe_a = effective_speed(&state.pokemon_a, state.weather);
         let spe_b = effective_speed(&state.pokemon_b, state.weather);
         if spe_a == spe_b {
-            rng.gen_bool(0.5)
+            rng.chance(0.5)
         } else {
             spe_a > spe_b
         }
@@ -395,6 +432,7 @@ fn handle_simultaneous_faints(state: &mut BattleState, rng: &mut SmallRng) {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn determine_order(
     pokemon_a: &Pokemon,
     action_a: Action,
@@ -403,10 +441,36 @@ pub fn determine_order(
     trick_room_active: bool,
     weather: Option<Weather>,
     field: Option<Field>,
-    rng: &mut SmallRng,
+    rng: &mut dyn Chance,
 ) -> (bool, bool) {
-    let priority_a = action_priority(action_a, pokemon_a, field);
-    let priority_b = action_priority(action_b, pokemon_b, field);
+    determine_order_in(
+        &Dex::builtin(),
+        pokemon_a,
+        action_a,
+        pokemon_b,
+        action_b,
+        trick_room_active,
+        weather,
+        field,
+        rng,
+    )
+}
+
+/// [`determine_order`] with move priorities looked up in `dex`.
+#[allow(clippy::too_many_arguments)]
+pub fn determine_order_in(
+    dex: &Dex,
+    pokemon_a: &Pokemon,
+    action_a: Action,
+    pokemon_b: &Pokemon,
+    action_b: Action,
+    trick_room_active: bool,
+    weather: Option<Weather>,
+    field: Option<Field>,
+    rng: &mut dyn Chance,
+) -> (bool, bool) {
+    let priority_a = action_priority(dex, action_a, pokemon_a, field);
+    let priority_b = action_priority(dex, action_b, pokemon_b, field);
     if priority_a != priority_b {
         let a_first = priority_a > priority_b;
         return (a_first, !a_first);
@@ -421,31 +485,31 @@ pub fn determine_order(
         };
         return (a_first, !a_first);
     }
-    let coin = rng.gen_bool(0.5);
+    let coin = rng.chance(0.5);
     (coin, !coin)
 }
 
-fn action_priority(action: Action, pokemon: &Pokemon, field: Option<Field>) -> i8 {
+fn action_priority(dex: &Dex, action: Action, pokemon: &Pokemon, field: Option<Field>) -> i8 {
     match action {
         Action::Move(idx) => pokemon
             .moves
             .get(idx)
-            .and_then(|name| get_move(name.as_str()))
+            .and_then(|name| dex.get_move(name.as_str()))
             .map(|mv| get_move_priority(mv, pokemon, field))
             .unwrap_or(0),
         Action::Switch(_) => 6,
     }
 }
 
-fn is_attack_action(action: Action, pokemon: &Pokemon) -> bool {
+fn is_attack_action(dex: &Dex, action: Action, pokemon: &Pokemon) -> bool {
     if let Some(charging) = pokemon.charging_move.as_deref() {
-        if let Some(data) = get_move(charging) {
+        if let Some(data) = dex.get_move(charging) {
             return !matches!(data.category, MoveCategory::Status);
         }
     }
     if pokemon.encore_turns > 0 {
         if let Some(encore) = pokemon.encore_move.as_deref() {
-            if let Some(data) = get_move(encore) {
+            if let Some(data) = dex.get_move(encore) {
                 return !matches!(data.category, MoveCategory::Status);
             }
         }
@@ -454,7 +518,7 @@ fn is_attack_action(action: Action, pokemon: &Pokemon) -> bool {
         Action::Move(idx) => pokemon
             .moves
             .get(idx)
-            .and_then(|name| get_move(name.as_str()))
+            .and_then(|name| dex.get_move(name.as_str()))
             .map(|mv| !matches!(mv.category, MoveCategory::Status))
             .unwrap_or(false),
         Action::Switch(_) => false,
@@ -467,7 +531,7 @@ pub(crate) const STAGE_SPA: usize = 2;
 pub(crate) const STAGE_SPD: usize = 3;
 pub(crate) const STAGE_SPE: usize = 4;
 
-fn effective_speed(pokemon: &Pokemon, weather: Option<Weather>) -> u16 {
+pub(crate) fn effective_speed(pokemon: &Pokemon, weather: Option<Weather>) -> u16 {
     let mut spe = apply_stage_multiplier(pokemon.stats.spe, pokemon.stat_stages[STAGE_SPE]);
     if matches!(pokemon.status, Some(Status::Paralysis)) && !pokemon.has_ability("Quick Feet") {
         spe = ((spe as f32) * 0.5).floor() as u16;
@@ -498,7 +562,7 @@ fn accuracy_multiplier(stage: i8) -> f32 {
     }
 }
 
-fn apply_stage_multiplier(base: u16, stage: i8) -> u16 {
+pub(crate) fn apply_stage_multiplier(base: u16, stage: i8) -> u16 {
     let value = (base as f32) * stage_multiplier(stage);
     value.floor().max(1.0) as u16
 }
@@ -511,19 +575,19 @@ pub(crate) fn apply_stage_change(pokemon: &mut Pokemon, name: &str, stat: usize,
         return false;
     }
     pokemon.stat_stages[stat] = next;
-    let stat_name = match stat {
-        STAGE_ATK => "こうげき",
-        STAGE_DEF => "ぼうぎょ",
-        STAGE_SPA => "とくこう",
-        STAGE_SPD => "とくぼう",
-        STAGE_SPE => "すばやさ",
-        _ => "のうりょく",
+    let stat_key = match stat {
+        STAGE_ATK => "stat_atk",
+        STAGE_DEF => "stat_def",
+        STAGE_SPA => "stat_spa",
+        STAGE_SPD => "stat_spd",
+        STAGE_SPE => "stat_spe",
+        _ => "stat_other",
     };
-    let direction = if delta > 0 { "あがった" } else { "さがった" };
-    println!("  {}の{}が{}！", name, stat_name, direction);
+    narrate_stage_change(name, stat_key, delta);
     true
 }
 
+#[allow(dead_code)]
 pub(crate) fn apply_accuracy_change(pokemon: &mut Pokemon, name: &str, delta: i8) -> bool {
     let current = pokemon.accuracy_stage;
     let next = current.saturating_add(delta).clamp(-6, 6);
@@ -531,11 +595,11 @@ pub(crate) fn apply_accuracy_change(pokemon: &mut Pokemon, name: &str, delta: i8
         return false;
     }
     pokemon.accuracy_stage = next;
-    let direction = if delta > 0 { "あがった" } else { "さがった" };
-    println!("  {}のめいちゅうが{}！", name, direction);
+    narrate_stage_change(name, "stat_accuracy", delta);
     true
 }
 
+#[allow(dead_code)]
 pub(crate) fn apply_evasion_change(pokemon: &mut Pokemon, name: &str, delta: i8) -> bool {
     let current = pokemon.evasion_stage;
     let next = current.saturating_add(delta).clamp(-6, 6);
@@ -543,45 +607,46 @@ pub(crate) fn apply_evasion_change(pokemon: &mut Pokemon, name: &str, delta: i8)
         return false;
     }
     pokemon.evasion_stage = next;
-    let direction = if delta > 0 { "あがった" } else { "さがった" };
-    println!("  {}のかいひが{}！", name, direction);
+    narrate_stage_change(name, "stat_evasion", delta);
     true
 }
 
+fn narrate_stage_change(name: &str, stat_key: &str, delta: i8) {
+    let key = if delta > 0 { "stat_rise" } else { "stat_fall" };
+    narrate!(key, name = name, stat = render(stat_key, &[]));
+}
+
 pub(crate) fn reset_stat_stages(pokemon: &mut Pokemon, name: &str) {
     pokemon.stat_stages = [0; 6];
     pokemon.accuracy_stage = 0;
     pokemon.evasion_stage = 0;
-    println!("  {}ののうりょくへんかが もとにもどった！", name);
+    narrate!("stats_reset", name = name);
 }
 
+#[allow(dead_code)]
 pub(crate) fn heal_hp(pokemon: &mut Pokemon, name: &str, ratio: f32) {
     let max_hp = pokemon.stats.hp;
     if pokemon.current_hp >= max_hp {
-        println!("  しかし こうかがなかった！");
+        narrate!("no_effect");
         return;
     }
     let amount = ((max_hp as f32) * ratio).floor() as u16;
     pokemon.current_hp = (pokemon.current_hp + amount).min(max_hp);
-    println!(
-        "  {}はHPをかいふくした！ (HP: {}/{})",
-        name, pokemon.current_hp, max_hp
-    );
+    narrate!("heal", name = name, hp = pokemon.current_hp, max_hp = max_hp);
 }
 
-fn effective_types(pokemon: &Pokemon) -> [Type; 2] {
+pub(crate) fn effective_types(pokemon: &Pokemon) -> [Type; 2] {
     if !pokemon.roosted {
         return pokemon.types;
     }
-    let mut t0 = pokemon.types[0];
-    let mut t1 = pokemon.types[1];
-    if t0 == Type::Flying {
-        t0 = t1;
-        t1 = Type::Normal;
-    } else if t1 == Type::Flying {
-        t1 = Type::Normal;
+    // PS: Roost removes Flying; a pure Flying type becomes Normal (Gen 5+).
+    let [t0, t1] = pokemon.types;
+    match (t0 == Type::Flying, t1 == Type::Flying) {
+        (true, true) => [Type::Normal, Type::Normal],
+        (true, false) => [t1, t1],
+        (false, true) => [t0, t0],
+        (false, false) => [t0, t1],
     }
-    [t0, t1]
 }
 
 fn item_id(pokemon: &Pokemon) -> Option<String> {
@@ -623,36 +688,37 @@ fn apply_on_entry_ability_effects(
     apply_field_ability(state, ability);
     apply_weather_ability(state, ability);
 
+    let dex = state.dex.clone();
     let (user, foe) = if side_idx == 0 {
         (&mut state.pokemon_a, &mut state.pokemon_b)
     } else {
         (&mut state.pokemon_b, &mut state.pokemon_a)
     };
-    let user_name = translate_pokemon(&user.species);
-    let foe_name = translate_pokemon(&foe.species);
+    let user_name = user.display_name(&dex);
+    let foe_name = foe.display_name(&dex);
 
     if ability.eq_ignore_ascii_case("Intimidate") && !foe.is_fainted() {
         if apply_intimidate(foe) {
-            println!("  {}のこうげきがさがった！", foe_name);
+            narrate!("stat_fall", name = foe_name, stat = render("stat_atk", &[]));
         } else {
-            println!("  しかし こうかがなかった！");
+            narrate!("no_effect");
         }
     }
 
     if ability.eq_ignore_ascii_case("Download") && !user.is_fainted() {
         match apply_download(user, foe) {
-            Some(DownloadBoost::Attack) => println!("  {}のこうげきがあがった！", user_name),
-            Some(DownloadBoost::SpAttack) => println!("  {}のとくこうがあがった！", user_name),
-            None => println!("  しかし こうかがなかった！"),
+            Some(DownloadBoost::Attack) => narrate!("stat_rise", name = user_name, stat = render("stat_atk", &[])),
+            Some(DownloadBoost::SpAttack) => narrate!("stat_rise", name = user_name, stat = render("stat_spa", &[])),
+            None => narrate!("no_effect"),
         }
     }
 
     if allow_trace && ability.eq_ignore_ascii_case("Trace") && !user.is_fainted() {
         if let Some(traced) = apply_trace(user, foe) {
-            println!("  {}は{}をトレースした！", user_name, traced);
+            narrate!("trace", name = user_name, ability = messages::ability_name(&dex, &traced));
             apply_on_entry_ability_effects(state, side_idx, traced.as_str(), false);
         } else {
-            println!("  しかし こうかがなかった！");
+            narrate!("no_effect");
         }
     }
 }
@@ -661,22 +727,22 @@ fn apply_field_ability(state: &mut BattleState, ability: &str) {
     if ability.eq_ignore_ascii_case("Grassy Surge") {
         state.field = Some(Field::Grassy);
         state.field_turns = 5;
-        println!("  グラスフィールドが展開された！");
+        narrate!("grassy_terrain_start");
     }
     if ability.eq_ignore_ascii_case("Electric Surge") {
         state.field = Some(Field::Electric);
         state.field_turns = 5;
-        println!("  エレキフィールドが展開された！");
+        narrate!("electric_terrain_start");
     }
     if ability.eq_ignore_ascii_case("Psychic Surge") {
         state.field = Some(Field::Psychic);
         state.field_turns = 5;
-        println!("  サイコフィールドが展開された！");
+        narrate!("psychic_terrain_start");
     }
     if ability.eq_ignore_ascii_case("Misty Surge") {
         state.field = Some(Field::Misty);
         state.field_turns = 5;
-        println!("  ミストフィールドが展開された！");
+        narrate!("misty_terrain_start");
     }
 }
 
@@ -684,22 +750,22 @@ fn apply_weather_ability(state: &mut BattleState, ability: &str) {
     if ability.eq_ignore_ascii_case("Drought") {
         state.weather = Some(Weather::Sun);
         state.weather_turns = 5;
-        println!("  ひざしがつよくなった！");
+        narrate!("sun_start");
     }
     if ability.eq_ignore_ascii_case("Drizzle") {
         state.weather = Some(Weather::Rain);
         state.weather_turns = 5;
-        println!("  あめがふりはじめた！");
+        narrate!("rain_start");
     }
     if ability.eq_ignore_ascii_case("Sand Stream") {
         state.weather = Some(Weather::Sand);
         state.weather_turns = 5;
-        println!("  すなあらしがふきはじめた！");
+        narrate!("sandstorm_start");
     }
     if ability.eq_ignore_ascii_case("Snow Warning") {
         state.weather = Some(Weather::Hail);
         state.weather_turns = 5;
-        println!("  あられがふりはじめた！");
+        narrate!("hail_start");
     }
 }
 
@@ -749,23 +815,28 @@ fn reset_on_switch(pokemon: &mut Pokemon) {
     }
 }
 
+#[allow(clippy::if_same_then_else, clippy::collapsible_if)]
 fn apply_entry_hazards(
+    dex: &Dex,
     pokemon: &mut Pokemon,
     side: &mut SideConditions,
     field: Option<Field>,
-    rng: &mut SmallRng,
+    type_chart: TypeChart,
+    rng: &mut dyn Chance,
 ) {
-    let name = translate_pokemon(&pokemon.species);
+    let name = pokemon.display_name(dex);
     if side.stealth_rock {
         let types = effective_types(pokemon);
-        let effectiveness = effectiveness_dual(Type::Rock, types[0], types[1]);
+        let effectiveness = type_chart.effectiveness_dual(Type::Rock, types[0], types[1]);
         if effectiveness > 0.0 {
             let ratio = effectiveness / 8.0;
             let dmg = ((pokemon.stats.hp as f32) * ratio).floor().max(1.0) as u16;
             pokemon.take_damage(dmg);
-            println!(
-                "  {}はステルスロックのダメージをうけた！ (HP: {}/{})",
-                name, pokemon.current_hp, pokemon.stats.hp
+            narrate!(
+                "stealth_rock_damage",
+                name = name,
+                hp = pokemon.current_hp,
+                max_hp = pokemon.stats.hp,
             );
         }
     }
@@ -777,27 +848,24 @@ fn apply_entry_hazards(
         };
         let dmg = ((pokemon.stats.hp as f32) * ratio).floor().max(1.0) as u16;
         pokemon.take_damage(dmg);
-        println!(
-            "  {}はまきびしのダメージをうけた！ (HP: {}/{})",
-            name, pokemon.current_hp, pokemon.stats.hp
-        );
+        narrate!("spikes_damage", name = name, hp = pokemon.current_hp, max_hp = pokemon.stats.hp);
     }
     if side.toxic_spikes > 0 && is_grounded(pokemon) {
         let poison_type = pokemon.types[0] == Type::Poison || pokemon.types[1] == Type::Poison;
         if poison_type {
             side.toxic_spikes = 0;
-            println!("  どくびしがきれいに かたづけられた！");
+            narrate!("toxic_spikes_absorbed");
         } else {
             let toxic = side.toxic_spikes >= 2;
             let status = if toxic { Status::Poison } else { Status::Poison };
             if apply_status_with_field(pokemon, status, toxic, field, rng) {
-                println!("  {}は{}！", name, format_status(status));
+                narrate!(status_key(status), name = name);
             }
         }
     }
     if side.sticky_web && is_grounded(pokemon) {
         if !apply_stage_change(pokemon, &name, STAGE_SPE, -1) {
-            println!("  しかし こうかがなかった！");
+            narrate!("no_effect");
         }
     }
 }
@@ -807,7 +875,7 @@ fn perform_switch(
     side_idx: usize,
     bench_idx: usize,
     kind: SwitchKind,
-    rng: &mut SmallRng,
+    rng: &mut dyn Chance,
 ) -> bool {
     let do_log = state.logger.is_some();
     let mut pending_switch_log: Option<(String, String, u16, u16)> = None;
@@ -818,31 +886,31 @@ fn perform_switch(
             (&mut state.pokemon_b, &mut state.bench_b, &mut state.side_b)
         };
         if !switching::can_switch(active, kind) {
-            println!("  しかし こうかがなかった！");
+            narrate!("no_effect");
             return false;
         }
         if bench_idx >= bench.len() || bench[bench_idx].is_fainted() {
             return false;
         }
-        let outgoing_name = translate_pokemon(&active.species);
+        let outgoing_name = active.display_name(&state.dex);
         reset_on_switch(active);
         std::mem::swap(active, &mut bench[bench_idx]);
-        let incoming_name = translate_pokemon(&active.species);
-        println!("  {}は {}に交代した！", outgoing_name, incoming_name);
+        let incoming_name = active.display_name(&state.dex);
+        narrate!("switch", old = outgoing_name, new = incoming_name);
         if do_log {
             pending_switch_log = Some((
-                showdown_ident(side_idx, &active.species),
-                active.species.clone(),
+                showdown_ident(side_idx, &active.name),
+                active.showdown_details(),
                 active.current_hp,
                 active.stats.hp,
             ));
         }
-        apply_entry_hazards(active, side, state.field, rng);
+        apply_entry_hazards(&state.dex, active, side, state.field, state.type_chart, rng);
         if side.healing_wish_pending && !active.is_fainted() {
             side.healing_wish_pending = false;
             active.current_hp = active.stats.hp;
             active.clear_status();
-            println!("  {}は いやしのねがいで かいふくした！", incoming_name);
+            narrate!("healing_wish_heal", name = incoming_name);
         }
     }
     if do_log {
@@ -863,10 +931,10 @@ fn side_has_available(active: &Pokemon, bench: &[Pokemon]) -> bool {
     bench.iter().any(|pokemon| !pokemon.is_fainted())
 }
 
-fn apply_env_update(state: &mut BattleState, update: EnvUpdate, rng: &mut SmallRng) {
+fn apply_env_update(state: &mut BattleState, update: EnvUpdate, rng: &mut dyn Chance) {
     if update.court_change {
         std::mem::swap(&mut state.side_a, &mut state.side_b);
-        println!("  コートチェンジ！");
+        narrate!("court_change");
     }
     if let Some(weather) = update.weather {
         state.weather = Some(weather);
@@ -884,34 +952,34 @@ fn apply_env_update(state: &mut BattleState, update: EnvUpdate, rng: &mut SmallR
         match hazard.kind {
             HazardKind::StealthRock => {
                 if side.stealth_rock {
-                    println!("  しかし こうかがなかった！");
+                    narrate!("no_effect");
                 } else {
                     side.stealth_rock = true;
-                    println!("  ステルスロックが しかけられた！");
+                    narrate!("stealth_rock_set");
                 }
             }
             HazardKind::Spikes => {
                 if side.spikes >= 3 {
-                    println!("  しかし こうかがなかった！");
+                    narrate!("no_effect");
                 } else {
                     side.spikes = (side.spikes + 1).min(3);
-                    println!("  まきびしが しかけられた！");
+                    narrate!("spikes_set");
                 }
             }
             HazardKind::ToxicSpikes => {
                 if side.toxic_spikes >= 2 {
-                    println!("  しかし こうかがなかった！");
+                    narrate!("no_effect");
                 } else {
                     side.toxic_spikes = (side.toxic_spikes + 1).min(2);
-                    println!("  どくびしが しかけられた！");
+                    narrate!("toxic_spikes_set");
                 }
             }
             HazardKind::StickyWeb => {
                 if side.sticky_web {
-                    println!("  しかし こうかがなかった！");
+                    narrate!("no_effect");
                 } else {
                     side.sticky_web = true;
-                    println!("  ねばねばネットが しかけられた！");
+                    narrate!("sticky_web_set");
                 }
             }
         }
@@ -920,30 +988,30 @@ fn apply_env_update(state: &mut BattleState, update: EnvUpdate, rng: &mut SmallR
         let side = side_conditions_mut(state, wish.target);
         side.wish_turns = 2;
         side.wish_heal = wish.heal.max(1);
-        println!("  ねがいごとが となえられた！");
+        narrate!("wish_set");
     }
     if let Some(target) = update.healing_wish {
         let side = side_conditions_mut(state, target);
         side.healing_wish_pending = true;
-        println!("  いやしのねがいが こめられた！");
+        narrate!("healing_wish_set");
     }
     if let Some(screen) = update.screen {
         let side = side_conditions_mut(state, screen.target);
         match screen.kind {
             FieldEffect::Reflect => {
                 if side.reflect_turns > 0 {
-                    println!("  しかし こうかがなかった！");
+                    narrate!("no_effect");
                 } else {
                     side.reflect_turns = screen.turns;
-                    println!("  リフレクターが はられた！");
+                    narrate!("reflect_start");
                 }
             }
             FieldEffect::LightScreen => {
                 if side.light_screen_turns > 0 {
-                    println!("  しかし こうかがなかった！");
+                    narrate!("no_effect");
                 } else {
                     side.light_screen_turns = screen.turns;
-                    println!("  ひかりのかべが はられた！");
+                    narrate!("light_screen_start");
                 }
             }
         }
@@ -953,42 +1021,42 @@ fn apply_env_update(state: &mut BattleState, update: EnvUpdate, rng: &mut SmallR
         match side_update.kind {
             SideConditionKind::Mist => {
                 if side.mist_turns > 0 {
-                    println!("  しかし こうかがなかった！");
+                    narrate!("no_effect");
                 } else {
                     side.mist_turns = side_update.turns.max(1);
-                    println!("  しろいきりが かかった！");
+                    narrate!("mist_start");
                 }
             }
             SideConditionKind::Safeguard => {
                 if side.safeguard_turns > 0 {
-                    println!("  しかし こうかがなかった！");
+                    narrate!("no_effect");
                 } else {
                     side.safeguard_turns = side_update.turns.max(1);
-                    println!("  しんぴのベールに つつまれた！");
+                    narrate!("safeguard_start");
                 }
             }
             SideConditionKind::Tailwind => {
                 if side.tailwind_turns > 0 {
-                    println!("  しかし こうかがなかった！");
+                    narrate!("no_effect");
                 } else {
                     side.tailwind_turns = side_update.turns.max(1);
-                    println!("  おいかぜが ふきはじめた！");
+                    narrate!("tailwind_start");
                 }
             }
             SideConditionKind::LuckyChant => {
                 if side.lucky_chant_turns > 0 {
-                    println!("  しかし こうかがなかった！");
+                    narrate!("no_effect");
                 } else {
                     side.lucky_chant_turns = side_update.turns.max(1);
-                    println!("  おまじないを となえた！");
+                    narrate!("lucky_chant_start");
                 }
             }
             SideConditionKind::AuroraVeil => {
                 if side.aurora_veil_turns > 0 {
-                    println!("  しかし こうかがなかった！");
+                    narrate!("no_effect");
                 } else {
                     side.aurora_veil_turns = side_update.turns.max(1);
-                    println!("  オーロラベールが はられた！");
+                    narrate!("aurora_veil_start");
                 }
             }
         }
@@ -1001,12 +1069,12 @@ fn apply_env_update(state: &mut BattleState, update: EnvUpdate, rng: &mut SmallR
                 side.spikes = 0;
                 side.toxic_spikes = 0;
                 side.sticky_web = false;
-                println!("  しかけられていた わざが とりのぞかれた！");
+                narrate!("hazards_cleared");
             }
             HazardClear::Both => {
                 clear_hazards(&mut state.side_a);
                 clear_hazards(&mut state.side_b);
-                println!("  しかけられていた わざが ぜんぶ とりのぞかれた！");
+                narrate!("hazards_cleared_all");
             }
         }
     }
@@ -1018,14 +1086,14 @@ fn apply_env_update(state: &mut BattleState, update: EnvUpdate, rng: &mut SmallR
         state.side_b.light_screen_turns = 0;
         state.side_b.aurora_veil_turns = 0;
         state.field_effects.clear();
-        println!("  バリアが かきけされた！");
+        narrate!("screens_cleared");
     }
     if let Some(target_side) = update.force_switch {
         let bench = bench_mut(state, target_side);
         if let Some(idx) = switching::pick_random_switch(bench, rng) {
             perform_switch(state, target_side, idx, SwitchKind::Forced, rng);
         } else {
-            println!("  しかし こうかがなかった！");
+            narrate!("no_effect");
         }
     }
 }
@@ -1054,35 +1122,40 @@ fn targets_opponent_pokemon(target: &str) -> bool {
     )
 }
 
+#[allow(clippy::collapsible_if)]
 fn apply_contact_abilities(
+    dex: &Dex,
     attacker: &mut Pokemon,
     defender: &mut Pokemon,
     move_data: &crate::data::moves::MoveData,
     field: Option<Field>,
-    rng: &mut SmallRng,
+    rng: &mut dyn Chance,
 ) {
     if !is_contact_move(move_data) {
         return;
     }
-    let attacker_ja = translate_pokemon(&attacker.species);
+    let attacker_ja = attacker.display_name(dex);
     if defender.has_ability("Poison Point") {
-        if rng.gen_bool(0.3) {
+        if rng.chance(0.3) {
             if apply_status_with_field(attacker, Status::Poison, false, field, rng) {
-                println!("  {}は{}！", attacker_ja, format_status(Status::Poison));
+                narrate!(status_key(Status::Poison), name = attacker_ja);
             }
         }
     }
-    apply_contact_damage_abilities(attacker, defender);
-    apply_effect_spore(attacker, defender, field, rng);
+    apply_contact_damage_abilities(dex, attacker, defender);
+    apply_effect_spore(dex, attacker, defender, field, rng);
     if has_item(defender, "rockyhelmet") {
         let dmg = (attacker.stats.hp as u32 / 6).max(1) as u16;
         attacker.take_damage(dmg);
-        println!(
-            "  {}は{}のダメージをうけた！ (HP: {}/{})",
-            attacker_ja, dmg, attacker.current_hp, attacker.stats.hp
+        narrate!(
+            "damage",
+            name = attacker_ja,
+            damage = dmg,
+            hp = attacker.current_hp,
+            max_hp = attacker.stats.hp,
         );
         if attacker.is_fainted() {
-            println!("  {}はたおれた！", attacker_ja);
+            narrate!("fainted", name = attacker_ja);
         }
     }
 }
@@ -1151,7 +1224,7 @@ fn resolve_action(
     action: Action,
     defender_action: Action,
     defender_idx: usize,
-    rng: &mut SmallRng,
+    rng: &mut dyn Chance,
 ) {
     match action {
         Action::Move(idx) => {
@@ -1167,7 +1240,7 @@ fn passes_accuracy(
     accuracy: Option<f32>,
     attacker: &Pokemon,
     defender: &Pokemon,
-    rng: &mut SmallRng,
+    rng: &mut dyn Chance,
 ) -> bool {
     if attacker.has_ability("No Guard") || defender.has_ability("No Guard") {
         return true;
@@ -1182,7 +1255,7 @@ fn passes_accuracy(
                 final_acc *= 1.3;
             }
             let final_acc = final_acc.clamp(0.0, 100.0);
-            rng.gen_range(0.0..100.0) < final_acc
+            rng.percent(final_acc as f64)
         }
     }
 }
@@ -1207,7 +1280,7 @@ fn is_ohko_move(normalized_move: &str) -> bool {
 fn move_hit_count(
     move_data: &crate::data::moves::MoveData,
     normalized_move: &str,
-    rng: &mut SmallRng,
+    rng: &mut dyn Chance,
 ) -> u8 {
     let _ = normalized_move;
     calculate_multihit_count(move_data, rng)
@@ -1221,17 +1294,17 @@ fn critical_stage(move_data: &crate::data::moves::MoveData) -> u8 {
         .min(3)
 }
 
-fn roll_critical(stage: u8, rng: &mut SmallRng) -> bool {
+fn roll_critical(stage: u8, rng: &mut dyn Chance) -> bool {
     let chance = match stage {
         0 => 1.0 / 24.0,
         1 => 1.0 / 8.0,
         2 => 0.5,
         _ => 1.0,
     };
-    rng.gen_bool(chance)
+    rng.critical(chance)
 }
 
-fn fixed_damage(normalized_move: &str, attacker: &Pokemon, defender: &Pokemon) -> Option<u16> {
+pub(crate) fn fixed_damage(normalized_move: &str, attacker: &Pokemon, defender: &Pokemon) -> Option<u16> {
     match normalized_move {
         "seismictoss" | "nightshade" => Some(attacker.level as u16),
         "endeavor" => {
@@ -1251,7 +1324,7 @@ pub(crate) fn apply_status_with_field(
     status: Status,
     toxic: bool,
     field: Option<Field>,
-    rng: &mut SmallRng,
+    rng: &mut dyn Chance,
 ) -> bool {
     if is_grounded(target) {
         match field {
@@ -1281,7 +1354,7 @@ pub(crate) fn screen_turns(attacker: &Pokemon) -> u8 {
     }
 }
 
-fn screen_damage_modifier(
+pub(crate) fn screen_damage_modifier(
     reflect_turns: u8,
     light_screen_turns: u8,
     aurora_veil_turns: u8,
@@ -1313,7 +1386,7 @@ fn screen_damage_modifier(
     }
 }
 
-fn burn_damage_modifier(attacker: &Pokemon, category: MoveCategory, move_id: &str) -> f32 {
+pub(crate) fn burn_damage_modifier(attacker: &Pokemon, category: MoveCategory, move_id: &str) -> f32 {
     if matches!(category, MoveCategory::Physical)
         && matches!(attacker.status, Some(Status::Burn))
         && !attacker.has_ability("Guts")
@@ -1346,27 +1419,27 @@ fn map_status(id: &str) -> Option<(Status, bool)> {
     }
 }
 
-fn can_act(pokemon: &mut Pokemon, rng: &mut SmallRng) -> bool {
+fn can_act(dex: &Dex, pokemon: &mut Pokemon, rng: &mut dyn Chance) -> bool {
     if pokemon.flinched {
         pokemon.flinched = false;
         return false;
     }
     if pokemon.confusion_turns > 0 {
         pokemon.confusion_turns = pokemon.confusion_turns.saturating_sub(1);
-        if rng.gen_bool(1.0 / 3.0) {
+        if rng.chance(1.0 / 3.0) {
             let atk = apply_stage_multiplier(pokemon.stats.atk, pokemon.stat_stages[STAGE_ATK]);
             let def = apply_stage_multiplier(pokemon.stats.def, pokemon.stat_stages[STAGE_DEF]);
-            let random_factor = rng.gen_range(85..=100) as f32 / 100.0;
+            let random_factor = rng.damage_roll() as f32 / 100.0;
             let dmg = calculate_damage(pokemon.level, atk, def, 40, 1.0, false, random_factor, 1.0);
             pokemon.take_damage(dmg);
-            println!(
-                "  {}はこんらんしてじぶんを こうげきした！ (HP: {}/{})",
-                translate_pokemon(&pokemon.species),
-                pokemon.current_hp,
-                pokemon.stats.hp
+            narrate!(
+                "confusion_hit",
+                name = pokemon.display_name(dex),
+                hp = pokemon.current_hp,
+                max_hp = pokemon.stats.hp,
             );
             if pokemon.is_fainted() {
-                println!("  {}はたおれた！", translate_pokemon(&pokemon.species));
+                narrate!("fainted", name = pokemon.display_name(dex));
             }
             return false;
         }
@@ -1380,19 +1453,21 @@ fn can_act(pokemon: &mut Pokemon, rng: &mut SmallRng) -> bool {
             false
         }
         Some(Status::Freeze) => {
-            if rng.gen_bool(0.2) {
+            if rng.chance(0.2) {
                 pokemon.clear_status();
                 true
             } else {
                 false
             }
         }
-        Some(Status::Paralysis) => !rng.gen_bool(0.25),
+        Some(Status::Paralysis) => !rng.chance(0.25),
         _ => true,
     }
 }
 
-fn apply_start_of_turn_effects(state: &mut BattleState, rng: &mut SmallRng) {
+#[allow(clippy::collapsible_if)]
+fn apply_start_of_turn_effects(state: &mut BattleState, rng: &mut dyn Chance) {
+    let dex = state.dex.clone();
     let field = state.field;
     for pokemon in [&mut state.pokemon_a, &mut state.pokemon_b] {
         if pokemon.is_fainted() {
@@ -1404,18 +1479,15 @@ fn apply_start_of_turn_effects(state: &mut BattleState, rng: &mut SmallRng) {
         }
         if has_item(pokemon, "flameorb") && pokemon.status.is_none() {
             if apply_status_with_field(pokemon, Status::Burn, false, field, rng) {
-                println!(
-                    "  {}は{}のこうかで {}！",
-                    translate_pokemon(&pokemon.species),
-                    translate_item("Flame Orb"),
-                    format_status(Status::Burn)
-                );
+                narrate!("item_burn", name = pokemon.display_name(&dex), item = messages::item_name(&dex, "Flame Orb"));
             }
         }
     }
 }
 
-pub(crate) fn apply_end_of_turn_effects(state: &mut BattleState, rng: &mut SmallRng) {
+#[allow(clippy::collapsible_match)]
+pub(crate) fn apply_end_of_turn_effects(state: &mut BattleState, rng: &mut dyn Chance) {
+    let dex = state.dex.clone();
     let weather = state.weather;
     let field = state.field;
     for pokemon in [&mut state.pokemon_a, &mut state.pokemon_b] {
@@ -1426,21 +1498,21 @@ pub(crate) fn apply_end_of_turn_effects(state: &mut BattleState, rng: &mut Small
             Some(Status::Burn) => {
                 let dmg = (pokemon.stats.hp as u32 / 16).max(1) as u16;
                 pokemon.take_damage(dmg);
-                println!(
-                    "  {}はやけどでダメージをうけた！ (HP: {}/{})",
-                    translate_pokemon(&pokemon.species),
-                    pokemon.current_hp,
-                    pokemon.stats.hp
+                narrate!(
+                    "burn_damage",
+                    name = pokemon.display_name(&dex),
+                    hp = pokemon.current_hp,
+                    max_hp = pokemon.stats.hp,
                 );
             }
             Some(Status::Poison) => {
                 if let Some(heal) = poison_heal_amount(pokemon) {
                     pokemon.current_hp = (pokemon.current_hp + heal).min(pokemon.stats.hp);
-                    println!(
-                        "  {}はポイズンヒールで たいりょくをかいふくした！ (HP: {}/{})",
-                        translate_pokemon(&pokemon.species),
-                        pokemon.current_hp,
-                        pokemon.stats.hp
+                    narrate!(
+                        "poison_heal",
+                        name = pokemon.display_name(&dex),
+                        hp = pokemon.current_hp,
+                        max_hp = pokemon.stats.hp,
                     );
                 } else {
                     let dmg = if pokemon.toxic_counter > 0 {
@@ -1451,11 +1523,11 @@ pub(crate) fn apply_end_of_turn_effects(state: &mut BattleState, rng: &mut Small
                         (pokemon.stats.hp as u32 / 8).max(1) as u16
                     };
                     pokemon.take_damage(dmg);
-                    println!(
-                        "  {}はどくでダメージをうけた！ (HP: {}/{})",
-                        translate_pokemon(&pokemon.species),
-                        pokemon.current_hp,
-                        pokemon.stats.hp
+                    narrate!(
+                        "poison_damage",
+                        name = pokemon.display_name(&dex),
+                        hp = pokemon.current_hp,
+                        max_hp = pokemon.stats.hp,
                     );
                 }
             }
@@ -1473,39 +1545,27 @@ pub(crate) fn apply_end_of_turn_effects(state: &mut BattleState, rng: &mut Small
         if pokemon.taunt_turns > 0 {
             pokemon.taunt_turns = pokemon.taunt_turns.saturating_sub(1);
             if pokemon.taunt_turns == 0 {
-                println!(
-                    "  {}のちょうはつが とけた！",
-                    translate_pokemon(&pokemon.species)
-                );
+                narrate!("taunt_end", name = pokemon.display_name(&dex));
             }
         }
         if pokemon.encore_turns > 0 {
             pokemon.encore_turns = pokemon.encore_turns.saturating_sub(1);
             if pokemon.encore_turns == 0 {
                 pokemon.encore_move = None;
-                println!(
-                    "  {}のアンコールが とけた！",
-                    translate_pokemon(&pokemon.species)
-                );
+                narrate!("encore_end", name = pokemon.display_name(&dex));
             }
         }
         if pokemon.telekinesis_turns > 0 {
             pokemon.telekinesis_turns = pokemon.telekinesis_turns.saturating_sub(1);
             if pokemon.telekinesis_turns == 0 {
-                println!(
-                    "  {}は もとにもどった！",
-                    translate_pokemon(&pokemon.species)
-                );
+                narrate!("telekinesis_end", name = pokemon.display_name(&dex));
             }
         }
         if pokemon.perish_count > 0 {
             pokemon.perish_count = pokemon.perish_count.saturating_sub(1);
             if pokemon.perish_count == 0 && !pokemon.is_fainted() {
                 pokemon.current_hp = 0;
-                println!(
-                    "  {}はほろびのうたで たおれた！",
-                    translate_pokemon(&pokemon.species)
-                );
+                narrate!("perish_faint", name = pokemon.display_name(&dex));
                 continue;
             }
         }
@@ -1514,33 +1574,33 @@ pub(crate) fn apply_end_of_turn_effects(state: &mut BattleState, rng: &mut Small
                 battle_items::EndOfTurnEffect::Heal { amount, item_id } => {
                     pokemon.current_hp = (pokemon.current_hp + amount).min(pokemon.stats.hp);
                     let item_name = match item_id {
-                        "leftovers" => translate_item("Leftovers"),
-                        "blacksludge" => translate_item("Black Sludge"),
+                        "leftovers" => messages::item_name(&dex, "Leftovers"),
+                        "blacksludge" => messages::item_name(&dex, "Black Sludge"),
                         _ => item_id.to_string(),
                     };
-                    println!(
-                        "  {}は{}で たいりょくをかいふくした！ (HP: {}/{})",
-                        translate_pokemon(&pokemon.species),
-                        item_name,
-                        pokemon.current_hp,
-                        pokemon.stats.hp
+                    narrate!(
+                        "item_heal",
+                        name = pokemon.display_name(&dex),
+                        item = item_name,
+                        hp = pokemon.current_hp,
+                        max_hp = pokemon.stats.hp,
                     );
                 }
                 battle_items::EndOfTurnEffect::Damage { amount, item_id } => {
                     pokemon.take_damage(amount);
                     let item_name = match item_id {
-                        "blacksludge" => translate_item("Black Sludge"),
+                        "blacksludge" => messages::item_name(&dex, "Black Sludge"),
                         _ => item_id.to_string(),
                     };
-                    println!(
-                        "  {}は{}で ダメージをうけた！ (HP: {}/{})",
-                        translate_pokemon(&pokemon.species),
-                        item_name,
-                        pokemon.current_hp,
-                        pokemon.stats.hp
+                    narrate!(
+                        "item_hurt",
+                        name = pokemon.display_name(&dex),
+                        item = item_name,
+                        hp = pokemon.current_hp,
+                        max_hp = pokemon.stats.hp,
                     );
                     if pokemon.is_fainted() {
-                        println!("  {}はたおれた！", translate_pokemon(&pokemon.species));
+                        narrate!("fainted", name = pokemon.display_name(&dex));
                     }
                 }
             }
@@ -1550,38 +1610,38 @@ pub(crate) fn apply_end_of_turn_effects(state: &mut BattleState, rng: &mut Small
                 let heal = (pokemon.stats.hp as u32 / 16).max(1) as u16;
                 if pokemon.current_hp < pokemon.stats.hp {
                     pokemon.current_hp = (pokemon.current_hp + heal).min(pokemon.stats.hp);
-                    println!(
-                        "  {}はグラスフィールドでかいふくした！ (HP: {}/{})",
-                        translate_pokemon(&pokemon.species),
-                        pokemon.current_hp,
-                        pokemon.stats.hp
+                    narrate!(
+                        "grassy_terrain_heal",
+                        name = pokemon.display_name(&dex),
+                        hp = pokemon.current_hp,
+                        max_hp = pokemon.stats.hp,
                     );
                 }
             }
         }
         if let Some((dmg, kind)) = crate::sim::weather_field::weather_residual_damage(pokemon, weather) {
             pokemon.take_damage(dmg);
-            let msg = match kind {
-                Weather::Sand => "すなあらし",
-                Weather::Hail => "あられ",
-                _ => "てんこう",
+            let weather_key = match kind {
+                Weather::Sand => "weather_sandstorm",
+                Weather::Hail => "weather_hail",
+                _ => "weather_other",
             };
-            println!(
-                "  {}は{}でダメージをうけた！ (HP: {}/{})",
-                translate_pokemon(&pokemon.species),
-                msg,
-                pokemon.current_hp,
-                pokemon.stats.hp
+            narrate!(
+                "weather_damage",
+                name = pokemon.display_name(&dex),
+                weather = render(weather_key, &[]),
+                hp = pokemon.current_hp,
+                max_hp = pokemon.stats.hp,
             );
             if pokemon.is_fainted() {
-                println!("  {}はたおれた！", translate_pokemon(&pokemon.species));
+                narrate!("fainted", name = pokemon.display_name(&dex));
                 continue;
             }
         }
         let _ = rng;
     }
-    apply_wish(&mut state.side_a, &mut state.pokemon_a);
-    apply_wish(&mut state.side_b, &mut state.pokemon_b);
+    apply_wish(&state.dex, &mut state.side_a, &mut state.pokemon_a);
+    apply_wish(&state.dex, &mut state.side_b, &mut state.pokemon_b);
     if state.field_turns > 0 {
         state.field_turns = state.field_turns.saturating_sub(1);
         if state.field_turns == 0 {
@@ -1597,14 +1657,14 @@ pub(crate) fn apply_end_of_turn_effects(state: &mut BattleState, rng: &mut Small
     if state.trick_room_turns > 0 {
         state.trick_room_turns = state.trick_room_turns.saturating_sub(1);
         if state.trick_room_turns == 0 {
-            println!("  トリックルームが もとにもどった！");
+            narrate!("trick_room_end");
         }
     }
     crate::sim::moves::decrement_side_conditions(&mut state.side_a);
     crate::sim::moves::decrement_side_conditions(&mut state.side_b);
 }
 
-fn apply_wish(side: &mut SideConditions, pokemon: &mut Pokemon) {
+fn apply_wish(dex: &Dex, side: &mut SideConditions, pokemon: &mut Pokemon) {
     if side.wish_turns == 0 {
         return;
     }
@@ -1613,25 +1673,28 @@ fn apply_wish(side: &mut SideConditions, pokemon: &mut Pokemon) {
         let heal = side.wish_heal.max(1);
         if pokemon.current_hp < pokemon.stats.hp {
             pokemon.current_hp = (pokemon.current_hp + heal).min(pokemon.stats.hp);
-            println!(
-                "  {}はねがいごとで たいりょくをかいふくした！ (HP: {}/{})",
-                translate_pokemon(&pokemon.species),
-                pokemon.current_hp,
-                pokemon.stats.hp
+            narrate!(
+                "wish_heal",
+                name = pokemon.display_name(dex),
+                hp = pokemon.current_hp,
+                max_hp = pokemon.stats.hp,
             );
         }
         side.wish_heal = 0;
     }
 }
 
+#[allow(clippy::needless_borrow)]
 pub(crate) fn execute_move_impl(
     state: &mut BattleState,
     attacker_idx: usize,
     move_idx: usize,
     defender_action: Action,
     defender_idx: usize,
-    rng: &mut SmallRng,
+    rng: &mut dyn Chance,
 ) {
+    let dex = state.dex.clone();
+    let type_chart = state.type_chart;
     let weather = state.weather;
     let field = state.field;
     let trick_room_turns = state.trick_room_turns;
@@ -1698,7 +1761,7 @@ pub(crate) fn execute_move_impl(
             Some(name) => name,
             None => return,
         };
-        let mut move_data = match get_move(move_name.as_str()) {
+        let mut move_data = match dex.get_move(move_name.as_str()) {
             Some(data) => data,
             None => {
                 eprintln!("Warning: Move '{}' not found", move_name);
@@ -1708,13 +1771,13 @@ pub(crate) fn execute_move_impl(
         let mut normalized = crate::data::moves::normalize_move_name(move_data.name);
         if let Some(charging) = attacker.charging_move.clone() {
             if charging != normalized {
-                if let Some(data) = get_move(charging.as_str()) {
+                if let Some(data) = dex.get_move(charging.as_str()) {
                     move_data = data;
                     normalized = charging;
                 }
             }
         }
-        let attacker_ja = translate_pokemon(&attacker.species);
+        let attacker_ja = attacker.display_name(&dex);
         let bypass_substitute = bypasses_substitute(&move_data);
         let bypass_protect = bypasses_protect(&move_data);
         let targets_opponent = targets_opponent_pokemon(move_data.target);
@@ -1727,26 +1790,26 @@ pub(crate) fn execute_move_impl(
         }
         let is_second_turn = attacker.charging_move.as_deref() == Some(normalized.as_str());
         if matches!(move_data.category, MoveCategory::Status) && attacker.taunt_turns > 0 {
-            println!("  {}はちょうはつされて へんかわざがだせない！", attacker_ja);
+            narrate!("taunted", name = attacker_ja);
             return;
         }
-        if !can_act(attacker, rng) {
+        if !can_act(&dex, attacker, rng) {
             if matches!(normalized.as_str(), "protect" | "kingsshield" | "detect" | "endure") {
                 attacker.protect_counter = 0;
             }
             if attacker.charging_move.is_some() {
                 attacker.charging_move = None;
             }
-            println!("  {}はうまくうごけなかった！", translate_pokemon(&attacker.species));
+            narrate!("cant_move", name = attacker.display_name(&dex));
             return;
         }
-        let move_ja = translate_move(move_data.name);
-        println!("  {}は{}をつかった！", attacker_ja, move_ja);
+        let move_ja = messages::move_name(&dex, move_data.name);
+        narrate!("use_move", name = attacker_ja, move_name = move_ja);
         if do_log {
             pending_move_log = Some((
-                showdown_ident(attacker_idx, &attacker.species),
+                showdown_ident(attacker_idx, &attacker.name),
                 move_data.name.to_string(),
-                showdown_ident(defender_idx, &defender.species),
+                showdown_ident(defender_idx, &defender.name),
             ));
         }
         battle_items::set_choice_lock_move(attacker, normalized.as_str());
@@ -1755,21 +1818,21 @@ pub(crate) fn execute_move_impl(
             if is_second_turn {
                 attacker.charging_move = None;
             }
-            println!("  しかし こうかがなかった！");
+            narrate!("no_effect");
             return;
         }
-        if normalized == "suckerpunch" && !is_attack_action(defender_action, defender) {
+        if normalized == "suckerpunch" && !is_attack_action(&dex, defender_action, defender) {
             if is_second_turn {
                 attacker.charging_move = None;
             }
-            println!("  しかし うまくきまらなかった！");
+            narrate!("move_failed");
             return;
         }
         if defender.semi_invulnerable && !matches!(move_data.category, MoveCategory::Status) {
             if is_second_turn {
                 attacker.charging_move = None;
             }
-            println!("  しかし あたらなかった！");
+            narrate!("missed");
             return;
         }
         if is_charging_move(normalized.as_str()) && !is_second_turn {
@@ -1780,15 +1843,11 @@ pub(crate) fn execute_move_impl(
             if has_consumable_item(attacker, "powerherb") {
                 consume_item(attacker);
                 skip_charge = true;
-                println!(
-                    "  {}の{}が こうかをあらわした！",
-                    attacker_ja,
-                    translate_item("Power Herb")
-                );
+                narrate!("power_herb", name = attacker_ja, item = messages::item_name(&dex, "Power Herb"));
             }
             if !skip_charge {
-                handle_charging_move(attacker, normalized.as_str());
-                println!("  {}はちからをためている！", attacker_ja);
+                handle_charging_move(attacker, move_data);
+                narrate!("charging", name = attacker_ja);
                 return;
             }
         }
@@ -1800,7 +1859,7 @@ pub(crate) fn execute_move_impl(
                 if is_second_turn {
                     attacker.charging_move = None;
                 }
-                println!("  しかし あたらなかった！");
+                narrate!("missed");
                 return;
             }
         } else {
@@ -1809,7 +1868,7 @@ pub(crate) fn execute_move_impl(
                 if is_second_turn {
                     attacker.charging_move = None;
                 }
-                println!("  しかし あたらなかった！");
+                narrate!("missed");
                 return;
             }
         }
@@ -1818,13 +1877,14 @@ pub(crate) fn execute_move_impl(
                 if is_second_turn {
                     attacker.charging_move = None;
                 }
-                println!("  しかし みがわりが まもっている！");
+                narrate!("substitute_blocked");
                 return;
             }
             if targets_opponent && defender.magic_coat_active {
-                let defender_ja = translate_pokemon(&defender.species);
-                println!("  {}は マジックコートで はねかえした！", defender_ja);
+                let defender_ja = defender.display_name(&dex);
+                narrate!("magic_coat", name = defender_ja);
                 env_update = handle_status_move(
+                    &dex,
                     defender,
                     attacker,
                     &move_data,
@@ -1836,6 +1896,7 @@ pub(crate) fn execute_move_impl(
                 );
             } else {
                 env_update = handle_status_move(
+                    &dex,
                     attacker,
                     defender,
                     &move_data,
@@ -1855,36 +1916,36 @@ pub(crate) fn execute_move_impl(
                 if is_second_turn {
                     attacker.charging_move = None;
                 }
-                println!("  サイコフィールドのちからで うまくきまらなかった！");
+                narrate!("psychic_terrain_blocked");
                 return;
             }
             if defender.protect_active && !bypass_protect {
                 if defender.kings_shield_active && is_contact_move(&move_data) {
-                    let attacker_ja = translate_pokemon(&attacker.species);
+                    let attacker_ja = attacker.display_name(&dex);
                     if !apply_stage_change(attacker, &attacker_ja, STAGE_ATK, -2) {
-                        println!("  しかし こうかがなかった！");
+                        narrate!("no_effect");
                     }
                 }
                 if is_second_turn {
                     attacker.charging_move = None;
                 }
-                println!("  しかし まもられた！");
+                narrate!("protected");
                 return;
             }
             let move_type = parse_type(move_data.move_type);
             apply_libero(attacker, move_type);
-            let defender_ja = translate_pokemon(&defender.species);
+            let defender_ja = defender.display_name(&dex);
             if defender.substitute_hp == 0 || bypass_substitute {
                 if let Some(absorb) = try_absorb_water_move(defender, move_type) {
                     if is_second_turn {
                         attacker.charging_move = None;
                     }
-                    println!(
-                        "  {}は{}で たいりょくをかいふくした！ (HP: {}/{})",
-                        defender_ja,
-                        absorb.kind.display_name(),
-                        defender.current_hp,
-                        defender.stats.hp
+                    narrate!(
+                        "absorb_heal",
+                        name = defender_ja,
+                        ability = messages::ability_name(&dex, absorb.kind.ability()),
+                        hp = defender.current_hp,
+                        max_hp = defender.stats.hp,
                     );
                     return;
                 }
@@ -1913,11 +1974,11 @@ pub(crate) fn execute_move_impl(
             }
             if matches!(defender.status, Some(Status::Freeze)) && move_type == Type::Fire {
                 defender.clear_status();
-                println!("  {}のこおりがとけた！", translate_pokemon(&defender.species));
+                narrate!("thawed", name = defender.display_name(&dex));
             }
             let defender_types = effective_types(defender);
             let type_effectiveness =
-                effectiveness_dual(move_type, defender_types[0], defender_types[1]);
+                type_chart.effectiveness_dual(move_type, defender_types[0], defender_types[1]);
             let ability_mod = ability_damage_modifier(attacker, move_type);
             let item_mod = item_damage_modifier(attacker, type_effectiveness);
             let weather_mod = weather_damage_modifier(weather, move_type);
@@ -1927,7 +1988,7 @@ pub(crate) fn execute_move_impl(
                 if is_second_turn {
                     attacker.charging_move = None;
                 }
-                println!("  しかし こうかがないようだ！");
+                narrate!("immune");
                 return;
             }
             let is_sandstorm = matches!(weather, Some(Weather::Sand));
@@ -1947,10 +2008,13 @@ pub(crate) fn execute_move_impl(
             let crit_stage = critical_stage(&move_data);
             let mut total_damage: u16 = 0;
             let mut damage_to_target: u16 = 0;
-            for _ in 0..hits {
+            for hit in 0..hits {
                 if attacker.is_fainted() || defender.is_fainted() {
                     break;
                 }
+                if hits > 1 {
+                    rng.start_hit(hit);
+                }
                 let is_crit = roll_critical(crit_stage, rng);
                 let attacker_stat = match move_data.category {
                     MoveCategory::Physical => {
@@ -2009,7 +2073,7 @@ pub(crate) fn execute_move_impl(
                     }
                     MoveCategory::Status => return,
                 };
-                let random_factor = rng.gen_range(85..=100) as f32 / 100.0;
+                let random_factor = rng.damage_roll() as f32 / 100.0;
                 let crit_mod = if is_crit { 1.5 } else { 1.0 };
                 let screen_mod = screen_damage_modifier(
                     defender_reflect_turns,
@@ -2041,7 +2105,7 @@ pub(crate) fn execute_move_impl(
                 };
                 if damage == 0 {
                     if fixed.is_some() {
-                        println!("  しかし こうかがないようだ！");
+                        narrate!("immune");
                         break;
                     }
                     continue;
@@ -2050,12 +2114,9 @@ pub(crate) fn execute_move_impl(
                     let sub_damage = damage.min(defender.substitute_hp);
                     defender.substitute_hp = defender.substitute_hp.saturating_sub(damage);
                     total_damage = total_damage.saturating_add(damage);
-                    println!(
-                        "  {}のみがわりは{}のダメージをうけた！",
-                        defender_ja, sub_damage
-                    );
+                    narrate!("substitute_damage", name = defender_ja, damage = sub_damage);
                     if defender.substitute_hp == 0 {
-                        println!("  {}のみがわりは こわれた！", defender_ja);
+                        narrate!("substitute_broke", name = defender_ja);
                     }
                     continue;
                 }
@@ -2065,35 +2126,35 @@ pub(crate) fn execute_move_impl(
                     type_effectiveness,
                 ) {
                     damage = (damage / 2).max(1);
-                    println!(
-                        "  {}のきのみが こうかをあらわした！",
-                        defender_ja
-                    );
+                    narrate!("resist_berry", name = defender_ja);
                 }
                 let (final_damage, prevention) = prevent_ko_if_applicable(defender, damage);
                 if let Some(prevention) = prevention {
                     match prevention {
-                        KoPrevention::Endure => println!("  {}はこらえている！", defender_ja),
-                        KoPrevention::Sturdy => println!("  {}はがんじょうで もちこたえた！", defender_ja),
-                        KoPrevention::FocusSash => println!("  {}はきあいのタスキで もちこたえた！", defender_ja),
+                        KoPrevention::Endure => narrate!("endure", name = defender_ja),
+                        KoPrevention::Sturdy => narrate!("sturdy", name = defender_ja),
+                        KoPrevention::FocusSash => narrate!("focus_sash", name = defender_ja),
                     }
                 }
                 defender.take_damage(final_damage);
                 total_damage = total_damage.saturating_add(final_damage);
                 damage_to_target = damage_to_target.saturating_add(final_damage);
-                println!(
-                    "  {}は{}のダメージをうけた！ (HP: {}/{})",
-                    defender_ja, final_damage, defender.current_hp, defender.stats.hp
+                narrate!(
+                    "damage",
+                    name = defender_ja,
+                    damage = final_damage,
+                    hp = defender.current_hp,
+                    max_hp = defender.stats.hp,
                 );
                 if do_log {
                     pending_damage_logs.push((
-                        showdown_ident(defender_idx, &defender.species),
+                        showdown_ident(defender_idx, &defender.name),
                         defender.current_hp,
                         defender.stats.hp,
                     ));
                 }
                 if is_crit {
-                    println!("  きゅうしょにあたった！");
+                    narrate!("critical_hit");
                 }
                 for effect in secondary_effects_from_move(normalized.as_str(), &move_data) {
                     let applied = apply_secondary_effect_with_update(
@@ -2115,25 +2176,28 @@ pub(crate) fn execute_move_impl(
                                     (&defender_ja, defender.status)
                                 };
                                 if target_status == Some(status) {
-                                    println!("  {}は{}！", target_name, format_status(status));
+                                    narrate!(status_key(status), name = target_name);
                                 }
                             }
                         }
                     }
                 }
-                apply_contact_abilities(attacker, defender, &move_data, field, rng);
+                apply_contact_abilities(&dex, attacker, defender, &move_data, field, rng);
                 if defender.is_fainted() {
-                    println!("  {}はたおれた！", defender_ja);
+                    narrate!("fainted", name = defender_ja);
                     if let Some(dmg) = apply_aftermath_if_applicable(attacker, defender, &move_data) {
-                        println!(
-                            "  {}はゆうばくで{}のダメージをうけた！ (HP: {}/{})",
-                            attacker_ja, dmg, attacker.current_hp, attacker.stats.hp
+                        narrate!(
+                            "aftermath_damage",
+                            name = attacker_ja,
+                            damage = dmg,
+                            hp = attacker.current_hp,
+                            max_hp = attacker.stats.hp,
                         );
                     }
                     if defender.destiny_bond && !attacker.is_fainted() {
                         defender.destiny_bond = false;
                         attacker.take_damage(attacker.current_hp);
-                        println!("  {}はみちづれになった！", attacker_ja);
+                        narrate!("destiny_bond", name = attacker_ja);
                     }
                     break;
                 }
@@ -2141,6 +2205,9 @@ pub(crate) fn execute_move_impl(
                     break;
                 }
             }
+            if hits > 1 {
+                rng.end_hits();
+            }
             if is_second_turn {
                 attacker.charging_move = None;
             }
@@ -2159,7 +2226,7 @@ pub(crate) fn execute_move_impl(
                     if applied {
                         if let Some(status) = effect.status {
                             if attacker.status == Some(status) {
-                                println!("  {}は{}！", attacker_ja, format_status(status));
+                                narrate!(status_key(status), name = attacker_ja);
                             }
                         }
                     }
@@ -2172,9 +2239,11 @@ pub(crate) fn execute_move_impl(
                 if let Some(drain) = move_data.drain {
                     if damage_to_target > 0 {
                         apply_drain(attacker, damage_to_target, drain);
-                        println!(
-                            "  {}はHPをすいとった！ (HP: {}/{})",
-                            attacker_ja, attacker.current_hp, attacker.stats.hp
+                        narrate!(
+                            "drain",
+                            name = attacker_ja,
+                            hp = attacker.current_hp,
+                            max_hp = attacker.stats.hp,
                         );
                     }
                 }
@@ -2182,37 +2251,39 @@ pub(crate) fn execute_move_impl(
                     let hp_before = attacker.current_hp;
                     apply_recoil_damage(attacker, total_damage, recoil);
                     let _recoil = hp_before.saturating_sub(attacker.current_hp);
-                    println!(
-                        "  {}ははんどうをうけた！ (HP: {}/{})",
-                        attacker_ja, attacker.current_hp, attacker.stats.hp
+                    narrate!(
+                        "recoil",
+                        name = attacker_ja,
+                        hp = attacker.current_hp,
+                        max_hp = attacker.stats.hp,
                     );
                     if attacker.is_fainted() {
-                        println!("  {}はたおれた！", attacker_ja);
+                        narrate!("fainted", name = attacker_ja);
                     }
                 }
                 if has_item(attacker, "lifeorb") {
                     let recoil = (attacker.stats.hp as u32 / 10).max(1) as u16;
                     attacker.take_damage(recoil);
-                    println!(
-                        "  {}は{}のはんどうをうけた！ (HP: {}/{})",
-                        attacker_ja,
-                        translate_item("Life Orb"),
-                        attacker.current_hp,
-                        attacker.stats.hp
+                    narrate!(
+                        "item_recoil",
+                        name = attacker_ja,
+                        item = messages::item_name(&dex, "Life Orb"),
+                        hp = attacker.current_hp,
+                        max_hp = attacker.stats.hp,
                     );
                     if attacker.is_fainted() {
-                        println!("  {}はたおれた！", attacker_ja);
+                        narrate!("fainted", name = attacker_ja);
                     }
                 }
             }
             if !defender.is_fainted() {
                 if let Some(_heal) = crate::sim::items::consumable::try_consume_sitrus_berry(defender) {
-                    println!(
-                        "  {}は{}で たいりょくをかいふくした！ (HP: {}/{})",
-                        defender_ja,
-                        translate_item("Sitrus Berry"),
-                        defender.current_hp,
-                        defender.stats.hp
+                    narrate!(
+                        "item_heal",
+                        name = defender_ja,
+                        item = messages::item_name(&dex, "Sitrus Berry"),
+                        hp = defender.current_hp,
+                        max_hp = defender.stats.hp,
                     );
                 }
             }
@@ -2296,17 +2367,6 @@ fn parse_type(name: &str) -> Type {
     }
 }
 
-pub(crate) fn format_status(status: crate::sim::pokemon::Status) -> &'static str {
-    match status {
-        crate::sim::pokemon::Status::Burn => "やけどをおった",
-        crate::sim::pokemon::Status::Paralysis => "まひした",
-        crate::sim::pokemon::Status::Poison => "どくをうけた",
-        crate::sim::pokemon::Status::Sleep => "ねむってしまった",
-        crate::sim::pokemon::Status::Freeze => "こおってしまった",
-        crate::sim::pokemon::Status::Flinch => "ひるんだ",
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2540,6 +2600,20 @@ mod tests {
         assert!(matches!(pokemon.status, Some(Status::Poison)));
     }
 
+    #[test]
+    fn test_type_chart_comes_from_battle_state() {
+        let attacker = make_pokemon(vec!["earthquake".to_string()]);
+        let defender = make_pokemon(vec!["tackle".to_string()]);
+        let mut rng = SmallRng::seed_from_u64(7);
+        let mut state = BattleState::new(attacker.clone(), defender.clone());
+        execute_move_impl(&mut state, 0, 0, Action::Move(0), 1, &mut rng);
+        assert_eq!(state.pokemon_b.current_hp, state.pokemon_b.stats.hp);
+
+        let mut state = BattleState::new(attacker, defender).with_type_chart(TypeChart::GEN6.inverse());
+        execute_move_impl(&mut state, 0, 0, Action::Move(0), 1, &mut rng);
+        assert!(state.pokemon_b.current_hp < state.pokemon_b.stats.hp);
+    }
+
     #[test]
     fn test_battle_loop() {
         let base_moves = vec!["thunderbolt".to_string()];
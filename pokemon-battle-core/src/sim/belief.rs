@@ -0,0 +1,552 @@
+//! Opponent set inference: what the opponent is probably running, from what it has done.
+//!
+//! A [`CandidatePool`] lists the sets each species may have (a local JSON file in
+//! Showdown's JSON set format, with an optional `"weight"` prior per set). A [`Belief`]
+//! keeps a probability for every candidate of every opposing Pokémon and updates them by
+//! Bayes' rule from [`Observation`]s:
+//!
+//! - revealed moves and items rule out the sets without them;
+//! - damage taken or dealt is compared with each candidate's damage-roll range
+//!   ([`estimate_damage`], the `sim::damage` formula), which tells EVs, natures and
+//!   boosting items apart;
+//! - turn order is compared with each candidate's speed (Choice Scarf, speed EVs);
+//! - end-of-turn HP changes of +1/16 and -1/10 point to Leftovers and Life Orb.
+//!
+//! An observation no candidate explains leaves the belief unchanged. [`Belief::sample_team`]
+//! and [`Belief::determinize`] draw full opposing teams for determinized search.
+
+use crate::data::moves::normalize_move_name;
+use crate::dex::{to_id, Dex};
+use crate::engine::Player;
+use crate::parser::parse_json_sets;
+use crate::sets::PokemonSet;
+use crate::sim::ai::heuristic::estimate_damage;
+use crate::sim::battle::{effective_speed, BattleState, SideConditions};
+use crate::sim::pokemon::Pokemon;
+use crate::usage::UsageStats;
+use anyhow::{bail, Context, Result};
+use rand::Rng;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Likelihood of an HP change coming from something other than the item it points to
+/// (Grassy Terrain, Rocky Helmet, ...).
+const OTHER_CAUSE: f64 = 0.05;
+
+/// Candidate sets by species id.
+#[derive(Clone, Debug, Default)]
+pub struct CandidatePool {
+    pub species: BTreeMap<String, Vec<(PokemonSet, f64)>>,
+}
+
+impl CandidatePool {
+    /// An array of sets (Showdown's JSON team format); each may carry a `"weight"` prior
+    /// (default 1).
+    pub fn from_json(text: &str) -> Result<Self> {
+        let sets = parse_json_sets(text)?;
+        let root: Value = serde_json::from_str(text)?;
+        let mut species: BTreeMap<String, Vec<(PokemonSet, f64)>> = BTreeMap::new();
+        for (idx, (set, entry)) in sets.into_iter().zip(root.as_array().into_iter().flatten()).enumerate() {
+            let weight = match entry.get("weight") {
+                None => 1.0,
+                Some(value) => match value.as_f64() {
+                    Some(weight) if weight > 0.0 => weight,
+                    _ => bail!("set {}: 'weight' must be a positive number", idx + 1),
+                },
+            };
+            species.entry(to_id(&set.species)).or_default().push((set, weight));
+        }
+        Ok(Self { species })
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+        Self::from_json(&text).with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    /// Candidates drawn from usage stats: for each species in `stats`,
+    /// [`UsageStats::plausible_sets`] from `draws` generated sets, weighted by how often
+    /// each came up. Species the dex cannot build are left out.
+    pub fn from_usage(stats: &UsageStats, dex: &Dex, draws: usize, seed: u64) -> Self {
+        let species = stats
+            .species
+            .iter()
+            .filter_map(|(id, usage)| {
+                let sets = stats.plausible_sets(dex, &usage.name, draws, seed).ok()?;
+                Some((id.clone(), sets))
+            })
+            .collect();
+        Self { species }
+    }
+
+    pub fn candidates(&self, species: &str) -> &[(PokemonSet, f64)] {
+        self.species.get(&to_id(species)).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// One set an opposing Pokémon may have.
+#[derive(Clone, Debug)]
+pub struct Candidate {
+    pub set: PokemonSet,
+    /// Built at the observed Pokémon's level.
+    pub pokemon: Pokemon,
+    pub probability: f64,
+}
+
+/// The candidates of one opposing Pokémon.
+#[derive(Clone, Debug)]
+pub struct PokemonBelief {
+    pub species: String,
+    pub candidates: Vec<Candidate>,
+}
+
+impl PokemonBelief {
+    /// Multiply in `likelihood` for each candidate and renormalize. False (and no change)
+    /// when no candidate explains the observation.
+    fn update(&mut self, likelihood: impl Fn(&Candidate) -> f64) -> bool {
+        let posterior: Vec<f64> = self.candidates.iter().map(|c| c.probability * likelihood(c).max(0.0)).collect();
+        let total: f64 = posterior.iter().sum();
+        if total <= 0.0 {
+            return false;
+        }
+        for (candidate, p) in self.candidates.iter_mut().zip(posterior) {
+            candidate.probability = p / total;
+        }
+        true
+    }
+
+    /// The likeliest candidate.
+    pub fn best(&self) -> Option<&Candidate> {
+        self.candidates.iter().max_by(|a, b| a.probability.total_cmp(&b.probability))
+    }
+
+    /// Probability that the Pokémon holds `item`.
+    pub fn item_probability(&self, item: &str) -> f64 {
+        let id = to_id(item);
+        self.candidates
+            .iter()
+            .filter(|c| c.set.item.as_deref().is_some_and(|held| to_id(held) == id))
+            .map(|c| c.probability)
+            .sum()
+    }
+
+    /// Probability that the Pokémon has `move_name`.
+    pub fn move_probability(&self, move_name: &str) -> f64 {
+        let id = normalize_move_name(move_name);
+        self.candidates
+            .iter()
+            .filter(|c| c.pokemon.moves.iter().any(|known| normalize_move_name(known) == id))
+            .map(|c| c.probability)
+            .sum()
+    }
+
+    fn sample(&self, rng: &mut dyn rand::RngCore) -> Option<&Candidate> {
+        let mut pick = rng.gen::<f64>();
+        for candidate in &self.candidates {
+            if pick < candidate.probability {
+                return Some(candidate);
+            }
+            pick -= candidate.probability;
+        }
+        self.best()
+    }
+}
+
+/// Something seen during the battle. States are snapshots from just before the event.
+#[derive(Clone, Copy, Debug)]
+pub enum Observation<'a> {
+    /// The opposing `species` used `move_name`.
+    Move { species: &'a str, move_name: &'a str },
+    /// The opposing `species` holds (or held) `item`.
+    Item { species: &'a str, item: &'a str },
+    /// Our active Pokémon hit the opposing active one with `move_name` for `percent` of its
+    /// max HP (as the HP bar shows it). `knocked_out`: it fainted, so only a lower bound.
+    DamageTaken { state: &'a BattleState, move_name: &'a str, percent: f64, crit: bool, knocked_out: bool },
+    /// The opposing active Pokémon hit ours with `move_name` for `hp` HP.
+    DamageDealt { state: &'a BattleState, move_name: &'a str, hp: u16, crit: bool, knocked_out: bool },
+    /// Who moved first when both actives used moves of the same priority.
+    TurnOrder { state: &'a BattleState, opponent_first: bool },
+    /// The opposing `species`' HP changed by `percent` of its max HP at the end of a turn
+    /// (or after attacking, for Life Orb).
+    HpChange { species: &'a str, percent: f64 },
+}
+
+/// Probabilities over the opponent's sets.
+#[derive(Clone, Debug)]
+pub struct Belief {
+    pub opponent: Player,
+    pub pokemon: Vec<PokemonBelief>,
+}
+
+impl Belief {
+    /// A belief about `team` (the opponent's Pokémon as seen, e.g. at team preview),
+    /// with the pool's candidates for each species at its level. Species missing from the
+    /// pool keep their observed set as the only candidate.
+    pub fn new(pool: &CandidatePool, dex: &Dex, team: &[Pokemon], opponent: Player) -> Self {
+        let pokemon = team
+            .iter()
+            .map(|observed| {
+                let mut candidates: Vec<Candidate> = pool
+                    .candidates(&observed.species)
+                    .iter()
+                    .filter_map(|(set, weight)| {
+                        let mut set = set.clone();
+                        set.level = observed.level;
+                        let pokemon = Pokemon::from_set_in(dex, &set).ok()?;
+                        Some(Candidate { set, pokemon, probability: *weight })
+                    })
+                    .collect();
+                if candidates.is_empty() {
+                    candidates.push(Candidate {
+                        set: observed.to_set_in(dex),
+                        pokemon: observed.clone(),
+                        probability: 1.0,
+                    });
+                }
+                let mut belief = PokemonBelief { species: observed.species.clone(), candidates };
+                belief.update(|_| 1.0);
+                belief
+            })
+            .collect();
+        Self { opponent, pokemon }
+    }
+
+    /// The belief about the opposing `species`.
+    pub fn get(&self, species: &str) -> Option<&PokemonBelief> {
+        let id = to_id(species);
+        self.pokemon.iter().find(|belief| to_id(&belief.species) == id)
+    }
+
+    fn get_mut(&mut self, species: &str) -> Option<&mut PokemonBelief> {
+        let id = to_id(species);
+        self.pokemon.iter_mut().find(|belief| to_id(&belief.species) == id)
+    }
+
+    fn sides<'s>(&self, state: &'s BattleState) -> (&'s Pokemon, &'s SideConditions, &'s Pokemon, &'s SideConditions) {
+        match self.opponent {
+            Player::A => (&state.pokemon_a, &state.side_a, &state.pokemon_b, &state.side_b),
+            Player::B => (&state.pokemon_b, &state.side_b, &state.pokemon_a, &state.side_a),
+        }
+    }
+
+    /// Update on `observation`. False when it was ignored: an unknown species, or no
+    /// candidate explains it.
+    pub fn observe(&mut self, observation: Observation) -> bool {
+        match observation {
+            Observation::Move { species, move_name } => {
+                let id = normalize_move_name(move_name);
+                let Some(belief) = self.get_mut(species) else {
+                    return false;
+                };
+                belief.update(|c| {
+                    if c.pokemon.moves.iter().any(|known| normalize_move_name(known) == id) {
+                        1.0
+                    } else {
+                        0.0
+                    }
+                })
+            }
+            Observation::Item { species, item } => {
+                let id = to_id(item);
+                let Some(belief) = self.get_mut(species) else {
+                    return false;
+                };
+                belief.update(|c| if c.set.item.as_deref().is_some_and(|held| to_id(held) == id) { 1.0 } else { 0.0 })
+            }
+            Observation::DamageTaken { state, move_name, percent, crit, knocked_out } => {
+                let (foe, foe_side, me, _) = self.sides(state);
+                let Some(belief) = self.get_mut(&foe.species) else {
+                    return false;
+                };
+                belief.update(|c| {
+                    let defender = as_candidate(foe, &c.pokemon);
+                    let Some((low, high)) = damage_range(state, me, &defender, foe_side, move_name, crit) else {
+                        return 1.0;
+                    };
+                    let max_hp = defender.stats.hp as f64;
+                    if knocked_out {
+                        return roll_share(low, high, defender.current_hp as f64, f64::INFINITY);
+                    }
+                    // The bar shows whole percents: anything that rounds to `percent`.
+                    roll_share(low, high, (percent - 0.5) / 100.0 * max_hp, (percent + 0.5) / 100.0 * max_hp)
+                })
+            }
+            Observation::DamageDealt { state, move_name, hp, crit, knocked_out } => {
+                let (foe, _, me, my_side) = self.sides(state);
+                let Some(belief) = self.get_mut(&foe.species) else {
+                    return false;
+                };
+                belief.update(|c| {
+                    let attacker = as_candidate(foe, &c.pokemon);
+                    let Some((low, high)) = damage_range(state, &attacker, me, my_side, move_name, crit) else {
+                        return 1.0;
+                    };
+                    if knocked_out {
+                        roll_share(low, high, hp as f64, f64::INFINITY)
+                    } else {
+                        roll_share(low, high, hp as f64, hp as f64)
+                    }
+                })
+            }
+            Observation::TurnOrder { state, opponent_first } => {
+                let (foe, foe_side, me, my_side) = self.sides(state);
+                let my_speed = speed(state, me, my_side);
+                let Some(belief) = self.get_mut(&foe.species) else {
+                    return false;
+                };
+                belief.update(|c| {
+                    let theirs = speed(state, &as_candidate(foe, &c.pokemon), foe_side);
+                    let first = if state.trick_room_turns > 0 { theirs < my_speed } else { theirs > my_speed };
+                    match (theirs == my_speed, first == opponent_first) {
+                        (true, _) => 0.5,
+                        (false, true) => 1.0,
+                        (false, false) => 0.0,
+                    }
+                })
+            }
+            Observation::HpChange { species, percent } => {
+                let item = if (percent - 100.0 / 16.0).abs() < 1.0 {
+                    ["leftovers", "blacksludge"].as_slice()
+                } else if (percent + 10.0).abs() < 1.0 {
+                    ["lifeorb"].as_slice()
+                } else {
+                    return false;
+                };
+                let Some(belief) = self.get_mut(species) else {
+                    return false;
+                };
+                belief.update(|c| {
+                    let held = c.set.item.as_deref().map(to_id);
+                    if held.is_some_and(|held| item.contains(&held.as_str())) {
+                        1.0
+                    } else {
+                        OTHER_CAUSE
+                    }
+                })
+            }
+        }
+    }
+
+    /// Observe what `state` shows about the opposing active Pokémon: its last move.
+    pub fn observe_state(&mut self, state: &BattleState) -> bool {
+        let (foe, ..) = self.sides(state);
+        match foe.last_move.clone() {
+            Some(last) => {
+                let species = foe.species.clone();
+                self.observe(Observation::Move { species: &species, move_name: &last })
+            }
+            None => false,
+        }
+    }
+
+    /// One full opposing team, a candidate drawn for each Pokémon by its probability.
+    pub fn sample_team(&self, rng: &mut dyn rand::RngCore) -> Vec<Pokemon> {
+        self.pokemon
+            .iter()
+            .filter_map(|belief| belief.sample(rng).map(|candidate| candidate.pokemon.clone()))
+            .collect()
+    }
+
+    /// A copy of `state` in which the opponent's Pokémon have sampled sets, keeping what
+    /// the battle has done to them (HP fraction, status, stat stages, ...).
+    pub fn determinize(&self, state: &BattleState, rng: &mut dyn rand::RngCore) -> BattleState {
+        let mut world = state.clone();
+        world.logger = None;
+        let (active, bench) = match self.opponent {
+            Player::A => (&mut world.pokemon_a, &mut world.bench_a),
+            Player::B => (&mut world.pokemon_b, &mut world.bench_b),
+        };
+        for pokemon in std::iter::once(active).chain(bench.iter_mut()) {
+            if let Some(candidate) = self.get(&pokemon.species).and_then(|belief| belief.sample(rng)) {
+                *pokemon = as_candidate(pokemon, &candidate.pokemon);
+            }
+        }
+        world
+    }
+}
+
+/// `observed` (with its battle state) rebuilt with `candidate`'s set.
+fn as_candidate(observed: &Pokemon, candidate: &Pokemon) -> Pokemon {
+    let mut pokemon = observed.clone();
+    let fraction = observed.current_hp as f64 / observed.stats.hp.max(1) as f64;
+    pokemon.stats = candidate.stats;
+    pokemon.evs = candidate.evs;
+    pokemon.ivs = candidate.ivs;
+    pokemon.nature = candidate.nature;
+    pokemon.ability = candidate.ability.clone();
+    pokemon.moves = candidate.moves.clone();
+    if !observed.item_consumed {
+        pokemon.item = candidate.item.clone();
+    }
+    pokemon.current_hp = if observed.current_hp == 0 {
+        0
+    } else {
+        ((fraction * candidate.stats.hp as f64).round() as u16).clamp(1, candidate.stats.hp)
+    };
+    pokemon
+}
+
+/// Damage-roll range of `move_name`, 1.5x for a critical hit.
+fn damage_range(
+    state: &BattleState,
+    attacker: &Pokemon,
+    defender: &Pokemon,
+    defender_side: &SideConditions,
+    move_name: &str,
+    crit: bool,
+) -> Option<(f64, f64)> {
+    let estimate = estimate_damage(state, attacker, defender, defender_side, move_name)?;
+    let scale = if crit { 1.5 } else { 1.0 };
+    Some((estimate.min as f64 * scale, estimate.max as f64 * scale))
+}
+
+/// Share of the (evenly spread) rolls from `low` to `high` that land in `from..=to`.
+fn roll_share(low: f64, high: f64, from: f64, to: f64) -> f64 {
+    let (low, high) = (low.floor(), high.floor());
+    let first = from.ceil().max(low);
+    let last = to.floor().min(high);
+    if last < first {
+        return 0.0;
+    }
+    (last - first + 1.0) / (high - low + 1.0)
+}
+
+fn speed(state: &BattleState, pokemon: &Pokemon, side: &SideConditions) -> u32 {
+    let speed = effective_speed(pokemon, state.weather) as u32;
+    if side.tailwind_turns > 0 {
+        speed * 2
+    } else {
+        speed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::battle::STAGE_SPE;
+    use crate::sim::stats::Nature;
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    const POOL: &str = r#"[
+        {"species": "Garchomp", "item": "Choice Scarf", "ability": "Rough Skin", "nature": "Jolly",
+         "evs": {"atk": 252, "spe": 252}, "moves": ["Earthquake", "Outrage", "Stone Edge", "Fire Fang"]},
+        {"species": "Garchomp", "item": "Life Orb", "ability": "Rough Skin", "nature": "Adamant",
+         "evs": {"atk": 252, "spe": 252}, "moves": ["Earthquake", "Dragon Claw", "Swords Dance", "Fire Fang"],
+         "weight": 2},
+        {"species": "Garchomp", "item": "Leftovers", "ability": "Rough Skin", "nature": "Impish",
+         "evs": {"hp": 252, "def": 252}, "moves": ["Earthquake", "Dragon Tail", "Stealth Rock", "Toxic"]},
+        {"species": "Rotom-Wash", "item": "Leftovers", "ability": "Levitate", "nature": "Bold",
+         "evs": {"hp": 252, "def": 252}, "moves": ["Hydro Pump", "Volt Switch", "Will-O-Wisp", "Pain Split"]}
+    ]"#;
+
+    fn make(species: &str, moves: &[&str], evs: [u8; 6], nature: Nature) -> Pokemon {
+        Pokemon::new(species, 50, evs, [31; 6], nature, moves.iter().map(|m| m.to_string()).collect(), "Pressure", None)
+            .unwrap()
+    }
+
+    fn setup() -> (Belief, BattleState) {
+        let pool = CandidatePool::from_json(POOL).unwrap();
+        // What we see of the opponent: species and level only.
+        let garchomp = make("Garchomp", &["Earthquake"], [0; 6], Nature::Hardy);
+        let rotom = make("Rotom-Wash", &["Hydro Pump"], [0; 6], Nature::Hardy);
+        let belief = Belief::new(&pool, &Dex::builtin(), &[garchomp.clone(), rotom], Player::B);
+        let me = make("Metagross", &["Zen Headbutt", "Bullet Punch"], [0, 252, 0, 0, 0, 252], Nature::Jolly);
+        (belief, BattleState::new(me, garchomp))
+    }
+
+    fn probabilities(belief: &Belief, species: &str) -> Vec<f64> {
+        belief.get(species).unwrap().candidates.iter().map(|c| c.probability).collect()
+    }
+
+    #[test]
+    fn pool_weights_are_priors_and_reveals_rule_sets_out() {
+        assert!(CandidatePool::from_json(r#"[{"species": "Pikachu", "weight": -1}]"#).is_err());
+        let (mut belief, _) = setup();
+        let p = probabilities(&belief, "Garchomp");
+        assert!((p[0] - 0.25).abs() < 1e-9 && (p[1] - 0.5).abs() < 1e-9);
+        assert_eq!(probabilities(&belief, "Rotom-Wash"), [1.0]);
+
+        assert!(belief.observe(Observation::Move { species: "Garchomp", move_name: "Fire Fang" }));
+        assert!((probabilities(&belief, "Garchomp")[2]).abs() < 1e-9);
+        assert!((belief.get("Garchomp").unwrap().move_probability("Stone Edge") - 1.0 / 3.0).abs() < 1e-9);
+
+        assert!(belief.observe(Observation::HpChange { species: "Garchomp", percent: -10.0 }));
+        assert!(belief.get("Garchomp").unwrap().item_probability("Life Orb") > 0.9);
+        assert!(belief.observe(Observation::Item { species: "Garchomp", item: "Choice Scarf" }));
+        assert_eq!(belief.get("Garchomp").unwrap().best().unwrap().set.item.as_deref(), Some("Choice Scarf"));
+        // Nothing holds Focus Sash: ignored.
+        assert!(!belief.observe(Observation::Item { species: "Garchomp", item: "Focus Sash" }));
+        assert!(!belief.observe(Observation::Item { species: "Mew", item: "Leftovers" }));
+    }
+
+    #[test]
+    fn turn_order_tells_scarf_apart() {
+        let (mut belief, state) = setup();
+        // Jolly Metagross (134 Speed) moving second rules out the slow Impish Garchomp (122).
+        assert!(belief.observe(Observation::TurnOrder { state: &state, opponent_first: true }));
+        let p = probabilities(&belief, "Garchomp");
+        assert!(p[2] < 1e-9, "{:?}", p);
+
+        let (mut belief, mut state) = setup();
+        // At +1 (201) only Choice Scarf (253) outspeeds it.
+        state.pokemon_a.stat_stages[STAGE_SPE] = 1;
+        assert!(belief.observe(Observation::TurnOrder { state: &state, opponent_first: true }));
+        assert_eq!(probabilities(&belief, "Garchomp"), [1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn damage_rolls_separate_bulk_and_attack_investment() {
+        let (mut belief, state) = setup();
+        // Our Zen Headbutt into the bulky set, as the HP bar would show it.
+        let bulky = as_candidate(&state.pokemon_b, &belief.get("Garchomp").unwrap().candidates[2].pokemon);
+        let estimate = estimate_damage(&state, &state.pokemon_a, &bulky, &state.side_b, "Zen Headbutt").unwrap();
+        let percent = (estimate.average() as f64 * 100.0 / bulky.stats.hp as f64).round();
+        assert!(belief.observe(Observation::DamageTaken {
+            state: &state,
+            move_name: "Zen Headbutt",
+            percent,
+            crit: false,
+            knocked_out: false,
+        }));
+        let p = probabilities(&belief, "Garchomp");
+        assert!(p[2] > 0.9, "{:?}", p);
+
+        // Earthquake for exactly what the Life Orb set does rules out the bulky set.
+        let (mut belief, state) = setup();
+        let orb = as_candidate(&state.pokemon_b, &belief.get("Garchomp").unwrap().candidates[1].pokemon);
+        let hit = estimate_damage(&state, &orb, &state.pokemon_a, &state.side_a, "Earthquake").unwrap().max;
+        assert!(belief.observe(Observation::DamageDealt {
+            state: &state,
+            move_name: "Earthquake",
+            hp: hit,
+            crit: false,
+            knocked_out: false,
+        }));
+        let p = probabilities(&belief, "Garchomp");
+        assert!(p[1] > p[0] && p[2] < 1e-9, "{:?}", p);
+    }
+
+    #[test]
+    fn samples_teams_and_determinizes_states() {
+        let (mut belief, mut state) = setup();
+        belief.observe(Observation::Move { species: "Garchomp", move_name: "Stealth Rock" });
+        state.pokemon_b.current_hp = state.pokemon_b.stats.hp / 2;
+        state.pokemon_b.stat_stages[0] = 1;
+        let mut rng = SmallRng::seed_from_u64(1);
+        let team = belief.sample_team(&mut rng);
+        assert_eq!(team.len(), 2);
+        assert_eq!(team[0].item.as_deref(), Some("Leftovers"));
+
+        let world = belief.determinize(&state, &mut rng);
+        let garchomp = &world.pokemon_b;
+        assert!(garchomp.moves.iter().any(|m| normalize_move_name(m) == "stealthrock"));
+        assert_eq!(garchomp.stats.hp, team[0].stats.hp);
+        assert!((garchomp.current_hp as f64 / garchomp.stats.hp as f64 - 0.5).abs() < 0.01);
+        assert_eq!(garchomp.stat_stages[0], 1);
+        // Our side is untouched.
+        assert_eq!(world.pokemon_a.moves, state.pokemon_a.moves);
+    }
+}
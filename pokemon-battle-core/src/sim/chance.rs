@@ -0,0 +1,341 @@
+//! Randomness in battles.
+//!
+//! The battle code rolls through [`Chance`] rather than a concrete RNG. A seeded
+//! [`SmallRng`] samples one outcome; [`enumerate`] instead replays a turn once per branch
+//! (speed ties, accuracy, crits, damage rolls, secondary effects, ...) and returns every
+//! resulting state with its probability.
+//!
+//! Multi-hit moves would multiply the branches by every hit's crit and damage roll, so
+//! [`enumerate`] branches on those once, for the first hit, and plays the later hits with
+//! the same draws. The expected damage is unchanged; the spread of the total is wider than
+//! in a real battle.
+
+use crate::sim::battle::BattleState;
+use rand::rngs::SmallRng;
+use rand::Rng;
+
+/// A source of random decisions.
+pub trait Chance {
+    /// True with probability `p` (0-1).
+    fn chance(&mut self, p: f64) -> bool;
+
+    /// Uniform in `0..n` (`n > 0`).
+    fn roll(&mut self, n: u32) -> u32;
+
+    /// True with probability `percent / 100`; accuracy checks.
+    fn percent(&mut self, percent: f64) -> bool;
+
+    /// True with probability `hits / n`, rolled as `roll(n) < hits`.
+    fn odds(&mut self, hits: u32, n: u32) -> bool {
+        self.roll(n) < hits
+    }
+
+    /// The damage roll: 85-100 (% of the top roll).
+    fn damage_roll(&mut self) -> u32 {
+        85 + self.roll(16)
+    }
+
+    /// A critical hit, with probability `p`.
+    fn critical(&mut self, p: f64) -> bool {
+        self.chance(p)
+    }
+
+    /// Hits of a 2-5 hit move: 2 or 3 with 35% each, 4 or 5 with 15% each.
+    fn multi_hit_count(&mut self) -> u8 {
+        match self.roll(20) {
+            0..=6 => 2,
+            7..=13 => 3,
+            14..=16 => 4,
+            _ => 5,
+        }
+    }
+
+    /// Hit `hit` (from 0) of a move that hits more than once is about to be rolled.
+    fn start_hit(&mut self, _hit: u8) {}
+
+    /// The move that called [`start_hit`](Self::start_hit) is done hitting.
+    fn end_hits(&mut self) {}
+}
+
+impl Chance for SmallRng {
+    fn chance(&mut self, p: f64) -> bool {
+        self.gen_bool(p)
+    }
+
+    fn roll(&mut self, n: u32) -> u32 {
+        self.gen_range(0..n)
+    }
+
+    fn percent(&mut self, percent: f64) -> bool {
+        self.gen_range(0.0f32..100.0) < percent as f32
+    }
+}
+
+/// How finely [`enumerate`] splits a turn.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EnumerateOptions {
+    /// Damage rolls are grouped into this many equally likely buckets (1-16), each played
+    /// with its middle roll. 16 is exact.
+    pub damage_buckets: u32,
+    /// Branches less likely than this (given the branches before them) are dropped and the
+    /// remaining probability renormalized. 0 keeps everything.
+    pub min_probability: f64,
+    /// At most this many paths are played; past that the paths played so far are
+    /// renormalized and the rest are dropped.
+    pub max_paths: usize,
+}
+
+impl Default for EnumerateOptions {
+    fn default() -> Self {
+        Self { damage_buckets: 16, min_probability: 0.0, max_paths: 4096 }
+    }
+}
+
+/// One random decision made during a replay.
+struct Branch {
+    taken: usize,
+    /// Probability of each outcome; dropped outcomes are left out.
+    outcomes: Vec<(usize, f64)>,
+}
+
+/// The first hit's draws of a multi-hit move, reused by the later hits.
+#[derive(Default)]
+struct HitDraws {
+    repeat: bool,
+    critical: Option<bool>,
+    damage_roll: Option<u32>,
+}
+
+/// Replays a script of outcome choices and records every decision point on the way.
+struct Replay {
+    script: Vec<usize>,
+    trace: Vec<Branch>,
+    options: EnumerateOptions,
+    hit: Option<HitDraws>,
+}
+
+impl Replay {
+    /// Take the scripted outcome at this decision point (the first one past the script).
+    fn branch(&mut self, probabilities: &[f64]) -> usize {
+        let mut outcomes: Vec<(usize, f64)> = probabilities
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|&(_, p)| p > 0.0 && p >= self.options.min_probability)
+            .collect();
+        if outcomes.is_empty() {
+            // Everything fell under the threshold: keep the likeliest outcome.
+            let likeliest = (0..probabilities.len())
+                .max_by(|&a, &b| probabilities[a].total_cmp(&probabilities[b]))
+                .unwrap_or(0);
+            outcomes.push((likeliest, 1.0));
+        }
+        let kept: f64 = outcomes.iter().map(|(_, p)| p).sum();
+        for (_, p) in outcomes.iter_mut() {
+            *p /= kept;
+        }
+        let pick = self.script.get(self.trace.len()).copied().unwrap_or(0);
+        let taken = outcomes[pick].0;
+        self.trace.push(Branch { taken: pick, outcomes });
+        taken
+    }
+
+    /// A damage roll, one branch per bucket.
+    fn bucketed_damage_roll(&mut self) -> u32 {
+        let buckets = self.options.damage_buckets.clamp(1, 16);
+        let bucket = if buckets == 1 { 0 } else { self.branch(&vec![1.0 / buckets as f64; buckets as usize]) as u32 };
+        // Rolls 0-15 split as evenly as possible; the bucket plays its middle roll.
+        let first = bucket * 16 / buckets;
+        let last = (bucket + 1) * 16 / buckets - 1;
+        85 + (first + last) / 2
+    }
+
+    fn probability(&self) -> f64 {
+        self.trace.iter().map(|branch| branch.outcomes[branch.taken].1).product()
+    }
+
+    /// The script for the next unexplored path, if any.
+    fn next_script(&self) -> Option<Vec<usize>> {
+        let depth = self.trace.iter().rposition(|branch| branch.taken + 1 < branch.outcomes.len())?;
+        let mut script: Vec<usize> = self.trace[..depth].iter().map(|branch| branch.taken).collect();
+        script.push(self.trace[depth].taken + 1);
+        Some(script)
+    }
+}
+
+impl Chance for Replay {
+    fn chance(&mut self, p: f64) -> bool {
+        if p >= 1.0 {
+            return true;
+        }
+        if p <= 0.0 {
+            return false;
+        }
+        self.branch(&[p, 1.0 - p]) == 0
+    }
+
+    fn roll(&mut self, n: u32) -> u32 {
+        if n <= 1 {
+            return 0;
+        }
+        self.branch(&vec![1.0 / n as f64; n as usize]) as u32
+    }
+
+    fn percent(&mut self, percent: f64) -> bool {
+        self.chance(percent / 100.0)
+    }
+
+    fn odds(&mut self, hits: u32, n: u32) -> bool {
+        self.chance(hits as f64 / n as f64)
+    }
+
+    fn damage_roll(&mut self) -> u32 {
+        if let Some(HitDraws { repeat: true, damage_roll: Some(roll), .. }) = self.hit {
+            return roll;
+        }
+        let roll = self.bucketed_damage_roll();
+        if let Some(hit) = self.hit.as_mut() {
+            hit.damage_roll = Some(roll);
+        }
+        roll
+    }
+
+    fn critical(&mut self, p: f64) -> bool {
+        if let Some(HitDraws { repeat: true, critical: Some(critical), .. }) = self.hit {
+            return critical;
+        }
+        let critical = self.chance(p);
+        if let Some(hit) = self.hit.as_mut() {
+            hit.critical = Some(critical);
+        }
+        critical
+    }
+
+    fn multi_hit_count(&mut self) -> u8 {
+        2 + self.branch(&[0.35, 0.35, 0.15, 0.15]) as u8
+    }
+
+    fn start_hit(&mut self, hit: u8) {
+        if hit == 0 {
+            self.hit = Some(HitDraws::default());
+        } else if let Some(draws) = self.hit.as_mut() {
+            draws.repeat = true;
+        }
+    }
+
+    fn end_hits(&mut self) {
+        self.hit = None;
+    }
+}
+
+/// Run `turn` on a copy of `state` once for every combination of random outcomes and
+/// return each resulting state with its probability (the probabilities sum to 1). The
+/// copies have no logger. Stops after [`EnumerateOptions::max_paths`] paths.
+pub fn enumerate(
+    state: &BattleState,
+    options: EnumerateOptions,
+    mut turn: impl FnMut(&mut BattleState, &mut dyn Chance),
+) -> Vec<(f64, BattleState)> {
+    let mut start = state.clone();
+    start.logger = None;
+    let mut results = Vec::new();
+    let mut script = Vec::new();
+    loop {
+        let mut replay = Replay { script, trace: Vec::new(), options, hit: None };
+        let mut after = start.clone();
+        turn(&mut after, &mut replay);
+        results.push((replay.probability(), after));
+        match replay.next_script() {
+            Some(next) if results.len() < options.max_paths.max(1) => script = next,
+            Some(_) => {
+                let played: f64 = results.iter().map(|(p, _)| p).sum();
+                for (p, _) in results.iter_mut() {
+                    *p /= played;
+                }
+                break;
+            }
+            None => break,
+        }
+    }
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::pokemon::Pokemon;
+    use crate::sim::stats::Nature;
+
+    fn state() -> BattleState {
+        let make = |species: &str| {
+            Pokemon::new(species, 50, [0; 6], [31; 6], Nature::Hardy, vec!["Tackle".to_string()], "Pressure", None)
+                .unwrap()
+        };
+        BattleState::new(make("Pikachu"), make("Eevee"))
+    }
+
+    #[test]
+    fn every_branch_is_visited_with_its_probability() {
+        let outcomes = enumerate(&state(), EnumerateOptions::default(), |state, chance| {
+            if chance.chance(0.25) {
+                state.turn += 10;
+            }
+            state.turn += chance.roll(3);
+        });
+        assert_eq!(outcomes.len(), 6);
+        let total: f64 = outcomes.iter().map(|(p, _)| p).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+        let p_ten: f64 = outcomes.iter().filter(|(_, s)| s.turn >= 10).map(|(p, _)| p).sum();
+        assert!((p_ten - 0.25).abs() < 1e-9);
+        assert!(outcomes.iter().any(|(p, s)| s.turn == 12 && (p - 0.25 / 3.0).abs() < 1e-9));
+    }
+
+    #[test]
+    fn damage_rolls_can_be_bucketed_and_unlikely_branches_dropped() {
+        let rolls = |buckets| {
+            let options = EnumerateOptions { damage_buckets: buckets, ..EnumerateOptions::default() };
+            let outcomes = enumerate(&state(), options, |state, chance| state.turn = chance.damage_roll());
+            outcomes.iter().map(|(_, s)| s.turn).collect::<Vec<_>>()
+        };
+        assert_eq!(rolls(16), (85..=100).collect::<Vec<_>>());
+        assert_eq!(rolls(4), [86, 90, 94, 98]);
+        assert_eq!(rolls(1), [92]);
+
+        let options = EnumerateOptions { min_probability: 0.1, ..EnumerateOptions::default() };
+        let outcomes = enumerate(&state(), options, |state, chance| {
+            if chance.chance(1.0 / 24.0) {
+                state.turn = 1;
+            }
+        });
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(outcomes[0].0, 1.0);
+        assert_eq!(outcomes[0].1.turn, 0);
+    }
+
+    #[test]
+    fn paths_past_the_cap_are_dropped_and_the_rest_renormalized() {
+        let options = EnumerateOptions { max_paths: 5, ..EnumerateOptions::default() };
+        let outcomes = enumerate(&state(), options, |state, chance| state.turn = chance.roll(4) * 4 + chance.roll(4));
+        assert_eq!(outcomes.len(), 5);
+        let total: f64 = outcomes.iter().map(|(p, _)| p).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn later_hits_reuse_the_first_hits_draws() {
+        let outcomes = enumerate(&state(), EnumerateOptions::default(), |state, chance| {
+            let hits = chance.multi_hit_count();
+            let mut total = 0;
+            for hit in 0..hits {
+                chance.start_hit(hit);
+                total += chance.damage_roll() + if chance.critical(1.0 / 24.0) { 100 } else { 0 };
+            }
+            chance.end_hits();
+            state.turn = total;
+        });
+        assert_eq!(outcomes.len(), 4 * 16 * 2);
+        let expected: f64 = outcomes.iter().map(|(p, s)| p * s.turn as f64).sum();
+        let hits = 2.0 * 0.35 + 3.0 * 0.35 + 4.0 * 0.15 + 5.0 * 0.15;
+        assert!((expected - hits * (92.5 + 100.0 / 24.0)).abs() < 1e-6);
+    }
+}
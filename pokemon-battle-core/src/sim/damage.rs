@@ -95,6 +95,7 @@ fn compute_base_damage(
     base_damage
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn calculate_damage_with_modifiers(
     attacker_level: u8,
     attacker_atk_or_spa: u16,
@@ -141,6 +142,7 @@ pub fn calculate_damage_with_modifiers(
     (base_damage & 0xFFFF) as u16
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn calculate_damage(
     attacker_level: u8,
     attacker_atk_or_spa: u16,
@@ -177,7 +179,7 @@ pub fn damage_range(base_damage: u16) -> Vec<u16> {
 }
 
 pub fn is_stab(move_type: Type, pokemon_types: [Type; 2]) -> bool {
-    pokemon_types.iter().any(|t| *t == move_type)
+    pokemon_types.contains(&move_type)
 }
 
 pub fn ability_attack_modifier(
@@ -205,7 +207,8 @@ pub fn item_type_boost(item: &str, move_type: Type) -> f32 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::data::types::{effectiveness_against, effectiveness_dual, Type};
+    use crate::data::types::{effectiveness_against, Type};
+    use crate::sim::type_chart::TypeChart;
     use crate::sim::stats::{Nature, StatsSet};
     use crate::data::moves::get_move;
     use crate::sim::pokemon::{Pokemon, Status};
@@ -263,7 +266,7 @@ mod tests {
             .expect("garchomp stats");
         let defender = StatsSet::from_species("heatran", 50, evs, ivs, Nature::Hardy)
             .expect("heatran stats");
-        let type_effectiveness = effectiveness_dual(Type::Ground, Type::Fire, Type::Steel);
+        let type_effectiveness = TypeChart::GEN6.effectiveness_dual(Type::Ground, Type::Fire, Type::Steel);
         let max_damage = calculate_damage_with_modifiers(
             50,
             attacker.atk,
@@ -296,7 +299,7 @@ mod tests {
             .expect("pikachu stats");
         let defender = StatsSet::from_species("gyarados", 100, evs, ivs, Nature::Hardy)
             .expect("gyarados stats");
-        let type_effectiveness = effectiveness_dual(Type::Electric, Type::Water, Type::Flying);
+        let type_effectiveness = TypeChart::GEN6.effectiveness_dual(Type::Electric, Type::Water, Type::Flying);
         let max_damage = calculate_damage_with_modifiers(
             100,
             attacker.spa,
@@ -340,7 +343,7 @@ mod tests {
         let attacker = make_test_pokemon("Huge Power");
         let tackle = get_move("tackle").expect("tackle");
         assert_eq!(
-            ability_attack_modifier(&attacker, &tackle, Type::Normal, false),
+            ability_attack_modifier(&attacker, tackle, Type::Normal, false),
             2.0
         );
     }
@@ -349,7 +352,7 @@ mod tests {
     fn test_ability_attack_modifier_iron_fist_punch() {
         let attacker = make_test_pokemon("Iron Fist");
         let move_data = get_move("firepunch").expect("firepunch");
-        let modifier = ability_attack_modifier(&attacker, &move_data, Type::Fire, false);
+        let modifier = ability_attack_modifier(&attacker, move_data, Type::Fire, false);
         assert!((modifier - 1.2).abs() < 1e-6);
     }
 
@@ -359,7 +362,7 @@ mod tests {
         attacker.status = Some(Status::Burn);
         let tackle = get_move("tackle").expect("tackle");
         assert_eq!(
-            ability_attack_modifier(&attacker, &tackle, Type::Normal, false),
+            ability_attack_modifier(&attacker, tackle, Type::Normal, false),
             1.5
         );
     }
@@ -369,7 +372,7 @@ mod tests {
         let defender = make_test_pokemon("Filter");
         let move_data = get_move("tackle").expect("tackle");
         assert_eq!(
-            ability_defense_modifier(&defender, &move_data, 2.0),
+            ability_defense_modifier(&defender, move_data, 2.0),
             0.75
         );
     }
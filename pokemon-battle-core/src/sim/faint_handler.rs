@@ -10,6 +10,7 @@ pub enum KoPrevention {
     FocusSash,
 }
 
+#[allow(clippy::collapsible_if)]
 pub fn prevent_ko_if_applicable(defender: &mut Pokemon, damage: u16) -> (u16, Option<KoPrevention>) {
     if defender.current_hp == 0 || defender.current_hp == 1 {
         return (damage, None);
@@ -104,6 +105,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::needless_borrow)]
     fn aftermath_damages_contact_attacker() {
         let move_data = get_move("tackle").expect("move exists");
         let mut attacker = make_pokemon("Blaze", None);
@@ -10,7 +10,16 @@ pub mod items;
 pub mod faint_handler;
 pub mod weather_field;
 pub mod switching;
+pub mod type_chart;
+pub use type_chart::{ChartGeneration, TypeChart};
+pub mod chance;
 pub mod battle;
-pub use battle::{run_battle, run_team_battle, Action, BattleResult, BattleState, Weather, FieldEffect};
+pub mod outcomes;
+pub mod belief;
+pub mod nash;
+pub mod review;
+pub use battle::{run_battle, run_battle_with_state, run_team_battle, Action, BattleResult, BattleState, Weather, FieldEffect};
+pub mod team_preview;
+pub use team_preview::{TeamChoice, TeamPreview};
 pub mod ai;
-pub use ai::{BattleAI, RandomAI};
+pub use ai::{BattleAI, Difficulty, HeuristicAI, MctsAI, MctsMode, MctsParams, NashAI, RandomAI};
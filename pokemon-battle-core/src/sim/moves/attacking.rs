@@ -1,4 +1,4 @@
-use crate::data::moves::{normalize_move_name, MoveData, MOVES};
+use crate::data::moves::{normalize_move_name, MoveData};
 use crate::data::types::Type;
 use crate::sim::battle::{Field, Weather};
 use crate::sim::items::battle_items;
@@ -6,8 +6,7 @@ use crate::sim::pokemon::Pokemon;
 use crate::sim::pokemon::Status;
 use crate::sim::weather_field;
 use crate::sim::abilities::misc_abilities::speed_multiplier;
-use rand::rngs::SmallRng;
-use rand::Rng;
+use crate::sim::chance::Chance;
 
 /// Apply recoil damage based on total damage dealt.
 pub fn apply_recoil_damage(attacker: &mut Pokemon, damage_dealt: u16, recoil: (u8, u8)) {
@@ -40,22 +39,16 @@ pub fn apply_drain(attacker: &mut Pokemon, damage_dealt: u16, drain: (u8, u8)) {
 }
 
 /// Determine the number of hits for multihit moves.
-pub fn calculate_multihit_count(move_data: &MoveData, rng: &mut SmallRng) -> u8 {
+pub fn calculate_multihit_count(move_data: &MoveData, rng: &mut dyn Chance) -> u8 {
     // Showdown: battle-actions.ts#L859-L877 (multihit distribution)
     if let Some((min_hits, max_hits)) = move_data.multihit {
         if min_hits == max_hits {
             return min_hits;
         }
         if min_hits == 2 && max_hits == 5 {
-            let roll = rng.gen_range(0..20);
-            return match roll {
-                0..=6 => 2,
-                7..=13 => 3,
-                14..=16 => 4,
-                _ => 5,
-            };
+            return rng.multi_hit_count();
         }
-        return rng.gen_range(min_hits..=max_hits);
+        return min_hits + rng.roll((max_hits - min_hits + 1) as u32) as u8;
     }
     let normalized = normalize_move_name(move_data.name);
     match normalized.as_str() {
@@ -79,11 +72,11 @@ pub fn calculate_multihit_count(move_data: &MoveData, rng: &mut SmallRng) -> u8
 
 /// Handle the first/second turn of a charging move.
 /// Returns true if the move consumes the turn to charge.
-pub fn handle_charging_move(pokemon: &mut Pokemon, move_id: &str) -> bool {
-    let normalized = normalize_move_name(move_id);
-    if !is_charging_move(normalized.as_str()) {
+pub fn handle_charging_move(pokemon: &mut Pokemon, move_data: &MoveData) -> bool {
+    if !is_charging_move(move_data) {
         return false;
     }
+    let normalized = normalize_move_name(move_data.name);
     // Showdown: pokemon.ts#L814-L818 (two-turn charge checks)
     if pokemon.charging_move.as_deref() == Some(normalized.as_str()) {
         pokemon.charging_move = None;
@@ -100,7 +93,7 @@ pub fn handle_ohko_move(
     attacker: &Pokemon,
     defender: &Pokemon,
     move_id: &str,
-    rng: &mut SmallRng,
+    rng: &mut dyn Chance,
 ) -> Option<u16> {
     let normalized = normalize_move_name(move_id);
     let ohko_type = ohko_type(normalized.as_str())?;
@@ -118,19 +111,15 @@ pub fn handle_ohko_move(
     if normalized == "sheercold" && !has_type(attacker, Type::Ice) {
         accuracy = 20 + level_diff;
     }
-    let roll = rng.gen_range(0..100) as i16;
-    if roll < accuracy {
+    if rng.odds(accuracy.clamp(0, 100) as u32, 100) {
         // Showdown: battle-actions.ts#L1604 (OHKO damage = target max HP)
         return Some(defender.stats.hp);
     }
     None
 }
 
-fn is_charging_move(move_id: &str) -> bool {
-    MOVES
-        .get(move_id)
-        .map(|data| data.flags.iter().any(|flag| *flag == "charge"))
-        .unwrap_or(false)
+fn is_charging_move(move_data: &MoveData) -> bool {
+    move_data.flags.contains(&"charge")
 }
 
 fn is_semi_invulnerable_move(move_id: &str) -> bool {
@@ -211,7 +200,7 @@ pub fn calculate_variable_power(
         "flail" | "reversal" => {
             // PS: ratio = max(floor(hp * 48 / maxhp), 1)
             let max_hp = attacker.stats.hp.max(1);
-            let ratio = ((attacker.current_hp as u32 * 48) / (max_hp as u32)).max(1) as u32;
+            let ratio = ((attacker.current_hp as u32 * 48) / (max_hp as u32)).max(1);
             if ratio < 2 {
                 200
             } else if ratio < 5 {
@@ -230,7 +219,7 @@ pub fn calculate_variable_power(
             // PS: ratio = floor(userSpe / targetSpe)
             let user_spe = effective_speed_for_variable_power(attacker, weather) as u32;
             let target_spe = effective_speed_for_variable_power(defender, weather) as u32;
-            let ratio = if target_spe == 0 { 0 } else { user_spe / target_spe };
+            let ratio = user_spe.checked_div(target_spe).unwrap_or(0);
             match ratio.min(4) {
                 0 => 40,
                 1 => 60,
@@ -253,6 +242,7 @@ pub fn calculate_variable_power(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::data::moves::MOVES;
     use crate::sim::stats::Nature;
 
     fn dummy_pokemon(species: &str, moves: Vec<String>) -> Pokemon {
@@ -17,7 +17,7 @@ pub const FLAG_METRONOME: &str = "metronome";
 pub const FLAG_BYPASS_SUB: &str = "bypasssub";
 
 pub fn move_has_flag(move_data: &MoveData, flag: &str) -> bool {
-    move_data.flags.iter().any(|candidate| *candidate == flag)
+    move_data.flags.contains(&flag)
 }
 
 pub fn is_contact_move(move_data: &MoveData) -> bool {
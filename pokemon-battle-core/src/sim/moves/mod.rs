@@ -4,9 +4,10 @@ pub mod secondary;
 pub mod status;
 
 use crate::data::moves::{MoveCategory, MoveData};
+use crate::dex::Dex;
 use crate::sim::battle::{Action, BattleState, EnvUpdate, Field, Weather};
 use crate::sim::pokemon::Pokemon;
-use rand::rngs::SmallRng;
+use crate::sim::chance::Chance;
 
 pub use attacking::{
     apply_drain, apply_recoil_damage, calculate_multihit_count, calculate_variable_power,
@@ -22,18 +23,20 @@ pub use secondary::{
     apply_secondary_effect, secondary_effect_from_move, secondary_effects_from_move,
     self_effect_from_move, SecondaryEffect,
 };
-pub(crate) use status::{decrement_side_conditions, handle_status_move};
+pub(crate) use status::decrement_side_conditions;
 
 /// 技実行コンテキスト（M5）。
+#[allow(dead_code)]
 pub(crate) struct BattleContext<'a> {
     pub weather: Option<Weather>,
     pub field: Option<Field>,
     pub defender_action: Action,
-    pub rng: &'a mut SmallRng,
+    pub rng: &'a mut dyn Chance,
     pub env_update: EnvUpdate,
 }
 
 /// 技実行結果（M5）。
+#[allow(dead_code)]
 pub(crate) enum MoveResult {
     Protected,
     Immune,
@@ -46,6 +49,7 @@ pub(crate) enum MoveResult {
 /// 技実行の統合関数（M5）。
 ///
 /// NOTE: 現状のbattle.rs実装に合わせ、env_update は MoveResult に含めて呼び出し側で適用する。
+#[allow(dead_code)]
 pub(crate) fn execute_move(
     move_data: &MoveData,
     attacker: &mut Pokemon,
@@ -68,6 +72,7 @@ pub(crate) fn execute_move(
     // 3. 状態変化技（M2）
     if matches!(move_data.category, MoveCategory::Status) {
         let update = status::handle_status_move(
+            &Dex::builtin(),
             attacker,
             defender,
             move_data,
@@ -106,7 +111,7 @@ pub(crate) fn execute_move_state(
     move_idx: usize,
     defender_action: Action,
     defender_idx: usize,
-    rng: &mut SmallRng,
+    rng: &mut dyn Chance,
 ) {
     crate::sim::battle::execute_move_impl(state, attacker_idx, move_idx, defender_action, defender_idx, rng)
 }
@@ -4,8 +4,7 @@ use crate::sim::battle::{
 };
 use crate::sim::pokemon::{Pokemon, Status};
 use crate::sim::stats::Stat;
-use rand::rngs::SmallRng;
-use rand::Rng;
+use crate::sim::chance::Chance;
 use std::collections::BTreeMap;
 
 #[derive(Clone, Debug)]
@@ -59,12 +58,13 @@ pub fn apply_secondary_effect(
     defender: &mut Pokemon,
     effect: &SecondaryEffect,
     field: Option<Field>,
-    rng: &mut SmallRng,
+    rng: &mut dyn Chance,
 ) -> bool {
     let mut update = EnvUpdate::default();
     apply_secondary_effect_with_update(attacker, defender, effect, field, 0, 1, &mut update, rng)
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn apply_secondary_effect_with_update(
     attacker: &mut Pokemon,
     defender: &mut Pokemon,
@@ -73,7 +73,7 @@ pub(crate) fn apply_secondary_effect_with_update(
     attacker_side_idx: usize,
     defender_side_idx: usize,
     update: &mut EnvUpdate,
-    rng: &mut SmallRng,
+    rng: &mut dyn Chance,
 ) -> bool {
     let mut chance = effect.chance;
     if effect.affected_by_serene_grace && attacker.has_ability("Serene Grace") {
@@ -82,8 +82,7 @@ pub(crate) fn apply_secondary_effect_with_update(
     if chance == 0 {
         return false;
     }
-    let roll: u8 = rng.gen_range(0..100);
-    if roll >= chance {
+    if !rng.odds(chance as u32, 100) {
         return false;
     }
 
@@ -162,7 +161,7 @@ fn apply_side_effect(
     }
 }
 
-fn apply_volatile_status(target: &mut Pokemon, volatile: &str, rng: &mut SmallRng) -> bool {
+fn apply_volatile_status(target: &mut Pokemon, volatile: &str, rng: &mut dyn Chance) -> bool {
     match volatile {
         "confusion" => target.apply_confusion(rng),
         _ => false,
@@ -289,6 +288,7 @@ mod tests {
     use crate::data::moves::get_move;
     use crate::sim::pokemon::Pokemon;
     use crate::sim::stats::Nature;
+    use rand::rngs::SmallRng;
     use rand::SeedableRng;
 
     fn make_pokemon(species: &str) -> Pokemon {
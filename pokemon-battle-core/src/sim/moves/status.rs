@@ -9,17 +9,20 @@
 
 use crate::data::moves::{normalize_move_name, MoveData};
 use crate::data::types::Type;
-use crate::i18n::translate_pokemon;
+use crate::dex::Dex;
+use crate::i18n::messages::{narrate, status_key};
 use crate::sim::battle::{
-    apply_stage_change, apply_status_with_field, format_status, screen_turns, EnvUpdate, Field,
+    apply_stage_change, apply_status_with_field, screen_turns, EnvUpdate, Field,
     FieldEffect, HazardKind, HazardUpdate, ScreenUpdate, SideConditionKind, SideConditionUpdate,
     SideConditions, Weather, STAGE_SPD,
 };
 use crate::sim::pokemon::{Pokemon, Status};
-use rand::rngs::SmallRng;
+use crate::sim::chance::Chance;
 
 /// Handle status moves that alter the field, sides, or user state.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn handle_status_move(
+    dex: &Dex,
     attacker: &mut Pokemon,
     defender: &mut Pokemon,
     move_data: &MoveData,
@@ -27,7 +30,7 @@ pub(crate) fn handle_status_move(
     weather: Option<Weather>,
     trick_room_turns: u8,
     target_side_idx: usize,
-    rng: &mut SmallRng,
+    rng: &mut dyn Chance,
 ) -> EnvUpdate {
     let mut update = EnvUpdate::default();
     let id = normalize_move_name(move_data.name);
@@ -40,7 +43,7 @@ pub(crate) fn handle_status_move(
         }
         "charge" => {
             attacker.charge_active = true;
-            let user = translate_pokemon(&attacker.species);
+            let user = attacker.display_name(dex);
             let _ = apply_stage_change(attacker, &user, STAGE_SPD, 1);
         }
         "telekinesis" => {
@@ -66,17 +69,13 @@ pub(crate) fn handle_status_move(
         // Status
         "thunderwave" => {
             if defender.types[0] == Type::Ground || defender.types[1] == Type::Ground {
-                println!("  しかし うまくきまらなかった！");
+                narrate!("move_failed");
                 return update;
             }
             if apply_status_with_field(defender, Status::Paralysis, false, field, rng) {
-                println!(
-                    "  {}は{}！",
-                    translate_pokemon(&defender.species),
-                    format_status(Status::Paralysis)
-                );
+                narrate!(status_key(Status::Paralysis), name = defender.display_name(dex));
             } else {
-                println!("  しかし うまくきまらなかった！");
+                narrate!("move_failed");
             }
         }
 
@@ -85,12 +84,12 @@ pub(crate) fn handle_status_move(
             let max_hp = attacker.stats.hp;
             let cost = (max_hp as u32 / 4).max(1) as u16;
             if attacker.current_hp <= cost || attacker.substitute_hp > 0 {
-                println!("  しかし うまくきまらなかった！");
+                narrate!("move_failed");
                 return update;
             }
             attacker.current_hp = attacker.current_hp.saturating_sub(cost);
             attacker.substitute_hp = cost;
-            println!("  {}はみがわりをだした！", translate_pokemon(&attacker.species));
+            narrate!("substitute_made", name = attacker.display_name(dex));
         }
 
         // Screens (apply to user's side)
@@ -168,7 +167,7 @@ pub(crate) fn handle_status_move(
         }
         "auroraveil" => {
             if !matches!(weather, Some(Weather::Hail)) {
-                println!("  しかし こうかがなかった！");
+                narrate!("no_effect");
                 return update;
             }
             update.side_condition = Some(SideConditionUpdate {
@@ -188,43 +187,43 @@ pub(crate) fn decrement_side_conditions(side: &mut SideConditions) {
     if side.reflect_turns > 0 {
         side.reflect_turns = side.reflect_turns.saturating_sub(1);
         if side.reflect_turns == 0 {
-            println!("  リフレクターの こうかが きれた！");
+            narrate!("reflect_end");
         }
     }
     if side.light_screen_turns > 0 {
         side.light_screen_turns = side.light_screen_turns.saturating_sub(1);
         if side.light_screen_turns == 0 {
-            println!("  ひかりのかべの こうかが きれた！");
+            narrate!("light_screen_end");
         }
     }
     if side.mist_turns > 0 {
         side.mist_turns = side.mist_turns.saturating_sub(1);
         if side.mist_turns == 0 {
-            println!("  しろいきりが きえた！");
+            narrate!("mist_end");
         }
     }
     if side.safeguard_turns > 0 {
         side.safeguard_turns = side.safeguard_turns.saturating_sub(1);
         if side.safeguard_turns == 0 {
-            println!("  しんぴのベールが きえた！");
+            narrate!("safeguard_end");
         }
     }
     if side.tailwind_turns > 0 {
         side.tailwind_turns = side.tailwind_turns.saturating_sub(1);
         if side.tailwind_turns == 0 {
-            println!("  おいかぜが やんだ！");
+            narrate!("tailwind_end");
         }
     }
     if side.lucky_chant_turns > 0 {
         side.lucky_chant_turns = side.lucky_chant_turns.saturating_sub(1);
         if side.lucky_chant_turns == 0 {
-            println!("  おまじないの こうかが きれた！");
+            narrate!("lucky_chant_end");
         }
     }
     if side.aurora_veil_turns > 0 {
         side.aurora_veil_turns = side.aurora_veil_turns.saturating_sub(1);
         if side.aurora_veil_turns == 0 {
-            println!("  オーロラベールの こうかが きれた！");
+            narrate!("aurora_veil_end");
         }
     }
 }
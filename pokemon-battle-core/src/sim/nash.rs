@@ -0,0 +1,282 @@
+//! Nash equilibria of zero-sum matrix games, for simultaneous turns.
+//!
+//! Both players choose at once, so the best reply to a fixed guess is exploitable. A turn is
+//! a payoff matrix for player A, indexed `[action A][action B]` over
+//! [`legal_actions`](crate::engine::BattleEngine::legal_actions) (A maximizes, B minimizes);
+//! [`solve`] finds the exact equilibrium by linear programming and [`regret_matching`]
+//! approximates it iteratively.
+
+const EPSILON: f64 = 1e-9;
+
+/// Optimal mixed strategies and the value of a game.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Equilibrium {
+    /// Expected payoff for the row player (A) when both play their strategies.
+    pub value: f64,
+    /// Probability of each row (A's actions).
+    pub row: Vec<f64>,
+    /// Probability of each column (B's actions).
+    pub col: Vec<f64>,
+}
+
+impl Equilibrium {
+    /// How much a best reply gains against these strategies: 0 for an exact equilibrium.
+    pub fn exploitability(&self, payoff: &[Vec<f64>]) -> f64 {
+        let row_best = best_row(payoff, &self.col);
+        let col_best = best_col(payoff, &self.row);
+        (row_best - col_best).max(0.0)
+    }
+}
+
+/// Exact equilibrium: strictly dominated actions are removed, then the rest is solved
+/// with the simplex method. Removed actions get probability 0.
+pub fn solve(payoff: &[Vec<f64>]) -> Equilibrium {
+    let (rows, cols) = undominated(payoff);
+    let reduced: Vec<Vec<f64>> = rows.iter().map(|&i| cols.iter().map(|&j| payoff[i][j]).collect()).collect();
+    let (value, p, q) = solve_zero_sum(&reduced);
+    let mut row = vec![0.0; payoff.len()];
+    let mut col = vec![0.0; payoff.first().map_or(0, Vec::len)];
+    for (k, &i) in rows.iter().enumerate() {
+        row[i] = p[k];
+    }
+    for (k, &j) in cols.iter().enumerate() {
+        col[j] = q[k];
+    }
+    Equilibrium { value, row, col }
+}
+
+/// Approximate equilibrium by regret matching+ self-play over `iterations` rounds: the
+/// average strategies converge to an equilibrium, and the value is their expected payoff.
+/// Cheaper than [`solve`] on large matrices and can be stopped early.
+pub fn regret_matching(payoff: &[Vec<f64>], iterations: usize) -> Equilibrium {
+    let m = payoff.len();
+    let n = payoff.first().map_or(0, Vec::len);
+    if m == 0 || n == 0 {
+        return Equilibrium { value: 0.0, row: vec![1.0; m.min(1)], col: vec![1.0; n.min(1)] };
+    }
+    let mut regret_row = vec![0.0; m];
+    let mut regret_col = vec![0.0; n];
+    let mut sum_row = vec![0.0; m];
+    let mut sum_col = vec![0.0; n];
+    for iteration in 1..=iterations.max(1) {
+        let row = regret_strategy(&regret_row);
+        let col = regret_strategy(&regret_col);
+        let row_values: Vec<f64> = payoff.iter().map(|line| dot(line, &col)).collect();
+        let col_values: Vec<f64> = (0..n).map(|j| payoff.iter().zip(&row).map(|(line, w)| w * line[j]).sum()).collect();
+        let value = dot(&row_values, &row);
+        // Regrets are floored at zero (RM+); B minimizes, so its regrets are negated.
+        for (regret, action_value) in regret_row.iter_mut().zip(&row_values) {
+            *regret = (*regret + action_value - value).max(0.0);
+        }
+        for (regret, action_value) in regret_col.iter_mut().zip(&col_values) {
+            *regret = (*regret + value - action_value).max(0.0);
+        }
+        // Later rounds count more (linear averaging), which converges faster.
+        let weight = iteration as f64;
+        for (sum, w) in sum_row.iter_mut().zip(&row) {
+            *sum += weight * w;
+        }
+        for (sum, w) in sum_col.iter_mut().zip(&col) {
+            *sum += weight * w;
+        }
+    }
+    let row = normalize(sum_row);
+    let col = normalize(sum_col);
+    let value = payoff.iter().zip(&row).map(|(line, w)| w * dot(line, &col)).sum();
+    Equilibrium { value, row, col }
+}
+
+/// Rows and columns left after iteratively removing strictly dominated actions
+/// (A maximizes, B minimizes).
+pub fn undominated(payoff: &[Vec<f64>]) -> (Vec<usize>, Vec<usize>) {
+    let mut rows: Vec<usize> = (0..payoff.len()).collect();
+    let mut cols: Vec<usize> = (0..payoff.first().map_or(0, Vec::len)).collect();
+    loop {
+        let row_dominated = rows.iter().position(|&i| {
+            rows.iter().any(|&k| k != i && cols.iter().all(|&j| payoff[k][j] > payoff[i][j] + EPSILON))
+        });
+        if let Some(pos) = row_dominated {
+            rows.remove(pos);
+            continue;
+        }
+        let col_dominated = cols.iter().position(|&j| {
+            cols.iter().any(|&k| k != j && rows.iter().all(|&i| payoff[i][k] < payoff[i][j] - EPSILON))
+        });
+        match col_dominated {
+            Some(pos) => {
+                cols.remove(pos);
+            }
+            None => return (rows, cols),
+        }
+    }
+}
+
+/// Value and optimal mixed strategies `(p, q)` of the zero-sum game where the row player
+/// maximizes `payoff`, by the simplex method.
+fn solve_zero_sum(payoff: &[Vec<f64>]) -> (f64, Vec<f64>, Vec<f64>) {
+    let m = payoff.len();
+    let n = payoff.first().map_or(0, Vec::len);
+    if m == 0 || n == 0 {
+        return (0.0, vec![1.0; m.min(1)], vec![1.0; n.min(1)]);
+    }
+    // Shift every entry above zero; then the column player's LP is
+    // max sum(y) s.t. payoff * y <= 1, y >= 0, and the value is 1 / sum(y).
+    let low = payoff.iter().flatten().copied().fold(f64::INFINITY, f64::min);
+    let shift = 1.0 - low;
+    let width = n + m + 1;
+    let mut tableau: Vec<Vec<f64>> = (0..m)
+        .map(|i| {
+            let mut row = vec![0.0; width];
+            for j in 0..n {
+                row[j] = payoff[i][j] + shift;
+            }
+            row[n + i] = 1.0;
+            row[width - 1] = 1.0;
+            row
+        })
+        .collect();
+    let mut objective = vec![0.0; width];
+    objective[..n].fill(-1.0);
+    let mut basis: Vec<usize> = (n..n + m).collect();
+
+    // Bland's rule: entering column is the first with a negative reduced cost.
+    while let Some(col) = (0..width - 1).find(|&j| objective[j] < -EPSILON) {
+        let Some(pivot) = (0..m)
+            .filter(|&i| tableau[i][col] > EPSILON)
+            .min_by(|&a, &b| {
+                let ratio = |i: usize| tableau[i][width - 1] / tableau[i][col];
+                ratio(a).total_cmp(&ratio(b)).then(basis[a].cmp(&basis[b]))
+            })
+        else {
+            break;
+        };
+        let scale = tableau[pivot][col];
+        for value in tableau[pivot].iter_mut() {
+            *value /= scale;
+        }
+        let pivot_row = tableau[pivot].clone();
+        for (i, row) in tableau.iter_mut().enumerate() {
+            if i != pivot && row[col].abs() > EPSILON {
+                let factor = row[col];
+                for (value, pivot_value) in row.iter_mut().zip(&pivot_row) {
+                    *value -= factor * pivot_value;
+                }
+            }
+        }
+        let factor = objective[col];
+        for (value, pivot_value) in objective.iter_mut().zip(&pivot_row) {
+            *value -= factor * pivot_value;
+        }
+        basis[pivot] = col;
+    }
+
+    let total = objective[width - 1];
+    let game = 1.0 / total;
+    let mut q = vec![0.0; n];
+    for (i, &var) in basis.iter().enumerate() {
+        if var < n {
+            q[var] = tableau[i][width - 1] * game;
+        }
+    }
+    let p: Vec<f64> = (0..m).map(|i| (objective[n + i] * game).max(0.0)).collect();
+    (game - shift, normalize(p), normalize(q))
+}
+
+/// Positive regrets, normalized; uniform when there are none.
+fn regret_strategy(regrets: &[f64]) -> Vec<f64> {
+    let total: f64 = regrets.iter().sum();
+    if total > EPSILON {
+        regrets.iter().map(|r| r / total).collect()
+    } else {
+        vec![1.0 / regrets.len() as f64; regrets.len()]
+    }
+}
+
+fn best_row(payoff: &[Vec<f64>], col: &[f64]) -> f64 {
+    payoff.iter().map(|line| dot(line, col)).fold(f64::NEG_INFINITY, f64::max)
+}
+
+fn best_col(payoff: &[Vec<f64>], row: &[f64]) -> f64 {
+    let n = payoff.first().map_or(0, Vec::len);
+    (0..n)
+        .map(|j| payoff.iter().zip(row).map(|(line, w)| w * line[j]).sum::<f64>())
+        .fold(f64::INFINITY, f64::min)
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn normalize(mut weights: Vec<f64>) -> Vec<f64> {
+    let total: f64 = weights.iter().sum();
+    if total > EPSILON {
+        for weight in weights.iter_mut() {
+            *weight /= total;
+        }
+    }
+    weights
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-6, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn solves_matrix_games() {
+        let (value, p, q) = solve_zero_sum(&[vec![1.0, -1.0], vec![-1.0, 1.0]]);
+        assert_close(value, 0.0);
+        assert_close(p[0], 0.5);
+        assert_close(q[1], 0.5);
+
+        // Rock paper scissors where rock beats scissors twice over: both strategies hold the value.
+        let rps = [vec![0.0, -1.0, 2.0], vec![1.0, 0.0, -1.0], vec![-1.0, 1.0, 0.0]];
+        let (value, p, q) = solve_zero_sum(&rps);
+        assert_close(p.iter().sum::<f64>(), 1.0);
+        assert_close(q.iter().sum::<f64>(), 1.0);
+        for k in 0..3 {
+            assert!(p.iter().zip(&rps).map(|(w, row)| w * row[k]).sum::<f64>() >= value - 1e-6);
+            assert!(q.iter().zip(&rps[k]).map(|(w, x)| w * x).sum::<f64>() <= value + 1e-6);
+        }
+        assert!(value > 0.0 && p.iter().all(|&w| w > 0.0));
+
+        let (value, p, _) = solve_zero_sum(&[vec![0.3, 0.6], vec![0.5, 0.4], vec![0.1, 0.2]]);
+        assert!(value > 0.4 && value < 0.5);
+        assert_close(p[2], 0.0);
+        assert_eq!(undominated(&[vec![0.3, 0.6], vec![0.5, 0.4], vec![0.1, 0.2]]).0, [0, 1]);
+    }
+
+    #[test]
+    fn solve_keeps_the_full_matrix_shape() {
+        // Row 2 and column 2 are dominated and get probability 0.
+        let payoff = [vec![0.3, 0.6, 0.9], vec![0.5, 0.4, 0.8], vec![0.1, 0.2, 0.3]];
+        let equilibrium = solve(&payoff);
+        assert_eq!(equilibrium.row.len(), 3);
+        assert_eq!(equilibrium.col.len(), 3);
+        assert_close(equilibrium.row[2], 0.0);
+        assert_close(equilibrium.col[2], 0.0);
+        assert_close(equilibrium.exploitability(&payoff), 0.0);
+        // Saddle point: a pure strategy each.
+        let equilibrium = solve(&[vec![1.0, 2.0], vec![0.0, 3.0]]);
+        assert_eq!(equilibrium.row, [1.0, 0.0]);
+        assert_eq!(equilibrium.col, [1.0, 0.0]);
+        assert_close(equilibrium.value, 1.0);
+    }
+
+    #[test]
+    fn regret_matching_converges_to_the_linear_program() {
+        let rps = [vec![0.0, -1.0, 2.0], vec![1.0, 0.0, -1.0], vec![-1.0, 1.0, 0.0]];
+        let exact = solve(&rps);
+        let approx = regret_matching(&rps, 5000);
+        assert!((approx.value - exact.value).abs() < 0.01, "{} vs {}", approx.value, exact.value);
+        assert!(approx.exploitability(&rps) < 0.01);
+        for (a, b) in approx.row.iter().zip(&exact.row) {
+            assert!((a - b).abs() < 0.02);
+        }
+        let uniform = Equilibrium { value: 0.0, row: vec![1.0 / 3.0; 3], col: vec![1.0 / 3.0; 3] };
+        assert!(uniform.exploitability(&rps) > 0.1);
+    }
+}
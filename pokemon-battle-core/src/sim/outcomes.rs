@@ -0,0 +1,195 @@
+//! Exact probability distribution of one turn, for damage calcs and search.
+//!
+//! [`turn_outcomes`] plays [`execute_turn`] once per combination of random branches (speed
+//! ties, accuracy, crits, all 16 damage rolls, secondary effects, random replacements)
+//! through [`chance::enumerate`](crate::sim::chance::enumerate), and merges branches that
+//! end in the same state. [`summarize`] reduces the states to what a calc shows: HP after
+//! the turn, fainted and status for every Pokémon.
+//!
+//! Multi-hit moves are enumerated with one crit and damage roll shared by every hit (see
+//! [`chance`](crate::sim::chance)), and at most
+//! [`EnumerateOptions::max_paths`](crate::sim::chance::EnumerateOptions::max_paths) paths
+//! are played.
+
+use crate::engine::Player;
+use crate::i18n::messages::silently;
+use crate::sim::battle::{execute_turn, Action, BattleState, Field, FieldEffect, SideConditions, Weather};
+use crate::sim::chance::{enumerate, EnumerateOptions};
+use crate::sim::pokemon::{Pokemon, Status};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+/// One distinct result of a turn.
+#[derive(Clone, Debug)]
+pub struct Outcome {
+    pub probability: f64,
+    /// The state after the turn (without a logger).
+    pub state: BattleState,
+}
+
+/// Every distinct state `execute_turn(state, action_a, action_b)` can end in, with its exact
+/// probability, most likely first. The probabilities sum to 1. Nothing is narrated.
+pub fn turn_outcomes(state: &BattleState, action_a: Action, action_b: Action) -> Vec<Outcome> {
+    let branches = silently(|| {
+        enumerate(state, EnumerateOptions::default(), |after, chance| execute_turn(after, action_a, action_b, chance))
+    });
+    merge(branches)
+}
+
+/// Merge branches that end in the same state, most likely first.
+pub fn merge(branches: Vec<(f64, BattleState)>) -> Vec<Outcome> {
+    let mut seen: HashMap<StateKey, usize> = HashMap::new();
+    // (probability, index of the first branch ending in that state)
+    let mut merged: Vec<(f64, usize)> = Vec::new();
+    for (idx, (probability, state)) in branches.iter().enumerate() {
+        match seen.entry(StateKey::of(state)) {
+            Entry::Occupied(entry) => merged[*entry.get()].0 += probability,
+            Entry::Vacant(entry) => {
+                entry.insert(merged.len());
+                merged.push((*probability, idx));
+            }
+        }
+    }
+    drop(seen);
+    let mut states: Vec<Option<BattleState>> = branches.into_iter().map(|(_, state)| Some(state)).collect();
+    let mut outcomes: Vec<Outcome> = merged
+        .into_iter()
+        .filter_map(|(probability, idx)| states[idx].take().map(|state| Outcome { probability, state }))
+        .collect();
+    outcomes.sort_by(|a, b| b.probability.total_cmp(&a.probability));
+    outcomes
+}
+
+/// Everything in a state that a turn can change (the dex, type chart and logger cannot).
+#[derive(Eq, Hash, PartialEq)]
+struct StateKey<'a> {
+    pokemon: [&'a Pokemon; 2],
+    benches: [&'a [Pokemon]; 2],
+    turn: u32,
+    weather: (Option<Weather>, u8),
+    field_effects: &'a [FieldEffect],
+    field: (Option<Field>, u8),
+    trick_room_turns: u8,
+    sides: [&'a SideConditions; 2],
+}
+
+impl<'a> StateKey<'a> {
+    fn of(state: &'a BattleState) -> Self {
+        Self {
+            pokemon: [&state.pokemon_a, &state.pokemon_b],
+            benches: [&state.bench_a, &state.bench_b],
+            turn: state.turn,
+            weather: (state.weather, state.weather_turns),
+            field_effects: &state.field_effects,
+            field: (state.field, state.field_turns),
+            trick_room_turns: state.trick_room_turns,
+            sides: [&state.side_a, &state.side_b],
+        }
+    }
+}
+
+/// Probability that `outcomes` end in a state matching `predicate`.
+pub fn probability(outcomes: &[Outcome], predicate: impl Fn(&BattleState) -> bool) -> f64 {
+    outcomes.iter().filter(|outcome| predicate(&outcome.state)).map(|outcome| outcome.probability).sum()
+}
+
+/// A Pokémon after the turn.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PokemonSummary {
+    pub name: String,
+    pub hp: u16,
+    pub max_hp: u16,
+    pub fainted: bool,
+    pub status: Option<Status>,
+}
+
+impl PokemonSummary {
+    fn of(pokemon: &Pokemon) -> Self {
+        Self {
+            name: pokemon.name.clone(),
+            hp: pokemon.current_hp,
+            max_hp: pokemon.stats.hp,
+            fainted: pokemon.is_fainted(),
+            status: pokemon.status,
+        }
+    }
+}
+
+/// One side after the turn, in the order of the side's team before the turn.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SideSummary {
+    /// Index in `team` of the Pokémon active after the turn.
+    pub active: usize,
+    /// The Pokémon active before the turn first, then the bench.
+    pub team: Vec<PokemonSummary>,
+}
+
+/// Both sides after the turn.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TurnSummary {
+    pub sides: [SideSummary; 2],
+}
+
+impl TurnSummary {
+    /// The Pokémon `player` had active before the turn.
+    pub fn starter(&self, player: Player) -> &PokemonSummary {
+        &self.side(player).team[0]
+    }
+
+    pub fn side(&self, player: Player) -> &SideSummary {
+        match player {
+            Player::A => &self.sides[0],
+            Player::B => &self.sides[1],
+        }
+    }
+}
+
+/// Summaries of `outcomes` of a turn played from `before`, with the probabilities of
+/// outcomes that look the same merged, most likely first.
+pub fn summarize(before: &BattleState, outcomes: &[Outcome]) -> Vec<(f64, TurnSummary)> {
+    let mut summaries: Vec<(f64, TurnSummary)> = Vec::new();
+    for outcome in outcomes {
+        let summary = TurnSummary {
+            sides: [
+                summarize_side(&before.pokemon_a, &before.bench_a, &outcome.state.pokemon_a, &outcome.state.bench_a),
+                summarize_side(&before.pokemon_b, &before.bench_b, &outcome.state.pokemon_b, &outcome.state.bench_b),
+            ],
+        };
+        match summaries.iter_mut().find(|(_, seen)| *seen == summary) {
+            Some((probability, _)) => *probability += outcome.probability,
+            None => summaries.push((outcome.probability, summary)),
+        }
+    }
+    summaries.sort_by(|a, b| b.0.total_cmp(&a.0));
+    summaries
+}
+
+fn summarize_side(active_before: &Pokemon, bench_before: &[Pokemon], active: &Pokemon, bench: &[Pokemon]) -> SideSummary {
+    let mut team = Vec::new();
+    let mut active_idx = 0;
+    for (idx, pokemon) in std::iter::once(active_before).chain(bench_before).enumerate() {
+        // Switching swaps Pokémon around; find each one again by its id.
+        let Some(now) = std::iter::once(active).chain(bench).find(|candidate| candidate.id == pokemon.id) else {
+            continue;
+        };
+        if now.id == active.id {
+            active_idx = idx;
+        }
+        team.push(PokemonSummary::of(now));
+    }
+    SideSummary { active: active_idx, team }
+}
+
+/// HP of the Pokémon `player` had active before the turn, with probabilities, from low to high.
+pub fn hp_distribution(summaries: &[(f64, TurnSummary)], player: Player) -> Vec<(u16, f64)> {
+    let mut hp: Vec<(u16, f64)> = Vec::new();
+    for (probability, summary) in summaries {
+        let after = summary.starter(player).hp;
+        match hp.iter_mut().find(|(seen, _)| *seen == after) {
+            Some((_, total)) => *total += probability,
+            None => hp.push((after, *probability)),
+        }
+    }
+    hp.sort_by_key(|(after, _)| *after);
+    hp
+}
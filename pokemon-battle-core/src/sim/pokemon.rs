@@ -1,11 +1,17 @@
-use crate::data::species::POKEDEX;
+use crate::data::moves::normalize_move_name;
 use crate::data::types::Type;
+use crate::dex::Dex;
+use crate::i18n::messages::pokemon_name;
+use crate::sets::{Gender, PokemonSet};
 use crate::sim::abilities::status_abilities::ability_blocks_status;
 use crate::sim::stats::{Nature, StatsSet};
 use anyhow::{anyhow, Result};
-use rand::Rng;
+use crate::sim::chance::Chance;
+use std::sync::atomic::{AtomicU32, Ordering};
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+static NEXT_ID: AtomicU32 = AtomicU32::new(0);
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Status {
     Burn,
     Paralysis,
@@ -15,10 +21,28 @@ pub enum Status {
     Flinch,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Pokemon {
+    /// Identity that survives switching and cloning; tells apart Pokémon with the same name.
+    pub id: u32,
+    /// Nickname, or the species name when the set has none (PS: `pokemon.name`).
+    pub name: String,
     pub species: String,
+    pub gender: Option<Gender>,
+    pub shiny: bool,
+    pub happiness: u8,
+    pub tera_type: Option<Type>,
+    /// Set details the battle does not use; kept so export round-trips.
+    pub hidden_power: Option<Type>,
+    pub pp_ups: Vec<u8>,
+    pub dynamax_level: u8,
+    pub gigantamax: bool,
     pub level: u8,
+    /// The team's level when a format rule changed it (exported instead of `level`).
+    pub original_level: Option<u8>,
+    pub evs: [u8; 6],
+    pub ivs: [u8; 6],
+    pub nature: Nature,
     pub stats: StatsSet,
     pub current_hp: u16,
     pub substitute_hp: u16,
@@ -57,6 +81,7 @@ pub struct Pokemon {
 }
 
 impl Pokemon {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         species: impl Into<String>,
         level: u8,
@@ -66,17 +91,46 @@ impl Pokemon {
         moves: Vec<String>,
         ability: impl Into<String>,
         item: Option<String>,
+    ) -> Result<Self> {
+        Self::new_in(&Dex::builtin(), species, level, evs, ivs, nature, moves, ability, item)
+    }
+
+    /// [`Pokemon::new`] with species data looked up in `dex` (which may carry data mods).
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_in(
+        dex: &Dex,
+        species: impl Into<String>,
+        level: u8,
+        evs: [u8; 6],
+        ivs: [u8; 6],
+        nature: Nature,
+        moves: Vec<String>,
+        ability: impl Into<String>,
+        item: Option<String>,
     ) -> Result<Self> {
         let species_str = species.into();
         let ability_str = ability.into();
-        let species_id = normalize_id(species_str.as_str());
-        let stats = StatsSet::from_species(species_id.as_str(), level, evs, ivs, nature)
+        let stats = StatsSet::from_species_in(dex, species_str.as_str(), level, evs, ivs, nature)
             .ok_or_else(|| anyhow!("Species '{}' not found in POKEDEX", species_str))?;
-        let types = species_types(species_id.as_str())
+        let types = species_types(dex, species_str.as_str())
             .ok_or_else(|| anyhow!("Species '{}' not found in POKEDEX", species_str))?;
         Ok(Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            name: species_str.clone(),
             species: species_str,
+            gender: None,
+            shiny: false,
+            happiness: 255,
+            tera_type: None,
+            hidden_power: None,
+            pp_ups: Vec::new(),
+            dynamax_level: 10,
+            gigantamax: false,
             level,
+            original_level: None,
+            evs,
+            ivs,
+            nature,
             current_hp: stats.hp,
             substitute_hp: 0,
             stats,
@@ -115,6 +169,117 @@ impl Pokemon {
         })
     }
 
+    /// Build from a parsed team entry.
+    pub fn from_set(set: &PokemonSet) -> Result<Self> {
+        Self::from_set_in(&Dex::builtin(), set)
+    }
+
+    /// [`Pokemon::from_set`] with species data looked up in `dex`.
+    pub fn from_set_in(dex: &Dex, set: &PokemonSet) -> Result<Self> {
+        let moves = set.moves.iter().map(|m| normalize_move_name(m)).collect();
+        let ability = set.ability.clone().unwrap_or_else(|| "No Ability".to_string());
+        let mut pokemon = Self::new_in(
+            dex,
+            set.species.clone(),
+            set.level,
+            set.evs,
+            set.ivs,
+            set.nature,
+            moves,
+            ability,
+            set.item.clone(),
+        )?;
+        pokemon.name = set.name().to_string();
+        pokemon.gender = set.gender;
+        pokemon.shiny = set.shiny;
+        pokemon.happiness = set.happiness;
+        pokemon.tera_type = set.tera_type;
+        pokemon.hidden_power = set.hidden_power;
+        pokemon.pp_ups = set.pp_ups.clone();
+        pokemon.dynamax_level = set.dynamax_level;
+        pokemon.gigantamax = set.gigantamax;
+        Ok(pokemon)
+    }
+
+    /// Back to a team entry (for export). Moves and the item/ability get their
+    /// display names from `dex`.
+    pub fn to_set_in(&self, dex: &Dex) -> PokemonSet {
+        let mut set = PokemonSet::new(self.species.clone());
+        set.nickname = (self.name != self.species).then(|| self.name.clone());
+        set.item = self.item.clone();
+        set.ability = (self.ability != "No Ability").then(|| self.ability.clone());
+        set.moves = self
+            .moves
+            .iter()
+            .map(|id| dex.get_move(id).map_or_else(|| id.clone(), |m| m.name.to_string()))
+            .collect();
+        set.nature = self.nature;
+        set.gender = self.gender;
+        set.evs = self.evs;
+        set.ivs = self.ivs;
+        set.level = self.original_level.unwrap_or(self.level);
+        set.shiny = self.shiny;
+        set.happiness = self.happiness;
+        set.tera_type = self.tera_type;
+        set.hidden_power = self.hidden_power;
+        set.pp_ups = self.pp_ups.clone();
+        set.dynamax_level = self.dynamax_level;
+        set.gigantamax = self.gigantamax;
+        set
+    }
+
+    pub fn to_set(&self) -> PokemonSet {
+        self.to_set_in(&Dex::builtin())
+    }
+
+    /// Battle at `level`: stats are recomputed from `dex` and HP keeps its ratio.
+    /// The first level is remembered in [`Pokemon::original_level`] for export.
+    pub fn set_level_in(&mut self, dex: &Dex, level: u8) -> Result<()> {
+        if level == self.level {
+            return Ok(());
+        }
+        let stats = StatsSet::from_species_in(dex, &self.species, level, self.evs, self.ivs, self.nature)
+            .ok_or_else(|| anyhow!("Species '{}' not found in POKEDEX", self.species))?;
+        let hp = if self.current_hp == self.stats.hp {
+            stats.hp
+        } else {
+            ((self.current_hp as u32 * stats.hp as u32) / self.stats.hp.max(1) as u32).max(1) as u16
+        };
+        self.original_level.get_or_insert(self.level);
+        if self.original_level == Some(level) {
+            self.original_level = None;
+        }
+        self.level = level;
+        self.stats = stats;
+        self.current_hp = if self.current_hp == 0 { 0 } else { hp };
+        Ok(())
+    }
+
+    /// Name used in battle messages: the nickname, or the species name in the current locale.
+    pub fn display_name(&self, dex: &Dex) -> String {
+        if self.name == self.species {
+            pokemon_name(dex, &self.species)
+        } else {
+            self.name.clone()
+        }
+    }
+
+    /// Protocol details string, e.g. `Garchomp, L50, F, shiny` (PS: `pokemon.details`).
+    pub fn showdown_details(&self) -> String {
+        let mut details = self.species.clone();
+        if self.level != 100 {
+            details.push_str(&format!(", L{}", self.level));
+        }
+        if let Some(gender) = self.gender {
+            details.push_str(", ");
+            details.push_str(gender.letter());
+        }
+        if self.shiny {
+            details.push_str(", shiny");
+        }
+        details
+    }
+
     pub fn take_damage(&mut self, damage: u16) {
         self.current_hp = self.current_hp.saturating_sub(damage);
     }
@@ -123,24 +288,24 @@ impl Pokemon {
         self.current_hp == 0
     }
 
-    pub fn apply_status(&mut self, status: Status, rng: &mut impl Rng) -> bool {
+    pub fn apply_status(&mut self, status: Status, rng: &mut dyn Chance) -> bool {
         self.apply_status_internal(status, false, rng)
     }
 
-    pub fn apply_toxic(&mut self, rng: &mut impl Rng) -> bool {
+    pub fn apply_toxic(&mut self, rng: &mut dyn Chance) -> bool {
         self.apply_status_internal(Status::Poison, true, rng)
     }
 
-    pub fn apply_confusion(&mut self, rng: &mut impl Rng) -> bool {
+    pub fn apply_confusion(&mut self, rng: &mut dyn Chance) -> bool {
         if self.confusion_turns > 0 {
             return false;
         }
         // Showdown: random(2, 6) -> 2..=5 turns
-        self.confusion_turns = rng.gen_range(2..=5);
+        self.confusion_turns = 2 + rng.roll(4) as u8;
         true
     }
 
-    fn apply_status_internal(&mut self, status: Status, toxic: bool, rng: &mut impl Rng) -> bool {
+    fn apply_status_internal(&mut self, status: Status, toxic: bool, rng: &mut dyn Chance) -> bool {
         if matches!(status, Status::Flinch) {
             self.flinched = true;
             return true;
@@ -154,7 +319,7 @@ impl Pokemon {
         match status {
             Status::Sleep => {
                 // PS: random(2, 5) -> 2..=4 (1-3 turns asleep)
-                self.sleep_turns = rng.gen_range(2..=4);
+                self.sleep_turns = 2 + rng.roll(3) as u8;
             }
             Status::Poison => {
                 // PS: tox stage starts at 0 and increments each residual
@@ -203,22 +368,41 @@ impl Pokemon {
     }
 }
 
-fn species_types(species: &str) -> Option<[Type; 2]> {
-    let id = normalize_id(species);
-    let info = POKEDEX.get(id.as_str())?;
+fn species_types(dex: &Dex, species: &str) -> Option<[Type; 2]> {
+    let info = dex.species(species)?;
     let primary = parse_type(info.types[0]).unwrap_or(Type::Normal);
     let secondary = parse_type(info.types[1]).unwrap_or(primary);
     Some([primary, secondary])
 }
 
-fn normalize_id(name: &str) -> String {
-    name.to_ascii_lowercase()
-        .chars()
-        .filter(|c| c.is_ascii_alphanumeric())
-        .collect()
+/// Showdown's name for `ty` (`Fire`, `???`, `Stellar`).
+pub(crate) fn type_name(ty: Type) -> &'static str {
+    match ty {
+        Type::Normal => "Normal",
+        Type::Fire => "Fire",
+        Type::Water => "Water",
+        Type::Electric => "Electric",
+        Type::Grass => "Grass",
+        Type::Ice => "Ice",
+        Type::Fighting => "Fighting",
+        Type::Poison => "Poison",
+        Type::Ground => "Ground",
+        Type::Flying => "Flying",
+        Type::Psychic => "Psychic",
+        Type::Bug => "Bug",
+        Type::Rock => "Rock",
+        Type::Ghost => "Ghost",
+        Type::Dragon => "Dragon",
+        Type::Dark => "Dark",
+        Type::Steel => "Steel",
+        Type::Fairy => "Fairy",
+        Type::Typeless => "???",
+        Type::Stellar => "Stellar",
+    }
 }
 
-fn parse_type(name: &str) -> Option<Type> {
+/// The type named `name` (case-insensitive Showdown name, e.g. `fire`).
+pub fn parse_type(name: &str) -> Option<Type> {
     match name.to_ascii_lowercase().as_str() {
         "normal" => Some(Type::Normal),
         "fire" => Some(Type::Fire),
@@ -238,6 +422,8 @@ fn parse_type(name: &str) -> Option<Type> {
         "dark" => Some(Type::Dark),
         "steel" => Some(Type::Steel),
         "fairy" => Some(Type::Fairy),
+        "???" | "typeless" => Some(Type::Typeless),
+        "stellar" => Some(Type::Stellar),
         _ => None,
     }
 }
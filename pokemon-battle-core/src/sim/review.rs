@@ -0,0 +1,442 @@
+//! Decision quality of a finished battle: which choices lost value, and how much.
+//!
+//! A [`BattleRecord`] (both teams, the [`BattleEngine`] seed and every turn's choices) is
+//! replayed turn by turn. Before each turn the position is searched with
+//! [`expectiminimax::analyze`], which gives every action's expected score against the
+//! opponent's equilibrium strategy. A choice that scores [`ReviewParams::threshold`] or
+//! more below the best alternative is a blunder. [`Review::to_json`] and
+//! [`Review::to_html`] render the per-turn report (the HTML matches the diff reports under
+//! `reports/`).
+
+use crate::dex::Dex;
+use crate::engine::{BattleEngine, Player};
+use crate::i18n::messages::silently;
+use crate::parser::parse_showdown_team_in;
+use crate::sets::export_team;
+use crate::sim::ai::expectiminimax::{self, Evaluator, SearchParams};
+use crate::sim::battle::{Action, BattleResult, BattleState};
+use crate::sim::pokemon::Pokemon;
+use anyhow::{anyhow, bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::path::Path;
+
+/// A finished battle as the engine played it.
+#[derive(Clone, Debug)]
+pub struct BattleRecord {
+    pub team_a: Vec<Pokemon>,
+    pub team_b: Vec<Pokemon>,
+    /// Seed passed to [`BattleEngine::new`].
+    pub seed: u64,
+    /// Player A's and player B's action, turn by turn.
+    pub turns: Vec<[Action; 2]>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct RecordFile {
+    team_a: String,
+    team_b: String,
+    seed: u64,
+    turns: Vec<[String; 2]>,
+}
+
+impl BattleRecord {
+    /// `{"team_a": <export>, "team_b": <export>, "seed": 1, "turns": [["move 1", "switch 2"], ...]}`,
+    /// with choices as in [`parse_choice`].
+    pub fn from_json(dex: &Dex, text: &str) -> Result<Self> {
+        let file: RecordFile = serde_json::from_str(text)?;
+        let team_a = parse_showdown_team_in(dex, &file.team_a).context("team_a")?;
+        let team_b = parse_showdown_team_in(dex, &file.team_b).context("team_b")?;
+        if team_a.is_empty() || team_b.is_empty() {
+            bail!("both teams need at least one Pokémon");
+        }
+        let turns = file
+            .turns
+            .iter()
+            .enumerate()
+            .map(|(idx, [a, b])| {
+                let parse = |choice: &str| parse_choice(choice).with_context(|| format!("turn {}", idx + 1));
+                Ok([parse(a)?, parse(b)?])
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { team_a, team_b, seed: file.seed, turns })
+    }
+
+    pub fn load(dex: &Dex, path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+        Self::from_json(dex, &text).with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    /// The record in [`from_json`](Self::from_json)'s format.
+    pub fn to_json(&self) -> String {
+        let file = RecordFile {
+            team_a: export_team(&self.team_a.iter().map(Pokemon::to_set).collect::<Vec<_>>()),
+            team_b: export_team(&self.team_b.iter().map(Pokemon::to_set).collect::<Vec<_>>()),
+            seed: self.seed,
+            turns: self.turns.iter().map(|turn| turn.map(format_choice)).collect(),
+        };
+        serde_json::to_string_pretty(&file).expect("records serialize")
+    }
+}
+
+/// `move <n>` (move slot, from 1) or `switch <n>` (bench slot, from 1), like Showdown's
+/// `/choose`.
+pub fn parse_choice(text: &str) -> Result<Action> {
+    let mut words = text.split_whitespace();
+    let (kind, slot) = (words.next().unwrap_or(""), words.next().and_then(|n| n.parse::<usize>().ok()));
+    match (kind.to_ascii_lowercase().as_str(), slot, words.next()) {
+        ("move", Some(n), None) if n >= 1 => Ok(Action::Move(n - 1)),
+        ("switch", Some(n), None) if n >= 1 => Ok(Action::Switch(n - 1)),
+        _ => Err(anyhow!("invalid choice '{}': expected 'move <n>' or 'switch <n>'", text)),
+    }
+}
+
+/// The inverse of [`parse_choice`].
+pub fn format_choice(action: Action) -> String {
+    match action {
+        Action::Move(idx) => format!("move {}", idx + 1),
+        Action::Switch(idx) => format!("switch {}", idx + 1),
+    }
+}
+
+/// How each turn is searched and judged.
+#[derive(Clone, Copy, Debug)]
+pub struct ReviewParams {
+    pub search: SearchParams,
+    /// Expected score lost (scores run from -1, a loss, to 1, a win) from which a choice
+    /// is a blunder.
+    pub threshold: f64,
+}
+
+impl Default for ReviewParams {
+    fn default() -> Self {
+        Self { search: SearchParams::default(), threshold: 0.1 }
+    }
+}
+
+/// One player's choice on one turn, scored from that player's side.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Decision {
+    pub chosen: String,
+    pub chosen_value: f64,
+    pub best: String,
+    pub best_value: f64,
+    /// `best_value - chosen_value`.
+    pub loss: f64,
+    pub blunder: bool,
+    /// Every legal action with its expected score, best first.
+    pub alternatives: Vec<Alternative>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Alternative {
+    pub action: String,
+    pub value: f64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TurnReview {
+    pub turn: usize,
+    /// Expected score for player A under both equilibrium strategies, before the turn.
+    pub value: f64,
+    /// Player A's and player B's decisions; `None` when a player had only one legal action.
+    pub decisions: [Option<Decision>; 2],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Review {
+    pub seed: u64,
+    pub threshold: f64,
+    /// `TeamAWins`, `TeamBWins` or `Draw`; `None` when the record stops before the end.
+    pub result: Option<String>,
+    pub turns: Vec<TurnReview>,
+}
+
+/// Replay `record` and search every turn's position with `params` and `evaluator`.
+/// Fails on a choice that is not legal where it was made, or on turns after the end.
+pub fn review(record: &BattleRecord, params: ReviewParams, evaluator: &dyn Evaluator) -> Result<Review> {
+    let mut engine = BattleEngine::new(&record.team_a, &record.team_b, record.seed);
+    let mut turns = Vec::new();
+    for (idx, &[action_a, action_b]) in record.turns.iter().enumerate() {
+        let turn = idx + 1;
+        if engine.is_terminal() {
+            bail!("the battle is over before turn {}", turn);
+        }
+        for (player, action) in [(Player::A, action_a), (Player::B, action_b)] {
+            if !engine.legal_actions(player).contains(&action) {
+                bail!("turn {}: '{}' is not legal for player {:?}", turn, format_choice(action), player);
+            }
+        }
+        let analysis = expectiminimax::analyze(engine.state(), params.search, evaluator);
+        let state = engine.state();
+        // Each action's expected score against the other side's equilibrium strategy.
+        let values_a: Vec<f64> = analysis
+            .payoff
+            .iter()
+            .map(|row| row.iter().zip(&analysis.strategy_b).map(|(x, p)| x * p).sum())
+            .collect();
+        let values_b: Vec<f64> = (0..analysis.actions_b.len())
+            .map(|col| -analysis.payoff.iter().zip(&analysis.strategy_a).map(|(row, p)| row[col] * p).sum::<f64>())
+            .collect();
+        let decisions = [
+            decide(state, Player::A, &analysis.actions_a, &values_a, action_a, params.threshold),
+            decide(state, Player::B, &analysis.actions_b, &values_b, action_b, params.threshold),
+        ];
+        turns.push(TurnReview { turn, value: analysis.value, decisions });
+        silently(|| engine.step(action_a, action_b));
+    }
+    let result = engine.outcome().map(|result| {
+        match result {
+            BattleResult::TeamAWins => "TeamAWins",
+            BattleResult::TeamBWins => "TeamBWins",
+            BattleResult::Draw => "Draw",
+        }
+        .to_string()
+    });
+    Ok(Review { seed: record.seed, threshold: params.threshold, result, turns })
+}
+
+fn decide(state: &BattleState, player: Player, actions: &[Action], values: &[f64], chosen: Action, threshold: f64) -> Option<Decision> {
+    if actions.len() <= 1 {
+        return None;
+    }
+    let mut alternatives: Vec<Alternative> = actions
+        .iter()
+        .zip(values)
+        .map(|(&action, &value)| Alternative { action: describe(state, player, action), value })
+        .collect();
+    let chosen_value = values[actions.iter().position(|&action| action == chosen)?];
+    let chosen = describe(state, player, chosen);
+    alternatives.sort_by(|a, b| b.value.total_cmp(&a.value));
+    let (best, best_value) = (alternatives[0].action.clone(), alternatives[0].value);
+    let loss = (best_value - chosen_value).max(0.0);
+    Some(Decision { chosen, chosen_value, best, best_value, loss, blunder: loss >= threshold, alternatives })
+}
+
+/// `Earthquake` / `switch Rotom-Wash`.
+fn describe(state: &BattleState, player: Player, action: Action) -> String {
+    let (active, bench) = match player {
+        Player::A => (&state.pokemon_a, &state.bench_a),
+        Player::B => (&state.pokemon_b, &state.bench_b),
+    };
+    match action {
+        Action::Move(idx) => active
+            .moves
+            .get(idx)
+            .map(|name| state.dex.get_move(name).map_or(name.clone(), |data| data.name.to_string()))
+            .unwrap_or_else(|| format_choice(action)),
+        Action::Switch(idx) => match bench.get(idx) {
+            Some(pokemon) => format!("switch {}", pokemon.species),
+            None => format_choice(action),
+        },
+    }
+}
+
+impl Review {
+    /// Every blunder as (turn, player, decision).
+    pub fn blunders(&self) -> impl Iterator<Item = (usize, Player, &Decision)> + '_ {
+        self.turns.iter().flat_map(|turn| {
+            [Player::A, Player::B]
+                .into_iter()
+                .zip(&turn.decisions)
+                .filter_map(move |(player, decision)| decision.as_ref().filter(|d| d.blunder).map(|d| (turn.turn, player, d)))
+        })
+    }
+
+    /// Expected score each player gave away over the battle.
+    pub fn total_loss(&self) -> [f64; 2] {
+        let mut total = [0.0; 2];
+        for turn in &self.turns {
+            for (side, decision) in turn.decisions.iter().enumerate() {
+                total[side] += decision.as_ref().map_or(0.0, |d| d.loss);
+            }
+        }
+        total
+    }
+
+    fn summary(&self) -> Value {
+        let blunders: Vec<Value> = self
+            .blunders()
+            .map(|(turn, player, d)| json!({ "turn": turn, "player": format!("{:?}", player), "chosen": d.chosen, "best": d.best, "loss": d.loss }))
+            .collect();
+        let [loss_a, loss_b] = self.total_loss();
+        json!({
+            "seed": self.seed,
+            "result": self.result,
+            "turns": self.turns.len(),
+            "threshold": self.threshold,
+            "total_loss": { "A": loss_a, "B": loss_b },
+            "blunders": blunders,
+        })
+    }
+
+    /// The summary and every turn's decisions.
+    pub fn to_json(&self) -> Value {
+        json!({ "summary": self.summary(), "turns": self.turns })
+    }
+
+    pub fn to_html(&self) -> String {
+        let mut body = String::new();
+        body.push_str("<!doctype html><html><head><meta charset=\"utf-8\"/>");
+        body.push_str("<title>Blunder Report</title>");
+        body.push_str(
+            "<style>
+body{font-family:system-ui, -apple-system, Segoe UI, Roboto, sans-serif; margin:24px;}
+code,pre{font-family:ui-monospace, SFMono-Regular, Menlo, Monaco, Consolas, monospace;}
+.ok{color:#0a7;}
+.bad{color:#c33;}
+.card{border:1px solid #ddd; border-radius:10px; padding:12px 14px; margin:12px 0;}
+.turn{margin-top:18px;}
+.grid{display:grid; grid-template-columns:1fr 1fr; gap:12px;}
+.tag{display:inline-block; padding:2px 8px; border-radius:999px; font-size:12px; background:#eee;}
+.tag.bad{background:#fee; color:#a00; border:1px solid #fbb;}
+.tag.ok{background:#efe; color:#060; border:1px solid #bfb;}
+details > summary{cursor:pointer;}
+</style>",
+        );
+        body.push_str("</head><body>");
+        body.push_str("<h1>Blunder Report</h1>");
+        body.push_str("<div class=\"card\"><h2>Summary</h2><pre>");
+        body.push_str(&html_escape(&serde_json::to_string_pretty(&self.summary()).unwrap_or_default()));
+        body.push_str("</pre></div>");
+        let count = self.blunders().count();
+        if count == 0 {
+            body.push_str("<div class=\"card\"><span class=\"tag ok\">NO BLUNDER</span> 悪手は検出されませんでした。</div>");
+        } else {
+            body.push_str(&format!(
+                "<div class=\"card\"><span class=\"tag bad\">BLUNDER</span> 悪手が {} 件検出されました。</div>",
+                count
+            ));
+        }
+
+        for turn in &self.turns {
+            body.push_str(&format!("<div class=\"turn\"><h2>Turn {}</h2>", turn.turn));
+            body.push_str(&format!("<div>評価値 (A): {:+.3}</div>", turn.value));
+            body.push_str("<div class=\"grid\">");
+            for (player, decision) in ["Player A", "Player B"].iter().zip(&turn.decisions) {
+                body.push_str(&format!("<div class=\"card\"><h3>{}</h3>", player));
+                match decision {
+                    None => body.push_str("<span class=\"tag ok\">forced</span>"),
+                    Some(d) => {
+                        let (class, tag) = if d.blunder { ("bad", "BLUNDER") } else { ("ok", "OK") };
+                        body.push_str(&format!("<span class=\"tag {}\">{}</span>", class, tag));
+                        body.push_str(&format!(
+                            "<div>選択: {} ({:+.3})</div><div>最善: {} ({:+.3})</div><div class=\"{}\">損失: {:.3}</div>",
+                            html_escape(&d.chosen),
+                            d.chosen_value,
+                            html_escape(&d.best),
+                            d.best_value,
+                            class,
+                            d.loss
+                        ));
+                        body.push_str("<details><summary>Alternatives</summary><pre>");
+                        for alternative in &d.alternatives {
+                            body.push_str(&html_escape(&format!("{:+.3}  {}\n", alternative.value, alternative.action)));
+                        }
+                        body.push_str("</pre></details>");
+                    }
+                }
+                body.push_str("</div>");
+            }
+            body.push_str("</div></div>");
+        }
+
+        body.push_str("</body></html>");
+        body
+    }
+}
+
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::ai::expectiminimax::HpEvaluator;
+    use crate::sim::stats::Nature;
+
+    fn make(species: &str, moves: &[&str]) -> Pokemon {
+        Pokemon::new(
+            species,
+            50,
+            [0, 252, 0, 252, 0, 0],
+            [31; 6],
+            Nature::Hardy,
+            moves.iter().map(|m| m.to_string()).collect(),
+            "Pressure",
+            None,
+        )
+        .unwrap()
+    }
+
+    fn params() -> ReviewParams {
+        ReviewParams { search: SearchParams { depth: 1, ..SearchParams::default() }, threshold: 0.1 }
+    }
+
+    #[test]
+    fn parses_and_formats_choices() {
+        assert_eq!(parse_choice("move 2").unwrap(), Action::Move(1));
+        assert_eq!(parse_choice(" Switch 1 ").unwrap(), Action::Switch(0));
+        for bad in ["move 0", "move", "run 1", "move 1 2"] {
+            assert!(parse_choice(bad).is_err(), "{}", bad);
+        }
+        assert_eq!(format_choice(Action::Switch(2)), "switch 3");
+    }
+
+    #[test]
+    fn flags_the_choice_that_gives_value_away() {
+        // Growl instead of Earthquake into a Pikachu is a blunder; Earthquake is not.
+        let record = BattleRecord {
+            team_a: vec![make("Garchomp", &["Growl", "Earthquake"])],
+            team_b: vec![make("Pikachu", &["Thunderbolt", "Growl"])],
+            seed: 7,
+            turns: vec![[Action::Move(0), Action::Move(0)], [Action::Move(1), Action::Move(0)]],
+        };
+        let review = review(&record, params(), &HpEvaluator).unwrap();
+        assert_eq!(review.turns.len(), 2);
+        let first = review.turns[0].decisions[0].as_ref().unwrap();
+        assert_eq!((first.chosen.as_str(), first.best.as_str()), ("Growl", "Earthquake"));
+        assert!(first.blunder && first.loss >= 0.1);
+        assert_eq!(first.alternatives[0].action, "Earthquake");
+        let second = review.turns[1].decisions[0].as_ref().unwrap();
+        assert!(!second.blunder && second.loss.abs() < 1e-9);
+        let blunders: Vec<_> = review.blunders().map(|(turn, player, _)| (turn, player)).collect();
+        assert_eq!(blunders, [(1, Player::A)]);
+        assert!(review.total_loss()[0] >= 0.1);
+
+        let json = review.to_json();
+        assert_eq!(json["summary"]["blunders"][0]["chosen"], "Growl");
+        assert_eq!(json["turns"][0]["decisions"][0]["best"], "Earthquake");
+        let html = review.to_html();
+        assert!(html.contains("<h2>Turn 2</h2>") && html.contains("tag bad"));
+    }
+
+    #[test]
+    fn records_round_trip_and_bad_turns_fail() {
+        let record = BattleRecord {
+            team_a: vec![make("Garchomp", &["Earthquake"]), make("Pikachu", &["Thunderbolt"])],
+            team_b: vec![make("Snorlax", &["Tackle"])],
+            seed: 3,
+            turns: vec![[Action::Switch(0), Action::Move(0)]],
+        };
+        let dex = Dex::builtin();
+        let parsed = BattleRecord::from_json(&dex, &record.to_json()).unwrap();
+        assert_eq!(parsed.turns, record.turns);
+        assert_eq!(parsed.team_a.len(), 2);
+        assert_eq!(parsed.team_a[1].species, "Pikachu");
+        let review = review(&parsed, params(), &HpEvaluator).unwrap();
+        // Snorlax has one move: nothing to judge.
+        assert!(review.turns[0].decisions[1].is_none());
+        assert_eq!(review.result, None);
+
+        let illegal = BattleRecord { turns: vec![[Action::Switch(3), Action::Move(0)]], ..record };
+        let err = super::review(&illegal, params(), &HpEvaluator).unwrap_err();
+        assert!(err.to_string().contains("turn 1"));
+    }
+}
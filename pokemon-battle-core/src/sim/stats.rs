@@ -1,6 +1,6 @@
-use crate::data::species::POKEDEX;
+use crate::dex::Dex;
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Nature {
     Hardy,
     Lonely,
@@ -29,6 +29,73 @@ pub enum Nature {
     Quirky,
 }
 
+impl Nature {
+    pub const ALL: [Nature; 25] = [
+        Nature::Hardy,
+        Nature::Lonely,
+        Nature::Brave,
+        Nature::Adamant,
+        Nature::Naughty,
+        Nature::Bold,
+        Nature::Docile,
+        Nature::Relaxed,
+        Nature::Impish,
+        Nature::Lax,
+        Nature::Timid,
+        Nature::Hasty,
+        Nature::Serious,
+        Nature::Jolly,
+        Nature::Naive,
+        Nature::Modest,
+        Nature::Mild,
+        Nature::Quiet,
+        Nature::Bashful,
+        Nature::Rash,
+        Nature::Calm,
+        Nature::Gentle,
+        Nature::Sassy,
+        Nature::Careful,
+        Nature::Quirky,
+    ];
+
+    /// English name as used in exports (`Jolly`).
+    pub fn name(self) -> &'static str {
+        match self {
+            Nature::Hardy => "Hardy",
+            Nature::Lonely => "Lonely",
+            Nature::Brave => "Brave",
+            Nature::Adamant => "Adamant",
+            Nature::Naughty => "Naughty",
+            Nature::Bold => "Bold",
+            Nature::Docile => "Docile",
+            Nature::Relaxed => "Relaxed",
+            Nature::Impish => "Impish",
+            Nature::Lax => "Lax",
+            Nature::Timid => "Timid",
+            Nature::Hasty => "Hasty",
+            Nature::Serious => "Serious",
+            Nature::Jolly => "Jolly",
+            Nature::Naive => "Naive",
+            Nature::Modest => "Modest",
+            Nature::Mild => "Mild",
+            Nature::Quiet => "Quiet",
+            Nature::Bashful => "Bashful",
+            Nature::Rash => "Rash",
+            Nature::Calm => "Calm",
+            Nature::Gentle => "Gentle",
+            Nature::Sassy => "Sassy",
+            Nature::Careful => "Careful",
+            Nature::Quirky => "Quirky",
+        }
+    }
+
+    /// Case-insensitive lookup by English name.
+    pub fn from_name(name: &str) -> Option<Nature> {
+        let name = name.trim();
+        Self::ALL.into_iter().find(|n| n.name().eq_ignore_ascii_case(name))
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub enum Stat {
     Hp,
@@ -90,7 +157,7 @@ pub fn calc_stat(base: u16, iv: u8, ev: u8, level: u8, nature_mod: f32) -> u16 {
     stat.floor() as u16
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct StatsSet {
     pub hp: u16,
     pub atk: u16,
@@ -108,8 +175,19 @@ impl StatsSet {
         ivs: [u8; 6],
         nature: Nature,
     ) -> Option<Self> {
-        let id = normalize_id(species);
-        let data = POKEDEX.get(id.as_str())?;
+        Self::from_species_in(&Dex::builtin(), species, level, evs, ivs, nature)
+    }
+
+    /// [`StatsSet::from_species`] with base stats looked up in `dex`.
+    pub fn from_species_in(
+        dex: &Dex,
+        species: &str,
+        level: u8,
+        evs: [u8; 6],
+        ivs: [u8; 6],
+        nature: Nature,
+    ) -> Option<Self> {
+        let data = dex.species(species)?;
         let base = data.base_stats;
         Some(Self {
             hp: calc_hp(base.hp as u16, ivs[0], evs[0], level),
@@ -122,13 +200,6 @@ impl StatsSet {
     }
 }
 
-fn normalize_id(name: &str) -> String {
-    name.to_ascii_lowercase()
-        .chars()
-        .filter(|c| c.is_ascii_alphanumeric())
-        .collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
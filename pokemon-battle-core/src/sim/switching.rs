@@ -1,6 +1,5 @@
 use crate::sim::pokemon::Pokemon;
-use rand::rngs::SmallRng;
-use rand::Rng;
+use crate::sim::chance::Chance;
 
 // Showdown reference (switching / forced switch / trapping):
 // - pokemon-showdown/sim/battle-actions.ts: forceSwitch / selfSwitch behavior is implemented around move resolution
@@ -43,7 +42,7 @@ pub fn clear_trap(target: &mut Pokemon) {
     target.trapped = false;
 }
 
-pub fn pick_random_switch(bench: &[Pokemon], rng: &mut SmallRng) -> Option<usize> {
+pub fn pick_random_switch(bench: &[Pokemon], rng: &mut dyn Chance) -> Option<usize> {
     let options: Vec<usize> = bench
         .iter()
         .enumerate()
@@ -52,7 +51,7 @@ pub fn pick_random_switch(bench: &[Pokemon], rng: &mut SmallRng) -> Option<usize
     if options.is_empty() {
         return None;
     }
-    Some(options[rng.gen_range(0..options.len())])
+    Some(options[rng.roll(options.len() as u32) as usize])
 }
 
 #[cfg(test)]
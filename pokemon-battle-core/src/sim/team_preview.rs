@@ -0,0 +1,173 @@
+//! Team preview: both players see each other's species, then pick a lead and the
+//! Pokémon they bring (e.g. 3 of 6 in Battle Stadium Singles).
+//! Showdown参照: pokemon-showdown/sim/battle.ts (`makeRequest('teampreview')`, `chooseTeam`)
+
+use crate::battle_logger::BattleLogger;
+use crate::sim::pokemon::Pokemon;
+use anyhow::{anyhow, bail, Result};
+
+/// A team choice: indices into the full team, lead first. Showdown writes it as `team 314`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TeamChoice {
+    pub order: Vec<usize>,
+}
+
+impl TeamChoice {
+    pub fn lead(&self) -> usize {
+        self.order[0]
+    }
+
+    /// Showdown's `team` command (1-based slots).
+    pub fn to_command(&self) -> String {
+        let slots: String = self.order.iter().map(|idx| (idx + 1).to_string()).collect();
+        format!("team {}", slots)
+    }
+
+    /// Parse `team 314` / `314` / `3,1,4` (1-based slots) for a team of `team_len`
+    /// bringing `bring`. The choice must be legal (see [`legal_team_choices`]).
+    pub fn parse(command: &str, team_len: usize, bring: usize) -> Result<Self> {
+        let body = command.trim();
+        let body = body.strip_prefix("team").unwrap_or(body).trim();
+        let slots: Vec<&str> = if body.contains([',', ' ']) {
+            body.split([',', ' ']).filter(|s| !s.is_empty()).collect()
+        } else {
+            body.split("").filter(|s| !s.is_empty()).collect()
+        };
+        let order = slots
+            .iter()
+            .map(|slot| {
+                slot.parse::<usize>()
+                    .ok()
+                    .filter(|n| (1..=team_len).contains(n))
+                    .map(|n| n - 1)
+                    .ok_or_else(|| anyhow!("'{}' is not a team slot (1-{})", slot, team_len))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let choice = Self { order };
+        choice.check(team_len, bring)?;
+        Ok(choice.normalized())
+    }
+
+    /// Fail unless this choice brings `bring` distinct members of a team of `team_len`.
+    pub fn check(&self, team_len: usize, bring: usize) -> Result<()> {
+        if self.order.len() != bring {
+            bail!("choose {} Pokémon (got {})", bring, self.order.len());
+        }
+        for (pos, idx) in self.order.iter().enumerate() {
+            if *idx >= team_len {
+                bail!("team slot {} does not exist", idx + 1);
+            }
+            if self.order[..pos].contains(idx) {
+                bail!("team slot {} is chosen twice", idx + 1);
+            }
+        }
+        Ok(())
+    }
+
+    // The bench order does not matter in singles; keep it sorted so equal picks compare equal.
+    fn normalized(mut self) -> Self {
+        self.order[1..].sort_unstable();
+        self
+    }
+
+    /// The chosen Pokémon from `team`, lead first.
+    pub fn select(&self, team: &[Pokemon]) -> Vec<Pokemon> {
+        self.order.iter().map(|&idx| team[idx].clone()).collect()
+    }
+}
+
+/// Every distinct choice of a lead plus `bring - 1` other members of a team of `team_len`.
+pub fn legal_team_choices(team_len: usize, bring: usize) -> Vec<TeamChoice> {
+    let bring = bring.min(team_len);
+    let mut choices = Vec::new();
+    if bring == 0 {
+        return choices;
+    }
+    for lead in 0..team_len {
+        let rest: Vec<usize> = (0..team_len).filter(|&idx| idx != lead).collect();
+        for_each_combination(&rest, bring - 1, &mut Vec::new(), &mut |bench| {
+            let mut order = vec![lead];
+            order.extend_from_slice(bench);
+            choices.push(TeamChoice { order });
+        });
+    }
+    choices
+}
+
+fn for_each_combination(pool: &[usize], k: usize, current: &mut Vec<usize>, f: &mut impl FnMut(&[usize])) {
+    if current.len() == k {
+        f(current);
+        return;
+    }
+    for (pos, &idx) in pool.iter().enumerate() {
+        current.push(idx);
+        for_each_combination(&pool[pos + 1..], k, current, f);
+        current.pop();
+    }
+}
+
+/// What one player sees at team preview.
+#[derive(Clone, Debug)]
+pub struct TeamPreview {
+    /// The player's own full team.
+    pub team: Vec<Pokemon>,
+    /// Species the opponent might bring, in team order.
+    pub opponent_species: Vec<String>,
+    /// How many Pokémon to bring.
+    pub bring: usize,
+}
+
+impl TeamPreview {
+    pub fn new(team: &[Pokemon], opponent: &[Pokemon], bring: usize) -> Self {
+        Self {
+            team: team.to_vec(),
+            opponent_species: opponent.iter().map(|p| p.species.clone()).collect(),
+            bring: bring.min(team.len()),
+        }
+    }
+
+    pub fn legal_choices(&self) -> Vec<TeamChoice> {
+        legal_team_choices(self.team.len(), self.bring)
+    }
+}
+
+/// Log the `|clearpoke` / `|poke|` / `|teampreview|` lines for both teams.
+pub fn log_team_preview(logger: &mut BattleLogger, team_a: &[Pokemon], team_b: &[Pokemon], bring: usize) {
+    logger.log_clear_poke();
+    for (player, team) in [("p1", team_a), ("p2", team_b)] {
+        for pokemon in team {
+            let details = pokemon.showdown_details().replace(", shiny", "");
+            logger.log_poke(player, &details, pokemon.item.is_some());
+        }
+    }
+    let whole_team = bring >= team_a.len().max(team_b.len());
+    logger.log_team_preview((!whole_team).then_some(bring));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_leads_times_subsets() {
+        assert_eq!(legal_team_choices(6, 3).len(), 6 * 10);
+        assert_eq!(legal_team_choices(6, 4).len(), 6 * 10);
+        assert_eq!(legal_team_choices(6, 6).len(), 6);
+        assert_eq!(legal_team_choices(2, 3).len(), 2);
+        let choices = legal_team_choices(4, 2);
+        assert!(choices.contains(&TeamChoice { order: vec![3, 0] }));
+        assert!(choices.iter().all(|c| c.check(4, 2).is_ok()));
+    }
+
+    #[test]
+    fn parses_showdown_team_commands() {
+        let choice = TeamChoice::parse("team 416", 6, 3).unwrap();
+        assert_eq!(choice.order, vec![3, 0, 5]);
+        assert_eq!(choice.lead(), 3);
+        assert_eq!(choice.to_command(), "team 416");
+        assert_eq!(TeamChoice::parse("4, 6, 1", 6, 3).unwrap(), choice);
+        assert!(TeamChoice::parse("team 41", 6, 3).is_err());
+        assert!(TeamChoice::parse("team 447", 6, 3).is_err());
+        assert!(TeamChoice::parse("team 44 1", 6, 3).is_err());
+    }
+}
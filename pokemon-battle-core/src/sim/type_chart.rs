@@ -0,0 +1,142 @@
+//! Per-battle type charts: generation-specific charts and Inverse Battles.
+//!
+//! The Gen 6+ chart is the generated `data::types::EFFECTIVENESS` table; the
+//! older charts are expressed as differences from it, matching Showdown's
+//! `data/mods/gen1/typechart.ts` and `data/mods/gen5/typechart.ts`.
+
+use crate::data::types::{effectiveness_against, Type};
+
+/// Which generation's chart to use.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ChartGeneration {
+    /// No Dark/Steel/Fairy; Bug and Poison hit each other super effectively,
+    /// Ghost does not affect Psychic and Fire does not resist Ice.
+    Gen1,
+    /// No Fairy; Steel resists Ghost and Dark.
+    Gen2To5,
+    #[default]
+    Gen6,
+}
+
+/// Type chart used by a battle (see `BattleState::type_chart`).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct TypeChart {
+    pub generation: ChartGeneration,
+    /// Inverse Battle: every matchup is flipped, immunities become super effective.
+    pub inverse: bool,
+}
+
+impl TypeChart {
+    pub const GEN1: TypeChart = TypeChart { generation: ChartGeneration::Gen1, inverse: false };
+    pub const GEN2_5: TypeChart = TypeChart { generation: ChartGeneration::Gen2To5, inverse: false };
+    pub const GEN6: TypeChart = TypeChart { generation: ChartGeneration::Gen6, inverse: false };
+
+    /// Standard chart of generation `gen` (1-9).
+    pub fn for_generation(gen: u8) -> Self {
+        match gen {
+            0 | 1 => Self::GEN1,
+            2..=5 => Self::GEN2_5,
+            _ => Self::GEN6,
+        }
+    }
+
+    /// The same chart used as an Inverse Battle.
+    pub fn inverse(self) -> Self {
+        Self { inverse: true, ..self }
+    }
+
+    /// Multiplier of an `attacking` move against a single `defending` type.
+    pub fn effectiveness(&self, attacking: Type, defending: Type) -> f32 {
+        let base = self.base_effectiveness(attacking, defending);
+        if !self.inverse {
+            return base;
+        }
+        // PS: data/mods/inverse — a type immunity counts as super effective.
+        if base == 0.0 {
+            2.0
+        } else {
+            1.0 / base
+        }
+    }
+
+    /// Multiplier against a Pokémon's `[type1, type2]`. Mono-type Pokémon carry their
+    /// type in both slots, so a repeated type is only counted once.
+    pub fn effectiveness_dual(&self, attacking: Type, type1: Type, type2: Type) -> f32 {
+        let first = self.effectiveness(attacking, type1);
+        if type2 == type1 {
+            return first;
+        }
+        first * self.effectiveness(attacking, type2)
+    }
+
+    fn base_effectiveness(&self, attacking: Type, defending: Type) -> f32 {
+        match self.generation {
+            ChartGeneration::Gen6 => effectiveness_against(attacking, defending),
+            ChartGeneration::Gen2To5 => {
+                if attacking == Type::Fairy || defending == Type::Fairy {
+                    return 1.0;
+                }
+                if defending == Type::Steel && matches!(attacking, Type::Ghost | Type::Dark) {
+                    return 0.5;
+                }
+                effectiveness_against(attacking, defending)
+            }
+            ChartGeneration::Gen1 => {
+                let missing = [Type::Dark, Type::Steel, Type::Fairy];
+                if missing.contains(&attacking) || missing.contains(&defending) {
+                    return 1.0;
+                }
+                match (attacking, defending) {
+                    (Type::Bug, Type::Poison) | (Type::Poison, Type::Bug) => 2.0,
+                    (Type::Ghost, Type::Psychic) => 0.0,
+                    (Type::Ice, Type::Fire) => 1.0,
+                    _ => effectiveness_against(attacking, defending),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gen6_matches_generated_table() {
+        let chart = TypeChart::default();
+        assert_eq!(chart.effectiveness(Type::Dragon, Type::Fairy), 0.0);
+        assert_eq!(chart.effectiveness(Type::Ghost, Type::Steel), 1.0);
+        assert_eq!(chart.effectiveness_dual(Type::Ice, Type::Dragon, Type::Flying), 4.0);
+        assert_eq!(chart.effectiveness_dual(Type::Fire, Type::Grass, Type::Grass), 2.0);
+    }
+
+    #[test]
+    fn older_generations() {
+        assert_eq!(TypeChart::GEN2_5.effectiveness(Type::Dark, Type::Steel), 0.5);
+        assert_eq!(TypeChart::GEN2_5.effectiveness(Type::Dragon, Type::Fairy), 1.0);
+        assert_eq!(TypeChart::GEN1.effectiveness(Type::Ghost, Type::Psychic), 0.0);
+        assert_eq!(TypeChart::GEN1.effectiveness(Type::Bug, Type::Poison), 2.0);
+        assert_eq!(TypeChart::GEN1.effectiveness(Type::Ice, Type::Fire), 1.0);
+        assert_eq!(TypeChart::for_generation(4), TypeChart::GEN2_5);
+        assert_eq!(TypeChart::for_generation(9), TypeChart::GEN6);
+    }
+
+    #[test]
+    fn inverse_flips_matchups_and_immunities() {
+        let chart = TypeChart::GEN6.inverse();
+        assert_eq!(chart.effectiveness(Type::Fire, Type::Grass), 0.5);
+        assert_eq!(chart.effectiveness(Type::Fire, Type::Water), 2.0);
+        assert_eq!(chart.effectiveness(Type::Normal, Type::Ghost), 2.0);
+        assert_eq!(chart.effectiveness(Type::Ground, Type::Flying), 2.0);
+        assert_eq!(chart.effectiveness_dual(Type::Ice, Type::Dragon, Type::Flying), 0.25);
+    }
+
+    #[test]
+    fn typeless_and_stellar_are_neutral() {
+        for chart in [TypeChart::GEN1, TypeChart::GEN2_5, TypeChart::GEN6, TypeChart::GEN6.inverse()] {
+            assert_eq!(chart.effectiveness(Type::Typeless, Type::Ghost), 1.0);
+            assert_eq!(chart.effectiveness(Type::Fighting, Type::Typeless), 1.0);
+            assert_eq!(chart.effectiveness(Type::Stellar, Type::Steel), 1.0);
+        }
+    }
+}
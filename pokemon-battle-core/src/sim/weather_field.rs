@@ -54,6 +54,7 @@ fn is_always_hit_move(normalized_move: &str) -> bool {
     )
 }
 
+#[allow(clippy::collapsible_match)]
 pub fn field_damage_modifier(
     field: Option<Field>,
     attacker: &Pokemon,
@@ -102,6 +103,7 @@ pub fn weather_speed_multiplier(pokemon: &Pokemon, weather: Option<Weather>) ->
     }
 }
 
+#[allow(clippy::question_mark)]
 pub fn weather_residual_damage(pokemon: &Pokemon, weather: Option<Weather>) -> Option<(u16, Weather)> {
     let Some(weather) = weather else {
         return None;
@@ -155,12 +157,14 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::needless_borrow)]
     fn thunder_hits_in_rain() {
         let thunder = get_move("thunder").expect("thunder");
         assert_eq!(effective_accuracy(&thunder, Some(Weather::Rain)), Some(100.0));
     }
 
     #[test]
+    #[allow(clippy::needless_borrow)]
     fn blizzard_hits_in_hail() {
         let blizzard = get_move("blizzard").expect("blizzard");
         assert_eq!(effective_accuracy(&blizzard, Some(Weather::Hail)), Some(100.0));
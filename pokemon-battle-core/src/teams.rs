@@ -0,0 +1,441 @@
+//! Team library: named team files with metadata, loaded from files or whole directories.
+//!
+//! A team file is either
+//! - JSON: a bare export string (the name comes from the file name), an object
+//!   `{ "name", "format", "author", "tags", "team" }` where `team` is an export string or
+//!   an array of JSON sets, an array of such objects, or the legacy `{ "team_a", "team_b" }` pair;
+//! - text: a Showdown export, optionally split into several teams by teambuilder
+//!   backup headers (`=== [gen9ou] Team Name ===`).
+//!
+//! Every team is validated on load with [`check_showdown_team`].
+
+use crate::dex::{to_id, Dex};
+use crate::parser::{check_showdown_team, parse_json_sets, Diagnostic, ParseOptions, Severity, TeamParseError};
+use crate::sets::{export_team, PokemonSet};
+use crate::sim::pokemon::Pokemon;
+use anyhow::{anyhow, bail, Context, Result};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Largest team a file may hold.
+pub const MAX_TEAM_SIZE: usize = 6;
+
+/// Descriptive fields stored next to a team.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TeamMeta {
+    pub name: String,
+    /// Format id, e.g. `gen9bss`.
+    pub format: Option<String>,
+    pub author: Option<String>,
+    pub tags: Vec<String>,
+}
+
+/// A validated team.
+#[derive(Clone, Debug)]
+pub struct Team {
+    pub meta: TeamMeta,
+    /// The team as a Showdown export.
+    pub text: String,
+    pub sets: Vec<PokemonSet>,
+    /// Warnings reported while validating (errors make loading fail).
+    pub warnings: Vec<Diagnostic>,
+    /// File the team was loaded from.
+    pub source: Option<PathBuf>,
+}
+
+impl Team {
+    /// Validate an export and wrap it with `meta`.
+    pub fn from_export(dex: &Dex, meta: TeamMeta, text: &str, options: ParseOptions) -> Result<Self> {
+        let parsed = check_showdown_team(dex, text);
+        let failures = parsed.failures(options);
+        if !failures.is_empty() {
+            return Err(anyhow::Error::new(TeamParseError { diagnostics: failures }))
+                .with_context(|| format!("team '{}' is invalid", meta.name));
+        }
+        if parsed.sets.is_empty() {
+            bail!("team '{}' has no Pokémon", meta.name);
+        }
+        if parsed.sets.len() > MAX_TEAM_SIZE {
+            bail!("team '{}' has {} Pokémon (at most {})", meta.name, parsed.sets.len(), MAX_TEAM_SIZE);
+        }
+        let warnings = parsed.diagnostics.into_iter().filter(|d| d.severity == Severity::Warning).collect();
+        Ok(Self { meta, text: text.to_string(), sets: parsed.sets, warnings, source: None })
+    }
+
+    /// Lookup key: the name as an id (`"Cynthia"` → `cynthia`).
+    pub fn id(&self) -> String {
+        to_id(&self.meta.name)
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.meta.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+
+    /// Build the battle-ready Pokémon, resolving data in `dex`.
+    pub fn pokemon(&self, dex: &Dex) -> Result<Vec<Pokemon>> {
+        self.sets
+            .iter()
+            .map(|set| Pokemon::from_set_in(dex, set).with_context(|| format!("team '{}'", self.meta.name)))
+            .collect()
+    }
+}
+
+/// Teams that face each other in a legacy `{ "team_a", "team_b" }` file.
+#[derive(Clone, Debug)]
+pub struct TeamsFile {
+    pub team_a: Team,
+    pub team_b: Team,
+}
+
+/// Load a legacy `{ "team_a", "team_b" }` file (e.g. the root `teams.json`).
+pub fn load_teams(dex: &Dex, path: impl AsRef<Path>) -> Result<TeamsFile> {
+    let path = path.as_ref();
+    let mut teams = load_team_file(dex, path, ParseOptions::default())?.into_iter();
+    match (teams.next(), teams.next(), teams.next()) {
+        (Some(team_a), Some(team_b), None) if team_a.meta.name == "team_a" => Ok(TeamsFile { team_a, team_b }),
+        _ => bail!("{} is not a team_a/team_b file", path.display()),
+    }
+}
+
+/// Load every team in one file. Teams without a name are named after the file.
+pub fn load_team_file(dex: &Dex, path: impl AsRef<Path>, options: ParseOptions) -> Result<Vec<Team>> {
+    let path = path.as_ref();
+    let text = fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("team");
+    let is_json = path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+    let teams = if is_json {
+        parse_team_json(dex, &text, stem, options)
+    } else {
+        parse_team_text(dex, &text, stem, options)
+    };
+    let mut teams = teams.with_context(|| format!("failed to load {}", path.display()))?;
+    for team in &mut teams {
+        team.source = Some(path.to_path_buf());
+    }
+    Ok(teams)
+}
+
+/// Parse the JSON form of a team file (see the module docs).
+pub fn parse_team_json(dex: &Dex, text: &str, default_name: &str, options: ParseOptions) -> Result<Vec<Team>> {
+    let root: Value = serde_json::from_str(text).context("team file is not valid JSON")?;
+    match &root {
+        Value::String(export) => Ok(vec![Team::from_export(dex, named(default_name), export, options)?]),
+        Value::Array(entries) => entries
+            .iter()
+            .enumerate()
+            .map(|(idx, entry)| {
+                team_from_object(dex, entry, &format!("{}-{}", default_name, idx + 1), options)
+                    .with_context(|| format!("team entry {}", idx + 1))
+            })
+            .collect(),
+        Value::Object(map) if map.contains_key("team_a") || map.contains_key("team_b") => ["team_a", "team_b"]
+            .iter()
+            .map(|key| {
+                let export = map
+                    .get(*key)
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| anyhow!("'{}' must be a team export string", key))?;
+                Team::from_export(dex, named(key), export, options)
+            })
+            .collect(),
+        Value::Object(_) => Ok(vec![team_from_object(dex, &root, default_name, options)?]),
+        _ => bail!("a team file must hold a string, an object or an array"),
+    }
+}
+
+fn named(name: &str) -> TeamMeta {
+    TeamMeta { name: name.to_string(), ..TeamMeta::default() }
+}
+
+fn team_from_object(dex: &Dex, entry: &Value, default_name: &str, options: ParseOptions) -> Result<Team> {
+    let text = |key: &str| -> Result<Option<String>> {
+        match entry.get(key) {
+            None | Some(Value::Null) => Ok(None),
+            Some(Value::String(s)) if s.trim().is_empty() => Ok(None),
+            Some(Value::String(s)) => Ok(Some(s.trim().to_string())),
+            Some(_) => bail!("'{}' must be a string", key),
+        }
+    };
+    let tags = match entry.get("tags") {
+        None | Some(Value::Null) => Vec::new(),
+        Some(Value::Array(tags)) => tags
+            .iter()
+            .map(|tag| tag.as_str().map(str::to_string).ok_or_else(|| anyhow!("'tags' must be strings")))
+            .collect::<Result<_>>()?,
+        Some(_) => bail!("'tags' must be an array of strings"),
+    };
+    let meta = TeamMeta {
+        name: text("name")?.unwrap_or_else(|| default_name.to_string()),
+        format: text("format")?,
+        author: text("author")?,
+        tags,
+    };
+    let export = match entry.get("team") {
+        Some(Value::String(export)) => export.clone(),
+        Some(sets @ Value::Array(_)) => export_team(&parse_json_sets(&sets.to_string())?),
+        _ => bail!("team '{}' needs a \"team\" export string or array of sets", meta.name),
+    };
+    Team::from_export(dex, meta, &export, options)
+}
+
+/// Parse the text form of a team file: one export, or several under backup headers.
+pub fn parse_team_text(dex: &Dex, text: &str, default_name: &str, options: ParseOptions) -> Result<Vec<Team>> {
+    let mut sections: Vec<(TeamMeta, String)> = Vec::new();
+    for line in text.lines() {
+        if let Some(meta) = backup_header(line) {
+            sections.push((meta, String::new()));
+            continue;
+        }
+        if sections.is_empty() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            sections.push((named(default_name), String::new()));
+        }
+        let body = &mut sections.last_mut().expect("section was just pushed").1;
+        body.push_str(line);
+        body.push('\n');
+    }
+    if sections.is_empty() {
+        bail!("team file is empty");
+    }
+    sections
+        .into_iter()
+        .map(|(meta, body)| Team::from_export(dex, meta, &body, options))
+        .collect()
+}
+
+/// `=== [gen9ou] Team Name ===` (the format part is optional).
+fn backup_header(line: &str) -> Option<TeamMeta> {
+    let inner = line.trim().strip_prefix("===")?.strip_suffix("===")?.trim();
+    let (format, name) = match inner.strip_prefix('[').and_then(|rest| rest.split_once(']')) {
+        Some((format, name)) => (Some(format.trim().to_string()).filter(|f| !f.is_empty()), name.trim()),
+        None => (None, inner),
+    };
+    if name.is_empty() {
+        return None;
+    }
+    Some(TeamMeta { name: name.to_string(), format, ..TeamMeta::default() })
+}
+
+/// Named teams, looked up by id.
+#[derive(Clone, Debug, Default)]
+pub struct TeamLibrary {
+    teams: BTreeMap<String, Team>,
+}
+
+impl TeamLibrary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load every `.json` / `.txt` file under `dir` (recursively).
+    pub fn load_dir(dex: &Dex, dir: impl AsRef<Path>, options: ParseOptions) -> Result<Self> {
+        let mut library = Self::new();
+        library.add_dir(dex, dir.as_ref(), options)?;
+        Ok(library)
+    }
+
+    fn add_dir(&mut self, dex: &Dex, dir: &Path, options: ParseOptions) -> Result<()> {
+        let mut entries: Vec<PathBuf> = fs::read_dir(dir)
+            .with_context(|| format!("failed to read team directory {}", dir.display()))?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<std::io::Result<_>>()?;
+        entries.sort();
+        for path in entries {
+            if path.is_dir() {
+                self.add_dir(dex, &path, options)?;
+                continue;
+            }
+            let is_team_file = path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("json") || ext.eq_ignore_ascii_case("txt"));
+            if is_team_file {
+                for team in load_team_file(dex, &path, options)? {
+                    self.add(team)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Add a team; a name that is already taken is an error.
+    pub fn add(&mut self, team: Team) -> Result<()> {
+        let id = team.id();
+        if id.is_empty() {
+            bail!("team name '{}' has no letters or digits", team.meta.name);
+        }
+        if let Some(existing) = self.teams.get(&id) {
+            bail!(
+                "team '{}' is defined twice{}",
+                team.meta.name,
+                existing.source.as_ref().map(|p| format!(" (first in {})", p.display())).unwrap_or_default()
+            );
+        }
+        self.teams.insert(id, team);
+        Ok(())
+    }
+
+    /// The team named `name` (case, spaces and punctuation are ignored).
+    pub fn get(&self, name: &str) -> Option<&Team> {
+        self.teams.get(&to_id(name))
+    }
+
+    /// Look `name` up, or load it as a file path when no team has that name.
+    pub fn resolve(&self, dex: &Dex, name: &str, options: ParseOptions) -> Result<Team> {
+        if let Some(team) = self.get(name) {
+            return Ok(team.clone());
+        }
+        let path = Path::new(name);
+        if path.is_file() {
+            let mut teams = load_team_file(dex, path, options)?;
+            if teams.len() != 1 {
+                bail!("{} holds {} teams; refer to one by name", path.display(), teams.len());
+            }
+            return Ok(teams.remove(0));
+        }
+        let known: Vec<&str> = self.iter().map(|t| t.meta.name.as_str()).collect();
+        bail!("unknown team '{}' (known: {})", name, if known.is_empty() { "none".to_string() } else { known.join(", ") })
+    }
+
+    /// Teams sorted by id.
+    pub fn iter(&self) -> impl Iterator<Item = &Team> {
+        self.teams.values()
+    }
+
+    pub fn with_tag<'a>(&'a self, tag: &'a str) -> impl Iterator<Item = &'a Team> + 'a {
+        self.iter().filter(move |team| team.has_tag(tag))
+    }
+
+    pub fn len(&self) -> usize {
+        self.teams.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.teams.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXPORT: &str = "Garchomp @ Choice Scarf\nAbility: Rough Skin\n- Earthquake\n- Outrage\n\nRotom-Wash\nAbility: Levitate\n- Hydro Pump\n";
+
+    fn temp_dir(label: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("pokemon-teams-{}-{}", label, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn reads_metadata_objects_and_bare_strings() {
+        let dex = Dex::builtin();
+        let object = serde_json::json!({
+            "name": "Sand Core",
+            "format": "gen9bss",
+            "author": "Volo",
+            "tags": ["sand", "offense"],
+            "team": EXPORT,
+        });
+        let teams = parse_team_json(&dex, &object.to_string(), "file", ParseOptions::default()).unwrap();
+        let team = &teams[0];
+        assert_eq!(team.meta.name, "Sand Core");
+        assert_eq!(team.meta.format.as_deref(), Some("gen9bss"));
+        assert_eq!(team.meta.author.as_deref(), Some("Volo"));
+        assert!(team.has_tag("SAND"));
+        assert_eq!(team.id(), "sandcore");
+        assert_eq!(team.sets.len(), 2);
+
+        let bare = serde_json::to_string(EXPORT).unwrap();
+        let teams = parse_team_json(&dex, &bare, "cynthia", ParseOptions::default()).unwrap();
+        assert_eq!(teams[0].meta.name, "cynthia");
+        assert_eq!(teams[0].pokemon(&dex).unwrap()[1].species, "Rotom-Wash");
+    }
+
+    #[test]
+    fn reads_json_sets_and_legacy_pairs() {
+        let dex = Dex::builtin();
+        let sets = serde_json::json!({ "name": "Solo", "team": [{ "species": "Garchomp", "moves": ["Earthquake"] }] });
+        let team = &parse_team_json(&dex, &sets.to_string(), "x", ParseOptions::default()).unwrap()[0];
+        assert_eq!(team.sets[0].moves, vec!["Earthquake".to_string()]);
+        assert!(team.text.starts_with("Garchomp"));
+
+        let pair = serde_json::json!({ "team_a": EXPORT, "team_b": "Gyarados\n- Waterfall" });
+        let teams = parse_team_json(&dex, &pair.to_string(), "teams", ParseOptions::default()).unwrap();
+        assert_eq!(teams.iter().map(|t| t.meta.name.as_str()).collect::<Vec<_>>(), ["team_a", "team_b"]);
+    }
+
+    #[test]
+    fn splits_backup_headers() {
+        let dex = Dex::builtin();
+        let text = format!("=== [gen9ou] Rain ===\n\n{}\n=== Sun ===\nCharizard\n- Flamethrower\n", EXPORT);
+        let teams = parse_team_text(&dex, &text, "backup", ParseOptions::default()).unwrap();
+        assert_eq!(teams.len(), 2);
+        assert_eq!(teams[0].meta.format.as_deref(), Some("gen9ou"));
+        assert_eq!(teams[1].meta.name, "Sun");
+        assert_eq!(teams[1].meta.format, None);
+        let plain = parse_team_text(&dex, EXPORT, "plain", ParseOptions::default()).unwrap();
+        assert_eq!(plain[0].meta.name, "plain");
+    }
+
+    #[test]
+    fn validates_on_load() {
+        let dex = Dex::builtin();
+        let bad_species = parse_team_text(&dex, "Garchompp\n- Earthquake\n", "bad", ParseOptions::default());
+        let message = format!("{:#}", bad_species.unwrap_err());
+        assert!(message.contains("unknown species 'Garchompp'"), "{}", message);
+
+        let typo = "Garchomp\n- Earthquak\n";
+        let lenient = parse_team_text(&dex, typo, "typo", ParseOptions::default()).unwrap();
+        assert_eq!(lenient[0].warnings.len(), 1);
+        assert!(parse_team_text(&dex, typo, "typo", ParseOptions { strict: true }).is_err());
+
+        let seven = "Pikachu\n- Thunderbolt\n\n".repeat(7);
+        assert!(parse_team_text(&dex, &seven, "seven", ParseOptions::default()).is_err());
+        assert!(parse_team_json(&dex, r#"{"name": "empty"}"#, "x", ParseOptions::default()).is_err());
+    }
+
+    #[test]
+    fn library_loads_directories_and_resolves_names() {
+        let dex = Dex::builtin();
+        let dir = temp_dir("library");
+        fs::create_dir_all(dir.join("champions")).unwrap();
+        fs::write(dir.join("champions/cynthia.json"), serde_json::to_string(EXPORT).unwrap()).unwrap();
+        fs::write(
+            dir.join("leon.json"),
+            serde_json::json!({ "name": "Leon", "tags": ["champion"], "team": "Charizard\n- Flamethrower" }).to_string(),
+        )
+        .unwrap();
+        fs::write(dir.join("notes.md"), "not a team").unwrap();
+        let library = TeamLibrary::load_dir(&dex, &dir, ParseOptions::default()).unwrap();
+        assert_eq!(library.len(), 2);
+        assert_eq!(library.get("LEON").unwrap().meta.name, "Leon");
+        assert!(library.get("Cynthia").unwrap().source.as_ref().unwrap().ends_with("champions/cynthia.json"));
+        assert_eq!(library.with_tag("champion").count(), 1);
+        let by_path = library.resolve(&dex, dir.join("leon.json").to_str().unwrap(), ParseOptions::default());
+        assert_eq!(by_path.unwrap().meta.name, "Leon");
+        let unknown = library.resolve(&dex, "red", ParseOptions::default()).unwrap_err().to_string();
+        assert!(unknown.contains("known: cynthia, Leon"), "{}", unknown);
+
+        fs::write(dir.join("leon2.json"), serde_json::json!({ "name": "leon", "team": EXPORT }).to_string()).unwrap();
+        assert!(TeamLibrary::load_dir(&dex, &dir, ParseOptions::default()).is_err());
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn bundled_teams_load() {
+        let dex = Dex::builtin();
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../teams");
+        let library = TeamLibrary::load_dir(&dex, dir, ParseOptions::default()).unwrap();
+        for name in ["cynthia", "leon"] {
+            let team = library.get(name).unwrap();
+            assert_eq!(team.sets.len(), 6, "{}", name);
+            assert!(team.meta.format.is_some());
+        }
+    }
+}
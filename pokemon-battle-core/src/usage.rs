@@ -0,0 +1,385 @@
+//! Smogon usage statistics.
+//!
+//! Reads the "chaos" JSON files Smogon publishes each month
+//! (`https://www.smogon.com/stats/<month>/chaos/<format>-<cutoff>.json`), stored locally:
+//!
+//! ```json
+//! { "info": { "metagame": "gen9ou", "cutoff": 1695, "number of battles": 1000 },
+//!   "data": { "Garchomp": { "Raw count": 120, "usage": 0.12,
+//!       "Abilities": { "roughskin": 118.0 }, "Items": { "choicescarf": 40.5, ... },
+//!       "Spreads": { "Jolly:0/252/0/0/4/252": 61.2, ... }, "Moves": { "earthquake": 117.9, ... },
+//!       "Teammates": { "Rotom-Wash": 30.1, ... }, "Tera Types": { "steel": 20.0, ... },
+//!       "Checks and Counters": { "Skarmory": [33.0, 0.71, 0.04], ... } } } }
+//! ```
+//!
+//! Counts are weighted by rating, so shares are taken against the species' weighted count
+//! (the sum of its ability counts). Moves, items and abilities are keyed by id, teammates
+//! and checks by species name. [`UsageStats::generate_set`] draws a set piece by piece in
+//! proportion to the stats; [`UsageStats::plausible_sets`] merges many draws into weighted
+//! sets, e.g. for [`crate::sim::belief::CandidatePool::from_usage`].
+
+use crate::dex::{to_id, Dex};
+use crate::sets::PokemonSet;
+use crate::sim::pokemon::{parse_type, Pokemon};
+use crate::sim::stats::Nature;
+use anyhow::{anyhow, bail, Context, Result};
+use rand::distributions::WeightedIndex;
+use rand::prelude::Distribution;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// The chaos file's `info` block.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct UsageInfo {
+    #[serde(default)]
+    pub metagame: String,
+    #[serde(default)]
+    pub cutoff: f64,
+    #[serde(rename = "number of battles", default)]
+    pub battles: u64,
+}
+
+/// How well `species` checks or counters a Pokémon.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Check {
+    pub species: String,
+    /// Matchups seen.
+    pub matchups: f64,
+    /// Share of them in which the Pokémon was knocked out or forced out.
+    pub score: f64,
+    pub deviation: f64,
+}
+
+impl Check {
+    /// Smogon's ranking: the score minus four standard deviations.
+    pub fn rating(&self) -> f64 {
+        self.score - 4.0 * self.deviation
+    }
+}
+
+/// An EV spread with its nature.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Spread {
+    pub nature: Nature,
+    pub evs: [u8; 6],
+}
+
+impl Spread {
+    /// `Jolly:0/252/0/0/4/252`.
+    pub fn parse(text: &str) -> Result<Self> {
+        let (nature, evs) = text.split_once(':').ok_or_else(|| anyhow!("spread '{}': expected <nature>:<evs>", text))?;
+        let nature = Nature::from_name(nature).ok_or_else(|| anyhow!("spread '{}': unknown nature", text))?;
+        let values = evs.split('/').map(str::parse::<u8>).collect::<Result<Vec<_>, _>>();
+        match values {
+            Ok(values) if values.len() == 6 => Ok(Self { nature, evs: values.try_into().expect("six values") }),
+            _ => bail!("spread '{}': expected six EVs from 0 to 255", text),
+        }
+    }
+}
+
+/// One species' stats.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct SpeciesUsage {
+    #[serde(skip)]
+    pub name: String,
+    #[serde(rename = "Raw count", default)]
+    pub raw_count: f64,
+    #[serde(default)]
+    pub usage: f64,
+    #[serde(rename = "Abilities", default)]
+    pub abilities: BTreeMap<String, f64>,
+    #[serde(rename = "Items", default)]
+    pub items: BTreeMap<String, f64>,
+    #[serde(rename = "Spreads", default)]
+    pub spreads: BTreeMap<String, f64>,
+    #[serde(rename = "Moves", default)]
+    pub moves: BTreeMap<String, f64>,
+    #[serde(rename = "Teammates", default)]
+    pub teammates: BTreeMap<String, f64>,
+    #[serde(rename = "Tera Types", default)]
+    pub tera_types: BTreeMap<String, f64>,
+    #[serde(rename = "Checks and Counters", default)]
+    pub checks: BTreeMap<String, Vec<f64>>,
+}
+
+impl SpeciesUsage {
+    /// Weighted count of the species (its ability counts summed), the base of every share.
+    pub fn count(&self) -> f64 {
+        let total: f64 = self.abilities.values().sum();
+        if total > 0.0 {
+            total
+        } else {
+            self.raw_count
+        }
+    }
+
+    /// The `n` most used moves by id, with the share of sets running each. Empty move
+    /// slots (`""`) are left out.
+    pub fn top_moves(&self, n: usize) -> Vec<(String, f64)> {
+        self.top(&self.moves, n)
+    }
+
+    /// Items by id; `nothing` is no item.
+    pub fn top_items(&self, n: usize) -> Vec<(String, f64)> {
+        self.top(&self.items, n)
+    }
+
+    pub fn top_abilities(&self, n: usize) -> Vec<(String, f64)> {
+        self.top(&self.abilities, n)
+    }
+
+    pub fn top_tera_types(&self, n: usize) -> Vec<(String, f64)> {
+        self.top(&self.tera_types, n)
+    }
+
+    /// Species most often on the same team.
+    pub fn top_teammates(&self, n: usize) -> Vec<(String, f64)> {
+        self.top(&self.teammates, n)
+    }
+
+    /// Spreads that parse, most used first.
+    pub fn top_spreads(&self, n: usize) -> Vec<(Spread, f64)> {
+        self.top(&self.spreads, usize::MAX)
+            .into_iter()
+            .filter_map(|(text, share)| Some((Spread::parse(&text).ok()?, share)))
+            .take(n)
+            .collect()
+    }
+
+    /// The `n` best checks and counters by [`Check::rating`].
+    pub fn top_checks(&self, n: usize) -> Vec<Check> {
+        let mut checks: Vec<Check> = self
+            .checks
+            .iter()
+            .filter_map(|(species, values)| match values.as_slice() {
+                [matchups, score, deviation, ..] => Some(Check {
+                    species: species.clone(),
+                    matchups: *matchups,
+                    score: *score,
+                    deviation: *deviation,
+                }),
+                _ => None,
+            })
+            .collect();
+        checks.sort_by(|a, b| b.rating().total_cmp(&a.rating()).then_with(|| a.species.cmp(&b.species)));
+        checks.truncate(n);
+        checks
+    }
+
+    fn top(&self, counts: &BTreeMap<String, f64>, n: usize) -> Vec<(String, f64)> {
+        let total = self.count();
+        let mut shares: Vec<(String, f64)> = counts
+            .iter()
+            .filter(|(key, _)| !key.is_empty())
+            .map(|(key, &count)| (key.clone(), if total > 0.0 { count / total } else { 0.0 }))
+            .collect();
+        shares.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        shares.truncate(n);
+        shares
+    }
+}
+
+#[derive(Deserialize)]
+struct ChaosFile {
+    #[serde(default)]
+    info: UsageInfo,
+    data: BTreeMap<String, SpeciesUsage>,
+}
+
+/// A metagame's usage stats, by species id.
+#[derive(Clone, Debug, Default)]
+pub struct UsageStats {
+    pub info: UsageInfo,
+    pub species: BTreeMap<String, SpeciesUsage>,
+}
+
+impl UsageStats {
+    pub fn from_json(text: &str) -> Result<Self> {
+        let file: ChaosFile = serde_json::from_str(text)?;
+        let mut species = BTreeMap::new();
+        for (name, mut usage) in file.data {
+            for spread in usage.spreads.keys() {
+                Spread::parse(spread).with_context(|| name.clone())?;
+            }
+            usage.name = name.clone();
+            species.insert(to_id(&name), usage);
+        }
+        Ok(Self { info: file.info, species })
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+        Self::from_json(&text).with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.species.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.species.is_empty()
+    }
+
+    /// The stats of `species` (any spelling of its name).
+    pub fn get(&self, species: &str) -> Option<&SpeciesUsage> {
+        self.species.get(&to_id(species))
+    }
+
+    /// The `n` most used species, with their usage share.
+    pub fn top_species(&self, n: usize) -> Vec<(&str, f64)> {
+        let mut top: Vec<(&str, f64)> = self.species.values().map(|usage| (usage.name.as_str(), usage.usage)).collect();
+        top.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        top.truncate(n);
+        top
+    }
+
+    /// A set for `species` drawn from its stats: ability, item, spread and Tera Type each in
+    /// proportion to their counts (`nothing` draws no item), and up to four distinct moves drawn one after another
+    /// the same way. Entries the dex does not know are skipped. Level 50, like parsed sets.
+    pub fn generate_set(&self, dex: &Dex, species: &str, rng: &mut impl Rng) -> Result<PokemonSet> {
+        let usage = self.get(species).ok_or_else(|| anyhow!("no usage stats for '{}'", species))?;
+        let data = dex.species(&usage.name).ok_or_else(|| anyhow!("unknown species '{}'", usage.name))?;
+        let mut set = PokemonSet::new(data.name);
+        set.ability = pick(&usage.abilities, rng, |id| dex.ability(id).map(|a| a.name.to_string()));
+        set.item = pick(&usage.items, rng, |id| match id {
+            "nothing" => Some(None),
+            _ => dex.item(id).map(|i| Some(i.name.to_string())),
+        })
+        .flatten();
+        if let Some(spread) = pick(&usage.spreads, rng, |text| Spread::parse(text).ok()) {
+            set.nature = spread.nature;
+            set.evs = spread.evs;
+        }
+        set.tera_type = pick(&usage.tera_types, rng, parse_type);
+        let mut moves = usage.moves.clone();
+        while set.moves.len() < 4 {
+            let Some((id, name)) = pick(&moves, rng, |id| dex.get_move(id).map(|m| (id.to_string(), m.name.to_string())))
+            else {
+                break;
+            };
+            moves.remove(&id);
+            set.moves.push(name);
+        }
+        if set.moves.is_empty() {
+            bail!("no usable moves in the usage stats for '{}'", usage.name);
+        }
+        Ok(set)
+    }
+
+    /// [`generate_set`](Self::generate_set) built into a Pokémon.
+    pub fn generate(&self, dex: &Dex, species: &str, rng: &mut impl Rng) -> Result<Pokemon> {
+        let set = self.generate_set(dex, species, rng)?;
+        Pokemon::from_set_in(dex, &set).with_context(|| format!("Failed to build '{}'", set.species))
+    }
+
+    /// `draws` generated sets for `species` (deterministic for `seed`), identical ones
+    /// merged, with the share of draws that produced each; most likely first.
+    pub fn plausible_sets(&self, dex: &Dex, species: &str, draws: usize, seed: u64) -> Result<Vec<(PokemonSet, f64)>> {
+        let mut rng = SmallRng::seed_from_u64(seed);
+        let mut sets: Vec<(PokemonSet, f64)> = Vec::new();
+        for _ in 0..draws {
+            let mut set = self.generate_set(dex, species, &mut rng)?;
+            set.moves.sort();
+            match sets.iter_mut().find(|(seen, _)| *seen == set) {
+                Some((_, weight)) => *weight += 1.0 / draws as f64,
+                None => sets.push((set, 1.0 / draws as f64)),
+            }
+        }
+        sets.sort_by(|a, b| b.1.total_cmp(&a.1));
+        Ok(sets)
+    }
+}
+
+/// One key drawn in proportion to its count among those `resolve` accepts.
+fn pick<T>(counts: &BTreeMap<String, f64>, rng: &mut impl Rng, resolve: impl Fn(&str) -> Option<T>) -> Option<T> {
+    let mut options: Vec<(T, f64)> = counts
+        .iter()
+        .filter(|(_, &count)| count > 0.0)
+        .filter_map(|(key, &count)| Some((resolve(key)?, count)))
+        .collect();
+    let index = WeightedIndex::new(options.iter().map(|(_, count)| *count)).ok()?.sample(rng);
+    Some(options.swap_remove(index).0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::belief::CandidatePool;
+
+    const CHAOS: &str = r#"{
+        "info": {"metagame": "gen9ou", "cutoff": 1695, "number of battles": 500},
+        "data": {
+            "Garchomp": {
+                "Raw count": 100, "usage": 0.2,
+                "Abilities": {"roughskin": 90.0, "sandveil": 10.0},
+                "Items": {"choicescarf": 60.0, "rockyhelmet": 40.0},
+                "Spreads": {"Jolly:0/252/0/0/4/252": 70.0, "Impish:252/0/252/0/4/0": 30.0},
+                "Moves": {"earthquake": 100.0, "outrage": 60.0, "stoneedge": 55.0, "stealthrock": 45.0,
+                          "spikes": 40.0, "notamove": 50.0, "": 50.0},
+                "Teammates": {"Rotom-Wash": 30.0, "Clefable": 12.5},
+                "Tera Types": {"steel": 80.0, "fire": 20.0},
+                "Checks and Counters": {"Skarmory": [40.0, 0.7, 0.05], "Rotom-Wash": [50.0, 0.65, 0.02]}
+            },
+            "Rotom-Wash": {"Raw count": 50, "usage": 0.3, "Abilities": {"levitate": 50.0},
+                           "Moves": {"hydropump": 50.0, "voltswitch": 50.0}}
+        }
+    }"#;
+
+    #[test]
+    fn queries_shares_spreads_and_checks() {
+        let stats = UsageStats::from_json(CHAOS).unwrap();
+        assert_eq!((stats.info.metagame.as_str(), stats.info.battles, stats.len()), ("gen9ou", 500, 2));
+        assert_eq!(stats.top_species(1), [("Rotom-Wash", 0.3)]);
+        let chomp = stats.get("garchomp").unwrap();
+        assert_eq!(chomp.count(), 100.0);
+        assert_eq!(chomp.top_moves(2), [("earthquake".to_string(), 1.0), ("outrage".to_string(), 0.6)]);
+        assert_eq!(chomp.top_items(1), [("choicescarf".to_string(), 0.6)]);
+        assert_eq!(chomp.top_abilities(5).len(), 2);
+        assert_eq!(chomp.top_teammates(1), [("Rotom-Wash".to_string(), 0.3)]);
+        assert_eq!(chomp.top_tera_types(1)[0].0, "steel");
+        let spreads = chomp.top_spreads(1);
+        assert_eq!(spreads[0].0, Spread { nature: Nature::Jolly, evs: [0, 252, 0, 0, 4, 252] });
+        let checks = chomp.top_checks(2);
+        // 0.65 - 4 * 0.02 beats 0.7 - 4 * 0.05.
+        assert_eq!(checks[0].species, "Rotom-Wash");
+        assert!((checks[1].rating() - 0.5).abs() < 1e-9);
+
+        assert!(UsageStats::from_json(r#"{"data": {"Mew": {"Spreads": {"Jolly:0/0": 1.0}}}}"#).is_err());
+        assert!(Spread::parse("Bold:252/0/252/0/4/0").is_ok());
+    }
+
+    #[test]
+    fn generates_sets_in_proportion_to_usage() {
+        let stats = UsageStats::from_json(CHAOS).unwrap();
+        let dex = Dex::builtin();
+        let mut rng = SmallRng::seed_from_u64(3);
+        let mut scarves = 0;
+        for _ in 0..200 {
+            let set = stats.generate_set(&dex, "Garchomp", &mut rng).unwrap();
+            assert_eq!(set.species, "Garchomp");
+            assert_eq!(set.moves.len(), 4);
+            assert!(set.moves.iter().all(|name| dex.get_move(name).is_some()));
+            scarves += (set.item.as_deref() == Some("Choice Scarf")) as usize;
+        }
+        assert!((90..150).contains(&scarves), "{}", scarves);
+
+        let rotom = stats.generate(&dex, "Rotom-Wash", &mut rng).unwrap();
+        assert_eq!(rotom.moves.len(), 2);
+        assert!(stats.generate_set(&dex, "Mew", &mut rng).is_err());
+
+        let sets = stats.plausible_sets(&dex, "Garchomp", 100, 1).unwrap();
+        assert!((sets.iter().map(|(_, weight)| weight).sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(sets.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+        assert_eq!(sets, stats.plausible_sets(&dex, "Garchomp", 100, 1).unwrap());
+
+        let pool = CandidatePool::from_usage(&stats, &dex, 50, 1);
+        for species in ["Garchomp", "Rotom-Wash"] {
+            let total: f64 = pool.candidates(species).iter().map(|(_, weight)| weight).sum();
+            assert!((total - 1.0).abs() < 1e-9);
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use pokemon_battle_core::battle_logger::BattleLogger;
+use pokemon_battle_core::dex::Dex;
+use pokemon_battle_core::engine::{BattleEngine, Player};
+use pokemon_battle_core::formats::format;
+use pokemon_battle_core::sim::battle::BattleState;
+use pokemon_battle_core::sim::pokemon::Pokemon;
+use pokemon_battle_core::sim::stats::Nature;
+use pokemon_battle_core::sim::{RandomAI, TeamChoice};
+
+fn make_pokemon(species: &str, item: Option<&str>) -> Pokemon {
+    Pokemon::new(
+        species,
+        50,
+        [0; 6],
+        [31; 6],
+        Nature::Hardy,
+        vec!["Tackle".to_string()],
+        "Pressure",
+        item.map(str::to_string),
+    )
+    .expect("species exists")
+}
+
+fn team(species: &[&str]) -> Vec<Pokemon> {
+    species.iter().map(|name| make_pokemon(name, Some("Leftovers"))).collect()
+}
+
+fn six_vs_six() -> (Vec<Pokemon>, Vec<Pokemon>) {
+    (
+        team(&["Garchomp", "Pikachu", "Gengar", "Dragonite", "Lucario", "Milotic"]),
+        team(&["Snorlax", "Gyarados", "Alakazam", "Tyranitar", "Scizor", "Blissey"]),
+    )
+}
+
+fn preview_engine(bring: usize) -> BattleEngine {
+    let (team_a, team_b) = six_vs_six();
+    let state = BattleState::new_with_bench(team_a[0].clone(), team_b[0].clone(), Vec::new(), Vec::new());
+    BattleEngine::with_team_preview(state, &team_a, &team_b, bring, 7)
+}
+
+#[test]
+fn preview_shows_opponent_species_and_blocks_actions() {
+    let engine = preview_engine(3);
+    assert!(engine.in_team_preview());
+    assert!(!engine.is_terminal());
+    assert!(engine.legal_actions(Player::A).is_empty());
+    let preview = engine.team_preview(Player::A).unwrap();
+    assert_eq!(preview.bring, 3);
+    assert_eq!(preview.team[0].species, "Garchomp");
+    assert_eq!(preview.opponent_species[0], "Snorlax");
+    assert_eq!(engine.legal_team_actions(Player::B).len(), 60);
+}
+
+#[test]
+fn chosen_lead_and_bench_start_the_battle() {
+    let mut engine = preview_engine(3);
+    engine.choose_team(Player::A, TeamChoice::parse("team 315", 6, 3).unwrap()).unwrap();
+    assert!(engine.in_team_preview());
+    assert!(engine.legal_team_actions(Player::A).is_empty());
+    assert!(engine.choose_team(Player::A, TeamChoice { order: vec![0, 1, 2] }).is_err());
+    assert!(engine.choose_team(Player::B, TeamChoice { order: vec![0, 1] }).is_err());
+    engine.choose_team(Player::B, TeamChoice { order: vec![5, 0, 1] }).unwrap();
+
+    assert!(!engine.in_team_preview());
+    assert!(engine.team_preview(Player::A).is_none());
+    let state = engine.state();
+    assert_eq!(state.pokemon_a.species, "Gengar");
+    let bench_a: Vec<&str> = state.bench_a.iter().map(|p| p.species.as_str()).collect();
+    assert_eq!(bench_a, ["Garchomp", "Lucario"]);
+    assert_eq!(state.pokemon_b.species, "Blissey");
+    assert_eq!(state.bench_b.len(), 2);
+    assert!(!engine.legal_actions(Player::A).is_empty());
+    assert!(engine.choose_team(Player::A, TeamChoice { order: vec![0, 1, 2] }).is_err());
+}
+
+#[test]
+fn ais_pick_teams_and_preview_is_logged() {
+    let (team_a, team_b) = six_vs_six();
+    let mut state = BattleState::new_with_bench(team_a[0].clone(), team_b[0].clone(), Vec::new(), Vec::new());
+    state.logger = Some(BattleLogger::new());
+    let mut engine = BattleEngine::with_team_preview(state, &team_a, &team_b, 4, 11);
+    engine.run_team_preview(&mut RandomAI::new(1), &mut RandomAI::new(2)).unwrap();
+    assert!(!engine.in_team_preview());
+    assert_eq!(engine.state().bench_a.len(), 3);
+    assert_eq!(engine.state().bench_b.len(), 3);
+
+    let lines = engine.state().logger.as_ref().unwrap().log_lines();
+    assert_eq!(lines[0], "|clearpoke");
+    assert_eq!(lines.iter().filter(|line| line.starts_with("|poke|p1|")).count(), 6);
+    assert!(lines.contains(&"|poke|p2|Snorlax, L50|item".to_string()));
+    assert!(lines.contains(&"|teampreview|4".to_string()));
+}
+
+#[test]
+fn formats_decide_whether_there_is_a_preview() {
+    let dex = Dex::builtin();
+    let (team_a, team_b) = six_vs_six();
+    let bss = format("gen9bss").unwrap();
+    let engine = BattleEngine::for_format(bss, &team_a, &team_b, 1, dex.clone()).unwrap();
+    assert!(engine.in_team_preview());
+    assert_eq!(engine.team_preview(Player::B).unwrap().bring, 3);
+
+    let random = format("gen9randombattle").unwrap();
+    let engine = BattleEngine::for_format(random, &team_a, &team_b, 1, dex.clone()).unwrap();
+    assert!(!engine.in_team_preview());
+    assert_eq!(engine.state().bench_a.len(), 5);
+
+    let vgc = format("gen9vgc2025").unwrap();
+    assert!(BattleEngine::for_format(vgc, &team_a, &team_b, 1, dex).is_err());
+}
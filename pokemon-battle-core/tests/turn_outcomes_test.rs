@@ -0,0 +1,129 @@
+use pokemon_battle_core::engine::{BattleEngine, Player};
+use pokemon_battle_core::sim::battle::{Action, BattleState};
+use pokemon_battle_core::sim::outcomes::{hp_distribution, probability, summarize, turn_outcomes};
+use pokemon_battle_core::sim::pokemon::{Pokemon, Status};
+use pokemon_battle_core::sim::stats::Nature;
+
+fn make(species: &str, moves: &[&str], item: Option<&str>) -> Pokemon {
+    Pokemon::new(
+        species,
+        50,
+        [0, 252, 0, 252, 0, 0],
+        [31; 6],
+        Nature::Hardy,
+        moves.iter().map(|m| m.to_string()).collect(),
+        "Pressure",
+        item.map(str::to_string),
+    )
+    .expect("species exists")
+}
+
+fn assert_close(a: f64, b: f64) {
+    assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+}
+
+#[test]
+fn damage_rolls_and_crits_are_enumerated_exactly() {
+    let state = BattleState::new(make("Snorlax", &["Tackle"], None), make("Blissey", &["Splash"], None));
+    let outcomes = turn_outcomes(&state, Action::Move(0), Action::Move(0));
+    assert_close(outcomes.iter().map(|o| o.probability).sum(), 1.0);
+
+    let summaries = summarize(&state, &outcomes);
+    let hp = hp_distribution(&summaries, Player::B);
+    let full = state.pokemon_b.current_hp;
+    // 16 rolls, each also as a crit (some rolls may deal the same damage).
+    assert!(hp.len() > 16 && hp.len() <= 32, "{:?}", hp);
+    assert!(hp.iter().all(|&(after, _)| after < full));
+    assert_close(hp.iter().map(|(_, p)| p).sum(), 1.0);
+    assert_close(hp_distribution(&summaries, Player::A)[0].1, 1.0);
+}
+
+#[test]
+fn accuracy_and_secondary_effects_have_exact_probabilities() {
+    let state = BattleState::new(make("Pikachu", &["Thunder"], None), make("Blissey", &["Splash"], None));
+    let outcomes = turn_outcomes(&state, Action::Move(0), Action::Move(0));
+    let full = state.pokemon_b.current_hp;
+    // Thunder is 70% accurate and paralyzes 30% of the time it hits.
+    assert_close(probability(&outcomes, |s| s.pokemon_b.current_hp == full), 0.3);
+    assert_close(probability(&outcomes, |s| s.pokemon_b.status == Some(Status::Paralysis)), 0.21);
+
+    let summaries = summarize(&state, &outcomes);
+    let paralyzed: f64 = summaries
+        .iter()
+        .filter(|(_, s)| s.starter(Player::B).status == Some(Status::Paralysis))
+        .map(|(p, _)| p)
+        .sum();
+    assert_close(paralyzed, 0.21);
+    assert!(summaries.len() <= outcomes.len());
+}
+
+#[test]
+fn faints_and_replacements_are_summarized_per_pokemon() {
+    let mut frail = make("Pikachu", &["Splash"], None);
+    frail.current_hp = 1;
+    let state = BattleState::new_with_bench(
+        make("Garchomp", &["Earthquake"], None),
+        frail,
+        Vec::new(),
+        vec![make("Eevee", &["Splash"], None), make("Snorlax", &["Splash"], None)],
+    );
+    let outcomes = turn_outcomes(&state, Action::Move(0), Action::Move(0));
+    let summaries = summarize(&state, &outcomes);
+    // Pikachu always faints; the random replacement is one of two, evenly.
+    assert!(summaries.iter().all(|(_, s)| s.starter(Player::B).fainted));
+    let eevee_in: f64 = summaries.iter().filter(|(_, s)| s.side(Player::B).active == 1).map(|(p, _)| p).sum();
+    assert_close(eevee_in, 0.5);
+}
+
+#[test]
+fn multi_hit_moves_branch_on_the_hit_count_not_on_every_hit() {
+    let state = BattleState::new(make("Cloyster", &["Icicle Spear"], None), make("Blissey", &["Splash"], None));
+    let outcomes = turn_outcomes(&state, Action::Move(0), Action::Move(0));
+    assert_close(outcomes.iter().map(|o| o.probability).sum(), 1.0);
+    // 4 hit counts x crit or not x 16 damage rolls.
+    assert!(outcomes.len() <= 4 * 2 * 16, "{} outcomes", outcomes.len());
+    let hp = hp_distribution(&summarize(&state, &outcomes), Player::B);
+    let full = state.pokemon_b.current_hp;
+    let worst = hp.first().unwrap().0;
+    let best = hp.last().unwrap().0;
+    // Five hits at least double what two hits do.
+    assert!(full - worst >= 2 * (full - best), "{:?}", hp);
+}
+
+#[test]
+fn same_name_pokemon_are_told_apart_after_switching() {
+    let mut frail = make("Pikachu", &["Splash"], None);
+    frail.current_hp = 1;
+    let mut hurt = make("Eevee", &["Splash"], None);
+    hurt.current_hp = 10;
+    let state = BattleState::new_with_bench(
+        make("Garchomp", &["Earthquake"], None),
+        frail,
+        Vec::new(),
+        vec![make("Eevee", &["Splash"], None), hurt],
+    );
+    let summaries = summarize(&state, &turn_outcomes(&state, Action::Move(0), Action::Move(0)));
+    for (_, summary) in &summaries {
+        let side = summary.side(Player::B);
+        assert_eq!(side.team.len(), 3);
+        assert_eq!(side.team[2].hp, 10);
+        assert_eq!(side.team[1].hp, side.team[1].max_hp);
+    }
+    let hurt_in: f64 = summaries.iter().filter(|(_, s)| s.side(Player::B).active == 2).map(|(p, _)| p).sum();
+    assert_close(hurt_in, 0.5);
+}
+
+#[test]
+fn engine_outcomes_include_end_of_turn_and_leave_the_engine_alone() {
+    let team_a = [make("Snorlax", &["Tackle"], None)];
+    let team_b = [make("Blissey", &["Splash"], Some("Leftovers"))];
+    let engine = BattleEngine::new(&team_a, &team_b, 1);
+    let outcomes = engine.outcomes(Action::Move(0), Action::Move(0));
+    assert_close(outcomes.iter().map(|o| o.probability).sum(), 1.0);
+    assert!(outcomes.iter().all(|o| o.state.turn == 1));
+    let plain = turn_outcomes(engine.state(), Action::Move(0), Action::Move(0));
+    let healed = |hp: u16| outcomes.iter().any(|o| o.state.pokemon_b.current_hp == hp);
+    assert!(plain.iter().any(|o| !healed(o.state.pokemon_b.current_hp)));
+    assert_eq!(engine.state().turn, 0);
+    assert_eq!(engine.state().pokemon_b.current_hp, engine.state().pokemon_b.stats.hp);
+}
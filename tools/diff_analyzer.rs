@@ -385,6 +385,7 @@ fn parse_log_array_to_turns(log: &[Value]) -> (Vec<TurnLog>, Option<String>, boo
     (turns, winner, tie)
 }
 
+#[allow(clippy::unwrap_or_default)]
 fn parse_turn(raw: &Value, idx: usize) -> TurnLog {
     let turn = get_u32(raw, "turn").unwrap_or_else(|| (idx + 1) as u32);
     let events = raw
@@ -395,6 +396,7 @@ fn parse_turn(raw: &Value, idx: usize) -> TurnLog {
     TurnLog { turn, events }
 }
 
+#[allow(clippy::unnecessary_lazy_evaluations, clippy::if_same_then_else)]
 fn parse_battle_log(root: &Value) -> BattleLog {
     let winner_from_field = get_str(root, "winner");
     let seed = root.get("seed").map(|v| v.to_string());
@@ -436,6 +438,7 @@ struct TurnDiff {
     mismatched: Vec<(Event, Event, Vec<String>)>,
 }
 
+#[allow(clippy::type_complexity)]
 fn compare_events(showdown: &[Event], rust: &[Event]) -> (Vec<Event>, Vec<Event>, Vec<(Event, Event, Vec<String>)>) {
     let mut s_map: BTreeMap<EventKey, Vec<Event>> = BTreeMap::new();
     let mut r_map: BTreeMap<EventKey, Vec<Event>> = BTreeMap::new();
@@ -0,0 +1,61 @@
+#!/usr/bin/env node
+"use strict";
+
+// Dumps the Showdown dex to a single JSON file.
+//
+// The Rust tables in pokemon-battle-core/src/data are rendered from this dump
+// by `pokemon-battle-cli gen-data`; node is only needed to refresh the dump.
+//
+// Usage: node tools/dump_dex.js [out.json]
+
+const fs = require("fs");
+const path = require("path");
+
+require("ts-node").register({
+  transpileOnly: true,
+  compilerOptions: {
+    module: "CommonJS",
+    moduleResolution: "Node",
+    esModuleInterop: true,
+    target: "ES2019",
+  },
+});
+
+const SHOWDOWN_DATA = path.resolve(__dirname, "../pokemon-showdown/data");
+const OUTPUT = path.resolve(
+  process.argv[2] || path.resolve(__dirname, "../pokemon-showdown/dex.json")
+);
+
+const { Pokedex } = require(path.resolve(SHOWDOWN_DATA, "pokedex"));
+const { Moves } = require(path.resolve(SHOWDOWN_DATA, "moves"));
+const { Abilities } = require(path.resolve(SHOWDOWN_DATA, "abilities"));
+const { Items } = require(path.resolve(SHOWDOWN_DATA, "items"));
+const { TypeChart } = require(path.resolve(SHOWDOWN_DATA, "typechart"));
+const { Learnsets } = require(path.resolve(SHOWDOWN_DATA, "learnsets"));
+const { MovesText } = require(path.resolve(SHOWDOWN_DATA, "text/moves"));
+const { AbilitiesText } = require(path.resolve(SHOWDOWN_DATA, "text/abilities"));
+const { ItemsText } = require(path.resolve(SHOWDOWN_DATA, "text/items"));
+
+// Descriptions live in data/text/*.ts rather than next to the data entries.
+function withText(entries, texts) {
+  for (const [id, entry] of Object.entries(entries)) {
+    const text = texts[id];
+    if (!text) continue;
+    if (text.shortDesc) entry.shortDesc = text.shortDesc;
+    if (text.desc) entry.desc = text.desc;
+  }
+  return entries;
+}
+
+// JSON.stringify drops the event handlers, leaving only the data fields.
+const dump = {
+  pokedex: Pokedex,
+  moves: withText(Moves, MovesText),
+  abilities: withText(Abilities, AbilitiesText),
+  items: withText(Items, ItemsText),
+  typechart: TypeChart,
+  learnsets: Learnsets,
+};
+
+fs.writeFileSync(OUTPUT, JSON.stringify(dump, null, 1) + "\n");
+console.log(`wrote ${OUTPUT}`);
@@ -12,8 +12,11 @@ else
   git -C "$SHOWDOWN_DIR" pull --ff-only
 fi
 
-echo "Extracting data..."
+echo "Dumping dex..."
 cd "$ROOT_DIR"
-node tools/extract_data.js
+node tools/dump_dex.js "$SHOWDOWN_DIR/dex.json"
+
+echo "Rendering tables..."
+cargo run --bin pokemon-battle-cli -- gen-data --dex "$SHOWDOWN_DIR/dex.json"
 
 echo "Done."